@@ -1,6 +1,8 @@
 //! Sample, purely random-data for atof benchmarks.
 //! Mostly invokes the worst-case scenarios.
 
+#![allow(clippy::excessive_precision, clippy::large_const_arrays, clippy::redundant_static_lifetimes)]
+
 extern crate criterion;
 extern crate lexical_core;
 
@@ -1,3 +1,5 @@
+#![allow(clippy::large_const_arrays, clippy::redundant_static_lifetimes)]
+
 extern crate criterion;
 extern crate lexical_core;
 
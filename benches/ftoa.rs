@@ -1,3 +1,11 @@
+#![allow(
+    clippy::eq_op,
+    clippy::excessive_precision,
+    clippy::large_const_arrays,
+    clippy::redundant_static_lifetimes,
+    clippy::zero_divided_by_zero
+)]
+
 extern crate criterion;
 extern crate dtoa;
 extern crate lexical_core;
@@ -39,6 +39,9 @@ cargo fmt
 cd ../lexical-derive
 cargo fmt
 
+cd ../lexical-macros
+cargo fmt
+
 cd ../lexical-benchmark/lexical
 cargo fmt
 
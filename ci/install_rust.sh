@@ -39,6 +39,11 @@ main() {
             ;;
     esac
 
+    # Tier 3 targets build the core library from source.
+    if [ ! -z $BUILD_STD ]; then
+        rustup component add rust-src
+    fi
+
     # This fetches latest stable release
     local tag=$(git ls-remote --tags --refs --exit-code https://github.com/japaric/cross \
                        | cut -d/ -f3 \
@@ -21,6 +21,13 @@ else
     CARGO_TARGET="--target $TARGET"
 fi
 
+# Tier 3 targets, such as the 16-bit AVR and MSP430, have no pre-built
+# core library, so build it from source.
+if [ ! -z $BUILD_STD ]; then
+    CARGO=cargo
+    CARGO_TARGET="--target $TARGET -Zbuild-std=core"
+fi
+
 # Detect our Python command if we are on travis or not (so we can test locally).
 if [ -z $CI ]; then
     # Not on CI, use latest Python3.
@@ -42,6 +49,11 @@ if [ -z $NO_STD ]; then
     REQUIRED_FEATURES="std,$REQUIRED_FEATURES"
 fi
 
+# Build the float and integer algorithms with small tables on tier 3 targets.
+if [ ! -z $BUILD_STD ]; then
+    REQUIRED_FEATURES="floats,integers,no_alloc,compact,$REQUIRED_FEATURES"
+fi
+
 # Add property tests to all tests if enabled.
 if [ -z $DISABLE_PROPERTY_TESTS ] && [ -z $DISABLE_TESTS ]; then
     REQUIRED_FEATURES="property_tests,$REQUIRED_FEATURES"
@@ -58,6 +70,7 @@ if [ ! -z $NO_FEATURES ]; then
     CORE_FEATURES=()
 else
     LEXICAL_FEATURES=(
+        "integers"
         "rounding"
         "rounding,power_of_two"
         "rounding,radix"
@@ -88,6 +101,8 @@ check() {
         "radix,rounding"
         "format,radix,rounding"
         "format,rounding,power_of_two"
+        "safe"
+        "format,radix,safe"
     )
 
     # Iterate over all features.
@@ -173,6 +188,9 @@ main() {
         build
         test "${LEXICAL_FEATURES[@]}"
         bench
+    else
+        # Ensure lexical builds without an allocator.
+        $CARGO build $CARGO_TARGET --no-default-features --features=no_alloc
     fi
 
     # Build and test lexical-core.
@@ -188,6 +206,10 @@ main() {
     # Build and test lexical-derive
     cd ../lexical-derive
     derive_tests
+
+    # Build and test lexical-macros
+    cd ../lexical-macros
+    derive_tests
 }
 
 # we don't run the "test phase" when doing deploys
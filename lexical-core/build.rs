@@ -10,10 +10,13 @@ fn main() {
     // instructions of architecture instruction support for 64-bit
     // mathematical operations.
 
+    // Portable builds use 32-bit limbs on all architectures, so the
+    // big-integer arithmetic is the same on every target.
     // https://github.com/rust-lang/cargo/issues/4302#issuecomment-316482399
     let limb_64_archs = ["aarch64", "mips64", "powerpc64", "x86_64"];
+    let portable = std::env::var("CARGO_FEATURE_PORTABLE").is_ok();
     let limb_width_64 = match std::env::var("CARGO_CFG_TARGET_ARCH") {
-        Ok(arch) => limb_64_archs.contains(&&*arch),
+        Ok(arch) => !portable && limb_64_archs.contains(&&*arch),
         _ => false,
     };
     if limb_width_64 {
@@ -33,4 +36,11 @@ fn main() {
     if (rustc.major, rustc.minor) >= (1, 50) {
         println!("cargo:rustc-cfg=has_slice_fill");
     }
+    // Const generics are required for the compile-time radix API.
+    if (rustc.major, rustc.minor) >= (1, 51) {
+        println!("cargo:rustc-cfg=has_const_generics");
+    }
+    if (rustc.major, rustc.minor) >= (1, 74) {
+        println!("cargo:rustc-cfg=has_saturating");
+    }
 }
@@ -0,0 +1,352 @@
+//! Parse and write a software half-precision (binary16) float.
+//!
+//! lexical only implements its algorithms for `f32` and `f64`, so `F16`
+//! implements `FromLexical` and `ToLexical` by converting through `f64`,
+//! which is exact from `F16`, and rounds to nearest, ties to even, using
+//! the layout from `Float`.
+//!
+//! Parsing rounds twice, first to `f64` and then to `F16`, which differs
+//! from the correctly rounded result only for inputs within `2^-53`
+//! (relative) of a halfway point between two `F16` values. Writing uses
+//! the shortest `f32` representation, which round-trips, but may have more
+//! digits than the shortest `F16` representation.
+
+extern crate lexical_core;
+
+use lexical_core::{AsCast, AsPrimitive, IsSigned, Primitive, TryCast, TryPrimitive};
+use lexical_core::{Float, FromLexical, Number, Result, ToLexical};
+use lexical_core::{ParseFloatOptions, WriteFloatOptions};
+use std::{cmp, fmt, iter, ops, str};
+
+/// Software IEEE754 half-precision float, stored as raw bits.
+#[derive(Clone, Copy, Debug, Default)]
+struct F16(u16);
+
+impl F16 {
+    /// Convert to an `f64`, which is always exact.
+    fn to_f64(self) -> f64 {
+        let value = if self.is_nan() {
+            f64::NAN
+        } else if self.is_inf() {
+            f64::INFINITY
+        } else {
+            self.mantissa() as f64 * 2f64.powi(self.exponent())
+        };
+        if self.is_sign_negative() {
+            -value
+        } else {
+            value
+        }
+    }
+
+    /// Round an `f64` to the nearest `F16`, with ties to even.
+    fn from_f64(value: f64) -> F16 {
+        if value.is_nan() {
+            return F16::NAN;
+        }
+        let sign = if value.is_sign_negative() {
+            F16::SIGN_MASK
+        } else {
+            0
+        };
+        if value.is_inf() {
+            return F16(sign | F16::INFINITY_BITS);
+        }
+
+        // The value is `mant * 2^exp`: find the exponent that keeps
+        // `MANTISSA_SIZE + 1` bits, without going below the denormal
+        // exponent, and round off the remaining bits.
+        let mant = value.mantissa();
+        let exp = value.exponent();
+        let top = exp + 63 - mant.leading_zeros() as i32;
+        let mut f16_exp = cmp::max(top - F16::MANTISSA_SIZE, F16::DENORMAL_EXPONENT);
+        let shift = (f16_exp - exp) as u32;
+        let mut f16_mant = match shift {
+            0 => mant,
+            1..=63 => {
+                let truncated = mant >> shift;
+                let remainder = mant & ((1 << shift) - 1);
+                let halfway = 1 << (shift - 1);
+                if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+                    truncated + 1
+                } else {
+                    truncated
+                }
+            },
+            _ => 0,
+        };
+        if f16_mant & F16::CARRY_MASK != 0 {
+            // Rounding carried into the next exponent.
+            f16_mant >>= 1;
+            f16_exp += 1;
+        }
+
+        let hidden = F16::HIDDEN_BIT_MASK as u64;
+        let bits = if f16_mant < hidden {
+            // Denormal or zero, which has a biased exponent of 0.
+            f16_mant as u16
+        } else if f16_exp > F16::MAX_EXPONENT {
+            F16::INFINITY_BITS
+        } else {
+            let biased_exp = (f16_exp + F16::EXPONENT_BIAS) as u16;
+            (biased_exp << F16::MANTISSA_SIZE) | (f16_mant as u16 & F16::MANTISSA_MASK)
+        };
+        F16(sign | bits)
+    }
+}
+
+// LAYOUT
+
+impl Float for F16 {
+    type Unsigned = u16;
+    type Mantissa = u64;
+
+    const ZERO: F16 = F16(0x0000);
+    const ONE: F16 = F16(0x3C00);
+    const TWO: F16 = F16(0x4000);
+    const MAX: F16 = F16(0x7BFF);
+    const MIN: F16 = F16(0xFBFF);
+    const INFINITY: F16 = F16(0x7C00);
+    const NEG_INFINITY: F16 = F16(0xFC00);
+    const NAN: F16 = F16(0x7E00);
+    const BITS: usize = 16;
+
+    const SIGN_MASK: u16 = 0x8000;
+    const EXPONENT_MASK: u16 = 0x7C00;
+    const HIDDEN_BIT_MASK: u16 = 0x0400;
+    const MANTISSA_MASK: u16 = 0x03FF;
+    const CARRY_MASK: u64 = 0x0800;
+
+    const INFINITY_BITS: u16 = 0x7C00;
+    const NEGATIVE_INFINITY_BITS: u16 = 0xFC00;
+    const EXPONENT_SIZE: i32 = 5;
+    const MANTISSA_SIZE: i32 = 10;
+    const EXPONENT_BIAS: i32 = 15 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0x1F - Self::EXPONENT_BIAS;
+
+    fn abs(self) -> F16 {
+        F16(self.0 & !F16::SIGN_MASK)
+    }
+
+    fn ceil(self) -> F16 {
+        F16::from_f64(self.to_f64().ceil())
+    }
+
+    fn exp(self) -> F16 {
+        F16::from_f64(self.to_f64().exp())
+    }
+
+    fn floor(self) -> F16 {
+        F16::from_f64(self.to_f64().floor())
+    }
+
+    fn ln(self) -> F16 {
+        F16::from_f64(self.to_f64().ln())
+    }
+
+    fn powi(self, n: i32) -> F16 {
+        F16::from_f64(self.to_f64().powi(n))
+    }
+
+    fn powf(self, n: F16) -> F16 {
+        F16::from_f64(self.to_f64().powf(n.to_f64()))
+    }
+
+    fn round(self) -> F16 {
+        F16::from_f64(self.to_f64().round())
+    }
+
+    fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    fn from_bits(u: u16) -> F16 {
+        F16(u)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.0 & F16::SIGN_MASK == 0
+    }
+
+    fn is_sign_negative(self) -> bool {
+        !self.is_sign_positive()
+    }
+}
+
+// LEXICAL
+
+impl Number for F16 {
+    const FORMATTED_SIZE: usize = <f32 as Number>::FORMATTED_SIZE;
+    const FORMATTED_SIZE_DECIMAL: usize = <f32 as Number>::FORMATTED_SIZE_DECIMAL;
+
+    type WriteOptions = WriteFloatOptions;
+    type ParseOptions = ParseFloatOptions;
+}
+
+impl FromLexical for F16 {
+    fn from_lexical(bytes: &[u8]) -> Result<F16> {
+        f64::from_lexical(bytes).map(F16::from_f64)
+    }
+
+    fn from_lexical_partial(bytes: &[u8]) -> Result<(F16, usize)> {
+        let (value, processed) = f64::from_lexical_partial(bytes)?;
+        Ok((F16::from_f64(value), processed))
+    }
+}
+
+impl ToLexical for F16 {
+    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        (self.to_f64() as f32).to_lexical(bytes)
+    }
+}
+
+// PRIMITIVE
+
+macro_rules! as_primitive {
+    ($($t:ident $meth:ident ; )*) => (
+        impl AsPrimitive for F16 {
+            $(
+                fn $meth(self) -> $t {
+                    self.to_f64() as $t
+                }
+            )*
+        }
+    );
+}
+
+as_primitive! {
+    u8 as_u8 ; u16 as_u16 ; u32 as_u32 ; u64 as_u64 ; u128 as_u128 ; usize as_usize ;
+    i8 as_i8 ; i16 as_i16 ; i32 as_i32 ; i64 as_i64 ; i128 as_i128 ; isize as_isize ;
+    f32 as_f32 ; f64 as_f64 ;
+}
+
+macro_rules! try_cast {
+    ($($t:ty)*) => ($(
+        impl TryCast<$t> for F16 {
+            fn try_cast(self) -> Option<$t> {
+                self.to_f64().try_cast()
+            }
+        }
+    )*);
+}
+
+try_cast! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
+
+impl AsCast for F16 {
+    fn as_cast<N: AsPrimitive>(n: N) -> F16 {
+        F16::from_f64(n.as_f64())
+    }
+}
+
+impl TryPrimitive for F16 {}
+
+impl Primitive for F16 {}
+
+impl IsSigned for F16 {
+    const IS_SIGNED: bool = true;
+}
+
+impl fmt::Display for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut buffer = [0u8; F16::FORMATTED_SIZE_DECIMAL];
+        let bytes = self.to_lexical(&mut buffer);
+        f.write_str(str::from_utf8(bytes).unwrap())
+    }
+}
+
+// OPERATIONS
+
+impl PartialEq for F16 {
+    fn eq(&self, other: &F16) -> bool {
+        self.to_f64() == other.to_f64()
+    }
+}
+
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &F16) -> Option<cmp::Ordering> {
+        self.to_f64().partial_cmp(&other.to_f64())
+    }
+}
+
+impl ops::Neg for F16 {
+    type Output = F16;
+
+    fn neg(self) -> F16 {
+        F16(self.0 ^ F16::SIGN_MASK)
+    }
+}
+
+// Rounding twice is exact for these operations, since `f64` has
+// more than twice the precision of `F16`.
+macro_rules! ops {
+    ($($t:ident $meth:ident $assign_t:ident $assign_meth:ident ; )*) => ($(
+        impl ops::$t for F16 {
+            type Output = F16;
+
+            fn $meth(self, other: F16) -> F16 {
+                F16::from_f64(self.to_f64().$meth(other.to_f64()))
+            }
+        }
+
+        impl ops::$assign_t for F16 {
+            fn $assign_meth(&mut self, other: F16) {
+                *self = ops::$t::$meth(*self, other);
+            }
+        }
+    )*);
+}
+
+ops! {
+    Add add AddAssign add_assign ;
+    Sub sub SubAssign sub_assign ;
+    Mul mul MulAssign mul_assign ;
+    Div div DivAssign div_assign ;
+    Rem rem RemAssign rem_assign ;
+}
+
+impl iter::Sum for F16 {
+    fn sum<I: Iterator<Item = F16>>(iter: I) -> F16 {
+        iter.fold(F16::ZERO, ops::Add::add)
+    }
+}
+
+impl iter::Product for F16 {
+    fn product<I: Iterator<Item = F16>>(iter: I) -> F16 {
+        iter.fold(F16::ONE, ops::Mul::mul)
+    }
+}
+
+pub fn main() {
+    // Parse, with rounding, overflow and underflow.
+    let parse = |bytes: &[u8]| lexical_core::parse::<F16>(bytes).unwrap();
+    assert_eq!(parse(b"1.5").to_bits(), 0x3E00);
+    assert_eq!(parse(b"0.1").to_bits(), 0x2E66);
+    assert_eq!(parse(b"-0.0").to_bits(), 0x8000);
+    assert_eq!(parse(b"65504").to_bits(), F16::MAX.to_bits());
+    assert_eq!(parse(b"65519.99").to_bits(), F16::MAX.to_bits());
+    assert_eq!(parse(b"65520").to_bits(), F16::INFINITY_BITS);
+    assert_eq!(parse(b"6e-8").to_bits(), 0x0001);
+    assert_eq!(parse(b"2.98e-8").to_bits(), 0x0000);
+    assert!(parse(b"NaN").is_nan());
+    assert_eq!(lexical_core::parse_partial::<F16>(b"2.5e1x"), Ok((F16(0x4E40), 5)));
+
+    // Write, which round-trips every value.
+    assert_eq!(F16::ONE.to_string(), "1.0");
+    assert_eq!(F16::MAX.to_string(), "65504.0");
+    assert_eq!(parse(b"0.1").to_string(), "0.099975586");
+    for bits in 0..F16::INFINITY_BITS {
+        let value = F16(bits);
+        assert_eq!(parse(value.to_string().as_bytes()).to_bits(), bits);
+        assert_eq!(parse((-value).to_string().as_bytes()).to_bits(), bits | F16::SIGN_MASK);
+    }
+
+    // Use the layout helpers from `Float`.
+    assert_eq!(F16::ONE.exponent(), -10);
+    assert_eq!(F16::ONE.mantissa(), 0x0400);
+    assert_eq!(F16::ONE.next().to_bits(), 0x3C01);
+    assert!(F16(0x0001).is_denormal());
+    assert_eq!(F16::TWO + F16::ONE, parse(b"3"));
+
+    println!("{} {} {}", F16::MIN, F16::ONE / parse(b"3"), F16::MAX.next());
+}
@@ -97,7 +97,8 @@ where
 {
     // Calculate the numerator and denominator.
     let exponent = -exponent;
-    let mut num = bhcomp::parse_mantissa::<F, _>(data, radix, max_digits);
+    let mut num = Bigint::<F>::default();
+    bhcomp::parse_mantissa::<F, _>(data, radix, max_digits, &mut num);
     let mut den = Bigint::<F>::from_u32(1);
     den.imul_power(radix, exponent.as_u32());
 
@@ -198,7 +199,7 @@ where
     if bhcomp::use_bigcomp(radix, count) {
         bigcomp::atof(data, radix, f, kind)
     } else if exponent >= 0 {
-        bhcomp::large_atof(data, radix, max_digits, exponent, kind)
+        bhcomp::large_atof(data, radix, max_digits, exponent, kind, &mut Bigint::default())
     } else {
         negative_exponent_atof(data, radix, max_digits, exponent, kind)
     }
@@ -624,7 +624,7 @@ macro_rules! float_type {
 }
 
 /// Trait to simplify type signatures for atof.
-pub(crate) trait FloatType: StablePower + MaxCorrectDigits + MaxIncorrectDigits
+pub(crate) trait FloatType: FloatStorage + StablePower + MaxCorrectDigits + MaxIncorrectDigits
 {
     type UnsignedType: FromUint;
     type MantissaType: MantissaType;
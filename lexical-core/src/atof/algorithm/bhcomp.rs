@@ -52,11 +52,15 @@ macro_rules! add_digits {
     };
 }
 
-/// Parse the full mantissa into a big integer.
+/// Parse the full mantissa into a big integer, reusing its storage.
 ///
 /// Max digits is the maximum number of digits plus one.
-pub(super) fn parse_mantissa<'a, F, Data>(data: Data, radix: u32, max_digits: usize) -> Bigint<F>
-where
+pub(super) fn parse_mantissa<'a, F, Data>(
+    data: Data,
+    radix: u32,
+    max_digits: usize,
+    result: &mut Bigint<F>,
+) where
     F: FloatType,
     Data: SlowDataInterface<'a>,
 {
@@ -72,7 +76,7 @@ where
     let mut counter = 0;
     let mut value: Limb = 0;
     let mut i: usize = 0;
-    let mut result = Bigint::<F>::default();
+    result.data.clear();
     result.data.reserve(bytes);
 
     // Iteratively process all the data in the mantissa.
@@ -90,7 +94,7 @@ where
         radix,
         max_digits
     );
-    if integer_iter.consumed() {
+    if integer_iter.consumed() && i < max_digits {
         // Continue if we haven't already processed the max digits.
         add_digits!(
             fraction_iter,
@@ -124,8 +128,6 @@ where
         result.imul_small(base);
         result.iadd_small(1);
     }
-
-    result
 }
 
 // ROUNDING
@@ -211,6 +213,7 @@ pub(super) fn large_atof<'a, F, Data>(
     max_digits: usize,
     exponent: i32,
     kind: RoundingKind,
+    bigmant: &mut Bigint<F>,
 ) -> F
 where
     F: FloatType,
@@ -220,7 +223,7 @@ where
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    let mut bigmant = parse_mantissa::<F, Data>(data, radix, max_digits);
+    parse_mantissa::<F, Data>(data, radix, max_digits, bigmant);
     bigmant.imul_power(radix, exponent.as_u32());
 
     // Get the exact representation of the float from the big integer.
@@ -238,6 +241,66 @@ where
 // BHCOMP
 // ------
 
+/// Maximum number of significant digits with the `bounded_bignum` feature.
+///
+/// The digits past the last one are replaced with a sticky digit, so
+/// the mantissa is below `10^65`. For `f64`, the slow path is only used
+/// near `b+h`, so the scientific exponent is from -325 to 308, and:
+///
+///  * `large_atof` creates the mantissa scaled to the float, which is
+///    below `10^310`, or 1030 bits.
+///  * `small_atof` scales the real digits and `b+h` to the same binary
+///    and decimal exponents, which is at most 54 bits for `b+h` and
+///    `log2(5)` bits for each of the at most 390 decimal digits after
+///    the point, or 960 bits.
+///
+/// Both fit in the 1280-bit storage of the `f64` big integers, with at
+/// most two big integers at a time, since the products are less than
+/// `KARATSUBA_CUTOFF` limbs and are calculated in place. The result is
+/// correct for up to `BOUNDED_DIGITS` digits. Otherwise, it is within
+/// 1 ULP of the correct result, and only differs if the digits past the
+/// last one decide a halfway case.
+#[cfg(feature = "bounded_bignum")]
+pub(crate) const BOUNDED_DIGITS: usize = 64;
+
+/// Limit the maximum number of digits with the `bounded_bignum` feature.
+#[inline(always)]
+fn bounded_digits(max_digits: usize) -> usize {
+    #[cfg(feature = "bounded_bignum")]
+    return max_digits.min(BOUNDED_DIGITS + 1);
+
+    #[cfg(not(feature = "bounded_bignum"))]
+    return max_digits;
+}
+
+/// Calculate the maximum number of digits that can change the rounded float.
+///
+/// For even radixes, the digits of the theoretical float (`b+h`, or `b`
+/// for directed rounding) terminate at the radix exponent
+/// `min(theor_exp, 0)`, so any digits past that point are equivalent to a
+/// single, sticky digit. Odd radixes have non-terminating representations,
+/// so use the worst-case number of digits.
+///
+/// Returns the maximum number of digits plus one, including the sticky
+/// digit, for `parse_mantissa`.
+#[inline]
+fn max_significant_digits<F>(radix: u32, sci_exponent: i32, f: F, kind: RoundingKind) -> usize
+where
+    F: FloatType,
+{
+    let max_digits = bounded_digits(unwrap_or_max(F::max_correct_digits(radix)));
+    if radix.is_odd() {
+        return max_digits;
+    }
+
+    // Digits from the scientific exponent to the last digit of the
+    // theoretical float, inclusive, and the sticky digit. The input is
+    // near `b`, so this is positive, but use at least 1 significant digit.
+    let theor_exp = bigcomp::theoretical_float(f, kind).exp().min(0);
+    let digits = sci_exponent as i64 - theor_exp as i64 + 2;
+    max_digits.min(digits.max(2) as usize)
+}
+
 /// Calculate the mantissa for a big integer with a negative exponent.
 ///
 /// This invokes the comparison with `b+h`.
@@ -248,6 +311,7 @@ pub(super) fn small_atof<'a, F, Data>(
     exponent: i32,
     f: F,
     kind: RoundingKind,
+    scratch: &mut BigintScratch<F>,
 ) -> F
 where
 // FUCK MY TRAIT BOUNDS
@@ -255,13 +319,15 @@ where
     Data: SlowDataInterface<'a>,
 {
     // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = parse_mantissa::<F, Data>(data, radix, max_digits);
+    let real_digits = &mut scratch.real;
+    parse_mantissa::<F, Data>(data, radix, max_digits, real_digits);
     let real_exp = exponent;
     debug_assert!(real_exp < 0);
 
     // Get the significant digits and the binary exponent for `b+h`.
     let theor = bigcomp::theoretical_float(f, kind);
-    let mut theor_digits = Bigint::from_mant(theor.mant());
+    let theor_digits = &mut scratch.theor;
+    theor_digits.set_mant(theor.mant());
     let theor_exp = theor.exp();
 
     // We need to scale the real digits and `b+h` digits to be the same
@@ -298,7 +364,7 @@ where
         real_digits.imul_power(2, (-binary_exp).as_u32());
     }
 
-    bigcomp::round_to_native(f, real_digits.compare(&theor_digits), kind)
+    bigcomp::round_to_native(f, real_digits.compare(theor_digits), kind)
 }
 
 /// Calculate the exact value of the float.
@@ -307,7 +373,14 @@ where
 ///     The digits iterator must not have any trailing zeros (true for
 ///     `FloatState2`).
 ///     sci_exponent and digits.size_hint() must not overflow i32.
-pub(super) fn atof<'a, F, Data>(data: Data, radix: u32, f: F, kind: RoundingKind) -> F
+///     The big integers are stored in the scratch space.
+pub(super) fn atof<'a, F, Data>(
+    data: Data,
+    radix: u32,
+    f: F,
+    kind: RoundingKind,
+    scratch: &mut BigintScratch<F>,
+) -> F
 where
     F: FloatType,
     Data: SlowDataInterface<'a>,
@@ -317,16 +390,20 @@ where
     // to have a finite representation in radix `y`, `b` should divide
     // an integer power of `y`. This means for binary, all even radixes
     // have finite representations, and all odd ones do not.
-    let max_digits = unwrap_or_max(F::max_correct_digits(radix));
+    //
+    // Only parse the digits that can change the rounded result, so long
+    // mantissas do not require large big integers.
+    let sci_exponent = data.scientific_exponent();
+    let max_digits = max_significant_digits(radix, sci_exponent, f, kind);
     let count = max_digits.min(data.mantissa_digits());
-    let exponent = data.scientific_exponent() + 1 - count.as_i32();
+    let exponent = sci_exponent + 1 - count.as_i32();
 
     if cfg!(feature = "radix") && use_bigcomp(radix, count) {
         // Use the slower algorithm for giant data, since we use a lot less memory.
         bigcomp::atof(data, radix, f, kind)
     } else if exponent >= 0 {
-        large_atof(data, radix, max_digits, exponent, kind)
+        large_atof(data, radix, max_digits, exponent, kind, &mut scratch.real)
     } else {
-        small_atof(data, radix, max_digits, exponent, f, kind)
+        small_atof(data, radix, max_digits, exponent, f, kind, scratch)
     }
 }
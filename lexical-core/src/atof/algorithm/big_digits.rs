@@ -0,0 +1,279 @@
+//! Generate the decimal digits of a big-integer mantissa.
+//!
+//! Runs the big-integer arithmetic of `bhcomp` in reverse: the value is
+//! the ratio of two big integers, scaled by a power of 10 so the ratio
+//! is in `[1, 10)`, and each digit is the quotient of the ratio, like
+//! Steele and White's free-format algorithm with a fixed number of
+//! digits. The last digit is rounded to nearest, with ties to even.
+
+use crate::lib::cmp;
+use crate::util::*;
+
+use super::bignum::*;
+use super::math::*;
+
+/// Big integer for the numerator and the denominator.
+type Bignum = Bigint<f64>;
+
+cfg_if! {
+if #[cfg(any(not(feature = "no_alloc"), feature = "heap_bignum", feature = "radix"))] {
+    /// Maximum number of bits in the big integers, which may grow.
+    const MAX_BITS: u64 = u64::max_value();
+} else {
+    use crate::traits::{FloatStorage, Integer};
+
+    /// Maximum number of bits in the big integers, in the storage of `f64`.
+    const MAX_BITS: u64 = (<f64 as FloatStorage>::BIGINT_LIMBS * <Limb as Integer>::BITS) as u64;
+}} // cfg_if
+
+/// Get the number of bits to store `10^n`, rounded up.
+#[inline]
+fn pow10_bits(n: i64) -> u64 {
+    // 3402 / 1024 is slightly above log2(10).
+    ((n.max(0) as u64 * 3402) >> 10) + 1
+}
+
+/// Get the number of bits to store the 32-bit words.
+#[inline]
+fn word_bits(words: &[u32]) -> u64 {
+    match words.iter().rposition(|&x| x != 0) {
+        Some(index) => 32 * (index as u64 + 1) - words[index].leading_zeros() as u64,
+        None => 0,
+    }
+}
+
+/// Create the numerator and denominator of the value, without scaling.
+///
+/// Returns `None` if the big integers may exceed their storage.
+#[inline]
+fn ratio(mantissa: &[u32], exponent: BigExponent) -> Option<(Bignum, Bignum)> {
+    let bits = word_bits(mantissa);
+    let (num_bits, den_bits) = match exponent {
+        BigExponent::Binary(exp) => {
+            let exp = exp as i64;
+            (bits + exp.max(0) as u64, 1 + (-exp).max(0) as u64)
+        },
+        BigExponent::Decimal(exp) => {
+            let exp = exp as i64;
+            (bits + pow10_bits(exp), pow10_bits(-exp))
+        },
+    };
+    // Scaling the ratio keeps both integers below the larger one,
+    // apart from the bits to estimate, generate and round the digits.
+    if num_bits.max(den_bits).saturating_add(16) > MAX_BITS {
+        return None;
+    }
+
+    let mut num = Bignum::from_u32_words(mantissa);
+    let mut den = Bignum::from_u32(1);
+    let (radix, exp) = match exponent {
+        BigExponent::Binary(exp) => (2, exp),
+        BigExponent::Decimal(exp) => (10, exp),
+    };
+    if exp >= 0 {
+        num.imul_power(radix, exp as u32);
+    } else {
+        den.imul_power(radix, exp.wrapping_neg() as u32);
+    }
+    Some((num, den))
+}
+
+/// Scale the ratio of the value to `[1, 10)`, with the exponent of the scale.
+///
+/// The ratio of zero is not scaled. Returns `None` if the big integers
+/// may exceed their storage.
+#[inline]
+fn scaled_ratio(mantissa: &[u32], exponent: BigExponent) -> Option<(Bignum, Bignum, i64)> {
+    let (mut num, mut den) = ratio(mantissa, exponent)?;
+    if num.is_zero() {
+        return Some((num, den, 0));
+    }
+
+    // Estimate the exponent from the bit lengths, which is at most the
+    // exponent of the value, then correct it.
+    let bits = num.bit_length() as i64 - den.bit_length() as i64;
+    let mut exp = ((bits - 1) * 1233) >> 12;
+    if exp >= 0 {
+        den.imul_power(10, exp as u32);
+    } else {
+        num.imul_power(10, exp.wrapping_neg() as u32);
+    }
+    while num.greater_equal(&den.mul_small(10)) {
+        den.imul_small(10);
+        exp += 1;
+    }
+    debug_assert!(num.greater_equal(&den));
+    Some((num, den, exp))
+}
+
+/// Get the next digit, the quotient of the ratio, leaving the remainder.
+#[inline]
+fn next_digit(num: &mut Bignum, den: &Bignum) -> u8 {
+    let mut quotient = b'0';
+    while num.greater_equal(den) {
+        num.isub_large(den);
+        quotient += 1;
+    }
+    quotient
+}
+
+/// Fill the buffer with the correctly rounded decimal digits of a value.
+///
+/// The value is `mantissa * 2^exp` or `mantissa * 10^exp`, where the
+/// mantissa is 32-bit words in little-endian order. Returns the
+/// exponent of the first digit, or `None` if the big integers may
+/// exceed their storage. The buffer must not be empty.
+pub(crate) fn big_digits(
+    mantissa: &[u32],
+    exponent: BigExponent,
+    digits: &mut [u8],
+) -> Option<i64> {
+    debug_assert!(!digits.is_empty());
+    let (mut num, den, mut exp) = scaled_ratio(mantissa, exponent)?;
+    if num.is_zero() {
+        write_bytes(digits, b'0');
+        return Some(0);
+    }
+
+    for (index, digit) in digits.iter_mut().enumerate() {
+        if index != 0 {
+            num.imul_small(10);
+        }
+        *digit = next_digit(&mut num, &den);
+    }
+
+    // Round the last digit, by comparing the remainder to half the denominator.
+    let last = digits[digits.len() - 1];
+    let round_up = match num.shl(1).compare(&den) {
+        cmp::Ordering::Less => false,
+        cmp::Ordering::Greater => true,
+        cmp::Ordering::Equal => (last - b'0') % 2 == 1,
+    };
+    if round_up {
+        match digits.iter().rposition(|&c| c != b'9') {
+            Some(index) => {
+                digits[index] += 1;
+                write_bytes(&mut digits[index + 1..], b'0');
+            },
+            None => {
+                digits[0] = b'1';
+                write_bytes(&mut digits[1..], b'0');
+                exp += 1;
+            },
+        }
+    }
+    Some(exp)
+}
+
+/// Fill the buffer with all the decimal digits of a value, exactly.
+///
+/// The value must have a finite decimal expansion, like binary and
+/// decimal floats. Returns the number of digits and the exponent of
+/// the first digit, or `None` if the buffer is too small or if the
+/// big integers may exceed their storage.
+pub(crate) fn exact_digits(
+    mantissa: &[u32],
+    exponent: BigExponent,
+    digits: &mut [u8],
+) -> Option<(usize, i64)> {
+    let (mut num, den, exp) = scaled_ratio(mantissa, exponent)?;
+    if num.is_zero() {
+        *digits.first_mut()? = b'0';
+        return Some((1, 0));
+    }
+
+    let mut count = 0;
+    while !num.is_zero() {
+        if count != 0 {
+            num.imul_small(10);
+        }
+        *digits.get_mut(count)? = next_digit(&mut num, &den);
+        count += 1;
+    }
+    Some((count, exp))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(mantissa: &[u32], exponent: BigExponent, expected: &[u8], expected_exp: i64) {
+        let mut digits = [0u8; 64];
+        let digits = &mut digits[..expected.len()];
+        assert_eq!(big_digits(mantissa, exponent, digits), Some(expected_exp));
+        assert_eq!(digits, expected);
+    }
+
+    #[test]
+    fn big_digits_test() {
+        check(&[0], BigExponent::Binary(100), b"000", 0);
+        check(&[], BigExponent::Decimal(-5), b"0", 0);
+        check(&[1], BigExponent::Binary(0), b"100", 0);
+        check(&[3], BigExponent::Binary(-1), b"15", 0);
+        check(&[1], BigExponent::Binary(-10), b"9765625", -4);
+        check(&[1], BigExponent::Binary(64), b"18446744073709551616", 19);
+        check(&[0, 1], BigExponent::Binary(0), b"4294967296", 9);
+        check(&[125], BigExponent::Decimal(-5), b"125", -3);
+        check(&[999], BigExponent::Decimal(0), b"999", 2);
+        check(&[1], BigExponent::Decimal(300), b"1000", 300);
+        check(&[1], BigExponent::Decimal(-300), b"1", -300);
+
+        // Rounding, with ties to even.
+        check(&[1], BigExponent::Binary(-3), b"12", -1);
+        check(&[3], BigExponent::Binary(-3), b"38", -1);
+        check(&[999], BigExponent::Decimal(0), b"10", 3);
+        check(&[2], BigExponent::Decimal(-1), b"2", -1);
+        check(&[1], BigExponent::Binary(-10), b"977", -4);
+        check(&[2], BigExponent::Binary(0), b"2", 0);
+        check(&[25], BigExponent::Decimal(0), b"2", 1);
+        check(&[35], BigExponent::Decimal(0), b"4", 1);
+    }
+
+    #[test]
+    fn big_digits_float_test() {
+        // The smallest denormal and the largest finite f64.
+        check(&[1], BigExponent::Binary(-1074), b"49406564584124654", -324);
+        let max = [u32::max_value(), 0x1F_FFFF];
+        check(&max, BigExponent::Binary(971), b"17976931348623157", 308);
+    }
+
+    #[test]
+    fn exact_digits_test() {
+        let check = |mantissa: &[u32], exponent, expected: &[u8], expected_exp| {
+            let mut digits = [0u8; 1024];
+            let result = exact_digits(mantissa, exponent, &mut digits);
+            assert_eq!(result, Some((expected.len(), expected_exp)));
+            assert_eq!(&digits[..expected.len()], expected);
+        };
+        check(&[0], BigExponent::Binary(-10), b"0", 0);
+        check(&[3], BigExponent::Binary(-3), b"375", -1);
+        check(&[1], BigExponent::Binary(-10), b"9765625", -4);
+        check(&[1200], BigExponent::Decimal(-2), b"12", 1);
+
+        // The f64 nearest to 0.1.
+        let expected = b"1000000000000000055511151231257827021181583404541015625";
+        check(&[0x9999_999A, 0x19_9999], BigExponent::Binary(-56), expected, -1);
+
+        let mut digits = [0u8; 2];
+        assert_eq!(exact_digits(&[3], BigExponent::Binary(-3), &mut digits), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "no_alloc", not(feature = "heap_bignum"), not(feature = "radix")))]
+    fn big_digits_storage_test() {
+        let mut digits = [0u8; 4];
+        assert_eq!(big_digits(&[1], BigExponent::Binary(100_000), &mut digits), None);
+        assert_eq!(big_digits(&[1], BigExponent::Decimal(-100_000), &mut digits), None);
+    }
+
+    #[test]
+    #[cfg(feature = "heap_bignum")]
+    fn big_digits_heap_storage_test() {
+        let mut digits = [0u8; 4];
+        assert!(big_digits(&[1], BigExponent::Binary(5000), &mut digits).is_some());
+        assert!(big_digits(&[1], BigExponent::Decimal(-1500), &mut digits).is_some());
+    }
+}
@@ -9,7 +9,6 @@
 //!     https://www.exploringbinary.com/bigcomp-deciding-truncated-near-halfway-conversions/
 
 use crate::lib::cmp;
-use crate::table::*;
 use crate::traits::*;
 use crate::util::*;
 
@@ -207,12 +206,13 @@ where
 macro_rules! compare_digits {
     ($iter:ident, $radix:ident, $num:ident, $den:ident) => {
         while !$num.data.is_empty() {
+            // Compare digit values, since letter digits may be lowercase.
             let actual = match $iter.next() {
-                Some(&v) => v,
+                Some(&c) => as_limb(to_digit(c, $radix).unwrap()),
                 None => return cmp::Ordering::Less,
             };
-            let expected = digit_to_char($num.quorem(&$den));
-            $num.imul_small($radix);
+            let expected = $num.quorem(&$den);
+            $num.imul_small(as_limb($radix));
             if actual < expected {
                 return cmp::Ordering::Less;
             } else if actual > expected {
@@ -243,15 +243,14 @@ where
 {
     // Iterate until we get a difference in the generated digits.
     // If we run out,return Equal.
-    let radix = as_limb(radix);
     let mut iter = integer.chain(fraction);
     compare_digits!(iter, radix, num, den);
 
-    // We cannot have any trailing zeros, so if there any remaining digits,
-    // we're >= to the value. We've already exhausted num.data here,
-    // so need to check if integer and fraction don't have data.
-    let is_none = iter.next().is_none();
-    match is_none {
+    // The fraction cannot have any trailing zeros, but the integer
+    // digits may, so if there any remaining non-zero digits, we're > to
+    // the value. We've already exhausted num.data here, so need to check
+    // if integer and fraction don't have data.
+    match iter.all(|&c| c == b'0') {
         true => cmp::Ordering::Equal,
         false => cmp::Ordering::Greater,
     }
@@ -359,22 +358,28 @@ mod tests {
             assert_eq!(
                 num1,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         1725370368, 1252154597, 1017462556, 675087593, 2805901938, 1401824593,
                         1124332496, 2380663002, 1612846757, 4128923878, 1492915356, 437569744,
                         2975325085, 3331531962, 3367627909, 730662168, 2699172281, 1440714968,
                         2778340312, 690527038, 1297115354, 763425880, 1453089653, 331561842
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 312
                 }
             );
             assert_eq!(
                 den1,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                         134217728
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 312
                 }
             );
@@ -382,22 +387,28 @@ mod tests {
             assert_eq!(
                 num2,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         881143808, 3756463792, 3052387668, 2025262779, 4122738518, 4205473780,
                         3372997488, 2847021710, 543572976, 3796837043, 183778774, 1312709233,
                         336040663, 1404661296, 1512949137, 2191986506, 3802549547, 27177609,
                         4040053641, 2071581115, 3891346062, 2290277640, 64301663, 994685527
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 312
                 }
             );
             assert_eq!(
                 den2,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                         134217728
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 312
                 }
             );
@@ -405,22 +416,28 @@ mod tests {
             assert_eq!(
                 num3,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1024,
                         2147483648
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 288
                 }
             );
             assert_eq!(
                 den3,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         1978138624, 2671552565, 2938166866, 3588566204, 1860064291, 2104472219,
                         2014975858, 2797301608, 462262832, 318515330, 1101517094, 1738264167,
                         3721375114, 414401884, 1406861075, 3053102637, 387329537, 2051556775,
                         1867945454, 3717689914, 1434550525, 1446648206, 238915486
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 288
                 }
             );
@@ -431,7 +448,7 @@ mod tests {
             assert_eq!(
                 num1,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         7410409304047484928,
                         4369968404176723173,
                         12051257060168107241,
@@ -445,14 +462,20 @@ mod tests {
                         5571068025259989822,
                         6240972538554414168,
                         331561842
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 280
                 }
             );
             assert_eq!(
                 den1,
                 Bigfloat {
-                    data: vector![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134217728],
+                    data: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134217728]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 280
                 }
             );
@@ -460,7 +483,7 @@ mod tests {
             assert_eq!(
                 num2,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         3784483838432903168,
                         13109905212530169520,
                         17707027106794770107,
@@ -474,14 +497,20 @@ mod tests {
                         16713204075779969467,
                         276173541953690888,
                         994685527
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 280
                 }
             );
             assert_eq!(
                 den2,
                 Bigfloat {
-                    data: vector![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134217728],
+                    data: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134217728]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 280
                 }
             );
@@ -489,14 +518,17 @@ mod tests {
             assert_eq!(
                 num3,
                 Bigfloat {
-                    data: vector![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4398046511104, 2147483648],
+                    data: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4398046511104, 2147483648]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 288
                 }
             );
             assert_eq!(
                 den3,
                 Bigfloat {
-                    data: vector![
+                    data: [
                         11474230898198052864,
                         15412774488649031250,
                         9038639357805614115,
@@ -509,7 +541,10 @@ mod tests {
                         15967356599166997998,
                         6213306735021621501,
                         238915486
-                    ],
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect(),
                     exp: 288
                 }
             );
@@ -626,6 +661,13 @@ mod tests {
             cmp::Ordering::Equal
         );
 
+        // Exactly halfway, with trailing zeros.
+        let zeros = b"000";
+        assert_eq!(
+            compare_digits(digits.iter(), zeros.iter(), 10, num.clone(), den.clone()),
+            cmp::Ordering::Equal
+        );
+
         // Above halfway.
         let digits = b"89884656743115805365666807213050294962762414131308158973971342756154045415486693752413698006024096935349884403114202125541629105369684531108613657287705365884742938136589844238179474556051429648741514697857438797685859063890851407391008830874765563025951597582513936655578157348020066364210154316532161708032001";
         assert_eq!(
@@ -633,4 +675,35 @@ mod tests {
             cmp::Ordering::Greater
         );
     }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn compare_digits_radix_test() {
+        // 1 + 2^-53, which has lowercase and uppercase digits in radix 36.
+        let (num, den) = make_ratio(36, 0, 1f64, RoundingKind::NearestTieEven);
+        let integer = b"1";
+        let fraction = b"0000000000em2djky0vz957y14i";
+        assert_eq!(
+            compare_digits(integer.iter(), fraction.iter(), 36, num.clone(), den.clone()),
+            cmp::Ordering::Equal
+        );
+
+        let fraction = b"0000000000EM2DJKY0VZ957Y14I";
+        assert_eq!(
+            compare_digits(integer.iter(), fraction.iter(), 36, num.clone(), den.clone()),
+            cmp::Ordering::Equal
+        );
+
+        let fraction = b"0000000000em2djky0vz957y14h";
+        assert_eq!(
+            compare_digits(integer.iter(), fraction.iter(), 36, num.clone(), den.clone()),
+            cmp::Ordering::Less
+        );
+
+        let fraction = b"0000000000em2djky0vz957y14i1";
+        assert_eq!(
+            compare_digits(integer.iter(), fraction.iter(), 36, num.clone(), den.clone()),
+            cmp::Ordering::Greater
+        );
+    }
 }
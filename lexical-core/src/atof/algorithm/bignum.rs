@@ -103,6 +103,27 @@ impl<F: FloatType> Bigint<F> {
     pub(crate) fn from_mant(mant: F::UnsignedType) -> Self {
         Self::from_uint(mant)
     }
+
+    /// Assign the mantissa digits, reusing the storage.
+    #[inline(always)]
+    pub(crate) fn set_mant(&mut self, mant: F::UnsignedType) {
+        mant.set_uint(self)
+    }
+
+    /// Create the container from 32-bit words, in little-endian order.
+    #[inline]
+    pub(crate) fn from_u32_words(words: &[u32]) -> Self {
+        let mut bigint = Self::default();
+        #[cfg(limb_width_32)]
+        bigint.data.extend_from_slice(words);
+        #[cfg(limb_width_64)]
+        for chunk in words.chunks(2) {
+            let hi = chunk.get(1).map_or(0, |&x| as_limb(x));
+            bigint.data.push(as_limb(chunk[0]) | hi << 32);
+        }
+        bigint.normalize();
+        bigint
+    }
 }
 
 impl<F: FloatType> Default for Bigint<F> {
@@ -117,6 +138,28 @@ impl<F: FloatType> Default for Bigint<F> {
     }
 }
 
+/// Big integers for the slow path, which may be reused between parses.
+///
+/// With an allocator, the big integers keep their storage, so repeated
+/// slow-path parses do not allocate.
+#[derive(Clone)]
+pub(crate) struct BigintScratch<F: FloatType> {
+    /// Significant digits of the input.
+    pub(crate) real: Bigint<F>,
+    /// Digits of the theoretical float.
+    pub(crate) theor: Bigint<F>,
+}
+
+impl<F: FloatType> Default for BigintScratch<F> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            real: Bigint::default(),
+            theor: Bigint::default(),
+        }
+    }
+}
+
 impl<F: FloatType> SharedOps for Bigint<F> {
     type StorageType = F::BigintStorage;
 
@@ -19,8 +19,9 @@
 //!     2300 i32
 //!
 //! This file is mostly automatically generated, do not change values
-//! manually, unless you know what you are doing. The script to generate
-//! the values is as follows:
+//! manually, unless you know what you are doing. The values can also be
+//! generated with `codegen::ModeratePathPowers`, with the codegen feature.
+//! The script to generate the values is as follows:
 //!
 //! ```text
 //! import math
@@ -19,8 +19,9 @@
 //!     2300 i32
 //!
 //! This file is mostly automatically generated, do not change values
-//! manually, unless you know what you are doing. The script to generate
-//! the values is as follows:
+//! manually, unless you know what you are doing. The values can also be
+//! generated with `codegen::ModeratePathPowers`, with the codegen feature.
+//! The script to generate the values is as follows:
 //!
 //! ```text
 //! import math
@@ -244,6 +245,28 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "codegen")]
+    #[test]
+    fn codegen_test() {
+        use crate::codegen::{self, FloatLayout};
+
+        // Ensure the generated powers match the cached powers.
+        for base in BASE_POWN.iter().cloned() {
+            let powers = get_powers(base);
+            let expected = codegen::ModeratePathPowers::new(base, 64, &FloatLayout::F64);
+            let small_mant: Vec<u128> = powers.small.mant.iter().map(|&x| x as u128).collect();
+            let large_mant: Vec<u128> = powers.large.mant.iter().map(|&x| x as u128).collect();
+            let small_int: Vec<u128> = powers.small_int.iter().map(|&x| x as u128).collect();
+            assert_eq!(small_mant, expected.small.mant, "{}", base);
+            assert_eq!(powers.small.exp, &expected.small.exp[..], "{}", base);
+            assert_eq!(large_mant, expected.large.mant, "{}", base);
+            assert_eq!(powers.large.exp, &expected.large.exp[..], "{}", base);
+            assert_eq!(small_int, expected.small_int, "{}", base);
+            assert_eq!(powers.step, expected.step, "{}", base);
+            assert_eq!(powers.bias, expected.bias, "{}", base);
+        }
+    }
+
     #[cfg(feature = "radix")]
     #[test]
     #[should_panic]
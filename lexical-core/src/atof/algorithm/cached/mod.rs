@@ -20,16 +20,20 @@ if #[cfg(feature = "f128")] {
 // ------
 
 /// Precalculated powers that uses two-separate arrays for memory-efficiency.
-#[doc(hidden)]
-pub(crate) struct ExtendedFloatArray<M: Mantissa> {
-    // Pre-calculated mantissa for the powers.
+///
+/// The extended float at `index` is `mant[index] * 2^exp[index]`, so
+/// both arrays must have the same length.
+pub struct ExtendedFloatArray<M: Mantissa> {
+    /// Pre-calculated mantissa for the powers.
     pub mant: &'static [M],
-    // Pre-calculated binary exponents for the powers.
+    /// Pre-calculated binary exponents for the powers.
     pub exp: &'static [i32],
 }
 
-/// Allow indexing of values without bounds checking
 impl<M: Mantissa> ExtendedFloatArray<M> {
+    /// Get the extended float at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
     #[inline]
     pub fn get_extended_float(&self, index: usize) -> ExtendedFloat<M> {
         let mant = self.mant[index];
@@ -40,42 +44,55 @@ impl<M: Mantissa> ExtendedFloatArray<M> {
         }
     }
 
+    /// Get the number of pre-calculated powers.
     #[inline]
     pub fn len(&self) -> usize {
         self.mant.len()
     }
+
+    /// Get if there are no pre-calculated powers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.mant.is_empty()
+    }
 }
 
 // MODERATE PATH POWERS
 // --------------------
 
 /// Precalculated powers of base N for the moderate path.
-#[doc(hidden)]
-pub(crate) struct ModeratePathPowers<M: Mantissa> {
-    // Pre-calculated small powers.
+///
+/// Any power `N^exponent` in range is the product of a large and a small
+/// power: with `biased = exponent + bias`, the large power is at
+/// `biased / step` and the small power is at `biased % step`. The
+/// `codegen` feature generates these tables for custom float types.
+pub struct ModeratePathPowers<M: Mantissa> {
+    /// Pre-calculated small powers, `N^0` through `N^(step-1)`.
     pub small: ExtendedFloatArray<M>,
-    // Pre-calculated large powers.
+    /// Pre-calculated large powers, in increments of `N^step`.
     pub large: ExtendedFloatArray<M>,
-    /// Pre-calculated small powers as 64-bit integers
+    /// Pre-calculated small powers as integers, for the exact path.
     pub small_int: &'static [M],
-    // Step between large powers and number of small powers.
+    /// Step between large powers and number of small powers.
     pub step: i32,
-    // Exponent bias for the large powers.
+    /// Exponent bias for the large powers.
     pub bias: i32,
 }
 
-/// Allow indexing of values without bounds checking
 impl<M: Mantissa> ModeratePathPowers<M> {
+    /// Get the small power `N^index`.
     #[inline]
     pub fn get_small(&self, index: usize) -> ExtendedFloat<M> {
         self.small.get_extended_float(index)
     }
 
+    /// Get the large power `N^(index*step - bias)`.
     #[inline]
     pub fn get_large(&self, index: usize) -> ExtendedFloat<M> {
         self.large.get_extended_float(index)
     }
 
+    /// Get the small power `N^index` as an integer.
     #[inline]
     pub fn get_small_int(&self, index: usize) -> M {
         self.small_int[index]
@@ -86,8 +103,16 @@ impl<M: Mantissa> ModeratePathPowers<M> {
 // ----------------------
 
 /// Cached powers as a trait for a floating-point type.
-pub(crate) trait ModeratePathCache<M: Mantissa> {
+///
+/// `ExtendedFloat<u64>` implements this with the cached powers used to
+/// parse `f32` and `f64`. Extended-precision float types may implement it
+/// with their own tables, for example, as generated by the `codegen`
+/// feature.
+pub trait ModeratePathCache<M: Mantissa> {
     /// Get powers from radix.
+    ///
+    /// The radix must have cached powers: 10, or, with the `radix`
+    /// feature, any radix from 3 to 36 that is not a power of 2.
     fn get_powers(radix: u32) -> &'static ModeratePathPowers<M>;
 }
 
@@ -105,3 +130,61 @@ impl ModeratePathCache<u128> for ExtendedFloat<u128> {
         float160::get_powers(radix)
     }
 }
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Only a single power, to check custom implementations.
+    const BASE10_SMALL_MANTISSA: [u64; 1] = [9223372036854775808];
+    const BASE10_SMALL_EXPONENT: [i32; 1] = [-63];
+    const BASE10_SMALL_INT_POWERS: [u64; 1] = [1];
+    const BASE10_POWERS: ModeratePathPowers<u64> = ModeratePathPowers {
+        small: ExtendedFloatArray {
+            mant: &BASE10_SMALL_MANTISSA,
+            exp: &BASE10_SMALL_EXPONENT,
+        },
+        large: ExtendedFloatArray {
+            mant: &BASE10_SMALL_MANTISSA,
+            exp: &BASE10_SMALL_EXPONENT,
+        },
+        small_int: &BASE10_SMALL_INT_POWERS,
+        step: 1,
+        bias: 0,
+    };
+
+    struct Custom;
+
+    impl ModeratePathCache<u64> for Custom {
+        fn get_powers(_: u32) -> &'static ModeratePathPowers<u64> {
+            &BASE10_POWERS
+        }
+    }
+
+    fn power<C: ModeratePathCache<u64>>(exponent: i32) -> f64 {
+        let powers = C::get_powers(10);
+        let biased = exponent + powers.bias;
+        let small = powers.get_small((biased % powers.step) as usize);
+        let large = powers.get_large((biased / powers.step) as usize);
+        small.mul(&large).into_f64()
+    }
+
+    #[test]
+    fn get_powers_test() {
+        assert_eq!(power::<ExtendedFloat<u64>>(0), 1.0);
+        assert_eq!(power::<ExtendedFloat<u64>>(1), 10.0);
+        assert_eq!(power::<ExtendedFloat<u64>>(22), 1e22);
+        assert_eq!(power::<ExtendedFloat<u64>>(-5), 1e-5);
+        assert_eq!(power::<ExtendedFloat<u64>>(300), 1e300);
+        assert_eq!(power::<Custom>(0), 1.0);
+
+        let powers = <ExtendedFloat<u64>>::get_powers(10);
+        assert_eq!(powers.small.len() as i32, powers.step);
+        assert_eq!(powers.small_int.len() as i32, powers.step);
+        assert!(!powers.large.is_empty());
+        assert_eq!(powers.get_small_int(2), 100);
+    }
+}
@@ -12,6 +12,8 @@ use crate::util::*;
 
 use super::alias::*;
 use super::bhcomp;
+use super::bigcomp;
+use super::bignum::BigintScratch;
 use super::cached::ModeratePathCache;
 use super::format::*;
 use super::incorrect as incorrect_algorithm;
@@ -278,6 +280,9 @@ where
 
 /// Fallback method. Do not inline so the stack requirements only occur
 /// if required.
+///
+/// Uses the scratch space for the big integers of the slow path, if
+/// provided, otherwise, they are created on the stack.
 fn pown_fallback<'a, F, M, Data>(
     data: Data,
     mantissa: M,
@@ -285,6 +290,7 @@ fn pown_fallback<'a, F, M, Data>(
     lossy: bool,
     sign: Sign,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
 ) -> F
 where
     M: MantissaType,
@@ -303,18 +309,47 @@ where
         return float;
     }
 
-    // Slow path
-    let b = fp.into_rounded_float_impl::<F>(RoundingKind::Downward);
+    // Slow path. Round-nearest compares the digits to `b+h`, so `b`
+    // is rounded down, while directed rounding compares the digits to
+    // `b`, so `b` is the float nearest the extended-precision float.
+    let b_kind = match kind.is_nearest() {
+        true => RoundingKind::Downward,
+        false => RoundingKind::NearestTieEven,
+    };
+    let b = fp.into_rounded_float_impl::<F>(b_kind);
     if b.is_special() {
         // We have a non-finite number, we get to leave early.
         return b;
+    } else if cfg!(feature = "bigcomp") {
+        // Compare the digits to `b+h`, which uses less memory.
+        bigcomp::atof(data, radix, b, kind)
+    } else if let Some(scratch) = scratch {
+        bhcomp::atof(data, radix, b, kind, scratch)
     } else {
-        let float = bhcomp::atof(data, radix, b, kind);
-        return float;
+        bhcomp::atof(data, radix, b, kind, &mut BigintScratch::default())
+    }
+}
+
+/// Convert extracted components with the incorrect algorithm, if enabled.
+///
+/// Denormal results return `None`, so they are parsed with the
+/// moderate path instead.
+#[inline(always)]
+fn incorrect_to_native<'a, F, Data>(data: &Data, radix: u32, incorrect: bool) -> Option<F>
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    // The native arithmetic may round twice on x87, so portable builds
+    // always parse correctly.
+    match incorrect && !cfg!(feature = "portable") {
+        true => incorrect_algorithm::to_native::<F, _>(data, radix),
+        false => None,
     }
 }
 
 /// Parse non-power-of-two radix string to native float.
+#[inline(always)]
 fn pown_to_native<'a, F, Data>(
     mut data: Data,
     bytes: &'a [u8],
@@ -323,46 +358,74 @@ fn pown_to_native<'a, F, Data>(
     lossy: bool,
     sign: Sign,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
 ) -> ParseResult<(F, *const u8)>
 where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
     Data: FastDataInterface<'a>,
 {
-    // Parse the mantissa and exponent.
     let ptr = data.extract(bytes, radix)?;
+    let float = pown_data_to_native(data, radix, incorrect, lossy, sign, rounding, scratch);
+    Ok((float, ptr))
+}
+
+/// Convert extracted non-power-of-two radix components to native float.
+fn pown_data_to_native<'a, F, Data>(
+    data: Data,
+    radix: u32,
+    incorrect: bool,
+    lossy: bool,
+    sign: Sign,
+    rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
+) -> F
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+    Data: FastDataInterface<'a>,
+{
+    // Parse the mantissa.
     let (mantissa, truncated) = process_mantissa::<F::MantissaType, _>(&data, radix);
 
     // Process the state to a float.
-    let float = if mantissa.is_zero() {
+    if mantissa.is_zero() {
         // Literal 0, return early.
         // Value cannot be truncated, since truncation only occurs on
         // overflow or underflow.
         F::ZERO
     } else if truncated.is_zero() {
-        // Try the fast path, no mantissa truncation.
+        // Try the fast path, no mantissa truncation. The native
+        // multiplication and division round to nearest, tie-even,
+        // so directed rounding must use the moderate or slow path. The
+        // x87 may round twice, so portable builds also skip it.
         let mant_exp = data.mantissa_exponent(0);
-        if let Some(float) = fast_path::<F, _>(mantissa, radix, mant_exp) {
+        let kind = internal_rounding(rounding, sign);
+        let fast = match kind == RoundingKind::NearestTieEven && !cfg!(feature = "portable") {
+            true => fast_path::<F, _>(mantissa, radix, mant_exp),
+            false => None,
+        };
+        if let Some(float) = fast {
+            float
+        } else if let Some(float) = incorrect_to_native::<F, _>(&data, radix, incorrect) {
             float
-        } else if incorrect {
-            incorrect_algorithm::to_native::<F, _>(data, radix)
         } else {
             let slow = data.to_slow(truncated);
-            pown_fallback(slow, mantissa, radix, lossy, sign, rounding)
+            pown_fallback(slow, mantissa, radix, lossy, sign, rounding, scratch)
         }
-    } else if incorrect {
-        incorrect_algorithm::to_native::<F, _>(data, radix)
+    } else if let Some(float) = incorrect_to_native::<F, _>(&data, radix, incorrect) {
+        float
     } else {
         // Can only use the moderate/slow path.
         let slow = data.to_slow(truncated);
-        pown_fallback(slow, mantissa, radix, lossy, sign, rounding)
-    };
-    Ok((float, ptr))
+        pown_fallback(slow, mantissa, radix, lossy, sign, rounding, scratch)
+    }
 }
 
 // POW2
 
 /// Parse power-of-two radix string to native float.
+#[inline(always)]
 #[cfg(feature = "power_of_two")]
 fn pow2_to_native<'a, F, Data>(
     mut data: Data,
@@ -376,39 +439,55 @@ where
     F: FloatType,
     Data: FastDataInterface<'a>,
 {
-    // Parse the mantissa and exponent.
     let ptr = data.extract(bytes, radix)?;
+    let float = pow2_data_to_native(data, radix, radix_log2, sign, rounding);
+    Ok((float, ptr))
+}
+
+/// Convert extracted power-of-two radix components to native float.
+#[cfg(feature = "power_of_two")]
+fn pow2_data_to_native<'a, F, Data>(
+    data: Data,
+    radix: u32,
+    radix_log2: i32,
+    sign: Sign,
+    rounding: RoundingKind,
+) -> F
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    // Parse the mantissa.
     let (mut mantissa, truncated) = process_mantissa::<F::MantissaType, _>(&data, radix);
 
     // We have a power of 2, can get an exact value even if the mantissa
     // was truncated. Check to see if there are any truncated digits, depending
     // on our rounding scheme.
     let mantissa_size = F::MANTISSA_SIZE + 1;
-    let float = if !truncated.is_zero() {
+    if !truncated.is_zero() {
         // Truncated mantissa.
         let kind = internal_rounding(rounding, sign);
         let slow = data.to_slow(truncated);
         if kind != RoundingKind::Downward {
-            if cfg!(feature = "rounding") || kind == RoundingKind::NearestTieEven {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F, _>(mantissa) && is_odd::<F, _>(mantissa) {
-                    mantissa += F::MantissaType::ONE;
-                }
-            } else if kind == RoundingKind::NearestTieAwayZero {
-                // Need to check if we're exactly halfway and if there are truncated digits.
-                if is_halfway::<F, _>(mantissa) {
-                    mantissa += F::MantissaType::ONE;
-                }
-            } else {
-                // Need to check if there are any bytes present.
-                // Check if there were any truncated bytes.
-                let index = slow.mantissa_digits() - slow.truncated_digits();
-                let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
-                let count = iter.take_while(|&&c| c == b'0').count();
-                let is_truncated = count < slow.truncated_digits();
-                if is_truncated {
-                    mantissa += F::MantissaType::ONE;
-                }
+            // Need to check if there are any bytes present.
+            // Check if there were any truncated bytes.
+            let index = slow.mantissa_digits() - slow.truncated_digits();
+            let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
+            let count = iter.take_while(|&&c| c == b'0').count();
+            let is_truncated = count < slow.truncated_digits();
+            let round_up = match kind {
+                // Exactly halfway, round-up if odd, or if the truncated
+                // digits are above halfway.
+                RoundingKind::NearestTieEven => {
+                    is_halfway::<F, _>(mantissa) && (is_odd::<F, _>(mantissa) || is_truncated)
+                },
+                // Exactly halfway or above, always round-up.
+                RoundingKind::NearestTieAwayZero => is_halfway::<F, _>(mantissa),
+                // Round-up if there are any truncated digits.
+                _ => is_truncated,
+            };
+            if round_up {
+                mantissa += F::MantissaType::ONE;
             }
         }
 
@@ -433,8 +512,7 @@ where
         // Nothing above the hidden bit, so no rounding-error, can use the fast path.
         let mant_exp = data.mantissa_exponent(0);
         pow2_fast_path(mantissa, radix, radix_log2, mant_exp)
-    };
-    Ok((float, ptr))
+    }
 }
 
 // DISPATCHER
@@ -451,6 +529,7 @@ pub(crate) fn to_native<'a, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
 ) -> ParseResult<(F, *const u8)>
 where
     F: FloatType,
@@ -459,19 +538,52 @@ where
 {
     #[cfg(not(feature = "power_of_two"))]
     {
-        pown_to_native(data, bytes, radix, incorrect, lossy, sign, rounding)
+        pown_to_native(data, bytes, radix, incorrect, lossy, sign, rounding, scratch)
     }
 
     #[cfg(feature = "power_of_two")]
     {
         let pow2_exp = log2(radix);
         match pow2_exp {
-            0 => pown_to_native(data, bytes, radix, incorrect, lossy, sign, rounding),
+            0 => pown_to_native(data, bytes, radix, incorrect, lossy, sign, rounding, scratch),
             _ => pow2_to_native(data, bytes, radix, pow2_exp, sign, rounding),
         }
     }
 }
 
+/// Convert pre-extracted float components to native float.
+///
+/// The components must already be validated and trimmed.
+#[inline(always)]
+pub(crate) fn data_to_native<'a, F, Data>(
+    data: Data,
+    sign: Sign,
+    radix: u32,
+    incorrect: bool,
+    lossy: bool,
+    rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
+) -> F
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+    Data: FastDataInterface<'a>,
+{
+    #[cfg(not(feature = "power_of_two"))]
+    {
+        pown_data_to_native(data, radix, incorrect, lossy, sign, rounding, scratch)
+    }
+
+    #[cfg(feature = "power_of_two")]
+    {
+        let pow2_exp = log2(radix);
+        match pow2_exp {
+            0 => pown_data_to_native(data, radix, incorrect, lossy, sign, rounding, scratch),
+            _ => pow2_data_to_native(data, radix, pow2_exp, sign, rounding),
+        }
+    }
+}
+
 // TESTS
 // -----
 
@@ -923,11 +1035,16 @@ mod tests {
         // Adapted from failures in strtod.
         assert_eq!(Ok((2.2250738585072014e-308, 23)), atod10(b"2.2250738585072014e-308"));
         assert_eq!(Ok((2.225073858507201e-308, 776)), atod10(b"2.2250738585072011360574097967091319759348195463516456480234261097248222220210769455165295239081350879141491589130396211068700864386945946455276572074078206217433799881410632673292535522868813721490129811224514518898490572223072852551331557550159143974763979834118019993239625482890171070818506906306666559949382757725720157630626906633326475653000092458883164330377797918696120494973903778297049050510806099407302629371289589500035837999672072543043602840788957717961509455167482434710307026091446215722898802581825451803257070188608721131280795122334262883686223215037756666225039825343359745688844239002654981983854879482922068947216898310996983658468140228542433306603398508864458040010349339704275671864433837704860378616227717385456230658746790140867233276367187499e-308"));
+        // Digits past `BOUNDED_DIGITS` are not exact with bounded bignums.
+        #[cfg(not(feature = "bounded_bignum"))]
         assert_eq!(Ok((2.2250738585072014e-308, 774)), atod10(b"2.22507385850720113605740979670913197593481954635164564802342610972482222202107694551652952390813508791414915891303962110687008643869459464552765720740782062174337998814106326732925355228688137214901298112245145188984905722230728525513315575501591439747639798341180199932396254828901710708185069063066665599493827577257201576306269066333264756530000924588831643303777979186961204949739037782970490505108060994073026293712895895000358379996720725430436028407889577179615094551674824347103070260914462157228988025818254518032570701886087211312807951223342628836862232150377566662250398253433597456888442390026549819838548794829220689472168983109969836584681402285424333066033985088644580400103493397042756718644338377048603786162277173854562306587467901408672332763671875e-308"));
+        #[cfg(not(feature = "bounded_bignum"))]
         assert_eq!(Ok((2.2250738585072014e-308, 776)), atod10(b"2.2250738585072011360574097967091319759348195463516456480234261097248222220210769455165295239081350879141491589130396211068700864386945946455276572074078206217433799881410632673292535522868813721490129811224514518898490572223072852551331557550159143974763979834118019993239625482890171070818506906306666559949382757725720157630626906633326475653000092458883164330377797918696120494973903778297049050510806099407302629371289589500035837999672072543043602840788957717961509455167482434710307026091446215722898802581825451803257070188608721131280795122334262883686223215037756666225039825343359745688844239002654981983854879482922068947216898310996983658468140228542433306603398508864458040010349339704275671864433837704860378616227717385456230658746790140867233276367187501e-308"));
         assert_eq!(Ok((1.7976931348623157e+308, 380)), atod10(b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791.9999999999999999999999999999999999999999999999999999999999999999999999"));
         assert_eq!(Ok((5e-324, 761)), atod10(b"7.4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984374999e-324"));
+        #[cfg(not(feature = "bounded_bignum"))]
         assert_eq!(Ok((1e-323, 758)), atod10(b"7.4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375e-324"));
+        #[cfg(not(feature = "bounded_bignum"))]
         assert_eq!(Ok((1e-323, 761)), atod10(b"7.4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375001e-324"));
 
         // Rounding error
@@ -970,6 +1087,12 @@ mod tests {
             atod10(b"11417981541647682851418088440284165581171589120.0")
         );
 
+        // Long mantissas, with digits past the last digit of `b+h`.
+        assert_eq!(Ok((1.0000000000000002, 456)), atod10(b"1.0000000000000001110223024625156540423631668090820312500000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"));
+        assert_eq!(Ok((1.0, 455)), atod10(b"1.000000000000000111022302462515654042363166809082031249999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999"));
+        assert_eq!(Ok((9007199254740994.0, 418)), atod10(b"9007199254740993.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"));
+        assert_eq!(Ok((9007199254740992.0, 417)), atod10(b"9007199254740993.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"));
+
         // Check other cases ostensibly identified via proptest.
         assert_eq!(Ok((71610528364411830000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0, 310)), atod10(b"71610528364411830000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0"));
         assert_eq!(Ok((126769393745745060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0, 311)), atod10(b"126769393745745060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0"));
@@ -1015,6 +1138,44 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "bounded_bignum")]
+    fn atod_bounded_test() {
+        let atod10 = move |x: &[u8]| f64::from_lexical(x).unwrap();
+        let ulps = |x: f64, y: f64| (x.to_bits() as i64 - y.to_bits() as i64).abs();
+        let long = |prefix: &[u8], fill: u8, suffix: &[u8]| {
+            let mut digits = prefix.to_vec();
+            digits.extend_from_slice(&[fill; 700]);
+            digits.extend_from_slice(suffix);
+            digits
+        };
+
+        // Halfway cases decided within `BOUNDED_DIGITS` are exact.
+        assert_eq!(9007199254740992.0, atod10(b"9007199254740993"));
+        assert_eq!(9007199254740996.0, atod10(b"9007199254740995"));
+        assert_eq!(9007199254740994.0, atod10(b"9007199254740993.000000000000000000000000000001"));
+
+        // Halfway cases decided by later digits are within 1 ULP.
+        let digits = long(
+            b"2.2250738585072011360574097967091319759348195463516456480234261097248",
+            b'0',
+            b"1e-308",
+        );
+        assert!(ulps(2.2250738585072014e-308, atod10(&digits)) <= 1);
+        let digits = long(
+            b"7.4109846876186981626485318930233205854758970392148714663837852375",
+            b'0',
+            b"1e-324",
+        );
+        assert!(ulps(1e-323, atod10(&digits)) <= 1);
+        let digits = long(
+            b"179769313486231580793728971405303415079934132710037826936173778980443.",
+            b'9',
+            b"e+240",
+        );
+        assert_eq!(1.7976931348623157e+308, atod10(&digits));
+    }
+
     #[test]
     fn atof_lossy_test() {
         let options = ParseFloatOptions::builder().lossy(true).build().unwrap();
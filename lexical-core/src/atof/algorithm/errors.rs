@@ -76,20 +76,20 @@ where
             let cmp2 = extra.checked_add(errors).is_none();
             // If either comparison is true, we have significant rounding error,
             // since we cannot distinguish the value (1 << 64).
-            cmp1 || cmp2
+            !(cmp1 || cmp2)
         } else {
             // Round toward something, need to check if we're close to
-            // IE, b10101 | 000000, where `|` signifies the truncation point.
-            // If the extract bits +/- the error can overflow, then  we have
-            // an issue.
+            // IE, b10101 | 000000, where `|` signifies the truncation point,
+            // from either side. If the extract bits +/- the error can
+            // cross the truncation point, then we have an issue.
             let fullway: M = nth_bit(maskbits);
             let cmp1 = fullway.wrapping_sub(errors) < extra;
-            let cmp2 = extra < fullway.wrapping_add(errors);
+            let cmp2 = extra < errors;
 
-            // If both comparisons are true, we have significant rounding error,
+            // If either comparison is true, we have significant rounding error,
             // and the value cannot be exactly represented. Otherwise, the
             // representation is valid.
-            !(cmp1 && cmp2)
+            !(cmp1 || cmp2)
         }
     }
 }
@@ -0,0 +1,154 @@
+//! Check if a float exactly represents the parsed digits.
+//!
+//! Compares the significant digits of the mantissa to the digits of
+//! the parsed float `b`, scaled to the same exponent, like `bhcomp`
+//! compares them to `b+h`.
+
+use crate::lib::cmp;
+use crate::traits::*;
+use crate::util::*;
+
+use super::alias::*;
+use super::bigcomp;
+use super::bignum::*;
+use super::format::*;
+use super::math::*;
+
+/// Parse the first `count` significant digits into a big integer.
+fn parse_digits<'a, F, Data>(data: &Data, radix: u32, count: usize) -> Bigint<F>
+where
+    F: FloatType,
+    Data: SlowDataInterface<'a>,
+{
+    let mut result = Bigint::<F>::default();
+    let iter = data.integer_iter().chain(data.significant_fraction_iter());
+    for &c in iter.take(count) {
+        result.imul_small(as_limb(radix));
+        result.iadd_small(as_limb(to_digit(c, radix).unwrap()));
+    }
+    result
+}
+
+/// Check if the number of digits can be exactly representable.
+///
+/// Filters out digits that cannot be exact before creating big integers,
+/// which also bounds the size of the big integers.
+fn can_be_exact<F>(radix: u32, pow2_exp: i32, real_exp: i32, count: usize) -> bool
+where
+    F: FloatType,
+{
+    if pow2_exp != 0 {
+        // Exact floats have at most `MANTISSA_SIZE + 1` significant bits,
+        // which can span 2 more digits than they fill.
+        count <= (F::MANTISSA_SIZE as usize + 1) / pow2_exp as usize + 2
+    } else if real_exp >= 0 {
+        // Integral values are bounded by the finite float.
+        true
+    } else {
+        // The last digit is non-zero, so fractions in odd radixes, such
+        // as `1/3`, never have finite binary representations. Exact floats
+        // have fewer digits than the halfway point between floats.
+        match F::max_correct_digits(radix) {
+            Some(max_digits) => radix.is_even() && count <= max_digits,
+            None => false,
+        }
+    }
+}
+
+/// Count the significant digits, up to the last non-zero digit.
+///
+/// Integer digits may have trailing zeros, which are not significant.
+pub(crate) fn significant_count<'a, Data>(data: &Data) -> usize
+where
+    Data: SlowDataInterface<'a>,
+{
+    let iter = data.integer_iter().chain(data.significant_fraction_iter());
+    iter.enumerate().filter(|&(_, &c)| c != b'0').last().map_or(0, |(i, _)| i + 1)
+}
+
+/// Compare the first `count` significant digits to a non-zero float.
+///
+/// The digits are `real_digits * radix^real_exp`, and the float is
+/// `theor_digits * 2^theor_exp`. Scale the digits to the same exponent,
+/// where the binary exponent is the power of 2 to multiply
+/// `theor_digits` by.
+fn compare_digits<'a, F, Data>(
+    data: &Data,
+    radix: u32,
+    f: F,
+    count: usize,
+    real_exp: i32,
+) -> cmp::Ordering
+where
+    F: FloatType,
+    Data: SlowDataInterface<'a>,
+{
+    let pow2_exp = log2(radix);
+    let theor = bigcomp::b(f);
+    let mut real_digits = parse_digits::<F, Data>(data, radix, count);
+    let mut theor_digits = Bigint::<F>::from_mant(theor.mant());
+    let binary_exp = if pow2_exp != 0 {
+        theor.exp() - real_exp * pow2_exp
+    } else if real_exp >= 0 {
+        real_digits.imul_power(radix, real_exp.as_u32());
+        theor.exp()
+    } else if radix.is_even() {
+        // Factor out the powers of 2 from the radix, like `bhcomp::small_atof`.
+        theor_digits.imul_power(radix / 2, (-real_exp).as_u32());
+        theor.exp() - real_exp
+    } else {
+        theor_digits.imul_power(radix, (-real_exp).as_u32());
+        theor.exp()
+    };
+    if binary_exp > 0 {
+        theor_digits.imul_power(2, binary_exp.as_u32());
+    } else if binary_exp < 0 {
+        real_digits.imul_power(2, (-binary_exp).as_u32());
+    }
+
+    real_digits.compare(&theor_digits)
+}
+
+/// Check if the float is exactly equal to the digits.
+///
+/// The float must be the positive value parsed from the digits. Floats
+/// clamped to infinity or zero are never exact, unless the digits are zero.
+pub(crate) fn is_exact<'a, F, Data>(data: Data, radix: u32, f: F) -> bool
+where
+    F: FloatType,
+    Data: SlowDataInterface<'a>,
+{
+    let count = significant_count(&data);
+    if f.is_special() {
+        return false;
+    } else if count == 0 || f.is_zero() {
+        return count == 0 && f.is_zero();
+    }
+
+    let real_exp = data.scientific_exponent() + 1 - count.as_i32();
+    if !can_be_exact::<F>(radix, log2(radix), real_exp, count) {
+        return false;
+    }
+    compare_digits(&data, radix, f, count, real_exp) == cmp::Ordering::Equal
+}
+
+/// Compare the digits to a positive, finite float.
+///
+/// The digits must have at most `max_correct_digits` significant digits,
+/// like the slow path, so the big integers cannot overflow.
+pub(crate) fn compare_exact<'a, F, Data>(data: Data, radix: u32, f: F) -> cmp::Ordering
+where
+    F: FloatType,
+    Data: SlowDataInterface<'a>,
+{
+    let count = significant_count(&data);
+    match (count == 0, f.is_zero()) {
+        (true, true) => cmp::Ordering::Equal,
+        (true, false) => cmp::Ordering::Less,
+        (false, true) => cmp::Ordering::Greater,
+        (false, false) => {
+            let real_exp = data.scientific_exponent() + 1 - count.as_i32();
+            compare_digits(&data, radix, f, count, real_exp)
+        },
+    }
+}
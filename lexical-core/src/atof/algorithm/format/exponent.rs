@@ -1,6 +1,7 @@
 //! Utilities to parse, extract, and interpret exponent components.
 
 use crate::atoi;
+#[cfg(not(feature = "safe"))]
 use crate::lib::slice;
 use crate::traits::*;
 use crate::util::*;
@@ -49,6 +50,28 @@ pub(super) fn mantissa_exponent(
     }
 }
 
+// TIMES TEN EXPONENT
+
+// Typographic exponent introducers, as in `1.5×10^3`.
+const TIMES_TEN_INTRODUCERS: [&'static [u8]; 3] = [b"\xC3\x9710^", b"x10^", b"X10^"];
+
+// Get the length of a typographic exponent introducer, like `×10^`,
+// at the start of the bytes, if the format allows them.
+#[inline(always)]
+pub(super) fn times_ten_introducer(
+    format: NumberFormat,
+    bytes: &[u8],
+    radix: u32,
+) -> Option<usize> {
+    if !format.times_ten_exponent() || radix != 10 {
+        return None;
+    }
+    TIMES_TEN_INTRODUCERS
+        .iter()
+        .find(|introducer| bytes.starts_with(introducer))
+        .map(|introducer| introducer.len())
+}
+
 // EXPONENT EXTRACTORS
 
 // Extract exponent substring and parse exponent.
@@ -74,6 +97,7 @@ where
     let (raw_exponent, ptr) = atoi::standalone_exponent(iter, radix, sign);
     data.set_raw_exponent(raw_exponent);
 
+    #[cfg(not(feature = "safe"))]
     unsafe {
         // Extract the exponent subslice.
         let first = bytes.as_ptr();
@@ -83,6 +107,14 @@ where
         let last = bytes[bytes.len()..].as_ptr();
         slice::from_raw_parts(ptr, distance(ptr, last))
     }
+
+    #[cfg(feature = "safe")]
+    {
+        // Split at the index of the pointer, which is bounds-checked.
+        let (exponent, rest) = bytes.split_at(distance(bytes.as_ptr(), ptr));
+        data.set_exponent(Some(exponent));
+        rest
+    }
 }
 
 // Parse exponent.
@@ -102,7 +134,7 @@ fn parse_exponent<'a, Data>(
     Data: FastDataInterface<'a>,
 {
     // Get an iterator over our digits and sign bits, and parse the exponent.
-    let iter = iterate_digits_ignore_separator(leading, digit_separator);
+    let iter = SeparatorDigits::new(leading, digit_separator);
 
     // Parse the exponent and store the extracted digits.
     let bytes_len = bytes.len() - trailing.len();
@@ -133,14 +165,13 @@ where
     // Remove leading exponent character and parse exponent.
     let bytes = &bytes[1..];
     let (sign, digits) = parse_sign_no_separator::<FloatType>(bytes, digit_separator);
-    let iter = iterate_digits_no_separator(digits, digit_separator);
+    let iter = ContiguousDigits::new(digits, digit_separator);
     extract_and_parse_exponent(data, iter, bytes, radix, sign)
 }
 
 // Extract exponent substring and parse exponent.
 // Consumes leading, internal, trailing, and consecutive digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 fn extract_exponent_iltc<'a, Data>(
     data: &mut Data,
     bytes: &'a [u8],
@@ -154,7 +185,7 @@ where
     // We're not calling `consumed()`, so it's fine to have trailing underscores.
     let bytes = &bytes[1..];
     let (sign, digits) = parse_sign_lc_separator::<FloatType>(bytes, digit_separator);
-    let iter = iterate_digits_ignore_separator(digits, digit_separator);
+    let iter = SeparatorDigits::new(digits, digit_separator);
     extract_and_parse_exponent(data, iter, bytes, radix, sign)
 }
 
@@ -287,7 +318,6 @@ where
 
 // Extract exponent while ignoring the digit separator.
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(crate) fn extract_exponent_ignore_separator<'a, Data>(
     data: &mut Data,
     bytes: &'a [u8],
@@ -399,6 +429,20 @@ mod test {
         assert_eq!(data.raw_exponent(), 0);
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn times_ten_introducer_test() {
+        let format = NumberFormat::STANDARD | NumberFormat::TIMES_TEN_EXPONENT;
+        assert_eq!(times_ten_introducer(format, "×10^3".as_bytes(), 10), Some(5));
+        assert_eq!(times_ten_introducer(format, b"x10^-3", 10), Some(4));
+        assert_eq!(times_ten_introducer(format, b"X10^", 10), Some(4));
+        assert_eq!(times_ten_introducer(format, b"x10", 10), None);
+        assert_eq!(times_ten_introducer(format, b"x11^3", 10), None);
+        assert_eq!(times_ten_introducer(format, b"e3", 10), None);
+        assert_eq!(times_ten_introducer(format, b"x10^3", 16), None);
+        assert_eq!(times_ten_introducer(NumberFormat::STANDARD, b"x10^3", 10), None);
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn extract_exponent_iltc_test() {
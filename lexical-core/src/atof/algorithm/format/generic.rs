@@ -19,9 +19,9 @@ macro_rules! generic_data_interface {
     (
         struct $fast:ident,
         struct $slow:ident,
-        integer_iter => ( $integer_iter:tt, $integer_iter_fn:ident ),
-        fraction_iter => ( $fraction_iter:tt, $fraction_iter_fn:ident ),
-        exponent_iter => ( $exponent_iter:tt, $exponent_iter_fn:ident ),
+        integer_iter => $integer_iter:ident,
+        fraction_iter => $fraction_iter:ident,
+        exponent_iter => $exponent_iter:ident,
         consume_integer_digits => $consume_integer_digits:expr,
         consume_fraction_digits => $consume_fraction_digits:expr,
         extract_exponent => $extract_exponent:expr,
@@ -36,9 +36,9 @@ macro_rules! generic_data_interface {
             fields => {
                 format: NumberFormat,
             },
-            integer_iter => ($integer_iter, $integer_iter_fn),
-            fraction_iter => ($fraction_iter, $fraction_iter_fn),
-            exponent_iter => ($exponent_iter, $exponent_iter_fn),
+            integer_iter => $integer_iter,
+            fraction_iter => $fraction_iter,
+            exponent_iter => $exponent_iter,
             format => |this: &Self| this.format,
             consume_integer_digits => $consume_integer_digits,
             consume_fraction_digits => $consume_fraction_digits,
@@ -68,9 +68,9 @@ macro_rules! generic_data_interface {
 generic_data_interface!(
     struct GenericFastDataInterface,
     struct GenericSlowDataInterface,
-    integer_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    integer_iter => ContiguousDigits,
+    fraction_iter => ContiguousDigits,
+    exponent_iter => ContiguousDigits,
     consume_integer_digits => consume_digits_no_separator,
     consume_fraction_digits => consume_digits_no_separator,
     extract_exponent => extract_exponent_no_separator,
@@ -84,9 +84,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericIFastDataInterface,
     struct GenericISlowDataInterface,
-    integer_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    integer_iter => SeparatorDigits,
+    fraction_iter => ContiguousDigits,
+    exponent_iter => ContiguousDigits,
     consume_integer_digits => consume_integer_digits_separator,
     consume_fraction_digits => consume_digits_no_separator,
     extract_exponent => extract_exponent_no_separator,
@@ -100,9 +100,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericFFastDataInterface,
     struct GenericFSlowDataInterface,
-    integer_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    integer_iter => ContiguousDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => ContiguousDigits,
     consume_integer_digits => consume_digits_no_separator,
     consume_fraction_digits => consume_fraction_digits_separator,
     extract_exponent => extract_exponent_no_separator,
@@ -116,9 +116,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericEFastDataInterface,
     struct GenericESlowDataInterface,
-    integer_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    integer_iter => ContiguousDigits,
+    fraction_iter => ContiguousDigits,
+    exponent_iter => SeparatorDigits,
     consume_integer_digits => consume_digits_no_separator,
     consume_fraction_digits => consume_digits_no_separator,
     extract_exponent => extract_exponent_separator,
@@ -132,9 +132,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericIFFastDataInterface,
     struct GenericIFSlowDataInterface,
-    integer_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    integer_iter => SeparatorDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => ContiguousDigits,
     consume_integer_digits => consume_integer_digits_separator,
     consume_fraction_digits => consume_fraction_digits_separator,
     extract_exponent => extract_exponent_no_separator,
@@ -148,9 +148,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericIEFastDataInterface,
     struct GenericIESlowDataInterface,
-    integer_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    integer_iter => SeparatorDigits,
+    fraction_iter => ContiguousDigits,
+    exponent_iter => SeparatorDigits,
     consume_integer_digits => consume_integer_digits_separator,
     consume_fraction_digits => consume_digits_no_separator,
     extract_exponent => extract_exponent_separator,
@@ -164,9 +164,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericFEFastDataInterface,
     struct GenericFESlowDataInterface,
-    integer_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    integer_iter => ContiguousDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => SeparatorDigits,
     consume_integer_digits => consume_digits_no_separator,
     consume_fraction_digits => consume_fraction_digits_separator,
     extract_exponent => extract_exponent_separator,
@@ -180,9 +180,9 @@ generic_data_interface!(
 generic_data_interface!(
     struct GenericIFEFastDataInterface,
     struct GenericIFESlowDataInterface,
-    integer_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    integer_iter => SeparatorDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => SeparatorDigits,
     consume_integer_digits => consume_integer_digits_separator,
     consume_fraction_digits => consume_fraction_digits_separator,
     extract_exponent => extract_exponent_separator,
@@ -20,9 +20,9 @@ data_interface!(
     fields => {
         format: NumberFormat,
     },
-    integer_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    fraction_iter => (IteratorSeparator, iterate_digits_ignore_separator),
-    exponent_iter => (IteratorSeparator, iterate_digits_ignore_separator),
+    integer_iter => SeparatorDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => SeparatorDigits,
     format => |this: &Self| this.format,
     consume_integer_digits => consume_digits_ignore_separator,
     consume_fraction_digits => consume_digits_ignore_separator,
@@ -12,7 +12,11 @@ macro_rules! apply_standard_interface {
 #[cfg(not(feature = "format"))]
 macro_rules! apply_interface {
     ($fn:expr, $format:expr $(,$args:expr)*) => {
-        apply_standard_interface!($fn, $format $(, $args)*)
+        // Only a runtime digit separator may be set without the format feature.
+        match $format.digit_separator() {
+            0 => apply_standard_interface!($fn, $format $(, $args)*),
+            _ => $fn(StandardSeparatorFastDataInterface::new($format) $(,$args)*),
+        }
     };
 }
 
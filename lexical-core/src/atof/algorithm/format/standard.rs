@@ -23,9 +23,9 @@ data_interface!(
     fields => {
         format: NumberFormat,
     },
-    integer_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    fraction_iter => (IteratorNoSeparator, iterate_digits_no_separator),
-    exponent_iter => (IteratorNoSeparator, iterate_digits_no_separator),
+    integer_iter => ContiguousDigits,
+    fraction_iter => ContiguousDigits,
+    exponent_iter => ContiguousDigits,
     format => |this: &Self| this.format,
     consume_integer_digits => consume_digits_no_separator,
     consume_fraction_digits =>  consume_digits_no_separator,
@@ -49,6 +49,48 @@ data_interface!(
     }
 );
 
+// Standard data interface for fast float parsers with a digit separator.
+//
+// Parses `NumberFormat::standard()`, but skips the digit separator
+// anywhere in the integer, fraction, and exponent digits. This is used
+// for the runtime `digit_separator` option, and therefore does not
+// require the `format` feature.
+//
+// The requirements:
+//     1). Must contain significant digits.
+//     2). Must contain exponent digits if an exponent is present.
+data_interface!(
+    struct StandardSeparatorFastDataInterface,
+    struct StandardSeparatorSlowDataInterface,
+    fields => {
+        format: NumberFormat,
+    },
+    integer_iter => SeparatorDigits,
+    fraction_iter => SeparatorDigits,
+    exponent_iter => SeparatorDigits,
+    format => |this: &Self| this.format,
+    consume_integer_digits => consume_digits_ignore_separator,
+    consume_fraction_digits => consume_digits_ignore_separator,
+    extract_exponent => extract_exponent_ignore_separator,
+    validate_mantissa => validate_permissive_mantissa,
+    validate_exponent => validate_required_exponent_digits,
+    validate_exponent_fraction => validate_exponent_optional_fraction,
+    validate_exponent_sign => validate_optional_exponent_sign,
+    ltrim_zero => ltrim_zero_separator,
+    ltrim_separator => ltrim_separator_separator,
+    rtrim_zero => rtrim_zero_separator,
+    rtrim_separator => rtrim_separator_separator,
+    new => fn new(format: NumberFormat) -> Self {
+        Self {
+            format,
+            integer: &[],
+            fraction: None,
+            exponent: None,
+            raw_exponent: 0
+        }
+    }
+);
+
 // FROM
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 //! Traits that provide format-dependent data for floating parsing algorithms.
 
 use crate::result::*;
+use crate::traits::*;
 use crate::util::*;
 
 use super::exponent::*;
@@ -53,6 +54,19 @@ pub(crate) trait SlowDataInterfaceImpl<'a>: Sized {
     fn set_raw_exponent(&mut self, raw_exponent: i32);
 }
 
+/// Split digits after `count` digits, skipping digit separators.
+#[inline]
+fn split_digits<'a>(bytes: &'a [u8], count: usize, digit_separator: u8) -> (&'a [u8], &'a [u8]) {
+    let mut digits = 0;
+    let index = bytes.iter().position(|&c| {
+        if c != digit_separator {
+            digits += 1;
+        }
+        digits > count
+    });
+    bytes.split_at(index.unwrap_or(bytes.len()))
+}
+
 // Implement FastDataInterfaceImpl for a default structure.
 macro_rules! fast_data_interface_impl {
     ($name:ident) => {
@@ -142,13 +156,13 @@ macro_rules! slow_data_interface_impl {
 /// Data interface for fast float parsers.
 pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
     /// Integer digits iterator type.
-    type IntegerIter: ConsumedIterator<Item = &'a u8> + AsPtrIterator<'a, u8>;
+    type IntegerIter: Digits<'a>;
 
     /// Float digits iterator type.
-    type FractionIter: ConsumedIterator<Item = &'a u8> + AsPtrIterator<'a, u8>;
+    type FractionIter: Digits<'a>;
 
     /// Exponent digits iterator type.
-    type ExponentIter: ConsumedIterator<Item = &'a u8> + AsPtrIterator<'a, u8>;
+    type ExponentIter: Digits<'a>;
 
     /// Associated slow data type.
     type SlowInterface: SlowDataInterface<'a>;
@@ -264,6 +278,10 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         if let Some(&c) = digits.first() {
             if c.to_ascii_lowercase() == exponent {
                 digits = self.extract_exponent(digits, radix);
+            } else if let Some(length) = times_ten_introducer(self.format(), digits, radix) {
+                // Consume the introducer up to the trailing `^`, which is
+                // removed like the exponent character.
+                digits = self.extract_exponent(&digits[length - 1..], radix);
             }
         }
         self.validate_exponent()?;
@@ -276,6 +294,54 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         Ok(digits.as_ptr())
     }
 
+    /// Set float subcomponents from pre-tokenized digits.
+    ///
+    /// The digits must not contain digit separators.
+    #[inline]
+    fn set_parts(&mut self, integer: &'a [u8], fraction: &'a [u8], raw_exponent: i32) {
+        self.set_integer(integer);
+        self.set_fraction(Some(fraction));
+        self.set_raw_exponent(raw_exponent);
+        self.trim();
+    }
+
+    /// Truncate the mantissa to at most `max_digits` significant digits.
+    ///
+    /// Truncated integer digits are added to the raw exponent, so the
+    /// magnitude of the float is unchanged. Returns a pointer to the
+    /// first truncated non-zero digit, if any non-zero digits were removed.
+    #[inline]
+    fn truncate_mantissa(&mut self, max_digits: usize) -> Option<*const u8> {
+        let digit_separator = self.format().digit_separator();
+        let integer = self.integer();
+        let fraction = self.fraction();
+        let fraction_digits = fraction.unwrap_or(&[]);
+        let is_truncated = |c: &&u8| **c != b'0' && **c != digit_separator;
+
+        let integer_count = self.integer_iter().count();
+        let truncated = if integer_count > max_digits {
+            let (kept, dropped) = split_digits(integer, max_digits, digit_separator);
+            let shift = (integer_count - max_digits).try_i32_or_max();
+            self.set_integer(kept);
+            self.set_fraction(fraction.map(|x| &x[..0]));
+            self.set_raw_exponent(self.raw_exponent().saturating_add(shift));
+            dropped.iter().chain(fraction_digits.iter()).find(is_truncated)
+        } else {
+            // Leading zeros in the fraction are not significant without integer digits.
+            let start = match integer_count {
+                0 => fraction_digits.len() - self.ltrim_zero(fraction_digits).0.len(),
+                _ => 0,
+            };
+            let count = max_digits - integer_count;
+            let (kept, dropped) = split_digits(&fraction_digits[start..], count, digit_separator);
+            self.set_fraction(fraction.map(|x| &x[..start + kept.len()]));
+            dropped.iter().find(is_truncated)
+        };
+        self.trim();
+
+        truncated.map(|c| c as *const u8)
+    }
+
     // TO SLOW DATA
 
     // Calculate the digit start from the integer and fraction slices.
@@ -338,9 +404,9 @@ macro_rules! fast_data_interface {
     (
         struct $name:ident,
         fields => { $( $field:ident : $type:ty, )* },
-        integer_iter => ( $integer_iter:tt, $integer_iter_fn:ident ),
-        fraction_iter => ( $fraction_iter:tt, $fraction_iter_fn:ident ),
-        exponent_iter => ( $exponent_iter:tt, $exponent_iter_fn:ident ),
+        integer_iter => $integer_iter:ident,
+        fraction_iter => $fraction_iter:ident,
+        exponent_iter => $exponent_iter:ident,
         format => $format:expr,
         slow_interface => $slow_interface:tt,
         consume_integer_digits => $consume_integer_digits:expr,
@@ -381,19 +447,19 @@ macro_rules! fast_data_interface {
 
             #[inline(always)]
             fn integer_iter(&self) -> Self::IntegerIter {
-                $integer_iter_fn(self.integer, self.format().digit_separator())
+                $integer_iter::new(self.integer, self.format().digit_separator())
             }
 
             #[inline(always)]
             fn fraction_iter(&self) -> Self::FractionIter {
                 let fraction = self.fraction.unwrap_or(&[]);
-                $fraction_iter_fn(fraction, self.format().digit_separator())
+                $fraction_iter::new(fraction, self.format().digit_separator())
             }
 
             #[inline(always)]
             fn exponent_iter(&self) -> Self::ExponentIter {
                 let exponent = self.exponent.unwrap_or(&[]);
-                $exponent_iter_fn(exponent, self.format().digit_separator())
+                $exponent_iter::new(exponent, self.format().digit_separator())
             }
 
             #[inline(always)]
@@ -482,10 +548,10 @@ macro_rules! fast_data_interface {
 /// Data interface for moderate/slow float parsers.
 pub(crate) trait SlowDataInterface<'a>: SlowDataInterfaceImpl<'a> {
     /// Integer digits iterator type.
-    type IntegerIter: ConsumedIterator<Item = &'a u8> + AsPtrIterator<'a, u8>;
+    type IntegerIter: Digits<'a>;
 
     /// Float digits iterator type.
-    type FractionIter: ConsumedIterator<Item = &'a u8> + AsPtrIterator<'a, u8>;
+    type FractionIter: Digits<'a>;
 
     /// Iterate over all integer digits.
     fn integer_iter(&self) -> Self::IntegerIter;
@@ -548,8 +614,8 @@ macro_rules! slow_data_interface {
     (
         struct $name:ident,
         fields => { $( $field:ident : $type:ty, )* },
-        integer_iter => ( $integer_iter:tt, $integer_iter_fn:ident ),
-        fraction_iter => ( $fraction_iter:tt, $fraction_iter_fn:ident ),
+        integer_iter => $integer_iter:ident,
+        fraction_iter => $fraction_iter:ident,
         format => $format:expr
     ) => (
         pub(crate) struct $name<'a> {
@@ -571,18 +637,18 @@ macro_rules! slow_data_interface {
 
             #[inline(always)]
             fn integer_iter(&self) -> Self::IntegerIter {
-                $integer_iter_fn(self.integer, self.format().digit_separator())
+                $integer_iter::new(self.integer, self.format().digit_separator())
             }
 
             #[inline(always)]
             fn fraction_iter(&self) -> Self::FractionIter {
-                $fraction_iter_fn(self.fraction, self.format().digit_separator())
+                $fraction_iter::new(self.fraction, self.format().digit_separator())
             }
 
             #[inline(always)]
             fn significant_fraction_iter(&self) -> Self::FractionIter {
                 let fraction = &self.fraction[self.digits_start..];
-                $fraction_iter_fn(fraction, self.format().digit_separator())
+                $fraction_iter::new(fraction, self.format().digit_separator())
             }
 
             #[inline(always)]
@@ -609,9 +675,9 @@ macro_rules! data_interface {
         struct $fast:ident,
         struct $slow:ident,
         fields => { $( $field:ident : $type:ty, )* },
-        integer_iter => ( $integer_iter:tt, $integer_iter_fn:ident ),
-        fraction_iter => ( $fraction_iter:tt, $fraction_iter_fn:ident ),
-        exponent_iter => ( $exponent_iter:tt, $exponent_iter_fn:ident ),
+        integer_iter => $integer_iter:ident,
+        fraction_iter => $fraction_iter:ident,
+        exponent_iter => $exponent_iter:ident,
         format => $format:expr,
         consume_integer_digits => $consume_integer_digits:expr,
         consume_fraction_digits => $consume_fraction_digits:expr,
@@ -629,9 +695,9 @@ macro_rules! data_interface {
         fast_data_interface!(
             struct $fast,
             fields => { $( $field : $type , )* },
-            integer_iter => ($integer_iter, $integer_iter_fn),
-            fraction_iter => ($fraction_iter, $fraction_iter_fn),
-            exponent_iter => ($exponent_iter, $exponent_iter_fn),
+            integer_iter => $integer_iter,
+            fraction_iter => $fraction_iter,
+            exponent_iter => $exponent_iter,
             format => $format,
             slow_interface => $slow,
             consume_integer_digits => $consume_integer_digits,
@@ -651,8 +717,8 @@ macro_rules! data_interface {
         slow_data_interface!(
             struct $slow,
             fields => { $( $field : $type , )* },
-            integer_iter => ($integer_iter, $integer_iter_fn),
-            fraction_iter => ($fraction_iter, $fraction_iter_fn),
+            integer_iter => $integer_iter,
+            fraction_iter => $fraction_iter,
             format => $format
         );
     );
@@ -13,7 +13,6 @@ pub(super) fn ltrim_zero_no_separator<'a>(bytes: &'a [u8], _: u8) -> (&'a [u8],
 
 /// Trim leading 0s and digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(super) fn ltrim_zero_separator<'a>(bytes: &'a [u8], digit_separator: u8) -> (&'a [u8], usize) {
     ltrim_char2_slice(bytes, b'0', digit_separator)
 }
@@ -27,7 +26,6 @@ pub(super) fn ltrim_separator_no_separator<'a>(bytes: &'a [u8], _: u8) -> (&'a [
 
 /// Trim leading digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(super) fn ltrim_separator_separator<'a>(
     bytes: &'a [u8],
     digit_separator: u8,
@@ -44,7 +42,6 @@ pub(super) fn rtrim_zero_no_separator<'a>(bytes: &'a [u8], _: u8) -> (&'a [u8],
 
 /// Trim trailing 0s and digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(super) fn rtrim_zero_separator<'a>(bytes: &'a [u8], digit_separator: u8) -> (&'a [u8], usize) {
     rtrim_char2_slice(bytes, b'0', digit_separator)
 }
@@ -58,7 +55,6 @@ pub(super) fn rtrim_separator_no_separator<'a>(bytes: &'a [u8], _: u8) -> (&'a [
 
 /// Trim trailing digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(super) fn rtrim_separator_separator<'a>(
     bytes: &'a [u8],
     digit_separator: u8,
@@ -86,14 +82,12 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "format")]
     fn trim_zero_iltc_separator_test() {
         assert_eq!(ltrim_zero_separator(b!("0_1_2"), b'_'), (b!("1_2"), 2));
         assert_eq!(rtrim_zero_separator(b!("2345_0_"), b'_'), (b!("2345"), 3));
     }
 
     #[test]
-    #[cfg(feature = "format")]
     fn trim_separator_iltc_separator_test() {
         assert_eq!(ltrim_separator_separator(b!("0_1_2"), b'_'), (b!("0_1_2"), 0));
         assert_eq!(rtrim_separator_separator(b!("2345_0_"), b'_'), (b!("2345_0"), 1));
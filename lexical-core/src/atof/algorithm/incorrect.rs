@@ -62,18 +62,28 @@ where
     fraction
 }
 
-pub(crate) fn to_native<'a, F, Data>(data: Data, radix: u32) -> F
+/// Convert the extracted components to a native float.
+///
+/// Returns `None` for denormal results, including zeros from non-zero
+/// digits, which the caller must parse with the moderate path. The
+/// iterative powers lose the denormal bits on soft-float targets,
+/// such as `arm-unknown-linux-gnueabi`, that flush intermediate
+/// results to zero.
+pub(crate) fn to_native<'a, F, Data>(data: &Data, radix: u32) -> Option<F>
 where
     F: FloatType,
     Data: FastDataInterface<'a>,
 {
-    let integer: F = process_integer(&data, radix);
-    let fraction: F = process_fraction(&data, radix);
+    let integer: F = process_integer(data, radix);
+    let fraction: F = process_fraction(data, radix);
     let mut value = integer + fraction;
     if !data.raw_exponent().is_zero() && !value.is_zero() {
         value = value.iterative_pow(radix, data.raw_exponent());
     }
-    value
+    match value.is_denormal() {
+        true => None,
+        false => Some(value),
+    }
 }
 
 // TESTS
@@ -132,4 +142,18 @@ mod tests {
         assert_eq!(Ok((12345.6789, 10)), atod10(b"12345.6789"));
         assert_f64_near_eq!(1.2345e10, atod10(b"1.2345e10").unwrap().0);
     }
+
+    #[test]
+    fn to_native_denormal_test() {
+        type Data<'a> = StandardFastDataInterface<'a>;
+
+        let data: Data = (b!("5"), None, None, -324).into();
+        assert_eq!(None, to_native::<f64, Data>(&data, 10));
+
+        let data: Data = (b!("1"), Some(b!("2345")), None, -310).into();
+        assert_eq!(None, to_native::<f64, Data>(&data, 10));
+
+        let data: Data = (b!("1"), Some(b!("2345")), None, -300).into();
+        assert!(to_native::<f64, Data>(&data, 10).is_some());
+    }
 }
@@ -80,47 +80,54 @@ fn split_u128(x: u128) -> [Limb; 2] {
 }
 
 /// Impl FromUint
-macro_rules! from_uint {
-    ($self:ident, $vec:ty, $split:ident) => {{
-        let mut v = <$vec>::default();
+macro_rules! set_uint {
+    ($self:ident, $v:ident, $split:ident) => {{
         let slc = $split($self);
-        v.data_mut().extend_from_slice(&slc);
-        v.normalize();
-        v
+        $v.data_mut().clear();
+        $v.data_mut().extend_from_slice(&slc);
+        $v.normalize();
     }};
 }
 
 /// Create vector-like type from integral value.
 pub(crate) trait FromUint: UnsignedInteger {
     /// Create vector-like type from value.
-    fn from_uint<VecType: SharedOps>(self) -> VecType;
+    #[inline]
+    fn from_uint<VecType: SharedOps>(self) -> VecType {
+        let mut v = VecType::default();
+        self.set_uint(&mut v);
+        v
+    }
+
+    /// Assign value to vector-like type, reusing its storage.
+    fn set_uint<VecType: SharedOps>(self, v: &mut VecType);
 }
 
 impl FromUint for u16 {
     #[inline]
-    fn from_uint<VecType: SharedOps>(self) -> VecType {
-        from_uint!(self, VecType, split_u16)
+    fn set_uint<VecType: SharedOps>(self, v: &mut VecType) {
+        set_uint!(self, v, split_u16)
     }
 }
 
 impl FromUint for u32 {
     #[inline]
-    fn from_uint<VecType: SharedOps>(self) -> VecType {
-        from_uint!(self, VecType, split_u32)
+    fn set_uint<VecType: SharedOps>(self, v: &mut VecType) {
+        set_uint!(self, v, split_u32)
     }
 }
 
 impl FromUint for u64 {
     #[inline]
-    fn from_uint<VecType: SharedOps>(self) -> VecType {
-        from_uint!(self, VecType, split_u64)
+    fn set_uint<VecType: SharedOps>(self, v: &mut VecType) {
+        set_uint!(self, v, split_u64)
     }
 }
 
 impl FromUint for u128 {
     #[inline]
-    fn from_uint<VecType: SharedOps>(self) -> VecType {
-        from_uint!(self, VecType, split_u128)
+    fn set_uint<VecType: SharedOps>(self, v: &mut VecType) {
+        set_uint!(self, v, split_u128)
     }
 }
 
@@ -1445,13 +1452,13 @@ pub(crate) mod small {
     /// Even using worst-case scenarios, exponentiation by squaring is
     /// significantly slower for our workloads. Just multiply by small powers,
     /// in simple cases, and use precalculated large powers in other cases.
+    #[cfg(not(feature = "compact"))]
     pub fn imul_power<T>(x: &mut T, radix: u32, n: u32)
     where
         T: CloneableVecLike<Limb>,
     {
         use super::large::KARATSUBA_CUTOFF;
 
-        let small_powers = get_small_powers(radix);
         let large_powers = get_large_powers(radix);
 
         if n == 0 {
@@ -1469,27 +1476,17 @@ pub(crate) mod small {
         if x.len() + large_powers[bit_length - 1].len() < 2 * KARATSUBA_CUTOFF {
             // We can use iterative small powers to make this faster for the
             // easy cases.
-
-            // Multiply by the largest small power until n < step.
-            let step = small_powers.len() - 1;
-            let power = small_powers[step];
-            let mut n = n.as_usize();
-            while n >= step {
-                imul(x, power);
-                n -= step;
-            }
-
-            // Multiply by the remainder.
-            imul(x, small_powers[n]);
+            imul_small_powers(x, radix, n);
         } else {
             // In theory, this code should be asymptotically a lot faster,
             // in practice, our small::imul seems to be the limiting step,
             // and large imul is slow as well.
 
             // Multiply by higher order powers.
+            // Keep `n` as a `u32`, since `usize` may only be 16 bits.
             let mut idx: usize = 0;
-            let mut bit: usize = 1;
-            let mut n = n.as_usize();
+            let mut bit: u32 = 1;
+            let mut n = n;
             while n != 0 {
                 if n & bit != 0 {
                     debug_assert!(idx < large_powers.len());
@@ -1502,6 +1499,39 @@ pub(crate) mod small {
         }
     }
 
+    /// MulAssign by a power.
+    ///
+    /// Compact builds do not include the precalculated large powers,
+    /// so only multiply by small powers.
+    #[inline]
+    #[cfg(feature = "compact")]
+    pub fn imul_power<T>(x: &mut T, radix: u32, n: u32)
+    where
+        T: CloneableVecLike<Limb>,
+    {
+        imul_small_powers(x, radix, n);
+    }
+
+    /// MulAssign by a power, iteratively multiplying by small powers.
+    #[inline]
+    fn imul_small_powers<T>(x: &mut T, radix: u32, mut n: u32)
+    where
+        T: CloneableVecLike<Limb>,
+    {
+        // Multiply by the largest small power until n < step.
+        let small_powers = get_small_powers(radix);
+        let step = small_powers.len() - 1;
+        let power = small_powers[step];
+        let step = step.as_u32();
+        while n >= step {
+            imul(x, power);
+            n -= step;
+        }
+
+        // Multiply by the remainder, which is less than `step`.
+        imul(x, small_powers[n.as_usize()]);
+    }
+
     /// Mul by a power.
     #[inline]
     #[allow(dead_code)]
@@ -2493,6 +2523,7 @@ pub(crate) trait SmallOps: SharedOps {
 
     /// Get the large powers from the radix.
     #[inline]
+    #[cfg(not(feature = "compact"))]
     fn large_powers(radix: u32) -> &'static [&'static [Limb]] {
         get_large_powers(radix)
     }
@@ -6,10 +6,12 @@ mod format;
 
 mod alias;
 mod bhcomp;
+mod big_digits;
 mod bigcomp;
 mod bignum;
 mod cached;
 mod errors;
+mod exact;
 mod math;
 mod powers;
 
@@ -19,7 +21,10 @@ pub(crate) mod incorrect;
 
 // Re-export the float type.
 pub(crate) use self::alias::FloatType;
-pub(crate) use self::cached::ModeratePathCache;
+pub(crate) use self::big_digits::{big_digits, exact_digits};
+pub(crate) use self::bignum::BigintScratch;
+pub(crate) use self::exact::{compare_exact, is_exact, significant_count};
+pub use self::cached::{ExtendedFloatArray, ModeratePathCache, ModeratePathPowers};
 pub(crate) use self::format::*;
 
 // Internal implementation details.
@@ -1,6 +1,7 @@
 //! Precalculated powers for performance gain.
 
 // Hide implementation details.
+#[cfg(not(feature = "compact"))]
 mod large;
 mod small;
 
@@ -12,18 +13,21 @@ mod small64_decimal;
 mod small64_radix;
 
 // Limb-width dependent types.
+// Compact builds do not include the large powers.
 cfg_if! {
 if #[cfg(limb_width_32)] {
+    #[cfg(not(feature = "compact"))]
     mod large32_decimal;
     mod small32_decimal;
     #[cfg(feature = "power_of_two")]
     mod small32_binary;
     cfg_if! {
     if #[cfg(feature = "radix")] {
+        #[cfg(not(feature = "compact"))]
         mod large32_radix;
         mod small32_radix;
     }}  // cfg_if
-} else {
+} else if #[cfg(not(feature = "compact"))] {
     mod large64_decimal;
     #[cfg(feature = "radix")]
     mod large64_radix;
@@ -40,5 +44,6 @@ if #[cfg(feature = "f128")] {
 }}  // cfg_if
 
 // Re-export methods.
+#[cfg(not(feature = "compact"))]
 pub(crate) use self::large::*;
 pub(crate) use self::small::*;
@@ -4,13 +4,14 @@
 
 use crate::error::*;
 use crate::float::*;
-use crate::lib::slice;
+use crate::lib::{cmp, iter, slice};
 use crate::result::*;
 use crate::traits::*;
 use crate::util::*;
 
 use super::algorithm::correct as algorithm;
 use super::algorithm::*;
+use super::parser::FloatParser;
 
 // NOTICE
 //  These internal calls are all ugly, and pass **all** the values
@@ -24,24 +25,11 @@ use super::algorithm::*;
 // SPECIAL
 // Utilities to filter special values.
 
-/// Convert slice to iterator without digit separators.
-#[inline(always)]
-fn to_iter<'a>(bytes: &'a [u8], _: u8) -> slice::Iter<'a, u8> {
-    bytes.iter()
-}
-
-/// Convert slice to iterator with digit separators.
-#[inline(always)]
-#[cfg(feature = "format")]
-fn to_iter_s<'a>(bytes: &'a [u8], digit_separator: u8) -> SkipValueIterator<'a, u8> {
-    SkipValueIterator::new(bytes, digit_separator)
-}
-
 // PARSER
 
 /// Parse infinity from string.
 #[inline]
-fn parse_infinity<'a, ToIter, StartsWith, Iter, F, Data>(
+fn parse_infinity<'a, Iter, StartsWith, F, Data>(
     data: Data,
     bytes: &'a [u8],
     sign: Sign,
@@ -49,28 +37,27 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
     inf_string: &'static [u8],
     infinity_string: &'static [u8],
-    to_iter: ToIter,
     starts_with: StartsWith,
 ) -> ParseResult<(F, *const u8)>
 where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
-    ToIter: Fn(&'a [u8], u8) -> Iter,
-    Iter: AsPtrIterator<'a, u8>,
+    Iter: Digits<'a>,
     StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter),
     Data: FastDataInterface<'a>,
 {
     let digit_separator = data.format().digit_separator();
-    if let (true, iter) = starts_with(to_iter(bytes, digit_separator), infinity_string.iter()) {
+    if let (true, iter) = starts_with(Iter::new(bytes, digit_separator), infinity_string.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
-    } else if let (true, iter) = starts_with(to_iter(bytes, digit_separator), inf_string.iter()) {
+    } else if let (true, iter) = starts_with(Iter::new(bytes, digit_separator), inf_string.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
     } else {
         // Not infinity, may be valid with a different radix.
         if cfg!(feature = "power_of_two") {
-            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding)
+            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding, scratch)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -79,7 +66,7 @@ where
 
 /// Parse NaN from string.
 #[inline]
-fn parse_nan<'a, ToIter, StartsWith, Iter, F, Data>(
+fn parse_nan<'a, Iter, StartsWith, F, Data>(
     data: Data,
     bytes: &'a [u8],
     sign: Sign,
@@ -87,25 +74,24 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
     nan_string: &'static [u8],
-    to_iter: ToIter,
     starts_with: StartsWith,
 ) -> ParseResult<(F, *const u8)>
 where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
-    ToIter: Fn(&'a [u8], u8) -> Iter,
-    Iter: AsPtrIterator<'a, u8>,
+    Iter: Digits<'a>,
     StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter),
     Data: FastDataInterface<'a>,
 {
     let digit_separator = data.format().digit_separator();
-    if let (true, iter) = starts_with(to_iter(bytes, digit_separator), nan_string.iter()) {
+    if let (true, iter) = starts_with(Iter::new(bytes, digit_separator), nan_string.iter()) {
         Ok((F::NAN, iter.as_ptr()))
     } else {
         // Not NaN, may be valid with a different radix.
         if cfg!(feature = "power_of_two") {
-            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding)
+            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding, scratch)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -114,120 +100,13 @@ where
 
 // ATOF/ATOD
 
-/// Parse special or float values with the standard format.
-/// Special values are allowed, the match is case-insensitive,
-/// and no digit separators are allowed.
-#[inline(always)]
-fn parse_float_standard<'a, F, Data>(
-    data: Data,
-    bytes: &'a [u8],
-    sign: Sign,
-    radix: u32,
-    incorrect: bool,
-    lossy: bool,
-    rounding: RoundingKind,
-    nan_string: &'static [u8],
-    inf_string: &'static [u8],
-    infinity_string: &'static [u8],
-) -> ParseResult<(F, *const u8)>
-where
-    F: FloatType,
-    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
-    Data: FastDataInterface<'a>,
-{
-    // Use predictive parsing to filter special cases. This leads to
-    // dramatic performance gains.
-    let starts_with = case_insensitive_starts_with_iter;
-    match bytes[0] {
-        b'i' | b'I' => parse_infinity(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            inf_string,
-            infinity_string,
-            to_iter,
-            starts_with,
-        ),
-        b'N' | b'n' => parse_nan(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            nan_string,
-            to_iter,
-            starts_with,
-        ),
-        _ => algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding),
-    }
-}
-
-/// Parse special or float values.
-/// Special values are allowed, the match is case-sensitive,
-/// and digit separators are allowed.
-#[inline]
-#[cfg(feature = "format")]
-fn parse_float_cs<'a, F, Data>(
-    data: Data,
-    bytes: &'a [u8],
-    sign: Sign,
-    radix: u32,
-    incorrect: bool,
-    lossy: bool,
-    rounding: RoundingKind,
-    nan_string: &'static [u8],
-    inf_string: &'static [u8],
-    infinity_string: &'static [u8],
-) -> ParseResult<(F, *const u8)>
-where
-    F: FloatType,
-    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
-    Data: FastDataInterface<'a>,
-{
-    let digit_separator = data.format().digit_separator();
-    let starts_with = starts_with_iter;
-    match SkipValueIterator::new(bytes, digit_separator).next() {
-        Some(&b'i') | Some(&b'I') => parse_infinity(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            inf_string,
-            infinity_string,
-            to_iter_s,
-            starts_with,
-        ),
-        Some(&b'n') | Some(&b'N') => parse_nan(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            nan_string,
-            to_iter_s,
-            starts_with,
-        ),
-        _ => algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding),
-    }
-}
-
 /// Parse special or float values.
-/// Special values are allowed, the match is case-sensitive,
-/// and no digit separators are allowed.
-#[inline]
-#[cfg(feature = "format")]
-fn parse_float_c<'a, F, Data>(
+///
+/// Special values are allowed, the digits iterator determines if digit
+/// separators are allowed, and `starts_with` determines if the match
+/// is case-sensitive.
+#[inline(always)]
+fn parse_float_special<'a, Iter, StartsWith, F, Data>(
     data: Data,
     bytes: &'a [u8],
     sign: Sign,
@@ -235,74 +114,24 @@ fn parse_float_c<'a, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     infinity_string: &'static [u8],
+    starts_with: StartsWith,
 ) -> ParseResult<(F, *const u8)>
 where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+    Iter: Digits<'a>,
+    StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter),
     Data: FastDataInterface<'a>,
 {
     // Use predictive parsing to filter special cases. This leads to
     // dramatic performance gains.
-    let starts_with = starts_with_iter;
-    match bytes[0] {
-        b'i' | b'I' => parse_infinity(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            inf_string,
-            infinity_string,
-            to_iter,
-            starts_with,
-        ),
-        b'N' | b'n' => parse_nan(
-            data,
-            bytes,
-            sign,
-            radix,
-            incorrect,
-            lossy,
-            rounding,
-            nan_string,
-            to_iter,
-            starts_with,
-        ),
-        _ => algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding),
-    }
-}
-
-/// Parse special or float values.
-/// Special values are allowed, the match is case-insensitive,
-/// and digit separators are allowed.
-#[inline]
-#[cfg(feature = "format")]
-fn parse_float_s<'a, F, Data>(
-    data: Data,
-    bytes: &'a [u8],
-    sign: Sign,
-    radix: u32,
-    incorrect: bool,
-    lossy: bool,
-    rounding: RoundingKind,
-    nan_string: &'static [u8],
-    inf_string: &'static [u8],
-    infinity_string: &'static [u8],
-) -> ParseResult<(F, *const u8)>
-where
-    F: FloatType,
-    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
-    Data: FastDataInterface<'a>,
-{
     let digit_separator = data.format().digit_separator();
-    let starts_with = case_insensitive_starts_with_iter;
-    match SkipValueIterator::new(bytes, digit_separator).next() {
-        Some(&b'i') | Some(&b'I') => parse_infinity(
+    match Iter::new(bytes, digit_separator).next() {
+        Some(&b'i') | Some(&b'I') => parse_infinity::<Iter, _, F, Data>(
             data,
             bytes,
             sign,
@@ -310,12 +139,12 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             inf_string,
             infinity_string,
-            to_iter_s,
             starts_with,
         ),
-        Some(&b'n') | Some(&b'N') => parse_nan(
+        Some(&b'n') | Some(&b'N') => parse_nan::<Iter, _, F, Data>(
             data,
             bytes,
             sign,
@@ -323,11 +152,11 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             nan_string,
-            to_iter_s,
             starts_with,
         ),
-        _ => algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding),
+        _ => algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding, scratch),
     }
 }
 
@@ -342,6 +171,7 @@ fn parse_float<'a, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     infinity_string: &'static [u8],
@@ -351,7 +181,8 @@ where
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
     Data: FastDataInterface<'a>,
 {
-    parse_float_standard(
+    // Special values are case-insensitive, without digit separators.
+    parse_float_special::<ContiguousDigits, _, F, Data>(
         data,
         bytes,
         sign,
@@ -359,9 +190,11 @@ where
         incorrect,
         lossy,
         rounding,
+        scratch,
         nan_string,
         inf_string,
         infinity_string,
+        case_insensitive_starts_with_iter,
     )
 }
 
@@ -376,6 +209,7 @@ fn parse_float<'a, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     infinity_string: &'static [u8],
@@ -395,9 +229,9 @@ where
     let has_sep = format.special_digit_separator();
     match (no_special, case, has_sep) {
         (true, _, _) => {
-            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding)
+            algorithm::to_native::<F, Data>(data, bytes, sign, radix, incorrect, lossy, rounding, scratch)
         },
-        (false, true, true) => parse_float_cs(
+        (false, true, true) => parse_float_special::<SeparatorDigits, _, F, Data>(
             data,
             bytes,
             sign,
@@ -405,11 +239,13 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             nan_string,
             inf_string,
             infinity_string,
+            starts_with_iter,
         ),
-        (false, false, true) => parse_float_s(
+        (false, false, true) => parse_float_special::<SeparatorDigits, _, F, Data>(
             data,
             bytes,
             sign,
@@ -417,11 +253,13 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             nan_string,
             inf_string,
             infinity_string,
+            case_insensitive_starts_with_iter,
         ),
-        (false, true, false) => parse_float_c(
+        (false, true, false) => parse_float_special::<ContiguousDigits, _, F, Data>(
             data,
             bytes,
             sign,
@@ -429,11 +267,13 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             nan_string,
             inf_string,
             infinity_string,
+            starts_with_iter,
         ),
-        (false, false, false) => parse_float_standard(
+        (false, false, false) => parse_float_special::<ContiguousDigits, _, F, Data>(
             data,
             bytes,
             sign,
@@ -441,9 +281,11 @@ where
             incorrect,
             lossy,
             rounding,
+            scratch,
             nan_string,
             inf_string,
             infinity_string,
+            case_insensitive_starts_with_iter,
         ),
     }
 }
@@ -469,6 +311,278 @@ fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, format: NumberFormat)
     }
 }
 
+/// Check if the exponent digits overflow an `i32`.
+///
+/// The raw exponent saturates when parsed, so `i32::MAX` may also be
+/// a literal exponent. Only digits that overflow saturate the exponent.
+#[inline]
+fn is_exponent_overflow(exponent: &[u8], radix: u32) -> bool {
+    let limit = match exponent.contains(&b'-') {
+        true => -(i32::min_value() as i64),
+        false => i32::max_value() as i64,
+    };
+    let mut value: i64 = 0;
+    for digit in exponent_digits(exponent, radix) {
+        value = value * radix as i64 + digit as i64;
+        if value > limit {
+            return true;
+        }
+    }
+    false
+}
+
+/// Validate the float was not clamped to infinity or zero.
+///
+/// The exponent is clamped on overflow, and the value is clamped to
+/// zero on underflow. Only re-extract the float components if we
+/// have one of these values, so valid floats pay no penalty.
+#[inline]
+fn validate_clamped<'a, F, Data>(
+    bytes: &'a [u8],
+    float: F,
+    format: NumberFormat,
+    radix: u32,
+    exponent_overflow_error: bool,
+    underflow_error: bool,
+) -> ParseResult<()>
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    if !float.is_inf() && !float.is_zero() {
+        return Ok(());
+    }
+
+    // Special values, such as infinity, will fail to extract.
+    let mut data = Data::new(format);
+    if data.extract(bytes, radix).is_err() {
+        return Ok(());
+    }
+    if data.integer_iter().chain(data.fraction_iter()).all(|&c| c == b'0') {
+        // Literal zero, cannot be clamped.
+        return Ok(());
+    }
+
+    let (ptr, is_overflow) = match data.exponent() {
+        Some(exponent) => (exponent.as_ptr(), is_exponent_overflow(exponent, radix)),
+        None => (bytes.as_ptr(), false),
+    };
+    if exponent_overflow_error && is_overflow {
+        Err((ErrorCode::ExponentOverflow, ptr))
+    } else if underflow_error && float.is_zero() {
+        Err((ErrorCode::Underflow, ptr))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validate the float exactly represents the digits.
+///
+/// Re-extracts the float components, like `validate_clamped`, to compare
+/// the digits to the float, so only the exact option pays the penalty.
+#[inline]
+fn validate_exact<'a, F, Data>(
+    bytes: &'a [u8],
+    float: F,
+    format: NumberFormat,
+    radix: u32,
+) -> ParseResult<()>
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    // Special values, such as infinity, will fail to extract.
+    let mut data = Data::new(format);
+    if data.extract(bytes, radix).is_err() {
+        return Ok(());
+    }
+    match is_exact(data.to_slow(0), radix, float) {
+        true => Ok(()),
+        false => Err((ErrorCode::Inexact, bytes.as_ptr())),
+    }
+}
+
+/// Get the statistics of the digits, and if the float exactly represents them.
+///
+/// Re-extracts the float components, like `validate_exact`, so only the
+/// info parsers pay the penalty. Special values have no digits.
+#[inline]
+fn parse_info<'a, F, Data>(
+    bytes: &'a [u8],
+    float: F,
+    format: NumberFormat,
+    radix: u32,
+    truncated: bool,
+) -> ParseInfo
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    let mut data = Data::new(format);
+    if data.extract(bytes, radix).is_err() {
+        return ParseInfo::new(0, 0, truncated, true);
+    }
+    let data = data.to_slow(0);
+    let digits = significant_count(&data);
+    let exponent = match digits {
+        0 => 0,
+        _ => data.scientific_exponent(),
+    };
+    ParseInfo::new(digits, exponent, truncated, is_exact(data, radix, float))
+}
+
+/// Check if the float exactly represents the digits extracted by the interface.
+#[inline]
+fn is_exact_data<'a, F, Data>(mut data: Data, bytes: &'a [u8], float: F, radix: u32) -> bool
+where
+    F: FloatType,
+    Data: FastDataInterface<'a>,
+{
+    match data.extract(bytes, radix) {
+        Ok(_) => is_exact(data.to_slow(0), radix, float),
+        Err(_) => false,
+    }
+}
+
+/// Check if the float exactly represents the digits written for it.
+///
+/// The digits are extracted with the interface of the format, since the
+/// float writers may group them with its digit separator. The digits
+/// must not have a sign.
+#[inline]
+pub(crate) fn is_exact_bytes<F>(bytes: &[u8], float: F, format: NumberFormat, radix: u32) -> bool
+where
+    F: FloatType,
+{
+    apply_interface!(is_exact_data::<F, _>, format, bytes, float, radix)
+}
+
+/// Compare the digits, `digits * radix^exponent`, to a positive, finite float.
+///
+/// Used by the float writers to correctly round their digits. The digits
+/// must have at most `max_correct_digits` significant digits.
+#[inline]
+pub(crate) fn compare_parts<F>(digits: &[u8], exponent: i32, float: F, radix: u32) -> cmp::Ordering
+where
+    F: FloatType,
+{
+    let mut data = StandardFastDataInterface::new(NumberFormat::STANDARD);
+    data.set_parts(digits, &[], exponent);
+    compare_exact(data.to_slow(0), radix, float)
+}
+
+/// Validate the exponent does not have more than `max_digits` digits.
+///
+/// Counting stops at the first digit past the limit, so long exponents
+/// are rejected without processing the remaining digits. Digit
+/// separators do not count towards the limit.
+#[inline]
+fn validate_exponent_digits(
+    bytes: &[u8],
+    format: NumberFormat,
+    radix: u32,
+    max_digits: usize,
+) -> ParseResult<()> {
+    let decimal_point = format.decimal_point();
+    let digit_separator = format.digit_separator();
+    let is_separator = |c: u8| digit_separator != 0 && c == digit_separator;
+
+    // Skip the mantissa, to find the exponent character.
+    let mantissa = bytes
+        .iter()
+        .take_while(|&&c| is_digit(c, radix) || c == decimal_point || is_separator(c))
+        .count();
+    let is_exponent = |c: u8| {
+        c.eq_ignore_ascii_case(&format.exponent(radix))
+            || c.eq_ignore_ascii_case(&format.exponent_backup())
+    };
+    let exponent = match bytes[mantissa..].split_first() {
+        Some((&c, rest)) if is_exponent(c) => rest,
+        _ => return Ok(()),
+    };
+    let exponent = match exponent.first() {
+        Some(&b'+') | Some(&b'-') => &exponent[1..],
+        _ => exponent,
+    };
+
+    let mut digits = exponent
+        .iter()
+        .take_while(|&&c| is_digit(c, radix) || is_separator(c))
+        .filter(|&&c| !is_separator(c));
+    match digits.nth(max_digits) {
+        Some(c) => Err((ErrorCode::TooManyExponentDigits, c)),
+        None => Ok(()),
+    }
+}
+
+/// Parse the float, limiting the mantissa to `max_digits` significant digits.
+///
+/// Returns the float, a pointer to the end of the float, and if any
+/// non-zero digits were truncated. Special values and invalid floats
+/// fail to extract, and fall back to the default parser to get the
+/// same value or error.
+#[inline]
+fn parse_float_truncated<'a, F, Data>(
+    data: Data,
+    bytes: &'a [u8],
+    sign: Sign,
+    radix: u32,
+    incorrect: bool,
+    lossy: bool,
+    rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
+    max_digits: usize,
+    truncate: bool,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> ParseResult<(F, *const u8, bool)>
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+    Data: FastDataInterface<'a>,
+{
+    // Special values are checked before any digits, even in radixes
+    // where the special characters are valid digits.
+    let mut extracted = Data::new(data.format());
+    let is_special = match bytes[0] {
+        b'i' | b'I' | b'n' | b'N' => !data.format().no_special(),
+        _ => false,
+    };
+    let ptr = match is_special {
+        false => extracted.extract(bytes, radix).ok(),
+        true => None,
+    };
+    let ptr = match ptr {
+        Some(ptr) => ptr,
+        None => {
+            let (float, ptr) = parse_float(
+                data,
+                bytes,
+                sign,
+                radix,
+                incorrect,
+                lossy,
+                rounding,
+                scratch,
+                nan_string,
+                inf_string,
+                infinity_string,
+            )?;
+            return Ok((float, ptr, false));
+        },
+    };
+
+    match extracted.truncate_mantissa(max_digits) {
+        Some(digit) if !truncate => Err((ErrorCode::TooManyMantissaDigits, digit)),
+        truncated => {
+            let float =
+                algorithm::data_to_native(extracted, sign, radix, incorrect, lossy, rounding, scratch);
+            Ok((float, ptr, truncated.is_some()))
+        },
+    }
+}
+
 /// Convert float to signed representation.
 #[inline(always)]
 fn to_signed<F: FloatType>(float: F, sign: Sign) -> F {
@@ -487,10 +601,18 @@ fn atof<'a, F, Data>(
     incorrect: bool,
     lossy: bool,
     rounding: RoundingKind,
+    scratch: Option<&mut BigintScratch<F>>,
+    exponent_overflow_error: bool,
+    underflow_error: bool,
+    max_exponent_digits: Option<usize>,
+    max_mantissa_digits: Option<usize>,
+    truncate_mantissa: bool,
+    exact: bool,
+    info: bool,
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     infinity_string: &'static [u8],
-) -> ParseResult<(F, *const u8)>
+) -> ParseResult<(F, *const u8, ParseMetadata)>
 where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
@@ -501,22 +623,200 @@ where
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
-    let (float, ptr): (F, *const u8) = parse_float(
-        data,
-        digits,
-        sign,
-        radix,
-        incorrect,
-        lossy,
-        rounding,
-        nan_string,
-        inf_string,
-        infinity_string,
-    )?;
+    if let Some(max_digits) = max_exponent_digits {
+        validate_exponent_digits(digits, format, radix, max_digits)?;
+    }
+    let (float, ptr, truncated): (F, *const u8, bool) = match max_mantissa_digits {
+        Some(max_digits) => parse_float_truncated(
+            data,
+            digits,
+            sign,
+            radix,
+            incorrect,
+            lossy,
+            rounding,
+            scratch,
+            max_digits,
+            truncate_mantissa,
+            nan_string,
+            inf_string,
+            infinity_string,
+        )?,
+        None => {
+            let (float, ptr) = parse_float(
+                data,
+                digits,
+                sign,
+                radix,
+                incorrect,
+                lossy,
+                rounding,
+                scratch,
+                nan_string,
+                inf_string,
+                infinity_string,
+            )?;
+            (float, ptr, false)
+        },
+    };
     validate_sign(bytes, digits, sign, format)?;
+    if exponent_overflow_error || underflow_error {
+        validate_clamped::<F, Data>(
+            digits,
+            float,
+            format,
+            radix,
+            exponent_overflow_error,
+            underflow_error,
+        )?;
+    }
+    if exact {
+        validate_exact::<F, Data>(digits, float, format, radix)?;
+    }
 
-    Ok((to_signed(float, sign), ptr))
-}
+    let metadata = match info {
+        true => {
+            let info = parse_info::<F, Data>(digits, float, format, radix, truncated);
+            ParseMetadata::new(truncated).with_info(info)
+        },
+        false => ParseMetadata::new(truncated),
+    };
+
+    Ok((to_signed(float, sign), ptr, metadata))
+}
+
+// WHITESPACE
+// Utilities to skip whitespace, like JavaScript's `Number()`.
+
+/// Get the length of the UTF-8 whitespace character at the start of the bytes.
+#[inline]
+fn whitespace_len(bytes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(&(b'\t'..=b'\r')) | Some(&b' ') => 1,
+        Some(&0xC2) if bytes.get(1) == Some(&0xA0) => 2,
+        Some(&(0xE1..=0xEF)) if bytes.len() >= 3 => match (bytes[0], bytes[1], bytes[2]) {
+            // U+1680, U+2000-U+200A, U+2028-U+2029, U+202F, U+205F, U+3000, U+FEFF.
+            (0xE1, 0x9A, 0x80)
+            | (0xE2, 0x80, 0x80..=0x8A)
+            | (0xE2, 0x80, 0xA8..=0xA9)
+            | (0xE2, 0x80, 0xAF)
+            | (0xE2, 0x81, 0x9F)
+            | (0xE3, 0x80, 0x80)
+            | (0xEF, 0xBB, 0xBF) => 3,
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+/// Get the length of the suffix after the digits, if present.
+///
+/// The suffix must directly follow a digit or the decimal point,
+/// so it is never skipped after special values.
+#[inline]
+fn suffix_len(bytes: &[u8], processed: usize, options: &ParseFloatOptions) -> usize {
+    let suffix = match options.suffix() {
+        Some(suffix) => suffix,
+        None => return 0,
+    };
+    let last = match processed {
+        0 => return 0,
+        _ => bytes[processed - 1],
+    };
+    let is_number = is_digit(last, options.radix()) || last == options.decimal_point();
+    match bytes.get(processed) {
+        Some(&c) if is_number && c.eq_ignore_ascii_case(&suffix) => 1,
+        _ => 0,
+    }
+}
+
+/// Get the number of leading whitespace bytes.
+#[inline]
+fn skip_whitespace(bytes: &[u8]) -> usize {
+    let mut index = 0;
+    loop {
+        match whitespace_len(&bytes[index..]) {
+            0 => return index,
+            len => index += len,
+        }
+    }
+}
+
+/// Get the length of the longest affix at the start of the bytes.
+#[inline]
+fn affix_len(bytes: &[u8], affixes: &[&[u8]]) -> usize {
+    let matches = affixes.iter().filter(|&&affix| bytes.starts_with(affix));
+    matches.map(|affix| affix.len()).max().unwrap_or(0)
+}
+
+/// Skip a prefix or suffix, and the whitespace after it when trimming.
+///
+/// Returns the number of bytes skipped, or 0 if no affix matches.
+#[inline]
+fn skip_affix(bytes: &[u8], affixes: &[&[u8]], trim_whitespace: bool) -> usize {
+    match affix_len(bytes, affixes) {
+        0 => 0,
+        len if trim_whitespace => len + skip_whitespace(&bytes[len..]),
+        len => len,
+    }
+}
+
+/// Skip a sign string before the number, like the Unicode minus sign.
+///
+/// Returns the start of the number after the sign string, and its sign.
+/// The sign string is the only sign of the number, so it must not be
+/// followed by a sign or the opening parenthesis of a negative value.
+#[inline]
+fn skip_sign_string(
+    bytes: &[u8],
+    start: usize,
+    options: &ParseFloatOptions,
+) -> Result<(usize, Option<Sign>)> {
+    let plus_signs = options.plus_signs();
+    let minus_signs = options.minus_signs();
+    match parse_sign_string(&bytes[start..], plus_signs, minus_signs) {
+        None => Ok((start, None)),
+        Some((sign, len)) => match bytes.get(start + len) {
+            Some(&b'+') | Some(&b'-') | Some(&b'(') => {
+                Err((ErrorCode::InvalidDigit, start + len).into())
+            },
+            _ => Ok((start + len, Some(sign))),
+        },
+    }
+}
+
+/// Skip a prefix before the number, which may follow the sign, like `-$1.5`.
+///
+/// Returns the start of the number, and the sign if it preceded the prefix.
+#[inline]
+fn skip_prefix(bytes: &[u8], start: usize, options: &ParseFloatOptions) -> (usize, Option<Sign>) {
+    let prefixes = options.skip_prefixes();
+    let trim_whitespace = options.trim_whitespace();
+    let sign = match bytes.get(start) {
+        Some(&b'+') => Sign::Positive,
+        Some(&b'-') => Sign::Negative,
+        _ => return (start + skip_affix(&bytes[start..], prefixes, trim_whitespace), None),
+    };
+    match skip_affix(&bytes[start + 1..], prefixes, trim_whitespace) {
+        0 => (start, None),
+        len => (start + 1 + len, Some(sign)),
+    }
+}
+
+/// Skip the opening parenthesis of a negative value, if present.
+///
+/// Returns the start of the digits, and if the value is in parentheses.
+/// A sign is not allowed inside the parentheses.
+#[inline]
+fn parse_parentheses(bytes: &[u8], start: usize, format: NumberFormat) -> Result<(usize, bool)> {
+    if !format.negative_parentheses() || bytes.get(start) != Some(&b'(') {
+        return Ok((start, false));
+    }
+    match bytes.get(start + 1) {
+        Some(&c) if c == b'+' || c == b'-' => Err((ErrorCode::InvalidDigit, start + 1).into()),
+        _ => Ok((start + 1, true)),
+    }
+}
 
 // Optimized atof with default options.
 #[inline(always)]
@@ -534,13 +834,21 @@ where
         DEFAULT_INCORRECT,
         DEFAULT_LOSSY,
         DEFAULT_ROUNDING,
+        None,
+        DEFAULT_EXPONENT_OVERFLOW_ERROR,
+        DEFAULT_UNDERFLOW_ERROR,
+        DEFAULT_MAX_EXPONENT_DIGITS,
+        DEFAULT_MAX_MANTISSA_DIGITS,
+        DEFAULT_TRUNCATE_MANTISSA,
+        DEFAULT_EXACT,
+        false,
         DEFAULT_NAN_STRING,
         DEFAULT_INF_STRING,
         DEFAULT_INFINITY_STRING
     );
     let index = |ptr| distance(bytes.as_ptr(), ptr);
     match result {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
+        Ok((value, ptr, _)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }
@@ -552,125 +860,775 @@ where
     F: FloatType,
     ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
 {
-    let format = options.format();
+    let (value, processed, _) = atof_with_metadata(bytes, options)?;
+    Ok((value, processed))
+}
+
+// Atof with custom options, returning the parse metadata.
+#[inline(always)]
+fn atof_with_metadata<F>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<(F, usize, ParseMetadata)>
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+{
+    atof_with_scratch(bytes, options, None, false)
+}
+
+// Atof with custom options, returning the statistics of the digits.
+#[inline(always)]
+fn atof_with_info<F>(bytes: &[u8], options: &ParseFloatOptions) -> Result<(F, usize, ParseInfo)>
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+{
+    let (value, processed, metadata) = atof_with_scratch(bytes, options, None, true)?;
+    let info = metadata.info().unwrap_or_default();
+    Ok((value, processed, info))
+}
+
+// Atof with custom options, using the scratch space for the slow path.
+//
+// The statistics of the digits are only calculated if `info` is set.
+#[inline(always)]
+fn atof_with_scratch<F>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+    scratch: Option<&mut BigintScratch<F>>,
+    info: bool,
+) -> Result<(F, usize, ParseMetadata)>
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+{
+    let format = options.number_format();
     let radix = options.radix();
     let incorrect = options.incorrect();
     let lossy = options.lossy();
     let rounding = options.rounding();
+    let exponent_overflow_error = options.exponent_overflow_error();
+    let underflow_error = options.underflow_error();
+    let max_exponent_digits = options.max_exponent_digits();
+    let max_mantissa_digits = options.max_mantissa_digits();
+    let truncate_mantissa = options.truncate_mantissa();
+    let exact = options.exact();
     let nan = options.nan_string();
     let inf = options.inf_string();
     let infinity = options.infinity_string();
+    let trim_whitespace = options.trim_whitespace();
+    let start = match trim_whitespace {
+        true => skip_whitespace(bytes),
+        false => 0,
+    };
+    if options.empty_nan() && start == bytes.len() {
+        let metadata = ParseMetadata::new(false).with_span(start, start);
+        let metadata = match info {
+            true => metadata.with_info(ParseInfo::new(0, 0, false, true)),
+            false => metadata,
+        };
+        return Ok((F::NAN, start, metadata));
+    }
+    let sign_start = start;
+    let (start, string_sign) = skip_sign_string(bytes, start, options)?;
+    let (prefix_end, prefix_sign) = skip_prefix(bytes, start, options);
+    // A sign string is never followed by a sign, so at most one is set.
+    let prefix_sign = prefix_sign.or(string_sign);
+    let number_start = match prefix_sign {
+        Some(_) => sign_start,
+        None => prefix_end,
+    };
+    let (start, parentheses) = parse_parentheses(bytes, prefix_end, format)?;
+    let start = match parentheses && prefix_end == number_start {
+        // Skip a prefix inside the parentheses, like `($1.5)`.
+        true => start + skip_affix(&bytes[start..], options.skip_prefixes(), trim_whitespace),
+        false => start,
+    };
+    // The sign before a prefix, or a sign string, is the only sign of the number.
+    if prefix_sign.is_some() {
+        match bytes.get(start) {
+            Some(&b'+') | Some(&b'-') => return Err((ErrorCode::InvalidDigit, start).into()),
+            _ if parentheses => return Err((ErrorCode::InvalidDigit, prefix_end).into()),
+            _ => (),
+        }
+    }
     let result = apply_interface!(
         atof::<F, _>,
         format,
-        bytes,
+        &bytes[start..],
         radix,
         incorrect,
         lossy,
         rounding,
+        scratch,
+        exponent_overflow_error,
+        underflow_error,
+        max_exponent_digits,
+        max_mantissa_digits,
+        truncate_mantissa,
+        exact,
+        info,
         nan,
         inf,
         infinity
     );
     let index = |ptr| distance(bytes.as_ptr(), ptr);
     match result {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
+        Ok((value, ptr, metadata)) => {
+            let mut processed = index(ptr);
+            processed += suffix_len(bytes, processed, options);
+            let value = match parentheses {
+                true if bytes.get(processed) == Some(&b')') => {
+                    processed += 1;
+                    -value
+                },
+                true => return Err((ErrorCode::MissingClosingParenthesis, processed).into()),
+                false => value,
+            };
+            let value = match prefix_sign {
+                Some(Sign::Negative) => -value,
+                _ => value,
+            };
+            let number_end = processed;
+            if trim_whitespace {
+                processed += skip_whitespace(&bytes[processed..]);
+            }
+            let suffixes = options.skip_suffixes();
+            processed += skip_affix(&bytes[processed..], suffixes, trim_whitespace);
+            Ok((value, processed, metadata.with_span(number_start, number_end)))
+        },
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }
 
-// FROM LEXICAL
-// ------------
-
-from_lexical!(atof_default, f32);
-from_lexical!(atof_default, f64);
+// Atof from pre-tokenized components.
+#[inline]
+fn atof_from_parts<F>(
+    sign: Sign,
+    integer: &[u8],
+    fraction: &[u8],
+    exponent: i32,
+    options: &ParseFloatOptions,
+) -> Result<F>
+where
+    F: FloatType,
+    ExtendedFloat<F::MantissaType>: ModeratePathCache<F::MantissaType>,
+{
+    // Validate the digits, since they have not been through extraction.
+    let radix = options.radix();
+    let mut digits = integer.iter().chain(fraction.iter());
+    if let Some(index) = digits.position(|&c| !is_digit(c, radix)) {
+        return Err((ErrorCode::InvalidDigit, index).into());
+    } else if integer.is_empty() && fraction.is_empty() {
+        return Err((ErrorCode::EmptyMantissa, 0).into());
+    }
 
-from_lexical_with_options!(atof_with_options, f32);
-from_lexical_with_options!(atof_with_options, f64);
+    let mut data = StandardFastDataInterface::new(NumberFormat::STANDARD);
+    data.set_parts(integer, fraction, exponent);
+    let is_literal_zero = data.integer().is_empty() && data.fraction().unwrap_or(&[]).is_empty();
+    let float: F = algorithm::data_to_native(
+        data,
+        sign,
+        radix,
+        options.incorrect(),
+        options.lossy(),
+        options.rounding(),
+        None,
+    );
+    // Validate the float did not underflow, like `validate_clamped`.
+    // The exponent is already an `i32`, so it cannot overflow.
+    if options.underflow_error() && float.is_zero() && !is_literal_zero {
+        return Err((ErrorCode::Underflow, integer.len() + fraction.len()).into());
+    }
+    if options.exact() {
+        let mut data = StandardFastDataInterface::new(NumberFormat::STANDARD);
+        data.set_parts(integer, fraction, exponent);
+        if !is_exact(data.to_slow(0), radix, float) {
+            return Err((ErrorCode::Inexact, 0).into());
+        }
+    }
 
-// TESTS
-// -----
+    Ok(to_signed(float, sign))
+}
 
-#[cfg(test)]
-mod tests {
-    use crate::error::*;
-    use crate::traits::*;
-    use crate::util::*;
+// Atof with a reusable parser, for f32.
+#[inline(always)]
+fn atof32_with_parser(bytes: &[u8], parser: &mut FloatParser) -> Result<(f32, usize)> {
+    let (options, scratch) = parser.f32_parts();
+    let (value, processed, _) = atof_with_scratch(bytes, options, Some(scratch), false)?;
+    Ok((value, processed))
+}
 
-    use approx::assert_relative_eq;
-    #[cfg(feature = "property_tests")]
-    use proptest::{prop_assert, prop_assert_eq, proptest};
+// Atof with a reusable parser, for f64.
+#[inline(always)]
+fn atof64_with_parser(bytes: &[u8], parser: &mut FloatParser) -> Result<(f64, usize)> {
+    let (options, scratch) = parser.f64_parts();
+    let (value, processed, _) = atof_with_scratch(bytes, options, Some(scratch), false)?;
+    Ok((value, processed))
+}
 
-    #[test]
-    fn special_bytes_test() {
-        // Test serializing and deserializing special strings.
-        assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
-        assert!(f32::from_lexical(b"nan").unwrap().is_nan());
-        assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
-        assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
-        assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
-        assert!(f32::from_lexical(b"Infinity").unwrap().is_infinite());
+// CLASSIFY
+// Utilities to classify numbers without converting them.
 
-        let options = ParseFloatOptions::builder()
-            .nan_string(b"nan")
-            .inf_string(b"Infinity")
-            .build()
-            .unwrap();
+/// Check if the bytes are exactly the special string.
+#[inline]
+fn is_special<'a, Iter, StartsWith>(
+    bytes: &'a [u8],
+    digit_separator: u8,
+    special: &'static [u8],
+    starts_with: StartsWith,
+) -> bool
+where
+    Iter: Digits<'a>,
+    StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter),
+{
+    let (is_match, iter) = starts_with(Iter::new(bytes, digit_separator), special.iter());
+    is_match && iter.as_ptr() == bytes[bytes.len()..].as_ptr()
+}
 
-        // The error message depends on whether the radix feature is enabled.
-        assert!(f32::from_lexical_with_options(b"inf", &options).is_err());
-        assert!(f32::from_lexical_with_options(b"Infinity", &options).unwrap().is_infinite());
+/// Classify digits with the data interface, without converting them.
+#[inline]
+fn classify_digits<'a, Data>(data: &mut Data, bytes: &'a [u8], radix: u32) -> NumberClass
+where
+    Data: FastDataInterface<'a>,
+{
+    match data.extract(bytes, radix) {
+        Ok(ptr) if ptr != bytes[bytes.len()..].as_ptr() => NumberClass::Invalid,
+        Ok(_) if data.fraction().is_none() && data.exponent().is_none() => NumberClass::Integer,
+        Ok(_) => NumberClass::Float,
+        Err(_) => NumberClass::Invalid,
     }
+}
 
-    #[test]
-    #[cfg(feature = "rounding")]
-    fn special_rounding_test() {
-        // Each one of these pairs is halfway, and we can detect the
-        // rounding schemes from this.
+/// Classify special or float values.
+///
+/// Uses the same predictive matching as `parse_float_special`.
+#[inline(always)]
+fn classify_special<'a, Iter, StartsWith, Data>(
+    data: &mut Data,
+    bytes: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+    starts_with: StartsWith,
+) -> NumberClass
+where
+    Iter: Digits<'a>,
+    StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter),
+    Data: FastDataInterface<'a>,
+{
+    let digit_separator = data.format().digit_separator();
+    let is_special = |special| is_special::<Iter, _>(bytes, digit_separator, special, &starts_with);
+    match Iter::new(bytes, digit_separator).next() {
+        Some(&b'i') | Some(&b'I') if is_special(infinity_string) || is_special(inf_string) => {
+            NumberClass::Infinity
+        },
+        Some(&b'n') | Some(&b'N') if is_special(nan_string) => NumberClass::Nan,
+        _ => classify_digits(data, bytes, radix),
+    }
+}
 
-        // Nearest, tie-even
-        let options =
-            ParseFloatOptions::builder().rounding(RoundingKind::NearestTieEven).build().unwrap();
-        assert_eq!(
-            f64::from_lexical_with_options(b"-9007199254740993", &options).unwrap(),
-            -9007199254740992.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"-9007199254740995", &options).unwrap(),
-            -9007199254740996.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"9007199254740993", &options).unwrap(),
-            9007199254740992.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"9007199254740995", &options).unwrap(),
-            9007199254740996.0
-        );
+/// Classify special or float values with the default formatter.
+#[inline(always)]
+#[cfg(not(feature = "format"))]
+fn classify_float<'a, Data>(
+    data: &mut Data,
+    bytes: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> NumberClass
+where
+    Data: FastDataInterface<'a>,
+{
+    // Special values are case-insensitive, without digit separators.
+    classify_special::<ContiguousDigits, _, Data>(
+        data,
+        bytes,
+        radix,
+        nan_string,
+        inf_string,
+        infinity_string,
+        case_insensitive_starts_with_iter,
+    )
+}
 
-        // Nearest, tie-away-zero
-        let options = ParseFloatOptions::builder()
-            .rounding(RoundingKind::NearestTieAwayZero)
-            .build()
-            .unwrap();
-        assert_eq!(
-            f64::from_lexical_with_options(b"-9007199254740993", &options).unwrap(),
-            -9007199254740994.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"-9007199254740995", &options).unwrap(),
-            -9007199254740996.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"9007199254740993", &options).unwrap(),
-            9007199254740994.0
-        );
-        assert_eq!(
-            f64::from_lexical_with_options(b"9007199254740995", &options).unwrap(),
-            9007199254740996.0
-        );
+/// Classify special or float values with the default formatter.
+#[inline(always)]
+#[cfg(feature = "format")]
+fn classify_float<'a, Data>(
+    data: &mut Data,
+    bytes: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> NumberClass
+where
+    Data: FastDataInterface<'a>,
+{
+    // Consider the same possibilities as `parse_float`.
+    let format = data.format();
+    let no_special = format.no_special();
+    let case = format.case_sensitive_special();
+    let has_sep = format.special_digit_separator();
+    match (no_special, case, has_sep) {
+        (true, _, _) => classify_digits(data, bytes, radix),
+        (false, true, true) => classify_special::<SeparatorDigits, _, Data>(
+            data,
+            bytes,
+            radix,
+            nan_string,
+            inf_string,
+            infinity_string,
+            starts_with_iter,
+        ),
+        (false, false, true) => classify_special::<SeparatorDigits, _, Data>(
+            data,
+            bytes,
+            radix,
+            nan_string,
+            inf_string,
+            infinity_string,
+            case_insensitive_starts_with_iter,
+        ),
+        (false, true, false) => classify_special::<ContiguousDigits, _, Data>(
+            data,
+            bytes,
+            radix,
+            nan_string,
+            inf_string,
+            infinity_string,
+            starts_with_iter,
+        ),
+        (false, false, false) => classify_special::<ContiguousDigits, _, Data>(
+            data,
+            bytes,
+            radix,
+            nan_string,
+            inf_string,
+            infinity_string,
+            case_insensitive_starts_with_iter,
+        ),
+    }
+}
 
-        // Toward positive infinity
+/// Scan the sign and classify the number, extracting the digits into the data.
+///
+/// The sign is validated like `atof`.
+#[inline]
+fn scan_number<'a, Data>(
+    data: &mut Data,
+    bytes: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> (Sign, NumberClass)
+where
+    Data: FastDataInterface<'a>,
+{
+    let format = data.format();
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() || validate_sign(bytes, digits, sign, format).is_err() {
+        return (sign, NumberClass::Invalid);
+    }
+    let class = classify_float(data, digits, radix, nan_string, inf_string, infinity_string);
+    (sign, class)
+}
+
+/// Standalone classifier.
+#[inline]
+fn classify<'a, Data>(
+    mut data: Data,
+    bytes: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> NumberClass
+where
+    Data: FastDataInterface<'a>,
+{
+    scan_number(&mut data, bytes, radix, nan_string, inf_string, infinity_string).1
+}
+
+/// Classify the complete bytes as a number in the format of the options.
+///
+/// Only the number format, radix and special strings of the options are
+/// used: whitespace, affixes and parentheses are not skipped.
+#[inline]
+pub(crate) fn classify_with_options(bytes: &[u8], options: &ParseFloatOptions) -> NumberClass {
+    apply_interface!(
+        classify,
+        options.number_format(),
+        bytes,
+        options.radix(),
+        options.nan_string(),
+        options.inf_string(),
+        options.infinity_string()
+    )
+}
+
+// RAW
+// Utilities to scan numbers without converting them.
+
+/// Get the end of the digits and digit separators at the start of the bytes.
+#[inline]
+fn digits_end(bytes: &[u8], start: usize, end: usize, radix: u32, digit_separator: u8) -> usize {
+    let is_digit = |&&c: &&u8| is_digit(c, radix) || (c == digit_separator && c != 0);
+    start + bytes[start..end].iter().take_while(is_digit).count()
+}
+
+/// Scan the sign, digits and exponent of a number, without converting them.
+///
+/// The sign is validated like `atof`, and the parts are stored verbatim,
+/// including any digit separators and leading or trailing zeros.
+#[inline]
+fn scan_raw<'a, Data>(
+    mut data: Data,
+    bytes: &'a [u8],
+    radix: u32,
+) -> ParseResult<(RawNumber<&'a [u8]>, *const u8)>
+where
+    Data: FastDataInterface<'a>,
+{
+    let format = data.format();
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+    let ptr = data.extract(digits, radix)?;
+    validate_sign(bytes, digits, sign, format)?;
+
+    // The fraction and exponent are only trimmed at the end, so they
+    // start directly after the decimal point and exponent character.
+    let index = |ptr| distance(bytes.as_ptr(), ptr);
+    let start = index(digits.as_ptr());
+    let end = index(ptr);
+    let digit_separator = format.digit_separator();
+    let (integer, fraction) = match data.fraction() {
+        Some(fraction) => {
+            let fraction_start = index(fraction.as_ptr());
+            let fraction_end = digits_end(bytes, fraction_start, end, radix, digit_separator);
+            ((start, fraction_start - 1), Some((fraction_start, fraction_end)))
+        },
+        None => ((start, digits_end(bytes, start, end, radix, digit_separator)), None),
+    };
+    let exponent = data.exponent().map(|exponent| {
+        let exponent_start = index(exponent.as_ptr());
+        (exponent_start, exponent_start + exponent.len())
+    });
+    let raw = RawNumber::new(&bytes[..end], sign, integer, fraction, exponent, format, radix);
+    Ok((raw, ptr))
+}
+
+/// Scan a number in the format of the options, without converting it.
+///
+/// Only the number format and radix of the options are used: whitespace,
+/// affixes, parentheses and special values are not parsed.
+#[inline]
+pub(crate) fn scan_raw_with_options<'a>(
+    bytes: &'a [u8],
+    options: &ParseFloatOptions,
+) -> Result<(RawNumber<&'a [u8]>, usize)> {
+    let result = apply_interface!(scan_raw, options.number_format(), bytes, options.radix());
+    match result {
+        Ok((raw, ptr)) => Ok((raw, distance(bytes.as_ptr(), ptr))),
+        Err((code, ptr)) => Err((code, distance(bytes.as_ptr(), ptr)).into()),
+    }
+}
+
+// COMPARE
+// Utilities to compare numbers without converting them.
+
+/// Check if the extracted digits are all zero.
+#[inline]
+fn is_zero_digits<'a, Data>(data: &Data) -> bool
+where
+    Data: FastDataInterface<'a>,
+{
+    data.integer_iter().chain(data.fraction_iter()).all(|&c| c == b'0')
+}
+
+/// Get the signum of a scanned number, or `None` if it is unordered.
+///
+/// Zeros have no sign, so `-0` and `0` are equal.
+#[inline]
+fn signum<'a, Data>(data: &Data, sign: Sign, class: NumberClass) -> Option<i8>
+where
+    Data: FastDataInterface<'a>,
+{
+    match class {
+        NumberClass::Nan | NumberClass::Invalid => None,
+        NumberClass::Integer | NumberClass::Float if is_zero_digits(data) => Some(0),
+        _ if sign == Sign::Negative => Some(-1),
+        _ => Some(1),
+    }
+}
+
+/// Get the shift from the raw exponent to the scientific exponent.
+#[inline]
+fn scientific_shift<'a, Data>(data: &Data) -> i128
+where
+    Data: SlowDataInterface<'a>,
+{
+    match data.integer_digits() {
+        0 => -(data.digits_start() as i128) - 1,
+        count => count as i128 - 1,
+    }
+}
+
+/// Iterate over the digits of an exponent, skipping the sign and digit separators.
+#[inline]
+fn exponent_digits(bytes: &[u8], radix: u32) -> impl Iterator<Item = u32> + '_ {
+    bytes.iter().filter_map(move |&c| to_digit(c, radix))
+}
+
+/// Compare the scientific exponents of non-zero, finite numbers.
+///
+/// The raw exponents saturate when parsed, so the exponent digits are
+/// compared instead, as `x_exponent + x_shift` and `y_exponent + y_shift`.
+/// The difference of the exponents is accumulated from the most
+/// significant digit, and once it exceeds any difference of the shifts,
+/// it can only grow, so the remaining digits are not needed.
+#[inline]
+fn compare_exponents(
+    x_exponent: &[u8],
+    x_shift: i128,
+    y_exponent: &[u8],
+    y_shift: i128,
+    radix: u32,
+) -> cmp::Ordering {
+    const LIMIT: i128 = 1 << 70;
+
+    let signum = |bytes: &[u8]| match bytes.contains(&b'-') {
+        true => -1,
+        false => 1,
+    };
+    let x_signum = signum(x_exponent);
+    let y_signum = signum(y_exponent);
+    let x_digits = exponent_digits(x_exponent, radix).count();
+    let y_digits = exponent_digits(y_exponent, radix).count();
+    let count = x_digits.max(y_digits);
+
+    // Align the digits, padding the shorter exponent with leading zeros.
+    let x_padding = iter::repeat(0).take(count - x_digits);
+    let y_padding = iter::repeat(0).take(count - y_digits);
+    let x_iter = x_padding.chain(exponent_digits(x_exponent, radix));
+    let y_iter = y_padding.chain(exponent_digits(y_exponent, radix));
+    let mut difference: i128 = 0;
+    for (x_digit, y_digit) in x_iter.zip(y_iter) {
+        difference = difference * radix as i128 + x_signum * x_digit as i128;
+        difference -= y_signum * y_digit as i128;
+        if difference.abs() > LIMIT {
+            return difference.cmp(&0);
+        }
+    }
+    (difference + x_shift - y_shift).cmp(&0)
+}
+
+/// Compare the magnitudes of non-zero, finite numbers.
+///
+/// Compares the scientific exponents, and then the significant digits,
+/// so the digits are never rounded.
+#[inline]
+fn compare_digits<'a, Data>(x: Data, y: Data, radix: u32) -> cmp::Ordering
+where
+    Data: FastDataInterface<'a>,
+{
+    let x_exponent = x.exponent().unwrap_or(&[]);
+    let y_exponent = y.exponent().unwrap_or(&[]);
+    let x = x.to_slow(0);
+    let y = y.to_slow(0);
+    let x_shift = scientific_shift(&x);
+    let y_shift = scientific_shift(&y);
+    let exponent = compare_exponents(x_exponent, x_shift, y_exponent, y_shift, radix);
+    if exponent != cmp::Ordering::Equal {
+        return exponent;
+    }
+
+    // Compare the significant digits, padding the shorter with zeros.
+    let digit = |c: Option<&u8>| c.and_then(|&c| to_digit(c, radix)).unwrap_or(0);
+    let mut x_iter = x.integer_iter().chain(x.significant_fraction_iter());
+    let mut y_iter = y.integer_iter().chain(y.significant_fraction_iter());
+    loop {
+        match (x_iter.next(), y_iter.next()) {
+            (None, None) => return cmp::Ordering::Equal,
+            (xc, yc) => match digit(xc).cmp(&digit(yc)) {
+                cmp::Ordering::Equal => (),
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+/// Standalone comparison of two numbers in the same format.
+#[inline]
+fn compare_numeric<'a, Data>(
+    mut x_data: Data,
+    x: &'a [u8],
+    y: &'a [u8],
+    radix: u32,
+    nan_string: &'static [u8],
+    inf_string: &'static [u8],
+    infinity_string: &'static [u8],
+) -> Option<cmp::Ordering>
+where
+    Data: FastDataInterface<'a>,
+{
+    let mut y_data = Data::new(x_data.format());
+    let (x_sign, x_class) =
+        scan_number(&mut x_data, x, radix, nan_string, inf_string, infinity_string);
+    let (y_sign, y_class) =
+        scan_number(&mut y_data, y, radix, nan_string, inf_string, infinity_string);
+    let x_signum = signum(&x_data, x_sign, x_class)?;
+    let y_signum = signum(&y_data, y_sign, y_class)?;
+    if x_signum != y_signum || x_signum == 0 {
+        return Some(x_signum.cmp(&y_signum));
+    }
+
+    let magnitude = match (x_class, y_class) {
+        (NumberClass::Infinity, NumberClass::Infinity) => cmp::Ordering::Equal,
+        (NumberClass::Infinity, _) => cmp::Ordering::Greater,
+        (_, NumberClass::Infinity) => cmp::Ordering::Less,
+        _ => compare_digits(x_data, y_data, radix),
+    };
+    match x_signum {
+        -1 => Some(magnitude.reverse()),
+        _ => Some(magnitude),
+    }
+}
+
+/// Compare the complete bytes as numbers in the format of the options.
+///
+/// Like `classify_with_options`, only the number format, radix and
+/// special strings of the options are used.
+#[inline]
+pub(crate) fn compare_with_options(
+    x: &[u8],
+    y: &[u8],
+    options: &ParseFloatOptions,
+) -> Option<cmp::Ordering> {
+    apply_interface!(
+        compare_numeric,
+        options.number_format(),
+        x,
+        y,
+        options.radix(),
+        options.nan_string(),
+        options.inf_string(),
+        options.infinity_string()
+    )
+}
+
+// FROM LEXICAL
+// ------------
+
+from_lexical!(atof_default, f32);
+from_lexical!(atof_default, f64);
+
+from_lexical_with_options!(atof_with_options, f32);
+from_lexical_with_options!(atof_with_options, f64);
+
+from_lexical_with_metadata!(atof_with_metadata, f32);
+from_lexical_with_metadata!(atof_with_metadata, f64);
+from_lexical_with_info!(atof_with_info, f32);
+from_lexical_with_info!(atof_with_info, f64);
+
+from_parts!(atof_from_parts, f32);
+from_parts!(atof_from_parts, f64);
+
+from_lexical_with_parser!(atof32_with_parser, f32);
+from_lexical_with_parser!(atof64_with_parser, f64);
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::atof::FloatParser;
+    use crate::error::*;
+    use crate::traits::*;
+    use crate::util::*;
+
+    use approx::assert_relative_eq;
+    #[cfg(feature = "property_tests")]
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    #[test]
+    fn special_bytes_test() {
+        // Test serializing and deserializing special strings.
+        assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"nan").unwrap().is_nan());
+        assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"Infinity").unwrap().is_infinite());
+
+        let options = ParseFloatOptions::builder()
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .build()
+            .unwrap();
+
+        // The error message depends on whether the radix feature is enabled.
+        assert!(f32::from_lexical_with_options(b"inf", &options).is_err());
+        assert!(f32::from_lexical_with_options(b"Infinity", &options).unwrap().is_infinite());
+    }
+
+    #[test]
+    #[cfg(feature = "rounding")]
+    fn special_rounding_test() {
+        // Each one of these pairs is halfway, and we can detect the
+        // rounding schemes from this.
+
+        // Nearest, tie-even
+        let options =
+            ParseFloatOptions::builder().rounding(RoundingKind::NearestTieEven).build().unwrap();
+        assert_eq!(
+            f64::from_lexical_with_options(b"-9007199254740993", &options).unwrap(),
+            -9007199254740992.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"-9007199254740995", &options).unwrap(),
+            -9007199254740996.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"9007199254740993", &options).unwrap(),
+            9007199254740992.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"9007199254740995", &options).unwrap(),
+            9007199254740996.0
+        );
+
+        // Nearest, tie-away-zero
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::NearestTieAwayZero)
+            .build()
+            .unwrap();
+        assert_eq!(
+            f64::from_lexical_with_options(b"-9007199254740993", &options).unwrap(),
+            -9007199254740994.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"-9007199254740995", &options).unwrap(),
+            -9007199254740996.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"9007199254740993", &options).unwrap(),
+            9007199254740994.0
+        );
+        assert_eq!(
+            f64::from_lexical_with_options(b"9007199254740995", &options).unwrap(),
+            9007199254740996.0
+        );
+
+        // Toward positive infinity
         let options = ParseFloatOptions::builder()
             .rounding(RoundingKind::TowardPositiveInfinity)
             .build()
@@ -735,6 +1693,68 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "rounding")]
+    fn directed_rounding_test() {
+        // Inexact values, which the fast path would round to nearest,
+        // and exact values, which are never rounded.
+        let parse = |bytes: &[u8], rounding| {
+            let options = ParseFloatOptions::builder().rounding(rounding).build().unwrap();
+            f64::from_lexical_with_options(bytes, &options).unwrap()
+        };
+        assert_eq!(parse(b"0.1", RoundingKind::TowardZero), 0.09999999999999999);
+        assert_eq!(parse(b"0.1", RoundingKind::TowardPositiveInfinity), 0.1);
+        assert_eq!(parse(b"-0.1", RoundingKind::TowardNegativeInfinity), -0.1);
+        assert_eq!(parse(b"-0.1", RoundingKind::TowardPositiveInfinity), -0.09999999999999999);
+        assert_eq!(parse(b"0.3", RoundingKind::TowardPositiveInfinity), 0.30000000000000004);
+        assert_eq!(parse(b"0.3", RoundingKind::TowardZero), 0.3);
+        for &rounding in &[RoundingKind::TowardPositiveInfinity, RoundingKind::TowardZero] {
+            assert_eq!(parse(b"1.5", rounding), 1.5);
+            assert_eq!(parse(b"123456.75", rounding), 123456.75);
+            assert_eq!(parse(b"-2.5e-1", rounding), -0.25);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rounding")]
+    fn tie_away_zero_test() {
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::NearestTieAwayZero)
+            .build()
+            .unwrap();
+        let parse = |bytes: &[u8]| f64::from_lexical_with_options(bytes, &options).unwrap();
+
+        // Halfway cases the fast path would round to even.
+        assert_eq!(parse(b"18014398509482010"), 18014398509482012.0);
+        assert_eq!(parse(b"-18014398509482010"), -18014398509482012.0);
+        assert_eq!(parse(b"4503599627370496.5"), 4503599627370497.0);
+        // Halfway cases in the moderate and slow paths.
+        assert_eq!(parse(b"9007199254740993.000"), 9007199254740994.0);
+        assert_eq!(parse(b"9007199254740992.999"), 9007199254740992.0);
+        // Below halfway.
+        assert_eq!(parse(b"18014398509482009"), 18014398509482008.0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "power_of_two", feature = "rounding"))]
+    fn truncated_rounding_binary_test() {
+        // `1 << 73` plus halfway to the next float, with or without a
+        // non-zero bit past the 64-bit truncated mantissa.
+        let halfway = b"100000000000000000000000000000000000000000000000000001000000000000000000000";
+        let above = b"100000000000000000000000000000000000000000000000000001000000000000000000001";
+        let parse = |bytes: &[u8], rounding| {
+            let builder = ParseFloatOptions::builder().radix(2).rounding(rounding);
+            f64::from_lexical_with_options(bytes, &builder.build().unwrap()).unwrap()
+        };
+        let (below, next) = (2f64.powi(74), 2f64.powi(74) + 2f64.powi(22));
+        assert_eq!(parse(halfway, RoundingKind::NearestTieEven), below);
+        assert_eq!(parse(above, RoundingKind::NearestTieEven), next);
+        assert_eq!(parse(halfway, RoundingKind::NearestTieAwayZero), next);
+        assert_eq!(parse(above, RoundingKind::NearestTieAwayZero), next);
+        assert_eq!(parse(halfway, RoundingKind::TowardZero), below);
+        assert_eq!(parse(above, RoundingKind::TowardPositiveInfinity), next);
+    }
+
     #[test]
     #[cfg(all(feature = "power_of_two", feature = "rounding"))]
     fn special_rounding_binary_test() {
@@ -993,168 +2013,1074 @@ mod tests {
             f32::from_lexical(b"0.000000000000000000000000000000000000012345").unwrap()
         );
 
-        assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
-        assert!(f32::from_lexical(b"nan").unwrap().is_nan());
-        assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
-        assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
-        assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
-        assert!(f32::from_lexical(b"+inf").unwrap().is_infinite());
-        assert!(f32::from_lexical(b"-inf").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"NaN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"nan").unwrap().is_nan());
+        assert!(f32::from_lexical(b"NAN").unwrap().is_nan());
+        assert!(f32::from_lexical(b"inf").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"INF").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"+inf").unwrap().is_infinite());
+        assert!(f32::from_lexical(b"-inf").unwrap().is_infinite());
+
+        // Check various expected failures.
+        assert_eq!(Err(ErrorCode::Empty.into()), f32::from_lexical(b""));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"E"));
+        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e1"));
+        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e-1"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e1"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e-1"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical(b"+"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical(b"-"));
+
+        // Bug fix for Issue #8
+        assert_eq!(Ok(5.002868148396374), f32::from_lexical(b"5.002868148396374"));
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn f32_radix_test() {
+        let options = ParseFloatOptions::builder().radix(36).build().unwrap();
+        assert_f32_eq!(1234.0, f32::from_lexical_with_options(b"YA", &options).unwrap());
+        let options = options.rebuild().lossy(true).build().unwrap();
+        assert_f32_eq!(1234.0, f32::from_lexical_with_options(b"YA", &options).unwrap());
+    }
+
+    #[test]
+    fn f64_decimal_test() {
+        // integer test
+        assert_f64_eq!(0.0, f64::from_lexical(b"0").unwrap());
+        assert_f64_eq!(1.0, f64::from_lexical(b"1").unwrap());
+        assert_f64_eq!(12.0, f64::from_lexical(b"12").unwrap());
+        assert_f64_eq!(123.0, f64::from_lexical(b"123").unwrap());
+        assert_f64_eq!(1234.0, f64::from_lexical(b"1234").unwrap());
+        assert_f64_eq!(12345.0, f64::from_lexical(b"12345").unwrap());
+        assert_f64_eq!(123456.0, f64::from_lexical(b"123456").unwrap());
+        assert_f64_eq!(1234567.0, f64::from_lexical(b"1234567").unwrap());
+        assert_f64_eq!(12345678.0, f64::from_lexical(b"12345678").unwrap());
+
+        // No fraction after decimal point test
+        assert_f64_eq!(1.0, f64::from_lexical(b"1.").unwrap());
+        assert_f64_eq!(12.0, f64::from_lexical(b"12.").unwrap());
+        assert_f64_eq!(1234567.0, f64::from_lexical(b"1234567.").unwrap());
+
+        // No integer before decimal point test
+        assert_f64_eq!(0.1, f64::from_lexical(b".1").unwrap());
+        assert_f64_eq!(0.12, f64::from_lexical(b".12").unwrap());
+        assert_f64_eq!(0.1234567, f64::from_lexical(b".1234567").unwrap());
+
+        // decimal test
+        assert_f64_eq!(123456789.0, f64::from_lexical(b"123456789").unwrap());
+        assert_f64_eq!(123456789.1, f64::from_lexical(b"123456789.1").unwrap());
+        assert_f64_eq!(123456789.12, f64::from_lexical(b"123456789.12").unwrap());
+        assert_f64_eq!(123456789.123, f64::from_lexical(b"123456789.123").unwrap());
+        assert_f64_eq!(123456789.1234, f64::from_lexical(b"123456789.1234").unwrap());
+        assert_f64_eq!(123456789.12345, f64::from_lexical(b"123456789.12345").unwrap());
+        assert_f64_eq!(123456789.123456, f64::from_lexical(b"123456789.123456").unwrap());
+        assert_f64_eq!(123456789.1234567, f64::from_lexical(b"123456789.1234567").unwrap());
+        assert_f64_eq!(123456789.12345678, f64::from_lexical(b"123456789.12345678").unwrap());
+
+        // rounding test
+        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.123456789").unwrap());
+        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.1234567890").unwrap());
+        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.123456789012").unwrap());
+        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.1234567890123").unwrap());
+        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.12345678901234").unwrap());
+
+        // exponent test
+        assert_f64_eq!(123456789.12345, f64::from_lexical(b"1.2345678912345e8").unwrap());
+        assert_f64_eq!(123450000.0, f64::from_lexical(b"1.2345e+8").unwrap());
+        assert_f64_eq!(1.2345e+11, f64::from_lexical(b"123450000000").unwrap());
+        assert_f64_eq!(1.2345e+11, f64::from_lexical(b"1.2345e+11").unwrap());
+        assert_f64_eq!(1.2345e+38, f64::from_lexical(b"1.2345e+38").unwrap());
+        assert_f64_eq!(
+            1.2345e+38,
+            f64::from_lexical(b"123450000000000000000000000000000000000").unwrap()
+        );
+        assert_f64_eq!(1.2345e+308, f64::from_lexical(b"1.2345e+308").unwrap());
+        assert_f64_eq!(1.2345e+308, f64::from_lexical(b"123450000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap());
+        assert_f64_eq!(0.000000012345, f64::from_lexical(b"1.2345e-8").unwrap());
+        assert_f64_eq!(1.2345e-8, f64::from_lexical(b"0.000000012345").unwrap());
+        assert_f64_eq!(1.2345e-38, f64::from_lexical(b"1.2345e-38").unwrap());
+        assert_f64_eq!(
+            1.2345e-38,
+            f64::from_lexical(b"0.000000000000000000000000000000000000012345").unwrap()
+        );
+
+        // denormalized (try extremely low values)
+        assert_f64_eq!(1.2345e-308, f64::from_lexical(b"1.2345e-308").unwrap());
+        // Denormal results of the incorrect parser use the moderate path.
+        let options = ParseFloatOptions::builder().incorrect(true).build().unwrap();
+        assert_eq!(Ok(5e-322), f64::from_lexical_with_options(b"5e-322", &options));
+        assert_eq!(Ok(5e-323), f64::from_lexical_with_options(b"5e-323", &options));
+        assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"5e-324", &options));
+        // due to issues in how the data is parsed, manually extracting
+        // non-exponents of 1.<e-299 is prone to error
+        // test the limit of our ability
+        // We tend to get relative errors of 1e-16, even at super low values.
+        assert_f64_eq!(1.2345e-299, f64::from_lexical(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345").unwrap(), epsilon=1e-314);
+
+        // Keep pushing from -300 to -324
+        assert_f64_eq!(1.2345e-300, f64::from_lexical(b"0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345").unwrap(), epsilon=1e-315);
+
+        assert_f64_near_eq!(1.2345e-310, f64::from_lexical_with_options(b"0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
+        assert_f64_near_eq!(1.2345e-320, f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
+        assert_f64_near_eq!(1.2345e-321, f64::from_lexical_with_options(b"0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
+        assert_f64_near_eq!(1.24e-322, f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000124", &options).unwrap(), epsilon=5e-324);
+        assert_eq!(Ok(1e-323), f64::from_lexical_with_options(b"0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", &options));
+        assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005", &options));
+
+        assert!(f64::from_lexical(b"NaN").unwrap().is_nan());
+        assert!(f64::from_lexical(b"nan").unwrap().is_nan());
+        assert!(f64::from_lexical(b"NAN").unwrap().is_nan());
+        assert!(f64::from_lexical(b"inf").unwrap().is_infinite());
+        assert!(f64::from_lexical(b"INF").unwrap().is_infinite());
+        assert!(f64::from_lexical(b"+inf").unwrap().is_infinite());
+        assert!(f64::from_lexical(b"-inf").unwrap().is_infinite());
+
+        // Check various expected failures.
+        assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical(b""));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E"));
+        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e1"));
+        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e-1"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e1"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e-1"));
+
+        // Check various reports from a fuzzer.
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical(b"0.0e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".E"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E2252525225"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e2252525225"));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"2E200000000000"));
+
+        // Add various unittests from proptests.
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"."));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"+."));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"-."));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"+"));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"-"));
+
+        // Bug fix for Issue #8
+        assert_eq!(Ok(5.002868148396374), f64::from_lexical(b"5.002868148396374"));
+    }
+
+    #[test]
+    fn f64_exponent_overflow_test() {
+        let options = ParseFloatOptions::builder().exponent_overflow_error(true).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 2).into()),
+            f64::from_lexical_with_options(b"2E200000000000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 3).into()),
+            f64::from_lexical_with_options(b"-2e-200000000000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 5).into()),
+            f64::from_lexical_with_options(b"+1.5e+99999999999", &options)
+        );
+
+        // Clamped values within the exponent range are still valid.
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1e400", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"1e-400", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"0e200000000000", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"0.000e-200000000000", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+
+        // Exponents that fit in an `i32` do not overflow.
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1e2147483647", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"1e-2147483648", &options));
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 2).into()),
+            f64::from_lexical_with_options(b"1e2147483648", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 2).into()),
+            f64::from_lexical_with_options(b"1e-2147483649", &options)
+        );
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1e0002147483647", &options));
+
+        // The default behavior clamps the value.
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"2E200000000000"));
+        assert_eq!(Ok(0.0), f64::from_lexical(b"2E-200000000000"));
+    }
+
+    #[test]
+    fn f64_underflow_test() {
+        let options = ParseFloatOptions::builder().underflow_error(true).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::Underflow, 2).into()),
+            f64::from_lexical_with_options(b"1e-5000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::Underflow, 5).into()),
+            f64::from_lexical_with_options(b"-2.5e-400", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::Underflow, 2).into()),
+            f64::from_lexical_with_options(b"2E-200000000000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::Underflow, 0).into()),
+            f32::from_lexical_with_options(
+                b"0.0000000000000000000000000000000000000000000000001",
+                &options
+            )
+        );
+
+        // Denormal and zero values are valid.
+        assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"5e-324", &options));
+        assert_eq!(Ok(0.0), f64::from_lexical_with_options(b"0.0e-5000", &options));
+        assert_eq!(Ok(-0.0), f64::from_lexical_with_options(b"-0", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1e5000", &options));
+
+        // Exponent overflow takes precedence.
+        let options = options.rebuild().exponent_overflow_error(true).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::ExponentOverflow, 2).into()),
+            f64::from_lexical_with_options(b"2E-200000000000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::Underflow, 2).into()),
+            f64::from_lexical_with_options(b"1e-5000", &options)
+        );
+    }
+
+    #[test]
+    fn f64_max_exponent_digits_test() {
+        let options = ParseFloatOptions::builder().max_exponent_digits(Some(3)).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 5).into()),
+            f64::from_lexical_with_options(b"1e1000", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 9).into()),
+            f64::from_lexical_with_options(b"-1.5e-0001", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 8).into()),
+            f64::from_lexical_partial_with_options(b"1.5E+1234abc", &options)
+        );
+
+        // Exponents within the limit, and floats without exponents, are valid.
+        assert_eq!(Ok(1e300), f64::from_lexical_with_options(b"1e300", &options));
+        assert_eq!(Ok(-1.5e-1), f64::from_lexical_with_options(b"-1.5e-001", &options));
+        assert_eq!(Ok(12345.0), f64::from_lexical_with_options(b"12345", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"inf", &options));
+        assert_eq!(
+            Err((ErrorCode::EmptyExponent, 2).into()),
+            f64::from_lexical_with_options(b"1e", &options)
+        );
+
+        // The default has no limit.
+        assert_eq!(Ok(1.0), f64::from_lexical(b"1e0000000000000000000000000"));
+        assert!(ParseFloatOptions::builder().max_exponent_digits(Some(0)).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn f64_max_exponent_digits_backup_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(2)
+            .max_exponent_digits(Some(3))
+            .build()
+            .unwrap();
+        assert_eq!(Ok(48.0), f64::from_lexical_with_options(b"1.1^101", &options));
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 7).into()),
+            f64::from_lexical_with_options(b"1.1^1010", &options)
+        );
+
+        let options = options.rebuild().exponent_backup(Some(b'#')).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 7).into()),
+            f64::from_lexical_with_options(b"1.1#1010", &options)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_max_exponent_digits_separator_test() {
+        let format = NumberFormat::from_spec("separator=_,exponent_internal_digit_separator");
+        let options = ParseFloatOptions::builder()
+            .format(format)
+            .max_exponent_digits(Some(3))
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1e100), f64::from_lexical_with_options(b"1e1_0_0", &options));
+        assert_eq!(
+            Err((ErrorCode::TooManyExponentDigits, 7).into()),
+            f64::from_lexical_with_options(b"1e1_0_00", &options)
+        );
+    }
+
+    #[test]
+    fn f64_trim_whitespace_test() {
+        let options = ParseFloatOptions::builder().trim_whitespace(true).build().unwrap();
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b" 1.5 ", &options));
+        assert_eq!(Ok(-2500.0), f64::from_lexical_with_options(b"\t\n-2.5e3\r\n", &options));
+        let bytes = "\u{feff}\u{3000}7\u{a0}\u{2029}".as_bytes();
+        assert_eq!(Ok(7.0), f64::from_lexical_with_options(bytes, &options));
+        assert_eq!(Ok((3.25, 7)), f64::from_lexical_partial_with_options(b"  3.25 xyz", &options));
+        assert_eq!(
+            Err((ErrorCode::Empty, 2).into()),
+            f64::from_lexical_with_options(b"  ", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 5).into()),
+            f64::from_lexical_with_options(b" 1.5 2", &options)
+        );
+
+        // Whitespace is invalid by default.
+        assert_eq!(
+            Err((ErrorCode::EmptyMantissa, 0).into()),
+            f64::from_lexical(b" 1.5")
+        );
+    }
+
+    #[test]
+    fn f64_skip_affixes_test() {
+        let options = ParseFloatOptions::builder()
+            .skip_prefixes(&[b"$", b"\xE2\x82\xAC", b"US$"])
+            .skip_suffixes(&[b"%", b"k", b"kg"])
+            .build()
+            .unwrap();
+        let parse = |bytes: &[u8]| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1.5), parse(b"$1.5"));
+        assert_eq!(Ok(1.5), parse("\u{20ac}1.5".as_bytes()));
+        assert_eq!(Ok(1.5), parse(b"US$1.5"));
+        assert_eq!(Ok(15.0), parse(b"15%"));
+        assert_eq!(Ok(2.5), parse(b"2.5kg"));
+        assert_eq!(Ok(-1.5), parse(b"$-1.5%"));
+        assert_eq!(Ok(-1.5), parse(b"-$1.5"));
+        assert_eq!(Ok(1.5), parse(b"+$1.5"));
+        assert_eq!(Ok(1.5), parse(b"1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parse(b"-$-1.5"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parse(b"$$1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse(b"1.5%%"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parse(b"$ 1.5"));
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_with_options(b"$1.5%,2", &options));
+
+        // Whitespace is skipped around the affixes when trimming.
+        let options = options.rebuild().trim_whitespace(true).build().unwrap();
+        let parse = |bytes: &[u8]| f64::from_lexical_with_metadata(bytes, &options);
+        let (value, metadata) = parse(b" $ 1.5 kg ").unwrap();
+        assert_eq!(value, 1.5);
+        assert_eq!((metadata.start(), metadata.end()), (3, 6));
+        let (value, metadata) = parse(b"-$ 1.5").unwrap();
+        assert_eq!(value, -1.5);
+        assert_eq!((metadata.start(), metadata.end()), (0, 6));
+
+        // Prefixes may be inside parentheses for negative values.
+        #[cfg(feature = "format")]
+        {
+            let format = NumberFormat::STANDARD | NumberFormat::NEGATIVE_PARENTHESES;
+            let options = options.rebuild().format(Some(format)).build().unwrap();
+            let parse = |bytes: &[u8]| f64::from_lexical_with_options(bytes, &options);
+            assert_eq!(Ok(-1.5), parse(b"$(1.5)"));
+            assert_eq!(Ok(-1.5), parse(b"($1.5)"));
+            assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), parse(b"-$(1.5)"));
+        }
+
+        // Affixes must not start with part of the number.
+        let builder = ParseFloatOptions::builder();
+        assert!(builder.skip_prefixes(&[b""]).build().is_none());
+        assert!(builder.skip_prefixes(&[b"-"]).build().is_none());
+        assert!(builder.skip_prefixes(&[b"1x"]).build().is_none());
+        assert!(builder.skip_prefixes(&[b".x"]).build().is_none());
+        assert!(builder.skip_suffixes(&[b"e"]).build().is_none());
+        assert!(builder.skip_suffixes(&[b"E"]).build().is_none());
+        assert!(builder.skip_prefixes(&[b"e"]).build().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_rust_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_rust_literal::<f64>(bytes);
+        assert_eq!(parse(b"1.5"), Ok((1.5, None)));
+        assert_eq!(parse(b"1_000.000_1"), Ok((1000.0001, None)));
+        assert_eq!(parse(b"1e3"), Ok((1000.0, None)));
+        assert_eq!(parse(b"1E-3"), Ok((0.001, None)));
+        assert_eq!(parse(b"1f64"), Ok((1.0, Some(RustSuffix::F64))));
+        assert_eq!(parse(b"1.5_f32"), Ok((1.5, Some(RustSuffix::F32))));
+        assert_eq!(parse(b"2.5e3f64"), Ok((2500.0, Some(RustSuffix::F64))));
+
+        assert_eq!(parse(b"1u8"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1.5f"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"0x1p3"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b".5"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"-1.5"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"inf"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert!(parse(b"1e").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_cxx_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_cxx_literal::<f64>(bytes);
+        assert_eq!(parse(b"1.5"), Ok((1.5, None)));
+        assert_eq!(parse(b"1'000.000'1"), Ok((1000.0001, None)));
+        assert_eq!(parse(b".5"), Ok((0.5, None)));
+        assert_eq!(parse(b"1."), Ok((1.0, None)));
+        assert_eq!(parse(b"1E-3"), Ok((0.001, None)));
+        assert_eq!(parse(b"01.5"), Ok((1.5, None)));
+        assert_eq!(parse(b"1.5f"), Ok((1.5, Some(CxxSuffix::Float))));
+        assert_eq!(parse(b"2.5e3L"), Ok((2500.0, Some(CxxSuffix::LongDouble))));
+
+        assert_eq!(parse(b"1.5u"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"1.5ff"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"1.5_0"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"-1.5"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"inf"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert!(parse(b"1e").is_err());
+
+        // Hexadecimal floats have a required binary exponent.
+        #[cfg(feature = "power_of_two")]
+        {
+            assert_eq!(parse(b"0x1.8p3"), Ok((12.0, None)));
+            assert_eq!(parse(b"0X1.8P-1f"), Ok((0.75, Some(CxxSuffix::Float))));
+            assert_eq!(parse(b"0x.8p1"), Ok((1.0, None)));
+            assert_eq!(parse(b"0x1'0p0"), Ok((16.0, None)));
+            assert_eq!(parse(b"0xAp0L"), Ok((10.0, Some(CxxSuffix::LongDouble))));
+            assert_eq!(parse(b"0x1p-1074"), Ok((5e-324, None)));
+            assert_eq!(parse(b"0x1p-1075"), Ok((0.0, None)));
+            assert_eq!(parse(b"0x1.0000001p-1075"), Ok((5e-324, None)));
+            assert_eq!(parse(b"0x1p1024"), Ok((f64::INFINITY, None)));
+            assert_eq!(parse(b"0x1p99999999999999"), Ok((f64::INFINITY, None)));
+
+            // Halfway cases round to even, unless any later digit is set.
+            assert_eq!(parse(b"0x1.00000000000008p0"), Ok((1.0, None)));
+            assert_eq!(parse(b"0x1.00000000000018p0"), Ok((1.0000000000000004, None)));
+            let above = b"0x1.000000000000080000000000000001p0";
+            assert_eq!(parse(above), Ok((1.0000000000000002, None)));
+
+            assert_eq!(parse(b"0x"), Err((ErrorCode::EmptyMantissa, 2).into()));
+            assert_eq!(parse(b"0x.p1"), Err((ErrorCode::EmptyMantissa, 2).into()));
+            assert_eq!(parse(b"0x1.8"), Err((ErrorCode::MissingExponent, 5).into()));
+            assert_eq!(parse(b"0x1'.8p0"), Err((ErrorCode::MissingExponent, 3).into()));
+            assert_eq!(parse(b"0x1p"), Err((ErrorCode::EmptyExponent, 4).into()));
+            assert_eq!(parse(b"0x1p-f"), Err((ErrorCode::EmptyExponent, 5).into()));
+            assert_eq!(parse(b"0x1p1'"), Err((ErrorCode::InvalidDigit, 5).into()));
+            assert_eq!(parse(b"0x1p1u"), Err((ErrorCode::InvalidDigit, 5).into()));
+        }
+        #[cfg(not(feature = "power_of_two"))]
+        assert_eq!(parse(b"0x1p3"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"0b1"), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    fn f64_sign_strings_test() {
+        let options = ParseFloatOptions::builder()
+            .plus_signs(&[b"\xEF\xB9\xA2"])
+            .minus_signs(&[b"\xE2\x88\x92"])
+            .skip_prefixes(&[b"$"])
+            .build()
+            .unwrap();
+        let parse = |bytes: &str| f64::from_lexical_with_options(bytes.as_bytes(), &options);
+        assert_eq!(Ok(-1.5), parse("\u{2212}1.5"));
+        assert_eq!(Ok(1.5), parse("\u{fe62}1.5"));
+        assert_eq!(Ok(-1.5), parse("-1.5"));
+        assert_eq!(Ok(-1.5), parse("\u{2212}$1.5"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parse("\u{2212}inf"));
+        assert!(parse("\u{2212}0").unwrap().is_sign_negative());
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse("\u{2212}-1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse("\u{2212}+1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse("\u{2212}$-1.5"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse("\u{2212}(1.5)"));
+        assert!(parse("\u{2212}").is_err());
+
+        // The span of the number starts at the sign string.
+        let options = options.rebuild().trim_whitespace(true).build().unwrap();
+        let bytes = " \u{2212}1.5 ".as_bytes();
+        let (value, metadata) = f64::from_lexical_with_metadata(bytes, &options).unwrap();
+        assert_eq!(value, -1.5);
+        assert_eq!((metadata.start(), metadata.end()), (1, 7));
+
+        // Sign strings must not start with part of the number.
+        let builder = ParseFloatOptions::builder();
+        assert!(builder.minus_signs(&[b""]).build().is_none());
+        assert!(builder.minus_signs(&[b"-"]).build().is_none());
+        assert!(builder.plus_signs(&[b"1"]).build().is_none());
+        assert!(builder.plus_signs(&[b"."]).build().is_none());
+    }
+
+    #[test]
+    fn f64_digit_separator_test() {
+        let options = ParseFloatOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        assert_eq!(Ok(1000000.0), f64::from_lexical_with_options(b"1_000_000", &options));
+        assert_eq!(Ok(-1000.25), f64::from_lexical_with_options(b"-1_000.2_5", &options));
+        assert_eq!(Ok(1.5e10), f64::from_lexical_with_options(b"1_.5e1_0", &options));
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b"_.5_", &options));
+        assert_eq!(Ok((1000.0, 5)), f64::from_lexical_partial_with_options(b"1_000 x", &options));
+        assert!(f64::from_lexical_with_options(b"1,000", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"1e_", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"_", &options).is_err());
+
+        // Disabled by default.
+        assert!(f64::from_lexical(b"1_000").is_err());
+
+        // Digit separators cannot be digits, signs or other punctuation.
+        let builder = ParseFloatOptions::builder();
+        assert!(builder.digit_separator(Some(b'1')).build().is_none());
+        assert!(builder.digit_separator(Some(b'+')).build().is_none());
+        assert!(builder.digit_separator(Some(b'.')).build().is_none());
+        assert!(builder.digit_separator(Some(b'e')).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_javascript_string_test() {
+        // Parsing the complete string, like `Number()`.
+        let options = ParseFloatOptions::javascript_string();
+        assert_eq!(Ok(0.5), f64::from_lexical_with_options(b" +.5", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"1.", &options));
+        assert_eq!(Ok(100.0), f64::from_lexical_with_options(b"1e+2", &options));
+        let value = f64::from_lexical_with_options(b" -Infinity ", &options);
+        assert_eq!(Ok(f64::NEG_INFINITY), value);
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"infinity", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"inf", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"nan", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"1e", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"12abc", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"--1", &options).is_err());
+
+        // Parsing a partial string, like `parseFloat`.
+        let parse_float = |bytes| f64::from_lexical_partial_with_options(bytes, &options);
+        assert_eq!(Ok((12.0, 2)), parse_float(b"12abc"));
+        assert_eq!(Ok((1.5, 4)), parse_float(b"1.5 2"));
+        assert_eq!(Ok((0.0, 1)), parse_float(b"0x10"));
+        assert_eq!(Ok((f64::INFINITY, 8)), parse_float(b"Infinityx"));
+        assert!(parse_float(b"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_xml_schema_double_test() {
+        // Valid lexical forms from the XML Schema test suite.
+        let options = ParseFloatOptions::xml_schema_double();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(-1e4), parse(b"-1E4"));
+        assert_eq!(Ok(1267.43233e12), parse(b"1267.43233E12"));
+        assert_eq!(Ok(12.78e-2), parse(b"12.78e-2"));
+        assert_eq!(Ok(12.0), parse(b"12"));
+        assert_eq!(Ok(-0.0), parse(b"-0"));
+        assert_eq!(Ok(0.5), parse(b".5"));
+        assert_eq!(Ok(5.0), parse(b"5."));
+        assert_eq!(Ok(7.0), parse(b"007"));
+        assert_eq!(Ok(1.5), parse(b"+1.5"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"INF"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parse(b"-INF"));
+        assert!(parse(b"NaN").unwrap().is_nan());
+
+        // Invalid lexical forms.
+        assert!(parse(b"inf").is_err());
+        assert!(parse(b"Infinity").is_err());
+        assert!(parse(b"nan").is_err());
+        assert!(parse(b"NAN").is_err());
+        assert!(parse(b"1e").is_err());
+        assert!(parse(b"1.5e+").is_err());
+        assert!(parse(b"0x1p3").is_err());
+        assert!(parse(b"1,5").is_err());
+        assert!(parse(b" 1.5").is_err());
+        assert!(parse(b"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_xml_schema_decimal_test() {
+        // Valid lexical forms from the XML Schema test suite.
+        let options = ParseFloatOptions::xml_schema_decimal();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(-1.23), parse(b"-1.23"));
+        assert_eq!(Ok(12678967.543233), parse(b"12678967.543233"));
+        assert_eq!(Ok(100000.00), parse(b"+100000.00"));
+        assert_eq!(Ok(210.0), parse(b"210"));
+        assert_eq!(Ok(0.5), parse(b".5"));
+        assert_eq!(Ok(5.0), parse(b"5."));
+        assert_eq!(Ok(1.0), parse(b"001.000"));
+
+        // Invalid lexical forms.
+        assert!(parse(b"1e5").is_err());
+        assert!(parse(b"1E5").is_err());
+        assert!(parse(b"INF").is_err());
+        assert!(parse(b"NaN").is_err());
+        assert!(parse(b"1,5").is_err());
+        assert!(parse(b".").is_err());
+    }
+
+    #[test]
+    fn f64_suffix_test() {
+        let options = ParseFloatOptions::builder().suffix(Some(b'f')).build().unwrap();
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5f", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5F", &options));
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"1.f", &options));
+        assert_eq!(Ok(1e5), f64::from_lexical_with_options(b"1e5f", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+        assert_eq!(Ok((1.5, 4)), f64::from_lexical_partial_with_options(b"1.5fx", &options));
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 4).into()),
+            f64::from_lexical_with_options(b"1.5ff", &options)
+        );
+        assert!(f64::from_lexical_with_options(b"f", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"inff", &options).is_err());
+        assert!(f64::from_lexical_with_options(b"1.5 f", &options).is_err());
+
+        // Disabled by default.
+        assert!(f64::from_lexical(b"1.5f").is_err());
+
+        // The suffix must not be part of the number.
+        let builder = ParseFloatOptions::builder();
+        assert!(builder.suffix(Some(b'e')).build().is_none());
+        assert!(builder.suffix(Some(b'E')).build().is_none());
+        assert!(builder.suffix(Some(b'1')).build().is_none());
+        assert!(builder.suffix(Some(b'.')).build().is_none());
+        assert!(builder.suffix(Some(b'L')).build().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_sql_test() {
+        let options = ParseFloatOptions::sql();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1.5), parse(b"+1.5"));
+        assert_eq!(Ok(-0.5), parse(b"-.5"));
+        assert_eq!(Ok(5.0), parse(b"5."));
+        assert_eq!(Ok(7.0), parse(b"007"));
+        assert_eq!(Ok(1.5e10), parse(b"1.5E10"));
+        assert_eq!(Ok(1e-3), parse(b"1e-3"));
+        assert!(parse(b"1e").is_err());
+        assert!(parse(b".").is_err());
+        assert!(parse(b"NaN").is_err());
+        assert!(parse(b"Infinity").is_err());
+
+        // PostgreSQL also accepts special values and whitespace in strings.
+        let options = ParseFloatOptions::postgresql();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1.5), parse(b" 1.5 "));
+        assert_eq!(Ok(f64::NEG_INFINITY), parse(b"-Infinity"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"infinity"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"inf"));
+        assert!(parse(b"nan").unwrap().is_nan());
+        assert!(parse(b"1e").is_err());
+
+        // MySQL converts strings from the longest numeric prefix.
+        let options = ParseFloatOptions::mysql();
+        assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial_with_options(b"1.5abc", &options));
+        assert!(f64::from_lexical_with_options(b"inf", &options).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_protobuf_text_test() {
+        let options = ParseFloatOptions::protobuf_text();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1.5), parse(b"1.5"));
+        assert_eq!(Ok(1.5), parse(b"1.5f"));
+        assert_eq!(Ok(0.5), parse(b".5F"));
+        assert_eq!(Ok(-2.0), parse(b"-2f"));
+        assert_eq!(Ok(0.0), parse(b"0"));
+        assert_eq!(Ok(1e-5), parse(b"1e-5"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"inf"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parse(b"-inf"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"Infinity"));
+        assert!(parse(b"nan").unwrap().is_nan());
+        assert!(parse(b"NaN").unwrap().is_nan());
+
+        assert!(parse(b"+1.5").is_err());
+        assert!(parse(b"01.5").is_err());
+        assert!(parse(b"1e").is_err());
+        assert!(parse(b"1.5d").is_err());
+        assert!(parse(b"inff").is_err());
+    }
+
+    #[test]
+    fn f64_empty_nan_test() {
+        let options = ParseFloatOptions::builder().empty_nan(true).build().unwrap();
+        assert!(f64::from_lexical_with_options(b"", &options).unwrap().is_nan());
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+        assert_eq!(
+            Err((ErrorCode::EmptyMantissa, 0).into()),
+            f64::from_lexical_with_options(b" ", &options)
+        );
+
+        // Whitespace-only strings are empty when trimming whitespace.
+        let options = options.rebuild().trim_whitespace(true).build().unwrap();
+        assert!(f64::from_lexical_with_options(b" \t", &options).unwrap().is_nan());
 
-        // Check various expected failures.
-        assert_eq!(Err(ErrorCode::Empty.into()), f32::from_lexical(b""));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"E"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e1"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f32::from_lexical(b".e-1"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e1"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f32::from_lexical(b"e-1"));
-        assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical(b"+"));
-        assert_eq!(Err((ErrorCode::Empty, 1).into()), f32::from_lexical(b"-"));
+        // Disabled by default.
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), f64::from_lexical(b""));
+    }
 
-        // Bug fix for Issue #8
-        assert_eq!(Ok(5.002868148396374), f32::from_lexical(b"5.002868148396374"));
+    #[test]
+    fn f64_csv_test() {
+        let options = ParseFloatOptions::csv();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1234.5), parse(b"1,234.5"));
+        assert_eq!(Ok(-1e6), parse(b" -1,000,000 "));
+        assert_eq!(Ok(0.25), parse(b"0.25"));
+        assert!(parse(b"").unwrap().is_nan());
+        assert!(parse(b"  ").unwrap().is_nan());
+        assert!(parse(b"NaN").unwrap().is_nan());
+        assert!(parse(b"1.5x").is_err());
+        assert!(parse(b"1;5").is_err());
+
+        let options = ParseFloatOptions::csv_european();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1234.5), parse(b"1.234,5"));
+        assert_eq!(Ok(-0.5), parse(b" -0,5"));
+        assert_eq!(Ok(1.5e3), parse(b"1,5e3"));
+        assert!(parse(b"").unwrap().is_nan());
+
+        // Empty fields are errors without `empty_nan`, to map them to `None`.
+        let options = ParseFloatOptions::csv().rebuild().empty_nan(false).build().unwrap();
+        let field = |bytes| match f64::from_lexical_with_options(bytes, &options) {
+            Err(error) if error.code == ErrorCode::Empty => Ok(None),
+            result => result.map(Some),
+        };
+        assert_eq!(Ok(None), field(b" "));
+        assert_eq!(Ok(Some(2.0)), field(b"2"));
     }
 
     #[test]
-    #[cfg(feature = "radix")]
-    fn f32_radix_test() {
-        let options = ParseFloatOptions::builder().radix(36).build().unwrap();
-        assert_f32_eq!(1234.0, f32::from_lexical_with_options(b"YA", &options).unwrap());
-        let options = options.rebuild().lossy(true).build().unwrap();
-        assert_f32_eq!(1234.0, f32::from_lexical_with_options(b"YA", &options).unwrap());
+    #[cfg(feature = "locale")]
+    fn f64_locale_test() {
+        let options = ParseFloatOptions::for_locale("de_DE").unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1234.5), parse(b"1.234,5"));
+        assert_eq!(Ok(-0.5), parse(b"-0,5"));
+        assert_eq!(Ok(1.5e3), parse(b"1,5e3"));
+
+        let options = ParseFloatOptions::for_locale("en_IN").unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(123456789.5), parse(b"12,34,56,789.5"));
+
+        // Non-ASCII group separators are not skipped.
+        let options = ParseFloatOptions::for_locale("fr_FR").unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1234.5), parse(b"1234,5"));
+        assert!(parse("1\u{202f}234,5".as_bytes()).is_err());
     }
 
     #[test]
-    fn f64_decimal_test() {
-        // integer test
-        assert_f64_eq!(0.0, f64::from_lexical(b"0").unwrap());
-        assert_f64_eq!(1.0, f64::from_lexical(b"1").unwrap());
-        assert_f64_eq!(12.0, f64::from_lexical(b"12").unwrap());
-        assert_f64_eq!(123.0, f64::from_lexical(b"123").unwrap());
-        assert_f64_eq!(1234.0, f64::from_lexical(b"1234").unwrap());
-        assert_f64_eq!(12345.0, f64::from_lexical(b"12345").unwrap());
-        assert_f64_eq!(123456.0, f64::from_lexical(b"123456").unwrap());
-        assert_f64_eq!(1234567.0, f64::from_lexical(b"1234567").unwrap());
-        assert_f64_eq!(12345678.0, f64::from_lexical(b"12345678").unwrap());
+    fn f64_info_test() {
+        let options = ParseFloatOptions::new();
+        let info = |bytes| {
+            let (_, info) = f64::from_lexical_with_info(bytes, &options).unwrap();
+            (info.digits(), info.exponent(), info.exact())
+        };
+        assert_eq!(info(b"1234.5"), (5, 3, true));
+        assert_eq!(info(b"1500"), (2, 3, true));
+        assert_eq!(info(b"0.0125"), (3, -2, false));
+        assert_eq!(info(b"-2.5e-3"), (2, -3, false));
+        assert_eq!(info(b"00.000"), (0, 0, true));
+        assert_eq!(info(b"9007199254740993"), (16, 15, false));
+        assert_eq!(info(b"1e400"), (1, 400, false));
+        assert_eq!(info(b"1e-400"), (1, -400, false));
+        assert_eq!(info(b"inf"), (0, 0, true));
+        assert!(f64::from_lexical_with_info(b"1.5,", &options).is_err());
+        let result = f64::from_lexical_partial_with_info(b"1.5,", &options);
+        let (value, processed, info) = result.unwrap();
+        assert_eq!((value, processed, info.digits()), (1.5, 3, 2));
+
+        // Truncated digits are counted, and are not exact.
+        let options = ParseFloatOptions::builder()
+            .max_mantissa_digits(Some(3))
+            .truncate_mantissa(true)
+            .build()
+            .unwrap();
+        let (value, info) = f64::from_lexical_with_info(b"1.2345", &options).unwrap();
+        assert_eq!(value, 1.23);
+        assert_eq!((info.digits(), info.truncated(), info.exact()), (5, true, false));
+        let (_, info) = f32::from_lexical_with_info(b"1.25", &options).unwrap();
+        assert_eq!((info.digits(), info.truncated(), info.exact()), (3, false, true));
+    }
 
-        // No fraction after decimal point test
-        assert_f64_eq!(1.0, f64::from_lexical(b"1.").unwrap());
-        assert_f64_eq!(12.0, f64::from_lexical(b"12.").unwrap());
-        assert_f64_eq!(1234567.0, f64::from_lexical(b"1234567.").unwrap());
+    #[test]
+    fn parse_number_test() {
+        let options = ParseFloatOptions::new();
+        let parse = |bytes: &[u8]| crate::parse_number(bytes, &options);
+        assert_eq!(parse(b"0"), Ok(ParsedNumber::U64(0)));
+        assert_eq!(parse(b"+15"), Ok(ParsedNumber::U64(15)));
+        assert_eq!(parse(b"-15"), Ok(ParsedNumber::I64(-15)));
+        assert_eq!(parse(b"18446744073709551615"), Ok(ParsedNumber::U64(u64::max_value())));
+        assert_eq!(parse(b"-9223372036854775808"), Ok(ParsedNumber::I64(i64::min_value())));
+        assert_eq!(parse(b"-9223372036854775809"), Ok(ParsedNumber::F64(-9223372036854775808.0)));
+        assert_eq!(parse(b"1.0"), Ok(ParsedNumber::F64(1.0)));
+        assert_eq!(parse(b"1e2"), Ok(ParsedNumber::F64(100.0)));
+        assert_eq!(parse(b"inf"), Ok(ParsedNumber::F64(f64::INFINITY)));
+        assert_eq!(parse(b"1.5x"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b""), Err((ErrorCode::Empty, 0).into()));
+        match parse(b"-0") {
+            Ok(ParsedNumber::F64(value)) => assert!(value == 0.0 && value.is_sign_negative()),
+            value => panic!("unexpected value {:?}", value),
+        }
+        assert_eq!(ParsedNumber::U64(1 << 60).as_f64(), 1152921504606846976.0);
+        assert_eq!(ParsedNumber::I64(-5).as_f64(), -5.0);
+
+        // Integers use the syntax of the options.
+        let options = ParseFloatOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        let parse = |bytes: &[u8]| crate::parse_number(bytes, &options);
+        assert_eq!(parse(b"1_000_000"), Ok(ParsedNumber::U64(1000000)));
+        assert_eq!(parse(b"-1_000.5"), Ok(ParsedNumber::F64(-1000.5)));
+    }
 
-        // No integer before decimal point test
-        assert_f64_eq!(0.1, f64::from_lexical(b".1").unwrap());
-        assert_f64_eq!(0.12, f64::from_lexical(b".12").unwrap());
-        assert_f64_eq!(0.1234567, f64::from_lexical(b".1234567").unwrap());
+    #[test]
+    #[cfg(feature = "format")]
+    fn parse_number_json_test() {
+        let options = ParseFloatOptions::json();
+        let parse = |bytes: &[u8]| crate::parse_number(bytes, &options);
+        assert_eq!(parse(b"-15"), Ok(ParsedNumber::I64(-15)));
+        assert_eq!(parse(b"2.5E3"), Ok(ParsedNumber::F64(2500.0)));
+        assert!(parse(b"+15").is_err());
+        assert!(parse(b"015").is_err());
+    }
 
-        // decimal test
-        assert_f64_eq!(123456789.0, f64::from_lexical(b"123456789").unwrap());
-        assert_f64_eq!(123456789.1, f64::from_lexical(b"123456789.1").unwrap());
-        assert_f64_eq!(123456789.12, f64::from_lexical(b"123456789.12").unwrap());
-        assert_f64_eq!(123456789.123, f64::from_lexical(b"123456789.123").unwrap());
-        assert_f64_eq!(123456789.1234, f64::from_lexical(b"123456789.1234").unwrap());
-        assert_f64_eq!(123456789.12345, f64::from_lexical(b"123456789.12345").unwrap());
-        assert_f64_eq!(123456789.123456, f64::from_lexical(b"123456789.123456").unwrap());
-        assert_f64_eq!(123456789.1234567, f64::from_lexical(b"123456789.1234567").unwrap());
-        assert_f64_eq!(123456789.12345678, f64::from_lexical(b"123456789.12345678").unwrap());
+    #[test]
+    fn f64_max_mantissa_digits_test() {
+        let options = ParseFloatOptions::builder().max_mantissa_digits(Some(5)).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::TooManyMantissaDigits, 5).into()),
+            f64::from_lexical_with_options(b"123456", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::TooManyMantissaDigits, 7).into()),
+            f64::from_lexical_with_options(b"-1.23451e10", &options)
+        );
 
-        // rounding test
-        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.123456789").unwrap());
-        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.1234567890").unwrap());
-        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.123456789012").unwrap());
-        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.1234567890123").unwrap());
-        assert_f64_eq!(123456789.12345679, f64::from_lexical(b"123456789.12345678901234").unwrap());
+        // Leading and trailing zeros are not significant.
+        assert_eq!(Ok(123450000.0), f64::from_lexical_with_options(b"123450000", &options));
+        assert_eq!(Ok(0.0012345), f64::from_lexical_with_options(b"0.0012345000", &options));
+        assert_eq!(Ok(12345.0), f64::from_lexical_with_options(b"0012345", &options));
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert_eq!(
+            Err((ErrorCode::EmptyMantissa, 0).into()),
+            f64::from_lexical_with_options(b".e10", &options)
+        );
 
-        // exponent test
-        assert_f64_eq!(123456789.12345, f64::from_lexical(b"1.2345678912345e8").unwrap());
-        assert_f64_eq!(123450000.0, f64::from_lexical(b"1.2345e+8").unwrap());
-        assert_f64_eq!(1.2345e+11, f64::from_lexical(b"123450000000").unwrap());
-        assert_f64_eq!(1.2345e+11, f64::from_lexical(b"1.2345e+11").unwrap());
-        assert_f64_eq!(1.2345e+38, f64::from_lexical(b"1.2345e+38").unwrap());
-        assert_f64_eq!(
-            1.2345e+38,
-            f64::from_lexical(b"123450000000000000000000000000000000000").unwrap()
+        // Truncate the digits, rather than error.
+        let options = options.rebuild().truncate_mantissa(true).build().unwrap();
+        let (value, metadata) = f64::from_lexical_with_metadata(b"123456", &options).unwrap();
+        assert_eq!(value, 123450.0);
+        assert!(metadata.truncated());
+        let (value, metadata) = f64::from_lexical_with_metadata(b"1.2345e3", &options).unwrap();
+        assert_eq!(value, 1234.5);
+        assert!(!metadata.truncated());
+        let (value, processed, metadata) =
+            f64::from_lexical_partial_with_metadata(b"-0.001234567e-2,", &options).unwrap();
+        assert_eq!((value, processed), (-0.000012345, 15));
+        assert!(metadata.truncated());
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 6).into()),
+            f64::from_lexical_with_metadata(b"1.2345,", &options)
         );
-        assert_f64_eq!(1.2345e+308, f64::from_lexical(b"1.2345e+308").unwrap());
-        assert_f64_eq!(1.2345e+308, f64::from_lexical(b"123450000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap());
-        assert_f64_eq!(0.000000012345, f64::from_lexical(b"1.2345e-8").unwrap());
-        assert_f64_eq!(1.2345e-8, f64::from_lexical(b"0.000000012345").unwrap());
-        assert_f64_eq!(1.2345e-38, f64::from_lexical(b"1.2345e-38").unwrap());
-        assert_f64_eq!(
-            1.2345e-38,
-            f64::from_lexical(b"0.000000000000000000000000000000000000012345").unwrap()
+
+        // Truncating to 17 digits is within 1 ULP of the correct value.
+        let options = ParseFloatOptions::builder()
+            .max_mantissa_digits(Some(17))
+            .truncate_mantissa(true)
+            .build()
+            .unwrap();
+        let halfway = b"9007199254740993.0000000000000000000000001";
+        let (value, metadata) = f64::from_lexical_with_metadata(halfway, &options).unwrap();
+        assert_eq!(value, 9007199254740992.0);
+        assert!(metadata.truncated());
+        assert_eq!(Ok(9007199254740994.0), f64::from_lexical(halfway));
+
+        // The default examines every digit.
+        let (value, metadata) =
+            f64::from_lexical_with_metadata(halfway, &ParseFloatOptions::new()).unwrap();
+        assert_eq!(value, 9007199254740994.0);
+        assert!(!metadata.truncated());
+        assert!(ParseFloatOptions::builder().max_mantissa_digits(Some(0)).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_max_mantissa_digits_separator_test() {
+        let format = NumberFormat::from_spec("separator=_,internal_digit_separator");
+        let options = ParseFloatOptions::builder()
+            .format(format)
+            .max_mantissa_digits(Some(3))
+            .build()
+            .unwrap();
+        assert_eq!(Ok(123.0), f64::from_lexical_with_options(b"1_2_3", &options));
+        assert_eq!(
+            Err((ErrorCode::TooManyMantissaDigits, 6).into()),
+            f64::from_lexical_with_options(b"1_2_3_4", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::TooManyMantissaDigits, 6).into()),
+            f64::from_lexical_with_options(b"1.2_3_4", &options)
         );
+    }
 
-        // denormalized (try extremely low values)
-        assert_f64_eq!(1.2345e-308, f64::from_lexical(b"1.2345e-308").unwrap());
-        // These next 3 tests fail on arm-unknown-linux-gnueabi with the
-        // incorrect parser.
-        #[cfg(not(target_arch = "arm"))]
-        {
-            let options = ParseFloatOptions::builder().incorrect(true).build().unwrap();
-            assert_eq!(Ok(5e-322), f64::from_lexical_with_options(b"5e-322", &options));
-            assert_eq!(Ok(5e-323), f64::from_lexical_with_options(b"5e-323", &options));
-            assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"5e-324", &options));
-        }
-        // due to issues in how the data is parsed, manually extracting
-        // non-exponents of 1.<e-299 is prone to error
-        // test the limit of our ability
-        // We tend to get relative errors of 1e-16, even at super low values.
-        assert_f64_eq!(1.2345e-299, f64::from_lexical(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345").unwrap(), epsilon=1e-314);
+    #[test]
+    fn f64_from_parts_test() {
+        let options = ParseFloatOptions::new();
+        let positive = Sign::Positive;
+        let negative = Sign::Negative;
+        assert_eq!(Ok(1.5), f64::from_parts(positive, b"1", b"5", 0, &options));
+        assert_eq!(Ok(-1.5), f64::from_parts(negative, b"1", b"5", 0, &options));
+        assert_eq!(Ok(1.5e10), f64::from_parts(positive, b"15", b"", 9, &options));
+        assert_eq!(Ok(0.1), f64::from_parts(positive, b"", b"1", 0, &options));
+        assert_eq!(Ok(0.0), f64::from_parts(positive, b"000", b"000", 50, &options));
+        assert_eq!(Ok(-0.0), f64::from_parts(negative, b"0", b"", 0, &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_parts(positive, b"1", b"", 400, &options));
+        assert_eq!(Ok(0.0), f64::from_parts(positive, b"1", b"", -400, &options));
+        assert_eq!(
+            Ok(9007199254740992.0),
+            f64::from_parts(positive, b"9007199254740993", b"", 0, &options)
+        );
+        assert_eq!(
+            Ok(2.2250738585072014e-308),
+            f64::from_parts(positive, b"2", b"2250738585072014", -308, &options)
+        );
+        assert_eq!(Ok(123.456f32), f32::from_parts(positive, b"123", b"456", 0, &options));
 
-        // Keep pushing from -300 to -324
-        assert_f64_eq!(1.2345e-300, f64::from_lexical(b"0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345").unwrap(), epsilon=1e-315);
+        // Errors index into the integer digits followed by the fraction digits.
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 3).into()),
+            f64::from_parts(positive, b"12", b"3a", 0, &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::EmptyMantissa, 0).into()),
+            f64::from_parts(positive, b"", b"", 0, &options)
+        );
 
-        // These next 3 tests fail on arm-unknown-linux-gnueabi with the
-        // incorrect parser.
-        #[cfg(not(target_arch = "arm"))]
-        {
-            let options = ParseFloatOptions::builder().incorrect(true).build().unwrap();
-            assert_f64_near_eq!(1.2345e-310, f64::from_lexical_with_options(b"0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
-            assert_f64_near_eq!(1.2345e-320, f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
-            assert_f64_near_eq!(1.2345e-321, f64::from_lexical_with_options(b"0.0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012345", &options).unwrap(), epsilon=5e-324);
-            assert_f64_near_eq!(1.24e-322, f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000124", &options).unwrap(), epsilon=5e-324);
-            assert_eq!(Ok(1e-323), f64::from_lexical_with_options(b"0.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", &options));
-            assert_eq!(Ok(5e-324), f64::from_lexical_with_options(b"0.000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005", &options));
-        }
+        let options = options.rebuild().underflow_error(true).build().unwrap();
+        assert_eq!(
+            Err((ErrorCode::Underflow, 1).into()),
+            f64::from_parts(positive, b"1", b"", -400, &options)
+        );
 
-        assert!(f64::from_lexical(b"NaN").unwrap().is_nan());
-        assert!(f64::from_lexical(b"nan").unwrap().is_nan());
-        assert!(f64::from_lexical(b"NAN").unwrap().is_nan());
-        assert!(f64::from_lexical(b"inf").unwrap().is_infinite());
-        assert!(f64::from_lexical(b"INF").unwrap().is_infinite());
-        assert!(f64::from_lexical(b"+inf").unwrap().is_infinite());
-        assert!(f64::from_lexical(b"-inf").unwrap().is_infinite());
+        // The exponent is already an `i32`, so it cannot overflow.
+        let options = ParseFloatOptions::builder().exponent_overflow_error(true).build().unwrap();
+        assert_eq!(Ok(f64::INFINITY), f64::from_parts(positive, b"1", b"5", i32::MAX, &options));
+        assert_eq!(Ok(-0.0), f64::from_parts(negative, b"1", b"", i32::MIN, &options));
+        assert_eq!(Ok(0.0), f64::from_parts(positive, b"0", b"", i32::MAX, &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_parts(positive, b"1", b"", 400, &options));
 
-        // Check various expected failures.
-        assert_eq!(Err(ErrorCode::Empty.into()), f64::from_lexical(b""));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e1"));
-        assert_eq!(Err(ErrorCode::EmptyMantissa.into()), f64::from_lexical(b".e-1"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e1"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e-1"));
+        let options = ParseFloatOptions::builder().exact(true).build().unwrap();
+        assert_eq!(Ok(-1.5), f64::from_parts(negative, b"1", b"5", 0, &options));
+        assert_eq!(Ok(0.0), f64::from_parts(positive, b"000", b"000", 50, &options));
+        assert_eq!(
+            Err((ErrorCode::Inexact, 0).into()),
+            f64::from_parts(positive, b"", b"1", 0, &options)
+        );
+    }
 
-        // Check various reports from a fuzzer.
-        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
-        assert_eq!(Err((ErrorCode::EmptyExponent, 4).into()), f64::from_lexical(b"0.0e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".E"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b".e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"E2252525225"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"e2252525225"));
-        assert_eq!(Ok(f64::INFINITY), f64::from_lexical(b"2E200000000000"));
+    #[test]
+    fn float_parser_test() {
+        // Slow-path values, with large then small big integers, to reuse the scratch space.
+        let values: [&[u8]; 8] = [
+            b"179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791",
+            b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+            b"9007199254740993",
+            b"9007199254740993.0000000000000000000000000000001",
+            b"2.2250738585072011e-308",
+            b"1.5",
+            b"-0.1e-5",
+            b"1.5x",
+        ];
+        let mut parser = FloatParser::new(ParseFloatOptions::new());
+        for &bytes in values.iter() {
+            assert_eq!(parser.parse::<f64>(bytes), f64::from_lexical(bytes));
+            assert_eq!(parser.parse_partial::<f64>(bytes), f64::from_lexical_partial(bytes));
+        }
+        let values32: [&[u8]; 3] = [b"16777217", b"1.00000017881393432617187499", b"1.5x"];
+        for &bytes in values32.iter() {
+            assert_eq!(parser.parse::<f32>(bytes), f32::from_lexical(bytes));
+        }
 
-        // Add various unittests from proptests.
-        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), f64::from_lexical(b"0e"));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical(b"."));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"+."));
-        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical(b"-."));
-        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"+"));
-        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical(b"-"));
+        // The options of the parser are used.
+        let options = ParseFloatOptions::builder().trim_whitespace(true).build().unwrap();
+        let mut parser = FloatParser::new(options);
+        for &bytes in values.iter() {
+            let expected = f64::from_lexical_with_options(bytes, &options);
+            assert_eq!(parser.parse::<f64>(bytes), expected);
+        }
+        assert_eq!(parser.parse::<f64>(b" 9007199254740993 "), Ok(9007199254740992.0));
+        assert_eq!(parser.options(), &options);
+    }
 
-        // Bug fix for Issue #8
-        assert_eq!(Ok(5.002868148396374), f64::from_lexical(b"5.002868148396374"));
+    #[test]
+    #[cfg(feature = "portable")]
+    fn portable_test() {
+        // The incorrect algorithm uses native float arithmetic, so it is skipped.
+        let options = ParseFloatOptions::builder().incorrect(true).build().unwrap();
+        let values: [&[u8]; 5] = [
+            b"1.2345678901234567e-100",
+            b"9007199254740993",
+            b"2.2250738585072011e-308",
+            b"123456.789e250",
+            b"0.3",
+        ];
+        for &bytes in values.iter() {
+            assert_eq!(f64::from_lexical_with_options(bytes, &options), f64::from_lexical(bytes));
+        }
+    }
+
+    #[test]
+    fn f64_exact_test() {
+        let options = ParseFloatOptions::builder().exact(true).build().unwrap();
+        let inexact = |index| Err((ErrorCode::Inexact, index).into());
+        assert_eq!(f64::from_lexical_with_options(b"0.5", &options), Ok(0.5));
+        assert_eq!(f64::from_lexical_with_options(b"-0.25", &options), Ok(-0.25));
+        assert_eq!(f64::from_lexical_with_options(b"0.00048828125", &options), Ok(0.00048828125));
+        assert_eq!(f64::from_lexical_with_options(b"100e-2", &options), Ok(1.0));
+        assert_eq!(f64::from_lexical_with_options(b"1.5e1", &options), Ok(15.0));
+        assert_eq!(f64::from_lexical_with_options(b"1e22", &options), Ok(1e22));
+        assert_eq!(f64::from_lexical_with_options(b"9007199254740992", &options), Ok(9007199254740992.0));
+        assert_eq!(f64::from_lexical_with_options(b"0.000", &options), Ok(0.0));
+        assert_eq!(f64::from_lexical_with_options(b"inf", &options), Ok(f64::INFINITY));
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+
+        // The exact value of the float nearest to 0.1.
+        let bytes = b"0.1000000000000000055511151231257827021181583404541015625";
+        assert_eq!(f64::from_lexical_with_options(bytes, &options), Ok(0.1));
+
+        // Rounded, overflowed and underflowed values are inexact.
+        assert_eq!(f64::from_lexical_with_options(b"0.1", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"-0.1", &options), inexact(1));
+        assert_eq!(f64::from_lexical_with_options(b"1e23", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"9007199254740993", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"0.30000000000000004", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"5e-324", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"1e400", &options), inexact(0));
+        assert_eq!(f64::from_lexical_with_options(b"1e-400", &options), inexact(0));
+        let partial = f64::from_lexical_partial_with_options(b"0.1x", &options);
+        assert_eq!(partial, Err((ErrorCode::Inexact, 0).into()));
+        assert_eq!(f32::from_lexical_with_options(b"16777216", &options), Ok(16777216.0));
+        let float = f32::from_lexical_with_options(b"16777217", &options);
+        assert_eq!(float, Err((ErrorCode::Inexact, 0).into()));
+
+        // Disabled by default.
+        let options = ParseFloatOptions::new();
+        assert_eq!(f64::from_lexical_with_options(b"0.1", &options), Ok(0.1));
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn f64_exact_radix_test() {
+        let builder = ParseFloatOptions::builder().exact(true);
+        let inexact = |index| Err((ErrorCode::Inexact, index).into());
+        let options = builder.radix(2).build().unwrap();
+        assert_eq!(f64::from_lexical_with_options(b"0.1", &options), Ok(0.5));
+        let bytes = b"111111111111111111111111111111111111111111111111111111";
+        assert_eq!(f64::from_lexical_with_options(&bytes[..53], &options), Ok(9007199254740991.0));
+        assert_eq!(f64::from_lexical_with_options(bytes, &options), inexact(0));
+
+        let options = builder.radix(3).build().unwrap();
+        assert_eq!(f64::from_lexical_with_options(b"10", &options), Ok(3.0));
+        assert_eq!(f64::from_lexical_with_options(b"0.1", &options), inexact(0));
+
+        let options = builder.radix(16).build().unwrap();
+        assert_eq!(f64::from_lexical_with_options(b"0.8", &options), Ok(0.5));
     }
 
     #[test]
@@ -1172,6 +3098,37 @@ mod tests {
         assert_f64_eq!(1234.0, f64::from_lexical_with_options(b"YA", &options).unwrap());
     }
 
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn f64_exponent_backup_test() {
+        let options = ParseFloatOptions::builder().radix(2).build().unwrap();
+        assert_eq!(options.exponent_backup(), b'^');
+        assert_eq!(Ok(6.0), f64::from_lexical_with_options(b"1.1^10", &options));
+
+        let options = options.rebuild().exponent_backup(Some(b'#')).build().unwrap();
+        assert_eq!(options.exponent_backup(), b'#');
+        assert_eq!(Ok(6.0), f64::from_lexical_with_options(b"1.1#10", &options));
+        assert_eq!(Ok(0.375), f64::from_lexical_with_options(b"1.1#-10", &options));
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 3).into()),
+            f64::from_lexical_with_options(b"1.1^10", &options)
+        );
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        // Decimal strings still use the decimal exponent character.
+        let options = ParseFloatOptions::builder().exponent_backup(Some(b'#')).build().unwrap();
+        assert_eq!(Ok(1.5e3), f64::from_lexical_with_options(b"1.5e3", &options));
+
+        // Invalid or conflicting characters.
+        let builder = ParseFloatOptions::builder().radix(2);
+        assert!(builder.exponent_backup(Some(b'p')).build().is_none());
+        assert!(builder.exponent_backup(Some(b'1')).build().is_none());
+        assert!(builder.exponent_backup(Some(b'-')).build().is_none());
+        assert!(builder.exponent_backup(Some(b'.')).build().is_none());
+        let builder = builder.digit_separator(Some(b'_'));
+        assert!(builder.exponent_backup(Some(b'_')).build().is_none());
+    }
+
     #[test]
     fn f32_lossy_decimal_test() {
         let options = ParseFloatOptions::builder().lossy(true).build().unwrap();
@@ -1417,6 +3374,71 @@ mod tests {
         assert!(f64::from_lexical_with_options(b"0.e", &options).is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_negative_parentheses_test() {
+        let format = NumberFormat::STANDARD.rebuild().negative_parentheses(true).build().unwrap();
+        let options = ParseFloatOptions::builder().format(Some(format)).build().unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(-1.5), parse(b"(1.5)"));
+        assert_eq!(Ok(-1.5e3), parse(b"(1.5e3)"));
+        assert_eq!(Ok(f64::NEG_INFINITY), parse(b"(inf)"));
+        assert_eq!(Ok(-1.5), parse(b"-1.5"));
+        assert_eq!(Ok(1.5), parse(b"1.5"));
+        assert!(parse(b"(0)").unwrap().is_sign_negative());
+        assert_eq!(Err((ErrorCode::MissingClosingParenthesis, 4).into()), parse(b"(1.5"));
+        assert_eq!(Err((ErrorCode::MissingClosingParenthesis, 2).into()), parse(b"(1x)"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse(b"(-1.5)"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse(b"(+1.5)"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), parse(b"()"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse(b"1.5)"));
+        assert_eq!(Ok((-1.5, 5)), f64::from_lexical_partial_with_options(b"(1.5)x", &options));
+
+        // Accounting reports, with thousands separators and whitespace.
+        let options = ParseFloatOptions::csv().rebuild().format(Some(format)).build().unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(-1234.56), parse(b" (1,234.56) "));
+        assert_eq!(Ok(1234.56), parse(b"1,234.56"));
+
+        // Disabled by default.
+        let options = ParseFloatOptions::new();
+        assert_eq!(
+            Err((ErrorCode::EmptyMantissa, 0).into()),
+            f64::from_lexical_with_options(b"(1.5)", &options)
+        );
+
+        // A required sign conflicts with the parentheses.
+        let builder = format.rebuild().required_mantissa_sign(true);
+        assert!(builder.build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_times_ten_exponent_test() {
+        let format = NumberFormat::STANDARD.rebuild().times_ten_exponent(true).build().unwrap();
+        let options = ParseFloatOptions::builder().format(Some(format)).build().unwrap();
+        let parse = |bytes| f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(1.5e3), parse("1.5×10^3".as_bytes()));
+        assert_eq!(Ok(-2.5e-7), parse("-2.5×10^-7".as_bytes()));
+        assert_eq!(Ok(6.022e23), parse(b"6.022x10^23"));
+        assert_eq!(Ok(1e10), parse(b"1X10^+10"));
+        assert_eq!(Ok(1.5e3), parse(b"1.5e3"));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 8).into()), parse("1.5×10^".as_bytes()));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), parse(b"1.5x10"));
+        assert_eq!(Ok((1.5e3, 8)), f64::from_lexical_partial_with_options(b"1.5x10^3 m", &options));
+
+        // Disabled by default.
+        let options = ParseFloatOptions::new();
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 3).into()),
+            f64::from_lexical_with_options(b"1.5x10^3", &options)
+        );
+
+        // Exponent notation is required to use the introducer.
+        let builder = format.rebuild().no_exponent_notation(true);
+        assert!(builder.build().is_none());
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_integer_internal_digit_separator_test() {
@@ -1432,6 +3454,34 @@ mod tests {
         assert!(f64::from_lexical_with_options(b"31_.0e7", &options).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_invalid_digit_separator_test() {
+        let format = NumberFormat::from_spec("separator=_,internal_digit_separator");
+        let options = ParseFloatOptions::builder().format(format).build().unwrap();
+        let error = |index| Err((ErrorCode::InvalidDigitSeparator, index).into());
+        assert_eq!(error(1), f64::from_lexical_with_options(b"1__000.5", &options));
+        assert_eq!(error(0), f64::from_lexical_with_options(b"_1.5", &options));
+        assert_eq!(error(1), f64::from_lexical_with_options(b"1_.5", &options));
+        assert_eq!(error(2), f64::from_lexical_with_options(b"1._5", &options));
+        assert_eq!(error(3), f64::from_lexical_with_options(b"1.5_", &options));
+        assert_eq!(error(4), f64::from_lexical_with_options(b"1.5e_1", &options));
+        assert_eq!(
+            Err((ErrorCode::InvalidDigitSeparator, 0).into()),
+            f64::from_lexical_partial_with_options(b"_1.5", &options)
+        );
+
+        // Other invalid digits, and separators without digit separator flags, are invalid digits.
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 3).into()),
+            f64::from_lexical_with_options(b"1_0a0", &options)
+        );
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 1).into()),
+            f64::from_lexical(b"1_000.5")
+        );
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_fraction_internal_digit_separator_test() {
@@ -1646,6 +3696,217 @@ mod tests {
         assert!(f64::from_lexical_with_options(b"-012.0", &options).is_err());
     }
 
+    #[test]
+    fn classify_test() {
+        use super::classify_with_options as classify;
+
+        let options = ParseFloatOptions::new();
+        assert_eq!(classify(b"0", &options), NumberClass::Integer);
+        assert_eq!(classify(b"-12", &options), NumberClass::Integer);
+        assert_eq!(classify(b"+12", &options), NumberClass::Integer);
+        assert_eq!(classify(b"1.", &options), NumberClass::Float);
+        assert_eq!(classify(b".5", &options), NumberClass::Float);
+        assert_eq!(classify(b"1e5", &options), NumberClass::Float);
+        assert_eq!(classify(b"-1.5E-3", &options), NumberClass::Float);
+        assert_eq!(classify(b"inf", &options), NumberClass::Infinity);
+        assert_eq!(classify(b"-Infinity", &options), NumberClass::Infinity);
+        assert_eq!(classify(b"NaN", &options), NumberClass::Nan);
+        assert_eq!(classify(b"", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"-", &options), NumberClass::Invalid);
+        assert_eq!(classify(b".", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"1e", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"12x", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"infx", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"nana", &options), NumberClass::Invalid);
+        assert_eq!(classify(b" 12", &options), NumberClass::Invalid);
+
+        let options = ParseFloatOptions::builder()
+            .digit_separator(Some(b'_'))
+            .nan_string(b"nan")
+            .build()
+            .unwrap();
+        assert_eq!(classify(b"1_000", &options), NumberClass::Integer);
+        assert_eq!(classify(b"1_000.5", &options), NumberClass::Float);
+        assert_eq!(classify(b"nan", &options), NumberClass::Nan);
+        assert_eq!(classify(b"1,000", &options), NumberClass::Invalid);
+
+        // The class agrees with the parser.
+        let options = ParseFloatOptions::new();
+        for &bytes in [&b"15"[..], b"-1.5e3", b"inf", b"1e", b"1.5.5"].iter() {
+            let is_valid = f64::from_lexical_with_options(bytes, &options).is_ok();
+            assert_eq!(classify(bytes, &options).is_valid(), is_valid);
+        }
+    }
+
+    #[test]
+    fn compare_numeric_test() {
+        use super::compare_with_options;
+        use crate::lib::cmp::Ordering;
+
+        let options = ParseFloatOptions::new();
+        let compare = |x: &[u8], y: &[u8]| compare_with_options(x, y, &options);
+        assert_eq!(compare(b"1", b"1"), Some(Ordering::Equal));
+        assert_eq!(compare(b"1", b"2"), Some(Ordering::Less));
+        assert_eq!(compare(b"10", b"9"), Some(Ordering::Greater));
+        assert_eq!(compare(b"1.5", b"15e-1"), Some(Ordering::Equal));
+        assert_eq!(compare(b"001.500", b"1.5"), Some(Ordering::Equal));
+        assert_eq!(compare(b"100", b"1e2"), Some(Ordering::Equal));
+        assert_eq!(compare(b"0.05", b".5e-1"), Some(Ordering::Equal));
+        assert_eq!(compare(b"0.05", b"0.5"), Some(Ordering::Less));
+        assert_eq!(compare(b"1.25", b"1.3"), Some(Ordering::Less));
+        assert_eq!(compare(b"1.3", b"1.25"), Some(Ordering::Greater));
+
+        // Signs and zeros.
+        assert_eq!(compare(b"-0", b"0.0e5"), Some(Ordering::Equal));
+        assert_eq!(compare(b"-1", b"0"), Some(Ordering::Less));
+        assert_eq!(compare(b"0", b"1e-300"), Some(Ordering::Less));
+        assert_eq!(compare(b"-1", b"-2"), Some(Ordering::Greater));
+        assert_eq!(compare(b"-1.5", b"-1.25"), Some(Ordering::Less));
+        assert_eq!(compare(b"+1", b"-1"), Some(Ordering::Greater));
+
+        // Special values.
+        assert_eq!(compare(b"inf", b"Infinity"), Some(Ordering::Equal));
+        assert_eq!(compare(b"inf", b"1e999"), Some(Ordering::Greater));
+        assert_eq!(compare(b"-inf", b"-1e999"), Some(Ordering::Less));
+        assert_eq!(compare(b"-inf", b"inf"), Some(Ordering::Less));
+        assert_eq!(compare(b"NaN", b"NaN"), None);
+        assert_eq!(compare(b"NaN", b"1"), None);
+        assert_eq!(compare(b"1", b"1x"), None);
+        assert_eq!(compare(b"", b"1"), None);
+
+        // Digits past the precision of a float.
+        assert_eq!(compare(b"9007199254740993", b"9007199254740992"), Some(Ordering::Greater));
+        let x = b"0.1000000000000000000000000000000000000001";
+        assert_eq!(compare(x, b"0.1"), Some(Ordering::Greater));
+        assert_eq!(compare(b"1e400", b"1e401"), Some(Ordering::Less));
+
+        // Exponents past the range of the raw exponent.
+        assert_eq!(compare(b"1e99999999999", b"1e9999999999"), Some(Ordering::Greater));
+        assert_eq!(compare(b"1e2147483647", b"1e2147483648"), Some(Ordering::Less));
+        assert_eq!(compare(b"1e-2147483649", b"1e-2147483648"), Some(Ordering::Less));
+        assert_eq!(compare(b"10e2147483647", b"1e2147483648"), Some(Ordering::Equal));
+        assert_eq!(compare(b"0.01e2147483650", b"1e2147483648"), Some(Ordering::Equal));
+        assert_eq!(compare(b"1e-99999999999", b"1e99999999999"), Some(Ordering::Less));
+        let x = b"1e100000000000000000000000000000000000000000000";
+        let y = b"1000e99999999999999999999999999999999999999999997";
+        assert_eq!(compare(x, y), Some(Ordering::Equal));
+        let y = b"2e99999999999999999999999999999999999999999999";
+        assert_eq!(compare(x, y), Some(Ordering::Greater));
+
+        let options = ParseFloatOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        assert_eq!(compare_with_options(b"1_000", b"1e3", &options), Some(Ordering::Equal));
+        assert_eq!(compare_with_options(b"1e1_0", b"1e9", &options), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn raw_number_test() {
+        let options = ParseFloatOptions::new();
+        let raw = |bytes| crate::parse_raw_number(bytes, &options).unwrap();
+        let parts = |bytes| {
+            let raw = raw(bytes);
+            let fraction = raw.fraction().map(|x| x.to_vec());
+            let exponent = raw.exponent().map(|x| x.to_vec());
+            (raw.sign(), raw.integer().to_vec(), fraction, exponent)
+        };
+        let bytes = |x: &[u8]| x.to_vec();
+        assert_eq!(parts(b"0"), (Sign::Positive, bytes(b"0"), None, None));
+        assert_eq!(parts(b"-007.500"), (Sign::Negative, bytes(b"007"), Some(bytes(b"500")), None));
+        let expected = (Sign::Positive, bytes(b""), Some(bytes(b"5")), Some(bytes(b"-10")));
+        assert_eq!(parts(b"+.5e-10"), expected);
+        let expected = (Sign::Positive, bytes(b"5"), Some(bytes(b"")), Some(bytes(b"3")));
+        assert_eq!(parts(b"5.E3"), expected);
+        assert_eq!(raw(b"-1.5e3").as_bytes(), b"-1.5e3");
+        assert!(raw(b"12").is_integer());
+        assert!(!raw(b"12.0").is_integer());
+        assert!(!raw(b"12e0").is_integer());
+
+        // Digits are never rounded, and are converted on demand.
+        let digits = b"123456789012345678901234567890123456789";
+        assert_eq!(raw(digits).integer(), &digits[..]);
+        assert_eq!(raw(digits).to_float::<f64>(), Ok(1.2345678901234568e38));
+        assert_eq!(raw(b"-1.5e3").to_float::<f32>(), Ok(-1500.0));
+        assert_eq!(raw(b"-15").to_integer::<i8>(), Ok(-15));
+        assert_eq!(raw(b"300").to_integer::<u8>(), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(raw(b"1.5").to_integer::<u64>(), Err((ErrorCode::InvalidDigit, 1).into()));
+        #[cfg(feature = "integers")]
+        assert_eq!(raw(digits).to_integer::<u128>(), Ok(123456789012345678901234567890123456789));
+
+        // Owned storage outlives the scanned bytes.
+        let owned = {
+            let buffer = b"-2.25e1 ".to_vec();
+            crate::parse_partial_raw_number(&buffer, &options).unwrap().0.to_vec()
+        };
+        assert_eq!(owned.as_bytes(), b"-2.25e1");
+        assert_eq!(owned.fraction(), Some(&b"25"[..]));
+        assert_eq!(owned.to_float::<f64>(), Ok(-22.5));
+        assert_eq!(owned.as_slice(), raw(b"-2.25e1"));
+
+        // Invalid and partial numbers.
+        let parse = |bytes| crate::parse_raw_number(bytes, &options);
+        assert_eq!(parse(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse(b"-"), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(parse(b"."), Err((ErrorCode::EmptyMantissa, 0).into()));
+        assert_eq!(parse(b"1e"), Err((ErrorCode::EmptyExponent, 2).into()));
+        assert_eq!(parse(b"inf"), Err((ErrorCode::EmptyMantissa, 0).into()));
+        assert_eq!(parse(b"1.5x"), Err((ErrorCode::InvalidDigit, 3).into()));
+        let partial = crate::parse_partial_raw_number(b"1.5x", &options).unwrap();
+        assert_eq!((partial.0.as_bytes(), partial.1), (&b"1.5"[..], 3));
+
+        // Digit separators skipped by the options are kept verbatim.
+        let options = ParseFloatOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        let raw = crate::parse_raw_number(b"1_000.5", &options).unwrap();
+        assert_eq!((raw.integer(), raw.fraction()), (&b"1_000"[..], Some(&b"5"[..])));
+        assert_eq!(raw.to_float::<f64>(), Ok(1000.5));
+        assert_eq!(raw.to_integer::<u32>(), Err((ErrorCode::InvalidDigit, 5).into()));
+        let raw = crate::parse_raw_number(b"-1_000", &options).unwrap();
+        assert_eq!(raw.to_integer::<i32>(), Ok(-1000));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn raw_number_format_test() {
+        // Digit separators are kept verbatim.
+        let format = NumberFormat::builder()
+            .digit_separator(b'_')
+            .integer_internal_digit_separator(true)
+            .fraction_internal_digit_separator(true)
+            .build()
+            .unwrap();
+        let options = ParseFloatOptions::builder().format(Some(format)).build().unwrap();
+        let raw = crate::parse_raw_number(b"-1_000.000_5e2", &options).unwrap();
+        assert_eq!(raw.integer(), b"1_000");
+        assert_eq!(raw.fraction(), Some(&b"000_5"[..]));
+        assert_eq!(raw.exponent(), Some(&b"2"[..]));
+        assert_eq!(raw.to_float::<f64>(), Ok(-100000.05));
+        assert_eq!(raw.format(), format);
+
+        // The sign is validated by the format.
+        let options = ParseFloatOptions::json();
+        assert!(crate::parse_raw_number(b"+1", &options).is_err());
+        assert!(crate::parse_raw_number(b"01", &options).is_err());
+        let raw = crate::parse_raw_number(b"-0.5E+2", &options).unwrap();
+        assert_eq!(raw.exponent(), Some(&b"+2"[..]));
+        assert_eq!(raw.to_float::<f64>(), Ok(-50.0));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn json_classify_test() {
+        use super::classify_with_options as classify;
+
+        let format = NumberFormat::JSON;
+        let options = ParseFloatOptions::builder().format(Some(format)).build().unwrap();
+        assert_eq!(classify(b"-12", &options), NumberClass::Integer);
+        assert_eq!(classify(b"12.5", &options), NumberClass::Float);
+        assert_eq!(classify(b"20e1", &options), NumberClass::Float);
+        assert_eq!(classify(b"+12", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"012", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"1.", &options), NumberClass::Invalid);
+        assert_eq!(classify(b".5", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"NaN", &options), NumberClass::Invalid);
+        assert_eq!(classify(b"Infinity", &options), NumberClass::Invalid);
+    }
+
     #[cfg(feature = "property_tests")]
     proptest! {
         #[test]
@@ -4,6 +4,10 @@
 #[macro_use]
 mod algorithm;
 mod api;
+mod parser;
 
 // Re-exports
+pub(crate) use self::algorithm::{big_digits, exact_digits, FloatType};
+pub use self::algorithm::{ExtendedFloatArray, ModeratePathCache, ModeratePathPowers};
 pub use self::api::*;
+pub use self::parser::*;
@@ -0,0 +1,107 @@
+//! Reusable float parser, with scratch space for the slow path.
+
+use crate::result::Result;
+use crate::traits::FromLexicalParser;
+use crate::util::ParseFloatOptions;
+
+use super::algorithm::BigintScratch;
+
+// FLOAT PARSER
+// ------------
+
+/// Float parser that owns its options and the scratch space of the slow path.
+///
+/// Floats with many significant digits, or near halfway between two
+/// floats, are parsed with big integers. The parsers create the big
+/// integers for each call, while a `FloatParser` keeps them between
+/// calls, so bulk parsing of high-precision decimal data does not
+/// re-initialize or re-allocate them for every value. The results are
+/// the same as `parse_with_options` with the same options.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{FloatParser, ParseFloatOptions};
+///
+/// # pub fn main() {
+/// let mut parser = FloatParser::new(ParseFloatOptions::new());
+/// let values: [&[u8]; 3] = [b"1.5", b"9007199254740993", b"2.4703282292062328e-324"];
+/// for &bytes in values.iter() {
+///     let value: f64 = parser.parse(bytes).unwrap();
+///     assert_eq!(Ok(value), lexical_core::parse(bytes));
+/// }
+/// assert_eq!(parser.parse_partial::<f32>(b"1.5 m"), Ok((1.5, 3)));
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FloatParser {
+    /// Options to dictate float parsing.
+    options: ParseFloatOptions,
+    /// Scratch space for `f32`.
+    scratch32: BigintScratch<f32>,
+    /// Scratch space for `f64`.
+    scratch64: BigintScratch<f64>,
+}
+
+impl FloatParser {
+    /// Create a parser from the options.
+    #[inline]
+    pub fn new(options: ParseFloatOptions) -> Self {
+        Self {
+            options,
+            scratch32: BigintScratch::default(),
+            scratch64: BigintScratch::default(),
+        }
+    }
+
+    /// Get the options of the parser.
+    #[inline]
+    pub fn options(&self) -> &ParseFloatOptions {
+        &self.options
+    }
+
+    /// Parse complete float from string, reusing the scratch space.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, like
+    /// `parse_with_options`.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn parse<F: FromLexicalParser>(&mut self, bytes: &[u8]) -> Result<F> {
+        F::from_lexical_with_parser(bytes, self)
+    }
+
+    /// Parse partial float from string, reusing the scratch space.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, like
+    /// `parse_partial_with_options`.
+    ///
+    /// * `bytes`   - Byte slice containing a numeric string.
+    #[inline]
+    pub fn parse_partial<F: FromLexicalParser>(&mut self, bytes: &[u8]) -> Result<(F, usize)> {
+        F::from_lexical_partial_with_parser(bytes, self)
+    }
+
+    /// Get the options and the scratch space for `f32`.
+    #[inline]
+    pub(crate) fn f32_parts(&mut self) -> (&ParseFloatOptions, &mut BigintScratch<f32>) {
+        (&self.options, &mut self.scratch32)
+    }
+
+    /// Get the options and the scratch space for `f64`.
+    #[inline]
+    pub(crate) fn f64_parts(&mut self) -> (&ParseFloatOptions, &mut BigintScratch<f64>) {
+        (&self.options, &mut self.scratch64)
+    }
+}
+
+impl Default for FloatParser {
+    #[inline]
+    fn default() -> Self {
+        Self::new(ParseFloatOptions::default())
+    }
+}
@@ -1,18 +1,34 @@
 //! Fast lexical string-to-integer conversion routines.
 
+use crate::error::*;
 use crate::result::*;
 use crate::traits::*;
 use crate::util::*;
 
 use super::generic::*;
+use super::lenient::*;
+use super::overflow::*;
+#[cfg(feature = "power_of_two")]
+use super::prefix::*;
 
 // ATOI TRAIT
 // ----------
 
 pub(crate) trait Atoi: Integer {
+    // Unsigned type for the magnitude of the integer.
+    type Unsigned: Atoi;
+
     // Parse integer from string.
     fn atoi(bytes: &[u8], radix: u32) -> ParseResult<(Self, *const u8)>;
 
+    // Parse integer from string, ignoring digit separators anywhere in the digits.
+    #[cfg(not(feature = "format"))]
+    fn atoi_separator(
+        bytes: &[u8],
+        radix: u32,
+        digit_separator: u8,
+    ) -> ParseResult<(Self, *const u8)>;
+
     // Parse integer from string with format.
     #[cfg(feature = "format")]
     fn atoi_format(
@@ -20,12 +36,31 @@ pub(crate) trait Atoi: Integer {
         radix: u32,
         format: NumberFormat,
     ) -> ParseResult<(Self, *const u8)>;
+
+    // Parse integer from string, accepting integral fractions and exponents.
+    #[inline(always)]
+    fn atoi_lenient(
+        bytes: &[u8],
+        radix: u32,
+        format: NumberFormat,
+    ) -> ParseResult<(Self, *const u8)> {
+        standalone_lenient(bytes, radix, format)
+    }
+
+    // Parse integer from string, detecting the radix from a C-style prefix.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    fn atoi_prefix(bytes: &[u8]) -> ParseResult<(Self, *const u8)> {
+        standalone_prefix(bytes)
+    }
 }
 
 // Implement atoi for type.
 macro_rules! atoi_impl {
-    ($($t:ty)*) => ($(
+    ($($t:ty => $u:ty ; )*) => ($(
         impl Atoi for $t {
+            type Unsigned = $u;
+
             #[inline(always)]
             fn atoi(bytes: &[u8], radix: u32)
                 -> ParseResult<($t, *const u8)>
@@ -33,6 +68,14 @@ macro_rules! atoi_impl {
                 standalone_no_separator(bytes, radix)
             }
 
+            #[inline(always)]
+            #[cfg(not(feature = "format"))]
+            fn atoi_separator(bytes: &[u8], radix: u32, digit_separator: u8)
+                -> ParseResult<($t, *const u8)>
+            {
+                standalone_ignore_separator(bytes, radix, digit_separator)
+            }
+
             #[inline(always)]
             #[cfg(feature = "format")]
             fn atoi_format(bytes: &[u8], radix: u32, format: NumberFormat)
@@ -44,14 +87,39 @@ macro_rules! atoi_impl {
     )*);
 }
 
-atoi_impl! { u8 u16 u32 u64 usize i8 i16 i32 i64 isize }
+atoi_impl! {
+    u8 => u8 ;
+    u16 => u16 ;
+    u32 => u32 ;
+    u64 => u64 ;
+    i8 => u8 ;
+    i16 => u16 ;
+    i32 => u32 ;
+    i64 => u64 ;
+}
+
+#[cfg(feature = "integers")]
+atoi_impl! { usize => usize ; isize => usize ; }
 
+#[cfg(feature = "integers")]
 impl Atoi for u128 {
+    type Unsigned = u128;
+
     #[inline(always)]
     fn atoi(bytes: &[u8], radix: u32) -> ParseResult<(u128, *const u8)> {
         standalone_128_no_separator::<u128, u64>(bytes, radix)
     }
 
+    #[inline(always)]
+    #[cfg(not(feature = "format"))]
+    fn atoi_separator(
+        bytes: &[u8],
+        radix: u32,
+        digit_separator: u8,
+    ) -> ParseResult<(u128, *const u8)> {
+        standalone_128_ignore_separator::<u128, u64>(bytes, radix, digit_separator)
+    }
+
     #[inline(always)]
     #[cfg(feature = "format")]
     fn atoi_format(
@@ -63,12 +131,25 @@ impl Atoi for u128 {
     }
 }
 
+#[cfg(feature = "integers")]
 impl Atoi for i128 {
+    type Unsigned = u128;
+
     #[inline(always)]
     fn atoi(bytes: &[u8], radix: u32) -> ParseResult<(i128, *const u8)> {
         standalone_128_no_separator::<i128, i64>(bytes, radix)
     }
 
+    #[inline(always)]
+    #[cfg(not(feature = "format"))]
+    fn atoi_separator(
+        bytes: &[u8],
+        radix: u32,
+        digit_separator: u8,
+    ) -> ParseResult<(i128, *const u8)> {
+        standalone_128_ignore_separator::<i128, i64>(bytes, radix, digit_separator)
+    }
+
     #[inline(always)]
     #[cfg(feature = "format")]
     fn atoi_format(
@@ -103,7 +184,39 @@ where
     atoi!(T, atoi, bytes, 10)
 }
 
-// Atoi with custom options.
+// Atoi with custom options, after any sign string.
+#[inline]
+fn atoi_no_sign_string<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions) -> Result<(T, usize)>
+where
+    T: Atoi,
+{
+    #[cfg(feature = "power_of_two")]
+    if options.radix_prefix() {
+        return atoi!(T, atoi_prefix, bytes);
+    }
+
+    if options.lenient() {
+        let format = options.format().unwrap_or(NumberFormat::STANDARD);
+        return atoi!(T, atoi_lenient, bytes, options.radix(), format);
+    }
+
+    #[cfg(not(feature = "format"))]
+    return match options.digit_separator() {
+        None => atoi!(T, atoi, bytes, options.radix()),
+        Some(separator) => atoi!(T, atoi_separator, bytes, options.radix(), separator),
+    };
+
+    #[cfg(feature = "format")]
+    return match (options.format(), options.digit_separator()) {
+        (None, None) => atoi!(T, atoi, bytes, options.radix()),
+        _ => atoi!(T, atoi_format, bytes, options.radix(), options.number_format()),
+    };
+}
+
+// Atoi with custom options, where the sign may be a sign string.
+//
+// A negative sign string is parsed as the magnitude of the integer,
+// which must not have another sign, and is then negated.
 #[inline]
 pub(crate) fn atoi_with_options<'a, T>(
     bytes: &'a [u8],
@@ -112,14 +225,81 @@ pub(crate) fn atoi_with_options<'a, T>(
 where
     T: Atoi,
 {
-    #[cfg(not(feature = "format"))]
-    return atoi!(T, atoi, bytes, options.radix());
+    let (sign, len) = match parse_sign_string(bytes, options.plus_signs(), options.minus_signs()) {
+        Some(sign_string) => sign_string,
+        None => return atoi_no_sign_string(bytes, options),
+    };
+    let shift = |error: Error| Error::from((error.code, error.index + len));
+    let digits = &bytes[len..];
+    match digits.first() {
+        Some(&b'+') | Some(&b'-') => return Err((ErrorCode::InvalidDigit, len).into()),
+        _ if sign == Sign::Negative && !T::IS_SIGNED => {
+            return Err((ErrorCode::InvalidDigit, 0).into())
+        },
+        _ => (),
+    }
+    if sign == Sign::Positive {
+        return match atoi_no_sign_string::<T>(digits, options) {
+            Ok((value, processed)) => Ok((value, processed + len)),
+            Err(error) => Err(shift(error)),
+        };
+    }
 
-    #[cfg(feature = "format")]
-    return match options.format() {
-        None => atoi!(T, atoi, bytes, options.radix()),
-        Some(format) => atoi!(T, atoi_format, bytes, options.radix(), format),
+    // Negate the magnitude, which underflows if it does not fit.
+    match atoi_no_sign_string::<T::Unsigned>(digits, options) {
+        Ok((magnitude, processed)) => {
+            let value = as_cast::<T, _>(magnitude).wrapping_neg();
+            match value > T::ZERO {
+                true => {
+                    let error = atoi_no_sign_string::<T>(digits, options).err();
+                    let index = error.map_or(processed, |error| error.index);
+                    Err((ErrorCode::Underflow, index + len).into())
+                },
+                false => Ok((value, processed + len)),
+            }
+        },
+        Err(error) if error.code == ErrorCode::Overflow => {
+            Err((ErrorCode::Underflow, error.index + len).into())
+        },
+        Err(error) => Err(shift(error)),
+    }
+}
+
+// Atoi with custom options, reporting the magnitude of overflows.
+#[inline]
+pub(crate) fn atoi_with_overflow<'a, T>(
+    bytes: &'a [u8],
+    options: &ParseIntegerOptions,
+) -> core::result::Result<(T, usize), (Error, Option<IntegerOverflow>)>
+where
+    T: Atoi,
+{
+    // The overflow context starts after any sign string.
+    let digits = match parse_sign_string(bytes, options.plus_signs(), options.minus_signs()) {
+        Some((_, len)) => &bytes[len..],
+        None => bytes,
     };
+    // Lenient integers may overflow from the exponent, not the digits.
+    atoi_with_options(bytes, options).map_err(|error| match error.code {
+        _ if options.lenient() => (error, None),
+        ErrorCode::Overflow => (error, Some(overflow_context(digits, options, Sign::Positive))),
+        ErrorCode::Underflow => (error, Some(overflow_context(digits, options, Sign::Negative))),
+        _ => (error, None),
+    })
+}
+
+// Atoi detecting the radix from a prefix or suffix.
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub(crate) fn atoi_detect_radix<T>(bytes: &[u8]) -> Result<(T, u32, usize)>
+where
+    T: Integer,
+{
+    let index = |ptr| distance(bytes.as_ptr(), ptr);
+    match standalone_detect_radix::<T>(bytes) {
+        Ok((value, radix, ptr)) => Ok((value, radix, index(ptr))),
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
 }
 
 // FROM LEXICAL
@@ -129,30 +309,56 @@ from_lexical!(atoi, u8);
 from_lexical!(atoi, u16);
 from_lexical!(atoi, u32);
 from_lexical!(atoi, u64);
+#[cfg(feature = "integers")]
 from_lexical!(atoi, usize);
+#[cfg(feature = "integers")]
 from_lexical!(atoi, u128);
 
 from_lexical!(atoi, i8);
 from_lexical!(atoi, i16);
 from_lexical!(atoi, i32);
 from_lexical!(atoi, i64);
+#[cfg(feature = "integers")]
 from_lexical!(atoi, isize);
+#[cfg(feature = "integers")]
 from_lexical!(atoi, i128);
 
 from_lexical_with_options!(atoi_with_options, u8);
 from_lexical_with_options!(atoi_with_options, u16);
 from_lexical_with_options!(atoi_with_options, u32);
 from_lexical_with_options!(atoi_with_options, u64);
+#[cfg(feature = "integers")]
 from_lexical_with_options!(atoi_with_options, usize);
+#[cfg(feature = "integers")]
 from_lexical_with_options!(atoi_with_options, u128);
 
 from_lexical_with_options!(atoi_with_options, i8);
 from_lexical_with_options!(atoi_with_options, i16);
 from_lexical_with_options!(atoi_with_options, i32);
 from_lexical_with_options!(atoi_with_options, i64);
+#[cfg(feature = "integers")]
 from_lexical_with_options!(atoi_with_options, isize);
+#[cfg(feature = "integers")]
 from_lexical_with_options!(atoi_with_options, i128);
 
+from_lexical_with_overflow!(atoi_with_overflow, u8);
+from_lexical_with_overflow!(atoi_with_overflow, u16);
+from_lexical_with_overflow!(atoi_with_overflow, u32);
+from_lexical_with_overflow!(atoi_with_overflow, u64);
+#[cfg(feature = "integers")]
+from_lexical_with_overflow!(atoi_with_overflow, usize);
+#[cfg(feature = "integers")]
+from_lexical_with_overflow!(atoi_with_overflow, u128);
+
+from_lexical_with_overflow!(atoi_with_overflow, i8);
+from_lexical_with_overflow!(atoi_with_overflow, i16);
+from_lexical_with_overflow!(atoi_with_overflow, i32);
+from_lexical_with_overflow!(atoi_with_overflow, i64);
+#[cfg(feature = "integers")]
+from_lexical_with_overflow!(atoi_with_overflow, isize);
+#[cfg(feature = "integers")]
+from_lexical_with_overflow!(atoi_with_overflow, i128);
+
 // TESTS
 // -----
 
@@ -160,7 +366,6 @@ from_lexical_with_options!(atoi_with_options, i128);
 mod tests {
     use crate::error::*;
     use crate::traits::*;
-    #[cfg(any(feature = "format", feature = "power_of_two"))]
     use crate::util::*;
 
     #[cfg(feature = "property_tests")]
@@ -324,6 +529,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn u128_decimal_test() {
         assert_eq!(Ok(0), u128::from_lexical(b"0"));
         assert_eq!(
@@ -343,6 +549,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn i128_decimal_test() {
         assert_eq!(Ok(0), i128::from_lexical(b"0"));
         assert_eq!(
@@ -435,6 +642,21 @@ mod tests {
         assert!(i32::from_lexical_with_options(b"31_", &options).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn i32_invalid_digit_separator_test() {
+        let format = NumberFormat::from_spec("separator=_,integer_internal_digit_separator");
+        let options = ParseIntegerOptions::builder().format(format).build().unwrap();
+        let error = |index| Err((ErrorCode::InvalidDigitSeparator, index).into());
+        assert_eq!(error(1), i32::from_lexical_with_options(b"1__000", &options));
+        assert_eq!(error(0), i32::from_lexical_with_options(b"_1000", &options));
+        assert_eq!(error(4), i32::from_lexical_with_options(b"1000_", &options));
+        assert_eq!(
+            Err((ErrorCode::InvalidDigit, 1).into()),
+            i32::from_lexical_with_options(b"1a00", &options)
+        );
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn i32_json_no_leading_zero() {
@@ -446,6 +668,192 @@ mod tests {
         assert!(i32::from_lexical_with_options(b"-012", &options).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn i64_json_test() {
+        let options = ParseIntegerOptions::json();
+        assert_eq!(i64::from_lexical_with_options(b"0", &options), Ok(0));
+        assert_eq!(i64::from_lexical_with_options(b"-0", &options), Ok(0));
+        assert_eq!(i64::from_lexical_with_options(b"-15", &options), Ok(-15));
+        assert_eq!(i64::from_lexical_with_options(b"-9223372036854775808", &options), Ok(i64::min_value()));
+        assert_eq!(u64::from_lexical_with_options(b"18446744073709551615", &options), Ok(u64::max_value()));
+
+        assert_eq!(i64::from_lexical_with_options(b"+1", &options), Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()));
+        assert_eq!(u64::from_lexical_with_options(b"+1", &options), Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()));
+        assert_eq!(i64::from_lexical_with_options(b"01", &options), Err((ErrorCode::InvalidLeadingZeros, 0).into()));
+        assert_eq!(i64::from_lexical_with_options(b"-01", &options), Err((ErrorCode::InvalidLeadingZeros, 0).into()));
+        assert_eq!(i64::from_lexical_with_options(b"1.0", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(i64::from_lexical_with_options(b" 1", &options), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(i64::from_lexical_with_options(b"1_0", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(i64::from_lexical_with_options(b"", &options), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(i64::from_lexical_with_options(b"-", &options), Err((ErrorCode::Empty, 1).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn parse_rust_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_rust_literal::<i64>(bytes);
+        assert_eq!(parse(b"0"), Ok((0, None)));
+        assert_eq!(parse(b"1_000_000"), Ok((1000000, None)));
+        assert_eq!(parse(b"1__0_"), Ok((10, None)));
+        assert_eq!(parse(b"15i64"), Ok((15, Some(RustSuffix::I64))));
+        assert_eq!(parse(b"15_u8"), Ok((15, Some(RustSuffix::U8))));
+        assert_eq!(parse(b"15usize"), Ok((15, Some(RustSuffix::Usize))));
+        assert_eq!(crate::parse_rust_literal::<u8>(b"255u8"), Ok((255, Some(RustSuffix::U8))));
+
+        assert_eq!(parse(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse(b"_1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"-1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"+1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"1f32"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1.0"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1e3"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"15u"), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(parse(b"15u8 "), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(crate::parse_rust_literal::<u8>(b"256u8"), Err((ErrorCode::Overflow, 2).into()));
+
+        // Radix prefixes are lowercase, and digit separators may follow them.
+        #[cfg(feature = "power_of_two")]
+        {
+            assert_eq!(parse(b"0xFF"), Ok((255, None)));
+            assert_eq!(parse(b"0x_ff_u8"), Ok((255, Some(RustSuffix::U8))));
+            assert_eq!(parse(b"0o17"), Ok((15, None)));
+            assert_eq!(parse(b"0b1010_1010"), Ok((170, None)));
+            assert_eq!(parse(b"0x1f32"), Ok((0x1f32, None)));
+            assert_eq!(parse(b"0xFFi32"), Ok((255, Some(RustSuffix::I32))));
+            assert_eq!(parse(b"0x"), Err((ErrorCode::Empty, 2).into()));
+            assert_eq!(parse(b"0x_"), Err((ErrorCode::Empty, 3).into()));
+            assert_eq!(parse(b"0b12"), Err((ErrorCode::InvalidDigit, 3).into()));
+            assert_eq!(parse(b"0XFF"), Err((ErrorCode::InvalidDigit, 1).into()));
+        }
+        #[cfg(not(feature = "power_of_two"))]
+        assert_eq!(parse(b"0xFF"), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn parse_cxx_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_cxx_literal::<i64>(bytes);
+        assert_eq!(parse(b"0"), Ok((0, None)));
+        assert_eq!(parse(b"1'000'000"), Ok((1000000, None)));
+        assert_eq!(parse(b"15u"), Ok((15, Some(CxxSuffix::Unsigned))));
+        assert_eq!(parse(b"15L"), Ok((15, Some(CxxSuffix::Long))));
+        assert_eq!(parse(b"15uL"), Ok((15, Some(CxxSuffix::UnsignedLong))));
+        assert_eq!(parse(b"15ll"), Ok((15, Some(CxxSuffix::LongLong))));
+        assert_eq!(parse(b"15ULL"), Ok((15, Some(CxxSuffix::UnsignedLongLong))));
+        assert_eq!(parse(b"0u"), Ok((0, Some(CxxSuffix::Unsigned))));
+
+        assert_eq!(parse(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse(b"'1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"-1"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"1'"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1''0"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1_0"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1f"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"1.0"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"15lL"), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(parse(b"15uu"), Err((ErrorCode::InvalidDigit, 2).into()));
+
+        // Radix prefixes are case-insensitive, and octal integers have a leading `0`.
+        #[cfg(feature = "power_of_two")]
+        {
+            assert_eq!(parse(b"0xFF"), Ok((255, None)));
+            assert_eq!(parse(b"0XffU"), Ok((255, Some(CxxSuffix::Unsigned))));
+            assert_eq!(parse(b"0b1010'1010"), Ok((170, None)));
+            assert_eq!(parse(b"0B1ll"), Ok((1, Some(CxxSuffix::LongLong))));
+            assert_eq!(parse(b"017"), Ok((15, None)));
+            assert_eq!(parse(b"0'17ul"), Ok((15, Some(CxxSuffix::UnsignedLong))));
+            assert_eq!(parse(b"0x"), Err((ErrorCode::Empty, 2).into()));
+            assert_eq!(parse(b"0x'FF"), Err((ErrorCode::InvalidDigit, 2).into()));
+            assert_eq!(parse(b"0b12"), Err((ErrorCode::InvalidDigit, 3).into()));
+            assert_eq!(parse(b"018"), Err((ErrorCode::InvalidDigit, 2).into()));
+        }
+        #[cfg(not(feature = "power_of_two"))]
+        assert_eq!(parse(b"0xFF"), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "hdl")]
+    fn parse_verilog_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_verilog_literal::<u64>(bytes);
+        let sized = |width, signed, value| Ok(SizedLiteral { width, signed, value });
+        assert_eq!(parse(b"8'hFF"), sized(Some(8), false, 0xFF));
+        assert_eq!(parse(b"12'b1010_1010"), sized(Some(12), false, 0xAA));
+        assert_eq!(parse(b"8'SHff"), sized(Some(8), true, 0xFF));
+        assert_eq!(parse(b"16'o17_"), sized(Some(16), false, 0o17));
+        assert_eq!(parse(b"'d15"), sized(None, false, 15));
+        assert_eq!(parse(b"'sd15"), sized(None, true, 15));
+        assert_eq!(parse(b"1_5"), sized(None, true, 15));
+        assert_eq!(parse(b"64'hFFFF_FFFF_FFFF_FFFF"), sized(Some(64), false, u64::max_value()));
+        assert_eq!(parse(b"128'h1"), sized(Some(128), false, 1));
+        assert_eq!(parse(b"1'b0"), sized(Some(1), false, 0));
+
+        assert_eq!(parse(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse(b"8'h"), Err((ErrorCode::Empty, 3).into()));
+        assert_eq!(parse(b"8'h_F"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"8'hxF"), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"8'hFz"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse(b"8'b102"), Err((ErrorCode::InvalidDigit, 5).into()));
+        assert_eq!(parse(b"8'h1FF"), Err((ErrorCode::Overflow, 3).into()));
+        assert_eq!(parse(b"1'b10"), Err((ErrorCode::Overflow, 3).into()));
+        assert_eq!(parse(b"80'h1_0000_0000_0000_0000"), Err((ErrorCode::Overflow, 24).into()));
+        assert_eq!(parse(b"-8'hFF"), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse(b"8 'hFF"), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "hdl")]
+    fn parse_vhdl_literal_test() {
+        let parse = |bytes: &[u8]| crate::parse_vhdl_literal::<u32>(bytes);
+        let based = |value| Ok(SizedLiteral { width: None, signed: false, value });
+        assert_eq!(parse(b"16#FF#"), based(0xFF));
+        assert_eq!(parse(b"16#ff#"), based(0xFF));
+        assert_eq!(parse(b"2#1010_1010#"), based(0xAA));
+        assert_eq!(parse(b"8:17:"), based(0o17));
+        assert_eq!(parse(b"10#15#"), based(15));
+        assert_eq!(parse(b"16#F#E2"), based(0xF00));
+        assert_eq!(parse(b"2#1#e+3_1"), based(1 << 31));
+        assert_eq!(parse(b"16#0#E99999999999"), based(0));
+
+        assert_eq!(parse(b"16##"), Err((ErrorCode::Empty, 3).into()));
+        assert_eq!(parse(b"16#FG#"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse(b"2#102#"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse(b"16#1_0000_0000#"), Err((ErrorCode::Overflow, 13).into()));
+        assert_eq!(parse(b"2#1#E32"), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(parse(b"16#F_#"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse(b"16#FF"), Err((ErrorCode::Empty, 5).into()));
+        #[cfg(feature = "radix")]
+        assert_eq!(parse(b"3#21#"), based(7));
+        #[cfg(not(feature = "radix"))]
+        assert_eq!(parse(b"3#21#"), Err((ErrorCode::InvalidDigit, 0).into()));
+    }
+
+    #[test]
+    fn parse_json_integer_test() {
+        let options = ParseIntegerOptions::new();
+        let parse = |bytes: &[u8]| crate::parse_json_integer(bytes, &options);
+        assert_eq!(parse(b"15"), Ok(JsonInteger::Signed(15)));
+        assert_eq!(parse(b"-15"), Ok(JsonInteger::Signed(-15)));
+        assert_eq!(parse(b"9223372036854775807"), Ok(JsonInteger::Signed(i64::max_value())));
+        assert_eq!(parse(b"9223372036854775808"), Ok(JsonInteger::Unsigned(1 << 63)));
+        assert_eq!(parse(b"18446744073709551615"), Ok(JsonInteger::Unsigned(u64::max_value())));
+        assert_eq!(parse(b"18446744073709551616"), Err((ErrorCode::Overflow, 19).into()));
+        assert_eq!(parse(b"-9223372036854775809"), Err((ErrorCode::Underflow, 19).into()));
+        assert_eq!(parse(b"1x"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse(b"99999999999999999999x"), Err((ErrorCode::Overflow, 19).into()));
+        assert_eq!(JsonInteger::Unsigned(u64::max_value()).as_i128(), u64::max_value() as i128);
+        assert_eq!(JsonInteger::Signed(-1).as_i128(), -1);
+
+        #[cfg(feature = "format")]
+        {
+            let options = ParseIntegerOptions::json();
+            let parse = |bytes: &[u8]| crate::parse_json_integer(bytes, &options);
+            assert_eq!(parse(b"18446744073709551615"), Ok(JsonInteger::Unsigned(u64::max_value())));
+            assert_eq!(parse(b"+1"), Err((ErrorCode::InvalidPositiveMantissaSign, 0).into()));
+            assert_eq!(parse(b"01"), Err((ErrorCode::InvalidLeadingZeros, 0).into()));
+        }
+    }
+
     #[test]
     #[cfg(feature = "power_of_two")]
     fn i32_binary_test() {
@@ -454,6 +862,257 @@ mod tests {
         assert_eq!(i32::from_lexical_with_options(b"-11", &options), Ok(-3));
     }
 
+    #[test]
+    fn i32_lenient_test() {
+        let options = ParseIntegerOptions::builder().lenient(true).build().unwrap();
+        assert_eq!(i32::from_lexical_with_options(b"3", &options), Ok(3));
+        assert_eq!(i32::from_lexical_with_options(b"3.000", &options), Ok(3));
+        assert_eq!(i32::from_lexical_with_options(b"3.", &options), Ok(3));
+        assert_eq!(i32::from_lexical_with_options(b"-3.0", &options), Ok(-3));
+        assert_eq!(i32::from_lexical_with_options(b"1e3", &options), Ok(1000));
+        assert_eq!(i32::from_lexical_with_options(b"1E+3", &options), Ok(1000));
+        assert_eq!(i32::from_lexical_with_options(b"2.5e1", &options), Ok(25));
+        assert_eq!(i32::from_lexical_with_options(b"2.5e3", &options), Ok(2500));
+        assert_eq!(i32::from_lexical_with_options(b"2500e-2", &options), Ok(25));
+        assert_eq!(i32::from_lexical_with_options(b".5e1", &options), Ok(5));
+        assert_eq!(i32::from_lexical_with_options(b"0e999999999999", &options), Ok(0));
+        assert_eq!(i32::from_lexical_with_options(b"0.0e-5", &options), Ok(0));
+
+        assert_eq!(i32::from_lexical_with_options(b"3.5", &options), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(i32::from_lexical_with_options(b"25e-1", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(i32::from_lexical_with_options(b"1e10", &options), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(i32::from_lexical_with_options(b"-1e10", &options), Err((ErrorCode::Underflow, 5).into()));
+        assert_eq!(i32::from_lexical_with_options(b"1e", &options), Err((ErrorCode::EmptyExponent, 2).into()));
+        assert_eq!(i32::from_lexical_with_options(b".", &options), Err((ErrorCode::EmptyMantissa, 1).into()));
+        assert_eq!(i32::from_lexical_with_options(b"", &options), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(i32::from_lexical_with_options(b"3.0x", &options), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(i32::from_lexical_partial_with_options(b"3.0x", &options), Ok((3, 3)));
+        assert_eq!(u8::from_lexical_with_options(b"2.55e2", &options), Ok(255));
+        assert_eq!(u8::from_lexical_with_options(b"2.56e2", &options), Err((ErrorCode::Overflow, 3).into()));
+
+        // Disabled by default.
+        let options = ParseIntegerOptions::new();
+        assert_eq!(i32::from_lexical_with_options(b"3.0", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    fn i32_digit_separator_test() {
+        let options = ParseIntegerOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        assert_eq!(i32::from_lexical_with_options(b"1_000_000", &options), Ok(1000000));
+        assert_eq!(i32::from_lexical_with_options(b"-1__0", &options), Ok(-10));
+        assert_eq!(i32::from_lexical_with_options(b"_1_", &options), Ok(1));
+        #[cfg(feature = "integers")]
+        assert_eq!(u128::from_lexical_with_options(b"1_000_000_000_000_000_000_000", &options), Ok(10u128.pow(21)));
+        #[cfg(feature = "integers")]
+        assert_eq!(i128::from_lexical_with_options(b"-1_000", &options), Ok(-1000));
+        assert_eq!(i32::from_lexical_partial_with_options(b"1_000 x", &options), Ok((1000, 5)));
+        assert_eq!(i32::from_lexical_with_options(b"1,000", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(i32::from_lexical_with_options(b"_", &options), Err((ErrorCode::Empty, 0).into()));
+
+        // Disabled by default.
+        let options = ParseIntegerOptions::new();
+        assert_eq!(i32::from_lexical_with_options(b"1_000", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+
+        // Invalid digit separators and lenient mode are rejected.
+        assert!(ParseIntegerOptions::builder().digit_separator(Some(b'1')).build().is_none());
+        assert!(ParseIntegerOptions::builder().digit_separator(Some(b'-')).build().is_none());
+        assert!(ParseIntegerOptions::builder().digit_separator(Some(b'_')).lenient(true).build().is_none());
+    }
+
+    #[test]
+    fn i32_sign_strings_test() {
+        let options = ParseIntegerOptions::builder()
+            .plus_signs(&[b"\xEF\xB9\xA2"])
+            .minus_signs(&[b"\xE2\x88\x92", b"neg "])
+            .build()
+            .unwrap();
+        assert_eq!(i32::from_lexical_with_options("\u{2212}15".as_bytes(), &options), Ok(-15));
+        assert_eq!(i32::from_lexical_with_options("\u{fe62}15".as_bytes(), &options), Ok(15));
+        assert_eq!(i32::from_lexical_with_options(b"neg 15", &options), Ok(-15));
+        assert_eq!(i32::from_lexical_with_options(b"-15", &options), Ok(-15));
+        assert_eq!(i32::from_lexical_with_options("\u{2212}0".as_bytes(), &options), Ok(0));
+        assert_eq!(i8::from_lexical_with_options("\u{2212}128".as_bytes(), &options), Ok(-128));
+        assert_eq!(i64::from_lexical_with_options("\u{2212}9223372036854775808".as_bytes(), &options), Ok(i64::min_value()));
+        assert_eq!(u8::from_lexical_with_options("\u{fe62}255".as_bytes(), &options), Ok(255));
+        assert_eq!(i32::from_lexical_partial_with_options("\u{2212}15 x".as_bytes(), &options), Ok((-15, 5)));
+
+        // Errors match the ASCII sign, after the longer sign string.
+        let ascii = |bytes: &[u8]| i8::from_lexical_with_options(bytes, &options);
+        let check = |digits: &[u8]| {
+            let mut buffer = [0u8; 16];
+            buffer[..3].copy_from_slice(b"\xE2\x88\x92");
+            buffer[3..3 + digits.len()].copy_from_slice(digits);
+            let result = ascii(&buffer[..3 + digits.len()]);
+            buffer[2] = b'-';
+            let expected = ascii(&buffer[2..3 + digits.len()]);
+            assert_eq!(result, expected.map_err(|error| (error.code, error.index + 2).into()));
+        };
+        let digits: [&[u8]; 9] = [b"", b"0", b"127", b"129", b"200", b"256", b"300", b"1x", b"99999"];
+        for &digits in digits.iter() {
+            check(digits);
+        }
+        assert_eq!(ascii("\u{2212}200".as_bytes()), Err((ErrorCode::Underflow, 5).into()));
+        assert_eq!(ascii("\u{2212}-1".as_bytes()), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(ascii("\u{2212}+1".as_bytes()), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(ascii("\u{2212}\u{2212}1".as_bytes()), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(u8::from_lexical_with_options("\u{2212}1".as_bytes(), &options), Err((ErrorCode::InvalidDigit, 0).into()));
+        #[cfg(feature = "integers")]
+        assert_eq!(i128::from_lexical_with_options("\u{2212}170141183460469231731687303715884105728".as_bytes(), &options), Ok(i128::min_value()));
+
+        // Overflows report the magnitude after the sign string.
+        let (error, overflow) = i8::from_lexical_with_overflow("\u{2212}200".as_bytes(), &options).unwrap_err();
+        assert_eq!(error, (ErrorCode::Underflow, 5).into());
+        let overflow = overflow.unwrap();
+        assert_eq!(overflow.sign(), Sign::Negative);
+        assert_eq!(overflow.magnitude(), Some(200));
+
+        // Sign strings must not start with part of the number.
+        let builder = ParseIntegerOptions::builder();
+        assert!(builder.minus_signs(&[b""]).build().is_none());
+        assert!(builder.minus_signs(&[b"-x"]).build().is_none());
+        assert!(builder.plus_signs(&[b"1"]).build().is_none());
+        assert!(builder.minus_signs(&[b"\xE2\x88\x92"]).build().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn i32_radix_prefix_test() {
+        let options = ParseIntegerOptions::builder().radix_prefix(true).build().unwrap();
+        assert_eq!(i32::from_lexical_with_options(b"0", &options), Ok(0));
+        assert_eq!(i32::from_lexical_with_options(b"42", &options), Ok(42));
+        assert_eq!(i32::from_lexical_with_options(b"017", &options), Ok(15));
+        assert_eq!(i32::from_lexical_with_options(b"0x1F", &options), Ok(31));
+        assert_eq!(i32::from_lexical_with_options(b"0X1f", &options), Ok(31));
+        assert_eq!(i32::from_lexical_with_options(b"-0x1F", &options), Ok(-31));
+        assert_eq!(i32::from_lexical_with_options(b"+017", &options), Ok(15));
+        assert_eq!(i8::from_lexical_with_options(b"-0x80", &options), Ok(-128));
+        #[cfg(feature = "integers")]
+        assert_eq!(u128::from_lexical_with_options(b"0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", &options), Ok(u128::max_value()));
+
+        assert_eq!(i32::from_lexical_with_options(b"08", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(i32::from_lexical_with_options(b"0x", &options), Err((ErrorCode::Empty, 2).into()));
+        assert_eq!(i32::from_lexical_with_options(b"0xG", &options), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(i8::from_lexical_with_options(b"0x80", &options), Err((ErrorCode::Overflow, 3).into()));
+        assert_eq!(u32::from_lexical_with_options(b"-0x1", &options), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(i32::from_lexical_partial_with_options(b"0x1Fg", &options), Ok((31, 4)));
+
+        // Disabled by default.
+        let options = ParseIntegerOptions::new();
+        assert_eq!(i32::from_lexical_with_options(b"017", &options), Ok(17));
+        assert_eq!(i32::from_lexical_with_options(b"0x1F", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+
+        // Only the default parser is supported.
+        let builder = ParseIntegerOptions::builder().radix_prefix(true);
+        assert!(builder.radix(16).build().is_none());
+        assert!(builder.lenient(true).build().is_none());
+        assert!(builder.digit_separator(Some(b'_')).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn detect_radix_test() {
+        use crate::parse_detect_radix;
+
+        assert_eq!(parse_detect_radix::<i32>(b"42"), Ok((42, 10)));
+        assert_eq!(parse_detect_radix::<i32>(b"0x1F"), Ok((31, 16)));
+        assert_eq!(parse_detect_radix::<i32>(b"0O17"), Ok((15, 8)));
+        assert_eq!(parse_detect_radix::<i32>(b"0b101"), Ok((5, 2)));
+        assert_eq!(parse_detect_radix::<i32>(b"-0x1F"), Ok((-31, 16)));
+        assert_eq!(parse_detect_radix::<i32>(b"+0b1"), Ok((1, 2)));
+        assert_eq!(parse_detect_radix::<i32>(b"1Fh"), Ok((31, 16)));
+        assert_eq!(parse_detect_radix::<i32>(b"0BAH"), Ok((186, 16)));
+        assert_eq!(parse_detect_radix::<i32>(b"-17o"), Ok((-15, 8)));
+        assert_eq!(parse_detect_radix::<i32>(b"17Q"), Ok((15, 8)));
+        assert_eq!(parse_detect_radix::<i32>(b"0h"), Ok((0, 16)));
+        assert_eq!(parse_detect_radix::<i8>(b"-0x80"), Ok((-128, 16)));
+        #[cfg(feature = "integers")]
+        assert_eq!(parse_detect_radix::<u128>(b"0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"), Ok((u128::max_value(), 16)));
+
+        assert_eq!(parse_detect_radix::<i32>(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"0x"), Err((ErrorCode::Empty, 2).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"h"), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"0b102"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"0x1Fq"), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"18o"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_detect_radix::<i32>(b"1F"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_detect_radix::<u8>(b"100h"), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(parse_detect_radix::<u8>(b"-0x1"), Err((ErrorCode::InvalidDigit, 0).into()));
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn i64_protobuf_text_test() {
+        let options = ParseIntegerOptions::protobuf_text();
+        assert_eq!(i64::from_lexical_with_options(b"-9223372036854775808", &options), Ok(i64::min_value()));
+        assert_eq!(u64::from_lexical_with_options(b"0xFFFFFFFFFFFFFFFF", &options), Ok(u64::max_value()));
+        assert_eq!(i64::from_lexical_with_options(b"0777", &options), Ok(511));
+        assert_eq!(i64::from_lexical_with_options(b"1.5", &options), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    fn overflow_test() {
+        let options = ParseIntegerOptions::new();
+        assert_eq!(u8::from_lexical_with_overflow(b"255", &options), Ok(255));
+        assert_eq!(u8::from_lexical_with_overflow(b"2a", &options), Err(((ErrorCode::InvalidDigit, 1).into(), None)));
+        assert_eq!(u8::from_lexical_partial_with_overflow(b"25 ", &options), Ok((25, 2)));
+
+        let (error, overflow) = u8::from_lexical_with_overflow(b"300", &options).unwrap_err();
+        assert_eq!(error, (ErrorCode::Overflow, 2).into());
+        let overflow = overflow.unwrap();
+        assert_eq!(overflow.sign(), Sign::Positive);
+        assert_eq!(overflow.digits(), 3);
+        assert_eq!(overflow.magnitude(), Some(300));
+        assert_eq!(overflow.wrapped() as u8, 44);
+
+        let (error, overflow) = i8::from_lexical_with_overflow(b"-200", &options).unwrap_err();
+        assert_eq!(error, (ErrorCode::Underflow, 3).into());
+        let overflow = overflow.unwrap();
+        assert_eq!(overflow.sign(), Sign::Negative);
+        assert_eq!(overflow.digits(), 3);
+        assert_eq!(overflow.magnitude(), Some(200));
+        assert_eq!(overflow.wrapped() as i8, 56);
+        assert_eq!(overflow.wrapped() as i128, -200);
+
+        // Partial parsers report the digits before the invalid digit.
+        let (_, overflow) = u32::from_lexical_partial_with_overflow(b"99999999999 x", &options).unwrap_err();
+        assert_eq!(overflow.unwrap().magnitude(), Some(99999999999));
+
+        // Magnitudes that do not fit in a u128 are only reported wrapped.
+        #[cfg(feature = "integers")]
+        {
+            let bytes = b"680564733841876926926749214863536422912";
+            let (_, overflow) = u128::from_lexical_with_overflow(bytes, &options).unwrap_err();
+            let overflow = overflow.unwrap();
+            assert_eq!(overflow.digits(), 39);
+            assert_eq!(overflow.magnitude(), None);
+            assert_eq!(overflow.wrapped(), 0);
+        }
+
+        // Digit separators are not counted as digits.
+        let options = ParseIntegerOptions::builder().digit_separator(Some(b'_')).build().unwrap();
+        let (_, overflow) = u16::from_lexical_with_overflow(b"1_000_000", &options).unwrap_err();
+        let overflow = overflow.unwrap();
+        assert_eq!(overflow.digits(), 7);
+        assert_eq!(overflow.magnitude(), Some(1000000));
+
+        // Lenient overflows may come from the exponent.
+        let options = ParseIntegerOptions::builder().lenient(true).build().unwrap();
+        assert_eq!(i32::from_lexical_with_overflow(b"-1e10", &options), Err(((ErrorCode::Underflow, 5).into(), None)));
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn radix_prefix_overflow_test() {
+        let options = ParseIntegerOptions::builder().radix_prefix(true).build().unwrap();
+        let (_, overflow) = i8::from_lexical_with_overflow(b"0x1FF", &options).unwrap_err();
+        let overflow = overflow.unwrap();
+        assert_eq!(overflow.digits(), 3);
+        assert_eq!(overflow.magnitude(), Some(0x1FF));
+
+        let (_, overflow) = u8::from_lexical_with_overflow(b"0777", &options).unwrap_err();
+        assert_eq!(overflow.unwrap().magnitude(), Some(0o777));
+    }
+
     #[cfg(feature = "property_tests")]
     proptest! {
         #[test]
@@ -98,7 +98,7 @@ fn validate_no_leading_zeros<'a>(
     // Check if the next character is a sign symbol.
     let index = distance(digits.as_ptr(), ptr);
     let digits = &digits[..index];
-    let mut iter = iterate_digits_ignore_separator(digits, digit_separator);
+    let mut iter = SeparatorDigits::new(digits, digit_separator);
     let is_zero = match iter.next() {
         Some(&b'+') | Some(&b'-') => false,
         Some(&b'0') => true,
@@ -119,6 +119,23 @@ fn validate_no_leading_zeros<'a>(
     }
 }
 
+/// Validate the sign of the integer is allowed by the format.
+#[inline]
+#[cfg(feature = "format")]
+fn validate_sign(digits: &[u8], digit_separator: u8, format: NumberFormat) -> ParseResult<()> {
+    let mut iter = SeparatorDigits::new(digits, digit_separator);
+    match iter.next() {
+        Some(&b'+') if format.no_positive_mantissa_sign() => {
+            Err((ErrorCode::InvalidPositiveMantissaSign, digits.as_ptr()))
+        },
+        Some(&b'+') | Some(&b'-') => Ok(()),
+        _ if format.required_mantissa_sign() => {
+            Err((ErrorCode::MissingMantissaSign, digits.as_ptr()))
+        },
+        _ => Ok(()),
+    }
+}
+
 // STANDALONE
 // ----------
 
@@ -144,7 +161,7 @@ macro_rules! parse_digits {
 
 // Parse the digits for the atoi processor.
 #[inline(always)]
-fn parse_digits<'a, T, Iter>(
+pub(super) fn parse_digits<'a, T, Iter>(
     digits: &[u8],
     mut iter: Iter,
     radix: u32,
@@ -173,23 +190,22 @@ where
     T: Integer,
 {
     let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
-    let iter = iterate_digits_no_separator(digits, b'\x00');
+    let iter = ContiguousDigits::new(digits, b'\x00');
     parse_digits(digits, iter, radix, sign)
 }
 
 // Standalone atoi processor with digit separators.
 // Consumes leading, internal, trailing, and consecutive digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
 fn standalone_iltc<T>(bytes: &[u8], radix: u32, digit_separator: u8) -> ParseResult<(T, *const u8)>
 where
     T: Integer,
 {
     let (sign, digits) = parse_sign_lc_separator::<T>(bytes, digit_separator);
-    if digits.is_empty() {
+    if digits.iter().all(|&c| c == digit_separator) {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
-    let iter = iterate_digits_ignore_separator(digits, digit_separator);
+    let iter = SeparatorDigits::new(digits, digit_separator);
     parse_digits(digits, iter, radix, sign)
 }
 
@@ -213,7 +229,7 @@ macro_rules! standalone_atoi_separator {
 
             // Extract the integer subslice, then parse.
             let leading = $consume(digits, radix, digit_separator).0;
-            let iter = iterate_digits_ignore_separator(leading, digit_separator);
+            let iter = SeparatorDigits::new(leading, digit_separator);
 
             parse_digits(leading, iter, radix, sign)
         }
@@ -310,6 +326,20 @@ where
     standalone(bytes, radix)
 }
 
+// Standalone atoi processor ignoring digit separators anywhere in the digits.
+#[inline(always)]
+#[cfg(not(feature = "format"))]
+pub(crate) fn standalone_ignore_separator<T>(
+    bytes: &[u8],
+    radix: u32,
+    digit_separator: u8,
+) -> ParseResult<(T, *const u8)>
+where
+    T: Integer,
+{
+    standalone_iltc(bytes, radix, digit_separator)
+}
+
 // Extract exponent with a digit separator in the exponent component.
 #[inline(always)]
 #[cfg(feature = "format")]
@@ -322,6 +352,7 @@ where
     V: Integer,
 {
     let digit_separator = format.digit_separator();
+    validate_sign(bytes, digit_separator, format)?;
     let (value, ptr) = generate_interface!(
         format => format,
         mask => INTEGER_DIGIT_SEPARATOR_FLAG_MASK,
@@ -363,11 +394,13 @@ where
 // This is the same as the u128 divisor, so don't duplicate the values
 // there.
 #[inline(always)]
+#[cfg(feature = "integers")]
 fn step_u64(radix: u32) -> usize {
     u128_divisor(radix).1
 }
 
 // Add 64-bit temporary to the 128-bit value.
+#[cfg(feature = "integers")]
 macro_rules! add_temporary_128 {
     ($value:ident, $tmp:ident, $step_power:ident, $ptr:expr, $op:ident, $code:ident) => {
         if !$value.is_zero() {
@@ -384,6 +417,7 @@ macro_rules! add_temporary_128 {
 }
 
 /// Iterate over the digits and iteratively process them.
+#[cfg(feature = "integers")]
 macro_rules! parse_digits_u128 {
     ($value:ident, $iter:ident, $radix:ident, $step:ident, $op:ident, $code:ident) => {{
         // Break the input into chunks of len `step`, which can be parsed
@@ -421,6 +455,7 @@ macro_rules! parse_digits_u128 {
 
 /// Quickly parse digits using a 64-bit intermediate for the 128-bit atoi processor.
 #[inline(always)]
+#[cfg(feature = "integers")]
 fn parse_digits_128_fast<'a, W, N, Iter>(
     digits: &[u8],
     iter: Iter,
@@ -438,6 +473,7 @@ where
 
 /// Slowly parse digits for the 128-bit atoi processor.
 #[inline(always)]
+#[cfg(feature = "integers")]
 fn parse_digits_128_slow<'a, T, Iter>(
     digits: &[u8],
     mut iter: Iter,
@@ -467,6 +503,7 @@ where
 /// This is a similar approach to what we take in the arbitrary-precision
 /// arithmetic.
 #[inline(always)]
+#[cfg(feature = "integers")]
 fn parse_digits_128<'a, W, N, Iter>(
     digits: &[u8],
     iter: Iter,
@@ -494,20 +531,21 @@ where
 
 /// Standalone atoi processor for 128-bit integers without a digit separator.
 #[inline(always)]
+#[cfg(feature = "integers")]
 fn standalone_128<W, N>(bytes: &[u8], radix: u32) -> ParseResult<(W, *const u8)>
 where
     W: Integer,
     N: Integer,
 {
     let (sign, digits) = parse_sign!(bytes, W::IS_SIGNED, Empty);
-    let iter = iterate_digits_no_separator(digits, b'\x00');
+    let iter = ContiguousDigits::new(digits, b'\x00');
     parse_digits_128::<W, N, _>(digits, iter, radix, sign)
 }
 
 /// Standalone atoi processor for 128-bit integers with digit separators.
 /// Consumes leading, internal, trailing, and consecutive digit separators.
 #[inline(always)]
-#[cfg(feature = "format")]
+#[cfg(feature = "integers")]
 fn standalone_128_iltc<W, N>(
     bytes: &[u8],
     radix: u32,
@@ -518,10 +556,10 @@ where
     N: Integer,
 {
     let (sign, digits) = parse_sign_lc_separator::<W>(bytes, digit_separator);
-    if digits.is_empty() {
+    if digits.iter().all(|&c| c == digit_separator) {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
-    let iter = iterate_digits_ignore_separator(digits, digit_separator);
+    let iter = SeparatorDigits::new(digits, digit_separator);
     parse_digits_128::<W, N, _>(digits, iter, radix, sign)
 }
 
@@ -532,7 +570,7 @@ where
 macro_rules! standalone_atoi_128_separator {
     (fn $name:ident,sign => $sign:ident,consume => $consume:ident) => {
         #[inline]
-        #[cfg(feature = "format")]
+        #[cfg(all(feature = "integers", feature = "format"))]
         fn $name<W, N>(bytes: &[u8], radix: u32, digit_separator: u8) -> ParseResult<(W, *const u8)>
         where
             W: Integer,
@@ -546,7 +584,7 @@ macro_rules! standalone_atoi_128_separator {
 
             // Extract the integer subslice, then parse.
             let leading = $consume(digits, radix, digit_separator).0;
-            let iter = iterate_digits_ignore_separator(leading, digit_separator);
+            let iter = SeparatorDigits::new(leading, digit_separator);
             parse_digits_128::<W, N, _>(leading, iter, radix, sign)
         }
     };
@@ -634,6 +672,7 @@ standalone_atoi_128_separator!(
 
 // Standalone atoi processor for u128 without a digit separator.
 #[inline(always)]
+#[cfg(feature = "integers")]
 pub(crate) fn standalone_128_no_separator<W, N>(
     bytes: &[u8],
     radix: u32,
@@ -645,9 +684,24 @@ where
     standalone_128::<W, N>(bytes, radix)
 }
 
+// Standalone atoi processor for u128 ignoring digit separators anywhere in the digits.
+#[inline(always)]
+#[cfg(all(feature = "integers", not(feature = "format")))]
+pub(crate) fn standalone_128_ignore_separator<W, N>(
+    bytes: &[u8],
+    radix: u32,
+    digit_separator: u8,
+) -> ParseResult<(W, *const u8)>
+where
+    W: Integer,
+    N: Integer,
+{
+    standalone_128_iltc::<W, N>(bytes, radix, digit_separator)
+}
+
 // Extract exponent with a digit separator in the exponent component.
 #[inline(always)]
-#[cfg(feature = "format")]
+#[cfg(all(feature = "integers", feature = "format"))]
 pub(crate) fn standalone_128_separator<W, N>(
     bytes: &[u8],
     radix: u32,
@@ -658,6 +712,7 @@ where
     N: Integer,
 {
     let digit_separator = format.digit_separator();
+    validate_sign(bytes, digit_separator, format)?;
     let (value, ptr) = generate_interface!(
         format => format,
         mask => INTEGER_DIGIT_SEPARATOR_FLAG_MASK,
@@ -0,0 +1,112 @@
+//! Lenient string-to-integer conversion routines.
+//!
+//! Accepts integers written in float notation, such as `3.000`,
+//! `1e3`, or `2.5e1`, as long as the value is exactly integral.
+
+use crate::error::*;
+use crate::result::*;
+use crate::traits::*;
+use crate::util::*;
+
+use super::exponent::standalone_exponent;
+
+// Process the digits, and validate any truncated digits are zero.
+macro_rules! parse_digits_lenient {
+    ($value:ident, $iter:ident, $point:ident, $radix:ident, $op:ident, $code:ident) => {
+        for (index, c) in $iter.enumerate() {
+            if index as i64 >= $point {
+                // Digit is after the radix point, and would be lost.
+                if *c != b'0' {
+                    return Err((ErrorCode::InvalidDigit, c));
+                }
+                continue;
+            }
+            // Digits have already been validated by the caller.
+            let digit = to_digit(*c, $radix).unwrap();
+            $value = match $value.checked_mul(as_cast($radix)) {
+                Some(v) => v,
+                None => return Err((ErrorCode::$code, c)),
+            };
+            $value = match $value.$op(as_cast(digit)) {
+                Some(v) => v,
+                None => return Err((ErrorCode::$code, c)),
+            };
+        }
+    };
+}
+
+// Apply the remaining positive exponent to a non-zero value.
+// Any non-zero value overflows within a few iterations, so this is short.
+macro_rules! shift_lenient {
+    ($value:ident, $shift:ident, $radix:ident, $code:ident, $ptr:expr) => {
+        if $value != T::ZERO {
+            for _ in 0..$shift {
+                $value = match $value.checked_mul(as_cast($radix)) {
+                    Some(v) => v,
+                    None => return Err((ErrorCode::$code, $ptr)),
+                };
+            }
+        }
+    };
+}
+
+/// Parse an integer that may be written with a fraction or exponent.
+///
+/// Returns an error if a non-zero digit would be truncated. Digit
+/// separators are not supported. Returns the parsed value and a
+/// pointer to the first unprocessed byte.
+#[inline]
+pub(crate) fn standalone_lenient<T>(
+    bytes: &[u8],
+    radix: u32,
+    format: NumberFormat,
+) -> ParseResult<(T, *const u8)>
+where
+    T: Integer,
+{
+    let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+    let (integer, rest) = consume_digits_no_separator(digits, radix, format);
+
+    // Extract the fraction, if present.
+    let (fraction, rest) = match rest.first() {
+        Some(&c) if c == format.decimal_point() => {
+            consume_digits_no_separator(&rest[1..], radix, format)
+        },
+        _ => (&rest[..0], rest),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return Err((ErrorCode::EmptyMantissa, rest.as_ptr()));
+    }
+
+    // Extract and parse the exponent, if present.
+    let (exponent, rest) = match rest.first() {
+        Some(&c) if c.eq_ignore_ascii_case(&format.exponent(radix)) => {
+            let (exp_sign, exp_digits) = parse_sign_no_separator::<i32>(&rest[1..], b'\x00');
+            let (exp_digits, rest) = consume_digits_no_separator(exp_digits, radix, format);
+            if exp_digits.is_empty() {
+                return Err((ErrorCode::EmptyExponent, exp_digits.as_ptr()));
+            }
+            let iter = ContiguousDigits::new(exp_digits, b'\x00');
+            (standalone_exponent(iter, radix, exp_sign).0, rest)
+        },
+        _ => (0, rest),
+    };
+
+    // Index of the radix point within the integer and fraction digits,
+    // after applying the exponent. May be negative or past the end.
+    let point = integer.len() as i64 + exponent as i64;
+    let shift = point - (integer.len() + fraction.len()) as i64;
+    let iter = integer.iter().chain(fraction.iter());
+    let ptr = rest.as_ptr();
+
+    let mut value = T::ZERO;
+    if sign == Sign::Positive {
+        parse_digits_lenient!(value, iter, point, radix, checked_add, Overflow);
+        shift_lenient!(value, shift, radix, Overflow, ptr);
+    } else {
+        parse_digits_lenient!(value, iter, point, radix, checked_sub, Underflow);
+        shift_lenient!(value, shift, radix, Underflow, ptr);
+    }
+
+    Ok((value, ptr))
+}
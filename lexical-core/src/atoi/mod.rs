@@ -6,8 +6,17 @@ mod shared;
 mod api;
 mod exponent;
 mod generic;
+mod lenient;
+#[cfg(feature = "floats")]
 mod mantissa;
+mod overflow;
+#[cfg(feature = "power_of_two")]
+mod prefix;
 
 // Re-exports
+#[cfg(feature = "power_of_two")]
+pub(crate) use self::api::atoi_detect_radix;
+#[cfg(feature = "floats")]
 pub(crate) use self::exponent::*;
+#[cfg(feature = "floats")]
 pub(crate) use self::mantissa::*;
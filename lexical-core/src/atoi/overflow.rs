@@ -0,0 +1,76 @@
+//! Context for integers that overflow their type.
+//!
+//! Overflow stops parsing at the first digit that does not fit, so
+//! the digits are rescanned to find the full magnitude of the integer.
+
+use crate::util::*;
+
+/// Rescan the integer digits to find the magnitude of an overflow.
+///
+/// Only called after parsing has already overflowed, so the sign,
+/// prefix and leading digits are known to be valid.
+pub(crate) fn overflow_context(
+    bytes: &[u8],
+    options: &ParseIntegerOptions,
+    sign: Sign,
+) -> IntegerOverflow {
+    let format = options.number_format();
+    #[cfg(feature = "format")]
+    let has_separator = format.intersects(NumberFormat::DIGIT_SEPARATOR_FLAG_MASK);
+    #[cfg(not(feature = "format"))]
+    let has_separator = options.digit_separator().is_some();
+    let digit_separator = match has_separator {
+        true => Some(format.digit_separator()),
+        false => None,
+    };
+    let is_separator = |c: u8| Some(c) == digit_separator;
+
+    // Skip leading digit separators and the sign.
+    let mut index = 0;
+    while index < bytes.len() && is_separator(bytes[index]) {
+        index += 1;
+    }
+    if let Some(b'+') | Some(b'-') = bytes.get(index) {
+        index += 1;
+    }
+
+    // Skip the radix prefix, if present.
+    #[cfg(not(feature = "power_of_two"))]
+    let radix = options.radix();
+    #[cfg(feature = "power_of_two")]
+    let radix = match (options.radix_prefix(), bytes.get(index), bytes.get(index + 1)) {
+        (true, Some(&b'0'), Some(&b'x')) | (true, Some(&b'0'), Some(&b'X')) => {
+            index += 2;
+            16
+        },
+        (true, Some(&b'0'), Some(c)) if c.is_ascii_digit() => {
+            index += 1;
+            8
+        },
+        (true, _, _) => 10,
+        (false, _, _) => options.radix(),
+    };
+
+    // Accumulate the digits, tracking if the magnitude fits in a u128.
+    let mut digits = 0;
+    let mut wrapped: u128 = 0;
+    let mut exact = true;
+    for &c in &bytes[index..] {
+        if is_separator(c) {
+            continue;
+        }
+        let digit = match to_digit(c, radix) {
+            Some(digit) => digit as u128,
+            None => break,
+        };
+        digits += 1;
+        exact = exact
+            && wrapped
+                .checked_mul(radix as u128)
+                .and_then(|v| v.checked_add(digit))
+                .is_some();
+        wrapped = wrapped.wrapping_mul(radix as u128).wrapping_add(digit);
+    }
+
+    IntegerOverflow::new(sign, digits, wrapped, exact)
+}
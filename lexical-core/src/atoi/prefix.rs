@@ -0,0 +1,87 @@
+//! String-to-integer conversion routines with radix prefixes.
+//!
+//! Detects the radix like C's `strtol` with a base of 0: `0x` or `0X`
+//! is hexadecimal, a leading `0` is octal, and all other digits are
+//! decimal. Also detects the radix from the `0x`, `0o` and `0b`
+//! prefixes, or from assembler-style suffixes, such as `FFh`.
+
+use crate::error::*;
+use crate::result::*;
+use crate::traits::*;
+use crate::util::*;
+
+use super::generic::parse_digits;
+use super::shared::last_ptr;
+
+/// Parse an integer, detecting the radix from the prefix.
+///
+/// The sign comes before the prefix. Returns the parsed value and a
+/// pointer to the first unprocessed byte.
+#[inline]
+pub(crate) fn standalone_prefix<T>(bytes: &[u8]) -> ParseResult<(T, *const u8)>
+where
+    T: Integer,
+{
+    let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+    let (radix, digits) = match (digits.get(0), digits.get(1)) {
+        (Some(&b'0'), Some(&b'x')) | (Some(&b'0'), Some(&b'X')) => (16, &digits[2..]),
+        (Some(&b'0'), Some(c)) if c.is_ascii_digit() => (8, &digits[1..]),
+        _ => (10, digits),
+    };
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+
+    let iter = ContiguousDigits::new(digits, b'\x00');
+    parse_digits(digits, iter, radix, sign)
+}
+
+/// Get the radix of an assembler-style suffix.
+#[inline]
+fn suffix_radix(c: u8) -> Option<u32> {
+    match c {
+        b'h' | b'H' => Some(16),
+        b'o' | b'O' | b'q' | b'Q' => Some(8),
+        _ => None,
+    }
+}
+
+/// Parse an integer, detecting the radix from the prefix or suffix.
+///
+/// A trailing `h` is hexadecimal, so `0BAh` is not binary. Otherwise,
+/// the `0x`, `0o` and `0b` prefixes, in either case, are hexadecimal,
+/// octal and binary, a trailing `o` or `q` is octal, and all other
+/// digits are decimal. The sign comes before the prefix. Returns the
+/// parsed value, the radix, and a pointer to the first unprocessed
+/// byte, which is only past the suffix if all digits were processed.
+#[inline]
+pub(crate) fn standalone_detect_radix<T>(bytes: &[u8]) -> ParseResult<(T, u32, *const u8)>
+where
+    T: Integer,
+{
+    let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+    let prefix = match (digits.get(0), digits.get(1).map(u8::to_ascii_lowercase)) {
+        (Some(&b'0'), Some(b'x')) => Some(16),
+        (Some(&b'0'), Some(b'o')) => Some(8),
+        (Some(&b'0'), Some(b'b')) => Some(2),
+        _ => None,
+    };
+    let suffix = suffix_radix(digits[digits.len() - 1]);
+    let trimmed = &digits[..digits.len() - 1];
+    let (radix, digits, has_suffix) = match (prefix, suffix) {
+        (_, Some(16)) => (16, trimmed, true),
+        (None, Some(radix)) => (radix, trimmed, true),
+        (Some(radix), _) => (radix, &digits[2..], false),
+        (None, None) => (10, digits, false),
+    };
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+
+    let iter = ContiguousDigits::new(digits, b'\x00');
+    let (value, ptr) = parse_digits(digits, iter, radix, sign)?;
+    match has_suffix && ptr == last_ptr(digits) {
+        true => Ok((value, radix, last_ptr(bytes))),
+        false => Ok((value, radix, ptr)),
+    }
+}
@@ -1,5 +1,6 @@
 //! Shared definitions for string-to-integer conversions.
 
+#[cfg(feature = "floats")]
 use crate::traits::*;
 
 // SHARED
@@ -39,6 +40,7 @@ pub(super) fn last_ptr<T>(slc: &[T]) -> *const T {
 
 // Add digit to mantissa.
 #[inline(always)]
+#[cfg(feature = "floats")]
 pub(super) fn add_digit<T>(value: T, digit: u32, radix: u32) -> Option<T>
 where
     T: UnsignedInteger,
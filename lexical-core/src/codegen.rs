@@ -0,0 +1,529 @@
+//! Generate the pre-computed tables for custom float types.
+//!
+//! The moderate path of the float parser uses cached powers of the
+//! radix as extended-precision floats, and the fast path uses the
+//! limits of exactly-representable powers of the radix. These tables
+//! are pre-computed in lexical for the native float types, and the
+//! functions here generate the same tables, as Rust source, for new
+//! float types, such as 24-bit floats or soft-float implementations.
+//!
+//! The generated source matches the format of the existing tables,
+//! and can be written to a file from a build script, or run once
+//! and formatted with rustfmt.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical_core;
+//! use lexical_core::codegen::{FloatLayout, ModeratePathPowers};
+//!
+//! # pub fn main() {
+//! // A 24-bit float with an 8-bit exponent and 15-bit mantissa.
+//! let layout = FloatLayout::new(15, -141, 127);
+//! assert_eq!(layout.exponent_limit(10), (-6, 6));
+//! assert_eq!(layout.mantissa_limit(10), 4);
+//!
+//! let powers = ModeratePathPowers::new(10, 64, &FloatLayout::F64);
+//! assert_eq!(powers.step, 10);
+//! assert_eq!(powers.bias, 350);
+//! assert!(powers.to_string().contains("const BASE10_STEP: i32 = 10;"));
+//! # }
+//! ```
+
+#![cfg(feature = "codegen")]
+
+use crate::lib::f64::consts::LN_2;
+use crate::lib::fmt::{self, Write};
+use crate::lib::string::String;
+use crate::lib::vec::Vec;
+
+// BIGINT
+
+/// Minimal arbitrary-precision integer, used to calculate exact powers.
+///
+/// Stored as little-endian, 64-bit limbs.
+struct Bigint {
+    data: Vec<u64>,
+}
+
+impl Bigint {
+    /// Create the integer `2^exp`.
+    fn pow2(exp: u32) -> Bigint {
+        let mut data = vec![0; (exp / 64) as usize + 1];
+        data[(exp / 64) as usize] = 1 << (exp % 64);
+        Bigint {
+            data,
+        }
+    }
+
+    /// Create the integer `radix^exp`.
+    fn pow(radix: u32, exp: u32) -> Bigint {
+        let mut value = Bigint::pow2(0);
+        for _ in 0..exp {
+            value.mul_small(radix as u64);
+        }
+        value
+    }
+
+    /// Multiply by a small integer, in-place.
+    fn mul_small(&mut self, y: u64) {
+        let mut carry: u128 = 0;
+        for limb in self.data.iter_mut() {
+            let z = (*limb as u128) * (y as u128) + carry;
+            *limb = z as u64;
+            carry = z >> 64;
+        }
+        if carry != 0 {
+            self.data.push(carry as u64);
+        }
+    }
+
+    /// Divide by a small integer, in-place, truncating the result.
+    fn div_small(&mut self, y: u64) {
+        let mut rem: u128 = 0;
+        for limb in self.data.iter_mut().rev() {
+            let x = (rem << 64) | (*limb as u128);
+            *limb = (x / y as u128) as u64;
+            rem = x % y as u128;
+        }
+        while self.data.len() > 1 && self.data.last() == Some(&0) {
+            self.data.pop();
+        }
+    }
+
+    /// Get the number of bits required to store the integer.
+    fn bit_length(&self) -> u32 {
+        let hi = self.data.iter().rposition(|&x| x != 0);
+        match hi {
+            Some(index) => 64 * index as u32 + 64 - self.data[index].leading_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Get the 128 bits starting at the bit index `shift`.
+    fn shr_to_u128(&self, shift: u32) -> u128 {
+        let index = (shift / 64) as usize;
+        let bits = shift % 64;
+        let limb = |i: usize| self.data.get(i).cloned().unwrap_or(0) as u128;
+        let lo = limb(index) | (limb(index + 1) << 64);
+        match bits {
+            0 => lo,
+            _ => (lo >> bits) | (limb(index + 2) << (128 - bits)),
+        }
+    }
+
+    /// Get the top `mantissa_bits` bits as an extended-precision float.
+    ///
+    /// Integers already smaller than `2^mantissa_bits` are not shifted.
+    fn normalize(&self, exp: i32, mantissa_bits: u32) -> (u128, i32) {
+        let shift = self.bit_length().saturating_sub(mantissa_bits);
+        (self.shr_to_u128(shift), exp + shift as i32)
+    }
+}
+
+// FLOAT LAYOUT
+
+/// Binary layout of a float type, used to calculate the table limits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FloatLayout {
+    /// Number of explicitly stored mantissa bits, without the hidden bit.
+    pub mantissa_size: i32,
+    /// Binary exponent of the smallest denormal value.
+    pub min_exponent: i32,
+    /// Binary exponent of the largest normal value.
+    pub max_exponent: i32,
+}
+
+impl FloatLayout {
+    /// Layout of IEEE754 half-precision floats.
+    pub const F16: FloatLayout = FloatLayout::new(10, -24, 15);
+    /// Layout of bfloat16 floats.
+    pub const BF16: FloatLayout = FloatLayout::new(7, -133, 127);
+    /// Layout of IEEE754 single-precision floats.
+    pub const F32: FloatLayout = FloatLayout::new(23, -149, 127);
+    /// Layout of IEEE754 double-precision floats.
+    pub const F64: FloatLayout = FloatLayout::new(52, -1074, 1023);
+    /// Layout of IEEE754 quadruple-precision floats.
+    pub const F128: FloatLayout = FloatLayout::new(112, -16494, 16383);
+
+    /// Create a layout from the mantissa size and exponent limits.
+    #[inline]
+    pub const fn new(mantissa_size: i32, min_exponent: i32, max_exponent: i32) -> FloatLayout {
+        FloatLayout {
+            mantissa_size,
+            min_exponent,
+            max_exponent,
+        }
+    }
+
+    /// Get the min and max exponents for the radix that can be exactly represented.
+    ///
+    /// Power-of-two radixes scale the binary exponent limits, otherwise,
+    /// the limit is the number of digits that fit in the mantissa,
+    /// after removing any power-of-two from the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    pub fn exponent_limit(&self, radix: u32) -> (i32, i32) {
+        assert!(radix >= 2 && radix <= 36, "Numerical base must be from 2-36.");
+        if radix.is_power_of_two() {
+            let log2 = (radix as f64).log2();
+            let min_exp = (self.min_exponent as f64 / log2) as i32;
+            let max_exp = (self.max_exponent as f64 / log2) as i32;
+            (min_exp, max_exp)
+        } else {
+            let base = radix >> radix.trailing_zeros();
+            let precision = (self.mantissa_size + 1) as f64;
+            let exp_limit = (precision / (base as f64).log2()) as i32;
+            (-exp_limit, exp_limit)
+        }
+    }
+
+    /// Get the number of digits that can be shifted from exponent to mantissa.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    pub fn mantissa_limit(&self, radix: u32) -> i32 {
+        assert!(radix >= 2 && radix <= 36, "Numerical base must be from 2-36.");
+        let precision = (self.mantissa_size + 1) as f64;
+        (precision / (radix as f64).log2()) as i32
+    }
+
+    /// Generate the `ExactExponent` implementation for a float type.
+    ///
+    /// The implementation has the same feature-gated match tables
+    /// as the implementations for the native float types.
+    pub fn exact_exponent_source(&self, float: &str) -> String {
+        const POW2_RADIXES: [u32; 6] = [2, 4, 8, 10, 16, 32];
+
+        let mut s = String::new();
+        let limits = |s: &mut String, radixes: &mut dyn Iterator<Item = u32>, exp: bool| {
+            s.push_str("            match radix.as_i32() {\n");
+            if exp {
+                for radix in radixes {
+                    let (min, max) = self.exponent_limit(radix);
+                    writeln!(s, "                {} => ({}, {}),", radix, min, max).unwrap();
+                }
+            } else {
+                for radix in radixes {
+                    writeln!(s, "                {} => {},", radix, self.mantissa_limit(radix))
+                        .unwrap();
+                }
+            }
+            s.push_str("                // Invalid radix\n");
+            s.push_str("                _ => unreachable!(),\n");
+            s.push_str("            }\n");
+        };
+        let function = |s: &mut String, exp: bool| {
+            if exp {
+                s.push_str("    #[inline]\n");
+                s.push_str("    fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {\n");
+                s.push_str("        debug_assert_radix!(radix);\n");
+                s.push_str("        #[cfg(not(feature = \"power_of_two\"))]\n");
+                let (min, max) = self.exponent_limit(10);
+                writeln!(s, "        {{\n            ({}, {})\n        }}\n", min, max).unwrap();
+            } else {
+                s.push_str("    #[inline]\n");
+                s.push_str("    fn mantissa_limit<T: Integer>(radix: T) -> i32 {\n");
+                s.push_str("        debug_assert_radix!(radix);\n");
+                s.push_str("        #[cfg(not(feature = \"power_of_two\"))]\n");
+                writeln!(s, "        {{\n            {}\n        }}\n", self.mantissa_limit(10))
+                    .unwrap();
+            }
+            s.push_str("        #[cfg(all(feature = \"power_of_two\", not(feature = \"radix\")))]\n");
+            s.push_str("        {\n");
+            limits(s, &mut POW2_RADIXES.iter().cloned(), exp);
+            s.push_str("        }\n\n");
+            s.push_str("        #[cfg(feature = \"radix\")]\n");
+            s.push_str("        {\n");
+            limits(s, &mut (2..37), exp);
+            s.push_str("        }\n");
+            s.push_str("    }\n");
+        };
+
+        writeln!(s, "impl ExactExponent for {} {{", float).unwrap();
+        function(&mut s, true);
+        s.push('\n');
+        function(&mut s, false);
+        s.push_str("}\n");
+        s
+    }
+}
+
+// MODERATE PATH POWERS
+
+/// Pre-computed powers of a radix as extended-precision floats.
+///
+/// The mantissas are normalized, so the most-significant bit is set,
+/// and the value of each power is `mant * 2^exp`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExtendedFloatArray {
+    /// Normalized mantissas for the powers.
+    pub mant: Vec<u128>,
+    /// Binary exponents for the powers.
+    pub exp: Vec<i32>,
+}
+
+impl ExtendedFloatArray {
+    /// Create an empty array.
+    fn new() -> ExtendedFloatArray {
+        ExtendedFloatArray {
+            mant: Vec::new(),
+            exp: Vec::new(),
+        }
+    }
+
+    /// Add a power to the array.
+    fn push(&mut self, (mant, exp): (u128, i32)) {
+        self.mant.push(mant);
+        self.exp.push(exp);
+    }
+}
+
+/// Pre-computed powers for the moderate path of the float parser.
+///
+/// The small powers are `radix^0` through `radix^(step-1)`, and the
+/// large powers are every `step` exponents, from below the smallest
+/// denormal value to above the largest normal value of the float
+/// layout. The large powers are truncated, rather than rounded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ModeratePathPowers {
+    /// Numerical base of the powers.
+    pub radix: u32,
+    /// Number of bits in each mantissa, 64 or 128.
+    pub mantissa_bits: u32,
+    /// Pre-computed small powers.
+    pub small: ExtendedFloatArray,
+    /// Pre-computed large powers, from the most negative exponent.
+    pub large: ExtendedFloatArray,
+    /// Pre-computed small powers as integers.
+    pub small_int: Vec<u128>,
+    /// Step between large powers and number of small powers.
+    pub step: i32,
+    /// Exponent bias for the large powers.
+    pub bias: i32,
+}
+
+impl ModeratePathPowers {
+    /// Calculate the powers for a radix, mantissa size and float layout.
+    ///
+    /// The large powers extend below the smallest denormal value by
+    /// the range of a 64-bit mantissa, like the native tables, so
+    /// truncated mantissas can be scaled to the smallest denormal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is a power-of-two or not in the range
+    /// `[2, 36]`, or if the mantissa is not 64 or 128 bits.
+    pub fn new(radix: u32, mantissa_bits: u32, layout: &FloatLayout) -> ModeratePathPowers {
+        assert!(radix >= 2 && radix <= 36, "Numerical base must be from 2-36.");
+        assert!(!radix.is_power_of_two(), "Power-of-two radixes do not use cached powers.");
+        assert!(mantissa_bits == 64 || mantissa_bits == 128, "Mantissa must be 64 or 128 bits.");
+
+        let ln_radix = (radix as f64).ln();
+        let step = (1e10f64.ln() / ln_radix).floor() as i32;
+        let min_ln = layout.min_exponent as f64 * LN_2;
+        let mant_ln = (u64::max_value() as f64).ln();
+        let max_ln = (layout.max_exponent + 1) as f64 * LN_2;
+        let min_exp = (min_ln / ln_radix - mant_ln / ln_radix).floor() as i32;
+        let max_exp = (max_ln / ln_radix).ceil() as i32;
+
+        // Scale the powers by a power-of-two, so the truncated
+        // negative powers have at least `mantissa_bits` bits.
+        let scale = Bigint::pow(radix, (step - min_exp) as u32);
+        let bitshift = mantissa_bits - 1 + scale.bit_length();
+        let exp = -(bitshift as i32);
+        let step_power = Bigint::pow(radix, step as u32).data[0];
+
+        // Small powers.
+        let mut small = ExtendedFloatArray::new();
+        let mut small_int = Vec::new();
+        let mut fp = Bigint::pow2(bitshift);
+        for i in 0..step {
+            small.push(fp.normalize(exp, mantissa_bits));
+            small_int.push((radix as u128).pow(i as u32));
+            fp.mul_small(radix as u64);
+        }
+
+        // Large, negative powers, which must go below the minimum
+        // exponent, so all the biased exponents are positive.
+        let mut negative = Vec::new();
+        let mut fp = Bigint::pow2(bitshift);
+        let mut power = -step;
+        while power > min_exp - step {
+            fp.div_small(step_power);
+            negative.push((fp.normalize(exp, mantissa_bits), power));
+            power -= step;
+        }
+        let bias = negative.last().map_or(0, |&(_, power)| -power);
+
+        // Large, positive powers.
+        let mut large = ExtendedFloatArray::new();
+        for &(fp, _) in negative.iter().rev() {
+            large.push(fp);
+        }
+        let mut fp = Bigint::pow2(bitshift);
+        large.push(fp.normalize(exp, mantissa_bits));
+        let mut power = step;
+        while power < max_exp {
+            fp.mul_small(step_power);
+            large.push(fp.normalize(exp, mantissa_bits));
+            power += step;
+        }
+
+        ModeratePathPowers {
+            radix,
+            mantissa_bits,
+            small,
+            large,
+            small_int,
+            step,
+            bias,
+        }
+    }
+
+    /// Write an array of values, with the power as a comment.
+    fn write_array<T: fmt::Display>(
+        &self,
+        f: &mut fmt::Formatter,
+        name: &str,
+        ty: &str,
+        values: &[T],
+        first: i32,
+        step: i32,
+    ) -> fmt::Result {
+        let strings: Vec<String> = values.iter().map(|x| format!("{},", x)).collect();
+        let width = strings.iter().map(|x| x.len()).max().unwrap_or(0);
+        writeln!(f, "const BASE{}_{}: [{}; {}] = [", self.radix, name, ty, values.len())?;
+        for (i, string) in strings.iter().enumerate() {
+            let power = first + i as i32 * step;
+            writeln!(f, "    {:width$} // {}^{}", string, self.radix, power, width = width)?;
+        }
+        writeln!(f, "];")
+    }
+}
+
+impl fmt::Display for ModeratePathPowers {
+    /// Write the powers as Rust source, in the format of the native tables.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let radix = self.radix;
+        let mant = match self.mantissa_bits {
+            64 => "u64",
+            _ => "u128",
+        };
+        let small_int: Vec<String> = self.small_int.iter().map(|x| x.to_string()).collect();
+        let (bias, step) = (self.bias, self.step);
+
+        self.write_array(f, "SMALL_MANTISSA", mant, &self.small.mant, 0, 1)?;
+        self.write_array(f, "SMALL_EXPONENT", "i32", &self.small.exp, 0, 1)?;
+        self.write_array(f, "LARGE_MANTISSA", mant, &self.large.mant, -bias, step)?;
+        self.write_array(f, "LARGE_EXPONENT", "i32", &self.large.exp, -bias, step)?;
+        writeln!(
+            f,
+            "const BASE{}_SMALL_INT_POWERS: [{}; {}] = [{}];",
+            radix,
+            mant,
+            small_int.len(),
+            small_int.join(", ")
+        )?;
+        writeln!(f, "const BASE{}_STEP: i32 = {};", radix, self.step)?;
+        writeln!(f, "const BASE{}_BIAS: i32 = {};\n", radix, self.bias)?;
+
+        writeln!(
+            f,
+            "pub(crate) const BASE{0}_POWERS: ModeratePathPowers<{1}> = ModeratePathPowers {{",
+            radix, mant
+        )?;
+        writeln!(f, "    small: ExtendedFloatArray {{")?;
+        writeln!(f, "        mant: &BASE{}_SMALL_MANTISSA,", radix)?;
+        writeln!(f, "        exp: &BASE{}_SMALL_EXPONENT,", radix)?;
+        writeln!(f, "    }},")?;
+        writeln!(f, "    large: ExtendedFloatArray {{")?;
+        writeln!(f, "        mant: &BASE{}_LARGE_MANTISSA,", radix)?;
+        writeln!(f, "        exp: &BASE{}_LARGE_EXPONENT,", radix)?;
+        writeln!(f, "    }},")?;
+        writeln!(f, "    small_int: &BASE{}_SMALL_INT_POWERS,", radix)?;
+        writeln!(f, "    step: BASE{}_STEP,", radix)?;
+        writeln!(f, "    bias: BASE{}_BIAS,", radix)?;
+        writeln!(f, "}};")
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bigint_test() {
+        let mut x = Bigint::pow(10, 30);
+        assert_eq!(x.bit_length(), 100);
+        assert_eq!(x.shr_to_u128(0), 10u128.pow(30));
+        assert_eq!(x.shr_to_u128(36), 10u128.pow(30) >> 36);
+        x.div_small(10u64.pow(10));
+        assert_eq!(x.shr_to_u128(0), 10u128.pow(20));
+        assert_eq!(Bigint::pow2(200).normalize(0, 64), (1 << 63, 137));
+    }
+
+    #[test]
+    fn exponent_limit_test() {
+        let f24 = FloatLayout::new(15, -141, 127);
+        assert_eq!(FloatLayout::F64.exponent_limit(2), (-1074, 1023));
+        assert_eq!(FloatLayout::F64.exponent_limit(32), (-214, 204));
+        assert_eq!(FloatLayout::F64.exponent_limit(10), (-22, 22));
+        assert_eq!(FloatLayout::F64.exponent_limit(36), (-16, 16));
+        assert_eq!(FloatLayout::F32.exponent_limit(10), (-10, 10));
+        assert_eq!(FloatLayout::F16.exponent_limit(10), (-4, 4));
+        assert_eq!(FloatLayout::F128.exponent_limit(10), (-48, 48));
+        assert_eq!(f24.exponent_limit(10), (-6, 6));
+        assert_eq!(f24.exponent_limit(16), (-35, 31));
+    }
+
+    #[test]
+    fn mantissa_limit_test() {
+        assert_eq!(FloatLayout::F64.mantissa_limit(2), 53);
+        assert_eq!(FloatLayout::F64.mantissa_limit(10), 15);
+        assert_eq!(FloatLayout::F32.mantissa_limit(10), 7);
+        assert_eq!(FloatLayout::BF16.mantissa_limit(10), 2);
+    }
+
+    #[test]
+    fn exact_exponent_source_test() {
+        let source = FloatLayout::F64.exact_exponent_source("f64");
+        assert!(source.starts_with("impl ExactExponent for f64 {\n"));
+        assert!(source.contains("            (-22, 22)\n"));
+        assert!(source.contains("                2 => (-1074, 1023),\n"));
+        assert!(source.contains("                36 => 10,\n"));
+        assert!(source.ends_with("    }\n}\n"));
+    }
+
+    #[test]
+    fn moderate_path_powers_test() {
+        // Compare to the first and last values of the 160-bit decimal tables.
+        let powers = ModeratePathPowers::new(10, 128, &FloatLayout::F64);
+        assert_eq!(powers.small.mant[9], 316912650057057350374175801344000000000);
+        assert_eq!(powers.small.exp[9], -98);
+        assert_eq!(powers.small_int[9], 1000000000);
+        assert_eq!(powers.step, 10);
+        assert_eq!(powers.bias, 350);
+        assert_eq!(powers.large.mant.len(), 66);
+
+        let source = ModeratePathPowers::new(10, 64, &FloatLayout::F64).to_string();
+        assert!(source.starts_with("const BASE10_SMALL_MANTISSA: [u64; 10] = [\n"));
+        assert!(source.contains("    9223372036854775808,  // 10^0\n"));
+        assert!(source.contains("    -1226, // 10^-350\n"));
+        assert!(source.contains("    933,   // 10^300\n"));
+        assert!(source.contains("const BASE10_BIAS: i32 = 350;\n"));
+        assert!(source.ends_with("    bias: BASE10_BIAS,\n};\n"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn moderate_path_powers_pow2_test() {
+        ModeratePathPowers::new(16, 64, &FloatLayout::F64);
+    }
+}
@@ -85,3 +85,16 @@ if #[cfg(target_pointer_width = "16")] {
 
 /// Maximum number of bytes required to serialize any number to string.
 pub const BUFFER_SIZE: usize = F64_FORMATTED_SIZE;
+
+// The f64 positional buffer is actually a size of 343, for a sign,
+// `0.`, 323 leading zeros and 17 digits, but use 512 since it's a
+// power of 2.
+/// Maximum number of bytes required to serialize any float to string,
+/// with `WriteFloatOptions::positional_exponents`.
+pub const POSITIONAL_BUFFER_SIZE: usize = 512;
+
+// The f64 exact buffer is a size of 1077, for a sign, `0.` and the
+// 1074 fractional digits of the smallest denormal float.
+/// Maximum number of bytes required to serialize any float to string,
+/// with `write_exact`.
+pub const EXACT_BUFFER_SIZE: usize = 1077;
@@ -1,6 +1,7 @@
 //! C-compatible error type.
 
 use crate::lib::fmt::{self, Display, Formatter};
+use crate::lib::str;
 
 #[cfg(feature = "std")]
 use std::error::Error as StdError;
@@ -31,11 +32,19 @@ use std::error::Error as StdError;
 /// const int32_t EXPONENT_WITHOUT_FRACTION = -14;
 /// const int32_t INVALID_LEADING_ZEROS = -15;
 /// const int32_t MISSING_EXPONENT = -16;
+/// const int32_t EXPONENT_OVERFLOW = -17;
+/// const int32_t TOO_MANY_EXPONENT_DIGITS = -18;
+/// const int32_t TOO_MANY_MANTISSA_DIGITS = -19;
+/// const int32_t INVALID_DIGIT_SEPARATOR = -20;
+/// const int32_t MISSING_CLOSING_PARENTHESIS = -21;
+/// const int32_t INEXACT = -22;
+/// const int32_t EXCEEDS_MAX_WIDTH = -23;
+/// const int32_t EXCEEDS_MAX_LENGTH = -24;
 /// ```
 ///
 /// # Safety
 ///
-/// Assigning any value outside the range `[-15, -1]` to value of type
+/// Assigning any value outside the range `[-24, -1]` to value of type
 /// ErrorCode may invoke undefined-behavior.
 #[repr(i32)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -47,6 +56,9 @@ pub enum ErrorCode {
     Overflow                    = -1,
     /// Integral underflow occurred during numeric parsing.
     ///
+    /// Also returned for non-zero floats that underflow to zero, if
+    /// `ParseFloatOptions::underflow_error` is set.
+    ///
     /// Numeric overflow takes precedence over the presence of an invalid
     /// digit.
     Underflow                   = -2,
@@ -78,6 +90,22 @@ pub enum ErrorCode {
     InvalidLeadingZeros         = -15,
     /// No exponent with required exponent notation.
     MissingExponent             = -16,
+    /// Exponent could not be represented without clamping the value.
+    ExponentOverflow            = -17,
+    /// Exponent had more digits than allowed by the parse options.
+    TooManyExponentDigits       = -18,
+    /// Mantissa had more significant digits than allowed by the parse options.
+    TooManyMantissaDigits       = -19,
+    /// Digit separator was found in a position not allowed by the format.
+    InvalidDigitSeparator       = -20,
+    /// Negative value in parentheses was missing the closing parenthesis.
+    MissingClosingParenthesis   = -21,
+    /// Float could not exactly represent the digits.
+    Inexact                     = -22,
+    /// Float could not be written in the maximum width allowed by the write options.
+    ExceedsMaxWidth             = -23,
+    /// Number continued past the maximum number of bytes allowed to be parsed.
+    ExceedsMaxLength            = -24,
 
     // We may add additional variants later, so ensure that client matching
     // does not depend on exhaustive matching.
@@ -85,6 +113,42 @@ pub enum ErrorCode {
     __Nonexhaustive             = -200,
 }
 
+impl ErrorCode {
+    /// Get a static description of the error.
+    ///
+    /// The description is available without `core::fmt`, so `no_std`
+    /// code may log it directly.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ErrorCode::Overflow => "numeric overflow",
+            ErrorCode::Underflow => "numeric underflow",
+            ErrorCode::InvalidDigit => "invalid digit",
+            ErrorCode::Empty => "empty input",
+            ErrorCode::EmptyMantissa => "empty mantissa",
+            ErrorCode::EmptyExponent => "empty exponent",
+            ErrorCode::EmptyInteger => "empty integer",
+            ErrorCode::EmptyFraction => "empty fraction",
+            ErrorCode::InvalidPositiveMantissaSign => "invalid positive mantissa sign",
+            ErrorCode::MissingMantissaSign => "missing required mantissa sign",
+            ErrorCode::InvalidExponent => "exponent not allowed",
+            ErrorCode::InvalidPositiveExponentSign => "invalid positive exponent sign",
+            ErrorCode::MissingExponentSign => "missing required exponent sign",
+            ErrorCode::ExponentWithoutFraction => "exponent without fraction",
+            ErrorCode::InvalidLeadingZeros => "invalid leading zeros",
+            ErrorCode::MissingExponent => "missing required exponent",
+            ErrorCode::ExponentOverflow => "exponent overflow",
+            ErrorCode::TooManyExponentDigits => "too many exponent digits",
+            ErrorCode::TooManyMantissaDigits => "too many mantissa digits",
+            ErrorCode::InvalidDigitSeparator => "invalid digit separator",
+            ErrorCode::MissingClosingParenthesis => "missing closing parenthesis",
+            ErrorCode::Inexact => "inexact float",
+            ErrorCode::ExceedsMaxWidth => "exceeds maximum width",
+            ErrorCode::ExceedsMaxLength => "exceeds maximum length",
+            ErrorCode::__Nonexhaustive => "unknown error",
+        }
+    }
+}
+
 /// Error type for lexical parsing.
 ///
 /// This error is FFI-compatible for interfacing with C code.
@@ -97,6 +161,40 @@ pub struct Error {
     pub index: usize,
 }
 
+impl Error {
+    /// Maximum number of bytes written by `description_at`.
+    pub const DESCRIPTION_SIZE: usize = 64;
+
+    /// Write a description of the error and its index to the buffer.
+    ///
+    /// Writes the description of the error code, followed by the index,
+    /// as in `invalid digit at index 3`, without `core::fmt`, and
+    /// returns the written bytes. The description is truncated if the
+    /// buffer has less than `DESCRIPTION_SIZE` bytes.
+    pub fn description_at<'a>(&self, buffer: &'a mut [u8]) -> &'a str {
+        // Write the index digits from the end of a stack buffer.
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        let mut index = self.index as u64;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (index % 10) as u8;
+            index /= 10;
+            if index == 0 {
+                break;
+            }
+        }
+
+        let parts = [self.code.description().as_bytes(), b" at index ", &digits[start..]];
+        let mut len = 0;
+        for &c in parts.iter().flat_map(|part| part.iter()).take(buffer.len()) {
+            buffer[len] = c;
+            len += 1;
+        }
+        str::from_utf8(&buffer[..len]).expect("Descriptions must be ASCII.")
+    }
+}
+
 impl From<ErrorCode> for Error {
     #[inline]
     fn from(code: ErrorCode) -> Self {
@@ -126,3 +224,34 @@ impl Display for Error {
 #[cfg(feature = "std")]
 impl StdError for Error {
 }
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn description_test() {
+        assert_eq!(ErrorCode::InvalidDigit.description(), "invalid digit");
+        assert_eq!(ErrorCode::Overflow.description(), "numeric overflow");
+    }
+
+    #[test]
+    fn description_at_test() {
+        let mut buffer = [0u8; Error::DESCRIPTION_SIZE];
+        let error = Error::from((ErrorCode::InvalidDigit, 3));
+        assert_eq!(error.description_at(&mut buffer), "invalid digit at index 3");
+        let error = Error::from((ErrorCode::Empty, 0));
+        assert_eq!(error.description_at(&mut buffer), "empty input at index 0");
+        let error = Error::from((ErrorCode::MissingMantissaSign, usize::max_value()));
+        let description = error.description_at(&mut buffer);
+        assert!(description.starts_with("missing required mantissa sign at index "));
+        assert!(description.ends_with('5'));
+
+        // Truncated to the buffer.
+        let error = Error::from((ErrorCode::InvalidDigit, 3));
+        assert_eq!(error.description_at(&mut buffer[..7]), "invalid");
+    }
+}
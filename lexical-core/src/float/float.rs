@@ -22,8 +22,11 @@ use super::shift::*;
 
 /// Extended precision floating-point type.
 ///
-/// Private implementation, exposed only for testing purposes.
-#[doc(hidden)]
+/// Represents the positive value `mant * 2^exp`, and is used by the
+/// moderate path to scale the parsed mantissa by a cached power of the
+/// radix. The operations are generic over the mantissa type, so
+/// `ExtendedFloat<u64>` and `ExtendedFloat<u128>` may be used as 80-bit
+/// and 160-bit floats for extended-precision float types.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ExtendedFloat<M: Mantissa> {
     /// Mantissa for the extended-precision float.
@@ -59,6 +59,13 @@
 //
 //  These functions are ugly as a result.
 
+use crate::atof::{big_digits, compare_parts, exact_digits, is_exact_bytes, FloatType};
+use crate::config::POSITIONAL_BUFFER_SIZE;
+use crate::error::ErrorCode;
+use crate::float::internal_rounding;
+use crate::itoa;
+use crate::lib::cmp;
+use crate::result::Result;
 use crate::traits::*;
 use crate::util::*;
 
@@ -66,21 +73,38 @@ use crate::util::*;
 use super::binary::{double_binary, float_binary};
 #[cfg(feature = "radix")]
 use super::radix::{double_radix, float_radix};
+#[cfg(feature = "rounding")]
+use super::directed::directed_digits;
 
 // Select the back-end
 cfg_if! {
 if #[cfg(feature = "grisu3")] {
-    use super::grisu3::{double_decimal, float_decimal};
+    use super::grisu3::{double_decimal, double_digits, float_decimal, float_digits};
+    use super::grisu3::{DOUBLE_DECIMAL_SIZE, DOUBLE_DIGITS, FLOAT_DECIMAL_SIZE, FLOAT_DIGITS};
 } else if #[cfg(feature = "ryu")] {
-    use super::ryu::{double_decimal, float_decimal};
+    use super::ryu::{double_decimal, double_digits, float_decimal, float_digits};
+    use super::ryu::{DOUBLE_DECIMAL_SIZE, DOUBLE_DIGITS, FLOAT_DECIMAL_SIZE, FLOAT_DIGITS};
 } else {
-    use super::grisu2::{double_decimal, float_decimal};
+    use super::grisu2::{double_decimal, double_digits, float_decimal, float_digits};
+    use super::grisu2::{DOUBLE_DECIMAL_SIZE, DOUBLE_DIGITS, FLOAT_DECIMAL_SIZE, FLOAT_DIGITS};
 }} //cfg_if
 
 // TRAITS
 
 /// Trait to define serialization of a float to string.
 pub(crate) trait FloatToString: Float {
+    /// Maximum number of bytes written by the decimal backend for a positive float.
+    const DECIMAL_SIZE: usize;
+
+    /// Maximum number of shortest decimal digits.
+    const MAX_DIGITS: usize;
+
+    /// Decimal exponent of the smallest positive float.
+    const MIN_DECIMAL_EXPONENT: i32;
+
+    /// Decimal exponent of the largest finite float.
+    const MAX_DECIMAL_EXPONENT: i32;
+
     /// Export float to decimal string with optimized algorithm.
     #[cfg(not(feature = "power_of_two"))]
     fn decimal<'a>(self, bytes: &'a mut [u8], format: NumberFormat) -> usize;
@@ -92,9 +116,32 @@ pub(crate) trait FloatToString: Float {
     /// Export float to radix string with slow algorithm.
     #[cfg(feature = "radix")]
     fn radix<'a>(self, radix: u32, bytes: &'a mut [u8], format: NumberFormat) -> usize;
+
+    /// Generate shortest decimal digits with optimized algorithm.
+    fn decimal_digits(self) -> DecimalDigits;
+
+    /// Generate shortest decimal digits that bound the float in a direction.
+    #[cfg(feature = "rounding")]
+    fn directed_digits(self, decimal: DecimalDigits, kind: RoundingKind) -> DecimalDigits;
 }
 
 impl FloatToString for f32 {
+    const DECIMAL_SIZE: usize = FLOAT_DECIMAL_SIZE;
+    const MAX_DIGITS: usize = FLOAT_DIGITS;
+    const MIN_DECIMAL_EXPONENT: i32 = -45;
+    const MAX_DECIMAL_EXPONENT: i32 = 38;
+
+    #[inline]
+    fn decimal_digits(self) -> DecimalDigits {
+        float_digits(self)
+    }
+
+    #[inline]
+    #[cfg(feature = "rounding")]
+    fn directed_digits(self, decimal: DecimalDigits, kind: RoundingKind) -> DecimalDigits {
+        directed_digits(self, decimal, kind)
+    }
+
     #[inline]
     #[cfg(not(feature = "power_of_two"))]
     fn decimal<'a>(self, bytes: &'a mut [u8], format: NumberFormat) -> usize {
@@ -125,6 +172,22 @@ impl FloatToString for f32 {
 }
 
 impl FloatToString for f64 {
+    const DECIMAL_SIZE: usize = DOUBLE_DECIMAL_SIZE;
+    const MAX_DIGITS: usize = DOUBLE_DIGITS;
+    const MIN_DECIMAL_EXPONENT: i32 = -324;
+    const MAX_DECIMAL_EXPONENT: i32 = 308;
+
+    #[inline]
+    fn decimal_digits(self) -> DecimalDigits {
+        double_digits(self)
+    }
+
+    #[inline]
+    #[cfg(feature = "rounding")]
+    fn directed_digits(self, decimal: DecimalDigits, kind: RoundingKind) -> DecimalDigits {
+        directed_digits(self, decimal, kind)
+    }
+
     #[inline]
     #[cfg(not(feature = "power_of_two"))]
     fn decimal<'a>(self, bytes: &'a mut [u8], format: NumberFormat) -> usize {
@@ -156,6 +219,217 @@ impl FloatToString for f64 {
 
 // FTOA
 
+/// Determine if a float is exactly halfway between its shortest digits
+/// and the next decimal value with as many digits.
+///
+/// The float backends may break these ties to even, while Rust's `{:e}`
+/// always rounds them up.
+#[inline]
+fn is_halfway<F: FloatToString>(value: F, digits: &[u8], exponent: i32) -> bool {
+    debug_assert!(!value.is_zero(), "Value cannot be zero.");
+    debug_assert!(digits.len() < 19, "Halfway point must fit in a u64.");
+
+    // The halfway point has an extra, trailing 5, and is therefore odd.
+    let halfway = digits.iter().fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64) * 10 + 5;
+    let halfway_exponent = exponent - digits.len() as i32;
+
+    // Compare `halfway * 10^halfway_exponent` to `mantissa * 2^exponent`:
+    // both the powers of 2 and the odd factors must be equal.
+    let mantissa = value.mantissa().as_u64();
+    let shift = mantissa.trailing_zeros();
+    let odd = mantissa >> shift;
+    if halfway_exponent != value.exponent() + shift as i32 {
+        return false;
+    }
+    let (mut lo, hi, power) = match halfway_exponent >= 0 {
+        true => (halfway, odd, halfway_exponent),
+        false => (odd, halfway, -halfway_exponent),
+    };
+    for _ in 0..power {
+        match lo.checked_mul(5) {
+            Some(v) if v <= hi => lo = v,
+            _ => return false,
+        }
+    }
+    lo == hi
+}
+
+/// Layout for the shortest decimal digits of a float.
+#[derive(Clone, Copy)]
+struct ShortestLayout {
+    /// Inclusive range of exponents written in positional notation.
+    positional: (i32, i32),
+    /// Write a `+` sign before positive exponents.
+    positive_exponent_sign: bool,
+    /// Minimum number of exponent digits.
+    min_exponent_digits: usize,
+    /// Round exact ties between the shortest candidates to even.
+    round_ties_even: bool,
+    /// Write negative zero without a sign.
+    unsigned_zero: bool,
+    /// Rounding direction of the digits.
+    rounding: RoundingKind,
+}
+
+impl ShortestLayout {
+    /// Get the layout from the write options, if floats use the shortest digits.
+    #[inline]
+    fn new(options: &WriteFloatOptions) -> Option<Self> {
+        // Scientific notation is the shortest digits, without positional exponents.
+        let positional = match options.scientific() {
+            true => Some((1, 0)),
+            false => options.positional_exponents(),
+        };
+        positional.map(|positional| Self::with_positional(options, positional))
+    }
+
+    /// Get the layout from the write options, with the positional range.
+    #[inline]
+    fn with_positional(options: &WriteFloatOptions, positional: (i32, i32)) -> Self {
+        ShortestLayout {
+            positional,
+            positive_exponent_sign: positive_exponent_sign(options),
+            min_exponent_digits: options.min_exponent_digits().unwrap_or(1),
+            round_ties_even: options.round_ties_even(),
+            unsigned_zero: options.unsigned_zero(),
+            rounding: options.rounding(),
+        }
+    }
+
+    /// Convert the rounding kind to the direction of the magnitude.
+    #[inline]
+    fn with_sign(self, sign: Sign) -> Self {
+        ShortestLayout {
+            rounding: internal_rounding(self.rounding, sign),
+            ..self
+        }
+    }
+}
+
+/// Generate the shortest decimal digits of a float for the layout.
+///
+/// Ties between the shortest candidates and directed rounding are
+/// resolved by the layout.
+#[inline]
+fn shortest_digits<F: FloatToString>(value: F, layout: ShortestLayout) -> DecimalDigits {
+    debug_assert!(value.is_sign_positive(), "Value cannot be negative.");
+
+    let mut decimal = match value.is_zero() {
+        true => DecimalDigits::zero(),
+        false => value.decimal_digits(),
+    };
+    let round_up = !layout.round_ties_even && !value.is_zero();
+    if round_up && is_halfway(value, decimal.digits(), decimal.exponent()) {
+        // Both candidates round-trip, so the last digit cannot be 9.
+        decimal.increment_last();
+    }
+    #[cfg(feature = "rounding")]
+    let decimal = match layout.rounding.is_toward() && !value.is_zero() {
+        true => value.directed_digits(decimal, layout.rounding),
+        false => decimal,
+    };
+    decimal
+}
+
+/// Write the shortest decimal digits of a float, like Rust's `Display`,
+/// `Debug` and `{:e}`, or Python's `repr`.
+#[inline]
+fn shortest<F: FloatToString>(
+    value: F,
+    bytes: &mut [u8],
+    format: NumberFormat,
+    layout: ShortestLayout,
+    trim_floats: bool,
+) -> usize {
+    write_digits(shortest_digits(value, layout), bytes, format, layout, trim_floats)
+}
+
+/// Write decimal digits with the shortest layout.
+///
+/// Digits with an exponent in the positional range are written in
+/// positional notation, and otherwise in scientific notation: the
+/// decimal point is only written if there is more than one digit,
+/// and the exponent is padded with leading zeros to the minimum
+/// number of exponent digits.
+#[inline]
+fn write_digits(
+    decimal: DecimalDigits,
+    bytes: &mut [u8],
+    format: NumberFormat,
+    layout: ShortestLayout,
+    trim_floats: bool,
+) -> usize {
+    let digits = decimal.digits();
+    let exponent = decimal.exponent();
+    let decimal_point = format.decimal_point();
+
+    let (min_exponent, max_exponent) = layout.positional;
+    if exponent >= min_exponent && exponent <= max_exponent {
+        // Check the buffer length, since the leading or trailing zeros
+        // are not bounded by `FORMATTED_SIZE`.
+        let count = match exponent >= 0 {
+            true => digits.len().max(exponent as usize + 1) + 2,
+            false => digits.len() + exponent.wrapping_neg() as usize + 1,
+        };
+        assert!(bytes.len() >= count, "Buffer is too small for positional notation.");
+
+        if exponent < 0 {
+            // Write `0.`, then leading zeros, then the digits.
+            let zeros = exponent.wrapping_neg() as usize - 1;
+            bytes[0] = b'0';
+            bytes[1] = decimal_point;
+            write_bytes(&mut bytes[2..zeros + 2], b'0');
+            zeros + 2 + copy_to_dst(&mut bytes[zeros + 2..], digits)
+        } else if digits.len() > exponent as usize + 1 {
+            // Split the digits with the decimal point.
+            let (integer, fraction) = digits.split_at(exponent as usize + 1);
+            let cursor = copy_to_dst(bytes, integer);
+            bytes[cursor] = decimal_point;
+            cursor + 1 + copy_to_dst(&mut bytes[cursor + 1..], fraction)
+        } else {
+            // Write the digits, then trailing zeros, then an optional `.0`.
+            let cursor = exponent as usize + 1;
+            copy_to_dst(bytes, digits);
+            write_bytes(&mut bytes[digits.len()..cursor], b'0');
+            match trim_floats {
+                true => cursor,
+                false => cursor + copy_to_dst(&mut bytes[cursor..], [decimal_point, b'0']),
+            }
+        }
+    } else {
+        bytes[0] = digits[0];
+        let mut cursor = 1;
+        if digits.len() > 1 {
+            bytes[1] = decimal_point;
+            cursor = 2 + copy_to_dst(&mut bytes[2..], &digits[1..]);
+        }
+        bytes[cursor] = format.exponent(10);
+        cursor += 1;
+        if exponent < 0 {
+            bytes[cursor] = b'-';
+            cursor += 1;
+        } else if layout.positive_exponent_sign {
+            bytes[cursor] = b'+';
+            cursor += 1;
+        }
+
+        // Pad the exponent with leading zeros, which are not bounded
+        // by `FORMATTED_SIZE`.
+        let exponent = exponent.wrapping_abs() as u32;
+        let mut count = 1;
+        while count < layout.min_exponent_digits && exponent >= 10u32.pow(count as u32) {
+            count += 1;
+        }
+        if count < layout.min_exponent_digits {
+            let zeros = layout.min_exponent_digits - count;
+            assert!(bytes.len() >= cursor + zeros + count, "Buffer is too small for exponent.");
+            write_bytes(&mut bytes[cursor..cursor + zeros], b'0');
+            cursor += zeros;
+        }
+        cursor + itoa::itoa_positive(exponent, 10, &mut bytes[cursor..])
+    }
+}
+
 /// Forward the correct arguments the ideal encoder.
 #[inline]
 fn forward<'a, F: FloatToString>(
@@ -192,11 +466,19 @@ fn filter_special<'a, F: FloatToString>(
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     trim_floats: bool,
+    layout: Option<ShortestLayout>,
 ) -> usize {
     // Logic errors, disable in release builds.
     debug_assert!(value.is_sign_positive(), "Value cannot be negative.");
     debug_assert_radix!(radix);
 
+    // Shortest digits are only valid with a radix of 10, and write zero.
+    if let Some(layout) = layout {
+        if !value.is_special() {
+            return shortest(value, bytes, format, layout, trim_floats);
+        }
+    }
+
     // We already check for 0 in `filter_sign` if value.is_zero().
     if !trim_floats && value.is_zero() {
         // This is safe, because we confirmed the buffer is >= 4
@@ -229,26 +511,59 @@ fn filter_sign<'a, F: FloatToString>(
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     trim_floats: bool,
+    layout: Option<ShortestLayout>,
 ) -> usize {
     debug_assert_radix!(radix);
 
     // Export "-0.0" and "0.0" as "0" with trimmed floats.
-    if trim_floats && value.is_zero() {
+    if trim_floats && layout.is_none() && value.is_zero() {
         // We know this is safe, because we confirmed the buffer is >= 1.
         bytes[0] = b'0';
         return 1;
     }
 
+    // Rust and Python never write a sign for NaN.
+    if layout.is_some() && value.is_nan() {
+        return copy_to_dst(bytes, nan_string);
+    }
+
+    // JavaScript writes negative zero as `0`.
+    let unsigned_zero = layout.map_or(false, |layout| layout.unsigned_zero);
+    let value = match unsigned_zero && value.is_zero() {
+        true => F::ZERO,
+        false => value,
+    };
+
     // If the sign bit is set, invert it and just set the first
     // value to "-".
     if value.is_sign_negative() {
         let value = -value;
+        let layout = layout.map(|layout| layout.with_sign(Sign::Negative));
         // We know this is safe, because we confirmed the buffer is >= 1.
         bytes[0] = b'-';
         let bytes = &mut bytes[1..];
-        filter_special(value, radix, bytes, format, nan_string, inf_string, trim_floats) + 1
+        filter_special(
+            value,
+            radix,
+            bytes,
+            format,
+            nan_string,
+            inf_string,
+            trim_floats,
+            layout,
+        ) + 1
     } else {
-        filter_special(value, radix, bytes, format, nan_string, inf_string, trim_floats)
+        let layout = layout.map(|layout| layout.with_sign(Sign::Positive));
+        filter_special(
+            value,
+            radix,
+            bytes,
+            format,
+            nan_string,
+            inf_string,
+            trim_floats,
+            layout,
+        )
     }
 }
 
@@ -273,8 +588,18 @@ fn from_native<F: FloatToString>(
     nan_string: &'static [u8],
     inf_string: &'static [u8],
     trim_floats: bool,
+    layout: Option<ShortestLayout>,
 ) -> usize {
-    let len = filter_sign(value, radix, bytes, format, nan_string, inf_string, trim_floats);
+    let len = filter_sign(
+        value,
+        radix,
+        bytes,
+        format,
+        nan_string,
+        inf_string,
+        trim_floats,
+        layout,
+    );
     let bytes = &mut bytes[..len];
     trim(bytes, trim_floats)
 }
@@ -290,18 +615,202 @@ fn ftoa<F: FloatToString>(value: F, radix: u32, bytes: &mut [u8]) -> usize {
         DEFAULT_NAN_STRING,
         DEFAULT_INF_STRING,
         DEFAULT_TRIM_FLOATS,
+        None,
     )
 }
 
-/// Write float to string.
+/// Always write a decimal point in the mantissa, for the alternate form.
+///
+/// A decimal point, and a zero fraction digit with `PointZero`, are
+/// inserted after integral mantissas, and the zero fraction digit of
+/// `3.0` is removed with `Point`, even if it was written without
+/// `trim_floats`.
+#[inline]
+fn alternate_form(
+    bytes: &mut [u8],
+    len: usize,
+    decimal_point: u8,
+    exponent_char: u8,
+    form: AlternateForm,
+) -> usize {
+    let start = (bytes[0] == b'-') as usize;
+    let end = match bytes[start..len].iter().position(|&c| c == exponent_char) {
+        Some(index) => start + index,
+        None => len,
+    };
+    let mantissa = &bytes[start..end];
+    let insert: &[u8] = match (mantissa.contains(&decimal_point), form) {
+        (true, AlternateForm::Point) if ends_with_slice(mantissa, &[decimal_point, b'0']) => {
+            bytes.copy_within(end..len, end - 1);
+            return len - 1;
+        },
+        (true, _) => return len,
+        (false, AlternateForm::Point) => &[decimal_point],
+        (false, AlternateForm::PointZero) => &[decimal_point, b'0'],
+    };
+
+    let shift = insert.len();
+    assert!(bytes.len() >= len + shift, "Buffer is too small for the decimal point.");
+    bytes.copy_within(end..len, end + shift);
+    copy_to_dst(&mut bytes[end..], insert);
+    len + shift
+}
+
+/// Write a `+` sign before a positive exponent written by the float backend,
+/// and pad the exponent with leading zeros to the minimum number of digits.
+///
+/// The shortest layout writes the exponent itself, so this is only used
+/// for the backend's layout, which never pads the exponent. The Grisu2
+/// backend writes a `+` sign for positive exponents, which is removed
+/// first, so only the digits are padded and the sign is only written
+/// if requested, like the other backends.
+#[inline]
+fn layout_exponent(
+    bytes: &mut [u8],
+    mut len: usize,
+    exponent_char: u8,
+    positive_exponent_sign: bool,
+    min_exponent_digits: usize,
+) -> usize {
+    let mut cursor = match bytes[..len].iter().rposition(|&c| c == exponent_char) {
+        Some(index) => index + 1,
+        None => return len,
+    };
+    if bytes[cursor] == b'+' {
+        bytes.copy_within(cursor + 1..len, cursor);
+        len -= 1;
+    }
+    let sign = match bytes[cursor] == b'-' {
+        true => {
+            cursor += 1;
+            0
+        },
+        false => positive_exponent_sign as usize,
+    };
+    let zeros = min_exponent_digits.saturating_sub(len - cursor);
+    let shift = sign + zeros;
+    if shift == 0 {
+        return len;
+    }
+
+    assert!(bytes.len() >= len + shift, "Buffer is too small for exponent.");
+    bytes.copy_within(cursor..len, cursor + shift);
+    if sign != 0 {
+        bytes[cursor] = b'+';
+    }
+    write_bytes(&mut bytes[cursor + sign..cursor + shift], b'0');
+    len + shift
+}
+
+/// Get if a `+` sign is written before positive exponents.
+///
+/// The sign is written if the options request it, or if the number
+/// format requires exponent signs.
 #[inline]
-fn ftoa_with_options<F: FloatToString>(
+fn positive_exponent_sign(options: &WriteFloatOptions) -> bool {
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    options.positive_exponent_sign() || format.required_exponent_sign()
+}
+
+/// Get how integral mantissas are written in the alternate form.
+///
+/// Number formats that require fraction digits write them as `3.0`,
+/// unless the options already request an alternate form.
+#[inline]
+fn alternate_form_of(options: &WriteFloatOptions) -> Option<AlternateForm> {
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    match options.alternate_form() {
+        None if format.required_fraction_digits() => Some(AlternateForm::PointZero),
+        form => form,
+    }
+}
+
+/// Get if the number format writes internal digit separators.
+#[inline]
+fn is_digit_separators(format: NumberFormat) -> bool {
+    let is_internal = format.integer_internal_digit_separator()
+        || format.fraction_internal_digit_separator();
+    format.digit_separator() != 0 && is_internal
+}
+
+/// Group the mantissa digits in threes from the decimal point, with the
+/// digit separator of the number format.
+///
+/// Only internal digit separators are written, so the integer digits
+/// are grouped with `integer_internal_digit_separator`, and the fraction
+/// digits with `fraction_internal_digit_separator`.
+#[inline]
+fn digit_separators(
+    bytes: &mut [u8],
+    len: usize,
+    format: NumberFormat,
+    exponent_char: u8,
+) -> usize {
+    let start = (bytes[0] == b'-') as usize;
+    let end = match bytes[start..len].iter().position(|&c| c == exponent_char) {
+        Some(index) => start + index,
+        None => len,
+    };
+    let decimal_point = format.decimal_point();
+    let point = bytes[start..end].iter().position(|&c| c == decimal_point).map(|i| start + i);
+    let integer_end = point.unwrap_or(end);
+    let integer = format.integer_internal_digit_separator();
+    let fraction = format.fraction_internal_digit_separator() && point.is_some();
+    let integer_count = match integer {
+        true => (integer_end - start).saturating_sub(1) / 3,
+        false => 0,
+    };
+    let fraction_count = match fraction {
+        true => (end - integer_end).saturating_sub(2) / 3,
+        false => 0,
+    };
+    let shift = integer_count + fraction_count;
+    if shift == 0 {
+        return len;
+    }
+
+    // Move the digits from the back, so they are not overwritten.
+    assert!(bytes.len() >= len + shift, "Buffer is too small for digit separators.");
+    let separator = format.digit_separator();
+    bytes.copy_within(end..len, end + shift);
+    let mut src = end;
+    let mut dst = end + shift;
+    if point.is_some() {
+        for index in (0..end - integer_end - 1).rev() {
+            src -= 1;
+            dst -= 1;
+            bytes[dst] = bytes[src];
+            if fraction && index != 0 && index % 3 == 0 {
+                dst -= 1;
+                bytes[dst] = separator;
+            }
+        }
+        src -= 1;
+        dst -= 1;
+        bytes[dst] = bytes[src];
+    }
+    for index in 0..integer_end - start {
+        src -= 1;
+        dst -= 1;
+        bytes[dst] = bytes[src];
+        if integer && index % 3 == 2 && src != start {
+            dst -= 1;
+            bytes[dst] = separator;
+        }
+    }
+    len + shift
+}
+
+/// Write float to string with the notation of the options.
+#[inline]
+fn ftoa_notation<F: FloatToString>(
     value: F,
     bytes: &mut [u8],
     options: &WriteFloatOptions,
 ) -> usize {
     let format = options.format().unwrap_or(DEFAULT_FORMAT);
-    from_native(
+    let layout = ShortestLayout::new(options);
+    let len = from_native(
         value,
         options.radix(),
         bytes,
@@ -309,125 +818,766 @@ fn ftoa_with_options<F: FloatToString>(
         options.nan_string(),
         options.inf_string(),
         options.trim_floats(),
-    )
+        layout,
+    );
+    let positive_exponent_sign = positive_exponent_sign(options);
+    let min_exponent_digits = options.min_exponent_digits().unwrap_or(1);
+    let is_exponent = positive_exponent_sign || min_exponent_digits > 1;
+    let exponent_char = format.exponent(options.radix());
+    let len = if layout.is_none() && is_exponent && !value.is_special() {
+        layout_exponent(bytes, len, exponent_char, positive_exponent_sign, min_exponent_digits)
+    } else {
+        len
+    };
+    let len = match alternate_form_of(options) {
+        Some(form) if !value.is_special() => {
+            alternate_form(bytes, len, format.decimal_point(), exponent_char, form)
+        },
+        _ => len,
+    };
+    match is_digit_separators(format) && !value.is_special() {
+        true => digit_separators(bytes, len, format, exponent_char),
+        false => len,
+    }
 }
 
-// TO LEXICAL
-
-to_lexical!(ftoa, f32);
-to_lexical!(ftoa, f64);
-
-to_lexical_with_options!(ftoa_with_options, f32);
-to_lexical_with_options!(ftoa_with_options, f64);
+/// Write float to string with the layout of the options.
+#[inline]
+fn ftoa_layout<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+) -> usize {
+    let len = match options.si_prefix() {
+        Some(precision) if !value.is_special() => si_prefix(value, bytes, options, precision),
+        _ => ftoa_notation(value, bytes, options),
+    };
+    let len = match options.negative_parentheses() {
+        true => negative_parentheses(bytes, len),
+        false => len,
+    };
+    match options.positive_sign() {
+        Some(sign) => positive_sign(bytes, len, sign),
+        None => len,
+    }
+}
 
-// TESTS
-// -----
+/// Correctly round the shortest digits of a float to at most `count` digits.
+///
+/// Ties are rounded to even. The digits are compared to the float exactly,
+/// since rounding the shortest digits again may round them incorrectly.
+#[inline]
+fn round_digits<F: FloatType>(value: F, decimal: DecimalDigits, count: usize) -> DecimalDigits {
+    let digits = decimal.digits();
+    if digits.len() <= count {
+        return decimal;
+    }
 
-#[cfg(test)]
-mod tests {
-    // Shouldn't need to include atof, should be fine with ToLexical in scope.
-    use crate::traits::*;
-    use crate::util::*;
-    use approx::assert_relative_eq;
+    // The candidate is `buffer[..count] * 10^(exponent + 1 - count)`.
+    let mut buffer = [b'0'; 20];
+    let mut exponent = decimal.exponent();
+    buffer[..count].copy_from_slice(&digits[..count]);
+    let scale = |exponent: i32| exponent + 1 - count as i32;
+
+    // The truncated digits are below the float, unless the backend's
+    // digits are not the shortest, like Grisu2.
+    if compare_parts(&buffer[..count], scale(exponent), value, 10) == cmp::Ordering::Greater {
+        let index = buffer[..count].iter().rposition(|&c| c != b'0').unwrap();
+        buffer[index] -= 1;
+        write_bytes(&mut buffer[index + 1..count], b'9');
+        if buffer[0] == b'0' {
+            buffer.copy_within(1..count, 0);
+            buffer[count - 1] = b'9';
+            exponent -= 1;
+        }
+    }
 
-    cfg_if! {
-    if #[cfg(feature = "property_tests")] {
-        use quickcheck::quickcheck;
-        use proptest::{proptest, prop_assert_eq};
-    }} // cfg_if
+    // Compare the float to the halfway point to the next candidate.
+    buffer[count] = b'5';
+    let round_up = match compare_parts(&buffer[..count + 1], scale(exponent) - 1, value, 10) {
+        cmp::Ordering::Less => true,
+        cmp::Ordering::Greater => false,
+        cmp::Ordering::Equal => (buffer[count - 1] - b'0') % 2 == 1,
+    };
+    if round_up {
+        match buffer[..count].iter().rposition(|&c| c != b'9') {
+            Some(index) => {
+                buffer[index] += 1;
+                write_bytes(&mut buffer[index + 1..count], b'0');
+            },
+            None => {
+                buffer[0] = b'1';
+                write_bytes(&mut buffer[1..count], b'0');
+                exponent += 1;
+            },
+        }
+    }
+    DecimalDigits::new(&buffer[..count], exponent)
+}
 
-    // Test data for roundtrips.
-    const F32_DATA: [f32; 31] = [
-        0.,
-        0.1,
-        1.,
-        1.1,
-        12.,
-        12.1,
-        123.,
-        123.1,
-        1234.,
-        1234.1,
-        12345.,
-        12345.1,
-        123456.,
-        123456.1,
-        1234567.,
-        1234567.1,
-        12345678.,
-        12345678.1,
-        123456789.,
-        123456789.1,
-        123456789.12,
-        123456789.123,
-        123456789.1234,
-        123456789.12345,
-        1.2345678912345e8,
-        1.2345e+8,
-        1.2345e+11,
-        1.2345e+38,
-        1.2345e-8,
-        1.2345e-11,
-        1.2345e-38,
-    ];
-    const F64_DATA: [f64; 33] = [
-        0.,
-        0.1,
-        1.,
-        1.1,
-        12.,
-        12.1,
-        123.,
-        123.1,
-        1234.,
-        1234.1,
-        12345.,
-        12345.1,
-        123456.,
-        123456.1,
-        1234567.,
-        1234567.1,
-        12345678.,
-        12345678.1,
-        123456789.,
-        123456789.1,
-        123456789.12,
-        123456789.123,
-        123456789.1234,
-        123456789.12345,
-        1.2345678912345e8,
-        1.2345e+8,
-        1.2345e+11,
-        1.2345e+38,
-        1.2345e+308,
-        1.2345e-8,
-        1.2345e-11,
-        1.2345e-38,
-        1.2345e-299,
-    ];
+/// Write a float that does not fit in the maximum width in scientific
+/// notation, with the most correctly rounded digits that fit.
+///
+/// Returns `None` if even a single digit does not fit.
+#[inline]
+fn fit_width<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+    max_width: usize,
+) -> Option<usize> {
+    if value.is_special() {
+        return None;
+    }
 
-    #[test]
-    fn special_bytes_test() {
-        let options = WriteFloatOptions::decimal();
-        let mut buffer = new_buffer();
-        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
-        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    let negative = value.is_sign_negative() && !(options.unsigned_zero() && value.is_zero());
+    let (value, sign) = match value.is_sign_negative() {
+        true => (-value, Sign::Negative),
+        false => (value, Sign::Positive),
+    };
+    let layout = ShortestLayout::with_positional(options, (1, 0)).with_sign(sign);
+    let shortest = shortest_digits(value, layout);
+    let decimal = match value.is_zero() {
+        true => DecimalDigits::zero(),
+        false => value.decimal_digits(),
+    };
+
+    let sign = match negative {
+        true => Some(b'-'),
+        false => options.positive_sign(),
+    };
+    let offset = sign.is_some() as usize;
+    let decimal_point = format.decimal_point();
+    let exponent_char = format.exponent(10);
+    for count in (1..=shortest.digits().len()).rev() {
+        let digits = match count == shortest.digits().len() {
+            true => shortest,
+            false => round_digits(value, decimal, count),
+        };
+        if let Some(sign) = sign {
+            bytes[0] = sign;
+        }
+        let len = offset + write_digits(digits, &mut bytes[offset..], format, layout, false);
+        let len = match alternate_form_of(options) {
+            Some(form) => alternate_form(bytes, len, decimal_point, exponent_char, form),
+            None => len,
+        };
+        let len = match is_digit_separators(format) {
+            true => digit_separators(bytes, len, format, exponent_char),
+            false => len,
+        };
+        let len = match negative && options.negative_parentheses() {
+            true => negative_parentheses(bytes, len),
+            false => len,
+        };
+        if len <= max_width {
+            return Some(len);
+        }
+    }
+    None
+}
 
-        let options = WriteFloatOptions::builder()
-            .nan_string(b"nan")
-            .inf_string(b"Infinity")
-            .build()
-            .unwrap();
+/// SI prefixes of the powers of 1000, from `1e-30` to `1e30`.
+///
+/// The micro sign, `µ`, is encoded in UTF-8.
+const SI_PREFIXES: [&[u8]; 21] = [
+    b"q", b"r", b"y", b"z", b"a", b"f", b"p", b"n", b"\xC2\xB5", b"m", b"", b"k", b"M", b"G",
+    b"T", b"P", b"E", b"Z", b"Y", b"R", b"Q",
+];
 
-        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"nan");
-        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"Infinity");
+/// Correctly round the digits of a float's magnitude to the significant
+/// digits written with an SI prefix.
+#[inline]
+fn si_digits<F: FloatToString + FloatType>(value: F, precision: usize) -> DecimalDigits {
+    match value.is_zero() {
+        true => DecimalDigits::zero(),
+        false => round_digits(value, value.decimal_digits(), precision),
     }
+}
 
-    #[test]
-    #[cfg(feature = "power_of_two")]
-    fn f32_binary_test() {
-        let mut buffer = new_buffer();
-        // positive
+/// Write a float scaled by a power of 1000, with the SI prefix of the power.
+///
+/// The integer part has 1 to 3 digits, and the digits are padded with
+/// trailing zeros to the significant digits, unless `trim_floats` is set.
+/// Floats outside the range of the prefixes are written in scientific
+/// notation, with a single integer digit.
+#[inline]
+fn si_prefix<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+    precision: usize,
+) -> usize {
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    let exponent_char = format.exponent(10);
+    let (value, mut cursor) = match value.is_sign_negative() {
+        true => {
+            bytes[0] = b'-';
+            (-value, 1)
+        },
+        false => (value, 0),
+    };
+    let decimal = si_digits(value, precision);
+    let digits = decimal.digits();
+    let exponent = decimal.exponent();
+
+    // Round the exponent down to a power of 1000, to get the prefix.
+    let power = match exponent >= 0 {
+        true => exponent / 3,
+        false => (exponent - 2) / 3,
+    };
+    let index = power + SI_PREFIXES.len() as i32 / 2;
+    let (integer, prefix) = match index >= 0 && index < SI_PREFIXES.len() as i32 {
+        true => ((exponent - 3 * power) as usize + 1, Some(SI_PREFIXES[index as usize])),
+        false => (1, None),
+    };
+
+    // Write the integer digits, then the fraction digits after the point.
+    let count = match options.trim_floats() {
+        true => digits.len().max(integer),
+        false => precision.max(integer),
+    };
+    let digit = |index: usize| digits.get(index).map_or(b'0', |&c| c);
+    assert!(bytes.len() > cursor + count, "Buffer is too small for the SI prefix.");
+    for index in 0..integer {
+        bytes[cursor] = digit(index);
+        cursor += 1;
+    }
+    if count > integer {
+        bytes[cursor] = format.decimal_point();
+        cursor += 1;
+        for index in integer..count {
+            bytes[cursor] = digit(index);
+            cursor += 1;
+        }
+    }
+    let len = match alternate_form_of(options) {
+        Some(form) => alternate_form(bytes, cursor, format.decimal_point(), exponent_char, form),
+        None => cursor,
+    };
+    let len = match is_digit_separators(format) {
+        true => digit_separators(bytes, len, format, exponent_char),
+        false => len,
+    };
+
+    match prefix {
+        Some(prefix) => {
+            assert!(bytes.len() >= len + prefix.len(), "Buffer is too small for the SI prefix.");
+            len + copy_to_dst(&mut bytes[len..], prefix)
+        },
+        None => {
+            // Write the exponent, then add the sign and padding of the options.
+            bytes[len] = exponent_char;
+            let mut cursor = len + 1;
+            if exponent < 0 {
+                bytes[cursor] = b'-';
+                cursor += 1;
+            }
+            let exponent = exponent.wrapping_abs() as u32;
+            cursor += itoa::itoa_positive(exponent, 10, &mut bytes[cursor..]);
+            let sign = positive_exponent_sign(options);
+            let min_digits = options.min_exponent_digits().unwrap_or(1);
+            layout_exponent(bytes, cursor, exponent_char, sign, min_digits)
+        },
+    }
+}
+
+/// Write float to string, fitting it in the maximum width of the options.
+#[inline]
+fn try_ftoa_with_options<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+) -> Result<usize> {
+    let len = ftoa_layout(value, bytes, options);
+    match options.max_width() {
+        Some(max_width) if len > max_width => match fit_width(value, bytes, options, max_width) {
+            Some(len) => Ok(len),
+            None => Err((ErrorCode::ExceedsMaxWidth, max_width).into()),
+        },
+        _ => Ok(len),
+    }
+}
+
+/// Write float to string.
+#[inline]
+fn ftoa_with_options<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+) -> usize {
+    match try_ftoa_with_options(value, bytes, options) {
+        Ok(len) => len,
+        Err(_) => panic!("Float does not fit in the maximum width."),
+    }
+}
+
+/// Replace the minus sign of a negative value with enclosing parentheses.
+#[inline]
+fn negative_parentheses(bytes: &mut [u8], len: usize) -> usize {
+    if bytes[0] != b'-' {
+        return len;
+    }
+    assert!(bytes.len() > len, "Buffer is too small for parentheses.");
+    bytes[0] = b'(';
+    bytes[len] = b')';
+    len + 1
+}
+
+/// Write the sign of a non-negative value before the digits.
+#[inline]
+fn positive_sign(bytes: &mut [u8], len: usize, sign: u8) -> usize {
+    if bytes[0] == b'-' || bytes[0] == b'(' {
+        return len;
+    }
+    assert!(bytes.len() > len, "Buffer is too small for the sign.");
+    bytes.copy_within(0..len, 1);
+    bytes[0] = sign;
+    len + 1
+}
+
+/// Write float to string, and check if the digits exactly represent it.
+#[inline]
+fn ftoa_with_info<F: FloatToString + FloatType>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+) -> (usize, Exactness) {
+    let len = ftoa_with_options(value, bytes, options);
+    if value.is_special() {
+        return (len, Exactness::Exact);
+    }
+
+    // Skip the sign or opening parenthesis, and check the magnitude.
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    let has_sign = match bytes[0] {
+        b'-' | b'(' => true,
+        c => Some(c) == options.positive_sign(),
+    };
+    let digits = match has_sign {
+        true => &bytes[1..len],
+        false => &bytes[..len],
+    };
+    let value = match value.is_sign_negative() {
+        true => -value,
+        false => value,
+    };
+    let is_exact = match options.si_prefix() {
+        // The prefix scales the digits, so compare the rounded digits.
+        Some(_) if value.is_zero() => true,
+        Some(precision) => {
+            let decimal = si_digits(value, precision);
+            let digits = decimal.digits();
+            let exponent = decimal.exponent() + 1 - digits.len() as i32;
+            compare_parts(digits, exponent, value, 10) == cmp::Ordering::Equal
+        },
+        None => is_exact_bytes(digits, value, format, options.radix()),
+    };
+    match is_exact {
+        true => (len, Exactness::Exact),
+        false => (len, Exactness::Inexact),
+    }
+}
+
+/// Write float to string, and invoke the callback for each part.
+#[inline]
+fn ftoa_with_parts<F, Cb>(
+    value: F,
+    bytes: &mut [u8],
+    options: &WriteFloatOptions,
+    mut cb: Cb,
+) -> usize
+where
+    F: FloatToString + FloatType,
+    Cb: FnMut(FloatPart, &[u8]),
+{
+    let len = ftoa_with_options(value, bytes, options);
+    let mut visit = |part, bytes: &[u8]| {
+        if !bytes.is_empty() {
+            cb(part, bytes);
+        }
+    };
+
+    // Split the sign, or the parentheses around a negative value.
+    let (sign, rest) = match bytes[0] {
+        b'-' | b'(' => bytes[..len].split_at(1),
+        c if Some(c) == options.positive_sign() => bytes[..len].split_at(1),
+        _ => bytes[..len].split_at(0),
+    };
+    let (rest, closing) = match sign {
+        b"(" => rest.split_at(rest.len() - 1),
+        _ => rest.split_at(rest.len()),
+    };
+    visit(FloatPart::Sign, sign);
+    if value.is_special() {
+        visit(FloatPart::Special, rest);
+        visit(FloatPart::Sign, closing);
+        return len;
+    }
+
+    // Split the SI prefix after the last digit or decimal point first,
+    // since the exa prefix, `E`, may be the exponent character.
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    let decimal_point = format.decimal_point();
+    let (rest, prefix) = match options.si_prefix() {
+        Some(_) => {
+            let end = rest.iter().rposition(|&c| c.is_ascii_digit() || c == decimal_point);
+            rest.split_at(end.map_or(0, |index| index + 1))
+        },
+        None => rest.split_at(rest.len()),
+    };
+
+    // Split the mantissa and the exponent, with its sign.
+    let exponent_char = format.exponent(options.radix());
+    let (mantissa, exponent) = match rest.iter().position(|&c| c == exponent_char) {
+        Some(index) => rest.split_at(index),
+        None => rest.split_at(rest.len()),
+    };
+    let (integer, fraction) = match mantissa.iter().position(|&c| c == decimal_point) {
+        Some(index) => mantissa.split_at(index),
+        None => mantissa.split_at(mantissa.len()),
+    };
+    visit(FloatPart::Integer, integer);
+    if !fraction.is_empty() {
+        visit(FloatPart::DecimalPoint, &fraction[..1]);
+        visit(FloatPart::Fraction, &fraction[1..]);
+    }
+    if !exponent.is_empty() {
+        let (marker, exponent) = exponent.split_at(1);
+        let (exponent_sign, exponent) = match exponent.first() {
+            Some(b'+') | Some(b'-') => exponent.split_at(1),
+            _ => exponent.split_at(0),
+        };
+        visit(FloatPart::ExponentMarker, marker);
+        visit(FloatPart::ExponentSign, exponent_sign);
+        visit(FloatPart::Exponent, exponent);
+    }
+    visit(FloatPart::SiPrefix, prefix);
+    visit(FloatPart::Sign, closing);
+
+    len
+}
+
+/// Get the number of bytes required to write any float with the options.
+///
+/// Shortest layouts are bounded by the number of digits and the range
+/// of exponents written in positional notation, and other layouts by
+/// the bytes written by the float backend and the exponent padding.
+#[inline]
+fn ftoa_formatted_size<F: FloatToString>(options: &WriteFloatOptions) -> usize {
+    // Special values, including `-0.0`, and the sign.
+    let nan_size = options.nan_string().len() + 1;
+    let inf_size = options.inf_string().len() + 1;
+    let special_size = nan_size.max(inf_size).max(4);
+    let min_exponent_digits = options.min_exponent_digits().unwrap_or(1);
+    let positive_exponent_sign = positive_exponent_sign(options) as usize;
+    let negative_parentheses = options.negative_parentheses() as usize;
+    let exponent = F::MIN_DECIMAL_EXPONENT.wrapping_neg().max(F::MAX_DECIMAL_EXPONENT);
+    let exponent_digits = digit_count(exponent as u128, 10).max(min_exponent_digits);
+
+    let size = match (options.si_prefix(), ShortestLayout::new(options)) {
+        // Sign, digits, decimal point, and the SI prefix, or the exponent
+        // character, exponent sign and exponent digits.
+        (Some(precision), _) => precision.max(3) + exponent_digits + 4,
+        (None, Some(layout)) => {
+            // Sign, digits, decimal point, exponent character, exponent sign
+            // and exponent digits.
+            let scientific_size = F::MAX_DIGITS + exponent_digits + 4;
+
+            // Only the exponents of finite floats are written positionally.
+            let (min_exponent, max_exponent) = layout.positional;
+            let min_exponent = min_exponent.max(F::MIN_DECIMAL_EXPONENT);
+            let max_exponent = max_exponent.min(F::MAX_DECIMAL_EXPONENT);
+            let mut positional_size = 0;
+            if min_exponent <= max_exponent && max_exponent >= 0 {
+                positional_size = F::MAX_DIGITS.max(max_exponent as usize + 1) + 3;
+            }
+            if min_exponent <= max_exponent && min_exponent < 0 {
+                let zeros = min_exponent.wrapping_neg() as usize;
+                positional_size = positional_size.max(F::MAX_DIGITS + zeros + 2);
+            }
+            scientific_size.max(positional_size)
+        },
+        // The sign, the backend's layout, and the exponent padding.
+        (None, None) if options.radix() == 10 => {
+            F::DECIMAL_SIZE + positive_exponent_sign + min_exponent_digits
+        },
+        (None, None) => F::FORMATTED_SIZE + positive_exponent_sign + min_exponent_digits - 1,
+    };
+    // The minus sign is replaced by the opening parenthesis, and the
+    // alternate form may add a decimal point and a zero fraction digit.
+    // Digit separators are written between groups of 3 digits.
+    let alternate_form = alternate_form_of(options).map_or(0, |_| 2);
+    let format = options.format().unwrap_or(DEFAULT_FORMAT);
+    let digit_separators = match is_digit_separators(format) {
+        true => (size + alternate_form) / 3,
+        false => 0,
+    };
+    size.max(special_size) + negative_parentheses + alternate_form + digit_separators
+}
+
+/// Get the number of bytes required to write the float with the options.
+///
+/// The float is written to a buffer on the stack, unless the options may
+/// require more than `POSITIONAL_BUFFER_SIZE` bytes. Floats that do not
+/// fit in the maximum width cannot be written, and require no bytes.
+#[inline]
+fn ftoa_size_hint<F: FloatToString + FloatType>(value: F, options: &WriteFloatOptions) -> usize {
+    let size = ftoa_formatted_size::<F>(options);
+    if size > POSITIONAL_BUFFER_SIZE {
+        return size;
+    }
+    let mut buffer = [0u8; POSITIONAL_BUFFER_SIZE];
+    try_ftoa_with_options(value, &mut buffer, options).unwrap_or(0)
+}
+
+/// Generate the shortest decimal digits of a float's magnitude.
+#[inline]
+fn ftoa_digits<F: FloatToString>(value: F) -> Option<DecimalDigits> {
+    if value.is_special() {
+        None
+    } else if value.is_zero() {
+        Some(DecimalDigits::zero())
+    } else if value.is_sign_negative() {
+        Some((-value).decimal_digits())
+    } else {
+        Some(value.decimal_digits())
+    }
+}
+
+// BIG MANTISSA
+
+/// Write a big-integer mantissa and exponent in scientific notation.
+///
+/// Writes the correctly rounded digits with `precision` digits after
+/// the decimal point, like Rust's `{:.*e}`. Returns `None` if the
+/// buffer is too small, or if the big integers may exceed their storage.
+pub(crate) fn write_big_mantissa(
+    mantissa: &[u32],
+    exponent: BigExponent,
+    precision: usize,
+    bytes: &mut [u8],
+) -> Option<usize> {
+    let count = precision.checked_add(1)?;
+    if bytes.len() < count {
+        return None;
+    }
+    let exponent = big_digits(mantissa, exponent, &mut bytes[..count])?;
+
+    // Move the fraction digits after the decimal point.
+    let mut cursor = 1;
+    if precision != 0 {
+        if bytes.len() == count {
+            return None;
+        }
+        bytes.copy_within(1..count, 2);
+        bytes[1] = b'.';
+        cursor = count + 1;
+    }
+
+    // Write the exponent, which is at most 21 bytes.
+    let mut buffer = [0u8; 21];
+    buffer[0] = b'e';
+    let mut len = 1;
+    if exponent < 0 {
+        buffer[1] = b'-';
+        len += 1;
+    }
+    len += itoa::itoa_positive(exponent.wrapping_abs() as u64, 10, &mut buffer[len..]);
+    if bytes.len() - cursor < len {
+        return None;
+    }
+    Some(cursor + copy_to_dst(&mut bytes[cursor..], &buffer[..len]))
+}
+
+// EXACT
+
+/// Write the exact decimal value of a float in positional notation.
+///
+/// Writes every digit of the binary float, with `.0` after whole
+/// numbers. NaN is written without a sign.
+pub(crate) fn write_exact<F: Float>(value: F, bytes: &mut [u8]) -> usize {
+    if value.is_nan() {
+        return copy_to_dst(bytes, b"NaN");
+    }
+    let cursor = match value.is_sign_negative() {
+        true => copy_to_dst(bytes, b"-"),
+        false => 0,
+    };
+    let bytes = &mut bytes[cursor..];
+    if value.is_inf() {
+        return cursor + copy_to_dst(bytes, b"inf");
+    }
+
+    // The big integers fit any f32 or f64, so only the buffer may be too small.
+    let mantissa = value.mantissa().as_u64();
+    let words = [mantissa as u32, (mantissa >> 32) as u32];
+    let exponent = BigExponent::Binary(value.exponent());
+    let (count, exponent) =
+        exact_digits(&words, exponent, bytes).expect("Buffer is too small for the exact digits.");
+
+    let len = if exponent < 0 {
+        // Move the digits after `0.` and the leading zeros.
+        let zeros = exponent.wrapping_neg() as usize - 1;
+        let len = count + zeros + 2;
+        assert!(bytes.len() >= len, "Buffer is too small for the exact digits.");
+        bytes.copy_within(..count, zeros + 2);
+        bytes[0] = b'0';
+        bytes[1] = b'.';
+        write_bytes(&mut bytes[2..zeros + 2], b'0');
+        len
+    } else if count > exponent as usize + 1 {
+        // Move the fraction after the decimal point.
+        let integer = exponent as usize + 1;
+        assert!(bytes.len() > count, "Buffer is too small for the exact digits.");
+        bytes.copy_within(integer..count, integer + 1);
+        bytes[integer] = b'.';
+        count + 1
+    } else {
+        // Write the trailing zeros, then `.0`.
+        let integer = exponent as usize + 1;
+        assert!(bytes.len() >= integer + 2, "Buffer is too small for the exact digits.");
+        write_bytes(&mut bytes[count..integer], b'0');
+        integer + copy_to_dst(&mut bytes[integer..], b".0")
+    };
+    cursor + len
+}
+
+// TO LEXICAL
+
+to_lexical!(ftoa, f32);
+to_lexical!(ftoa, f64);
+
+to_lexical_with_options!(ftoa_with_options, ftoa_formatted_size::<f32>, f32);
+to_lexical_with_options!(ftoa_with_options, ftoa_formatted_size::<f64>, f64);
+
+try_to_lexical_with_options!(try_ftoa_with_options, f32);
+try_to_lexical_with_options!(try_ftoa_with_options, f64);
+
+to_lexical_with_info!(ftoa_with_info, f32);
+to_lexical_with_info!(ftoa_with_info, f64);
+
+to_lexical_with_parts!(ftoa_with_parts, f32);
+to_lexical_with_parts!(ftoa_with_parts, f64);
+
+to_lexical_size_hint!(ftoa_size_hint, f32);
+to_lexical_size_hint!(ftoa_size_hint, f64);
+
+to_decimal_digits!(ftoa_digits, f32);
+to_decimal_digits!(ftoa_digits, f64);
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    // Shouldn't need to include atof, should be fine with ToLexical in scope.
+    use super::{write_big_mantissa, write_exact};
+    use crate::config::{EXACT_BUFFER_SIZE, POSITIONAL_BUFFER_SIZE};
+    use crate::error::ErrorCode;
+    use crate::traits::*;
+    use crate::util::*;
+    use approx::assert_relative_eq;
+
+    cfg_if! {
+    if #[cfg(feature = "property_tests")] {
+        use quickcheck::quickcheck;
+        use proptest::{proptest, prop_assert_eq};
+    }} // cfg_if
+
+    // Test data for roundtrips.
+    const F32_DATA: [f32; 31] = [
+        0.,
+        0.1,
+        1.,
+        1.1,
+        12.,
+        12.1,
+        123.,
+        123.1,
+        1234.,
+        1234.1,
+        12345.,
+        12345.1,
+        123456.,
+        123456.1,
+        1234567.,
+        1234567.1,
+        12345678.,
+        12345678.1,
+        123456789.,
+        123456789.1,
+        123456789.12,
+        123456789.123,
+        123456789.1234,
+        123456789.12345,
+        1.2345678912345e8,
+        1.2345e+8,
+        1.2345e+11,
+        1.2345e+38,
+        1.2345e-8,
+        1.2345e-11,
+        1.2345e-38,
+    ];
+    const F64_DATA: [f64; 33] = [
+        0.,
+        0.1,
+        1.,
+        1.1,
+        12.,
+        12.1,
+        123.,
+        123.1,
+        1234.,
+        1234.1,
+        12345.,
+        12345.1,
+        123456.,
+        123456.1,
+        1234567.,
+        1234567.1,
+        12345678.,
+        12345678.1,
+        123456789.,
+        123456789.1,
+        123456789.12,
+        123456789.123,
+        123456789.1234,
+        123456789.12345,
+        1.2345678912345e8,
+        1.2345e+8,
+        1.2345e+11,
+        1.2345e+38,
+        1.2345e+308,
+        1.2345e-8,
+        1.2345e-11,
+        1.2345e-38,
+        1.2345e-299,
+    ];
+
+    #[test]
+    fn special_bytes_test() {
+        let options = WriteFloatOptions::decimal();
+        let mut buffer = new_buffer();
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+
+        let options = WriteFloatOptions::builder()
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .build()
+            .unwrap();
+
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"nan");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"Infinity");
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn f32_binary_test() {
+        let mut buffer = new_buffer();
+        // positive
         let options = WriteFloatOptions::builder().radix(2).trim_floats(true).build().unwrap();
         assert_eq!(as_slice(b"0"), 0.0f32.to_lexical_with_options(&mut buffer, &options));
         assert_eq!(as_slice(b"0"), (-0.0f32).to_lexical_with_options(&mut buffer, &options));
@@ -679,6 +1829,599 @@ mod tests {
         }
     }
 
+    #[test]
+    fn f64_decimal_digits_test() {
+        let digits = 1234.5f64.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"12345");
+        assert_eq!(digits.exponent(), 3);
+
+        let digits = (-2.5e-10f64).to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"25");
+        assert_eq!(digits.exponent(), -10);
+
+        let digits = 0.1f64.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"1");
+        assert_eq!(digits.exponent(), -1);
+
+        let digits = 1e300f64.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"1");
+        assert_eq!(digits.exponent(), 300);
+
+        let digits = 5e-324f64.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"5");
+        assert_eq!(digits.exponent(), -324);
+
+        let digits = 0.0f64.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"0");
+        assert_eq!(digits.exponent(), 0);
+
+        let digits = 1.5f32.to_decimal_digits().unwrap();
+        assert_eq!(digits.digits(), b"15");
+        assert_eq!(digits.exponent(), 0);
+
+        assert_eq!(f64::NAN.to_decimal_digits(), None);
+        assert_eq!(f64::NEG_INFINITY.to_decimal_digits(), None);
+    }
+
+    #[test]
+    fn positional_iter_test() {
+        let check = |value: f64, options: &WriteFloatOptions| {
+            let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+            let positional = WriteFloatOptions::builder()
+                .positional_exponents(Some((-400, 400)))
+                .trim_floats(options.trim_floats())
+                .build()
+                .unwrap();
+            let expected = value.to_lexical_with_options(&mut buffer, &positional);
+            let bytes = crate::write_positional_iter(value, options);
+            assert_eq!(bytes.len(), expected.len());
+            assert!(bytes.eq(expected.iter().cloned()));
+        };
+
+        let options = WriteFloatOptions::new();
+        let trimmed = WriteFloatOptions::builder().trim_floats(true).build().unwrap();
+        for &value in [0.0, -0.0, 1.0, -1.5, 1e-4, 2.5e-10, 1.25e16, 1e300, 5e-324].iter() {
+            check(value, &options);
+            check(value, &trimmed);
+        }
+
+        let bytes = crate::write_positional_iter(-f64::NAN, &options);
+        assert!(bytes.eq(b"NaN".iter().cloned()));
+        let bytes = crate::write_positional_iter(f64::NEG_INFINITY, &options);
+        assert!(bytes.eq(b"-inf".iter().cloned()));
+    }
+
+    #[test]
+    fn write_big_mantissa_test() {
+        let check = |mantissa: &[u32], exponent, precision, expected: &[u8]| {
+            let mut buffer = new_buffer();
+            let len = write_big_mantissa(mantissa, exponent, precision, &mut buffer).unwrap();
+            assert_eq!(&buffer[..len], expected);
+        };
+        check(&[0], BigExponent::Binary(-5), 2, b"0.00e0");
+        check(&[1], BigExponent::Binary(0), 0, b"1e0");
+        check(&[15], BigExponent::Decimal(-4), 0, b"2e-3");
+        check(&[9995], BigExponent::Decimal(1), 2, b"1.00e5");
+        check(&[1], BigExponent::Decimal(-40), 3, b"1.000e-40");
+        check(&[1], BigExponent::Binary(1000), 4, b"1.0715e301");
+
+        // Buffers too small for the digits or the exponent.
+        let mut buffer = [0u8; 6];
+        assert_eq!(write_big_mantissa(&[1], BigExponent::Binary(0), 6, &mut buffer), None);
+        assert_eq!(write_big_mantissa(&[1], BigExponent::Binary(0), 5, &mut buffer), None);
+        assert_eq!(write_big_mantissa(&[1], BigExponent::Decimal(-40), 1, &mut buffer), None);
+        assert_eq!(write_big_mantissa(&[1], BigExponent::Decimal(-4), 1, &mut buffer), Some(6));
+        assert_eq!(&buffer, b"1.0e-4");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn write_big_mantissa_format_test() {
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        for &f in F64_DATA.iter() {
+            let bits = f.to_bits();
+            let biased = (bits >> 52) as i32;
+            let (mantissa, exponent) = match biased {
+                0 => (bits, -1074),
+                _ => (bits & 0xF_FFFF_FFFF_FFFF | 1 << 52, biased - 1075),
+            };
+            let words = [mantissa as u32, (mantissa >> 32) as u32];
+            for &precision in [0, 3, 16, 40].iter() {
+                let exponent = BigExponent::Binary(exponent);
+                let len = write_big_mantissa(&words, exponent, precision, &mut buffer).unwrap();
+                let expected = format!("{:.*e}", precision, f);
+                assert_eq!(&buffer[..len], expected.as_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn write_exact_test() {
+        let mut buffer = [b'\0'; EXACT_BUFFER_SIZE];
+        let mut check = |value: f64, expected: &[u8]| {
+            let len = write_exact(value, &mut buffer);
+            assert_eq!(&buffer[..len], expected);
+        };
+        check(0.0, b"0.0");
+        check(-0.0, b"-0.0");
+        check(1.0, b"1.0");
+        check(-2.5, b"-2.5");
+        check(0.375, b"0.375");
+        check(1e-3, b"0.001000000000000000020816681711721685132943093776702880859375");
+        check(1152921504606846976.0, b"1152921504606846976.0");
+        check(1e23, b"99999999999999991611392.0");
+        check(f64::NAN, b"NaN");
+        check(f64::NEG_INFINITY, b"-inf");
+
+        // The longest exact digits, and round-trips of the exact digits.
+        let len = write_exact(-5e-324f64, &mut buffer);
+        assert_eq!(len, EXACT_BUFFER_SIZE);
+        assert!(buffer[..326].iter().skip(3).all(|&c| c == b'0'));
+        assert!(buffer[326..len].starts_with(b"49406564584124654417656879286822137236505980"));
+        assert!(buffer[..len].ends_with(b"625"));
+        for &f in F64_DATA.iter() {
+            let len = write_exact(f, &mut buffer);
+            assert_eq!(f64::from_lexical(&buffer[..len]), Ok(f));
+        }
+    }
+
+    #[test]
+    fn f64_decimal_digits_roundtrip_test() {
+        let mut buffer = new_buffer();
+        for &f in F64_DATA.iter() {
+            let digits = f.to_decimal_digits().unwrap();
+            let mut string = digits.digits().to_vec();
+            string.push(b'e');
+            let exponent = (digits.exponent() - digits.digits().len() as i32 + 1) as i64;
+            string.extend_from_slice(exponent.to_lexical(&mut buffer));
+            assert_eq!(f64::from_lexical(&string).unwrap(), f.abs());
+        }
+    }
+
+    #[test]
+    fn rust_scientific_test() {
+        let options = WriteFloatOptions::rust_scientific();
+        let mut buffer = new_buffer();
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0e0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"-0e0");
+        assert_eq!(1.0f64.to_lexical_with_options(&mut buffer, &options), b"1e0");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5e0");
+        assert_eq!(1234.5f64.to_lexical_with_options(&mut buffer, &options), b"1.2345e3");
+        assert_eq!((-2.5e-10f64).to_lexical_with_options(&mut buffer, &options), b"-2.5e-10");
+        assert_eq!(1e300f64.to_lexical_with_options(&mut buffer, &options), b"1e300");
+        assert_eq!(5e-324f64.to_lexical_with_options(&mut buffer, &options), b"5e-324");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!((-f64::NAN).to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-inf");
+
+        // Grisu2 does not generate the shortest digits for f32.
+        #[cfg(feature = "ryu")]
+        {
+            assert_eq!(1.1f32.to_lexical_with_options(&mut buffer, &options), b"1.1e0");
+            // Exactly halfway between the two shortest candidates, rounded up.
+            let value = 5.97265625f32;
+            assert_eq!(value.to_lexical_with_options(&mut buffer, &options), b"5.9726563e0");
+        }
+
+        // Scientific notation is only valid for decimal floats.
+        let builder = WriteFloatOptions::builder().scientific(true);
+        assert!(builder.build().is_some());
+        #[cfg(feature = "power_of_two")]
+        assert_eq!(builder.radix(2).build(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "ryu"))]
+    fn rust_scientific_format_test() {
+        let options = WriteFloatOptions::rust_scientific();
+        let mut buffer = new_buffer();
+        for &f in F32_DATA.iter() {
+            let expected = format!("{:e}", -f);
+            assert_eq!(expected.as_bytes(), (-f).to_lexical_with_options(&mut buffer, &options));
+        }
+        for &f in F64_DATA.iter() {
+            let expected = format!("{:e}", f);
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &options));
+        }
+    }
+
+    #[test]
+    fn rust_display_test() {
+        let options = WriteFloatOptions::rust_display();
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"-0");
+        assert_eq!(1.0f64.to_lexical_with_options(&mut buffer, &options), b"1");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(1200.0f64.to_lexical_with_options(&mut buffer, &options), b"1200");
+        assert_eq!(1234.5f64.to_lexical_with_options(&mut buffer, &options), b"1234.5");
+        assert_eq!((-2.5e-4f64).to_lexical_with_options(&mut buffer, &options), b"-0.00025");
+        assert_eq!(1e20f64.to_lexical_with_options(&mut buffer, &options), b"100000000000000000000");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-inf");
+
+        let written = 5e-324f64.to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(written.len(), 326);
+        assert_eq!(&written[..4], b"0.00");
+        assert_eq!(written[325], b'5');
+
+        let written = (-f64::MAX).to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(written.len(), 310);
+        assert_eq!(&written[..5], b"-1797");
+    }
+
+    #[test]
+    #[should_panic]
+    fn rust_display_buffer_test() {
+        let options = WriteFloatOptions::rust_display();
+        let mut buffer = new_buffer();
+        1e300f64.to_lexical_with_options(&mut buffer, &options);
+    }
+
+    #[test]
+    fn rust_debug_test() {
+        let options = WriteFloatOptions::rust_debug();
+        let mut buffer = new_buffer();
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0.0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"-0.0");
+        assert_eq!(1.0f64.to_lexical_with_options(&mut buffer, &options), b"1.0");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(1e-4f64.to_lexical_with_options(&mut buffer, &options), b"0.0001");
+        assert_eq!(1e-5f64.to_lexical_with_options(&mut buffer, &options), b"1e-5");
+        assert_eq!(1e15f64.to_lexical_with_options(&mut buffer, &options), b"1000000000000000.0");
+        assert_eq!(1e16f64.to_lexical_with_options(&mut buffer, &options), b"1e16");
+        assert_eq!(1.25e16f64.to_lexical_with_options(&mut buffer, &options), b"1.25e16");
+        assert_eq!((-f64::NAN).to_lexical_with_options(&mut buffer, &options), b"NaN");
+
+        // Positional notation is only valid for decimal floats, without scientific notation.
+        let builder = WriteFloatOptions::builder().positional_exponents(Some((-4, 15)));
+        assert!(builder.build().is_some());
+        assert_eq!(builder.scientific(true).build(), None);
+        #[cfg(feature = "power_of_two")]
+        assert_eq!(builder.radix(2).build(), None);
+    }
+
+    #[test]
+    fn python_repr_test() {
+        let options = WriteFloatOptions::python_repr();
+        let mut buffer = new_buffer();
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0.0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"-0.0");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(1e-4f64.to_lexical_with_options(&mut buffer, &options), b"0.0001");
+        assert_eq!(1e-5f64.to_lexical_with_options(&mut buffer, &options), b"1e-05");
+        assert_eq!(1.5e-7f64.to_lexical_with_options(&mut buffer, &options), b"1.5e-07");
+        assert_eq!(1e15f64.to_lexical_with_options(&mut buffer, &options), b"1000000000000000.0");
+        assert_eq!(1e16f64.to_lexical_with_options(&mut buffer, &options), b"1e+16");
+        assert_eq!(1.25e16f64.to_lexical_with_options(&mut buffer, &options), b"1.25e+16");
+        assert_eq!(1e300f64.to_lexical_with_options(&mut buffer, &options), b"1e+300");
+        assert_eq!(5e-324f64.to_lexical_with_options(&mut buffer, &options), b"5e-324");
+        assert_eq!((-2.5e-10f64).to_lexical_with_options(&mut buffer, &options), b"-2.5e-10");
+        assert_eq!((-f64::NAN).to_lexical_with_options(&mut buffer, &options), b"nan");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-inf");
+
+        // Exactly halfway between the two shortest candidates: Python
+        // rounds to even, while Rust rounds up.
+        #[cfg(feature = "ryu")]
+        {
+            let value = 0.070453643798828125f64;
+            let written = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(written, b"0.07045364379882812");
+            let debug = WriteFloatOptions::rust_debug();
+            let written = value.to_lexical_with_options(&mut buffer, &debug);
+            assert_eq!(written, b"0.07045364379882813");
+        }
+
+        // The other layout options are only valid with the shortest digits.
+        let builder = WriteFloatOptions::builder();
+        assert_eq!(builder.round_ties_even(true).build(), None);
+        assert_eq!(builder.min_exponent_digits(Some(0)).build(), None);
+        assert_eq!(builder.min_exponent_digits(Some(6)).build(), None);
+        assert_eq!(builder.min_exponent_digits(Some(600)).build(), None);
+        assert!(builder.min_exponent_digits(Some(5)).build().is_some());
+        let builder = builder.scientific(true);
+        assert!(builder.min_exponent_digits(Some(3)).build().is_some());
+        let options = builder.min_exponent_digits(Some(3)).positive_exponent_sign(true).build();
+        let written = 1.5e-7f64.to_lexical_with_options(&mut buffer, &options.unwrap());
+        assert_eq!(written, b"1.5e-007");
+    }
+
+    #[test]
+    #[cfg(feature = "locale")]
+    fn locale_test() {
+        let options = WriteFloatOptions::for_locale("de_DE").unwrap();
+        let mut buffer = new_buffer();
+        assert_eq!(1234.5f64.to_lexical_with_options(&mut buffer, &options), b"1234,5");
+        assert_eq!(1.5e-10f64.to_lexical_with_options(&mut buffer, &options), b"1,5e-10");
+        assert_eq!(3f32.to_lexical_with_options(&mut buffer, &options), b"3,0");
+
+        let options = WriteFloatOptions::for_locale("en_IN").unwrap();
+        assert_eq!(1234.5f64.to_lexical_with_options(&mut buffer, &options), b"1234.5");
+    }
+
+    #[test]
+    fn positive_exponent_sign_test() {
+        let options = WriteFloatOptions::builder().positive_exponent_sign(true).build().unwrap();
+        let mut buffer = new_buffer();
+        assert_eq!(1.5e300f64.to_lexical_with_options(&mut buffer, &options), b"1.5e+300");
+        assert_eq!((-1e25f64).to_lexical_with_options(&mut buffer, &options), b"-1e+25");
+        assert_eq!(2.5e-10f64.to_lexical_with_options(&mut buffer, &options), b"2.5e-10");
+        assert_eq!(1.5f32.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+
+        #[cfg(feature = "power_of_two")]
+        {
+            let options = options.rebuild().radix(2).build().unwrap();
+            let written = 2f64.powi(100).to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(written, b"1.0^+1100100");
+            let written = 2f64.powi(-100).to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(written, b"1.0^-1100100");
+        }
+    }
+
+    #[test]
+    fn min_exponent_digits_test() {
+        let builder = WriteFloatOptions::builder().min_exponent_digits(Some(3));
+        let options = builder.build().unwrap();
+        let mut buffer = new_buffer();
+        assert_eq!(1.5e25f64.to_lexical_with_options(&mut buffer, &options), b"1.5e025");
+        assert_eq!((-2.5e-7f64).to_lexical_with_options(&mut buffer, &options), b"-2.5e-007");
+        assert_eq!(1e300f64.to_lexical_with_options(&mut buffer, &options), b"1e300");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+
+        let options = builder.positive_exponent_sign(true).build().unwrap();
+        assert_eq!(1.5e25f64.to_lexical_with_options(&mut buffer, &options), b"1.5e+025");
+        assert_eq!(1.5e-20f64.to_lexical_with_options(&mut buffer, &options), b"1.5e-020");
+        // Grisu2 does not generate the shortest digits for f32.
+        #[cfg(any(feature = "grisu3", feature = "ryu"))]
+        assert_eq!(1.5e30f32.to_lexical_with_options(&mut buffer, &options), b"1.5e+030");
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn xml_schema_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::xml_schema_double();
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"INF");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-INF");
+
+        // The output must round-trip with the parser preset.
+        let parse_options = ParseFloatOptions::xml_schema_double();
+        for &value in [1e300f64, -2.5e-300, f64::INFINITY, f64::NEG_INFINITY].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(value), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+
+        let options = WriteFloatOptions::xml_schema_decimal();
+        let parse_options = ParseFloatOptions::xml_schema_decimal();
+        let mut buffer = [b'0'; POSITIONAL_BUFFER_SIZE];
+        for &value in [1.5f64, -100.0, 1e20, 1.5e-10, f64::MAX, f64::MIN_POSITIVE].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(value), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn postgresql_test() {
+        let options = WriteFloatOptions::postgresql();
+        let mut buffer = new_buffer();
+        assert_eq!(1.0f64.to_lexical_with_options(&mut buffer, &options), b"1");
+        assert_eq!((-1.5f64).to_lexical_with_options(&mut buffer, &options), b"-1.5");
+        assert_eq!(1e-4f64.to_lexical_with_options(&mut buffer, &options), b"0.0001");
+        assert_eq!(1e-5f64.to_lexical_with_options(&mut buffer, &options), b"1e-05");
+        assert_eq!(1e14f64.to_lexical_with_options(&mut buffer, &options), b"100000000000000");
+        assert_eq!(1e15f64.to_lexical_with_options(&mut buffer, &options), b"1e+15");
+        assert_eq!(1.5e300f64.to_lexical_with_options(&mut buffer, &options), b"1.5e+300");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"Infinity");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-Infinity");
+
+        // The output must round-trip with the parser preset.
+        let parse_options = ParseFloatOptions::postgresql();
+        for &value in [0.1f64, -1e-5, 1e15, f64::MAX, f64::NEG_INFINITY].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(value), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn protobuf_text_test() {
+        let options = WriteFloatOptions::protobuf_text();
+        let mut buffer = new_buffer();
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"nan");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-inf");
+
+        // The output must round-trip with the parser preset.
+        let parse_options = ParseFloatOptions::protobuf_text();
+        for &value in [0.0f64, -1.5, 1e300, 2.5e-300, f64::INFINITY, f64::NEG_INFINITY].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(value), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+    }
+
+    #[test]
+    fn javascript_string_test() {
+        let options = WriteFloatOptions::javascript_string();
+        let mut buffer = new_buffer();
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"0");
+        assert_eq!(100.0f64.to_lexical_with_options(&mut buffer, &options), b"100");
+        assert_eq!((-1.5f64).to_lexical_with_options(&mut buffer, &options), b"-1.5");
+        assert_eq!(1e-6f64.to_lexical_with_options(&mut buffer, &options), b"0.000001");
+        assert_eq!(1e-7f64.to_lexical_with_options(&mut buffer, &options), b"1e-7");
+        assert_eq!(1.23e-18f64.to_lexical_with_options(&mut buffer, &options), b"1.23e-18");
+        assert_eq!(1e20f64.to_lexical_with_options(&mut buffer, &options), b"100000000000000000000");
+        assert_eq!(1e21f64.to_lexical_with_options(&mut buffer, &options), b"1e+21");
+        assert_eq!(2.5e300f64.to_lexical_with_options(&mut buffer, &options), b"2.5e+300");
+        assert_eq!((-f64::NAN).to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"Infinity");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"-Infinity");
+
+        // Unsigned zero is only valid with the shortest digits.
+        let builder = WriteFloatOptions::builder().unsigned_zero(true);
+        assert_eq!(builder.build(), None);
+        assert!(builder.scientific(true).build().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "rounding")]
+    fn directed_rounding_test() {
+        let scientific = |rounding| {
+            let builder = WriteFloatOptions::builder().scientific(true);
+            builder.rounding(rounding).build().unwrap()
+        };
+        let upward = scientific(RoundingKind::TowardPositiveInfinity);
+        let downward = scientific(RoundingKind::TowardNegativeInfinity);
+        let toward_zero = scientific(RoundingKind::TowardZero);
+        let mut buffer = new_buffer();
+
+        // 0.1 is slightly above one tenth, and 0.2 + 0.1 is below 0.3.
+        assert_eq!(0.1f64.to_lexical_with_options(&mut buffer, &upward), b"1.0000000000000001e-1");
+        assert_eq!(0.1f64.to_lexical_with_options(&mut buffer, &downward), b"1e-1");
+        assert_eq!(0.3f64.to_lexical_with_options(&mut buffer, &upward), b"3e-1");
+        assert_eq!(0.3f64.to_lexical_with_options(&mut buffer, &downward), b"2.9999999999999998e-1");
+        assert_eq!((-0.1f64).to_lexical_with_options(&mut buffer, &upward), b"-1e-1");
+        let result = (-0.1f64).to_lexical_with_options(&mut buffer, &downward);
+        assert_eq!(result, b"-1.0000000000000001e-1");
+        assert_eq!((-0.1f64).to_lexical_with_options(&mut buffer, &toward_zero), b"-1e-1");
+        assert_eq!(0.3f64.to_lexical_with_options(&mut buffer, &toward_zero), b"2.9999999999999998e-1");
+
+        // Exact values, zero and special values are unchanged.
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &upward), b"1.5e0");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &downward), b"1.5e0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &upward), b"-0e0");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &downward), b"inf");
+        assert_eq!(f64::MAX.to_lexical_with_options(&mut buffer, &upward), b"1.7976931348623158e308");
+        assert_eq!(5e-324f64.to_lexical_with_options(&mut buffer, &upward), b"5e-324");
+        assert_eq!(5e-324f64.to_lexical_with_options(&mut buffer, &downward), b"4e-324");
+        assert_eq!(0.1f32.to_lexical_with_options(&mut buffer, &downward), b"1e-1");
+        assert_eq!(0.1f32.to_lexical_with_options(&mut buffer, &upward), b"1.00000002e-1");
+
+        // The digits are always within one ULP of the float in the rounding direction.
+        let parse = |bytes: &[u8], rounding| {
+            let options = ParseFloatOptions::builder().rounding(rounding).build().unwrap();
+            f64::from_lexical_with_options(bytes, &options).unwrap()
+        };
+        for &f in F64_DATA.iter() {
+            let bytes = f.to_lexical_with_options(&mut buffer, &upward);
+            assert_eq!(parse(bytes, RoundingKind::TowardZero), f);
+            let bytes = f.to_lexical_with_options(&mut buffer, &downward);
+            assert_eq!(parse(bytes, RoundingKind::TowardPositiveInfinity), f);
+        }
+
+        // Directed rounding is only valid with the shortest digits.
+        let builder = WriteFloatOptions::builder().rounding(RoundingKind::TowardZero);
+        assert_eq!(builder.build(), None);
+        assert!(builder.scientific(true).build().is_some());
+        let builder = WriteFloatOptions::builder().rounding(RoundingKind::NearestTieAwayZero);
+        assert!(builder.build().is_some());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "ryu"))]
+    fn rust_display_format_test() {
+        let display = WriteFloatOptions::rust_display();
+        let debug = WriteFloatOptions::rust_debug();
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        for &f in F32_DATA.iter() {
+            let expected = (-f).to_string();
+            assert_eq!(expected.as_bytes(), (-f).to_lexical_with_options(&mut buffer, &display));
+            let expected = format!("{:?}", f);
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &debug));
+        }
+        for &f in F64_DATA.iter() {
+            let expected = f.to_string();
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &display));
+            let expected = format!("{:?}", -f);
+            assert_eq!(expected.as_bytes(), (-f).to_lexical_with_options(&mut buffer, &debug));
+        }
+    }
+
+    #[test]
+    #[ignore]
+    #[cfg(all(feature = "std", feature = "ryu"))]
+    fn f32_rust_format_full_test() {
+        // Exhaustive differential test against `Display`, `Debug` and `{:e}`.
+        let display = WriteFloatOptions::rust_display();
+        let debug = WriteFloatOptions::rust_debug();
+        let scientific = WriteFloatOptions::rust_scientific();
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        for bits in 0..=u32::max_value() {
+            let f = f32::from_bits(bits);
+            let expected = f.to_string();
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &display));
+            let expected = format!("{:?}", f);
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &debug));
+            let expected = format!("{:e}", f);
+            assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &scientific));
+        }
+    }
+
+    #[test]
+    fn negative_parentheses_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder().negative_parentheses(true).build().unwrap();
+        assert_eq!((-1234.56f64).to_lexical_with_options(&mut buffer, &options), b"(1234.56)");
+        assert_eq!(1234.56f64.to_lexical_with_options(&mut buffer, &options), b"1234.56");
+        // Grisu2 writes a `+` sign for positive exponents.
+        #[cfg(feature = "ryu")]
+        assert_eq!((-1.5e300f64).to_lexical_with_options(&mut buffer, &options), b"(1.5e300)");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"(0.0)");
+        assert_eq!(f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options), b"(inf)");
+        assert_eq!((-1.5f32).to_lexical_with_options(&mut buffer, &options), b"(1.5)");
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let options = WriteFloatOptions::rust_display().rebuild().negative_parentheses(true);
+        let options = options.positive_exponent_sign(true).build().unwrap();
+        assert_eq!((-1e-7f64).to_lexical_with_options(&mut buffer, &options), b"(0.0000001)");
+        let options = WriteFloatOptions::rust_scientific().rebuild().negative_parentheses(true);
+        let options = options.positive_exponent_sign(true).build().unwrap();
+        assert_eq!((-1e20f64).to_lexical_with_options(&mut buffer, &options), b"(1e+20)");
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn exponent_backup_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder()
+            .radix(2)
+            .exponent_backup(Some(b'#'))
+            .build()
+            .unwrap();
+        assert_eq!(options.exponent_backup(), b'#');
+        let written = 2f64.powi(100).to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(written, b"1.0#1100100");
+        let written = 2f64.powi(-100).to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(written, b"1.0#-1100100");
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let parse = ParseFloatOptions::builder()
+            .radix(2)
+            .exponent_backup(Some(b'#'))
+            .build()
+            .unwrap();
+        assert_eq!(Ok(2f64.powi(-100)), f64::from_lexical_with_options(written, &parse));
+
+        // Decimal strings still use the decimal exponent character.
+        let options = options.rebuild().radix(10).build().unwrap();
+        // Grisu2 writes a `+` sign in positive exponents.
+        #[cfg(any(feature = "grisu3", feature = "ryu"))]
+        assert_eq!(1e100f64.to_lexical_with_options(&mut buffer, &options), b"1e100");
+
+        let builder = WriteFloatOptions::builder().radix(2);
+        assert_eq!(builder.build().unwrap().exponent_backup(), b'^');
+        assert!(builder.exponent_backup(Some(b'p')).build().is_none());
+        assert!(builder.exponent_backup(Some(b'.')).build().is_none());
+    }
+
     #[test]
     #[cfg(feature = "radix")]
     fn f64_radix_roundtrip_test() {
@@ -735,6 +2478,562 @@ mod tests {
             let mut buffer = new_buffer();
             prop_assert_eq!(i, f64::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
+
+        #[test]
+        fn f32_decimal_digits_proptest(bits in proptest::num::u32::ANY) {
+            let f = f32::from_bits(bits).abs();
+            if f.is_finite() && f != 0.0 {
+                let mut buffer = new_buffer();
+                let expected = DecimalDigits::from_bytes(f.to_lexical(&mut buffer));
+                prop_assert_eq!(Some(expected), f.to_decimal_digits());
+            }
+        }
+
+        #[test]
+        fn f64_decimal_digits_proptest(bits in proptest::num::u64::ANY) {
+            let f = f64::from_bits(bits).abs();
+            if f.is_finite() && f != 0.0 {
+                let mut buffer = new_buffer();
+                let expected = DecimalDigits::from_bytes(f.to_lexical(&mut buffer));
+                prop_assert_eq!(Some(expected), f.to_decimal_digits());
+            }
+        }
+
+        // Grisu does not always generate the shortest digits, so only compare with Ryu.
+        #[test]
+        #[cfg(feature = "ryu")]
+        fn f32_rust_scientific_proptest(bits in proptest::num::u32::ANY) {
+            let f = f32::from_bits(bits);
+            let options = WriteFloatOptions::rust_scientific();
+            let mut buffer = new_buffer();
+            let expected = format!("{:e}", f);
+            prop_assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &options));
+        }
+
+        #[test]
+        #[cfg(feature = "ryu")]
+        fn f64_rust_scientific_proptest(bits in proptest::num::u64::ANY) {
+            let f = f64::from_bits(bits);
+            let options = WriteFloatOptions::rust_scientific();
+            let mut buffer = new_buffer();
+            let expected = format!("{:e}", f);
+            prop_assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &options));
+        }
+
+        #[test]
+        #[cfg(feature = "ryu")]
+        fn f64_rust_display_proptest(bits in proptest::num::u64::ANY) {
+            let f = f64::from_bits(bits);
+            let display = WriteFloatOptions::rust_display();
+            let debug = WriteFloatOptions::rust_debug();
+            let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+            let expected = f.to_string();
+            prop_assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &display));
+            let expected = format!("{:?}", f);
+            prop_assert_eq!(expected.as_bytes(), f.to_lexical_with_options(&mut buffer, &debug));
+        }
+    }
+
+    macro_rules! try_write {
+        ($value:expr, $buffer:ident, $options:expr) => {
+            $value.try_to_lexical_with_options(&mut $buffer, &$options).map(|bytes| &*bytes)
+        };
+    }
+
+    #[test]
+    fn max_width_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder().max_width(Some(8)).build().unwrap();
+        assert_eq!(try_write!(1.5f64, buffer, options), Ok(&b"1.5"[..]));
+        assert_eq!(try_write!(-1234.5f64, buffer, options), Ok(&b"-1234.5"[..]));
+        assert_eq!(try_write!(1234567.0f64, buffer, options), Ok(&b"1.2346e6"[..]));
+        assert_eq!(try_write!(-1234567.0f64, buffer, options), Ok(&b"-1.235e6"[..]));
+        assert_eq!(try_write!(9.9999e-100f64, buffer, options), Ok(&b"1e-99"[..]));
+        assert_eq!(try_write!(f64::NEG_INFINITY, buffer, options), Ok(&b"-inf"[..]));
+        assert_eq!(try_write!(-0.0f64, buffer, options), Ok(&b"-0.0"[..]));
+
+        // The digits are correctly rounded from the float, with ties to even.
+        let options = WriteFloatOptions::rust_scientific().rebuild().max_width(Some(6));
+        let options = options.build().unwrap();
+        assert_eq!(try_write!(125000000000.0f64, buffer, options), Ok(&b"1.2e11"[..]));
+        assert_eq!(try_write!(135000000000.0f64, buffer, options), Ok(&b"1.4e11"[..]));
+        assert_eq!(try_write!(0.15f64, buffer, options), Ok(&b"1.5e-1"[..]));
+        let options = options.rebuild().max_width(Some(4)).build().unwrap();
+        assert_eq!(try_write!(0.15f64, buffer, options), Ok(&b"1e-1"[..]));
+        assert_eq!(try_write!(0.35f64, buffer, options), Ok(&b"3e-1"[..]));
+        assert_eq!(try_write!(0.45f64, buffer, options), Ok(&b"5e-1"[..]));
+        assert_eq!(try_write!(9.6f32, buffer, options), Ok(&b"1e1"[..]));
+
+        // The sign and exponent options are kept.
+        let options = WriteFloatOptions::rust_display().rebuild().negative_parentheses(true);
+        let options = options.positive_exponent_sign(true).max_width(Some(8)).build().unwrap();
+        assert_eq!(try_write!(-123456.7f64, buffer, options), Ok(&b"(1.2e+5)"[..]));
+        assert_eq!(try_write!(-0.0001f64, buffer, options), Ok(&b"(0.0001)"[..]));
+
+        // Floats that cannot fit are errors.
+        let options = WriteFloatOptions::builder().max_width(Some(3)).build().unwrap();
+        let error = Err((ErrorCode::ExceedsMaxWidth, 3).into());
+        assert_eq!(try_write!(-0.0f64, buffer, options), error);
+        assert_eq!(try_write!(-1e10f64, buffer, options), error);
+        assert_eq!(try_write!(f64::NEG_INFINITY, buffer, options), error);
+        assert_eq!(try_write!(1e10f64, buffer, options), error);
+        assert_eq!(try_write!(1e9f64, buffer, options), Ok(&b"1e9"[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_width_panic_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder().max_width(Some(3)).build().unwrap();
+        (-1e10f64).to_lexical_with_options(&mut buffer, &options);
+    }
+
+    macro_rules! info {
+        ($value:expr, $buffer:ident, $options:expr) => {{
+            let (bytes, exactness) = $value.to_lexical_with_info(&mut $buffer, &$options);
+            (&*bytes, exactness)
+        }};
+    }
+
+    #[test]
+    fn exactness_test() {
+        use Exactness::{Exact, Inexact};
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        let options = WriteFloatOptions::decimal();
+        assert_eq!(info!(0.0f64, buffer, options), (&b"0.0"[..], Exact));
+        assert_eq!(info!(-0.5f64, buffer, options), (&b"-0.5"[..], Exact));
+        assert_eq!(info!(0.1f64, buffer, options), (&b"0.1"[..], Inexact));
+        assert_eq!(info!(-0.1f32, buffer, options).1, Inexact);
+        assert_eq!(info!(f64::NAN, buffer, options), (&b"NaN"[..], Exact));
+        assert_eq!(info!(f64::NEG_INFINITY, buffer, options), (&b"-inf"[..], Exact));
+
+        // Integral floats are exact, unless the shortest digits are rounded.
+        assert_eq!(info!(9007199254740992f64, buffer, options).1, Exact);
+        assert_eq!(info!(1e22f64, buffer, options).1, Exact);
+        assert_eq!(info!(1e23f64, buffer, options).1, Inexact);
+        assert_eq!(info!(f64::MAX, buffer, options).1, Inexact);
+        assert_eq!(info!(5e-324f64, buffer, options).1, Inexact);
+
+        // The layout does not affect exactness.
+        let options = WriteFloatOptions::rust_display().rebuild().negative_parentheses(true);
+        let options = options.build().unwrap();
+        assert_eq!(info!(-1.5e-10f64, buffer, options).1, Inexact);
+        assert_eq!(info!(-0.0009765625f64, buffer, options), (&b"(0.0009765625)"[..], Exact));
+        let options = WriteFloatOptions::rust_scientific().rebuild().positive_exponent_sign(true);
+        let options = options.min_exponent_digits(Some(3)).build().unwrap();
+        assert_eq!(info!(1024f64, buffer, options), (&b"1.024e+003"[..], Exact));
+        assert!(info!(1024f64, buffer, options).1.is_exact());
+    }
+
+    #[test]
+    fn positive_sign_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder().positive_sign(Some(b' ')).build().unwrap();
+        assert_eq!(1234.56f64.to_lexical_with_options(&mut buffer, &options), b" 1234.56");
+        assert_eq!((-1234.56f64).to_lexical_with_options(&mut buffer, &options), b"-1234.56");
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b" 0.0");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"-0.0");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b" inf");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b" NaN");
+        assert_eq!(1.5f32.to_lexical_with_options(&mut buffer, &options), b" 1.5");
+
+        let options = WriteFloatOptions::python_repr().rebuild().positive_sign(Some(b'+'));
+        let options = options.unsigned_zero(true).negative_parentheses(true).build().unwrap();
+        assert_eq!(1e20f64.to_lexical_with_options(&mut buffer, &options), b"+1e+20");
+        assert_eq!((-1e20f64).to_lexical_with_options(&mut buffer, &options), b"(1e+20)");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"+0.0");
+        assert_eq!(info!(0.5f64, buffer, options), (&b"+0.5"[..], Exactness::Exact));
+
+        // The sign counts toward the maximum width.
+        let options = options.rebuild().max_width(Some(6)).build().unwrap();
+        assert_eq!(try_write!(123456.7f64, buffer, options), Ok(&b"+1e+05"[..]));
+        let error = Err((ErrorCode::ExceedsMaxWidth, 6).into());
+        assert_eq!(try_write!(-123456.7f64, buffer, options), error);
+        let options = options.rebuild().positive_exponent_sign(false).max_width(Some(5));
+        let options = options.build().unwrap();
+        assert_eq!(try_write!(123456.7f64, buffer, options), Ok(&b"+1e05"[..]));
+    }
+
+    #[test]
+    fn alternate_form_test() {
+        let mut buffer = new_buffer();
+        let point = WriteFloatOptions::builder().alternate_form(Some(AlternateForm::Point));
+        let zero = WriteFloatOptions::builder().alternate_form(Some(AlternateForm::PointZero));
+        let options = point.build().unwrap();
+        assert_eq!(3.0f64.to_lexical_with_options(&mut buffer, &options), b"3.");
+        assert_eq!((-3.0f64).to_lexical_with_options(&mut buffer, &options), b"-3.");
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5");
+        assert_eq!(0.0f32.to_lexical_with_options(&mut buffer, &options), b"0.");
+        // Grisu writes a `+` sign for positive exponents, or writes large floats positionally.
+        #[cfg(feature = "ryu")]
+        assert_eq!(1e20f64.to_lexical_with_options(&mut buffer, &options), b"1.e20");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+        let options = zero.build().unwrap();
+        assert_eq!(3.0f64.to_lexical_with_options(&mut buffer, &options), b"3.0");
+        #[cfg(feature = "ryu")]
+        assert_eq!(1e20f64.to_lexical_with_options(&mut buffer, &options), b"1.0e20");
+
+        // The alternate form takes precedence over trimmed floats.
+        let options = point.trim_floats(true).build().unwrap();
+        assert_eq!(3.0f64.to_lexical_with_options(&mut buffer, &options), b"3.");
+        assert_eq!((-0.0f64).to_lexical_with_options(&mut buffer, &options), b"0.");
+        let options = zero.trim_floats(true).build().unwrap();
+        assert_eq!(3.0f64.to_lexical_with_options(&mut buffer, &options), b"3.0");
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0.0");
+
+        // Mantissas in scientific notation.
+        let options = WriteFloatOptions::rust_scientific().rebuild().negative_parentheses(true);
+        let options = options.alternate_form(Some(AlternateForm::Point)).build().unwrap();
+        assert_eq!(3e10f64.to_lexical_with_options(&mut buffer, &options), b"3.e10");
+        assert_eq!((-3e-10f64).to_lexical_with_options(&mut buffer, &options), b"(3.e-10)");
+        assert_eq!(1.5e10f64.to_lexical_with_options(&mut buffer, &options), b"1.5e10");
+        assert_eq!(info!(4f64, buffer, options), (&b"4.e0"[..], Exactness::Exact));
+        let options = options.rebuild().alternate_form(Some(AlternateForm::PointZero));
+        let options = options.positive_sign(Some(b'+')).build().unwrap();
+        assert_eq!(3e10f64.to_lexical_with_options(&mut buffer, &options), b"+3.0e10");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"+NaN");
+
+        // The decimal point counts toward the maximum width.
+        let options = WriteFloatOptions::python_repr().rebuild().max_width(Some(6));
+        let options = options.alternate_form(Some(AlternateForm::Point)).build().unwrap();
+        assert_eq!(try_write!(123456.7f64, buffer, options), Ok(&b"1.e+05"[..]));
+        assert_eq!(try_write!(100.0f64, buffer, options), Ok(&b"100."[..]));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn format_flags_test() {
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        let format = NumberFormat::builder().required_exponent_sign(true).build().unwrap();
+        let builder = WriteFloatOptions::builder().format(Some(format));
+        let options = builder.build().unwrap();
+        // Grisu3 writes 1.5e20 in positional notation.
+        #[cfg(not(feature = "grisu3"))]
+        assert_eq!(1.5e20f64.to_lexical_with_options(&mut buffer, &options), b"1.5e+20");
+        assert_eq!(1.5e-20f64.to_lexical_with_options(&mut buffer, &options), b"1.5e-20");
+        let options = builder.scientific(true).build().unwrap();
+        assert_eq!(1.5f64.to_lexical_with_options(&mut buffer, &options), b"1.5e+0");
+
+        // Integral mantissas are written with a fraction digit, even if trimmed.
+        let format = NumberFormat::builder().required_fraction_digits(true).build().unwrap();
+        let builder = WriteFloatOptions::builder().format(Some(format)).trim_floats(true);
+        let options = builder.build().unwrap();
+        assert_eq!(3.0f64.to_lexical_with_options(&mut buffer, &options), b"3.0");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        let options = builder.scientific(true).build().unwrap();
+        assert_eq!(3e10f64.to_lexical_with_options(&mut buffer, &options), b"3.0e10");
+        assert!(builder.alternate_form(Some(AlternateForm::Point)).build().is_none());
+
+        // Positive signs are rejected if the format forbids them.
+        let format = NumberFormat::builder().no_positive_mantissa_sign(true).build().unwrap();
+        let builder = WriteFloatOptions::builder().format(Some(format));
+        assert!(builder.positive_sign(Some(b'+')).build().is_none());
+        assert!(builder.positive_sign(Some(b' ')).build().is_some());
+
+        // Digits are grouped in threes from the decimal point.
+        let format = NumberFormat::builder()
+            .digit_separator(b'_')
+            .integer_internal_digit_separator(true)
+            .fraction_internal_digit_separator(true)
+            .build()
+            .unwrap();
+        let builder = WriteFloatOptions::builder().format(Some(format));
+        let options = builder.build().unwrap();
+        assert_eq!(1234567.125f64.to_lexical_with_options(&mut buffer, &options), b"1_234_567.125");
+        assert_eq!((-1234.5678f64).to_lexical_with_options(&mut buffer, &options), b"-1_234.567_8");
+        assert_eq!(123.0f64.to_lexical_with_options(&mut buffer, &options), b"123.0");
+        // Grisu2 writes a `+` sign in positive exponents.
+        #[cfg(any(feature = "grisu3", feature = "ryu"))]
+        assert_eq!(1.5e300f64.to_lexical_with_options(&mut buffer, &options), b"1.5e300");
+        assert_eq!(f64::INFINITY.to_lexical_with_options(&mut buffer, &options), b"inf");
+        let options = builder.positional_exponents(Some((-10, 10))).build().unwrap();
+        assert_eq!(1e10f64.to_lexical_with_options(&mut buffer, &options), b"10_000_000_000.0");
+        assert_eq!(1.2345e-5f64.to_lexical_with_options(&mut buffer, &options), b"0.000_012_345");
+        let si_prefix = builder.si_prefix(Some(5)).build().unwrap();
+        assert_eq!(1234567.8f64.to_lexical_with_options(&mut buffer, &si_prefix), b"1.234_6M");
+
+        // The output round-trips and is exact with the same format.
+        let parse_options = ParseFloatOptions::builder().format(Some(format)).build().unwrap();
+        for &value in [1234567.125f64, -0.001953125, 1e22, 5e-324, f64::MAX].iter() {
+            let bytes = value.to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(value), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+        assert_eq!(info!(-1024.5f64, buffer, options), (&b"-1_024.5"[..], Exactness::Exact));
+
+        // Only the integer digits are grouped, and separators count toward the width.
+        let format = format.rebuild().fraction_internal_digit_separator(false).build().unwrap();
+        let options = builder.format(Some(format)).max_width(Some(6)).build().unwrap();
+        assert_eq!(try_write!(1234.0f64, buffer, options), Ok(&b"1.23e3"[..]));
+        let options = options.rebuild().max_width(None).build().unwrap();
+        assert_eq!(1234.5678f64.to_lexical_with_options(&mut buffer, &options), b"1_234.5678");
+
+        // The formatted size includes the digit separators.
+        let options = WriteFloatOptions::rust_display().rebuild().format(Some(format));
+        let options = options.build().unwrap();
+        let size = f64::formatted_size(&options);
+        for &value in [-f64::MAX, -5e-324, -1.2345678901234567e22].iter() {
+            value.to_lexical_with_options(&mut buffer[..size], &options);
+        }
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let options = WriteFloatOptions::decimal();
+        assert_eq!(1.5f64.size_hint(&options), 3);
+        assert_eq!((-1.5f32).size_hint(&options), 4);
+        assert_eq!(f64::NAN.size_hint(&options), 3);
+        assert_eq!(f64::NEG_INFINITY.size_hint(&options), 4);
+        assert!(f64::MIN.size_hint(&options) < f64::formatted_size(&options));
+
+        // Positional layouts only require the bytes of the value.
+        let options = WriteFloatOptions::rust_display();
+        assert_eq!(f64::formatted_size(&options), 343);
+        assert_eq!(1e-300f64.size_hint(&options), 302);
+        assert_eq!(2.5f64.size_hint(&options), 3);
+
+        // Floats that cannot be written require no bytes.
+        let options = WriteFloatOptions::builder().max_width(Some(3)).build().unwrap();
+        assert_eq!(1e9f64.size_hint(&options), 3);
+        assert_eq!(1e10f64.size_hint(&options), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn exactness_binary_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::binary();
+        for &value in F64_DATA.iter() {
+            assert!(info!(value, buffer, options).1.is_exact());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn exactness_radix_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder().radix(3).build().unwrap();
+        assert_eq!(info!(1f64, buffer, options), (&b"1.0"[..], Exactness::Exact));
+        assert_eq!(info!(0.5f64, buffer, options).1, Exactness::Inexact);
+    }
+
+    macro_rules! parts {
+        ($value:expr, $buffer:ident, $options:expr) => {{
+            let mut parts = Vec::new();
+            let bytes = $value.to_lexical_with_parts(&mut $buffer, &$options, |part, bytes| {
+                parts.push((part, String::from_utf8(bytes.to_vec()).unwrap()));
+            });
+            let written: Vec<u8> = parts.iter().flat_map(|(_, s)| s.bytes()).collect();
+            assert_eq!(&*bytes, &written[..]);
+            parts
+        }};
+    }
+
+    #[test]
+    fn parts_test() {
+        use FloatPart::*;
+        let part = |part, s: &str| (part, s.to_string());
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::decimal();
+        assert_eq!(parts!(1.5f64, buffer, options), vec![
+            part(Integer, "1"),
+            part(DecimalPoint, "."),
+            part(Fraction, "5")
+        ]);
+        assert_eq!(parts!(-1.5e-10f64, buffer, options), vec![
+            part(Sign, "-"),
+            part(Integer, "1"),
+            part(DecimalPoint, "."),
+            part(Fraction, "5"),
+            part(ExponentMarker, "e"),
+            part(ExponentSign, "-"),
+            part(Exponent, "10")
+        ]);
+        // Grisu writes a `+` sign for positive exponents, or writes large floats positionally.
+        #[cfg(feature = "ryu")]
+        assert_eq!(parts!(1e20f32, buffer, options), vec![
+            part(Integer, "1"),
+            part(ExponentMarker, "e"),
+            part(Exponent, "20")
+        ]);
+        assert_eq!(parts!(f64::NEG_INFINITY, buffer, options), vec![
+            part(Sign, "-"),
+            part(Special, "inf")
+        ]);
+        assert_eq!(parts!(f64::NAN, buffer, options), vec![part(Special, "NaN")]);
+
+        // The parts follow the layout of the options.
+        let options = WriteFloatOptions::builder()
+            .negative_parentheses(true)
+            .positive_sign(Some(b'+'))
+            .positive_exponent_sign(true)
+            .build()
+            .unwrap();
+        assert_eq!(parts!(-2.5e300f64, buffer, options), vec![
+            part(Sign, "("),
+            part(Integer, "2"),
+            part(DecimalPoint, "."),
+            part(Fraction, "5"),
+            part(ExponentMarker, "e"),
+            part(ExponentSign, "+"),
+            part(Exponent, "300"),
+            part(Sign, ")")
+        ]);
+        assert_eq!(parts!(0.25f64, buffer, options), vec![
+            part(Sign, "+"),
+            part(Integer, "0"),
+            part(DecimalPoint, "."),
+            part(Fraction, "25")
+        ]);
+        assert_eq!(parts!(f64::NEG_INFINITY, buffer, options), vec![
+            part(Sign, "("),
+            part(Special, "inf"),
+            part(Sign, ")")
+        ]);
+
+        // The pieces always join to the written bytes.
+        let options = WriteFloatOptions::rust_display();
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        for &value in F64_DATA.iter() {
+            parts!(value, buffer, options);
+        }
+    }
+
+    #[test]
+    fn si_prefix_test() {
+        use FloatPart::*;
+        let mut buffer = new_buffer();
+        let builder = WriteFloatOptions::builder().si_prefix(Some(2));
+        let options = builder.build().unwrap();
+        assert_eq!(1500f64.to_lexical_with_options(&mut buffer, &options), b"1.5k");
+        assert_eq!(2.34e6f64.to_lexical_with_options(&mut buffer, &options), b"2.3M");
+        assert_eq!(4.7e-6f64.to_lexical_with_options(&mut buffer, &options), "4.7µ".as_bytes());
+        assert_eq!(153000f64.to_lexical_with_options(&mut buffer, &options), b"150k");
+        assert_eq!((-0.0123f64).to_lexical_with_options(&mut buffer, &options), b"-12m");
+        assert_eq!(15f64.to_lexical_with_options(&mut buffer, &options), b"15");
+        assert_eq!(1000f32.to_lexical_with_options(&mut buffer, &options), b"1.0k");
+        assert_eq!(0.0f64.to_lexical_with_options(&mut buffer, &options), b"0.0");
+        assert_eq!(f64::NAN.to_lexical_with_options(&mut buffer, &options), b"NaN");
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        // Rounding may carry into the next prefix, and floats outside
+        // the range of the prefixes are written in scientific notation.
+        assert_eq!(999.96f64.to_lexical_with_options(&mut buffer, &options), b"1.0k");
+        assert_eq!(9.996e29f64.to_lexical_with_options(&mut buffer, &options), b"1.0Q");
+        assert_eq!(1.5e30f64.to_lexical_with_options(&mut buffer, &options), b"1.5Q");
+        assert_eq!(1.5e33f64.to_lexical_with_options(&mut buffer, &options), b"1.5e33");
+        assert_eq!(1.5e-33f64.to_lexical_with_options(&mut buffer, &options), b"1.5e-33");
+
+        // The precision, and trimmed floats.
+        let options = builder.si_prefix(Some(4)).build().unwrap();
+        assert_eq!(1234567f64.to_lexical_with_options(&mut buffer, &options), b"1.235M");
+        assert_eq!(1e9f64.to_lexical_with_options(&mut buffer, &options), b"1.000G");
+        let options = builder.si_prefix(Some(4)).trim_floats(true).build().unwrap();
+        assert_eq!(1e9f64.to_lexical_with_options(&mut buffer, &options), b"1G");
+        assert_eq!(1.2e-9f64.to_lexical_with_options(&mut buffer, &options), b"1.2n");
+
+        // The layout options apply to the scaled digits.
+        let options = builder
+            .trim_floats(true)
+            .alternate_form(Some(AlternateForm::Point))
+            .negative_parentheses(true)
+            .positive_sign(Some(b'+'))
+            .positive_exponent_sign(true)
+            .min_exponent_digits(Some(3))
+            .build()
+            .unwrap();
+        assert_eq!((-1e3f64).to_lexical_with_options(&mut buffer, &options), b"(1.k)");
+        assert_eq!(1.5e3f64.to_lexical_with_options(&mut buffer, &options), b"+1.5k");
+        assert_eq!(1e40f64.to_lexical_with_options(&mut buffer, &options), b"+1.e+040");
+
+        // The digits are exact if they equal the float, and the prefix is a part.
+        assert_eq!(info!(1500f64, buffer, builder.build().unwrap()).1, Exactness::Exact);
+        assert_eq!(info!(1234f64, buffer, builder.build().unwrap()).1, Exactness::Inexact);
+        assert_eq!(parts!(-1.5e3f64, buffer, builder.build().unwrap()), vec![
+            (Sign, "-".to_string()),
+            (Integer, "1".to_string()),
+            (DecimalPoint, ".".to_string()),
+            (Fraction, "5".to_string()),
+            (SiPrefix, "k".to_string())
+        ]);
+
+        // Invalid options.
+        assert!(builder.si_prefix(Some(0)).build().is_none());
+        assert!(builder.si_prefix(Some(21)).build().is_none());
+        assert!(builder.si_prefix(Some(600)).build().is_none());
+        assert!(builder.si_prefix(Some(20)).build().is_some());
+        assert!(builder.scientific(true).build().is_none());
+        assert!(builder.positional_exponents(Some((-5, 15))).build().is_none());
+        assert!(builder.max_width(Some(8)).build().is_none());
+        #[cfg(feature = "power_of_two")]
+        assert!(builder.radix(2).build().is_none());
+    }
+
+    #[test]
+    fn formatted_size_test() {
+        let options = WriteFloatOptions::new();
+        assert!(f32::formatted_size(&options) <= f32::FORMATTED_SIZE_DECIMAL);
+        assert!(f64::formatted_size(&options) <= f64::FORMATTED_SIZE_DECIMAL);
+
+        // Grisu2 generates the digits of an f32 as an f64.
+        let options = WriteFloatOptions::rust_scientific();
+        if cfg!(any(feature = "grisu3", feature = "ryu")) {
+            assert_eq!(f32::formatted_size(&options), 15);
+        }
+        assert_eq!(f64::formatted_size(&options), 24);
+
+        let options = WriteFloatOptions::python_repr();
+        assert_eq!(f64::formatted_size(&options), 24);
+
+        let options = WriteFloatOptions::javascript_string();
+        assert_eq!(f64::formatted_size(&options), 25);
+
+        let options = WriteFloatOptions::rust_display();
+        if cfg!(any(feature = "grisu3", feature = "ryu")) {
+            assert_eq!(f32::formatted_size(&options), 56);
+        }
+        assert_eq!(f64::formatted_size(&options), 343);
+
+        let options = WriteFloatOptions::builder().nan_string(b"not a number").build().unwrap();
+        assert!(f64::formatted_size(&options) >= 12);
+    }
+
+    #[test]
+    fn formatted_size_roundtrip_test() {
+        let mut buffer = [b'\0'; POSITIONAL_BUFFER_SIZE];
+        let all_options = [
+            WriteFloatOptions::new(),
+            WriteFloatOptions::rust_scientific(),
+            WriteFloatOptions::rust_display(),
+            WriteFloatOptions::rust_debug(),
+            WriteFloatOptions::python_repr(),
+            WriteFloatOptions::javascript_string(),
+            WriteFloatOptions::builder().negative_parentheses(true).build().unwrap(),
+            WriteFloatOptions::rust_display().rebuild().negative_parentheses(true).build().unwrap(),
+            WriteFloatOptions::builder().si_prefix(Some(20)).build().unwrap(),
+        ];
+        let values = [
+            -f64::MAX,
+            -f64::MIN_POSITIVE,
+            -5e-324,
+            -1.2345678901234567e-300,
+            -1.2345678901234567e300,
+            -1.2345678901234567e22,
+            -0.000012345678901234567,
+            -0.0,
+            f64::NAN,
+            f64::NEG_INFINITY,
+        ];
+        for options in all_options.iter() {
+            let size = f64::formatted_size(options);
+            for &value in values.iter() {
+                value.to_lexical_with_options(&mut buffer[..size], options);
+            }
+            let size = f32::formatted_size(options);
+            for &value in values.iter() {
+                (value as f32).to_lexical_with_options(&mut buffer[..size], options);
+            }
+        }
     }
 
     #[test]
@@ -0,0 +1,178 @@
+//! Directed rounding of the shortest decimal digits of a float.
+//!
+//! The float backends generate the shortest digits that round-trip,
+//! which may be above or below the float. For directed rounding, the
+//! digits must instead be the shortest bound of the float in the
+//! rounding direction: an upper bound in `[v, next(v))`, or a lower
+//! bound in `(prev(v), v]`, so the bound parses back to the float
+//! when rounded in the opposite direction.
+
+use crate::traits::*;
+use crate::util::*;
+use core::cmp;
+
+/// Maximum number of digits in a candidate, including the carry digit.
+const MAX_CANDIDATE_DIGITS: usize = 20;
+
+/// Fixed-point decimal candidate.
+///
+/// Digit `i` is the coefficient of `10^(top - i)`, and the first
+/// digit is always zero in the shortest digits, so carries are
+/// never lost.
+#[derive(Clone, Copy)]
+struct Candidate {
+    digits: [u8; MAX_CANDIDATE_DIGITS],
+    top: i32,
+}
+
+impl Candidate {
+    /// Create a candidate from the shortest digits.
+    #[inline]
+    fn new(decimal: &DecimalDigits) -> Self {
+        let mut digits = [0; MAX_CANDIDATE_DIGITS];
+        for (index, &c) in decimal.digits().iter().enumerate() {
+            digits[index + 1] = c - b'0';
+        }
+        Candidate {
+            digits,
+            top: decimal.exponent() + 1,
+        }
+    }
+
+    /// Zero all digits after the index.
+    #[inline]
+    fn truncate(&mut self, index: usize) {
+        for digit in self.digits[index + 1..].iter_mut() {
+            *digit = 0;
+        }
+    }
+
+    /// Add a unit in the digit at the index.
+    #[inline]
+    fn increment(&mut self, mut index: usize) {
+        while self.digits[index] == 9 {
+            self.digits[index] = 0;
+            index -= 1;
+        }
+        self.digits[index] += 1;
+    }
+
+    /// Subtract a unit in the digit at the index.
+    ///
+    /// The candidate must be a non-zero multiple of the unit.
+    #[inline]
+    fn decrement(&mut self, mut index: usize) {
+        while self.digits[index] == 0 {
+            self.digits[index] = 9;
+            index -= 1;
+        }
+        self.digits[index] -= 1;
+    }
+
+    /// Get the significant digits, and the exponent of the first digit.
+    ///
+    /// Returns `None` if the candidate is zero.
+    #[inline]
+    fn significant(&self, buffer: &mut [u8; MAX_CANDIDATE_DIGITS]) -> Option<(usize, i32)> {
+        let first = self.digits.iter().position(|&d| d != 0)?;
+        let last = self.digits.iter().rposition(|&d| d != 0)?;
+        for (c, &d) in buffer.iter_mut().zip(self.digits[first..=last].iter()) {
+            *c = b'0' + d;
+        }
+        Some((last - first + 1, self.top - first as i32))
+    }
+
+    /// Compare the candidate to the float, parsed with the rounding options.
+    #[inline]
+    fn compare<F>(&self, value: F, options: &ParseFloatOptions) -> cmp::Ordering
+    where
+        F: Float + FromParts<ParseOptions = ParseFloatOptions>,
+    {
+        let mut buffer = [0; MAX_CANDIDATE_DIGITS];
+        let parsed = match self.significant(&mut buffer) {
+            None => F::ZERO,
+            Some((count, exponent)) => {
+                let digits = &buffer[..count];
+                let exponent = exponent - count as i32 + 1;
+                F::from_parts(Sign::Positive, digits, &[], exponent, options)
+                    .expect("Candidate digits are always valid.")
+            },
+        };
+        // Neither value can be NaN.
+        parsed.partial_cmp(&value).unwrap_or(cmp::Ordering::Equal)
+    }
+
+    /// Convert the candidate to decimal digits.
+    #[inline]
+    fn into_decimal(self) -> DecimalDigits {
+        let mut buffer = [0; MAX_CANDIDATE_DIGITS];
+        match self.significant(&mut buffer) {
+            None => DecimalDigits::zero(),
+            Some((count, exponent)) => DecimalDigits::new(&buffer[..count], exponent),
+        }
+    }
+}
+
+/// Get the shortest digits that bound a positive float in a direction.
+///
+/// `kind` must be `Upward` or `Downward`, the internal rounding kinds
+/// for the magnitude of the float.
+pub(crate) fn directed_digits<F>(
+    value: F,
+    decimal: DecimalDigits,
+    kind: RoundingKind,
+) -> DecimalDigits
+where
+    F: Float + FromParts<ParseOptions = ParseFloatOptions>,
+{
+    debug_assert!(value.is_sign_positive() && !value.is_zero(), "Value must be positive.");
+    debug_assert!(kind == RoundingKind::Upward || kind == RoundingKind::Downward);
+
+    // A candidate is a bound if it parses back to the float when rounded
+    // in the opposite direction: `Less` is below the bound interval, and
+    // `Greater` is above it.
+    let is_upward = kind == RoundingKind::Upward;
+    let rounding = match is_upward {
+        true => RoundingKind::TowardZero,
+        false => RoundingKind::TowardPositiveInfinity,
+    };
+    let options = ParseFloatOptions::builder().rounding(rounding).build().unwrap();
+
+    // Try the shortest digits truncated, and rounded in the direction,
+    // to fewer digits.
+    let length = decimal.digits().len();
+    let candidate = Candidate::new(&decimal);
+    for index in 1..length {
+        let mut shorter = candidate;
+        shorter.truncate(index);
+        if is_upward {
+            shorter.increment(index);
+        }
+        if shorter.compare(value, &options) == cmp::Ordering::Equal {
+            return shorter.into_decimal();
+        }
+    }
+
+    // Step the candidate toward the bound by a unit in the last digit,
+    // and add a digit if it steps past the bound.
+    let mut candidate = candidate;
+    let mut index = length;
+    while index < MAX_CANDIDATE_DIGITS {
+        match (candidate.compare(value, &options), is_upward) {
+            (cmp::Ordering::Equal, _) => return candidate.into_decimal(),
+            (cmp::Ordering::Less, true) => candidate.increment(index),
+            (cmp::Ordering::Greater, false) => candidate.decrement(index),
+            (_, true) => {
+                candidate.decrement(index);
+                index += 1;
+            },
+            (_, false) => {
+                candidate.increment(index);
+                index += 1;
+            },
+        }
+    }
+
+    // Unreachable, since 17 digits always bound a float.
+    decimal
+}
@@ -699,6 +699,22 @@ fn fpconv_dtoa(d: f64, dest: &mut [u8], format: NumberFormat) -> usize {
     emit_digits(&mut digits, ndigits, dest, k, format)
 }
 
+// SIZE
+// ----
+
+/// Maximum number of bytes written for a positive f64, for plain
+/// integers with up to 7 trailing zeros and a `.0` suffix.
+pub(crate) const DOUBLE_DECIMAL_SIZE: usize = 26;
+
+/// Maximum number of bytes written for a positive f32, written as an f64.
+pub(crate) const FLOAT_DECIMAL_SIZE: usize = DOUBLE_DECIMAL_SIZE;
+
+/// Maximum number of shortest digits of an f64.
+pub(crate) const DOUBLE_DIGITS: usize = 17;
+
+/// Maximum number of shortest digits of an f32, generated as an f64.
+pub(crate) const FLOAT_DIGITS: usize = DOUBLE_DIGITS;
+
 // DECIMAL
 // -------
 
@@ -721,3 +737,20 @@ pub(crate) fn float_decimal<'a>(f: f32, bytes: &'a mut [u8], format: NumberForma
 pub(crate) fn double_decimal<'a>(d: f64, bytes: &'a mut [u8], format: NumberFormat) -> usize {
     fpconv_dtoa(d, bytes, format)
 }
+
+// DIGITS
+
+/// Generate the shortest decimal digits for a positive, non-zero f32.
+#[inline]
+pub(crate) fn float_digits(f: f32) -> DecimalDigits {
+    double_digits(f.as_f64())
+}
+
+/// Generate the shortest decimal digits for a positive, non-zero f64.
+#[inline]
+pub(crate) fn double_digits(d: f64) -> DecimalDigits {
+    let mut digits: [u8; 18] = [0; 18];
+    let mut k: i32 = 0;
+    let ndigits = grisu2(d, &mut digits, &mut k);
+    DecimalDigits::new(&digits[..ndigits], k + ndigits.as_i32() - 1)
+}
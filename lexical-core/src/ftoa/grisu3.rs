@@ -1,10 +1,25 @@
 //! Wrapper around David Tolnay's dtoa.
 
+use crate::config::F64_FORMATTED_SIZE_DECIMAL;
 use crate::util::*;
 use dtoa;
 
 use super::replace::replace;
 
+// SIZE
+
+/// Maximum number of bytes written for a positive f32.
+pub(crate) const FLOAT_DECIMAL_SIZE: usize = 24;
+
+/// Maximum number of bytes written for a positive f64.
+pub(crate) const DOUBLE_DECIMAL_SIZE: usize = 24;
+
+/// Maximum number of shortest digits of an f32.
+pub(crate) const FLOAT_DIGITS: usize = 9;
+
+/// Maximum number of shortest digits of an f64.
+pub(crate) const DOUBLE_DIGITS: usize = 17;
+
 // F32
 
 /// Wrapper for dtoa.
@@ -30,3 +45,21 @@ pub(crate) fn double_decimal<'a>(d: f64, mut bytes: &'a mut [u8], format: Number
     replace(bytes, count, format);
     count
 }
+
+// DIGITS
+
+/// Generate the shortest decimal digits for a positive, non-zero f32.
+#[inline]
+pub(crate) fn float_digits(f: f32) -> DecimalDigits {
+    let mut bytes = [0u8; F64_FORMATTED_SIZE_DECIMAL];
+    let count = dtoa::write(&mut bytes[..], f).expect("Write to in-memory buffer.");
+    DecimalDigits::from_bytes(&bytes[..count])
+}
+
+/// Generate the shortest decimal digits for a positive, non-zero f64.
+#[inline]
+pub(crate) fn double_digits(d: f64) -> DecimalDigits {
+    let mut bytes = [0u8; F64_FORMATTED_SIZE_DECIMAL];
+    let count = dtoa::write(&mut bytes[..], d).expect("Write to in-memory buffer.");
+    DecimalDigits::from_bytes(&bytes[..count])
+}
@@ -4,6 +4,8 @@
 mod api;
 #[cfg(feature = "power_of_two")]
 mod binary;
+#[cfg(feature = "rounding")]
+mod directed;
 #[cfg(feature = "radix")]
 mod radix;
 
@@ -17,3 +19,6 @@ if #[cfg(feature = "grisu3")] {
 } else {
     mod grisu2;
 }} // cfg_if
+
+// Re-exports
+pub(crate) use self::api::{write_big_mantissa, write_exact};
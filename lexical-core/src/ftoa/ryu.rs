@@ -1,10 +1,56 @@
 //! Wrapper around David Tolnay's ryu.
 
+use crate::config::F64_FORMATTED_SIZE_DECIMAL;
 use crate::util::*;
+#[cfg(not(feature = "safe"))]
 use ryu::raw;
 
 use super::replace::replace;
 
+// SIZE
+
+/// Maximum number of bytes written for a positive f32.
+pub(crate) const FLOAT_DECIMAL_SIZE: usize = 15;
+
+/// Maximum number of bytes written for a positive f64.
+pub(crate) const DOUBLE_DECIMAL_SIZE: usize = 23;
+
+/// Maximum number of shortest digits of an f32.
+pub(crate) const FLOAT_DIGITS: usize = 9;
+
+/// Maximum number of shortest digits of an f64.
+pub(crate) const DOUBLE_DIGITS: usize = 17;
+
+// FORMAT
+
+/// Write the float to the buffer, returning the number of bytes written.
+#[inline(always)]
+#[cfg(not(feature = "safe"))]
+fn format32(f: f32, bytes: &mut [u8]) -> usize {
+    unsafe { raw::format32(f, bytes.as_mut_ptr()) }
+}
+
+/// Write the float to the buffer, returning the number of bytes written.
+#[inline(always)]
+#[cfg(feature = "safe")]
+fn format32(f: f32, bytes: &mut [u8]) -> usize {
+    copy_to_dst(bytes, ryu::Buffer::new().format_finite(f))
+}
+
+/// Write the double to the buffer, returning the number of bytes written.
+#[inline(always)]
+#[cfg(not(feature = "safe"))]
+fn format64(d: f64, bytes: &mut [u8]) -> usize {
+    unsafe { raw::format64(d, bytes.as_mut_ptr()) }
+}
+
+/// Write the double to the buffer, returning the number of bytes written.
+#[inline(always)]
+#[cfg(feature = "safe")]
+fn format64(d: f64, bytes: &mut [u8]) -> usize {
+    copy_to_dst(bytes, ryu::Buffer::new().format_finite(d))
+}
+
 // F32
 
 /// Wrapper for ryu.
@@ -13,7 +59,7 @@ use super::replace::replace;
 /// and non-zero.
 #[inline]
 pub(crate) fn float_decimal<'a>(f: f32, bytes: &'a mut [u8], format: NumberFormat) -> usize {
-    let count = unsafe { raw::format32(f, bytes.as_mut_ptr()) };
+    let count = format32(f, bytes);
     replace(bytes, count, format);
     count
 }
@@ -26,7 +72,25 @@ pub(crate) fn float_decimal<'a>(f: f32, bytes: &'a mut [u8], format: NumberForma
 /// and non-zero.
 #[inline]
 pub(crate) fn double_decimal<'a>(d: f64, bytes: &'a mut [u8], format: NumberFormat) -> usize {
-    let count = unsafe { raw::format64(d, bytes.as_mut_ptr()) };
+    let count = format64(d, bytes);
     replace(bytes, count, format);
     count
 }
+
+// DIGITS
+
+/// Generate the shortest decimal digits for a positive, non-zero f32.
+#[inline]
+pub(crate) fn float_digits(f: f32) -> DecimalDigits {
+    let mut bytes = [0u8; F64_FORMATTED_SIZE_DECIMAL];
+    let count = format32(f, &mut bytes);
+    DecimalDigits::from_bytes(&bytes[..count])
+}
+
+/// Generate the shortest decimal digits for a positive, non-zero f64.
+#[inline]
+pub(crate) fn double_digits(d: f64) -> DecimalDigits {
+    let mut bytes = [0u8; F64_FORMATTED_SIZE_DECIMAL];
+    let count = format64(d, &mut bytes);
+    DecimalDigits::from_bytes(&bytes[..count])
+}
@@ -9,6 +9,8 @@ use crate::traits::*;
 use crate::util::*;
 
 /// Select the back-end.
+#[cfg(feature = "power_of_two")]
+use super::binary::Binary;
 use super::decimal::Decimal;
 #[cfg(feature = "power_of_two")]
 use super::generic::Generic;
@@ -44,7 +46,7 @@ macro_rules! write_backwards {
 pub(crate) trait Itoa: Decimal + UnsignedInteger {}
 
 #[cfg(feature = "power_of_two")]
-pub(crate) trait Itoa: Decimal + Generic + UnsignedInteger {}
+pub(crate) trait Itoa: Binary + Decimal + Generic + UnsignedInteger {}
 
 macro_rules! itoa_impl {
     ($($t:ty)*) => ($(
@@ -52,7 +54,10 @@ macro_rules! itoa_impl {
     )*)
 }
 
-itoa_impl! { u8 u16 u32 u64 u128 usize }
+itoa_impl! { u8 u16 u32 u64 }
+
+#[cfg(feature = "integers")]
+itoa_impl! { u128 usize }
 
 // FORWARD
 
@@ -77,6 +82,8 @@ where
 {
     if radix == 10 {
         value.decimal(buffer)
+    } else if radix.is_power_of_two() {
+        write_backwards!(value, radix, buffer, T, binary)
     } else {
         write_backwards!(value, radix, buffer, T, generic)
     }
@@ -109,10 +116,33 @@ where
     unsigned::<Narrow, Wide>(value, options.radix() as u32, buffer)
 }
 
+/// Get the number of bytes required to write any unsigned integer with the options.
+#[inline]
+fn unsigned_formatted_size<Narrow>(options: &WriteIntegerOptions) -> usize
+where
+    Narrow: UnsignedInteger,
+{
+    digit_count(as_cast(Narrow::MAX), options.radix() as u32)
+}
+
+/// Get the number of bytes required to write the unsigned integer with the options.
+#[inline]
+fn unsigned_size_hint<Narrow>(value: Narrow, options: &WriteIntegerOptions) -> usize
+where
+    Narrow: UnsignedInteger,
+{
+    digit_count(as_cast(value), options.radix() as u32)
+}
+
 macro_rules! unsigned_to_lexical {
     ($narrow:ty, $wide:ty) => {
         to_lexical!(unsigned::<$narrow, $wide>, $narrow);
-        to_lexical_with_options!(unsigned_with_options::<$narrow, $wide>, $narrow);
+        to_lexical_with_options!(
+            unsigned_with_options::<$narrow, $wide>,
+            unsigned_formatted_size::<$narrow>,
+            $narrow
+        );
+        to_lexical_size_hint!(unsigned_size_hint::<$narrow>, $narrow);
     };
 }
 
@@ -120,12 +150,13 @@ unsigned_to_lexical!(u8, u32);
 unsigned_to_lexical!(u16, u32);
 unsigned_to_lexical!(u32, u32);
 unsigned_to_lexical!(u64, u64);
+#[cfg(feature = "integers")]
 unsigned_to_lexical!(u128, u128);
 
-#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+#[cfg(all(feature = "integers", any(target_pointer_width = "16", target_pointer_width = "32")))]
 unsigned_to_lexical!(usize, u32);
 
-#[cfg(target_pointer_width = "64")]
+#[cfg(all(feature = "integers", target_pointer_width = "64"))]
 unsigned_to_lexical!(usize, u64);
 
 // SIGNED
@@ -161,13 +192,52 @@ where
     Wide: SignedInteger,
     Unsigned: Itoa,
 {
-    signed::<Narrow, Wide, Unsigned>(value, options.radix() as u32, buffer)
+    let radix = options.radix() as u32;
+    match options.positive_sign() {
+        Some(sign) if value >= Narrow::ZERO => {
+            unchecked_index_mut!(buffer[0] = sign);
+            let buffer = &mut unchecked_index_mut!(buffer[1..]);
+            signed::<Narrow, Wide, Unsigned>(value, radix, buffer) + 1
+        },
+        _ => signed::<Narrow, Wide, Unsigned>(value, radix, buffer),
+    }
+}
+
+/// Get the number of bytes required to write any signed integer with the options.
+#[inline]
+fn signed_formatted_size<Narrow>(options: &WriteIntegerOptions) -> usize
+where
+    Narrow: SignedInteger,
+{
+    // The sign, and the magnitude of the minimum value.
+    let min: i128 = as_cast(Narrow::MIN);
+    let magnitude = (min as u128).wrapping_neg();
+    digit_count(magnitude, options.radix() as u32) + 1
+}
+
+/// Get the number of bytes required to write the signed integer with the options.
+#[inline]
+fn signed_size_hint<Narrow>(value: Narrow, options: &WriteIntegerOptions) -> usize
+where
+    Narrow: SignedInteger,
+{
+    let value: i128 = as_cast(value);
+    let (magnitude, sign) = match value < 0 {
+        true => ((value as u128).wrapping_neg(), true),
+        false => (value as u128, options.positive_sign().is_some()),
+    };
+    digit_count(magnitude, options.radix() as u32) + sign as usize
 }
 
 macro_rules! signed_to_lexical {
     ($narrow:ty, $wide:ty, $unsigned:ty) => {
         to_lexical!(signed::<$narrow, $wide, $unsigned>, $narrow);
-        to_lexical_with_options!(signed_with_options::<$narrow, $wide, $unsigned>, $narrow);
+        to_lexical_with_options!(
+            signed_with_options::<$narrow, $wide, $unsigned>,
+            signed_formatted_size::<$narrow>,
+            $narrow
+        );
+        to_lexical_size_hint!(signed_size_hint::<$narrow>, $narrow);
     };
 }
 
@@ -175,12 +245,13 @@ signed_to_lexical!(i8, i32, u32);
 signed_to_lexical!(i16, i32, u32);
 signed_to_lexical!(i32, i32, u32);
 signed_to_lexical!(i64, i64, u64);
+#[cfg(feature = "integers")]
 signed_to_lexical!(i128, i128, u128);
 
-#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+#[cfg(all(feature = "integers", any(target_pointer_width = "16", target_pointer_width = "32")))]
 signed_to_lexical!(isize, i32, u32);
 
-#[cfg(target_pointer_width = "64")]
+#[cfg(all(feature = "integers", target_pointer_width = "64"))]
 signed_to_lexical!(isize, i64, u64);
 
 // TESTS
@@ -284,6 +355,44 @@ mod tests {
         assert_eq!(b"18446744073709551615", (-1i64 as u64).to_lexical(&mut buffer));
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn u64_digit_boundaries_test() {
+        // Check every digit count, where the fixed-point reciprocal changes.
+        let mut buffer = new_buffer();
+        let mut power = 1u64;
+        for _ in 0..20 {
+            for &value in [power - 1, power, power + 1, power.wrapping_mul(10) - 1].iter() {
+                let expected = value.to_string();
+                assert_eq!(expected.as_bytes(), value.to_lexical(&mut buffer));
+                if value >> 32 == 0 {
+                    let value = value as u32;
+                    assert_eq!(expected.as_bytes(), value.to_lexical(&mut buffer));
+                }
+            }
+            power = power.wrapping_mul(10);
+        }
+        assert_eq!(b"4294967295", u32::max_value().to_lexical(&mut buffer));
+        assert_eq!(b"12345678987654321", 12345678987654321u64.to_lexical(&mut buffer));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn small_value_test() {
+        // Values below 10^4 use the direct table lookups.
+        let mut buffer = new_buffer();
+        for value in 0u16..=10000 {
+            let expected = value.to_string();
+            assert_eq!(expected.as_bytes(), value.to_lexical(&mut buffer));
+            assert_eq!(expected.as_bytes(), (value as u32).to_lexical(&mut buffer));
+            assert_eq!(expected.as_bytes(), (value as u64).to_lexical(&mut buffer));
+            let value = -(value as i32);
+            let expected = value.to_string();
+            assert_eq!(expected.as_bytes(), value.to_lexical(&mut buffer));
+            assert_eq!(expected.as_bytes(), (value as i64).to_lexical(&mut buffer));
+        }
+    }
+
     #[test]
     fn i64_test() {
         let mut buffer = new_buffer();
@@ -300,6 +409,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn u128_test() {
         let mut buffer = new_buffer();
         assert_eq!(b"0", 0u128.to_lexical(&mut buffer));
@@ -324,6 +434,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn i128_test() {
         let mut buffer = new_buffer();
         assert_eq!(b"0", 0i128.to_lexical(&mut buffer));
@@ -345,7 +456,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "power_of_two")]
+    #[cfg(all(feature = "integers", feature = "power_of_two"))]
     fn binary_test() {
         let mut buffer = new_buffer();
 
@@ -383,6 +494,43 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "integers", feature = "power_of_two", feature = "std"))]
+    fn power_of_two_test() {
+        let mut buffer = new_buffer();
+        let values =
+            [0u128, 1, 7, 8, 31, 32, 1023, 1024, u64::max_value() as u128, u128::max_value()];
+        for &value in values.iter() {
+            let options = WriteIntegerOptions::binary();
+            let expected = format!("{:b}", value);
+            assert_eq!(expected.as_bytes(), value.to_lexical_with_options(&mut buffer, &options));
+            let options = WriteIntegerOptions::builder().radix(8).build().unwrap();
+            let expected = format!("{:o}", value);
+            assert_eq!(expected.as_bytes(), value.to_lexical_with_options(&mut buffer, &options));
+            let options = WriteIntegerOptions::hexadecimal();
+            let expected = format!("{:X}", value);
+            assert_eq!(expected.as_bytes(), value.to_lexical_with_options(&mut buffer, &options));
+            let options = WriteIntegerOptions::hexadecimal();
+            let expected = format!("{:X}", value as u64);
+            assert_eq!(
+                expected.as_bytes(),
+                (value as u64).to_lexical_with_options(&mut buffer, &options)
+            );
+        }
+
+        // Radix 4 and 32 have no formatting trait, so compare to the digits.
+        let options = WriteIntegerOptions::builder().radix(4).build().unwrap();
+        assert_eq!(b"33", 15u32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"100", 16u64.to_lexical_with_options(&mut buffer, &options));
+        let options = WriteIntegerOptions::builder().radix(32).build().unwrap();
+        assert_eq!(b"V", 31u32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"10", 32u128.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(
+            b"-3VVVVVVVVVVVV",
+            (-(1i64 << 62) + 1).to_lexical_with_options(&mut buffer, &options)
+        );
+    }
+
     #[test]
     #[cfg(feature = "radix")]
     fn radix_test() {
@@ -796,6 +944,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn u128_pow2_test() {
         let mut buffer = new_buffer();
         let values: &[u128] = &[
@@ -1188,6 +1337,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "integers")]
     fn u128_pow10_test() {
         let mut buffer = new_buffer();
         let values: &[u128] = &[
@@ -1526,6 +1676,7 @@ mod tests {
 
     #[test]
     #[should_panic]
+    #[cfg(feature = "integers")]
     fn i128_buffer_test() {
         let mut buffer = [b'0'; i128::FORMATTED_SIZE_DECIMAL - 1];
         12i128.to_lexical(&mut buffer);
@@ -1533,6 +1684,7 @@ mod tests {
 
     #[test]
     #[should_panic]
+    #[cfg(feature = "integers")]
     fn isize_buffer_test() {
         let mut buffer = [b'0'; isize::FORMATTED_SIZE_DECIMAL - 1];
         12isize.to_lexical(&mut buffer);
@@ -1552,6 +1704,116 @@ mod tests {
         12i16.to_lexical(&mut buffer);
     }
 
+    #[test]
+    fn formatted_size_test() {
+        let options = WriteIntegerOptions::new();
+        assert_eq!(u8::formatted_size(&options), u8::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(i8::formatted_size(&options), i8::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(u64::formatted_size(&options), u64::FORMATTED_SIZE_DECIMAL);
+        assert_eq!(i64::formatted_size(&options), i64::FORMATTED_SIZE_DECIMAL);
+        #[cfg(feature = "integers")]
+        {
+            assert_eq!(u128::formatted_size(&options), u128::FORMATTED_SIZE_DECIMAL);
+            assert_eq!(i128::formatted_size(&options), i128::FORMATTED_SIZE_DECIMAL);
+
+            let mut buffer = [b'0'; i128::FORMATTED_SIZE_DECIMAL];
+            let buffer = &mut buffer[..i128::formatted_size(&options)];
+            let expected = b"-170141183460469231731687303715884105728";
+            assert_eq!(expected, i128::MIN.to_lexical_with_options(buffer, &options));
+        }
+    }
+
+    #[test]
+    fn forwarded_to_lexical_test() {
+        let mut buffer = [b'0'; crate::BUFFER_SIZE];
+        assert_eq!(b"-12", (&-12i32).to_lexical(&mut buffer));
+        assert_eq!(b"255", crate::write(&255u8, &mut buffer));
+        #[cfg(feature = "floats")]
+        assert_eq!(b"1.5", (&1.5f64).to_lexical(&mut buffer));
+        assert_eq!(b"-5", core::num::Wrapping(-5i64).to_lexical(&mut buffer));
+        assert_eq!(b"0", (core::num::Wrapping(u8::MAX) + core::num::Wrapping(1)).to_lexical(&mut buffer));
+        #[cfg(has_saturating)]
+        {
+            let saturated = core::num::Saturating(100i8) + core::num::Saturating(100);
+            assert_eq!(b"127", saturated.to_lexical(&mut buffer));
+        }
+
+        // Iterators of references serialize without dereferencing.
+        let expected: [&[u8]; 3] = [b"1", b"20", b"300"];
+        for (n, expected) in [1u32, 20, 300].iter().zip(expected.iter()) {
+            assert_eq!(n.to_lexical(&mut buffer), *expected);
+        }
+    }
+
+    #[test]
+    fn positive_sign_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder().positive_sign(Some(b' ')).build().unwrap();
+        assert_eq!(b" 5", 5i32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b" 0", 0i8.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-5", (-5i64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"5", 5u32.to_lexical_with_options(&mut buffer, &options));
+
+        // The sign fits in the formatted size of the minimum value.
+        let options = options.rebuild().positive_sign(Some(b'+')).build().unwrap();
+        let mut buffer = [b'0'; i8::FORMATTED_SIZE_DECIMAL];
+        assert_eq!(b"+127", i8::MAX.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-128", i8::MIN.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[test]
+    fn size_hint_test() {
+        let options = WriteIntegerOptions::decimal();
+        assert_eq!(0u8.size_hint(&options), 1);
+        #[cfg(feature = "integers")]
+        assert_eq!(u128::MAX.size_hint(&options), 39);
+        assert_eq!(99i16.size_hint(&options), 2);
+        assert_eq!((-100i16).size_hint(&options), 4);
+        #[cfg(feature = "integers")]
+        assert_eq!(i128::MIN.size_hint(&options), 40);
+
+        let options = options.rebuild().positive_sign(Some(b'+')).build().unwrap();
+        assert_eq!(0i32.size_hint(&options), 2);
+        assert_eq!(0u32.size_hint(&options), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn power_of_two_size_hint_test() {
+        let options = WriteIntegerOptions::hexadecimal();
+        assert_eq!(255u8.size_hint(&options), 2);
+        assert_eq!(i32::MIN.size_hint(&options), 9);
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn power_of_two_formatted_size_test() {
+        let options = WriteIntegerOptions::binary();
+        assert_eq!(u8::formatted_size(&options), 8);
+        assert_eq!(i8::formatted_size(&options), 9);
+        #[cfg(feature = "integers")]
+        assert_eq!(u128::formatted_size(&options), 128);
+        #[cfg(feature = "integers")]
+        assert_eq!(i128::formatted_size(&options), 129);
+
+        let options = WriteIntegerOptions::hexadecimal();
+        assert_eq!(u32::formatted_size(&options), 8);
+        assert_eq!(i32::formatted_size(&options), 9);
+
+        let mut buffer = [b'0'; 9];
+        assert_eq!(b"-80000000", i32::MIN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"FFFFFFFF", u32::MAX.to_lexical_with_options(&mut buffer[..8], &options));
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "power_of_two")]
+    fn power_of_two_buffer_test() {
+        let options = WriteIntegerOptions::hexadecimal();
+        let mut buffer = [b'0'; 8];
+        1i32.to_lexical_with_options(&mut buffer, &options);
+    }
+
     #[test]
     #[should_panic]
     fn u32_buffer_test() {
@@ -1568,6 +1830,7 @@ mod tests {
 
     #[test]
     #[should_panic]
+    #[cfg(feature = "integers")]
     fn u128_buffer_test() {
         let mut buffer = [b'0'; u128::FORMATTED_SIZE_DECIMAL - 1];
         12i128.to_lexical(&mut buffer);
@@ -1575,6 +1838,7 @@ mod tests {
 
     #[test]
     #[should_panic]
+    #[cfg(feature = "integers")]
     fn usize_buffer_test() {
         let mut buffer = [b'0'; usize::FORMATTED_SIZE_DECIMAL - 1];
         12usize.to_lexical(&mut buffer);
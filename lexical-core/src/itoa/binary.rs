@@ -0,0 +1,94 @@
+//! Fast lexical integer-to-string conversion routines for power-of-two radixes.
+//!
+//! Each digit of a power-of-two radix is a fixed number of bits, so
+//! the digits can be extracted with a mask and a shift rather than
+//! with a division, including for 128-bit integers, which do not
+//! need to be split into 64-bit chunks.
+
+use crate::table::*;
+use crate::traits::*;
+#[cfg(feature = "integers")]
+use crate::util::*;
+
+/// Get lookup table for 2 digit power-of-two radix conversions.
+#[inline]
+fn get_table(radix: u32) -> &'static [u8] {
+    match radix {
+        2 => &DIGIT_TO_BASE2_SQUARED,
+        4 => &DIGIT_TO_BASE4_SQUARED,
+        8 => &DIGIT_TO_BASE8_SQUARED,
+        16 => &DIGIT_TO_BASE16_SQUARED,
+        32 => &DIGIT_TO_BASE32_SQUARED,
+        _ => unreachable!(),
+    }
+}
+
+/// Optimized implementation for power-of-two radixes.
+/// Precondition: `value` must be non-negative and mutable.
+#[inline]
+#[allow(unused_unsafe)]
+fn binary<T>(mut value: T, radix: u32, table: &[u8], buffer: &mut [u8]) -> usize
+where
+    T: UnsignedInteger,
+{
+    // Both forms of unchecked indexing cannot overflow.
+    // The table always has 2*radix^2 elements, so it must be a legal index.
+    // The buffer is ensured to have at least MAX_DIGITS characters, which
+    // is the maximum number of digits an integer of that size may write.
+    let shift = radix.trailing_zeros();
+    let radix: T = as_cast(radix);
+    let mask2 = radix * radix - T::ONE;
+
+    // Decode 2 digits at a time.
+    let mut index = buffer.len();
+    while value > mask2 {
+        let r = (T::TWO * (value & mask2)).as_usize();
+        value >>= 2 * shift;
+
+        // This is always safe, since the table is 2*radix^2, and
+        // r must be in the range [0, 2*radix^2-1).
+        index -= 1;
+        unchecked_index_mut!(buffer[index] = unchecked_index!(table[r + 1]));
+        index -= 1;
+        unchecked_index_mut!(buffer[index] = unchecked_index!(table[r]));
+    }
+
+    // Decode last 2 digits.
+    if value < radix {
+        // This is always safe, since value < radix, so it must be < 32.
+        index -= 1;
+        unchecked_index_mut!(buffer[index] = digit_to_char(value));
+    } else {
+        let r = (T::TWO * value).as_usize();
+        // This is always safe, since the table is 2*radix^2, and the value
+        // must < radix^2, so rem must be in the range [0, 2*radix^2-1).
+        index -= 1;
+        unchecked_index_mut!(buffer[index] = unchecked_index!(table[r + 1]));
+        index -= 1;
+        unchecked_index_mut!(buffer[index] = unchecked_index!(table[r]));
+    }
+    index
+}
+
+pub(crate) trait Binary {
+    // Export integer to string.
+    fn binary(self, radix: u32, buffer: &mut [u8]) -> usize;
+}
+
+// Implement binary for type.
+macro_rules! binary_impl {
+    ($($t:ty)*) => ($(
+        impl Binary for $t {
+            #[inline(always)]
+            fn binary(self, radix: u32, buffer: &mut [u8]) -> usize {
+                let table = get_table(radix);
+                binary(self, radix, table, buffer)
+            }
+        }
+    )*);
+}
+
+binary_impl! { u8 u16 u32 u64 }
+
+#[cfg(feature = "integers")]
+binary_impl! { u128 usize }
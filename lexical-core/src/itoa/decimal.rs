@@ -13,6 +13,14 @@
 //  formatters (see, dtolnay/itoa, or the generic algorithm) for 32-bits
 //  or less, highlighting the advantage of removing for loops with
 //  minimal branches. It also scales well for 64 or more bit integers.
+//
+//  The 32 and 64-bit formatters use the fixed-point algorithm from
+//  jeaiii's "integer to chars", which avoids divisions on the hot path.
+//  The value is scaled by a reciprocal power of 10, so the leading
+//  digit pair is the integral part of a fixed-point number. Every
+//  following pair is extracted by multiplying the fractional part
+//  by 100, and written using the two-digit lookup table. The
+//  benchmarks below predate this algorithm.
 
 //  The following benchmarks were run on an "Intel(R) Core(TM) i7-6560U
 //  CPU @ 2.20GHz" CPU, on Fedora 28, Linux kernel version 4.18.16-200
@@ -125,6 +133,7 @@
 
 use crate::table::*;
 use crate::traits::*;
+#[cfg(feature = "integers")]
 use crate::util::*;
 
 // Lookup table for optimized base10 itoa.
@@ -138,6 +147,7 @@ const TABLE: &[u8] = &DIGIT_TO_BASE10_SQUARED;
 // tricks so they may not be very legible.
 
 // Calculate the number of leading 0s.
+#[cfg(feature = "integers")]
 macro_rules! ctlz {
     ($value:ident) => {
         $value.leading_zeros().as_usize()
@@ -145,6 +155,7 @@ macro_rules! ctlz {
 }
 
 // Calculate the offset where the digits were first written.
+#[cfg(feature = "integers")]
 macro_rules! calculate_offset {
     ($value:ident, $digits:ident, $max_digits:expr, $size:expr) => {{
         // Get the log2 of the value to estimate the log10 quickly.
@@ -251,74 +262,10 @@ fn write_5(value: u32, buffer: &mut [u8]) {
     unchecked_index_mut!(buffer[0] = unchecked_index!(TABLE[i_2 + 1]));
 }
 
-/// Write 10 digits to buffer.
-#[inline]
-#[allow(unused_unsafe)]
-fn write_10(value: u32, buffer: &mut [u8]) {
-    let t0 = value / 100000000;
-    let v_0 = value.wrapping_sub(t0.wrapping_mul(100000000));
-    let v_1 = v_0 / 100;
-    let v_2 = v_1 / 100;
-    let v_3 = v_2 / 100;
-    let v_4 = t0;
-    let i_0 = sequential_index!(v_0, v_1);
-    let i_1 = sequential_index!(v_1, v_2);
-    let i_2 = sequential_index!(v_2, v_3);
-    let i_3 = last_index!(v_3);
-    let i_4 = last_index!(v_4);
-    unchecked_index_mut!(buffer[9] = unchecked_index!(TABLE[i_0 + 1]));
-    unchecked_index_mut!(buffer[8] = unchecked_index!(TABLE[i_0 + 0]));
-    unchecked_index_mut!(buffer[7] = unchecked_index!(TABLE[i_1 + 1]));
-    unchecked_index_mut!(buffer[6] = unchecked_index!(TABLE[i_1 + 0]));
-    unchecked_index_mut!(buffer[5] = unchecked_index!(TABLE[i_2 + 1]));
-    unchecked_index_mut!(buffer[4] = unchecked_index!(TABLE[i_2 + 0]));
-    unchecked_index_mut!(buffer[3] = unchecked_index!(TABLE[i_3 + 1]));
-    unchecked_index_mut!(buffer[2] = unchecked_index!(TABLE[i_3 + 0]));
-    unchecked_index_mut!(buffer[1] = unchecked_index!(TABLE[i_4 + 1]));
-    unchecked_index_mut!(buffer[0] = unchecked_index!(TABLE[i_4 + 0]));
-}
-
-/// Write 15 digits to buffer.
-#[inline]
-#[allow(unused_unsafe)]
-fn write_15(value: u64, buffer: &mut [u8]) {
-    let t_0 = (value / 100000000).as_u32();
-    let v_0 = value.as_u32().wrapping_sub(t_0.wrapping_mul(100000000));
-    let v_1 = v_0 / 100;
-    let v_2 = v_1 / 100;
-    let v_3 = v_2 / 100;
-    let v_4 = t_0;
-    let v_5 = v_4 / 100;
-    let v_6 = v_5 / 100;
-    let v_7 = v_6 / 100;
-    let i_0 = sequential_index!(v_0, v_1);
-    let i_1 = sequential_index!(v_1, v_2);
-    let i_2 = sequential_index!(v_2, v_3);
-    let i_3 = last_index!(v_3);
-    let i_4 = sequential_index!(v_4, v_5);
-    let i_5 = sequential_index!(v_5, v_6);
-    let i_6 = sequential_index!(v_6, v_7);
-    let i_7 = last_index!(v_7);
-    unchecked_index_mut!(buffer[14] = unchecked_index!(TABLE[i_0 + 1]));
-    unchecked_index_mut!(buffer[13] = unchecked_index!(TABLE[i_0 + 0]));
-    unchecked_index_mut!(buffer[12] = unchecked_index!(TABLE[i_1 + 1]));
-    unchecked_index_mut!(buffer[11] = unchecked_index!(TABLE[i_1 + 0]));
-    unchecked_index_mut!(buffer[10] = unchecked_index!(TABLE[i_2 + 1]));
-    unchecked_index_mut!(buffer[9] = unchecked_index!(TABLE[i_2 + 0]));
-    unchecked_index_mut!(buffer[8] = unchecked_index!(TABLE[i_3 + 1]));
-    unchecked_index_mut!(buffer[7] = unchecked_index!(TABLE[i_3 + 0]));
-    unchecked_index_mut!(buffer[6] = unchecked_index!(TABLE[i_4 + 1]));
-    unchecked_index_mut!(buffer[5] = unchecked_index!(TABLE[i_4 + 0]));
-    unchecked_index_mut!(buffer[4] = unchecked_index!(TABLE[i_5 + 1]));
-    unchecked_index_mut!(buffer[3] = unchecked_index!(TABLE[i_5 + 0]));
-    unchecked_index_mut!(buffer[2] = unchecked_index!(TABLE[i_6 + 1]));
-    unchecked_index_mut!(buffer[1] = unchecked_index!(TABLE[i_6 + 0]));
-    unchecked_index_mut!(buffer[0] = unchecked_index!(TABLE[i_7 + 1]));
-}
-
 /// Write 19 digits to buffer (used internally for the u128 writers).
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn write_19(value: u64, buffer: &mut [u8]) {
     let t_0 = (value / 100000000).as_u32();
     let t_1 = (value / 10000000000000000).as_u32();
@@ -363,57 +310,10 @@ fn write_19(value: u64, buffer: &mut [u8]) {
     unchecked_index_mut!(buffer[0] = unchecked_index!(TABLE[i_9 + 1]));
 }
 
-/// Write 20 digits to buffer.
-#[inline]
-#[allow(unused_unsafe)]
-fn write_20(value: u64, buffer: &mut [u8]) {
-    let t_0 = (value / 100000000).as_u32();
-    let t_1 = (value / 10000000000000000).as_u32();
-    let v_0 = value.as_u32().wrapping_sub(t_0.wrapping_mul(100000000));
-    let v_1 = v_0 / 100;
-    let v_2 = v_1 / 100;
-    let v_3 = v_2 / 100;
-    let v_4 = t_0.wrapping_sub(t_1.wrapping_mul(100000000));
-    let v_5 = v_4 / 100;
-    let v_6 = v_5 / 100;
-    let v_7 = v_6 / 100;
-    let v_8 = t_1;
-    let v_9 = v_8 / 100;
-    let i_0 = sequential_index!(v_0, v_1);
-    let i_1 = sequential_index!(v_1, v_2);
-    let i_2 = sequential_index!(v_2, v_3);
-    let i_3 = last_index!(v_3);
-    let i_4 = sequential_index!(v_4, v_5);
-    let i_5 = sequential_index!(v_5, v_6);
-    let i_6 = sequential_index!(v_6, v_7);
-    let i_7 = last_index!(v_7);
-    let i_8 = sequential_index!(v_8, v_9);
-    let i_9 = last_index!(v_9);
-    unchecked_index_mut!(buffer[19] = unchecked_index!(TABLE[i_0 + 1]));
-    unchecked_index_mut!(buffer[18] = unchecked_index!(TABLE[i_0 + 0]));
-    unchecked_index_mut!(buffer[17] = unchecked_index!(TABLE[i_1 + 1]));
-    unchecked_index_mut!(buffer[16] = unchecked_index!(TABLE[i_1 + 0]));
-    unchecked_index_mut!(buffer[15] = unchecked_index!(TABLE[i_2 + 1]));
-    unchecked_index_mut!(buffer[14] = unchecked_index!(TABLE[i_2 + 0]));
-    unchecked_index_mut!(buffer[13] = unchecked_index!(TABLE[i_3 + 1]));
-    unchecked_index_mut!(buffer[12] = unchecked_index!(TABLE[i_3 + 0]));
-    unchecked_index_mut!(buffer[11] = unchecked_index!(TABLE[i_4 + 1]));
-    unchecked_index_mut!(buffer[10] = unchecked_index!(TABLE[i_4 + 0]));
-    unchecked_index_mut!(buffer[9] = unchecked_index!(TABLE[i_5 + 1]));
-    unchecked_index_mut!(buffer[8] = unchecked_index!(TABLE[i_5 + 0]));
-    unchecked_index_mut!(buffer[7] = unchecked_index!(TABLE[i_6 + 1]));
-    unchecked_index_mut!(buffer[6] = unchecked_index!(TABLE[i_6 + 0]));
-    unchecked_index_mut!(buffer[5] = unchecked_index!(TABLE[i_7 + 1]));
-    unchecked_index_mut!(buffer[4] = unchecked_index!(TABLE[i_7 + 0]));
-    unchecked_index_mut!(buffer[3] = unchecked_index!(TABLE[i_8 + 1]));
-    unchecked_index_mut!(buffer[2] = unchecked_index!(TABLE[i_8 + 0]));
-    unchecked_index_mut!(buffer[1] = unchecked_index!(TABLE[i_9 + 1]));
-    unchecked_index_mut!(buffer[0] = unchecked_index!(TABLE[i_9 + 0]));
-}
-
 /// Write 25 digits to buffer.
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn write_25(value: u128, buffer: &mut [u8]) {
     // Split value into high 6 and low 19.
     let (high, low) = u128_divrem_1e19(value);
@@ -440,6 +340,7 @@ fn write_25(value: u128, buffer: &mut [u8]) {
 /// Write 29 digits to buffer.
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn write_29(value: u128, buffer: &mut [u8]) {
     // Split value into high 10 and low 19.
     let (high, low) = u128_divrem_1e19(value);
@@ -475,6 +376,7 @@ fn write_29(value: u128, buffer: &mut [u8]) {
 /// Write 34 digits to buffer.
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn write_34(value: u128, buffer: &mut [u8]) {
     // Split value into high 15 and low 19.
     let (high, low) = u128_divrem_1e19(value);
@@ -521,6 +423,7 @@ fn write_34(value: u128, buffer: &mut [u8]) {
 /// Write 39 digits to buffer.
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn write_39(value: u128, buffer: &mut [u8]) {
     // Split value into high 20 and low 19.
     let (high, low) = u128_divrem_1e19(value);
@@ -538,6 +441,68 @@ fn write_39(value: u128, buffer: &mut [u8]) {
     unchecked_index_mut!(buffer[0] = digit_to_char(high));
 }
 
+// WRITE FIXED-POINT
+// -----------------
+
+// Write digit pairs from a fixed-point representation of the value,
+// scaled so the integral part is the leading digit pair. These
+// reciprocals have been exhaustively verified for every value in range.
+
+/// Number of fractional bits in the fixed-point representation.
+const FRACTION_BITS: u32 = 57;
+
+/// Mask for the fractional bits in the fixed-point representation.
+const FRACTION_MASK: u64 = (1 << FRACTION_BITS) - 1;
+
+/// Fixed-point reciprocal of 10^4, `ceil(2^57 / 10^4)`, for values below 10^6.
+const RECIPROCAL_1E4: u64 = 14411518807586;
+
+/// Fixed-point reciprocal of 10^6, `ceil(2^57 / 10^6)`, for values below 10^8.
+const RECIPROCAL_1E6: u64 = 144115188076;
+
+/// Fixed-point reciprocal of 10^8, `ceil(2^57 / 10^8)`, for values below 2^32.
+const RECIPROCAL_1E8: u64 = 1441151881;
+
+/// Write the digit pairs following the leading pair.
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn write_fraction_pairs(mut fixed: u64, pairs: usize, buffer: &mut [u8]) {
+    for index in 0..pairs {
+        fixed = (fixed & FRACTION_MASK) * 100;
+        let value = (fixed >> FRACTION_BITS).as_u32();
+        write_2(value, &mut unchecked_index_mut!(buffer[2 * index..]));
+    }
+}
+
+/// Write a value with `pairs` digit pairs, without leading zeros.
+///
+/// The value must have `2 * pairs - 1` or `2 * pairs` digits, and
+/// `reciprocal` must be the fixed-point reciprocal of `10^(2 * pairs - 2)`.
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn write_pairs(value: u32, reciprocal: u64, pairs: usize, buffer: &mut [u8]) -> usize {
+    let fixed = value.as_u64() * reciprocal;
+    let leading = (fixed >> FRACTION_BITS).as_u32();
+    let count = if leading < 10 {
+        write_1(leading, buffer);
+        1
+    } else {
+        write_2(leading, buffer);
+        2
+    };
+    write_fraction_pairs(fixed, pairs - 1, &mut unchecked_index_mut!(buffer[count..]));
+    count + 2 * (pairs - 1)
+}
+
+/// Write exactly 8 digits, including leading zeros (value < 10^8).
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn write_8(value: u32, buffer: &mut [u8]) {
+    let fixed = value.as_u64() * RECIPROCAL_1E6;
+    write_2((fixed >> FRACTION_BITS).as_u32(), buffer);
+    write_fraction_pairs(fixed, 3, &mut unchecked_index_mut!(buffer[2..]));
+}
+
 // WRITE RAMGE
 // -----------
 
@@ -559,62 +524,44 @@ fn write_1_3(value: u32, buffer: &mut [u8]) -> usize {
     }
 }
 
-/// Write 1-3 digits (from a u16 value).
-#[inline]
-fn write_1_5(value: u32, buffer: &mut [u8]) -> usize {
-    if value < 10 {
-        write_1(value, buffer);
-        1
-    } else if value < 100 {
-        write_2(value, buffer);
-        2
+/// Write 1-4 digits (from a value less than 10^4).
+///
+/// Fast path for the small values that dominate most workloads, such
+/// as indexes, status codes and counters, which writes the digits with
+/// direct table lookups rather than the fixed-point digit pairs.
+#[inline(always)]
+fn write_1_4(value: u32, buffer: &mut [u8]) -> usize {
+    if value < 100 {
+        if value < 10 {
+            write_1(value, buffer);
+            1
+        } else {
+            write_2(value, buffer);
+            2
+        }
     } else if value < 1000 {
         write_3(value, buffer);
         3
-    } else if value < 10000 {
+    } else {
         write_4(value, buffer);
         4
-    } else {
-        write_5(value, buffer);
-        5
     }
 }
 
-/// Write 5-10 digits (from a u32 value).
+/// Write 1-5 digits (from a u16 value).
 #[inline]
-fn write_5_10(value: u32, buffer: &mut [u8]) -> usize {
-    // Use a temporary buffer so we only need a single code path.
-    let mut tmp_buf: [u8; 16] = [b'0'; 16];
-    let digits = &mut tmp_buf[..10];
-    write_10(value, digits);
-    let offset = calculate_offset!(value, digits, 10, 32);
-    copy_to_dst(buffer, &unchecked_index!(digits[offset..]))
-}
-
-/// Write 10-15 digits (from a u64 value).
-#[inline]
-fn write_10_15(value: u64, buffer: &mut [u8]) -> usize {
-    // Use a temporary buffer so we only need a single code path.
-    let mut tmp_buf: [u8; 32] = [b'0'; 32];
-    let digits = &mut tmp_buf[..15];
-    write_15(value, digits);
-    let offset = calculate_offset!(value, digits, 15, 64);
-    copy_to_dst(buffer, &unchecked_index!(digits[offset..]))
-}
-
-/// Write 15-20 digits (from a u64 value).
-#[inline]
-fn write_15_20(value: u64, buffer: &mut [u8]) -> usize {
-    // Use a temporary buffer so we only need a single code path.
-    let mut tmp_buf: [u8; 32] = [b'0'; 32];
-    let digits = &mut tmp_buf[..20];
-    write_20(value, digits);
-    let offset = calculate_offset!(value, digits, 20, 64);
-    copy_to_dst(buffer, &unchecked_index!(digits[offset..]))
+fn write_1_5(value: u32, buffer: &mut [u8]) -> usize {
+    if value < 10000 {
+        write_1_4(value, buffer)
+    } else {
+        write_5(value, buffer);
+        5
+    }
 }
 
 /// Write 20-25 digits (from a u64 value).
 #[inline]
+#[cfg(feature = "integers")]
 fn write_20_25(value: u128, buffer: &mut [u8]) -> usize {
     // Use a temporary buffer so we only need a single code path.
     let mut tmp_buf: [u8; 64] = [b'0'; 64];
@@ -626,6 +573,7 @@ fn write_20_25(value: u128, buffer: &mut [u8]) -> usize {
 
 /// Write 25-29 digits (from a u64 value).
 #[inline]
+#[cfg(feature = "integers")]
 fn write_25_29(value: u128, buffer: &mut [u8]) -> usize {
     // Use a temporary buffer so we only need a single code path.
     let mut tmp_buf: [u8; 64] = [b'0'; 64];
@@ -637,6 +585,7 @@ fn write_25_29(value: u128, buffer: &mut [u8]) -> usize {
 
 /// Write 29-34 digits (from a u64 value).
 #[inline]
+#[cfg(feature = "integers")]
 fn write_29_34(value: u128, buffer: &mut [u8]) -> usize {
     // Use a temporary buffer so we only need a single code path.
     let mut tmp_buf: [u8; 64] = [b'0'; 64];
@@ -648,6 +597,7 @@ fn write_29_34(value: u128, buffer: &mut [u8]) -> usize {
 
 /// Write 34-39 digits (from a u64 value).
 #[inline]
+#[cfg(feature = "integers")]
 fn write_34_39(value: u128, buffer: &mut [u8]) -> usize {
     // Use a temporary buffer so we only need a single code path.
     let mut tmp_buf: [u8; 64] = [b'0'; 64];
@@ -680,48 +630,59 @@ fn u16toa(value: u16, buffer: &mut [u8]) -> usize {
 /// Internal integer formatter for u32.
 #[inline]
 fn u32toa(value: u32, buffer: &mut [u8]) -> usize {
-    if value >> 16 == 0 {
-        // [0, 2^16 - 1]
-        write_1_5(value, buffer)
+    if value < 10000 {
+        // [0, 10^4 - 1]
+        write_1_4(value, buffer)
+    } else if value < 1000000 {
+        // [10^4, 10^6 - 1]
+        write_pairs(value, RECIPROCAL_1E4, 3, buffer)
+    } else if value < 100000000 {
+        // [10^6, 10^8 - 1]
+        write_pairs(value, RECIPROCAL_1E6, 4, buffer)
     } else {
-        // [2^16, 2^32 - 1]
-        write_5_10(value, buffer)
+        // [10^8, 2^32 - 1]
+        write_pairs(value, RECIPROCAL_1E8, 5, buffer)
     }
 }
 
 /// Internal integer formatter for u64.
 #[inline]
+#[allow(unused_unsafe)]
 fn u64toa(value: u64, buffer: &mut [u8]) -> usize {
-    if value >> 16 == 0 {
-        // [0, 2^16 - 1]
-        write_1_5(value.as_u32(), buffer)
+    if value < 10000 {
+        // [0, 10^4 - 1]
+        write_1_4(value.as_u32(), buffer)
     } else if value >> 32 == 0 {
-        // [2^16, 2^32 - 1]
-        write_5_10(value.as_u32(), buffer)
-    } else if value >> 48 == 0 {
-        // [2^32, 2^48 - 1]
-        write_10_15(value, buffer)
+        // [0, 2^32 - 1]
+        u32toa(value.as_u32(), buffer)
+    } else if value < 10000000000000000 {
+        // [2^32, 10^16 - 1]
+        // Division by a constant compiles to a multiplication.
+        let high = (value / 100000000).as_u32();
+        let low = value.as_u32().wrapping_sub(high.wrapping_mul(100000000));
+        let count = u32toa(high, buffer);
+        write_8(low, &mut unchecked_index_mut!(buffer[count..]));
+        count + 8
     } else {
-        // [2^48, 2^64 - 1]
-        write_15_20(value, buffer)
+        // [10^16, 2^64 - 1]
+        let high = (value / 10000000000000000).as_u32();
+        let rest = value - high.as_u64() * 10000000000000000;
+        let mid = (rest / 100000000).as_u32();
+        let low = rest.as_u32().wrapping_sub(mid.wrapping_mul(100000000));
+        let count = u32toa(high, buffer);
+        write_8(mid, &mut unchecked_index_mut!(buffer[count..]));
+        write_8(low, &mut unchecked_index_mut!(buffer[count + 8..]));
+        count + 16
     }
 }
 
 /// Internal integer formatter for u128.
 #[inline]
+#[cfg(feature = "integers")]
 fn u128toa(value: u128, buffer: &mut [u8]) -> usize {
-    if value >> 16 == 0 {
-        // [0, 2^16 - 1]
-        write_1_5(value.as_u32(), buffer)
-    } else if value >> 32 == 0 {
-        // [2^16, 2^32 - 1]
-        write_5_10(value.as_u32(), buffer)
-    } else if value >> 48 == 0 {
-        // [2^32, 2^48 - 1]
-        write_10_15(value.as_u64(), buffer)
-    } else if value >> 64 == 0 {
-        // [2^48, 2^64 - 1]
-        write_15_20(value.as_u64(), buffer)
+    if value >> 64 == 0 {
+        // [0, 2^64 - 1]
+        u64toa(value.as_u64(), buffer)
     } else if value >> 80 == 0 {
         // [2^64, 2^80 - 1]
         write_20_25(value, buffer)
@@ -738,17 +699,17 @@ fn u128toa(value: u128, buffer: &mut [u8]) -> usize {
 }
 
 cfg_if! {
-if #[cfg(target_pointer_width = "16")] {
+if #[cfg(all(feature = "integers", target_pointer_width = "16"))] {
     #[inline]
     fn usizetoa(value: usize, buffer: &mut [u8]) -> usize {
         u16toa(value.as_u16(), buffer)
     }
-} else if #[cfg(target_pointer_width = "32")] {
+} else if #[cfg(all(feature = "integers", target_pointer_width = "32"))] {
     #[inline]
     fn usizetoa(value: usize, buffer: &mut [u8]) -> usize {
         u32toa(value.as_u32(), buffer)
     }
-} else if #[cfg(target_pointer_width = "64")] {
+} else if #[cfg(all(feature = "integers", target_pointer_width = "64"))] {
     #[inline]
     fn usizetoa(value: usize, buffer: &mut [u8]) -> usize {
         u64toa(value.as_u64(), buffer)
@@ -779,5 +740,7 @@ decimal_impl!(u8, u8toa);
 decimal_impl!(u16, u16toa);
 decimal_impl!(u32, u32toa);
 decimal_impl!(u64, u64toa);
+#[cfg(feature = "integers")]
 decimal_impl!(u128, u128toa);
+#[cfg(feature = "integers")]
 decimal_impl!(usize, usizetoa);
@@ -72,6 +72,7 @@
 
 use crate::table::*;
 use crate::traits::*;
+#[cfg(feature = "integers")]
 use crate::util::*;
 
 // Generic itoa algorithm.
@@ -235,6 +236,7 @@ where
 ///  Buffer must be 0-initialized.
 #[inline]
 #[allow(unused_unsafe)]
+#[cfg(feature = "integers")]
 fn generic_u128(value: u128, radix: u32, table: &[u8], buffer: &mut [u8]) -> usize {
     // Both forms of unchecked indexing cannot overflow.
     // The table always has 2*radix^2 elements, so it must be a legal index.
@@ -291,8 +293,12 @@ macro_rules! generic_impl {
     )*);
 }
 
-generic_impl! { u8 u16 u32 u64 usize }
+generic_impl! { u8 u16 u32 u64 }
 
+#[cfg(feature = "integers")]
+generic_impl! { usize }
+
+#[cfg(feature = "integers")]
 impl Generic for u128 {
     #[inline(always)]
     fn generic(self, radix: u32, buffer: &mut [u8]) -> usize {
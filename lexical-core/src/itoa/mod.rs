@@ -2,9 +2,13 @@
 
 // Hide internal implementation details.
 mod api;
+#[cfg(feature = "power_of_two")]
+mod binary;
 mod decimal;
 #[cfg(feature = "power_of_two")]
 mod generic;
 
+#[cfg(feature = "floats")]
+pub(crate) use self::api::itoa_positive;
 #[cfg(feature = "power_of_two")]
-pub(crate) use self::api::{itoa_positive, Itoa};
+pub(crate) use self::api::Itoa;
@@ -24,6 +24,7 @@
 //!
 //! // String to number using Rust slices.
 //! // The argument is the byte string parsed.
+//! # #[cfg(feature = "floats")]
 //! let f: f32 = lexical_core::parse(b"3.5").unwrap();   // 3.5
 //! let i: i32 = lexical_core::parse(b"15").unwrap();    // 15
 //!
@@ -44,6 +45,7 @@
 //! let mut buf = [b'0'; 1];
 //! //let slc = lexical_core::write::<i64>(15, &mut buf);
 //!
+//! # #[cfg(feature = "floats")] {
 //! // In order to guarantee the buffer is long enough, always ensure there
 //! // are at least `T::FORMATTED_SIZE` bytes, which requires the
 //! // `lexical_core::Number` trait to be in scope.
@@ -60,6 +62,17 @@
 //! let mut buf = [b'0'; f64::FORMATTED_SIZE_DECIMAL];
 //! let slc = lexical_core::write::<f64>(15.1, &mut buf);
 //! assert_eq!(slc, b"15.1");
+//!
+//! // `T::formatted_size` computes the space required by the actual
+//! // options, which requires the `lexical_core::ToLexicalOptions`
+//! // trait to be in scope.
+//! use lexical_core::ToLexicalOptions;
+//! let options = lexical_core::WriteFloatOptions::rust_scientific();
+//! let mut buf = [b'0'; 24];
+//! assert_eq!(f64::formatted_size(&options), buf.len());
+//! let slc = lexical_core::write_with_options::<f64>(15.1, &mut buf, &options);
+//! assert_eq!(slc, b"1.51e1");
+//! # }
 //! ```
 //!
 //! # Conversion API
@@ -67,12 +80,21 @@
 //! **To String**
 //! - [`write`]
 //! - [`write_with_options`]
+//! - [`write_with_info`]
+//! - [`write_with_parts`]
+//! - [`try_write_with_options`]
+//! - [`write_to_vec`]
+//! - [`write_size_hint`]
 //!
 //! **From String**
 //! - [`parse`]
 //! - [`parse_with_options`]
 //! - [`parse_partial`]
 //! - [`parse_partial_with_options`]
+//! - [`parse_partial_limited`]
+//! - [`parse_partial_limited_with_options`]
+//! - [`parse_field`]
+//! - [`parse_field_with_options`]
 //!
 //! # Configuration API
 //!
@@ -163,10 +185,18 @@
 //!
 //! [`write`]: fn.write.html
 //! [`write_with_options`]: fn.write_with_options.html
+//! [`write_with_info`]: fn.write_with_info.html
+//! [`write_with_parts`]: fn.write_with_parts.html
+//! [`try_write_with_options`]: fn.try_write_with_options.html
+//! [`write_to_vec`]: fn.write_to_vec.html
 //! [`parse`]: fn.parse.html
 //! [`parse_with_options`]: fn.parse_with_options.html
 //! [`parse_partial`]: fn.parse_partial.html
 //! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+//! [`parse_partial_limited`]: fn.parse_partial_limited.html
+//! [`parse_partial_limited_with_options`]: fn.parse_partial_limited_with_options.html
+//! [`parse_field`]: fn.parse_field.html
+//! [`parse_field_with_options`]: fn.parse_field_with_options.html
 //!
 //! [`NumberFormat`]: struct.NumberFormat.html
 //! [`ParseFloatOptions`]: struct.ParseFloatOptions.html
@@ -192,7 +222,7 @@ extern crate cfg_if;
 // we're using the correct and radix features.
 #[cfg(all(
     not(feature = "std"),
-    any(not(feature = "no_alloc"), feature = "f128", feature = "radix")
+    any(not(feature = "no_alloc"), feature = "heap_bignum", feature = "f128", feature = "radix")
 ))]
 #[cfg_attr(test, macro_use)]
 extern crate alloc;
@@ -205,6 +235,21 @@ extern crate arrayvec;
 #[cfg(all(feature = "grisu3", feature = "ryu"))]
 compile_error!("Lexical only accepts one of the following backends: `grisu3` or `ryu`.");
 
+// The memory bound of bounded bignums requires fixed-size decimal storage.
+#[cfg(all(feature = "bounded_bignum", any(feature = "heap_bignum", feature = "radix")))]
+compile_error!("Lexical does not support `bounded_bignum` with `heap_bignum` or `radix`.");
+
+// The radix writer uses native float arithmetic and logarithms.
+#[cfg(all(feature = "portable", feature = "radix"))]
+compile_error!("Lexical does not support `portable` with `radix`.");
+
+// Deserializing special strings requires an allocator.
+#[cfg(all(feature = "serde", not(feature = "std")))]
+compile_error!("Lexical requires the `std` feature for `serde` support.");
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
 // Import the back-end, if applicable.
 cfg_if! {
 if #[cfg(feature = "grisu3")] {
@@ -222,7 +267,12 @@ pub(crate) mod lib {
     pub(crate) use core::*;
 
     cfg_if! {
-    if #[cfg(any(not(feature = "no_alloc"), feature = "f128", feature = "radix"))] {
+    if #[cfg(any(
+        not(feature = "no_alloc"),
+        feature = "heap_bignum",
+        feature = "f128",
+        feature = "radix"
+    ))] {
         #[cfg(feature = "std")]
         pub(crate) use std::vec::Vec;
 
@@ -259,6 +309,7 @@ mod traits;
 
 mod config;
 mod error;
+#[cfg(feature = "floats")]
 mod float;
 mod result;
 mod table;
@@ -266,18 +317,54 @@ mod table;
 // Re-export configuration, options, and utilities globally.
 pub use config::*;
 pub use error::*;
+#[cfg(feature = "floats")]
 pub use options::*;
 pub use result::*;
+#[cfg(feature = "floats")]
 pub use table::*;
 pub use traits::*;
 pub use util::*;
 
+// Extended-precision floats and the moderate-path cached powers.
+#[cfg(feature = "floats")]
+pub use atof::{ExtendedFloatArray, ModeratePathCache, ModeratePathPowers};
+#[cfg(feature = "floats")]
+pub use float::{ExtendedFloat, Mantissa};
+
+// Reusable float parser.
+#[cfg(feature = "floats")]
+pub use atof::FloatParser;
+
 // Submodules
+#[cfg(feature = "floats")]
 mod atof;
 mod atoi;
+#[cfg(feature = "floats")]
 mod ftoa;
 mod itoa;
 
+// Public submodules.
+#[cfg(feature = "floats")]
+pub mod codegen;
+
+// Compile-time radix API, which requires const generics.
+#[cfg(all(feature = "power_of_two", has_const_generics))]
+mod radix_const;
+
+#[cfg(all(feature = "power_of_two", has_const_generics))]
+pub use radix_const::*;
+
+// Const integer parsers and writers, which require loops in const fn.
+#[cfg(has_const_if)]
+mod parse_const;
+#[cfg(has_const_if)]
+mod write_const;
+
+#[cfg(has_const_if)]
+pub use parse_const::*;
+#[cfg(has_const_if)]
+pub use write_const::*;
+
 // API
 // ---
 
@@ -298,6 +385,7 @@ mod itoa;
 /// # Example
 ///
 /// ```
+/// # #[cfg(feature = "floats")] {
 /// // import `Number` trait to get the `FORMATTED_SIZE_DECIMAL` of the number.
 /// use lexical_core::Number;
 ///
@@ -307,6 +395,7 @@ mod itoa;
 /// lexical_core::write(float, &mut buffer);
 ///
 /// assert_eq!(&buffer[0..9], b"3.1415927");
+/// # }
 /// ```
 ///
 /// This will panic, because the buffer is not large enough:
@@ -314,7 +403,10 @@ mod itoa;
 /// ```should_panic
 /// // note: the buffer is only one byte large
 /// let mut buffer = [0u8; 1];
+/// # #[cfg(feature = "floats")]
 /// let float = 3.14159265359_f32;
+/// # #[cfg(not(feature = "floats"))]
+/// # let float = 314159265359_u64;
 ///
 /// lexical_core::write(float, &mut buffer);
 /// ```
@@ -341,6 +433,7 @@ pub fn write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8]) -> &'a mut [u8] {
 /// # Example
 ///
 /// ```
+/// # #[cfg(feature = "floats")] {
 /// // import `Number` trait to get the `FORMATTED_SIZE` of the number.
 /// use lexical_core::Number;
 ///
@@ -351,6 +444,7 @@ pub fn write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8]) -> &'a mut [u8] {
 /// lexical_core::write_with_options(float, &mut buffer, &options);
 ///
 /// assert_eq!(&buffer[0..9], b"3.1415927");
+/// # }
 /// ```
 ///
 /// This will panic, because the buffer is not large enough:
@@ -358,9 +452,15 @@ pub fn write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8]) -> &'a mut [u8] {
 /// ```should_panic
 /// // note: the buffer is only one byte large
 /// let mut buffer = [0u8; 1];
+/// # #[cfg(feature = "floats")]
 /// let float = 3.14159265359_f32;
+/// # #[cfg(not(feature = "floats"))]
+/// # let float = 314159265359_u64;
 ///
+/// # #[cfg(feature = "floats")]
 /// let options = lexical_core::WriteFloatOptions::decimal();
+/// # #[cfg(not(feature = "floats"))]
+/// # let options = lexical_core::WriteIntegerOptions::decimal();
 /// lexical_core::write_with_options(float, &mut buffer, &options);
 /// ```
 #[inline]
@@ -372,6 +472,383 @@ pub fn write_with_options<'a, N: ToLexicalOptions>(
     n.to_lexical_with_options(bytes, options)
 }
 
+/// Write float to string with custom options, or return an error.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error if the float cannot be written in the maximum width of the
+/// options, even with a single digit in scientific notation.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, like [`write_with_options`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "floats")] {
+/// use lexical_core::{ErrorCode, Number};
+///
+/// let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .max_width(Some(5))
+///     .build()
+///     .unwrap();
+///
+/// let bytes = lexical_core::try_write_with_options(1.5f64, &mut buffer, &options).unwrap();
+/// assert_eq!(bytes, b"1.5");
+/// let bytes = lexical_core::try_write_with_options(123456.7f64, &mut buffer, &options).unwrap();
+/// assert_eq!(bytes, b"1.2e5");
+///
+/// let error = lexical_core::try_write_with_options(-1.5e-300f64, &mut buffer, &options);
+/// assert_eq!(error.unwrap_err().code, ErrorCode::ExceedsMaxWidth);
+/// # }
+/// ```
+///
+/// [`write_with_options`]: fn.write_with_options.html
+#[inline]
+pub fn try_write_with_options<'a, N: TryToLexicalOptions>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::WriteOptions,
+) -> Result<&'a mut [u8]> {
+    n.try_to_lexical_with_options(bytes, options)
+}
+
+/// Write float to string with custom options, and report its exactness.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, and
+/// whether the written digits exactly represent the float or are a
+/// rounding of it, such as the shortest digits that round-trip.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, like [`write_with_options`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "floats")] {
+/// use lexical_core::{Exactness, Number};
+///
+/// let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+/// let options = lexical_core::WriteFloatOptions::decimal();
+///
+/// let (bytes, exactness) = lexical_core::write_with_info(1.25f64, &mut buffer, &options);
+/// assert_eq!(bytes, b"1.25");
+/// assert_eq!(exactness, Exactness::Exact);
+///
+/// let (bytes, exactness) = lexical_core::write_with_info(1.1f64, &mut buffer, &options);
+/// assert_eq!(bytes, b"1.1");
+/// assert_eq!(exactness, Exactness::Inexact);
+/// # }
+/// ```
+///
+/// [`write_with_options`]: fn.write_with_options.html
+#[inline]
+pub fn write_with_info<'a, N: ToLexicalInfo>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::WriteOptions,
+) -> (&'a mut [u8], Exactness) {
+    n.to_lexical_with_info(bytes, options)
+}
+
+/// Write float to string with custom options, visiting each written part.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, and
+/// invokes the callback for each part of the written bytes, in order,
+/// such as the sign, the digits, the decimal point and the exponent.
+/// Custom layouts may be built from the parts without parsing the
+/// written bytes.
+///
+/// * `value`       - Number to serialize.
+/// * `bytes`       - Buffer to write number to.
+/// * `options`     - Options to customize number writing.
+/// * `callback`    - Callback invoked with each part and its bytes.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, like [`write_with_options`].
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "floats")] {
+/// use lexical_core::{FloatPart, Number};
+///
+/// let mut buffer = [0u8; f64::FORMATTED_SIZE_DECIMAL];
+/// let options = lexical_core::WriteFloatOptions::decimal();
+///
+/// let mut exponent = Vec::new();
+/// let bytes = lexical_core::write_with_parts(6.02e23f64, &mut buffer, &options, |part, bytes| {
+///     if part == FloatPart::Exponent {
+///         exponent.extend_from_slice(bytes);
+///     }
+/// });
+/// assert_eq!(bytes, b"6.02e23");
+/// assert_eq!(exponent, b"23");
+/// # }
+/// ```
+///
+/// [`write_with_options`]: fn.write_with_options.html
+#[inline]
+pub fn write_with_parts<'a, N: ToLexicalParts, Cb: FnMut(FloatPart, &[u8])>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::WriteOptions,
+    callback: Cb,
+) -> &'a mut [u8] {
+    n.to_lexical_with_parts(bytes, options, callback)
+}
+
+/// Append number to a vector with custom options.
+///
+/// Reserves the `formatted_size` required by the options, writes the
+/// number after the existing bytes, and truncates the vector to the
+/// written length. Returns a subslice of the vector containing the
+/// appended bytes.
+///
+/// * `value`   - Number to serialize.
+/// * `vec`     - Vector to append the number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Example
+///
+/// ```
+/// let mut vec = b"Content-Length: ".to_vec();
+/// let options = lexical_core::WriteIntegerOptions::decimal();
+/// lexical_core::write_to_vec(1024u32, &mut vec, &options);
+///
+/// assert_eq!(vec, b"Content-Length: 1024");
+///
+/// # #[cfg(feature = "floats")] {
+/// let options = lexical_core::WriteFloatOptions::decimal();
+/// assert_eq!(lexical_core::write_to_vec(-0.5f64, &mut vec, &options), b"-0.5");
+/// assert_eq!(vec, b"Content-Length: 1024-0.5");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_to_vec<'a, N: ToLexicalOptions>(
+    n: N,
+    vec: &'a mut std::vec::Vec<u8>,
+    options: &N::WriteOptions,
+) -> &'a mut [u8] {
+    let start = vec.len();
+    let size = N::formatted_size(options);
+    vec.reserve_exact(size);
+    vec.resize(start + size, 0);
+    let len = n.to_lexical_with_options(&mut vec[start..], options).len();
+    vec.truncate(start + len);
+    &mut vec[start..]
+}
+
+/// Write float to an iterator over the bytes in positional notation.
+///
+/// The bytes are the shortest digits of the float, written without an
+/// exponent, and are computed when they are requested, so large
+/// exponents may be streamed to a sink without a buffer. The decimal
+/// point, trimmed floats, unsigned zero and the NaN and infinity
+/// strings of the options are used, and NaN is written without a sign.
+///
+/// * `value`   - Float to serialize.
+/// * `options` - Options to customize float writing.
+///
+/// # Example
+///
+/// ```
+/// let options = lexical_core::WriteFloatOptions::decimal();
+/// let bytes: Vec<u8> = lexical_core::write_positional_iter(1.5e5f64, &options).collect();
+/// assert_eq!(bytes, b"150000.0");
+///
+/// let mut bytes = lexical_core::write_positional_iter(1e300f64, &options);
+/// assert_eq!(bytes.len(), 303);
+/// assert_eq!(bytes.nth(301), Some(b'.'));
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn write_positional_iter<F: ToDecimalDigits + Float>(
+    value: F,
+    options: &WriteFloatOptions,
+) -> PositionalBytes {
+    let is_negative =
+        value.is_sign_negative() && !(value.is_zero() && options.unsigned_zero());
+    match value.to_decimal_digits() {
+        Some(decimal) => PositionalBytes::new(
+            is_negative,
+            decimal,
+            options.decimal_point(),
+            options.trim_floats(),
+        ),
+        None if value.is_nan() => PositionalBytes::special(false, options.nan_string()),
+        None => PositionalBytes::special(is_negative, options.inf_string()),
+    }
+}
+
+/// Write a big-integer mantissa and exponent to string in scientific notation.
+///
+/// The value is `mantissa * 2^exponent` or `mantissa * 10^exponent`,
+/// such as the mantissa and exponent of an extended-precision float,
+/// and is written with the correctly rounded decimal digits, like
+/// Rust's `{:.*e}`. The mantissa is unsigned, so the sign must be
+/// written separately. Returns a subslice of the input buffer
+/// containing the written bytes, starting from the same address in
+/// memory as the input slice.
+///
+/// Returns `None` if the buffer is too small, or if the value needs
+/// big integers larger than their storage, which is 4096 bits with
+/// the `no_alloc` feature, or 1280 bits with `bounded_bignum`, unless
+/// `heap_bignum` is enabled.
+///
+/// * `mantissa`    - Mantissa as 32-bit words, in little-endian order.
+/// * `exponent`    - Binary or decimal exponent of the mantissa.
+/// * `precision`   - Number of digits after the decimal point.
+/// * `bytes`       - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::BigExponent;
+///
+/// // 2^128, from the words of a big integer.
+/// let mut buffer = [0u8; 64];
+/// let mantissa = [0, 0, 0, 0, 1];
+/// let exponent = BigExponent::Binary(0);
+/// let bytes = lexical_core::write_big_mantissa(&mantissa, exponent, 5, &mut buffer);
+/// assert_eq!(bytes.unwrap(), b"3.40282e38");
+///
+/// // 1/3 to 30 digits, from an extended-precision float.
+/// let mantissa = [0x5555_5555, 0x5555_5555, 0x5555_5555, 0x5555_5555];
+/// let exponent = BigExponent::Binary(-128);
+/// let bytes = lexical_core::write_big_mantissa(&mantissa, exponent, 29, &mut buffer);
+/// assert_eq!(bytes.unwrap(), b"3.33333333333333333333333333333e-1");
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn write_big_mantissa<'a>(
+    mantissa: &[u32],
+    exponent: BigExponent,
+    precision: usize,
+    bytes: &'a mut [u8],
+) -> Option<&'a mut [u8]> {
+    let len = ftoa::write_big_mantissa(mantissa, exponent, precision, bytes)?;
+    Some(&mut bytes[..len])
+}
+
+/// Write the exact decimal value of a float to string.
+///
+/// Writes every digit of the binary float in positional notation,
+/// rather than the shortest digits that round-trip, such as the 55
+/// fractional digits of `0.1`, to debug or teach floating-point
+/// rounding. Whole numbers are written with `.0`, and NaN and
+/// infinity are written as `NaN` and `inf`. Returns a subslice of the
+/// input buffer containing the written bytes, starting from the same
+/// address in memory as the input slice.
+///
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the exact digits.
+/// A buffer of [`EXACT_BUFFER_SIZE`] bytes is large enough for any float.
+///
+/// # Example
+///
+/// ```
+/// let mut buffer = [0u8; lexical_core::EXACT_BUFFER_SIZE];
+/// let bytes = lexical_core::write_exact(0.1f64, &mut buffer);
+/// assert_eq!(bytes, &b"0.1000000000000000055511151231257827021181583404541015625"[..]);
+///
+/// let bytes = lexical_core::write_exact(-0.1f32, &mut buffer);
+/// assert_eq!(bytes, b"-0.100000001490116119384765625");
+/// ```
+///
+/// [`EXACT_BUFFER_SIZE`]: constant.EXACT_BUFFER_SIZE.html
+#[inline]
+#[cfg(feature = "floats")]
+pub fn write_exact<'a, F: ToDecimalDigits + Float>(value: F, bytes: &'a mut [u8]) -> &'a mut [u8] {
+    let len = ftoa::write_exact(value, bytes);
+    &mut bytes[..len]
+}
+
+/// Append a natural sort key for a string to a vector.
+///
+/// Runs of decimal digits in the string are encoded so comparing the
+/// keys byte-wise orders the runs by value, and the other bytes are
+/// copied, so `file2` sorts before `file10`. Runs may have any number
+/// of digits, and leading zeros are ignored, so `file02` and `file2`
+/// have the same key. Returns a subslice of the vector containing the
+/// appended key.
+///
+/// * `bytes`   - Byte slice containing the string.
+/// * `vec`     - Vector to append the key to.
+///
+/// # Example
+///
+/// ```
+/// let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+/// files.sort_by_cached_key(|file| {
+///     let mut key = Vec::new();
+///     lexical_core::natural_sort_key(file.as_bytes(), &mut key);
+///     key
+/// });
+/// assert_eq!(files, ["file1.txt", "file2.txt", "file10.txt"]);
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn natural_sort_key<'a>(bytes: &[u8], vec: &'a mut std::vec::Vec<u8>) -> &'a mut [u8] {
+    let start = vec.len();
+    write_natural_sort_key(bytes, vec);
+    &mut vec[start..]
+}
+
+/// Get the number of bytes required to write a number with custom options.
+///
+/// The size is for the value, rather than any value like
+/// `formatted_size`, so arena and bump allocators can reserve the
+/// bytes it needs exactly. Integer sizes are computed from the number
+/// of digits, and floats are written to a buffer on the stack. Floats
+/// that cannot be written in the maximum width of the options require
+/// no bytes.
+///
+/// * `value`   - Number to serialize.
+/// * `options` - Options to customize number writing.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::Number;
+///
+/// let options = lexical_core::WriteIntegerOptions::decimal();
+/// assert_eq!(lexical_core::write_size_hint(7u64, &options), 1);
+/// assert_eq!(lexical_core::write_size_hint(-1024i32, &options), 5);
+///
+/// # #[cfg(feature = "floats")] {
+/// let options = lexical_core::WriteFloatOptions::decimal();
+/// assert_eq!(lexical_core::write_size_hint(1.5f64, &options), 3);
+/// assert!(f64::FORMATTED_SIZE_DECIMAL > 3);
+/// # }
+/// ```
+#[inline]
+pub fn write_size_hint<N: ToLexicalSizeHint>(n: N, options: &N::WriteOptions) -> usize {
+    n.size_hint(options)
+}
+
 /// Parse number from string.
 ///
 /// This method parses the entire string, returning an error if
@@ -425,3 +902,844 @@ pub fn parse_partial_with_options<N: FromLexicalOptions>(
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options(bytes, options)
 }
+
+/// Parse an integer as an `i64`, or as a `u64` if it is too large.
+///
+/// This method parses the entire string, like JSON parsers that store
+/// integers as `i64` and fall back to `u64` for large positive values.
+/// Use `ParseIntegerOptions::json()` to enforce the JSON integer syntax.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{JsonInteger, ParseIntegerOptions};
+///
+/// let options = ParseIntegerOptions::new();
+/// let value = lexical_core::parse_json_integer(b"9223372036854775808", &options);
+/// assert_eq!(value, Ok(JsonInteger::Unsigned(1 << 63)));
+/// ```
+#[inline]
+pub fn parse_json_integer(bytes: &[u8], options: &ParseIntegerOptions) -> Result<JsonInteger> {
+    match i64::from_lexical_with_options(bytes, options) {
+        Ok(value) => Ok(JsonInteger::Signed(value)),
+        Err(error) if error.code == ErrorCode::Overflow => {
+            u64::from_lexical_with_options(bytes, options).map(JsonInteger::Unsigned)
+        },
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse number from string as the narrowest lossless type.
+///
+/// This method parses the entire string, like JSON and TOML parsers:
+/// integers are a `u64` if they are positive, or an `i64` if they are
+/// negative, and all other numbers are an `f64`. Integers are parsed
+/// with the radix, format, digit separator and sign strings of the
+/// options, and integers that overflow both types, as well as `-0`,
+/// are parsed as floats, so the value or its sign is not lost.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{ParseFloatOptions, ParsedNumber};
+///
+/// let options = ParseFloatOptions::new();
+/// let value = lexical_core::parse_number(b"18446744073709551615", &options);
+/// assert_eq!(value, Ok(ParsedNumber::U64(u64::max_value())));
+/// let value = lexical_core::parse_number(b"18446744073709551616", &options);
+/// assert_eq!(value, Ok(ParsedNumber::F64(18446744073709551616.0)));
+/// assert_eq!(lexical_core::parse_number(b"1e3", &options), Ok(ParsedNumber::F64(1000.0)));
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn parse_number(bytes: &[u8], options: &ParseFloatOptions) -> Result<ParsedNumber> {
+    if let Some(integer_options) = options.integer_options() {
+        if let Ok(value) = u64::from_lexical_with_options(bytes, &integer_options) {
+            return Ok(ParsedNumber::U64(value));
+        }
+        // Negative zero is a float, to keep its sign.
+        match i64::from_lexical_with_options(bytes, &integer_options) {
+            Ok(value) if value != 0 => return Ok(ParsedNumber::I64(value)),
+            _ => (),
+        }
+    }
+    f64::from_lexical_with_options(bytes, options).map(ParsedNumber::F64)
+}
+
+/// Parse a Rust numeric literal, with its type suffix.
+///
+/// This method parses the entire string as a literal, with `_` digit
+/// separators, an optional `0x`, `0o` or `0b` radix prefix for integers,
+/// and an optional type suffix, such as `u8` or `f32`, returning an
+/// error if any invalid digits are found during parsing. Literals start
+/// with a digit, so signs are invalid, like Rust tokens where negation
+/// is an operator. Float suffixes are only valid for floats, and
+/// integer suffixes for integers. Radix prefixes require the
+/// `power_of_two` feature.
+///
+/// * `bytes`   - Byte slice containing a Rust numeric literal.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::RustSuffix;
+///
+/// let value = lexical_core::parse_rust_literal::<u64>(b"1_000_000");
+/// assert_eq!(value, Ok((1000000, None)));
+/// let value = lexical_core::parse_rust_literal::<f32>(b"2.5e-3f32");
+/// assert_eq!(value, Ok((2.5e-3, Some(RustSuffix::F32))));
+/// assert!(lexical_core::parse_rust_literal::<u8>(b"1f32").is_err());
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_rust_literal<N: FromLexicalOptions>(bytes: &[u8]) -> Result<(N, Option<RustSuffix>)>
+where
+    N::ParseOptions: FromRustLiteral,
+{
+    match bytes.first() {
+        Some(c) if c.is_ascii_digit() => (),
+        Some(_) => return Err((ErrorCode::InvalidDigit, 0).into()),
+        None => return Err((ErrorCode::Empty, 0).into()),
+    }
+    let (radix, prefix_len) = rust_literal_prefix(bytes);
+    let options = match N::ParseOptions::from_rust_literal(radix) {
+        Some(options) => options,
+        None => return Err((ErrorCode::InvalidDigit, 1).into()),
+    };
+
+    // Digit separators may directly follow the prefix, like `0x_FF`.
+    let start = match prefix_len {
+        0 => 0,
+        _ => prefix_len + bytes[prefix_len..].iter().take_while(|&&c| c == b'_').count(),
+    };
+    let (value, processed) = N::from_lexical_partial_with_options(&bytes[start..], &options)
+        .map_err(|error| Error::from((error.code, error.index + start)))?;
+    let end = start + processed;
+    if end == bytes.len() {
+        return Ok((value, None));
+    }
+    match RustSuffix::from_bytes(&bytes[end..]) {
+        Some(suffix) if suffix.is_float() == N::ParseOptions::IS_FLOAT => Ok((value, Some(suffix))),
+        _ => Err((ErrorCode::InvalidDigit, end).into()),
+    }
+}
+
+/// Parse a C++ numeric literal, with its type suffix.
+///
+/// This method parses the entire string as a literal, with `'` digit
+/// separators, an optional `0x` or `0b` radix prefix for integers, a
+/// leading `0` for octal integers, and an optional type suffix, such
+/// as `ull` or `f`, returning an error if any invalid digits are found
+/// during parsing. Floats may also be hexadecimal, with a `0x` prefix
+/// and a required binary exponent, such as `0x1.8p3`. Literals start
+/// with a digit, or the decimal point for floats, so signs are invalid.
+/// Float suffixes are only valid for floats, and integer suffixes for
+/// integers. Radix prefixes and octal integers require the
+/// `power_of_two` feature.
+///
+/// * `bytes`   - Byte slice containing a C++ numeric literal.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::CxxSuffix;
+///
+/// let value = lexical_core::parse_cxx_literal::<u64>(b"1'000'000");
+/// assert_eq!(value, Ok((1000000, None)));
+/// let value = lexical_core::parse_cxx_literal::<u64>(b"1'000ull");
+/// assert_eq!(value, Ok((1000, Some(CxxSuffix::UnsignedLongLong))));
+/// let value = lexical_core::parse_cxx_literal::<f32>(b"2.5e-3F");
+/// assert_eq!(value, Ok((2.5e-3, Some(CxxSuffix::Float))));
+/// assert!(lexical_core::parse_cxx_literal::<u8>(b"1f").is_err());
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_cxx_literal<N: FromLexicalOptions>(bytes: &[u8]) -> Result<(N, Option<CxxSuffix>)>
+where
+    N::ParseOptions: FromCxxLiteral,
+{
+    match bytes.first() {
+        Some(c) if c.is_ascii_digit() => (),
+        Some(&b'.') if N::ParseOptions::IS_FLOAT => (),
+        Some(_) => return Err((ErrorCode::InvalidDigit, 0).into()),
+        None => return Err((ErrorCode::Empty, 0).into()),
+    }
+    let (radix, start) = cxx_literal_prefix(bytes);
+    let options = match N::ParseOptions::from_cxx_literal(radix) {
+        Some(options) => options,
+        None => return Err((ErrorCode::InvalidDigit, 1).into()),
+    };
+
+    #[cfg(feature = "power_of_two")]
+    let is_hex_float = N::ParseOptions::IS_FLOAT && radix == 16;
+    #[cfg(not(feature = "power_of_two"))]
+    let is_hex_float = false;
+    let result = match is_hex_float {
+        #[cfg(feature = "power_of_two")]
+        true => parse_cxx_hex_float(&bytes[start..], &options),
+        _ => N::from_lexical_partial_with_options(&bytes[start..], &options),
+    };
+    let (value, processed) =
+        result.map_err(|error| Error::from((error.code, error.index + start)))?;
+    let end = start + processed;
+    if end == bytes.len() {
+        return Ok((value, None));
+    }
+    let suffix = match N::ParseOptions::IS_FLOAT {
+        true => CxxSuffix::from_float_bytes(&bytes[end..]),
+        false => CxxSuffix::from_integer_bytes(&bytes[end..]),
+    };
+    match suffix {
+        Some(suffix) => Ok((value, Some(suffix))),
+        None => Err((ErrorCode::InvalidDigit, end).into()),
+    }
+}
+
+/// Parse the digits of a C++ hexadecimal float, after the `0x` prefix.
+///
+/// The options must parse binary floats.
+#[inline]
+#[cfg(all(feature = "format", feature = "power_of_two"))]
+fn parse_cxx_hex_float<N: FromLexicalOptions>(
+    bytes: &[u8],
+    options: &N::ParseOptions,
+) -> Result<(N, usize)> {
+    let mut buffer = [0u8; CXX_HEX_FLOAT_SIZE];
+    let (count, processed) = cxx_hex_float(bytes, &mut buffer).map_err(Error::from)?;
+    let value = N::from_lexical_with_options(&buffer[..count], options)?;
+    Ok((value, processed))
+}
+
+/// Parse a Verilog integer literal, with its width and signedness.
+///
+/// This method parses the entire string as a sized or unsized literal,
+/// such as `8'hFF`, `12'b1010_1010` or `'sd15`, returning an error if
+/// any invalid digits are found during parsing. The base is `b`, `o`,
+/// `d` or `h`, and may follow an `s` for signed literals, and `_` digit
+/// separators may follow the first digit. Literals without a base, such
+/// as `15`, are unsized, signed decimal numbers. Unknown and
+/// high-impedance digits, such as `x` and `z`, are invalid, and values
+/// that do not fit in the width are an overflow, rather than truncated.
+///
+/// * `bytes`   - Byte slice containing a Verilog integer literal.
+///
+/// # Example
+///
+/// ```
+/// let value = lexical_core::parse_verilog_literal::<u64>(b"12'b1010_1010").unwrap();
+/// assert_eq!(value.width, Some(12));
+/// assert_eq!(value.signed, false);
+/// assert_eq!(value.value, 0b1010_1010);
+/// assert!(lexical_core::parse_verilog_literal::<u64>(b"4'hFF").is_err());
+/// ```
+#[inline]
+#[cfg(feature = "hdl")]
+pub fn parse_verilog_literal<N>(bytes: &[u8]) -> Result<SizedLiteral<N>>
+where
+    N: UnsignedInteger + FromLexicalOptions<ParseOptions = ParseIntegerOptions>,
+{
+    let prefix = verilog_literal_prefix(bytes).map_err(Error::from)?;
+    let options = hdl_options(prefix.radix).ok_or((ErrorCode::InvalidDigit, prefix.start))?;
+    let value: N = parse_hdl_digits(&bytes[prefix.start..], &options)
+        .map_err(|error| Error::from((error.code, error.index + prefix.start)))?;
+    if let Some(width) = prefix.width {
+        if N::BITS - value.leading_zeros() as usize > width as usize {
+            return Err((ErrorCode::Overflow, prefix.start).into());
+        }
+    }
+    Ok(SizedLiteral {
+        width: prefix.width,
+        signed: prefix.signed,
+        value,
+    })
+}
+
+/// Parse a VHDL based integer literal.
+///
+/// This method parses the entire string as a based literal, such as
+/// `16#FF#` or `2#1010_1010#`, returning an error if any invalid digits
+/// are found during parsing. The base is from 2 to 16, and the digits
+/// are delimited by `#`, or `:` for both. An exponent, such as `E2`,
+/// may follow, and multiplies the value by a power of the base. Digit
+/// separators are `_`, and must be between digits. Based literals are
+/// unsized and unsigned. Bases other than powers of two and 10 require
+/// the `radix` feature.
+///
+/// * `bytes`   - Byte slice containing a VHDL based integer literal.
+///
+/// # Example
+///
+/// ```
+/// let value = lexical_core::parse_vhdl_literal::<u64>(b"16#FF#").unwrap();
+/// assert_eq!(value.width, None);
+/// assert_eq!(value.value, 255);
+/// let value = lexical_core::parse_vhdl_literal::<u64>(b"2#1#E4").unwrap();
+/// assert_eq!(value.value, 16);
+/// ```
+#[inline]
+#[cfg(feature = "hdl")]
+pub fn parse_vhdl_literal<N>(bytes: &[u8]) -> Result<SizedLiteral<N>>
+where
+    N: UnsignedInteger + FromLexicalOptions<ParseOptions = ParseIntegerOptions>,
+{
+    let parts = vhdl_literal_parts(bytes).map_err(Error::from)?;
+    let options = hdl_options(parts.radix).ok_or((ErrorCode::InvalidDigit, 0))?;
+    let (start, end) = parts.digits;
+    let mut value: N = parse_hdl_digits(&bytes[start..end], &options)
+        .map_err(|error| Error::from((error.code, error.index + start)))?;
+
+    // Scale by the exponent, after the closing delimiter.
+    let radix: N = traits::as_cast(parts.radix);
+    for _ in 0..parts.exponent {
+        if value == N::ZERO {
+            break;
+        }
+        value = match value.checked_mul(radix) {
+            Some(value) => value,
+            None => return Err((ErrorCode::Overflow, end + 1).into()),
+        };
+    }
+    Ok(SizedLiteral {
+        width: None,
+        signed: false,
+        value,
+    })
+}
+
+/// Create the options to parse the digits of a hardware description literal.
+///
+/// Digit separators are `_`. Returns `None` if the radix is not supported.
+#[inline]
+#[cfg(feature = "hdl")]
+fn hdl_options(radix: u32) -> Option<ParseIntegerOptions> {
+    ParseIntegerOptions::builder()
+        .radix(radix as u8)
+        .digit_separator(Some(b'_'))
+        .build()
+}
+
+/// Parse the digits of a hardware description literal, which must start with a digit.
+#[inline]
+#[cfg(feature = "hdl")]
+fn parse_hdl_digits<N>(digits: &[u8], options: &ParseIntegerOptions) -> Result<N>
+where
+    N: FromLexicalOptions<ParseOptions = ParseIntegerOptions>,
+{
+    match digits.first() {
+        Some(&c) if (c as char).is_digit(options.radix()) => (),
+        Some(_) => return Err((ErrorCode::InvalidDigit, 0).into()),
+        None => return Err((ErrorCode::Empty, 0).into()),
+    }
+    N::from_lexical_with_options(digits, options)
+}
+
+/// Parse at most `max_len` bytes of a number.
+///
+/// Parses one byte past the limit, so numbers that continue past the
+/// limit are errors, rather than silently truncated. Errors at or past
+/// the limit required bytes past the limit to find, so are also errors
+/// for exceeding the limit.
+#[inline]
+fn parse_partial_limited_impl<N, Cb>(bytes: &[u8], max_len: usize, cb: Cb) -> Result<(N, usize)>
+where
+    Cb: FnOnce(&[u8]) -> Result<(N, usize)>,
+{
+    if bytes.len() <= max_len {
+        return cb(bytes);
+    }
+    let exceeds = Err((ErrorCode::ExceedsMaxLength, max_len).into());
+    match cb(&bytes[..max_len + 1]) {
+        Ok((_, processed)) if processed > max_len => exceeds,
+        Err(error) if error.index >= max_len => exceeds,
+        result => result,
+    }
+}
+
+/// Parse number from string, consuming at most `max_len` bytes.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), like [`parse_partial`], returning the number of
+/// processed digits and the parsed value until that point. Only the
+/// first `max_len` bytes, and one byte to find the end of the number,
+/// are read, so protocol decoders may enforce field-length limits
+/// without slicing the input and adjusting error indexes.
+///
+/// Returns an error with `ErrorCode::ExceedsMaxLength` at `max_len`
+/// if the number continues past the limit, or if any other error is
+/// found at or past the limit.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `max_len` - Maximum number of bytes to consume.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::ErrorCode;
+///
+/// assert_eq!(lexical_core::parse_partial_limited::<u32>(b"1234,5678", 4), Ok((1234, 4)));
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical_core::parse_partial_limited::<f64>(b"1.5,5678", 4), Ok((1.5, 3)));
+///
+/// let error = lexical_core::parse_partial_limited::<u32>(b"12345678", 4).unwrap_err();
+/// assert_eq!(error.code, ErrorCode::ExceedsMaxLength);
+/// assert_eq!(error.index, 4);
+/// ```
+///
+/// [`parse_partial`]: fn.parse_partial.html
+#[inline]
+pub fn parse_partial_limited<N: FromLexical>(bytes: &[u8], max_len: usize) -> Result<(N, usize)> {
+    parse_partial_limited_impl(bytes, max_len, N::from_lexical_partial)
+}
+
+/// Parse number from string with custom parsing options, consuming at
+/// most `max_len` bytes.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), like [`parse_partial_limited`], returning an
+/// error with `ErrorCode::ExceedsMaxLength` at `max_len` if the
+/// number continues past the limit.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `max_len` - Maximum number of bytes to consume.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "floats")] {
+/// use lexical_core::ErrorCode;
+///
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .trim_whitespace(true)
+///     .build()
+///     .unwrap();
+/// let parse = |bytes, max_len| {
+///     lexical_core::parse_partial_limited_with_options::<f64>(bytes, max_len, &options)
+/// };
+/// assert_eq!(parse(b" 1.5 ", 8), Ok((1.5, 5)));
+/// assert_eq!(parse(b"1.5e10", 4).unwrap_err().code, ErrorCode::ExceedsMaxLength);
+/// # }
+/// ```
+///
+/// [`parse_partial_limited`]: fn.parse_partial_limited.html
+#[inline]
+pub fn parse_partial_limited_with_options<N: FromLexicalOptions>(
+    bytes: &[u8],
+    max_len: usize,
+    options: &N::ParseOptions,
+) -> Result<(N, usize)> {
+    parse_partial_limited_impl(bytes, max_len, |bytes| {
+        N::from_lexical_partial_with_options(bytes, options)
+    })
+}
+
+/// Parse a fixed-width field with the callback.
+#[inline]
+fn parse_field_impl<N, Cb>(bytes: &[u8], spec: &FieldSpec, cb: Cb) -> Result<N>
+where
+    Cb: FnOnce(&[u8]) -> Result<N>,
+{
+    let buffer = normalize_field(bytes, spec);
+    cb(buffer.as_slice()).map_err(|error| (error.code, buffer.position(error.index)).into())
+}
+
+/// Parse number from a fixed-width field.
+///
+/// This method parses the first `spec.width()` bytes as a right-aligned,
+/// blank-padded field, returning an error if any invalid digits are
+/// found in the field. Bytes past the end of a short record are read
+/// as blanks, and error indexes are relative to the start of the field.
+///
+/// * `bytes`   - Byte slice starting with the field.
+/// * `spec`    - Width, implied decimals and blank mode of the field.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{BlankMode, ErrorCode, FieldSpec};
+///
+/// // A record with an `I4` and an `F8.3` field.
+/// let record = b"  42 1234567";
+/// let spec = FieldSpec::new(4, 0, BlankMode::Ignore).unwrap();
+/// assert_eq!(lexical_core::parse_field::<u32>(record, &spec), Ok(42));
+/// # #[cfg(feature = "floats")] {
+/// let spec = FieldSpec::new(8, 3, BlankMode::Ignore).unwrap();
+/// assert_eq!(lexical_core::parse_field::<f64>(&record[4..], &spec), Ok(1234.567));
+///
+/// let error = lexical_core::parse_field::<f64>(b" 12x4567", &spec).unwrap_err();
+/// assert_eq!(error.code, ErrorCode::InvalidDigit);
+/// assert_eq!(error.index, 3);
+/// # }
+/// ```
+#[inline]
+pub fn parse_field<N: FromLexical>(bytes: &[u8], spec: &FieldSpec) -> Result<N> {
+    parse_field_impl(bytes, spec, N::from_lexical)
+}
+
+/// Parse number from a fixed-width field with custom parsing options.
+///
+/// This method parses the first `spec.width()` bytes as a right-aligned,
+/// blank-padded field, like [`parse_field`], returning an error if any
+/// invalid digits are found in the field.
+///
+/// * `bytes`   - Byte slice starting with the field.
+/// * `spec`    - Width, implied decimals and blank mode of the field.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "floats")] {
+/// use lexical_core::{BlankMode, FieldSpec};
+///
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .digit_separator(Some(b','))
+///     .build()
+///     .unwrap();
+/// let spec = FieldSpec::new(10, 2, BlankMode::Ignore).unwrap();
+/// let parse = |bytes| lexical_core::parse_field_with_options::<f64>(bytes, &spec, &options);
+/// assert_eq!(parse(b" 1,234,567"), Ok(12345.67));
+/// # }
+/// ```
+///
+/// [`parse_field`]: fn.parse_field.html
+#[inline]
+pub fn parse_field_with_options<N: FromLexicalOptions>(
+    bytes: &[u8],
+    spec: &FieldSpec,
+    options: &N::ParseOptions,
+) -> Result<N> {
+    parse_field_impl(bytes, spec, |bytes| N::from_lexical_with_options(bytes, options))
+}
+
+/// Classify a numeric string without converting it.
+///
+/// This method checks the syntax of the complete string against the
+/// number format of the options, with the same scanner as the float
+/// parsers, and returns if it is an integer, a float, a special value
+/// or invalid. Integers have no fraction or exponent, and may still
+/// overflow an integer type.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options for the number format, radix and special strings.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{NumberClass, ParseFloatOptions};
+///
+/// let options = ParseFloatOptions::new();
+/// assert_eq!(lexical_core::classify(b"1234", &options), NumberClass::Integer);
+/// assert_eq!(lexical_core::classify(b"-1.5", &options), NumberClass::Float);
+/// assert_eq!(lexical_core::classify(b"1e10", &options), NumberClass::Float);
+/// assert_eq!(lexical_core::classify(b"-inf", &options), NumberClass::Infinity);
+/// assert_eq!(lexical_core::classify(b"1.5.", &options), NumberClass::Invalid);
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn classify(bytes: &[u8], options: &ParseFloatOptions) -> NumberClass {
+    atof::classify_with_options(bytes, options)
+}
+
+/// Compare two numeric strings by value, without converting them.
+///
+/// This method compares the complete strings as numbers in the number
+/// format of the options, with the digits and exponents of each
+/// number, so long numbers are ordered exactly rather than after
+/// rounding to a float. Returns `None` if either string is NaN or
+/// not a valid number, like `partial_cmp`. Zeros are equal regardless
+/// of sign, and infinities order before and after all finite numbers.
+///
+/// * `a`       - Byte slice containing a numeric string.
+/// * `b`       - Byte slice containing a numeric string.
+/// * `options` - Options for the number format, radix and special strings.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::ParseFloatOptions;
+/// use std::cmp::Ordering;
+///
+/// let options = ParseFloatOptions::new();
+/// let compare = |a, b| lexical_core::compare_numeric(a, b, &options);
+/// assert_eq!(compare(b"1.5", b"15e-1"), Some(Ordering::Equal));
+/// assert_eq!(compare(b"-2", b"1"), Some(Ordering::Less));
+/// assert_eq!(compare(b"inf", b"1e308"), Some(Ordering::Greater));
+///
+/// // Both round to the same float.
+/// let a = b"9007199254740993";
+/// let b = b"9007199254740992.5";
+/// assert_eq!(compare(a, b), Some(Ordering::Greater));
+///
+/// assert_eq!(compare(b"NaN", b"1"), None);
+/// assert_eq!(compare(b"1x", b"1"), None);
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn compare_numeric(
+    a: &[u8],
+    b: &[u8],
+    options: &ParseFloatOptions,
+) -> Option<lib::cmp::Ordering> {
+    atof::compare_with_options(a, b, options)
+}
+
+/// Scan a numeric string without converting it.
+///
+/// This method checks the syntax of the complete string against the
+/// number format of the options, with the same scanner as the float
+/// parsers, and returns the sign, digits and exponent verbatim, so
+/// they may be converted later without any loss of precision. Special
+/// values are invalid, since they have no digits.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options for the number format and radix.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::ParseFloatOptions;
+///
+/// let options = ParseFloatOptions::new();
+/// let raw = lexical_core::parse_raw_number(b"3.14159265358979323846264338327950288", &options);
+/// let raw = raw.unwrap();
+/// assert_eq!(raw.fraction().unwrap().len(), 35);
+/// assert_eq!(raw.to_float::<f64>(), Ok(3.141592653589793));
+/// assert!(lexical_core::parse_raw_number(b"inf", &options).is_err());
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn parse_raw_number<'a>(
+    bytes: &'a [u8],
+    options: &ParseFloatOptions,
+) -> Result<RawNumber<&'a [u8]>> {
+    let (raw, processed) = atof::scan_raw_with_options(bytes, options)?;
+    match processed == bytes.len() {
+        true => Ok(raw),
+        false => Err((ErrorCode::InvalidDigit, processed).into()),
+    }
+}
+
+/// Scan a numeric string without converting it, until an invalid digit.
+///
+/// This method scans as many digits as possible, like
+/// `parse_raw_number`, returning the raw number and the number of
+/// bytes processed, such as for tokenizers that find the end of each
+/// number in a larger document.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options for the number format and radix.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::ParseFloatOptions;
+///
+/// let options = ParseFloatOptions::new();
+/// let (raw, processed) = lexical_core::parse_partial_raw_number(b"1e5,2", &options).unwrap();
+/// assert_eq!((raw.as_bytes(), processed), (&b"1e5"[..], 3));
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn parse_partial_raw_number<'a>(
+    bytes: &'a [u8],
+    options: &ParseFloatOptions,
+) -> Result<(RawNumber<&'a [u8]>, usize)> {
+    atof::scan_raw_with_options(bytes, options)
+}
+
+/// Write number to string in a given radix.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `radix`   - Radix for number encoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid, or if the buffer may not be large
+/// enough to hold the serialized number. In order to ensure the function
+/// will not panic, provide a buffer with at least `{integer}::FORMATTED_SIZE`
+/// elements.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "power_of_two")] {
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_with_radix(255u8, &mut buffer, 16), b"FF");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn write_with_radix<'a, N: ToLexicalOptions>(
+    n: N,
+    bytes: &'a mut [u8],
+    radix: u32,
+) -> &'a mut [u8]
+where
+    N::WriteOptions: FromRadix,
+{
+    let options = N::WriteOptions::from_radix(radix).expect("Invalid radix.");
+    n.to_lexical_with_options(bytes, &options)
+}
+
+/// Parse number from string in a given radix.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid.
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn parse_with_radix<N: FromLexicalOptions>(bytes: &[u8], radix: u32) -> Result<N>
+where
+    N::ParseOptions: FromRadix,
+{
+    let options = N::ParseOptions::from_radix(radix).expect("Invalid radix.");
+    N::from_lexical_with_options(bytes, &options)
+}
+
+/// Parse number from string in a given radix.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid.
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn parse_partial_with_radix<N: FromLexicalOptions>(
+    bytes: &[u8],
+    radix: u32,
+) -> Result<(N, usize)>
+where
+    N::ParseOptions: FromRadix,
+{
+    let options = N::ParseOptions::from_radix(radix).expect("Invalid radix.");
+    N::from_lexical_partial_with_options(bytes, &options)
+}
+
+/// Parse integer from string, detecting the radix from its notation.
+///
+/// This method parses the entire string, returning the value and the
+/// radix, or an error if any invalid digits are found during parsing.
+/// The `0x`, `0o` and `0b` prefixes, in either case, are hexadecimal,
+/// octal and binary, and follow the sign, like `-0x1F`. Without a
+/// prefix, an assembler-style `h` suffix is hexadecimal, an `o` or `q`
+/// suffix is octal, and all other digits are decimal. A trailing `h`
+/// is hexadecimal even after a `0b` or `0o`, so `0BAh` is `186`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "power_of_two")] {
+/// assert_eq!(lexical_core::parse_detect_radix::<u8>(b"0xFF"), Ok((255, 16)));
+/// assert_eq!(lexical_core::parse_detect_radix::<i32>(b"-0b101"), Ok((-5, 2)));
+/// assert_eq!(lexical_core::parse_detect_radix::<u16>(b"0FFh"), Ok((255, 16)));
+/// assert_eq!(lexical_core::parse_detect_radix::<u16>(b"17q"), Ok((15, 8)));
+/// assert_eq!(lexical_core::parse_detect_radix::<u16>(b"017"), Ok((17, 10)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn parse_detect_radix<N: Integer>(bytes: &[u8]) -> Result<(N, u32)> {
+    let (value, radix, processed) = atoi::atoi_detect_radix(bytes)?;
+    match processed == bytes.len() {
+        true => Ok((value, radix)),
+        false => Err((ErrorCode::InvalidDigit, processed).into()),
+    }
+}
+
+/// Parse number from string with a number format known at compile time.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The options are
+/// built from the format of the policy `P` on every call, and the
+/// number is parsed like `parse_with_options`, so the format's flags
+/// are still checked at runtime.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the format is invalid.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "format")] {
+/// use lexical_core::JsonPolicy;
+///
+/// assert_eq!(lexical_core::parse_with_policy::<f64, JsonPolicy>(b"1.5e3"), Ok(1500.0));
+/// assert_eq!(lexical_core::parse_with_policy::<u32, JsonPolicy>(b"15"), Ok(15));
+/// assert!(lexical_core::parse_with_policy::<f64, JsonPolicy>(b"+1.5").is_err());
+/// assert!(lexical_core::parse_with_policy::<f64, JsonPolicy>(b"NaN").is_err());
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_with_policy<N: FromLexicalOptions, P: FormatPolicy>(bytes: &[u8]) -> Result<N>
+where
+    N::ParseOptions: FromFormat,
+{
+    let options = N::ParseOptions::from_format(P::FORMAT).expect("Invalid format.");
+    N::from_lexical_with_options(bytes, &options)
+}
+
+/// Parse number from string with a number format known at compile time.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. The options are built
+/// from the format of the policy `P` on every call, and the number
+/// is parsed like `parse_partial_with_options`, so the format's flags
+/// are still checked at runtime.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the format is invalid.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "format")] {
+/// use lexical_core::JsonPolicy;
+///
+/// let parse = lexical_core::parse_partial_with_policy::<f64, JsonPolicy>;
+/// assert_eq!(parse(b"1.5,2"), Ok((1.5, 3)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_with_policy<N: FromLexicalOptions, P: FormatPolicy>(
+    bytes: &[u8],
+) -> Result<(N, usize)>
+where
+    N::ParseOptions: FromFormat,
+{
+    let options = N::ParseOptions::from_format(P::FORMAT).expect("Invalid format.");
+    N::from_lexical_partial_with_options(bytes, &options)
+}
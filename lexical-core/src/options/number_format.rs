@@ -369,6 +369,18 @@ impl NumberFormatV2 {
     /// Number format for a XML literal floating-point number.
     standard!(Self, XML);
 
+    /// Number format for a XML Schema `xs:double` or `xs:float` value.
+    standard!(Self, XML_SCHEMA_DOUBLE);
+
+    /// Number format for a XML Schema `xs:decimal` value.
+    standard!(Self, XML_SCHEMA_DECIMAL);
+
+    /// Number format for a protobuf text format floating-point number.
+    standard!(Self, PROTOBUF_TEXT);
+
+    /// Number format for an ANSI SQL numeric literal.
+    standard!(Self, SQL);
+
     /// Number format for a SQLite literal floating-point number.
     standard!(Self, SQLITE);
 
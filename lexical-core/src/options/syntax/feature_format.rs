@@ -190,10 +190,13 @@ bitflags! {
     /// 38. JSON
     /// 39. TOML
     /// 40. XML
-    /// 41. SQLite
-    /// 42. PostgreSQL
-    /// 43. MySQL
-    /// 44. MongoDB
+    /// 41. SQL
+    /// 42. SQLite
+    /// 43. PostgreSQL
+    /// 44. MySQL
+    /// 45. MongoDB
+    /// 46. XML Schema
+    /// 47. Protocol Buffers Text Format
     #[repr(C)]
     #[repr(align(8))]
     #[derive(Default)]
@@ -1307,6 +1310,36 @@ bitflags! {
             Self::CASE_SENSITIVE_SPECIAL.bits
         );
 
+        // XML SCHEMA DOUBLE [01345678MN]
+        /// Number format for a XML Schema `xs:double` or `xs:float` value.
+        const XML_SCHEMA_DOUBLE = (
+            Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::CASE_SENSITIVE_SPECIAL.bits
+        );
+
+        // XML SCHEMA DECIMAL [013MN]
+        /// Number format for a XML Schema `xs:decimal` value.
+        const XML_SCHEMA_DECIMAL = (
+            Self::NO_EXPONENT_NOTATION.bits
+            | Self::NO_SPECIAL.bits
+        );
+
+        // PROTOBUF TEXT [014567]
+        /// Number format for a protobuf text format floating-point number.
+        const PROTOBUF_TEXT = (
+            Self::NO_POSITIVE_MANTISSA_SIGN.bits
+            | Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::NO_INTEGER_LEADING_ZEROS.bits
+            | Self::NO_FLOAT_LEADING_ZEROS.bits
+        );
+
+        // SQL [013456MN]
+        /// Number format for an ANSI SQL numeric literal.
+        const SQL = (
+            Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::NO_SPECIAL.bits
+        );
+
         // SQLITE [013456MN]
         /// Number format for a SQLite literal floating-point number.
         const SQLITE = (
@@ -1863,6 +1896,10 @@ mod tests {
             SyntaxFormat::TOML,
             SyntaxFormat::YAML,
             SyntaxFormat::XML,
+            SyntaxFormat::XML_SCHEMA_DOUBLE,
+            SyntaxFormat::XML_SCHEMA_DECIMAL,
+            SyntaxFormat::PROTOBUF_TEXT,
+            SyntaxFormat::SQL,
             SyntaxFormat::SQLITE,
             SyntaxFormat::POSTGRESQL,
             SyntaxFormat::MYSQL,
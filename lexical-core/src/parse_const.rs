@@ -0,0 +1,170 @@
+//! Integer parsers that may be evaluated at compile time.
+//!
+//! The parsers are `const fn`, so constants and lookup tables may be
+//! built from string literals without a proc-macro. They only parse
+//! decimal integers with the default options, and agree with `parse`
+//! for these strings, including the error codes and indexes. Requires
+//! loops in const fn, so the module is only compiled with Rustc 1.46.0
+//! or later.
+
+use crate::error::{Error, ErrorCode};
+use crate::result::Result;
+
+/// Parse the decimal digits of an integer, from the start index.
+///
+/// Returns `code` with the index of the digit if the value is larger
+/// than `max`.
+#[inline]
+const fn parse_digits(bytes: &[u8], start: usize, max: u64, code: ErrorCode) -> Result<u64> {
+    if start == bytes.len() {
+        return Err(Error {
+            code: ErrorCode::Empty,
+            index: start,
+        });
+    }
+
+    let mut value: u64 = 0;
+    let mut index = start;
+    while index < bytes.len() {
+        let digit = match bytes[index] {
+            c @ b'0'..=b'9' => (c - b'0') as u64,
+            _ => {
+                return Err(Error {
+                    code: ErrorCode::InvalidDigit,
+                    index,
+                })
+            },
+        };
+        // Checked arithmetic is not const until Rustc 1.47.0.
+        if value > (max - digit) / 10 {
+            return Err(Error {
+                code,
+                index,
+            });
+        }
+        value = value * 10 + digit;
+        index += 1;
+    }
+    Ok(value)
+}
+
+/// Get the index of the first digit, after an optional `+` sign.
+#[inline]
+const fn digits_start(bytes: &[u8]) -> usize {
+    match !bytes.is_empty() && bytes[0] == b'+' {
+        true => 1,
+        false => 0,
+    }
+}
+
+/// Parse a decimal `u64` from string, in a const context.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The string may have
+/// a leading `+` sign, and the result is the same as `parse::<u64>`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{parse_u64_const, Result};
+///
+/// const PORT: Result<u64> = parse_u64_const(b"8080");
+/// const TABLE: [u64; 3] = match (
+///     parse_u64_const(b"10"),
+///     parse_u64_const(b"+100"),
+///     parse_u64_const(b"1000"),
+/// ) {
+///     (Ok(a), Ok(b), Ok(c)) => [a, b, c],
+///     _ => [0; 3],
+/// };
+/// assert_eq!(PORT, Ok(8080));
+/// assert_eq!(TABLE, [10, 100, 1000]);
+/// assert!(parse_u64_const(b"-1").is_err());
+/// ```
+#[inline]
+pub const fn parse_u64_const(bytes: &[u8]) -> Result<u64> {
+    parse_digits(bytes, digits_start(bytes), u64::max_value(), ErrorCode::Overflow)
+}
+
+/// Parse a decimal `i64` from string, in a const context.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The string may have
+/// a leading `+` or `-` sign, and the result is the same as
+/// `parse::<i64>`.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// use lexical_core::{parse_i64_const, Result};
+///
+/// const OFFSET: Result<i64> = parse_i64_const(b"-3600");
+/// assert_eq!(OFFSET, Ok(-3600));
+/// assert!(parse_i64_const(b"9223372036854775808").is_err());
+/// ```
+#[inline]
+pub const fn parse_i64_const(bytes: &[u8]) -> Result<i64> {
+    let max = i64::max_value() as u64;
+    if !bytes.is_empty() && bytes[0] == b'-' {
+        // The magnitude of the minimum value is one larger than the maximum.
+        match parse_digits(bytes, 1, max + 1, ErrorCode::Underflow) {
+            Ok(value) => Ok((value as i64).wrapping_neg()),
+            Err(error) => Err(error),
+        }
+    } else {
+        match parse_digits(bytes, digits_start(bytes), max, ErrorCode::Overflow) {
+            Ok(value) => Ok(value as i64),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const STRINGS: [&[u8]; 16] = [
+        b"",
+        b"+",
+        b"-",
+        b"0",
+        b"-0",
+        b"+12",
+        b"-12",
+        b"12a",
+        b"1_0",
+        b"00001",
+        b"9223372036854775807",
+        b"9223372036854775808",
+        b"-9223372036854775808",
+        b"-9223372036854775809",
+        b"18446744073709551615",
+        b"184467440737095516150",
+    ];
+
+    #[test]
+    fn parse_u64_const_test() {
+        const VALUE: Result<u64> = parse_u64_const(b"18446744073709551615");
+        assert_eq!(VALUE, Ok(u64::max_value()));
+        for &bytes in STRINGS.iter() {
+            assert_eq!(parse_u64_const(bytes), parse::<u64>(bytes));
+        }
+    }
+
+    #[test]
+    fn parse_i64_const_test() {
+        const VALUE: Result<i64> = parse_i64_const(b"-9223372036854775808");
+        assert_eq!(VALUE, Ok(i64::min_value()));
+        for &bytes in STRINGS.iter() {
+            assert_eq!(parse_i64_const(bytes), parse::<i64>(bytes));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Conversion API with the radix as a compile-time constant.
+//!
+//! The radix is a const generic parameter and is validated at compile
+//! time, so an invalid radix fails to build rather than panicking.
+//! The functions are thin wrappers of the `_with_options` functions:
+//! the options are built from the radix on every call, and the digit
+//! loops still take the radix at runtime. Requires const generics, so
+//! the module is only compiled with Rustc 1.51.0 or later.
+//!
+//! ```compile_fail
+//! lexical_core::parse_radix_const::<u32, 37>(b"1");
+//! ```
+
+use crate::util::FromRadix;
+use crate::result::Result;
+use crate::traits::{FromLexicalOptions, ToLexicalOptions};
+
+/// Check if the radix is valid for the enabled features.
+#[inline]
+#[cfg(feature = "radix")]
+const fn is_valid_radix(radix: u32) -> bool {
+    radix >= 2 && radix <= 36
+}
+
+/// Check if the radix is valid for the enabled features.
+#[inline]
+#[cfg(not(feature = "radix"))]
+const fn is_valid_radix(radix: u32) -> bool {
+    radix == 10 || (radix >= 2 && radix <= 32 && radix.is_power_of_two())
+}
+
+/// Radix known at compile time.
+struct Radix<const RADIX: u32>;
+
+impl<const RADIX: u32> Radix<RADIX> {
+    /// The radix, which fails to evaluate if the radix is invalid,
+    /// since the index is out of bounds.
+    const VALID: u32 = [RADIX][!is_valid_radix(RADIX) as usize];
+}
+
+/// Write number to string in a radix known at compile time.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `RADIX`   - Radix for number encoding.
+///
+/// Fails to compile if the radix is invalid.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE` elements.
+///
+/// # Example
+///
+/// ```
+/// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_radix_const::<_, 16>(255u8, &mut buffer), b"FF");
+/// assert_eq!(lexical_core::write_radix_const::<_, 2>(0.5f64, &mut buffer), b"0.1");
+/// ```
+#[inline]
+pub fn write_radix_const<'a, N: ToLexicalOptions, const RADIX: u32>(
+    n: N,
+    bytes: &'a mut [u8],
+) -> &'a mut [u8]
+where
+    N::WriteOptions: FromRadix,
+{
+    let options = N::WriteOptions::from_radix(Radix::<RADIX>::VALID).unwrap();
+    n.to_lexical_with_options(bytes, &options)
+}
+
+/// Parse number from string in a radix known at compile time.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `RADIX`   - Radix for number decoding.
+///
+/// Fails to compile if the radix is invalid.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(lexical_core::parse_radix_const::<u32, 16>(b"FF"), Ok(255));
+/// assert_eq!(lexical_core::parse_radix_const::<f64, 2>(b"0.1"), Ok(0.5));
+/// assert!(lexical_core::parse_radix_const::<u32, 16>(b"FG").is_err());
+/// ```
+#[inline]
+pub fn parse_radix_const<N: FromLexicalOptions, const RADIX: u32>(bytes: &[u8]) -> Result<N>
+where
+    N::ParseOptions: FromRadix,
+{
+    let options = N::ParseOptions::from_radix(Radix::<RADIX>::VALID).unwrap();
+    N::from_lexical_with_options(bytes, &options)
+}
+
+/// Parse number from string in a radix known at compile time.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `RADIX`   - Radix for number decoding.
+///
+/// Fails to compile if the radix is invalid.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(lexical_core::parse_partial_radix_const::<u32, 16>(b"FFz"), Ok((255, 2)));
+/// ```
+#[inline]
+pub fn parse_partial_radix_const<N: FromLexicalOptions, const RADIX: u32>(
+    bytes: &[u8],
+) -> Result<(N, usize)>
+where
+    N::ParseOptions: FromRadix,
+{
+    let options = N::ParseOptions::from_radix(Radix::<RADIX>::VALID).unwrap();
+    N::from_lexical_partial_with_options(bytes, &options)
+}
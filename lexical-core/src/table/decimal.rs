@@ -1,9 +1,10 @@
 //! Cached tables for precalculated values for decimal strings.
 
 use crate::traits::*;
+#[cfg(feature = "floats")]
 use static_assertions::const_assert;
 
-#[cfg(feature = "radix")]
+#[cfg(all(feature = "floats", feature = "radix"))]
 use super::radix::*;
 
 /// Precalculated table for a digit to a character.
@@ -80,8 +81,10 @@ pub(crate) const DIGIT_TO_BASE10_SQUARED: [u8; 200] = [
 // `⌊ precision / log2(radix) ⌋`, where precision does not include
 // the hidden bit.
 //
-// The algorithm for calculating both `exponent_limit` and `mantissa_limit`,
-// in Python, can be done as follows:
+// The `codegen::FloatLayout` type calculates both limits, and generates
+// the implementations, with the codegen feature. The algorithm for
+// calculating both `exponent_limit` and `mantissa_limit`, in Python,
+// can be done as follows:
 //
 // ```python
 // import math
@@ -157,15 +160,46 @@ pub(crate) const DIGIT_TO_BASE10_SQUARED: [u8; 200] = [
 // ```
 
 /// Get exact exponent limit for radix.
+///
+/// The limits are the bounds of the fast path of the parser: a float
+/// is exact if its mantissa is exact and the power of the radix is
+/// within the exponent limit. They are useful to float-formatting
+/// crates and test generators, to know how many digits of a radix
+/// fit exactly in a float.
+///
+/// The radix must be supported by the enabled features: 10 by default,
+/// the powers of two with `power_of_two`, and 2 to 36 with `radix`.
+/// Other radixes give unspecified results, or panic.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::ExactExponent;
+///
+/// # pub fn main() {
+/// // Up to 15 decimal digits are exact, and 10^-22 to 10^22.
+/// assert_eq!(f64::mantissa_limit(10), 15);
+/// assert_eq!(f64::exponent_limit(10), (-22, 22));
+/// assert_eq!(f32::mantissa_limit(10), 7);
+/// assert_eq!(f32::exponent_limit(10), (-10, 10));
+/// # }
+/// ```
+#[cfg(feature = "floats")]
 pub trait ExactExponent {
     /// Get min and max exponent limits (exact) from radix.
+    ///
+    /// For radixes that are not powers of two, the powers of the radix
+    /// in the range are exact, and the range is symmetric.
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32);
 
     /// Get the number of digits that can be shifted from exponent to mantissa.
+    ///
+    /// Any integer with this many digits in the radix is exact.
     fn mantissa_limit<T: Integer>(radix: T) -> i32;
 }
 
-#[cfg(feature = "f16")]
+#[cfg(all(feature = "floats", feature = "f16"))]
 impl ExactExponent for f16 {
     #[inline]
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
@@ -300,7 +334,7 @@ impl ExactExponent for f16 {
     }
 }
 
-#[cfg(feature = "f16")]
+#[cfg(all(feature = "floats", feature = "f16"))]
 impl ExactExponent for bf16 {
     #[inline]
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
@@ -435,6 +469,7 @@ impl ExactExponent for bf16 {
     }
 }
 
+#[cfg(feature = "floats")]
 impl ExactExponent for f32 {
     #[inline]
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
@@ -569,6 +604,7 @@ impl ExactExponent for f32 {
     }
 }
 
+#[cfg(feature = "floats")]
 impl ExactExponent for f64 {
     #[inline]
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
@@ -702,7 +738,7 @@ impl ExactExponent for f64 {
     }
 }
 
-#[cfg(feature = "f128")]
+#[cfg(all(feature = "floats", feature = "f128"))]
 impl ExactExponent for f128 {
     #[inline]
     fn exponent_limit<T: Integer>(radix: T) -> (i32, i32) {
@@ -849,18 +885,40 @@ impl ExactExponent for f128 {
 // TABLE POW
 
 /// Calculate powers using pre-calculated lookup tables.
-/// No error-checking occurs, these methods are not safe.
+///
+/// The powers are exact. No error-checking occurs in release builds:
+/// the exponent must be valid for the float, and the radix must be
+/// supported by the enabled features, otherwise the result is
+/// unspecified, or the call panics.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{ExactExponent, TablePower};
+///
+/// # pub fn main() {
+/// let (_, max) = f64::exponent_limit(10);
+/// assert_eq!(f64::table_pow(10, max), 1e22);
+/// # }
+/// ```
+#[cfg(feature = "floats")]
 pub trait TablePower {
     /// Get power of 2 from exponent.
+    ///
+    /// The exponent must be within `exponent_limit(2)`.
     #[cfg(feature = "power_of_two")]
     fn table_pow2(exponent: i32) -> Self;
 
-    /// Get power of 2 from exponent.
+    /// Get power of radix from exponent.
+    ///
+    /// The radix must not be a power of two, and the exponent must be
+    /// from 0 to the maximum of `exponent_limit(radix)`.
     fn table_pow<T: Integer>(radix: T, exponent: i32) -> Self;
 }
 
 /// Calculate 2^exponent assigned straight from bits.
-#[cfg(feature = "power_of_two")]
+#[cfg(all(feature = "floats", feature = "power_of_two"))]
 macro_rules! bitwise_pow2 {
     ($exponent:ident, $float:ty, $unsigned:ty) => {{
         debug_assert!(
@@ -904,6 +962,7 @@ macro_rules! bitwise_pow2 {
 
 /// Precalculated values of radix**i for i in range [0, arr.len()-1].
 /// Each value can be **exactly** represented as that type.
+#[cfg(feature = "floats")]
 const F32_POW10: [f32; 11] = [
     1.0,
     10.0,
@@ -919,8 +978,10 @@ const F32_POW10: [f32; 11] = [
 ];
 
 // Compile-time guarantees for our tables.
+#[cfg(feature = "floats")]
 const_assert!(F32_POW10[1] / F32_POW10[0] == 10.0);
 
+#[cfg(feature = "floats")]
 impl TablePower for f32 {
     #[inline]
     #[cfg(feature = "power_of_two")]
@@ -982,6 +1043,7 @@ impl TablePower for f32 {
 
 /// Precalculated values of radix**i for i in range [0, arr.len()-1].
 /// Each value can be **exactly** represented as that type.
+#[cfg(feature = "floats")]
 const F64_POW10: [f64; 23] = [
     1.0,
     10.0,
@@ -1009,8 +1071,10 @@ const F64_POW10: [f64; 23] = [
 ];
 
 // Compile-time guarantees for our tables.
+#[cfg(feature = "floats")]
 const_assert!(F64_POW10[1] / F64_POW10[0] == 10.0);
 
+#[cfg(feature = "floats")]
 impl TablePower for f64 {
     #[inline]
     #[cfg(feature = "power_of_two")]
@@ -1070,7 +1134,7 @@ impl TablePower for f64 {
     }
 }
 
-#[cfg(all(test, feature = "power_of_two"))]
+#[cfg(all(test, feature = "floats", feature = "power_of_two"))]
 mod tests {
     use super::*;
 
@@ -1102,4 +1166,17 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[cfg(all(feature = "codegen", feature = "radix"))]
+    fn test_codegen() {
+        use crate::codegen::FloatLayout;
+
+        for radix in 2u32..37 {
+            assert_eq!(f32::exponent_limit(radix), FloatLayout::F32.exponent_limit(radix));
+            assert_eq!(f32::mantissa_limit(radix), FloatLayout::F32.mantissa_limit(radix));
+            assert_eq!(f64::exponent_limit(radix), FloatLayout::F64.exponent_limit(radix));
+            assert_eq!(f64::mantissa_limit(radix), FloatLayout::F64.mantissa_limit(radix));
+        }
+    }
 }
@@ -36,10 +36,15 @@
 
 // Hide modules.
 mod decimal;
+#[cfg(feature = "floats")]
 mod pow;
 
 // Re-export all tables and traits.
+#[cfg(feature = "floats")]
 pub use self::decimal::*;
+#[cfg(not(feature = "floats"))]
+pub(crate) use self::decimal::*;
+#[cfg(feature = "floats")]
 pub use self::pow::*;
 
 cfg_if! {
@@ -307,4 +307,39 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn mantissa_limit_test() {
+        // Every integer with `mantissa_limit` digits is exact, but not
+        // every integer with one more digit.
+        for b in BASE_POWN.iter().cloned() {
+            let radix = b as u128;
+            let limit = f32::mantissa_limit(b) as u32;
+            assert!(radix.pow(limit) <= 1 << (f32::MANTISSA_SIZE + 1));
+            assert!(radix.pow(limit + 1) > 1 << (f32::MANTISSA_SIZE + 1));
+            let limit = f64::mantissa_limit(b) as u32;
+            assert!(radix.pow(limit) <= 1 << (f64::MANTISSA_SIZE + 1));
+            assert!(radix.pow(limit + 1) > 1 << (f64::MANTISSA_SIZE + 1));
+        }
+    }
+
+    #[test]
+    fn table_pow_test() {
+        // Compare to the odd part of the power, which is an exact
+        // integer, scaled by the power of two.
+        for b in BASE_POWN.iter().cloned() {
+            let shift = b.trailing_zeros() as i32;
+            let odd = (b >> shift) as u64;
+            let (_, max) = f32::exponent_limit(b);
+            for i in 0..max + 1 {
+                let expected = odd.pow(i as u32) as f32 * 2f32.powi(shift * i);
+                assert_eq!(f32::table_pow(b, i), expected);
+            }
+            let (_, max) = f64::exponent_limit(b);
+            for i in 0..max + 1 {
+                let expected = odd.pow(i as u32) as f64 * 2f64.powi(shift * i);
+                assert_eq!(f64::table_pow(b, i), expected);
+            }
+        }
+    }
 }
@@ -2,6 +2,7 @@
 
 #![cfg(feature = "radix")]
 
+#[cfg(feature = "floats")]
 use static_assertions::const_assert;
 
 // RADIX^2 TABLES
@@ -1930,12 +1931,15 @@ pub(crate) const DIGIT_TO_BASE36_SQUARED: [u8; 2592] = [
 // F32
 // ---
 
+#[cfg(feature = "floats")]
 pub(super) const F32_POW3: [f32; 16] = [
     1.0, 3.0, 9.0, 27.0, 81.0, 243.0, 729.0, 2187.0, 6561.0, 19683.0, 59049.0, 177147.0, 531441.0,
     1594323.0, 4782969.0, 14348907.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW5: [f32; 11] =
     [1.0, 5.0, 25.0, 125.0, 625.0, 3125.0, 15625.0, 78125.0, 390625.0, 1953125.0, 9765625.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW6: [f32; 16] = [
     1.0,
     6.0,
@@ -1954,10 +1958,14 @@ pub(super) const F32_POW6: [f32; 16] = [
     78364164096.0,
     470184984576.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW7: [f32; 9] =
     [1.0, 7.0, 49.0, 343.0, 2401.0, 16807.0, 117649.0, 823543.0, 5764801.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW9: [f32; 8] = [1.0, 9.0, 81.0, 729.0, 6561.0, 59049.0, 531441.0, 4782969.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW11: [f32; 7] = [1.0, 11.0, 121.0, 1331.0, 14641.0, 161051.0, 1771561.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW12: [f32; 16] = [
     1.0,
     12.0,
@@ -1976,14 +1984,21 @@ pub(super) const F32_POW12: [f32; 16] = [
     1283918464548864.0,
     15407021574586368.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW13: [f32; 7] = [1.0, 13.0, 169.0, 2197.0, 28561.0, 371293.0, 4826809.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW14: [f32; 9] =
     [1.0, 14.0, 196.0, 2744.0, 38416.0, 537824.0, 7529536.0, 105413504.0, 1475789056.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW15: [f32; 7] = [1.0, 15.0, 225.0, 3375.0, 50625.0, 759375.0, 11390625.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW17: [f32; 6] = [1.0, 17.0, 289.0, 4913.0, 83521.0, 1419857.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW18: [f32; 8] =
     [1.0, 18.0, 324.0, 5832.0, 104976.0, 1889568.0, 34012224.0, 612220032.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW19: [f32; 6] = [1.0, 19.0, 361.0, 6859.0, 130321.0, 2476099.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW20: [f32; 11] = [
     1.0,
     20.0,
@@ -1997,10 +2012,14 @@ pub(super) const F32_POW20: [f32; 11] = [
     512000000000.0,
     10240000000000.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW21: [f32; 6] = [1.0, 21.0, 441.0, 9261.0, 194481.0, 4084101.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW22: [f32; 7] =
     [1.0, 22.0, 484.0, 10648.0, 234256.0, 5153632.0, 113379904.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW23: [f32; 6] = [1.0, 23.0, 529.0, 12167.0, 279841.0, 6436343.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW24: [f32; 16] = [
     1.0,
     24.0,
@@ -2019,55 +2038,96 @@ pub(super) const F32_POW24: [f32; 16] = [
     21035720123168587776.0,
     504857282956046106624.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW25: [f32; 6] = [1.0, 25.0, 625.0, 15625.0, 390625.0, 9765625.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW26: [f32; 7] =
     [1.0, 26.0, 676.0, 17576.0, 456976.0, 11881376.0, 308915776.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW27: [f32; 6] = [1.0, 27.0, 729.0, 19683.0, 531441.0, 14348907.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW28: [f32; 9] =
     [1.0, 28.0, 784.0, 21952.0, 614656.0, 17210368.0, 481890304.0, 13492928512.0, 377801998336.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW29: [f32; 5] = [1.0, 29.0, 841.0, 24389.0, 707281.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW30: [f32; 7] =
     [1.0, 30.0, 900.0, 27000.0, 810000.0, 24300000.0, 729000000.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW31: [f32; 5] = [1.0, 31.0, 961.0, 29791.0, 923521.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW33: [f32; 5] = [1.0, 33.0, 1089.0, 35937.0, 1185921.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW34: [f32; 6] = [1.0, 34.0, 1156.0, 39304.0, 1336336.0, 45435424.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW35: [f32; 5] = [1.0, 35.0, 1225.0, 42875.0, 1500625.0];
+#[cfg(feature = "floats")]
 pub(super) const F32_POW36: [f32; 8] =
     [1.0, 36.0, 1296.0, 46656.0, 1679616.0, 60466176.0, 2176782336.0, 78364164096.0];
 
+#[cfg(feature = "floats")]
 const_assert!(F32_POW3[1] / F32_POW3[0] == 3.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW5[1] / F32_POW5[0] == 5.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW6[1] / F32_POW6[0] == 6.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW7[1] / F32_POW7[0] == 7.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW9[1] / F32_POW9[0] == 9.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW11[1] / F32_POW11[0] == 11.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW12[1] / F32_POW12[0] == 12.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW13[1] / F32_POW13[0] == 13.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW14[1] / F32_POW14[0] == 14.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW15[1] / F32_POW15[0] == 15.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW17[1] / F32_POW17[0] == 17.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW18[1] / F32_POW18[0] == 18.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW19[1] / F32_POW19[0] == 19.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW20[1] / F32_POW20[0] == 20.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW21[1] / F32_POW21[0] == 21.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW22[1] / F32_POW22[0] == 22.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW23[1] / F32_POW23[0] == 23.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW24[1] / F32_POW24[0] == 24.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW25[1] / F32_POW25[0] == 25.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW26[1] / F32_POW26[0] == 26.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW27[1] / F32_POW27[0] == 27.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW28[1] / F32_POW28[0] == 28.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW29[1] / F32_POW29[0] == 29.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW30[1] / F32_POW30[0] == 30.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW31[1] / F32_POW31[0] == 31.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW33[1] / F32_POW33[0] == 33.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW34[1] / F32_POW34[0] == 34.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW35[1] / F32_POW35[0] == 35.0);
+#[cfg(feature = "floats")]
 const_assert!(F32_POW36[1] / F32_POW36[0] == 36.0);
 
 // F64
 // ---
 
+#[cfg(feature = "floats")]
 pub(super) const F64_POW3: [f64; 34] = [
     1.0,
     3.0,
@@ -2104,6 +2164,7 @@ pub(super) const F64_POW3: [f64; 34] = [
     1853020188851841.0,
     5559060566555523.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW5: [f64; 23] = [
     1.0,
     5.0,
@@ -2129,6 +2190,7 @@ pub(super) const F64_POW5: [f64; 23] = [
     476837158203125.0,
     2384185791015625.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW6: [f64; 34] = [
     1.0,
     6.0,
@@ -2165,6 +2227,7 @@ pub(super) const F64_POW6: [f64; 34] = [
     7958661109946400884391936.0,
     47751966659678405306351616.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW7: [f64; 19] = [
     1.0,
     7.0,
@@ -2186,6 +2249,7 @@ pub(super) const F64_POW7: [f64; 19] = [
     232630513987207.0,
     1628413597910449.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW9: [f64; 17] = [
     1.0,
     9.0,
@@ -2205,6 +2269,7 @@ pub(super) const F64_POW9: [f64; 17] = [
     205891132094649.0,
     1853020188851841.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW11: [f64; 16] = [
     1.0,
     11.0,
@@ -2223,6 +2288,7 @@ pub(super) const F64_POW11: [f64; 16] = [
     379749833583241.0,
     4177248169415651.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW12: [f64; 34] = [
     1.0,
     12.0,
@@ -2259,6 +2325,7 @@ pub(super) const F64_POW12: [f64; 34] = [
     34182189187166852111368841966125056.0,
     410186270246002225336426103593500672.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW13: [f64; 15] = [
     1.0,
     13.0,
@@ -2276,6 +2343,7 @@ pub(super) const F64_POW13: [f64; 15] = [
     302875106592253.0,
     3937376385699289.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW14: [f64; 19] = [
     1.0,
     14.0,
@@ -2297,6 +2365,7 @@ pub(super) const F64_POW14: [f64; 19] = [
     30491346729331195904.0,
     426878854210636742656.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW15: [f64; 14] = [
     1.0,
     15.0,
@@ -2313,6 +2382,7 @@ pub(super) const F64_POW15: [f64; 14] = [
     129746337890625.0,
     1946195068359375.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW17: [f64; 13] = [
     1.0,
     17.0,
@@ -2328,6 +2398,7 @@ pub(super) const F64_POW17: [f64; 13] = [
     34271896307633.0,
     582622237229761.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW18: [f64; 17] = [
     1.0,
     18.0,
@@ -2347,6 +2418,7 @@ pub(super) const F64_POW18: [f64; 17] = [
     6746640616477458432.0,
     121439531096594251776.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW19: [f64; 13] = [
     1.0,
     19.0,
@@ -2362,6 +2434,7 @@ pub(super) const F64_POW19: [f64; 13] = [
     116490258898219.0,
     2213314919066161.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW20: [f64; 23] = [
     1.0,
     20.0,
@@ -2387,6 +2460,7 @@ pub(super) const F64_POW20: [f64; 23] = [
     2097152000000000000000000000.0,
     41943040000000000000000000000.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW21: [f64; 13] = [
     1.0,
     21.0,
@@ -2402,6 +2476,7 @@ pub(super) const F64_POW21: [f64; 13] = [
     350277500542221.0,
     7355827511386641.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW22: [f64; 16] = [
     1.0,
     22.0,
@@ -2420,6 +2495,7 @@ pub(super) const F64_POW22: [f64; 16] = [
     6221821273427820544.0,
     136880068015412051968.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW23: [f64; 12] = [
     1.0,
     23.0,
@@ -2434,6 +2510,7 @@ pub(super) const F64_POW23: [f64; 12] = [
     41426511213649.0,
     952809757913927.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW24: [f64; 34] = [
     1.0,
     24.0,
@@ -2470,6 +2547,7 @@ pub(super) const F64_POW24: [f64; 34] = [
     146811384664566452713597726037899455366168576.0,
     3523473231949594865126345424909586928788045824.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW25: [f64; 12] = [
     1.0,
     25.0,
@@ -2484,6 +2562,7 @@ pub(super) const F64_POW25: [f64; 12] = [
     95367431640625.0,
     2384185791015625.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW26: [f64; 15] = [
     1.0,
     26.0,
@@ -2501,6 +2580,7 @@ pub(super) const F64_POW26: [f64; 15] = [
     2481152873203736576.0,
     64509974703297150976.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW27: [f64; 12] = [
     1.0,
     27.0,
@@ -2515,6 +2595,7 @@ pub(super) const F64_POW27: [f64; 12] = [
     205891132094649.0,
     5559060566555523.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW28: [f64; 19] = [
     1.0,
     28.0,
@@ -2536,6 +2617,7 @@ pub(super) const F64_POW28: [f64; 19] = [
     3996561798506898509529088.0,
     111903730358193158266814464.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW29: [f64; 11] = [
     1.0,
     29.0,
@@ -2549,6 +2631,7 @@ pub(super) const F64_POW29: [f64; 11] = [
     14507145975869.0,
     420707233300201.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW30: [f64; 14] = [
     1.0,
     30.0,
@@ -2565,6 +2648,7 @@ pub(super) const F64_POW30: [f64; 14] = [
     531441000000000000.0,
     15943230000000000000.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW31: [f64; 11] = [
     1.0,
     31.0,
@@ -2578,6 +2662,7 @@ pub(super) const F64_POW31: [f64; 11] = [
     26439622160671.0,
     819628286980801.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW33: [f64; 11] = [
     1.0,
     33.0,
@@ -2591,6 +2676,7 @@ pub(super) const F64_POW33: [f64; 11] = [
     46411484401953.0,
     1531578985264449.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW34: [f64; 13] = [
     1.0,
     34.0,
@@ -2606,6 +2692,7 @@ pub(super) const F64_POW34: [f64; 13] = [
     70188843638032384.0,
     2386420683693101056.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW35: [f64; 11] = [
     1.0,
     35.0,
@@ -2619,6 +2706,7 @@ pub(super) const F64_POW35: [f64; 11] = [
     78815638671875.0,
     2758547353515625.0,
 ];
+#[cfg(feature = "floats")]
 pub(super) const F64_POW36: [f64; 17] = [
     1.0,
     36.0,
@@ -2639,32 +2727,61 @@ pub(super) const F64_POW36: [f64; 17] = [
     7958661109946400884391936.0,
 ];
 
+#[cfg(feature = "floats")]
 const_assert!(F64_POW3[1] / F64_POW3[0] == 3.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW5[1] / F64_POW5[0] == 5.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW6[1] / F64_POW6[0] == 6.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW7[1] / F64_POW7[0] == 7.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW9[1] / F64_POW9[0] == 9.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW11[1] / F64_POW11[0] == 11.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW12[1] / F64_POW12[0] == 12.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW13[1] / F64_POW13[0] == 13.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW14[1] / F64_POW14[0] == 14.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW15[1] / F64_POW15[0] == 15.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW17[1] / F64_POW17[0] == 17.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW18[1] / F64_POW18[0] == 18.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW19[1] / F64_POW19[0] == 19.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW20[1] / F64_POW20[0] == 20.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW21[1] / F64_POW21[0] == 21.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW22[1] / F64_POW22[0] == 22.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW23[1] / F64_POW23[0] == 23.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW24[1] / F64_POW24[0] == 24.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW25[1] / F64_POW25[0] == 25.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW26[1] / F64_POW26[0] == 26.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW27[1] / F64_POW27[0] == 27.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW28[1] / F64_POW28[0] == 28.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW29[1] / F64_POW29[0] == 29.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW30[1] / F64_POW30[0] == 30.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW31[1] / F64_POW31[0] == 31.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW33[1] / F64_POW33[0] == 33.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW34[1] / F64_POW34[0] == 34.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW35[1] / F64_POW35[0] == 35.0);
+#[cfg(feature = "floats")]
 const_assert!(F64_POW36[1] / F64_POW36[0] == 36.0);
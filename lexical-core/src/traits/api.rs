@@ -6,10 +6,56 @@
 
 use super::num::Number;
 
+#[cfg(feature = "floats")]
+use crate::atof::FloatParser;
+use crate::error::{Error, ErrorCode};
+use crate::lib::num;
 use crate::result::Result;
+use crate::util::{
+    DecimalDigits, Exactness, FloatPart, IntegerOverflow, NumberFormat, ParseInfo, ParseMetadata,
+    Sign,
+};
 
 // HELPERS
 
+/// Report invalid digits and empty components at a digit separator
+/// as a misplaced digit separator.
+///
+/// Valid digit separators are always processed, so a digit separator
+/// at the error index was found in an invalid position.
+#[inline]
+#[cfg(feature = "format")]
+pub(crate) fn digit_separator_error(bytes: &[u8], error: Error, format: NumberFormat) -> Error {
+    let has_separator = format.intersects(NumberFormat::DIGIT_SEPARATOR_FLAG_MASK);
+    let is_separator = has_separator && bytes.get(error.index) == Some(&format.digit_separator());
+    match error.code {
+        ErrorCode::InvalidDigit
+        | ErrorCode::EmptyMantissa
+        | ErrorCode::EmptyInteger
+        | ErrorCode::EmptyFraction
+        | ErrorCode::EmptyExponent
+            if is_separator =>
+        {
+            (ErrorCode::InvalidDigitSeparator, error.index).into()
+        },
+        _ => error,
+    }
+}
+
+/// Report invalid digits and empty components at a digit separator
+/// as a misplaced digit separator.
+#[inline(always)]
+#[cfg(not(feature = "format"))]
+pub(crate) fn digit_separator_error(_: &[u8], error: Error, _: NumberFormat) -> Error {
+    error
+}
+
+/// Get the error for a string that was not completely parsed.
+#[inline]
+pub(crate) fn incomplete_error(bytes: &[u8], processed: usize, format: NumberFormat) -> Error {
+    digit_separator_error(bytes, (ErrorCode::InvalidDigit, processed).into(), format)
+}
+
 /// Map partial result to complete result.
 macro_rules! to_complete {
     ($cb:expr, $bytes:expr $(,$args:expr)*) => {
@@ -18,7 +64,7 @@ macro_rules! to_complete {
             Ok((value, processed))  => if processed == $bytes.len() {
                 Ok(value)
             } else{
-                Err((crate::ErrorCode::InvalidDigit, processed).into())
+                Err(($crate::ErrorCode::InvalidDigit, processed).into())
             }
         }
     };
@@ -124,14 +170,352 @@ macro_rules! from_lexical_with_options {
             fn from_lexical_with_options(bytes: &[u8], options: &Self::ParseOptions)
                 -> Result<Self>
             {
-                to_complete!($cb, bytes, options)
+                let format = options.number_format();
+                match $cb(bytes, options) {
+                    Err(e)                  => Err($crate::traits::digit_separator_error(bytes, e, format)),
+                    Ok((value, processed))  => if processed == bytes.len() {
+                        Ok(value)
+                    } else {
+                        Err($crate::traits::incomplete_error(bytes, processed, format))
+                    }
+                }
             }
 
             $(#[$meta:meta])?
             fn from_lexical_partial_with_options(bytes: &[u8], options: &Self::ParseOptions)
                 -> Result<($t, usize)>
             {
-                $cb(bytes, options)
+                let format = options.number_format();
+                $cb(bytes, options).map_err(|e| $crate::traits::digit_separator_error(bytes, e, format))
+            }
+        }
+    )
+}
+
+// FROM LEXICAL WITH METADATA
+
+/// Trait for numbers that can be parsed while reporting parse metadata.
+pub trait FromLexicalMetadata: FromLexicalOptions {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, like
+    /// `from_lexical_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value and
+    /// metadata describing how it was parsed, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_with_metadata(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> Result<(Self, ParseMetadata)>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), like `from_lexical_partial_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value, the
+    /// number of processed digits, and metadata describing how the
+    /// value was parsed, or an error containing any errors that
+    /// occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_partial_with_metadata(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> Result<(Self, usize, ParseMetadata)>;
+}
+
+// Implement FromLexicalMetadata for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! from_lexical_with_metadata {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl FromLexicalMetadata for $t {
+            $(#[$meta:meta])?
+            fn from_lexical_with_metadata(bytes: &[u8], options: &Self::ParseOptions)
+                -> Result<($t, ParseMetadata)>
+            {
+                let format = options.number_format();
+                match $cb(bytes, options) {
+                    Err(e)                          => Err($crate::traits::digit_separator_error(bytes, e, format)),
+                    Ok((value, processed, metadata)) => if processed == bytes.len() {
+                        Ok((value, metadata))
+                    } else {
+                        Err($crate::traits::incomplete_error(bytes, processed, format))
+                    }
+                }
+            }
+
+            $(#[$meta:meta])?
+            fn from_lexical_partial_with_metadata(bytes: &[u8], options: &Self::ParseOptions)
+                -> Result<($t, usize, ParseMetadata)>
+            {
+                let format = options.number_format();
+                $cb(bytes, options).map_err(|e| $crate::traits::digit_separator_error(bytes, e, format))
+            }
+        }
+    )
+}
+
+// FROM LEXICAL WITH INFO
+
+/// Trait for floats that can be parsed while reporting digit statistics.
+pub trait FromLexicalInfo: FromLexicalOptions {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, like
+    /// `from_lexical_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value and
+    /// statistics of its digits, or an error containing any errors
+    /// that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_with_info(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> Result<(Self, ParseInfo)>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), like `from_lexical_partial_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value, the
+    /// number of processed digits, and statistics of its digits, or
+    /// an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_partial_with_info(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> Result<(Self, usize, ParseInfo)>;
+}
+
+// Implement FromLexicalInfo for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! from_lexical_with_info {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl FromLexicalInfo for $t {
+            $(#[$meta:meta])?
+            fn from_lexical_with_info(bytes: &[u8], options: &Self::ParseOptions)
+                -> Result<($t, ParseInfo)>
+            {
+                let format = options.number_format();
+                match $cb(bytes, options) {
+                    Err(e)                      => Err($crate::traits::digit_separator_error(bytes, e, format)),
+                    Ok((value, processed, info)) => if processed == bytes.len() {
+                        Ok((value, info))
+                    } else {
+                        Err($crate::traits::incomplete_error(bytes, processed, format))
+                    }
+                }
+            }
+
+            $(#[$meta:meta])?
+            fn from_lexical_partial_with_info(bytes: &[u8], options: &Self::ParseOptions)
+                -> Result<($t, usize, ParseInfo)>
+            {
+                let format = options.number_format();
+                $cb(bytes, options).map_err(|e| $crate::traits::digit_separator_error(bytes, e, format))
+            }
+        }
+    )
+}
+
+// FROM LEXICAL WITH OVERFLOW
+
+/// Trait for integers that can be parsed while reporting overflow context.
+pub trait FromLexicalOverflow: FromLexicalOptions {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, like
+    /// `from_lexical_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value, or an
+    /// error containing any errors that occurred during parsing. If
+    /// the integer overflowed or underflowed its type, the error is
+    /// paired with the magnitude of the integer.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_with_overflow(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> core::result::Result<Self, (Error, Option<IntegerOverflow>)>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), like `from_lexical_partial_with_options`.
+    ///
+    /// Returns a `Result` containing either the parsed value and the
+    /// number of processed digits, or an error containing any errors
+    /// that occurred during parsing. If the integer overflowed or
+    /// underflowed its type, the error is paired with the magnitude
+    /// of the integer.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to dictate number parsing.
+    fn from_lexical_partial_with_overflow(
+        bytes: &[u8],
+        options: &Self::ParseOptions,
+    ) -> core::result::Result<(Self, usize), (Error, Option<IntegerOverflow>)>;
+}
+
+// Implement FromLexicalOverflow for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! from_lexical_with_overflow {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl FromLexicalOverflow for $t {
+            $(#[$meta:meta])?
+            fn from_lexical_with_overflow(bytes: &[u8], options: &Self::ParseOptions)
+                -> core::result::Result<$t, (Error, Option<IntegerOverflow>)>
+            {
+                let format = options.number_format();
+                match $cb(bytes, options) {
+                    Err((e, overflow))      => Err(($crate::traits::digit_separator_error(bytes, e, format), overflow)),
+                    Ok((value, processed))  => if processed == bytes.len() {
+                        Ok(value)
+                    } else {
+                        Err(($crate::traits::incomplete_error(bytes, processed, format), None))
+                    }
+                }
+            }
+
+            $(#[$meta:meta])?
+            fn from_lexical_partial_with_overflow(bytes: &[u8], options: &Self::ParseOptions)
+                -> core::result::Result<($t, usize), (Error, Option<IntegerOverflow>)>
+            {
+                let format = options.number_format();
+                $cb(bytes, options).map_err(|(e, overflow)| {
+                    ($crate::traits::digit_separator_error(bytes, e, format), overflow)
+                })
+            }
+        }
+    )
+}
+
+// FROM PARTS
+
+/// Trait for floats that can be created from pre-tokenized components.
+pub trait FromParts: FromLexicalOptions {
+    /// Exact conversion from the components of a float.
+    ///
+    /// Uses the same correct algorithm as the string parsers, so
+    /// callers who have already tokenized a number do not need
+    /// to serialize it back to a string.
+    ///
+    /// Returns a `Result` containing either the converted value,
+    /// or an error if any digits are invalid. Error indexes are
+    /// relative to the integer digits followed by the fraction digits.
+    ///
+    /// * `sign`        - Sign of the float.
+    /// * `integer`     - Digits preceding the decimal point.
+    /// * `fraction`    - Digits following the decimal point.
+    /// * `exponent`    - Power of the radix to scale the digits by.
+    /// * `options`     - Options to dictate number parsing.
+    fn from_parts(
+        sign: Sign,
+        integer: &[u8],
+        fraction: &[u8],
+        exponent: i32,
+        options: &Self::ParseOptions,
+    ) -> Result<Self>;
+}
+
+// Implement FromParts for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! from_parts {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl FromParts for $t {
+            $(#[$meta:meta])?
+            fn from_parts(
+                sign: Sign,
+                integer: &[u8],
+                fraction: &[u8],
+                exponent: i32,
+                options: &Self::ParseOptions
+            ) -> Result<$t>
+            {
+                $cb(sign, integer, fraction, exponent, options)
+            }
+        }
+    )
+}
+
+// FROM LEXICAL WITH PARSER
+
+/// Trait for floats that can be parsed with a reusable `FloatParser`.
+#[cfg(feature = "floats")]
+pub trait FromLexicalParser: FromLexicalOptions {
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, like
+    /// `from_lexical_with_options` with the options of the parser.
+    /// The big integers of the slow path use the scratch space of
+    /// the parser.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `parser`  - Parser with the options and the scratch space.
+    fn from_lexical_with_parser(bytes: &[u8], parser: &mut FloatParser) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), like `from_lexical_partial_with_options` with
+    /// the options of the parser. The big integers of the slow path
+    /// use the scratch space of the parser.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `parser`  - Parser with the options and the scratch space.
+    fn from_lexical_partial_with_parser(
+        bytes: &[u8],
+        parser: &mut FloatParser,
+    ) -> Result<(Self, usize)>;
+}
+
+// Implement FromLexicalParser for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! from_lexical_with_parser {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl FromLexicalParser for $t {
+            $(#[$meta:meta])?
+            fn from_lexical_with_parser(bytes: &[u8], parser: &mut FloatParser)
+                -> Result<$t>
+            {
+                let format = parser.options().number_format();
+                match $cb(bytes, parser) {
+                    Err(e)                  => Err($crate::traits::digit_separator_error(bytes, e, format)),
+                    Ok((value, processed))  => if processed == bytes.len() {
+                        Ok(value)
+                    } else {
+                        Err($crate::traits::incomplete_error(bytes, processed, format))
+                    }
+                }
+            }
+
+            $(#[$meta:meta])?
+            fn from_lexical_partial_with_parser(bytes: &[u8], parser: &mut FloatParser)
+                -> Result<($t, usize)>
+            {
+                let format = parser.options().number_format();
+                $cb(bytes, parser).map_err(|e| $crate::traits::digit_separator_error(bytes, e, format))
             }
         }
     )
@@ -141,14 +525,18 @@ macro_rules! from_lexical_with_options {
 
 /// Trait for numerical types that can be serialized to bytes.
 ///
+/// Implemented for the primitive numbers, and forwarded for references
+/// to them and for the `Wrapping` and `Saturating` wrappers, so generic
+/// code does not need to dereference or unwrap values.
+///
 /// To determine the number of bytes required to serialize a value to
-/// string, check the associated constants from a required trait:
+/// string, check the associated constants of the inner `Number`:
 /// - [`FORMATTED_SIZE`]
 /// - [`FORMATTED_SIZE_DECIMAL`]
 ///
 /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
 /// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
-pub trait ToLexical: Number {
+pub trait ToLexical: Sized {
     /// Serializer for a number-to-string conversion.
     ///
     /// Returns a subslice of the input buffer containing the written bytes,
@@ -186,10 +574,35 @@ macro_rules! to_lexical {
     )
 }
 
+// Forward ToLexical to the referenced number.
+impl<'b, T: ToLexical + Copy> ToLexical for &'b T {
+    #[inline]
+    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        (*self).to_lexical(bytes)
+    }
+}
+
+// Forward ToLexical to the wrapped number.
+impl<T: ToLexical> ToLexical for num::Wrapping<T> {
+    #[inline]
+    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        self.0.to_lexical(bytes)
+    }
+}
+
+// Forward ToLexical to the saturated number.
+#[cfg(has_saturating)]
+impl<T: ToLexical> ToLexical for num::Saturating<T> {
+    #[inline]
+    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        self.0.to_lexical(bytes)
+    }
+}
+
 // TO LEXICAL WITH OPTIONS
 
 /// Trait for numerical types that can be serialized to bytes with custom options.
-pub trait ToLexicalOptions: ToLexical {
+pub trait ToLexicalOptions: ToLexical + Number {
     /// Serializer for a number-to-string conversion.
     ///
     /// Returns a subslice of the input buffer containing the written bytes,
@@ -204,30 +617,265 @@ pub trait ToLexicalOptions: ToLexical {
     /// Also panics if the buffer is not of sufficient size. The caller
     /// must provide a slice of sufficient size. In order to ensure
     /// the function will not panic, ensure the buffer has at least
-    /// [`FORMATTED_SIZE`] elements.
+    /// [`formatted_size`] elements for the options.
     ///
-    /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
+    /// [`formatted_size`]: #tymethod.formatted_size
     fn to_lexical_with_options<'a>(
         self,
         bytes: &'a mut [u8],
         options: &Self::WriteOptions,
     ) -> &'a mut [u8];
+
+    /// Get the number of bytes required to serialize any value with the options.
+    ///
+    /// The size is computed from the options, such as the radix, the
+    /// float layout and the special strings, rather than the worst case
+    /// for any options, so buffers can be allocated tightly. For example,
+    /// decimal floats with the default options only require a few dozen
+    /// bytes, while positional layouts of large exponents may require
+    /// hundreds of bytes.
+    ///
+    /// Buffers smaller than this size are only rejected if they are also
+    /// smaller than [`FORMATTED_SIZE`], or [`FORMATTED_SIZE_DECIMAL`]
+    /// for decimal options: larger layouts check the buffer is large
+    /// enough for each value they write.
+    ///
+    /// * `options` - Options for number formatting.
+    ///
+    /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
+    /// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+    fn formatted_size(options: &Self::WriteOptions) -> usize;
 }
 
 // Implement ToLexicalOptions for numeric type.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! to_lexical_with_options {
-    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+    ($cb:expr, $size:expr, $t:ty $(, #[$meta:meta])?) => (
         impl ToLexicalOptions for $t {
             $(#[$meta:meta])?
             fn to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &Self::WriteOptions)
                 -> &'a mut [u8]
             {
-                assert_buffer!(options.radix(), bytes, $t);
+                assert_buffer!(options.radix(), bytes, $t, Self::formatted_size(options));
                 let len = $cb(self, bytes, options);
                 &mut bytes[..len]
             }
+
+            $(#[$meta:meta])?
+            fn formatted_size(options: &Self::WriteOptions) -> usize {
+                $size(options)
+            }
+        }
+    )
+}
+
+// TRY TO LEXICAL WITH OPTIONS
+
+/// Trait for floats that may not be serializable with the custom options.
+pub trait TryToLexicalOptions: ToLexicalOptions {
+    /// Fallible serializer for a number-to-string conversion.
+    ///
+    /// Writes the same bytes as `to_lexical_with_options`, but returns
+    /// an error rather than panicking if the float cannot be written
+    /// in the maximum width of the options.
+    ///
+    /// Returns a `Result` containing either a subslice of the input
+    /// buffer containing the written bytes, starting from the same
+    /// address in memory as the input slice, or an error with the
+    /// maximum width as the index.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options for number formatting.
+    /// * `bytes`   - Buffer to write number to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size, like
+    /// `to_lexical_with_options`.
+    fn try_to_lexical_with_options<'a>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::WriteOptions,
+    ) -> Result<&'a mut [u8]>;
+}
+
+// Implement TryToLexicalOptions for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! try_to_lexical_with_options {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl TryToLexicalOptions for $t {
+            $(#[$meta:meta])?
+            fn try_to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &Self::WriteOptions)
+                -> Result<&'a mut [u8]>
+            {
+                assert_buffer!(options.radix(), bytes, $t, Self::formatted_size(options));
+                let len = $cb(self, bytes, options)?;
+                Ok(&mut bytes[..len])
+            }
+        }
+    )
+}
+
+// TO LEXICAL WITH INFO
+
+/// Trait for floats that can be serialized while reporting their exactness.
+pub trait ToLexicalInfo: ToLexicalOptions {
+    /// Serializer for a number-to-string conversion.
+    ///
+    /// Writes the same bytes as `to_lexical_with_options`, and checks if
+    /// the written digits exactly represent the float, or are a rounding
+    /// of it. Special values, such as NaN and infinity, are always exact.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice, and
+    /// the exactness of the written digits.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options for number formatting.
+    /// * `bytes`   - Buffer to write number to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size, like
+    /// `to_lexical_with_options`.
+    fn to_lexical_with_info<'a>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::WriteOptions,
+    ) -> (&'a mut [u8], Exactness);
+}
+
+// Implement ToLexicalInfo for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! to_lexical_with_info {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl ToLexicalInfo for $t {
+            $(#[$meta:meta])?
+            fn to_lexical_with_info<'a>(self, bytes: &'a mut [u8], options: &Self::WriteOptions)
+                -> (&'a mut [u8], Exactness)
+            {
+                assert_buffer!(options.radix(), bytes, $t, Self::formatted_size(options));
+                let (len, exactness) = $cb(self, bytes, options);
+                (&mut bytes[..len], exactness)
+            }
+        }
+    )
+}
+
+// TO LEXICAL WITH PARTS
+
+/// Trait for floats that can be serialized while visiting their parts.
+pub trait ToLexicalParts: ToLexicalOptions {
+    /// Serializer for a number-to-string conversion.
+    ///
+    /// Writes the same bytes as `to_lexical_with_options`, and invokes
+    /// the callback for each structural part of the written bytes, in
+    /// order, such as the sign, the integer digits, the decimal point
+    /// and the exponent. Empty parts are skipped.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice.
+    ///
+    /// * `value`       - Number to serialize.
+    /// * `options`     - Options for number formatting.
+    /// * `bytes`       - Buffer to write number to.
+    /// * `callback`    - Callback invoked with each part and its bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size, like
+    /// `to_lexical_with_options`.
+    fn to_lexical_with_parts<'a, Cb: FnMut(FloatPart, &[u8])>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::WriteOptions,
+        callback: Cb,
+    ) -> &'a mut [u8];
+}
+
+// Implement ToLexicalParts for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! to_lexical_with_parts {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl ToLexicalParts for $t {
+            $(#[$meta:meta])?
+            fn to_lexical_with_parts<'a, Cb: FnMut(FloatPart, &[u8])>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::WriteOptions,
+                callback: Cb
+            )
+                -> &'a mut [u8]
+            {
+                assert_buffer!(options.radix(), bytes, $t, Self::formatted_size(options));
+                let len = $cb(self, bytes, options, callback);
+                &mut bytes[..len]
+            }
+        }
+    )
+}
+
+// TO LEXICAL SIZE HINT
+
+/// Trait for numerical types that can bound the bytes written for a value.
+pub trait ToLexicalSizeHint: ToLexicalOptions {
+    /// Get the number of bytes required to serialize the value with the options.
+    ///
+    /// Unlike [`formatted_size`], which bounds the size of any value,
+    /// the size hint is for this value, so arena and bump allocators
+    /// can reserve the bytes it needs exactly. Writing still requires
+    /// a buffer of [`formatted_size`] elements.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options for number formatting.
+    ///
+    /// [`formatted_size`]: trait.ToLexicalOptions.html#tymethod.formatted_size
+    fn size_hint(self, options: &Self::WriteOptions) -> usize;
+}
+
+// Implement ToLexicalSizeHint for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! to_lexical_size_hint {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl ToLexicalSizeHint for $t {
+            $(#[$meta:meta])?
+            fn size_hint(self, options: &Self::WriteOptions) -> usize {
+                $cb(self, options)
+            }
+        }
+    )
+}
+
+// TO DECIMAL DIGITS
+
+/// Trait for floats that can generate their shortest decimal digits.
+pub trait ToDecimalDigits: ToLexical {
+    /// Generate the shortest digits that round-trip to the float.
+    ///
+    /// Uses the same digit-generation algorithm as the float writer,
+    /// so custom formatters can lay out the digits without parsing
+    /// the written string. The sign is ignored.
+    ///
+    /// Returns `None` if the float is NaN or infinite.
+    fn to_decimal_digits(self) -> Option<DecimalDigits>;
+}
+
+// Implement ToDecimalDigits for numeric type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! to_decimal_digits {
+    ($cb:expr, $t:ty $(, #[$meta:meta])?) => (
+        impl ToDecimalDigits for $t {
+            $(#[$meta:meta])?
+            fn to_decimal_digits(self) -> Option<DecimalDigits>
+            {
+                $cb(self)
+            }
         }
     )
 }
@@ -6,21 +6,51 @@
 
 use crate::config::*;
 use crate::lib::{f32, f64, fmt, iter, mem, ops};
+#[cfg(feature = "floats")]
 use crate::options::*;
 use crate::util::*;
 
 use super::cast::{AsCast, TryCast};
 use super::primitive::Primitive;
+#[cfg(feature = "floats")]
 use super::sequence::CloneableVecLike;
 
-#[cfg(any(not(feature = "no_alloc"), feature = "f128", feature = "radix"))]
+#[cfg(all(
+    feature = "floats",
+    any(not(feature = "no_alloc"), feature = "heap_bignum", feature = "f128", feature = "radix")
+))]
 use crate::lib::Vec;
 
 // NUMBER
 // ------
 
 /// Numerical type trait.
-#[doc(hidden)]
+///
+/// Required for all types that implement `FromLexical` or `ToLexical`,
+/// providing the buffer sizes and the options types. Use it, `Integer`
+/// or `Float` as bounds for code generic over the types lexical parses
+/// and writes.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{Number, ToLexical};
+///
+/// # pub fn main() {
+/// // Write any number to a vector, sized for the decimal string.
+/// fn to_vec<N: Number + ToLexical>(n: N) -> Vec<u8> {
+///     let mut buffer = vec![0u8; N::FORMATTED_SIZE_DECIMAL];
+///     let len = n.to_lexical(&mut buffer).len();
+///     buffer.truncate(len);
+///     buffer
+/// }
+///
+/// assert_eq!(to_vec(-128i8), b"-128");
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(to_vec(1.5f32), b"1.5");
+/// # }
+/// ```
 pub trait Number:
     Primitive +
     IsSigned +
@@ -44,7 +74,10 @@ pub trait Number:
     const FORMATTED_SIZE_DECIMAL: usize;
 
     // OPTIONS
+
+    /// Options type used to write the number.
     type WriteOptions;
+    /// Options type used to parse the number.
     type ParseOptions;
 }
 
@@ -653,8 +686,25 @@ unsigned_integer_impl! { u8 u16 u32 u64 u128 usize }
 // FLOAT
 // -----
 
-/// Float information for native float types.
-#[doc(hidden)]
+/// Float information for binary floating-point types.
+///
+/// Describes the IEEE754-like bit layout of a float, a sign bit, followed
+/// by the biased exponent and the mantissa without the hidden bit, and
+/// provides helpers to decompose the float from the layout, such as
+/// `exponent`, `mantissa`, and `next`.
+///
+/// # Custom Float Types
+///
+/// The parsing and writing algorithms are only implemented for `f32`
+/// and `f64`, so types outside lexical cannot use them directly. Instead,
+/// a custom float implements `FromLexical` and `ToLexical` by converting
+/// to and from a native float, and implementing `Float` allows the
+/// conversions to use the layout, rather than hard-coded bit masks.
+/// `Float` requires `Number`, which requires the operators and the
+/// `Primitive` conversions, so the type must also implement those.
+///
+/// The `binary16` example in the lexical-core repository implements
+/// each of these traits for a software half-precision float.
 pub trait Float: Number + ops::Neg<Output = Self> {
     /// Unsigned type of the same size.
     type Unsigned: UnsignedInteger;
@@ -665,42 +715,25 @@ pub trait Float: Number + ops::Neg<Output = Self> {
     /// although for implementation reasons, it may be larger.
     type Mantissa: UnsignedInteger;
 
-    /// Number of limbs in a Bigint.
-    ///
-    /// This number is somewhat arbitrary, but needs
-    /// to be at least the number of bits required to store
-    /// a Bigint, which is log2(10) * digits, adjusted to the limb size.
-    ///
-    /// Since we reserve at least 20 digits in the default constructor,
-    /// this must be at least 20. This constant is mostly present
-    /// to ensure BigintStorage is correct.
-    const BIGINT_LIMBS: usize;
-
-    /// Number of limbs in a Bigfloat.
-    ///
-    /// This number is somewhat arbitrary, but needs
-    /// to be at least the number of bits required to store
-    /// a Bigfloat, which is log2(10) * digits, adjusted to the limb size.
-    ///
-    /// Since we reserve at least 10 digits in the default constructor,
-    /// this must be at least 10. This constant is mostly present
-    /// to ensure BigfloatStorage is correct.
-    const BIGFLOAT_LIMBS: usize;
-
-    /// The storage type for the Bigint.
-    type BigintStorage: CloneableVecLike<Limb>;
-    /// The storage type for the Bigfloat.
-    type BigfloatStorage: CloneableVecLike<Limb>;
-
     // CONSTANTS
+
+    /// Positive zero.
     const ZERO: Self;
+    /// One.
     const ONE: Self;
+    /// Two.
     const TWO: Self;
+    /// Largest finite value.
     const MAX: Self;
+    /// Smallest finite value.
     const MIN: Self;
+    /// Positive infinity.
     const INFINITY: Self;
+    /// Negative infinity.
     const NEG_INFINITY: Self;
+    /// Quiet NaN.
     const NAN: Self;
+    /// Number of bits in the float.
     const BITS: usize;
 
     /// Bitmask for the sign bit.
@@ -725,7 +758,7 @@ pub trait Float: Number + ops::Neg<Output = Self> {
 
     /// Positive infinity as bits.
     const INFINITY_BITS: Self::Unsigned;
-    /// Positive infinity as bits.
+    /// Negative infinity as bits.
     const NEGATIVE_INFINITY_BITS: Self::Unsigned;
     /// Size of the exponent.
     const EXPONENT_SIZE: i32;
@@ -741,17 +774,30 @@ pub trait Float: Number + ops::Neg<Output = Self> {
     // FUNCTIONS (INHERITED)
 
     // Re-export the to and from bits methods.
+
+    /// Get the absolute value.
     fn abs(self) -> Self;
+    /// Round towards positive infinity.
     fn ceil(self) -> Self;
+    /// Get `e^self`.
     fn exp(self) -> Self;
+    /// Round towards negative infinity.
     fn floor(self) -> Self;
+    /// Get the natural logarithm.
     fn ln(self) -> Self;
+    /// Raise to an integer power.
     fn powi(self, n: i32) -> Self;
+    /// Raise to a float power.
     fn powf(self, f: Self) -> Self;
+    /// Round to the nearest integer, with halfway cases away from zero.
     fn round(self) -> Self;
+    /// Get the raw bits of the float.
     fn to_bits(self) -> Self::Unsigned;
+    /// Create the float from raw bits.
     fn from_bits(u: Self::Unsigned) -> Self;
+    /// Returns true if the sign bit is not set.
     fn is_sign_positive(self) -> bool;
+    /// Returns true if the sign bit is set.
     fn is_sign_negative(self) -> bool;
 
     // FUNCTIONS
@@ -960,6 +1006,7 @@ macro_rules! float_masks {
 }
 
 /// Define the limb sizes.
+#[cfg(feature = "floats")]
 macro_rules! float_limbs {
     (
         bigint32_size => $bigint32:literal,
@@ -968,17 +1015,17 @@ macro_rules! float_limbs {
         bigfloat64_size => $bigfloat64:literal,
     ) => (
         cfg_if! {
-        if #[cfg(limb_width_64)] {
+        if #[cfg(any(not(feature = "no_alloc"), feature = "heap_bignum", feature = "radix"))] {
+        } else if #[cfg(limb_width_64)] {
             const BIGINT_LIMBS: usize = $bigint64;
-            const BIGFLOAT_LIMBS: usize = $bigfloat64;
         } else {
             const BIGINT_LIMBS: usize = $bigint32;
-            const BIGFLOAT_LIMBS: usize = $bigfloat64;
         }} // cfg_if
     );
 }
 
 /// Define the float storage types.
+#[cfg(feature = "floats")]
 macro_rules! float_storage {
     (
         bigint32_size => $bigint32:literal,
@@ -987,7 +1034,7 @@ macro_rules! float_storage {
         bigfloat64_size => $bigfloat64:literal,
     ) => (
         cfg_if! {
-        if #[cfg(any(not(feature = "no_alloc"), feature = "radix"))] {
+        if #[cfg(any(not(feature = "no_alloc"), feature = "heap_bignum", feature = "radix"))] {
             type BigintStorage = Vec<Limb>;
         } else if #[cfg(limb_width_64)] {
             type BigintStorage = arrayvec::ArrayVec<[Limb; $bigint64]>;
@@ -996,12 +1043,12 @@ macro_rules! float_storage {
         }} // cfg_if
 
         cfg_if! {
-        if #[cfg(not(feature = "no_alloc"))] {
+        if #[cfg(any(not(feature = "no_alloc"), feature = "heap_bignum"))] {
             type BigfloatStorage = Vec<Limb>;
         } else if #[cfg(limb_width_64)] {
             type BigfloatStorage = arrayvec::ArrayVec<[Limb; $bigfloat64]>;
         } else {
-            type BigfloatStorage = arrayvec::ArrayVec<[Limb; $bigfloat64]>;
+            type BigfloatStorage = arrayvec::ArrayVec<[Limb; $bigfloat32]>;
         }} // cfg_if
 
         float_limbs!(
@@ -1037,13 +1084,6 @@ impl Float for f16 {
     const EXPONENT_BIAS: i32 = 15 + Self::MANTISSA_SIZE;
     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
     const MAX_EXPONENT: i32 = 0x1F - Self::EXPONENT_BIAS;
-
-    float_storage!(
-        bigint32_size => 20,
-        bigint64_size => 20,
-        bigfloat32_size => 10,
-        bigfloat64_size => 10,
-    );
 }
 
 #[cfg(feature = "f16")]
@@ -1063,13 +1103,6 @@ impl Float for bf16 {
     const EXPONENT_BIAS: i32 = 127 + Self::MANTISSA_SIZE;
     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
     const MAX_EXPONENT: i32 = 0xFF - Self::EXPONENT_BIAS;
-
-    float_storage!(
-        bigint32_size => 20,
-        bigint64_size => 20,
-        bigfloat32_size => 20,
-        bigfloat64_size => 10,
-    );
 }
 
 impl Float for f32 {
@@ -1089,13 +1122,6 @@ impl Float for f32 {
     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
     const MAX_EXPONENT: i32 = 0xFF - Self::EXPONENT_BIAS;
 
-    float_storage!(
-        bigint32_size => 20,
-        bigint64_size => 20,
-        bigfloat32_size => 20,
-        bigfloat64_size => 10,
-    );
-
     #[inline]
     fn abs(self) -> f32 {
         float_method!(self, f32, abs, fabsf)
@@ -1179,13 +1205,6 @@ impl Float for f64 {
     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
     const MAX_EXPONENT: i32 = 0x7FF - Self::EXPONENT_BIAS;
 
-    float_storage!(
-        bigint32_size => 128,
-        bigint64_size => 64,
-        bigfloat32_size => 36,
-        bigfloat64_size => 20,
-    );
-
     #[inline]
     fn abs(self) -> f64 {
         float_method!(self, f64, abs, fabs)
@@ -1269,7 +1288,87 @@ impl Float for f128 {
     const EXPONENT_BIAS: i32 = 16383 + Self::MANTISSA_SIZE;
     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
     const MAX_EXPONENT: i32 = 0x7FFF - Self::EXPONENT_BIAS;
+}
+
+// FLOAT STORAGE
+// -------------
 
+/// Storage for the arbitrary-precision algorithms for native float types.
+#[cfg(feature = "floats")]
+pub(crate) trait FloatStorage: Float {
+    /// Number of limbs in a Bigint.
+    ///
+    /// This number is somewhat arbitrary, but needs
+    /// to be at least the number of bits required to store
+    /// a Bigint, which is log2(10) * digits, adjusted to the limb size.
+    ///
+    /// Since we reserve at least 20 digits in the default constructor,
+    /// this must be at least 20. This constant is mostly present
+    /// to ensure BigintStorage is correct.
+    ///
+    /// Only used to bound the big integers in fixed-size storage.
+    #[cfg(all(feature = "no_alloc", not(feature = "heap_bignum"), not(feature = "radix")))]
+    const BIGINT_LIMBS: usize;
+
+    /// The storage type for the Bigint.
+    type BigintStorage: CloneableVecLike<Limb>;
+    /// The storage type for the Bigfloat.
+    type BigfloatStorage: CloneableVecLike<Limb>;
+}
+
+#[cfg(feature = "f16")]
+impl FloatStorage for f16 {
+    float_storage!(
+        bigint32_size => 20,
+        bigint64_size => 20,
+        bigfloat32_size => 10,
+        bigfloat64_size => 10,
+    );
+}
+
+#[cfg(feature = "f16")]
+impl FloatStorage for bf16 {
+    float_storage!(
+        bigint32_size => 20,
+        bigint64_size => 20,
+        bigfloat32_size => 20,
+        bigfloat64_size => 10,
+    );
+}
+
+#[cfg(feature = "floats")]
+impl FloatStorage for f32 {
+    float_storage!(
+        bigint32_size => 20,
+        bigint64_size => 20,
+        bigfloat32_size => 20,
+        bigfloat64_size => 10,
+    );
+}
+
+#[cfg(feature = "floats")]
+impl FloatStorage for f64 {
+    #[cfg(not(feature = "bounded_bignum"))]
+    float_storage!(
+        bigint32_size => 128,
+        bigint64_size => 64,
+        bigfloat32_size => 36,
+        bigfloat64_size => 20,
+    );
+
+    // Bhcomp only uses `BOUNDED_DIGITS` digits, so the big integers
+    // are at most 1030 bits: see `bhcomp.rs` for the bound.
+    #[cfg(feature = "bounded_bignum")]
+    float_storage!(
+        bigint32_size => 40,
+        bigint64_size => 20,
+        bigfloat32_size => 36,
+        bigfloat64_size => 20,
+    );
+}
+
+#[cfg(feature = "f128")]
+impl FloatStorage for f128 {
     type BigintStorage = Vec<Limb>;
     type BigfloatStorage = Vec<Limb>;
 
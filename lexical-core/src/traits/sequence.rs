@@ -4,7 +4,9 @@
 //! slice-like, vector-like, and cloneable vector-like code
 //! to abstract away implementation details.
 
-use crate::lib::{cmp, iter, mem, ops, ptr, slice};
+use crate::lib::{cmp, iter, ops, slice};
+#[cfg(not(feature = "safe"))]
+use crate::lib::{mem, ptr};
 
 // INSERT MANY
 // -----------
@@ -22,6 +24,7 @@ use crate::lib::{cmp, iter, mem, ops, ptr, slice};
 /// warning. Smallvec is similarly licensed under an MIT/Apache dual license.
 ///
 /// [`smallvec`]: https://github.com/servo/rust-smallvec
+#[cfg(not(feature = "safe"))]
 pub fn insert_many<V, T, I>(vec: &mut V, index: usize, iterable: I)
 where
     V: VecLike<T>,
@@ -102,6 +105,23 @@ where
     }
 }
 
+/// Insert multiple elements at position `index`.
+///
+/// Appends the elements and rotates them into place, which
+/// does not require any unsafe code.
+#[cfg(feature = "safe")]
+pub fn insert_many<V, T, I>(vec: &mut V, index: usize, iterable: I)
+where
+    V: VecLike<T>,
+    I: iter::IntoIterator<Item = T>,
+{
+    assert!(index <= vec.len());
+    let old_len = vec.len();
+    vec.extend(iterable);
+    let num_added = vec.len() - old_len;
+    vec[index..].rotate_right(num_added);
+}
+
 // REMOVE_MANY
 
 /// Remove many elements from a vec-like container.
@@ -109,6 +129,7 @@ where
 /// Does not change the size of the vector, and may leak
 /// if the destructor panics. **Must** call `set_len` after,
 /// and ideally before (to 0).
+#[cfg(not(feature = "safe"))]
 fn remove_many<V, T, R>(vec: &mut V, range: R)
 where
     V: VecLike<T>,
@@ -156,6 +177,35 @@ where
     }
 }
 
+/// Remove many elements from a vec-like container.
+///
+/// Rotates the elements to the end and truncates them, which
+/// does not require any unsafe code.
+#[cfg(feature = "safe")]
+fn remove_many<V, T, R>(vec: &mut V, range: R)
+where
+    V: VecLike<T>,
+    R: ops::RangeBounds<usize>,
+{
+    // Get the bounds on the items we're removing.
+    let len = vec.len();
+    let start = match range.start_bound() {
+        ops::Bound::Included(&n) => n,
+        ops::Bound::Excluded(&n) => n + 1,
+        ops::Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        ops::Bound::Included(&n) => n + 1,
+        ops::Bound::Excluded(&n) => n,
+        ops::Bound::Unbounded => len,
+    };
+    assert!(start <= end);
+    assert!(end <= len);
+
+    vec[start..].rotate_left(end - start);
+    vec.truncate(len - (end - start));
+}
+
 // HELPERS
 // -------
 
@@ -989,7 +1039,12 @@ pub trait CloneableVecLike<T: Clone + Copy + Send>: Send + Clone + VecLike<T> {
 // --------
 
 cfg_if! {
-if #[cfg(any(not(feature = "no_alloc"), feature = "f128", feature = "radix"))] {
+if #[cfg(any(
+    not(feature = "no_alloc"),
+    feature = "heap_bignum",
+    feature = "f128",
+    feature = "radix"
+))] {
 use crate::lib::Vec;
 
 impl<T> SliceLikeImpl<T> for Vec<T> {
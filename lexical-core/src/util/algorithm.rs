@@ -1,5 +1,6 @@
 //! Simple, shared algorithm utilities.
 
+#[cfg(not(feature = "safe"))]
 use crate::lib::ptr;
 
 // ALGORITHMS
@@ -15,6 +16,7 @@ pub fn distance<T>(first: *const T, last: *const T) -> usize {
 
 /// Check if two slices are equal to each other.
 #[inline]
+#[cfg(feature = "floats")]
 pub fn equal_to_slice(l: &[u8], r: &[u8]) -> bool {
     l == r
 }
@@ -41,6 +43,7 @@ where
 
 /// Check if left iter starts with right iter without case-sensitivity.
 #[inline]
+#[cfg(feature = "floats")]
 pub fn case_insensitive_starts_with_iter<'a, Iter1, Iter2>(
     mut l: Iter1,
     mut r: Iter2,
@@ -61,39 +64,45 @@ where
 
 /// Check if left slice ends with right slice.
 #[inline]
+#[cfg(feature = "floats")]
 pub fn ends_with_slice(l: &[u8], r: &[u8]) -> bool {
     // This cannot be out-of-bounds, since we check `l.len() >= r.len()`
     // previous to extracting the subslice, so `l.len() - r.len()` must
     // also be <= l.len() and >= 0.
-    let rget = move || unsafe { l.get_unchecked(l.len() - r.len()..) };
+    #[allow(unused_unsafe)]
+    let rget = move || &unchecked_index!(l[l.len() - r.len()..]);
     l.len() >= r.len() && equal_to_slice(rget(), r)
 }
 
 /// Trim character from the left-side of a slice.
 #[inline]
+#[allow(unused_unsafe)]
 pub fn ltrim_char_slice<'a>(slc: &'a [u8], c: u8) -> (&'a [u8], usize) {
     let count = slc.iter().take_while(|&&si| si == c).count();
     //  This count cannot exceed the bounds of the slice, since it is
     // derived from an iterator using the standard library to generate it.
     debug_assert!(count <= slc.len());
-    let slc = unsafe { slc.get_unchecked(count..) };
+    let slc = &unchecked_index!(slc[count..]);
     (slc, count)
 }
 
 /// Trim characters from the left-side of a slice.
 #[inline]
-#[cfg(feature = "format")]
+#[allow(unused_unsafe)]
+#[cfg(feature = "floats")]
 pub fn ltrim_char2_slice<'a>(slc: &'a [u8], c1: u8, c2: u8) -> (&'a [u8], usize) {
     let count = slc.iter().take_while(|&&si| si == c1 || si == c2).count();
     //  This count cannot exceed the bounds of the slice, since it is
     // derived from an iterator using the standard library to generate it.
     debug_assert!(count <= slc.len());
-    let slc = unsafe { slc.get_unchecked(count..) };
+    let slc = &unchecked_index!(slc[count..]);
     (slc, count)
 }
 
 /// Trim character from the right-side of a slice.
 #[inline]
+#[allow(unused_unsafe)]
+#[cfg(feature = "floats")]
 pub fn rtrim_char_slice<'a>(slc: &'a [u8], c: u8) -> (&'a [u8], usize) {
     let count = slc.iter().rev().take_while(|&&si| si == c).count();
     let index = slc.len() - count;
@@ -102,13 +111,14 @@ pub fn rtrim_char_slice<'a>(slc: &'a [u8], c: u8) -> (&'a [u8], usize) {
     // in the standard library.
     debug_assert!(count <= slc.len());
     debug_assert!(index <= slc.len());
-    let slc = unsafe { slc.get_unchecked(..index) };
+    let slc = &unchecked_index!(slc[..index]);
     (slc, count)
 }
 
 /// Trim character from the right-side of a slice.
 #[inline]
-#[cfg(feature = "format")]
+#[allow(unused_unsafe)]
+#[cfg(feature = "floats")]
 pub fn rtrim_char2_slice<'a>(slc: &'a [u8], c1: u8, c2: u8) -> (&'a [u8], usize) {
     let count = slc.iter().rev().take_while(|&&si| si == c1 || si == c2).count();
     let index = slc.len() - count;
@@ -117,7 +127,7 @@ pub fn rtrim_char2_slice<'a>(slc: &'a [u8], c1: u8, c2: u8) -> (&'a [u8], usize)
     // in the standard library.
     debug_assert!(count <= slc.len());
     debug_assert!(index <= slc.len());
-    let slc = unsafe { slc.get_unchecked(..index) };
+    let slc = &unchecked_index!(slc[..index]);
     (slc, count)
 }
 
@@ -127,20 +137,30 @@ pub fn copy_to_dst<'a, Bytes: AsRef<[u8]>>(dst: &'a mut [u8], src: Bytes) -> usi
     let src = src.as_ref();
     let dst = &mut dst[..src.len()];
 
+    #[cfg(not(feature = "safe"))]
     unsafe {
         ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), dst.len());
     }
 
+    #[cfg(feature = "safe")]
+    dst.copy_from_slice(src);
+
     src.len()
 }
 
 /// Length-check variant of ptr::write_bytes for a slice.
 #[inline]
-#[cfg(not(any(feature = "grisu3", feature = "ryu")))]
+#[cfg(feature = "floats")]
 pub fn write_bytes(dst: &mut [u8], byte: u8) {
+    #[cfg(not(feature = "safe"))]
     unsafe {
         ptr::write_bytes(dst.as_mut_ptr(), byte, dst.len());
     }
+
+    #[cfg(feature = "safe")]
+    for c in dst.iter_mut() {
+        *c = byte;
+    }
 }
 
 // TEST
@@ -161,6 +181,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "floats")]
     fn equal_to_test() {
         let x = "Hello";
         let y = "Hello";
@@ -194,6 +215,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "floats")]
     fn case_insensitive_starts_with_test() {
         let w = b"Hello";
         let x = b"H";
@@ -215,6 +237,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "floats")]
     fn ends_with_test() {
         let w = "Hello";
         let x = "lO";
@@ -255,7 +278,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "format")]
+    #[cfg(all(feature = "floats", feature = "format"))]
     fn ltrim_char2_test() {
         let w = "0001";
         let x = "1010";
@@ -275,6 +298,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "floats")]
     fn rtrim_char_test() {
         let w = "0001";
         let x = "1010";
@@ -291,7 +315,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "format")]
+    #[cfg(all(feature = "floats", feature = "format"))]
     fn rtrim_char2_test() {
         let w = "0001";
         let x = "1010";
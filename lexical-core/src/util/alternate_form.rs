@@ -0,0 +1,48 @@
+//! Alternate form of written floats.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ALTERNATE FORM
+// --------------
+
+/// How integral float mantissas are written in the alternate form.
+///
+/// The alternate form, like the `#` flag of C's `printf`, always writes
+/// a decimal point in the mantissa, even if no fraction digits follow.
+/// Some Fortran list-directed readers require the decimal point to read
+/// a value as real.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{AlternateForm, WriteFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let mut buffer = [0u8; 64];
+/// let options = WriteFloatOptions::builder()
+///     .alternate_form(Some(AlternateForm::Point))
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical_core::write_with_options(3.0f64, &mut buffer, &options), b"3.");
+///
+/// let options = WriteFloatOptions::rust_scientific()
+///     .rebuild()
+///     .alternate_form(Some(AlternateForm::PointZero))
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical_core::write_with_options(3e10f64, &mut buffer, &options), b"3.0e10");
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlternateForm {
+    /// Write a bare decimal point, like `3.`.
+    Point,
+    /// Write a decimal point and a zero fraction digit, like `3.0`.
+    PointZero,
+}
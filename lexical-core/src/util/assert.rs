@@ -36,6 +36,7 @@ macro_rules! debug_assert_radix_primitive {
 }
 
 /// Check non-primitive radix is valid.
+#[cfg(any(feature = "floats", feature = "power_of_two"))]
 macro_rules! debug_assert_radix {
     ($radix:expr) => (debug_assert_radix_primitive!($radix.as_i32()));
 }
@@ -54,4 +55,19 @@ macro_rules! assert_buffer {
         #[cfg(not(feature = "power_of_two"))]
         assert!($slc.len() >= <$t>::FORMATTED_SIZE);
     }};
+
+    // Check the buffer against the size required by the options.
+    // Sizes larger than the radix-safe size are checked when written.
+    ($radix:expr, $slc:ident, $t:ty, $size:expr) => {{
+        #[cfg(feature = "power_of_two")]
+        let max_size = match $radix {
+            10 => <$t>::FORMATTED_SIZE_DECIMAL,
+            _ => <$t>::FORMATTED_SIZE,
+        };
+
+        #[cfg(not(feature = "power_of_two"))]
+        let max_size = <$t>::FORMATTED_SIZE;
+
+        assert!($slc.len() >= $size.min(max_size));
+    }};
 }
@@ -0,0 +1,39 @@
+//! Exponent of a big-integer mantissa.
+
+#![cfg(feature = "floats")]
+
+// BIG EXPONENT
+// ------------
+
+/// Exponent of a big-integer mantissa, for `write_big_mantissa`.
+///
+/// The value is the mantissa scaled by a power of 2 or 10, like the
+/// mantissas and exponents of extended-precision float types or of
+/// decimal types.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::BigExponent;
+///
+/// # pub fn main() {
+/// let mut buffer = [0u8; 32];
+/// // 3 * 2^-1
+/// let exponent = BigExponent::Binary(-1);
+/// let bytes = lexical_core::write_big_mantissa(&[3], exponent, 2, &mut buffer);
+/// assert_eq!(bytes.unwrap(), b"1.50e0");
+///
+/// // 125 * 10^3
+/// let exponent = BigExponent::Decimal(3);
+/// let bytes = lexical_core::write_big_mantissa(&[125], exponent, 1, &mut buffer);
+/// assert_eq!(bytes.unwrap(), b"1.2e5");
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BigExponent {
+    /// The value is `mantissa * 2^exponent`.
+    Binary(i32),
+    /// The value is `mantissa * 10^exponent`.
+    Decimal(i32),
+}
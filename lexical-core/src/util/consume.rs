@@ -65,7 +65,7 @@ use super::format::*;
 
 // Convert character to digit.
 #[inline(always)]
-#[cfg(feature = "format")]
+#[cfg(any(feature = "floats", feature = "format"))]
 fn is_digit_or_separator(c: u8, radix: u32, digit_separator: u8) -> bool {
     return is_digit(c, radix) || c == digit_separator;
 }
@@ -591,7 +591,7 @@ pub(crate) fn consume_digits_ilt<'a>(
 // Consume until a an invalid digit is found.
 // Consumes leading, internal, trailing, and consecutive digit separators.
 #[inline]
-#[cfg(feature = "format")]
+#[cfg(any(feature = "floats", feature = "format"))]
 pub(crate) fn consume_digits_iltc<'a>(
     digits: &'a [u8],
     radix: u32,
@@ -627,7 +627,7 @@ pub(crate) fn consume_digits_no_separator<'a>(
 
 // Consume digits while ignoring the digit separator.
 #[inline(always)]
-#[cfg(feature = "format")]
+#[cfg(feature = "floats")]
 pub(crate) fn consume_digits_ignore_separator<'a>(
     bytes: &'a [u8],
     radix: u32,
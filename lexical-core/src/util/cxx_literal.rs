@@ -0,0 +1,316 @@
+//! Radix prefixes and type suffixes of C++ numeric literals.
+
+#![cfg(feature = "format")]
+
+#[cfg(feature = "power_of_two")]
+use crate::error::ErrorCode;
+
+// CXX SUFFIX
+// ----------
+
+/// Type suffix of a C++ numeric literal, such as `ull` in `1ull`.
+///
+/// Returned by `parse_cxx_literal`, with the value of the literal.
+/// Suffixes are case-insensitive, except that the `l` of `ll` must
+/// have the same case, and the `u` may lead or trail the `l` or `ll`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::CxxSuffix;
+///
+/// # pub fn main() {
+/// let value = lexical_core::parse_cxx_literal::<u64>(b"1'000ULL");
+/// assert_eq!(value, Ok((1000, Some(CxxSuffix::UnsignedLongLong))));
+///
+/// let value = lexical_core::parse_cxx_literal::<f32>(b"1.5e3f");
+/// assert_eq!(value, Ok((1500.0, Some(CxxSuffix::Float))));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CxxSuffix {
+    /// The `u` suffix, for `unsigned int`.
+    Unsigned,
+    /// The `l` suffix, for `long`.
+    Long,
+    /// The `ul` or `lu` suffix, for `unsigned long`.
+    UnsignedLong,
+    /// The `ll` suffix, for `long long`.
+    LongLong,
+    /// The `ull` or `llu` suffix, for `unsigned long long`.
+    UnsignedLongLong,
+    /// The `f` suffix, for `float`.
+    Float,
+    /// The `l` suffix, for `long double`.
+    LongDouble,
+}
+
+impl CxxSuffix {
+    /// Get the integer suffix from its string, or `None` if it is not a suffix.
+    #[inline]
+    pub fn from_integer_bytes(bytes: &[u8]) -> Option<Self> {
+        // Split the unsigned suffix, which may lead or trail.
+        let is_unsigned = |c: Option<&u8>| c.map_or(false, |c| c.eq_ignore_ascii_case(&b'u'));
+        let (unsigned, long) = if is_unsigned(bytes.first()) {
+            (true, &bytes[1..])
+        } else if is_unsigned(bytes.last()) {
+            (true, &bytes[..bytes.len() - 1])
+        } else {
+            (false, bytes)
+        };
+        let suffix = match (unsigned, long) {
+            (true, b"") => CxxSuffix::Unsigned,
+            (false, b"l") | (false, b"L") => CxxSuffix::Long,
+            (true, b"l") | (true, b"L") => CxxSuffix::UnsignedLong,
+            (false, b"ll") | (false, b"LL") => CxxSuffix::LongLong,
+            (true, b"ll") | (true, b"LL") => CxxSuffix::UnsignedLongLong,
+            _ => return None,
+        };
+        Some(suffix)
+    }
+
+    /// Get the float suffix from its string, or `None` if it is not a suffix.
+    #[inline]
+    pub fn from_float_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"f" | b"F" => Some(CxxSuffix::Float),
+            b"l" | b"L" => Some(CxxSuffix::LongDouble),
+            _ => None,
+        }
+    }
+
+    /// Get if the suffix is a float type.
+    #[inline]
+    pub fn is_float(self) -> bool {
+        match self {
+            CxxSuffix::Float | CxxSuffix::LongDouble => true,
+            _ => false,
+        }
+    }
+}
+
+// CXX PREFIX
+// ----------
+
+/// Get the radix and length of the radix prefix of a C++ literal.
+///
+/// The prefixes are `0x` and `0b`, and are case-insensitive. Literals
+/// with a leading `0` and more digits are octal, where the `0` is a
+/// digit rather than a prefix. Other literals are decimal.
+#[inline]
+pub(crate) fn cxx_literal_prefix(bytes: &[u8]) -> (u32, usize) {
+    match (bytes.get(0), bytes.get(1)) {
+        (Some(&b'0'), Some(&b'x')) | (Some(&b'0'), Some(&b'X')) => (16, 2),
+        (Some(&b'0'), Some(&b'b')) | (Some(&b'0'), Some(&b'B')) => (2, 2),
+        (Some(&b'0'), Some(&c)) if c.is_ascii_digit() || c == b'\'' => (8, 0),
+        _ => (10, 0),
+    }
+}
+
+// CXX HEX FLOAT
+// -------------
+
+/// Size of the buffer for a C++ hexadecimal float, as a binary float.
+#[cfg(feature = "power_of_two")]
+pub(crate) const CXX_HEX_FLOAT_SIZE: usize = 128;
+
+/// Get if the byte at the index is a digit separator between two digits.
+#[inline]
+#[cfg(feature = "power_of_two")]
+fn is_internal_separator(bytes: &[u8], index: usize, radix: u32) -> bool {
+    let is_digit = |c: Option<&u8>| c.map_or(false, |&c| (c as char).is_digit(radix));
+    bytes[index] == b'\''
+        && index != 0
+        && is_digit(bytes.get(index - 1))
+        && is_digit(bytes.get(index + 1))
+}
+
+/// Rewrite the digits of a C++ hexadecimal float as a binary float.
+///
+/// The bytes follow the `0x` prefix, like `1.8p3`. Writes the binary
+/// digits, then `^` and the binary exponent, so a binary float parser
+/// gives the correctly rounded value. Only the first 64 significant bits
+/// are kept, with a sticky bit if any later bits are set, which is below
+/// the rounding position of any float. Returns the number of bytes written
+/// and the number of bytes processed, or the error code and its index.
+#[cfg(feature = "power_of_two")]
+pub(crate) fn cxx_hex_float(
+    bytes: &[u8],
+    buffer: &mut [u8; CXX_HEX_FLOAT_SIZE],
+) -> Result<(usize, usize), (ErrorCode, usize)> {
+    // Parse the mantissa, and the binary exponent of its last kept digit.
+    let mut mantissa: u64 = 0;
+    let mut sticky = false;
+    let mut exponent: i64 = 0;
+    let mut digits = 0;
+    let mut fraction = false;
+    let mut index = 0;
+    while let Some(&c) = bytes.get(index) {
+        if let Some(digit) = (c as char).to_digit(16) {
+            if mantissa >> 60 == 0 {
+                mantissa = mantissa << 4 | digit as u64;
+                exponent -= if fraction { 4 } else { 0 };
+            } else {
+                sticky |= digit != 0;
+                exponent += if fraction { 0 } else { 4 };
+            }
+            digits += 1;
+        } else if c == b'.' && !fraction {
+            fraction = true;
+        } else if !is_internal_separator(bytes, index, 16) {
+            break;
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        return Err((ErrorCode::EmptyMantissa, 0));
+    }
+
+    // Parse the exponent, which is required.
+    match bytes.get(index) {
+        Some(&b'p') | Some(&b'P') => index += 1,
+        _ => return Err((ErrorCode::MissingExponent, index)),
+    }
+    let is_negative = match bytes.get(index) {
+        Some(&b'+') => {
+            index += 1;
+            false
+        },
+        Some(&b'-') => {
+            index += 1;
+            true
+        },
+        _ => false,
+    };
+    let exponent_start = index;
+    let mut value: i64 = 0;
+    while let Some(&c) = bytes.get(index) {
+        if let Some(digit) = (c as char).to_digit(10) {
+            // Any larger exponent overflows or underflows every float.
+            if value < 1 << 40 {
+                value = value * 10 + digit as i64;
+            }
+        } else if !is_internal_separator(bytes, index, 10) {
+            break;
+        }
+        index += 1;
+    }
+    if index == exponent_start {
+        return Err((ErrorCode::EmptyExponent, index));
+    }
+    exponent += if is_negative { -value } else { value };
+
+    // Write the binary digits and exponent, with the sticky bit.
+    let mut count = 0;
+    let mut push = |c: u8| {
+        buffer[count] = c;
+        count += 1;
+    };
+    if mantissa == 0 {
+        push(b'0');
+        exponent = 0;
+    } else {
+        let bits = 64 - mantissa.leading_zeros();
+        for shift in (0..bits).rev() {
+            push(b'0' + (mantissa >> shift & 1) as u8);
+        }
+        if sticky {
+            push(b'1');
+            exponent -= 1;
+        }
+    }
+    push(b'^');
+    let exponent = exponent.max(i32::min_value() as i64).min(i32::max_value() as i64);
+    if exponent < 0 {
+        push(b'-');
+    }
+    let exponent = exponent.abs() as u64;
+    let bits = 64 - exponent.leading_zeros().min(63);
+    for shift in (0..bits).rev() {
+        push(b'0' + (exponent >> shift & 1) as u8);
+    }
+    Ok((count, index))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cxx_integer_suffix_test() {
+        let check = |bytes: &[u8], expected| {
+            assert_eq!(CxxSuffix::from_integer_bytes(bytes), expected);
+        };
+        check(b"u", Some(CxxSuffix::Unsigned));
+        check(b"U", Some(CxxSuffix::Unsigned));
+        check(b"l", Some(CxxSuffix::Long));
+        check(b"L", Some(CxxSuffix::Long));
+        check(b"ul", Some(CxxSuffix::UnsignedLong));
+        check(b"Lu", Some(CxxSuffix::UnsignedLong));
+        check(b"ll", Some(CxxSuffix::LongLong));
+        check(b"LL", Some(CxxSuffix::LongLong));
+        check(b"ull", Some(CxxSuffix::UnsignedLongLong));
+        check(b"LLU", Some(CxxSuffix::UnsignedLongLong));
+        check(b"llU", Some(CxxSuffix::UnsignedLongLong));
+        check(b"", None);
+        check(b"lL", None);
+        check(b"uu", None);
+        check(b"ulu", None);
+        check(b"lul", None);
+        check(b"f", None);
+    }
+
+    #[test]
+    fn cxx_float_suffix_test() {
+        assert_eq!(CxxSuffix::from_float_bytes(b"f"), Some(CxxSuffix::Float));
+        assert_eq!(CxxSuffix::from_float_bytes(b"F"), Some(CxxSuffix::Float));
+        assert_eq!(CxxSuffix::from_float_bytes(b"L"), Some(CxxSuffix::LongDouble));
+        assert_eq!(CxxSuffix::from_float_bytes(b""), None);
+        assert_eq!(CxxSuffix::from_float_bytes(b"u"), None);
+        assert_eq!(CxxSuffix::from_float_bytes(b"ff"), None);
+        assert!(CxxSuffix::LongDouble.is_float());
+        assert!(!CxxSuffix::Long.is_float());
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn cxx_hex_float_test() {
+        let check = |bytes: &[u8], expected: &[u8], processed| {
+            let mut buffer = [0u8; CXX_HEX_FLOAT_SIZE];
+            let (count, index) = cxx_hex_float(bytes, &mut buffer).unwrap();
+            assert_eq!(&buffer[..count], expected);
+            assert_eq!(index, processed);
+        };
+        check(b"1.8p3", b"11000^-1", 5);
+        check(b"1.8P+3f", b"11000^-1", 6);
+        check(b"A'0p-1'0", b"10100000^-1010", 8);
+        check(b"0.0p100", b"0^0", 7);
+        check(b".01p0", b"1^-1000", 5);
+        check(b"FFFF'FFFF'FFFF'FFFF'1p0", b"11111111111111111111111111111111111111111111111111111111111111111^11", 23);
+
+        let mut buffer = [0u8; CXX_HEX_FLOAT_SIZE];
+        assert_eq!(cxx_hex_float(b"", &mut buffer), Err((ErrorCode::EmptyMantissa, 0)));
+        assert_eq!(cxx_hex_float(b"'1p0", &mut buffer), Err((ErrorCode::EmptyMantissa, 0)));
+        assert_eq!(cxx_hex_float(b"1''0p0", &mut buffer), Err((ErrorCode::MissingExponent, 1)));
+        assert_eq!(cxx_hex_float(b"1p+", &mut buffer), Err((ErrorCode::EmptyExponent, 3)));
+    }
+
+    #[test]
+    fn cxx_literal_prefix_test() {
+        assert_eq!(cxx_literal_prefix(b"0xFF"), (16, 2));
+        assert_eq!(cxx_literal_prefix(b"0XFF"), (16, 2));
+        assert_eq!(cxx_literal_prefix(b"0b1"), (2, 2));
+        assert_eq!(cxx_literal_prefix(b"0B1"), (2, 2));
+        assert_eq!(cxx_literal_prefix(b"017"), (8, 0));
+        assert_eq!(cxx_literal_prefix(b"0'17"), (8, 0));
+        assert_eq!(cxx_literal_prefix(b"0"), (10, 0));
+        assert_eq!(cxx_literal_prefix(b"0.5"), (10, 0));
+        assert_eq!(cxx_literal_prefix(b"0u"), (10, 0));
+        assert_eq!(cxx_literal_prefix(b"12"), (10, 0));
+        assert_eq!(cxx_literal_prefix(b""), (10, 0));
+    }
+}
@@ -0,0 +1,205 @@
+//! Shortest decimal digits generated for a float.
+
+/// Maximum number of significant digits for any supported float.
+const MAX_DIGITS: usize = 20;
+
+// DECIMAL DIGITS
+// --------------
+
+/// Shortest decimal digits that round-trip to a float.
+///
+/// The digits represent the magnitude of the float in scientific
+/// notation, `d.ddd * 10^exponent`, with the first digit non-zero
+/// (unless the float is zero) and no trailing zeros. This allows
+/// custom formatters to lay out the digits without re-parsing
+/// the output of the float writer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::ToDecimalDigits;
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let digits = 1234.5f64.to_decimal_digits().unwrap();
+/// assert_eq!(digits.digits(), b"12345");
+/// assert_eq!(digits.exponent(), 3);
+/// # }
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecimalDigits {
+    /// ASCII digits, only the first `len` are valid.
+    digits: [u8; MAX_DIGITS],
+    /// Number of valid digits.
+    len: usize,
+    /// Scientific exponent of the first digit.
+    exponent: i32,
+}
+
+impl DecimalDigits {
+    /// Create decimal digits for zero.
+    #[inline]
+    #[cfg(any(feature = "floats", test))]
+    pub(crate) fn zero() -> Self {
+        let mut digits = [0; MAX_DIGITS];
+        digits[0] = b'0';
+        Self {
+            digits,
+            len: 1,
+            exponent: 0,
+        }
+    }
+
+    /// Create from significant digits and the exponent of the first digit.
+    ///
+    /// The first digit must be non-zero, and trailing zeros are trimmed.
+    #[inline]
+    #[cfg(any(feature = "floats", test))]
+    pub(crate) fn new(digits: &[u8], exponent: i32) -> Self {
+        debug_assert!(!digits.is_empty() && digits[0] != b'0');
+        let len = digits.len() - digits.iter().rev().take_while(|&&c| c == b'0').count();
+        let mut result = Self {
+            digits: [0; MAX_DIGITS],
+            len,
+            exponent,
+        };
+        result.digits[..len].copy_from_slice(&digits[..len]);
+        result
+    }
+
+    /// Create from a positive, formatted decimal float.
+    ///
+    /// Accepts both the plain (`0.0015`) and scientific (`1.5e-3`)
+    /// forms written by the float backends, with `.` as the decimal
+    /// point and `e` or `E` as the exponent character.
+    #[cfg(any(feature = "grisu3", feature = "ryu", test))]
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        let mut result = Self::zero();
+        result.len = 0;
+
+        // Track leading zeros, which shift the exponent, and pending zeros,
+        // which are only significant if followed by a non-zero digit.
+        let mut leading: i32 = 0;
+        let mut pending: usize = 0;
+        let mut integer: i32 = 0;
+        let mut is_fraction = false;
+        let mut exponent: i32 = 0;
+        let mut iter = bytes.iter();
+        while let Some(&c) = iter.next() {
+            match c {
+                b'.' => is_fraction = true,
+                b'0'..=b'9' => {
+                    if !is_fraction {
+                        integer += 1;
+                    }
+                    if c == b'0' && result.len == 0 {
+                        leading += 1;
+                    } else if c == b'0' {
+                        pending += 1;
+                    } else {
+                        for _ in 0..pending {
+                            result.digits[result.len] = b'0';
+                            result.len += 1;
+                        }
+                        pending = 0;
+                        result.digits[result.len] = c;
+                        result.len += 1;
+                    }
+                },
+                _ => {
+                    exponent = parse_exponent(iter.as_slice());
+                    break;
+                },
+            }
+        }
+
+        if result.len == 0 {
+            Self::zero()
+        } else {
+            result.exponent = exponent + integer - leading - 1;
+            result
+        }
+    }
+
+    /// Increment the last digit, which must not be a 9.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn increment_last(&mut self) {
+        debug_assert!(self.digits[self.len - 1] < b'9');
+        self.digits[self.len - 1] += 1;
+    }
+
+    /// Get the significant digits, as ASCII characters.
+    #[inline]
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+
+    /// Get the decimal exponent of the first digit.
+    #[inline]
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+}
+
+/// Parse the exponent digits, with an optional sign, of a formatted float.
+#[inline]
+#[cfg(any(feature = "grisu3", feature = "ryu", test))]
+fn parse_exponent(bytes: &[u8]) -> i32 {
+    let (is_negative, digits) = match bytes.first() {
+        Some(&b'-') => (true, &bytes[1..]),
+        Some(&b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let value = digits.iter().fold(0i32, |acc, &c| acc * 10 + (c - b'0') as i32);
+    match is_negative {
+        true => -value,
+        false => value,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes_test() {
+        let digits = DecimalDigits::from_bytes(b"1.0");
+        assert_eq!(digits.digits(), b"1");
+        assert_eq!(digits.exponent(), 0);
+
+        let digits = DecimalDigits::from_bytes(b"1205.0");
+        assert_eq!(digits.digits(), b"1205");
+        assert_eq!(digits.exponent(), 3);
+
+        let digits = DecimalDigits::from_bytes(b"0.0015");
+        assert_eq!(digits.digits(), b"15");
+        assert_eq!(digits.exponent(), -3);
+
+        let digits = DecimalDigits::from_bytes(b"1.5e-7");
+        assert_eq!(digits.digits(), b"15");
+        assert_eq!(digits.exponent(), -7);
+
+        let digits = DecimalDigits::from_bytes(b"1E+30");
+        assert_eq!(digits.digits(), b"1");
+        assert_eq!(digits.exponent(), 30);
+
+        let digits = DecimalDigits::from_bytes(b"100000000000000000000.0");
+        assert_eq!(digits.digits(), b"1");
+        assert_eq!(digits.exponent(), 20);
+
+        assert_eq!(DecimalDigits::from_bytes(b"0.0"), DecimalDigits::zero());
+    }
+
+    #[test]
+    fn new_test() {
+        let digits = DecimalDigits::new(b"12300", 4);
+        assert_eq!(digits.digits(), b"123");
+        assert_eq!(digits.exponent(), 4);
+    }
+}
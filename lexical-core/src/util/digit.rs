@@ -62,3 +62,15 @@ pub(crate) fn to_digit_err<'a>(c: &'a u8, radix: u32) -> Result<u32, &'a u8> {
         None => Err(c),
     }
 }
+
+/// Get the number of digits to write a value in the radix.
+#[inline]
+pub(crate) fn digit_count(mut value: u128, radix: u32) -> usize {
+    let radix = radix as u128;
+    let mut count = 1;
+    while value >= radix {
+        value /= radix;
+        count += 1;
+    }
+    count
+}
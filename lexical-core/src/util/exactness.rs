@@ -0,0 +1,48 @@
+//! Exactness of a written float.
+
+// EXACTNESS
+// ---------
+
+/// If the written digits of a float exactly represent its binary value.
+///
+/// Returned by the `ToLexicalInfo` writers, alongside the written bytes,
+/// so archival formats can fall back to a lossless representation,
+/// such as hexadecimal floats, when the digits are only a rounding
+/// of the float.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{Exactness, ToLexicalInfo, WriteFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = WriteFloatOptions::decimal();
+/// let mut buffer = [0u8; 64];
+/// let (bytes, exactness) = 0.5f64.to_lexical_with_info(&mut buffer, &options);
+/// assert_eq!(bytes, b"0.5");
+/// assert_eq!(exactness, Exactness::Exact);
+///
+/// let (bytes, exactness) = 0.1f64.to_lexical_with_info(&mut buffer, &options);
+/// assert_eq!(bytes, b"0.1");
+/// assert_eq!(exactness, Exactness::Inexact);
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Exactness {
+    /// The digits are exactly equal to the float.
+    Exact,
+    /// The digits are a rounding of the float, which round-trips to it.
+    Inexact,
+}
+
+impl Exactness {
+    /// Get if the digits are exactly equal to the float.
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        *self == Exactness::Exact
+    }
+}
@@ -0,0 +1,268 @@
+//! Fixed-width numeric fields in record-oriented data.
+//!
+//! Fields are right-aligned and blank-padded, as in FORTRAN card
+//! formats, mainframe extracts and FITS headers. The field is copied
+//! to a normalized buffer, removing or replacing blanks and inserting
+//! the implied decimal point, and the buffer is parsed as a number.
+
+use crate::lib::cmp;
+
+// CONSTANTS
+
+/// Maximum width of a fixed-width field, in bytes.
+pub const MAX_FIELD_WIDTH: usize = 128;
+
+/// Maximum size of a normalized field, with an implied decimal point
+/// and up to `MAX_FIELD_WIDTH` leading fraction zeros.
+const FIELD_BUFFER_SIZE: usize = 2 * MAX_FIELD_WIDTH + 1;
+
+// BLANK MODE
+
+/// How blanks after the first non-blank character of a field are read.
+///
+/// Leading blanks are always ignored. Matches the `BN` and `BZ`
+/// edit descriptors in FORTRAN.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BlankMode {
+    /// Embedded and trailing blanks are ignored.
+    Ignore,
+    /// Embedded and trailing blanks are read as zeros.
+    Zero,
+}
+
+// FIELD SPEC
+
+/// Specification for a fixed-width numeric field.
+///
+/// If the digits before the exponent have no decimal point, the
+/// implied decimal point is placed before the last `implied_decimals`
+/// digits, like a FORTRAN `Fw.d` edit descriptor. A field of only
+/// blanks is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{BlankMode, FieldSpec};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// // An `F8.2` field.
+/// let spec = FieldSpec::new(8, 2, BlankMode::Ignore).unwrap();
+/// assert_eq!(lexical_core::parse_field::<f64>(b"   12345", &spec), Ok(123.45));
+/// assert_eq!(lexical_core::parse_field::<f64>(b"  -1.5  ", &spec), Ok(-1.5));
+/// assert_eq!(lexical_core::parse_field::<f64>(b"      -5", &spec), Ok(-0.05));
+/// # }
+///
+/// // Embedded blanks are zeros in an `I6` field with `BZ`.
+/// let spec = FieldSpec::new(6, 0, BlankMode::Zero).unwrap();
+/// assert_eq!(lexical_core::parse_field::<u32>(b"  1 2 ", &spec), Ok(1020));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldSpec {
+    /// Width of the field, in bytes.
+    width: usize,
+    /// Number of fraction digits if the field has no decimal point.
+    implied_decimals: usize,
+    /// How blanks in the field are read.
+    blanks: BlankMode,
+}
+
+impl FieldSpec {
+    /// Create a field specification.
+    ///
+    /// Returns `None` if the width is 0 or larger than `MAX_FIELD_WIDTH`,
+    /// or if the implied decimals are larger than the width.
+    #[inline]
+    pub fn new(width: usize, implied_decimals: usize, blanks: BlankMode) -> Option<Self> {
+        if width == 0 || width > MAX_FIELD_WIDTH || implied_decimals > width {
+            return None;
+        }
+        Some(Self {
+            width,
+            implied_decimals,
+            blanks,
+        })
+    }
+
+    /// Get the width of the field, in bytes.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the number of fraction digits if the field has no decimal point.
+    #[inline]
+    pub fn implied_decimals(&self) -> usize {
+        self.implied_decimals
+    }
+
+    /// Get how blanks in the field are read.
+    #[inline]
+    pub fn blanks(&self) -> BlankMode {
+        self.blanks
+    }
+}
+
+// FIELD BUFFER
+
+/// Normalized field, with the index in the field of each byte.
+pub(crate) struct FieldBuffer {
+    bytes: [u8; FIELD_BUFFER_SIZE],
+    positions: [u8; FIELD_BUFFER_SIZE],
+    len: usize,
+    width: usize,
+}
+
+impl FieldBuffer {
+    /// Get the normalized bytes.
+    #[inline]
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Get the index in the field of an index in the normalized bytes.
+    #[inline]
+    pub(crate) fn position(&self, index: usize) -> usize {
+        match index < self.len {
+            true => self.positions[index] as usize,
+            false => self.width,
+        }
+    }
+
+    /// Append a byte from the field.
+    #[inline]
+    fn push(&mut self, byte: u8, position: usize) {
+        self.bytes[self.len] = byte;
+        self.positions[self.len] = position as u8;
+        self.len += 1;
+    }
+
+    /// Insert a byte before `index`, at the position of the byte it precedes.
+    #[inline]
+    fn insert(&mut self, index: usize, byte: u8) {
+        let position = self.position(index) as u8;
+        self.bytes.copy_within(index..self.len, index + 1);
+        self.positions.copy_within(index..self.len, index + 1);
+        self.bytes[index] = byte;
+        self.positions[index] = position;
+        self.len += 1;
+    }
+}
+
+/// Normalize a fixed-width field to bytes the parsers accept.
+///
+/// Bytes past the end of a short record are read as blanks, and
+/// `d` or `D` exponents, from FORTRAN double-precision fields, are
+/// read as `e`.
+pub(crate) fn normalize_field(bytes: &[u8], spec: &FieldSpec) -> FieldBuffer {
+    let mut buffer = FieldBuffer {
+        bytes: [0; FIELD_BUFFER_SIZE],
+        positions: [0; FIELD_BUFFER_SIZE],
+        len: 0,
+        width: spec.width,
+    };
+    let field = &bytes[..cmp::min(spec.width, bytes.len())];
+
+    let mut mantissa_start = 0;
+    let mut exponent_index = None;
+    let mut has_point = false;
+    for index in 0..spec.width {
+        let c = field.get(index).map_or(b' ', |&c| c);
+        match c {
+            b' ' if buffer.len == 0 => continue,
+            b' ' if spec.blanks == BlankMode::Ignore => continue,
+            b' ' => buffer.push(b'0', index),
+            b'+' | b'-' if buffer.len == 0 => {
+                buffer.push(c, index);
+                mantissa_start = 1;
+            },
+            b'e' | b'E' | b'd' | b'D' if exponent_index.is_none() => {
+                exponent_index = Some(buffer.len);
+                buffer.push(b'e', index);
+            },
+            b'.' if exponent_index.is_none() => {
+                has_point = true;
+                buffer.push(c, index);
+            },
+            _ => buffer.push(c, index),
+        }
+    }
+
+    if buffer.len == 0 {
+        buffer.push(b'0', spec.width);
+        return buffer;
+    }
+
+    // Insert the implied decimal point, padding the fraction with zeros.
+    let mantissa_end = exponent_index.unwrap_or(buffer.len);
+    let digits = mantissa_end - mantissa_start;
+    if spec.implied_decimals != 0 && !has_point && digits != 0 {
+        if digits > spec.implied_decimals {
+            buffer.insert(mantissa_end - spec.implied_decimals, b'.');
+        } else {
+            for _ in digits..spec.implied_decimals {
+                buffer.insert(mantissa_start, b'0');
+            }
+            buffer.insert(mantissa_start, b'.');
+        }
+    }
+
+    buffer
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(bytes: &[u8], width: usize, implied: usize, blanks: BlankMode) -> FieldBuffer {
+        let spec = FieldSpec::new(width, implied, blanks).unwrap();
+        normalize_field(bytes, &spec)
+    }
+
+    #[test]
+    fn field_spec_test() {
+        assert!(FieldSpec::new(0, 0, BlankMode::Ignore).is_none());
+        assert!(FieldSpec::new(MAX_FIELD_WIDTH + 1, 0, BlankMode::Ignore).is_none());
+        assert!(FieldSpec::new(4, 5, BlankMode::Ignore).is_none());
+        assert!(FieldSpec::new(MAX_FIELD_WIDTH, MAX_FIELD_WIDTH, BlankMode::Zero).is_some());
+    }
+
+    #[test]
+    fn normalize_blanks_test() {
+        assert_eq!(normalize(b"      ", 6, 0, BlankMode::Ignore).as_slice(), b"0");
+        assert_eq!(normalize(b"  1 2 ", 6, 0, BlankMode::Ignore).as_slice(), b"12");
+        assert_eq!(normalize(b"  1 2 ", 6, 0, BlankMode::Zero).as_slice(), b"1020");
+        assert_eq!(normalize(b" -1 2 ", 6, 0, BlankMode::Zero).as_slice(), b"-1020");
+        assert_eq!(normalize(b"12", 4, 0, BlankMode::Zero).as_slice(), b"1200");
+        assert_eq!(normalize(b"123456", 4, 0, BlankMode::Ignore).as_slice(), b"1234");
+    }
+
+    #[test]
+    fn normalize_implied_decimals_test() {
+        assert_eq!(normalize(b"   12345", 8, 2, BlankMode::Ignore).as_slice(), b"123.45");
+        assert_eq!(normalize(b"      -5", 8, 3, BlankMode::Ignore).as_slice(), b"-.005");
+        assert_eq!(normalize(b"     123", 8, 3, BlankMode::Ignore).as_slice(), b".123");
+        assert_eq!(normalize(b"  1.5   ", 8, 3, BlankMode::Ignore).as_slice(), b"1.5");
+        assert_eq!(normalize(b" 15E+03 ", 8, 1, BlankMode::Ignore).as_slice(), b"1.5e+03");
+        assert_eq!(normalize(b" 1.5D-3 ", 8, 1, BlankMode::Ignore).as_slice(), b"1.5e-3");
+        assert_eq!(normalize(b"   15E1 ", 8, 1, BlankMode::Zero).as_slice(), b"1.5e10");
+        assert_eq!(normalize(b"       -", 8, 2, BlankMode::Ignore).as_slice(), b"-");
+    }
+
+    #[test]
+    fn position_test() {
+        let spec = FieldSpec::new(8, 2, BlankMode::Ignore).unwrap();
+        let buffer = normalize_field(b"   1 2x ", &spec);
+        assert_eq!(buffer.as_slice(), b"1.2x");
+        assert_eq!(buffer.position(0), 3);
+        assert_eq!(buffer.position(1), 5);
+        assert_eq!(buffer.position(3), 6);
+        assert_eq!(buffer.position(4), 8);
+    }
+}
@@ -0,0 +1,57 @@
+//! Structural parts of a written float.
+
+// FLOAT PART
+// ----------
+
+/// Structural part of a written float.
+///
+/// Passed by the `ToLexicalParts` writers to the callback, alongside
+/// the bytes of the part, so custom layouts, such as markup around the
+/// exponent or colorized output, do not need to parse the written
+/// string. The parts are passed in order, and together are the
+/// written bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{FloatPart, ToLexicalParts, WriteFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = WriteFloatOptions::decimal();
+/// let mut buffer = [0u8; 64];
+/// let mut html = Vec::new();
+/// (-1.5e-10f64).to_lexical_with_parts(&mut buffer, &options, |part, bytes| {
+///     match part {
+///         FloatPart::ExponentMarker => html.extend_from_slice(b"&times;10<sup>"),
+///         _ => html.extend_from_slice(bytes),
+///     }
+/// });
+/// html.extend_from_slice(b"</sup>");
+/// assert_eq!(html, b"-1.5&times;10<sup>-10</sup>".to_vec());
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FloatPart {
+    /// Sign of the float, or a parenthesis around a negative float.
+    Sign,
+    /// Digits before the decimal point.
+    Integer,
+    /// Decimal point.
+    DecimalPoint,
+    /// Digits after the decimal point.
+    Fraction,
+    /// Character before the exponent, such as `e`.
+    ExponentMarker,
+    /// Sign of the exponent.
+    ExponentSign,
+    /// Digits of the exponent.
+    Exponent,
+    /// SI prefix after the digits, such as `k`.
+    SiPrefix,
+    /// String for NaN or infinity.
+    Special,
+}
@@ -175,10 +175,13 @@ bitflags! {
     /// 38. JSON
     /// 39. TOML
     /// 40. XML
-    /// 41. SQLite
-    /// 42. PostgreSQL
-    /// 43. MySQL
-    /// 44. MongoDB
+    /// 41. SQL
+    /// 42. SQLite
+    /// 43. PostgreSQL
+    /// 44. MySQL
+    /// 45. MongoDB
+    /// 46. XML Schema
+    /// 47. Protocol Buffers Text Format
     #[repr(C)]
     #[derive(Default)]
     pub struct NumberFormat: u64 {
@@ -199,6 +202,8 @@ bitflags! {
             | Self::NO_INTEGER_LEADING_ZEROS.bits
             | Self::NO_FLOAT_LEADING_ZEROS.bits
             | Self::REQUIRED_EXPONENT_NOTATION.bits
+            | Self::NEGATIVE_PARENTHESES.bits
+            | Self::TIMES_TEN_EXPONENT.bits
             | Self::INTERNAL_DIGIT_SEPARATOR.bits
             | Self::LEADING_DIGIT_SEPARATOR.bits
             | Self::TRAILING_DIGIT_SEPARATOR.bits
@@ -272,6 +277,7 @@ bitflags! {
             | Self::REQUIRED_EXPONENT_SIGN.bits
             | Self::NO_EXPONENT_WITHOUT_FRACTION.bits
             | Self::REQUIRED_EXPONENT_NOTATION.bits
+            | Self::TIMES_TEN_EXPONENT.bits
             | Self::EXPONENT_INTERNAL_DIGIT_SEPARATOR.bits
             | Self::EXPONENT_LEADING_DIGIT_SEPARATOR.bits
             | Self::EXPONENT_TRAILING_DIGIT_SEPARATOR.bits
@@ -326,6 +332,12 @@ bitflags! {
         #[doc(hidden)]
         const REQUIRED_EXPONENT_NOTATION            = flags::REQUIRED_EXPONENT_NOTATION;
 
+        #[doc(hidden)]
+        const NEGATIVE_PARENTHESES                  = flags::NEGATIVE_PARENTHESES;
+
+        #[doc(hidden)]
+        const TIMES_TEN_EXPONENT                    = flags::TIMES_TEN_EXPONENT;
+
         // DIGIT SEPARATOR FLAGS & MASKS
         // See `flags` for documentation.
 
@@ -1596,6 +1608,48 @@ bitflags! {
             | Self::CASE_SENSITIVE_SPECIAL.bits
         );
 
+        // XML SCHEMA DOUBLE [01345678MN]
+        /// Float format for a XML Schema `xs:double` or `xs:float` value.
+        const XML_SCHEMA_DOUBLE = (
+            flags::exponent_decimal_to_flags(b'e')
+            | flags::exponent_backup_to_flags(b'^')
+            | flags::decimal_point_to_flags(b'.')
+            | Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::CASE_SENSITIVE_SPECIAL.bits
+        );
+
+        // XML SCHEMA DECIMAL [013MN]
+        /// Float format for a XML Schema `xs:decimal` value.
+        const XML_SCHEMA_DECIMAL = (
+            flags::exponent_decimal_to_flags(b'e')
+            | flags::exponent_backup_to_flags(b'^')
+            | flags::decimal_point_to_flags(b'.')
+            | Self::NO_EXPONENT_NOTATION.bits
+            | Self::NO_SPECIAL.bits
+        );
+
+        // PROTOBUF TEXT [014567]
+        /// Float format for a protobuf text format floating-point number.
+        const PROTOBUF_TEXT = (
+            flags::exponent_decimal_to_flags(b'e')
+            | flags::exponent_backup_to_flags(b'^')
+            | flags::decimal_point_to_flags(b'.')
+            | Self::NO_POSITIVE_MANTISSA_SIGN.bits
+            | Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::NO_INTEGER_LEADING_ZEROS.bits
+            | Self::NO_FLOAT_LEADING_ZEROS.bits
+        );
+
+        // SQL [013456MN]
+        /// Float format for an ANSI SQL numeric literal.
+        const SQL = (
+            flags::exponent_decimal_to_flags(b'e')
+            | flags::exponent_backup_to_flags(b'^')
+            | flags::decimal_point_to_flags(b'.')
+            | Self::REQUIRED_EXPONENT_DIGITS.bits
+            | Self::NO_SPECIAL.bits
+        );
+
         // SQLITE [013456MN]
         /// Float format for a SQLite literal floating-point number.
         const SQLITE = (
@@ -1692,6 +1746,35 @@ impl NumberFormat {
         Self::new(flags::digit_separator_to_flags(digit_separator))
     }
 
+    /// Create a copy of the format with a different decimal point.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_decimal_point(self, decimal_point: u8) -> Self {
+        let bits = self.bits & !flags::decimal_point_to_flags(0x7F);
+        Self::new(bits | flags::decimal_point_to_flags(decimal_point))
+    }
+
+    /// Create a copy of the format with a different backup exponent character.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_exponent_backup(self, exponent_backup: u8) -> Self {
+        let bits = self.bits & !flags::exponent_backup_to_flags(0x7F);
+        Self::new(bits | flags::exponent_backup_to_flags(exponent_backup))
+    }
+
+    /// Create a copy of the format that ignores a digit separator anywhere
+    /// in the integer, fraction, and exponent digits.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_ignored_digit_separator(self, digit_separator: u8) -> Self {
+        let bits = self.bits & !flags::digit_separator_to_flags(0x7F);
+        let bits = bits
+            | Self::INTEGER_DIGIT_SEPARATOR_FLAG_MASK.bits
+            | Self::FRACTION_DIGIT_SEPARATOR_FLAG_MASK.bits
+            | Self::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK.bits;
+        Self::new(bits | flags::digit_separator_to_flags(digit_separator))
+    }
+
     /// Get the flag bits from the compiled float format.
     #[inline(always)]
     pub const fn flags(self) -> Self {
@@ -1829,6 +1912,18 @@ impl NumberFormat {
         self.intersects(Self::REQUIRED_EXPONENT_NOTATION)
     }
 
+    /// Get if negative values may be enclosed in parentheses.
+    #[inline(always)]
+    pub const fn negative_parentheses(self) -> bool {
+        self.intersects(Self::NEGATIVE_PARENTHESES)
+    }
+
+    /// Get if exponents may be written as a power of ten, as in `1.5×10^3`.
+    #[inline(always)]
+    pub const fn times_ten_exponent(self) -> bool {
+        self.intersects(Self::TIMES_TEN_EXPONENT)
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn integer_internal_digit_separator(self) -> bool {
@@ -1961,6 +2056,8 @@ impl NumberFormat {
             no_integer_leading_zeros: self.no_integer_leading_zeros(),
             no_float_leading_zeros: self.no_float_leading_zeros(),
             required_exponent_notation: self.required_exponent_notation(),
+            negative_parentheses: self.negative_parentheses(),
+            times_ten_exponent: self.times_ten_exponent(),
             integer_internal_digit_separator: self.integer_internal_digit_separator(),
             fraction_internal_digit_separator: self.fraction_internal_digit_separator(),
             exponent_internal_digit_separator: self.exponent_internal_digit_separator(),
@@ -2000,6 +2097,8 @@ impl NumberFormat {
 /// * `no_integer_leading_zeros`                - If leading zeros before an integer are not allowed.
 /// * `no_float_leading_zeros`                  - If leading zeros before a float are not allowed.
 /// * `required_exponent_notation`              - If exponent notation is required.
+/// * `negative_parentheses`                    - If negative values may be enclosed in parentheses.
+/// * `times_ten_exponent`                      - If exponents may be written as a power of ten.
 /// * `integer_internal_digit_separator`        - If digit separators are allowed between integer digits.
 /// * `fraction_internal_digit_separator`       - If digit separators are allowed between fraction digits.
 /// * `exponent_internal_digit_separator`       - If digit separators are allowed between exponent digits.
@@ -2035,6 +2134,8 @@ pub struct NumberFormatBuilder {
     no_integer_leading_zeros: bool,
     no_float_leading_zeros: bool,
     required_exponent_notation: bool,
+    negative_parentheses: bool,
+    times_ten_exponent: bool,
     integer_internal_digit_separator: bool,
     fraction_internal_digit_separator: bool,
     exponent_internal_digit_separator: bool,
@@ -2073,6 +2174,8 @@ impl NumberFormatBuilder {
             no_integer_leading_zeros: false,
             no_float_leading_zeros: false,
             required_exponent_notation: false,
+            negative_parentheses: false,
+            times_ten_exponent: false,
             integer_internal_digit_separator: false,
             fraction_internal_digit_separator: false,
             exponent_internal_digit_separator: false,
@@ -2199,6 +2302,18 @@ impl NumberFormatBuilder {
         self.required_exponent_notation
     }
 
+    /// Get if negative values may be enclosed in parentheses.
+    #[inline(always)]
+    pub const fn get_negative_parentheses(&self) -> bool {
+        self.negative_parentheses
+    }
+
+    /// Get if exponents may be written as a power of ten, as in `1.5×10^3`.
+    #[inline(always)]
+    pub const fn get_times_ten_exponent(&self) -> bool {
+        self.times_ten_exponent
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn get_integer_internal_digit_separator(&self) -> bool {
@@ -2419,6 +2534,27 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set if negative values may be enclosed in parentheses.
+    ///
+    /// Accounting formats write negative values as `(1,234.56)`,
+    /// rather than with a minus sign. Only valid for floats.
+    #[inline(always)]
+    pub const fn negative_parentheses(mut self, negative_parentheses: bool) -> Self {
+        self.negative_parentheses = negative_parentheses;
+        self
+    }
+
+    /// Set if exponents may be written as a power of ten, as in `1.5×10^3`.
+    ///
+    /// The exponent introducer `×10^`, or `x10^` and `X10^` in ASCII,
+    /// may be used in place of the exponent character. Only valid for
+    /// decimal floats.
+    #[inline(always)]
+    pub const fn times_ten_exponent(mut self, times_ten_exponent: bool) -> Self {
+        self.times_ten_exponent = times_ten_exponent;
+        self
+    }
+
     /// Set if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn integer_internal_digit_separator(
@@ -2610,6 +2746,8 @@ impl NumberFormatBuilder {
         add_flag!(format, self.no_integer_leading_zeros, NO_INTEGER_LEADING_ZEROS);
         add_flag!(format, self.no_float_leading_zeros, NO_FLOAT_LEADING_ZEROS);
         add_flag!(format, self.required_exponent_notation, REQUIRED_EXPONENT_NOTATION);
+        add_flag!(format, self.negative_parentheses, NEGATIVE_PARENTHESES);
+        add_flag!(format, self.times_ten_exponent, TIMES_TEN_EXPONENT);
 
         // Digit separator flags.
         add_flag!(
@@ -2699,6 +2837,7 @@ impl NumberFormatBuilder {
             || self.no_positive_exponent_sign && self.required_exponent_sign
             || self.no_special && (self.case_sensitive_special || self.special_digit_separator)
             || self.no_exponent_notation && self.required_exponent_notation
+            || self.required_mantissa_sign && self.negative_parentheses
             || check_flag!(
                 format,
                 INTEGER_DIGIT_SEPARATOR_FLAG_MASK,
@@ -2759,6 +2898,8 @@ mod tests {
         assert_eq!(flag.no_integer_leading_zeros(), false);
         assert_eq!(flag.no_float_leading_zeros(), false);
         assert_eq!(flag.required_exponent_notation(), false);
+        assert_eq!(flag.negative_parentheses(), false);
+        assert_eq!(flag.times_ten_exponent(), false);
         assert_eq!(flag.integer_internal_digit_separator(), true);
         assert_eq!(flag.fraction_internal_digit_separator(), true);
         assert_eq!(flag.exponent_internal_digit_separator(), true);
@@ -2798,6 +2939,8 @@ mod tests {
             NumberFormat::NO_INTEGER_LEADING_ZEROS,
             NumberFormat::NO_FLOAT_LEADING_ZEROS,
             NumberFormat::REQUIRED_EXPONENT_NOTATION,
+            NumberFormat::NEGATIVE_PARENTHESES,
+            NumberFormat::TIMES_TEN_EXPONENT,
             NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR,
             NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR,
             NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR,
@@ -2928,6 +3071,10 @@ mod tests {
             NumberFormat::TOML,
             NumberFormat::YAML,
             NumberFormat::XML,
+            NumberFormat::XML_SCHEMA_DOUBLE,
+            NumberFormat::XML_SCHEMA_DECIMAL,
+            NumberFormat::PROTOBUF_TEXT,
+            NumberFormat::SQL,
             NumberFormat::SQLITE,
             NumberFormat::POSTGRESQL,
             NumberFormat::MYSQL,
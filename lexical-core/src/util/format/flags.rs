@@ -128,6 +128,21 @@ pub(crate) const NO_FLOAT_LEADING_ZEROS: u64 =
 pub(crate) const REQUIRED_EXPONENT_NOTATION: u64 =
     0b0000000000000000000000000000000000000000000000000010000000000000;
 
+/// Negative values may be enclosed in parentheses.
+///
+/// Accounting formats write negative values as `(1,234.56)` rather
+/// than `-1,234.56`. A sign is not allowed inside the parentheses.
+pub(crate) const NEGATIVE_PARENTHESES: u64 =
+    0b0000000000000000000000000000000000000000000000000100000000000000;
+
+/// Exponents may be written as a power of ten, as in `1.5×10^3`.
+///
+/// The typographic exponent introducer `×10^`, or `x10^` and `X10^`
+/// in ASCII, may be used in place of the exponent character, as in
+/// exported lab data and publications. Only valid for decimal floats.
+pub(crate) const TIMES_TEN_EXPONENT: u64 =
+    0b0000000000000000000000000000000000000000000000001000000000000000;
+
 // DIGIT SEPARATOR FLAGS & MASKS
 // -----------------------------
 
@@ -229,6 +244,8 @@ check_subsequent_flags!(NO_SPECIAL, CASE_SENSITIVE_SPECIAL);
 check_subsequent_flags!(CASE_SENSITIVE_SPECIAL, NO_INTEGER_LEADING_ZEROS);
 check_subsequent_flags!(NO_INTEGER_LEADING_ZEROS, NO_FLOAT_LEADING_ZEROS);
 check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, REQUIRED_EXPONENT_NOTATION);
+check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, NEGATIVE_PARENTHESES);
+check_subsequent_flags!(NEGATIVE_PARENTHESES, TIMES_TEN_EXPONENT);
 
 // Digit separator flags.
 const_assert!(INTEGER_INTERNAL_DIGIT_SEPARATOR == 1 << 32);
@@ -412,7 +429,6 @@ pub(crate) const fn digit_separator_to_flags(ch: u8) -> u64 {
 
 /// Extract digit separator from flags.
 #[inline]
-#[cfg(any(test, feature = "format"))]
 pub(crate) const fn digit_separator_from_flags(flag: u64) -> u8 {
     from_flags!(flag, DIGIT_SEPARATOR_SHIFT, DIGIT_SEPARATOR_MASK)
 }
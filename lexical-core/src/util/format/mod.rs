@@ -682,10 +682,20 @@
 #[macro_use]
 mod flags;
 
+pub(crate) use self::flags::{
+    is_valid_digit_separator,
+    is_valid_exponent_backup,
+    is_valid_punctuation
+};
+
 cfg_if! {
 if #[cfg(feature = "format")] {
     mod feature_format;
+    mod policy;
+    mod spec;
     pub use self::feature_format::*;
+    pub use self::policy::*;
+    pub use self::spec::*;
 } else {
     mod not_feature_format;
     pub use self::not_feature_format::*;
@@ -76,6 +76,31 @@ impl NumberFormat {
         }
     }
 
+    /// Create a copy of the format with a different decimal point.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_decimal_point(self, decimal_point: u8) -> Self {
+        let bits = self.bits & !flags::decimal_point_to_flags(0x7F);
+        Self::new(bits | flags::decimal_point_to_flags(decimal_point))
+    }
+
+    /// Create a copy of the format with a different backup exponent character.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_exponent_backup(self, exponent_backup: u8) -> Self {
+        let bits = self.bits & !flags::exponent_backup_to_flags(0x7F);
+        Self::new(bits | flags::exponent_backup_to_flags(exponent_backup))
+    }
+
+    /// Create a copy of the format that ignores a digit separator anywhere
+    /// in the integer, fraction, and exponent digits.
+    /// This method should **NEVER** be public, use the builder API.
+    #[inline(always)]
+    pub(crate) const fn with_ignored_digit_separator(self, digit_separator: u8) -> Self {
+        let bits = self.bits & !flags::digit_separator_to_flags(0x7F);
+        Self::new(bits | flags::digit_separator_to_flags(digit_separator))
+    }
+
     /// Get the flag bits from the compiled float format.
     #[inline(always)]
     pub const fn flags(self) -> Self {
@@ -91,7 +116,7 @@ impl NumberFormat {
     /// Get the digit separator for the number format.
     #[inline(always)]
     pub const fn digit_separator(self) -> u8 {
-        b'\x00'
+        flags::digit_separator_from_flags(self.bits)
     }
 
     /// Get the decimal point character for the number format.
@@ -213,6 +238,18 @@ impl NumberFormat {
         false
     }
 
+    /// Get if negative values may be enclosed in parentheses.
+    #[inline(always)]
+    pub const fn negative_parentheses(self) -> bool {
+        false
+    }
+
+    /// Get if exponents may be written as a power of ten, as in `1.5×10^3`.
+    #[inline(always)]
+    pub const fn times_ten_exponent(self) -> bool {
+        false
+    }
+
     /// Get if digit separators are allowed between integer digits.
     #[inline(always)]
     pub const fn integer_internal_digit_separator(self) -> bool {
@@ -0,0 +1,67 @@
+//! Number formats known at compile time.
+//!
+//! A format policy is a zero-sized type with the number format as an
+//! associated constant, so a format can be named in a type, such as
+//! a generic parameter, rather than passed as a value. The parsers
+//! build their options from the constant on every call, and the
+//! format-aware parsers still check its flags at runtime.
+
+#![cfg(feature = "format")]
+
+use super::feature_format::*;
+
+// FORMAT POLICY
+
+/// Number format known at compile time.
+///
+/// Implement the trait on a zero-sized type, or declare one with
+/// the `format_policy!` macro, and parse with `parse_with_policy` or
+/// `parse_partial_with_policy`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{format_policy, NumberFormat};
+///
+/// # pub fn main() {
+/// format_policy! {
+///     /// Rust float literals.
+///     pub struct RustLiteral = NumberFormat::RUST_LITERAL;
+/// }
+///
+/// assert_eq!(lexical_core::parse_with_policy::<f64, RustLiteral>(b"1_000.5"), Ok(1000.5));
+/// assert!(lexical_core::parse_with_policy::<f64, RustLiteral>(b".5").is_err());
+/// # }
+/// ```
+pub trait FormatPolicy {
+    /// The number format to parse.
+    const FORMAT: NumberFormat;
+}
+
+/// Declare a zero-sized type implementing `FormatPolicy`.
+///
+/// The type is declared with the given attributes and visibility,
+/// and the format must be a constant expression.
+#[macro_export]
+macro_rules! format_policy {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident = $format:expr;) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+        $vis struct $name;
+
+        impl $crate::FormatPolicy for $name {
+            const FORMAT: $crate::NumberFormat = $format;
+        }
+    };
+}
+
+format_policy! {
+    /// Policy for the standard number format.
+    pub struct StandardPolicy = NumberFormat::STANDARD;
+}
+
+format_policy! {
+    /// Policy for JSON numbers.
+    pub struct JsonPolicy = NumberFormat::JSON;
+}
@@ -0,0 +1,281 @@
+//! Human-readable specifications for number formats.
+//!
+//! A specification is a comma-separated list of flag names, such as
+//! `no_special` or `internal_digit_separator`, and punctuation
+//! settings, such as `separator=_` or `decimal_point=,`. Flag names
+//! match the `NumberFormat` constants in lowercase.
+
+#![cfg(feature = "format")]
+
+use crate::lib::fmt;
+
+use super::feature_format::*;
+
+// FLAGS
+
+/// Names of individual flags, in the order they are written.
+const FLAGS: [(&'static str, NumberFormat); 29] = [
+    ("required_integer_digits", NumberFormat::REQUIRED_INTEGER_DIGITS),
+    ("required_fraction_digits", NumberFormat::REQUIRED_FRACTION_DIGITS),
+    ("required_exponent_digits", NumberFormat::REQUIRED_EXPONENT_DIGITS),
+    ("no_positive_mantissa_sign", NumberFormat::NO_POSITIVE_MANTISSA_SIGN),
+    ("required_mantissa_sign", NumberFormat::REQUIRED_MANTISSA_SIGN),
+    ("no_exponent_notation", NumberFormat::NO_EXPONENT_NOTATION),
+    ("no_positive_exponent_sign", NumberFormat::NO_POSITIVE_EXPONENT_SIGN),
+    ("required_exponent_sign", NumberFormat::REQUIRED_EXPONENT_SIGN),
+    ("no_exponent_without_fraction", NumberFormat::NO_EXPONENT_WITHOUT_FRACTION),
+    ("no_special", NumberFormat::NO_SPECIAL),
+    ("case_sensitive_special", NumberFormat::CASE_SENSITIVE_SPECIAL),
+    ("no_integer_leading_zeros", NumberFormat::NO_INTEGER_LEADING_ZEROS),
+    ("no_float_leading_zeros", NumberFormat::NO_FLOAT_LEADING_ZEROS),
+    ("required_exponent_notation", NumberFormat::REQUIRED_EXPONENT_NOTATION),
+    ("negative_parentheses", NumberFormat::NEGATIVE_PARENTHESES),
+    ("times_ten_exponent", NumberFormat::TIMES_TEN_EXPONENT),
+    ("integer_internal_digit_separator", NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR),
+    ("fraction_internal_digit_separator", NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR),
+    ("exponent_internal_digit_separator", NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR),
+    ("integer_leading_digit_separator", NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR),
+    ("fraction_leading_digit_separator", NumberFormat::FRACTION_LEADING_DIGIT_SEPARATOR),
+    ("exponent_leading_digit_separator", NumberFormat::EXPONENT_LEADING_DIGIT_SEPARATOR),
+    ("integer_trailing_digit_separator", NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR),
+    ("fraction_trailing_digit_separator", NumberFormat::FRACTION_TRAILING_DIGIT_SEPARATOR),
+    ("exponent_trailing_digit_separator", NumberFormat::EXPONENT_TRAILING_DIGIT_SEPARATOR),
+    ("integer_consecutive_digit_separator", NumberFormat::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR),
+    ("fraction_consecutive_digit_separator", NumberFormat::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR),
+    ("exponent_consecutive_digit_separator", NumberFormat::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR),
+    ("special_digit_separator", NumberFormat::SPECIAL_DIGIT_SEPARATOR),
+];
+
+/// Names of flags that combine individual flags, only used for parsing.
+const GROUPS: [(&'static str, NumberFormat); 5] = [
+    ("required_digits", NumberFormat::REQUIRED_DIGITS),
+    ("internal_digit_separator", NumberFormat::INTERNAL_DIGIT_SEPARATOR),
+    ("leading_digit_separator", NumberFormat::LEADING_DIGIT_SEPARATOR),
+    ("trailing_digit_separator", NumberFormat::TRAILING_DIGIT_SEPARATOR),
+    ("consecutive_digit_separator", NumberFormat::CONSECUTIVE_DIGIT_SEPARATOR),
+];
+
+/// Find a flag, or group of flags, by name.
+#[inline]
+fn find_flag(name: &[u8]) -> Option<NumberFormat> {
+    FLAGS
+        .iter()
+        .chain(GROUPS.iter())
+        .find(|(flag_name, _)| flag_name.as_bytes() == name)
+        .map(|&(_, flag)| flag)
+}
+
+// PARSE
+
+impl NumberFormat {
+    /// Create a format from a human-readable specification.
+    ///
+    /// The specification is a comma-separated list of items, starting
+    /// from the default format. Each item is either a flag name, such as
+    /// `no_special` or `internal_digit_separator`, or a punctuation
+    /// setting with a single ASCII character: `separator`,
+    /// `decimal_point`, `exponent_decimal`, or `exponent_backup`.
+    /// Since the value is always one character, `decimal_point=,`
+    /// is allowed. The digit separator is only used if a digit
+    /// separator flag is set.
+    ///
+    /// Returns `None` if an item is unknown or malformed, or if
+    /// the resulting format is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// use lexical_core::NumberFormat;
+    ///
+    /// # pub fn main() {
+    /// let format = NumberFormat::from_spec("separator=_,internal_digit_separator,no_special");
+    /// let format = format.unwrap();
+    /// assert_eq!(format.digit_separator(), b'_');
+    /// assert!(format.integer_internal_digit_separator());
+    /// assert!(format.no_special());
+    /// # }
+    /// ```
+    pub fn from_spec(spec: &str) -> Option<NumberFormat> {
+        let default = NumberFormat::builder();
+        let mut flags = NumberFormat::new(0);
+        let mut digit_separator = default.get_digit_separator();
+        let mut decimal_point = default.get_decimal_point();
+        let mut exponent_decimal = default.get_exponent_decimal();
+        let mut exponent_backup = default.get_exponent_backup();
+        let mut bytes = spec.as_bytes();
+        while !bytes.is_empty() {
+            let length = bytes.iter().position(|&c| c == b',' || c == b'=').unwrap_or(bytes.len());
+            let (name, rest) = bytes.split_at(length);
+            bytes = match rest.first() {
+                Some(&b'=') => {
+                    // Punctuation setting, with a single ASCII character.
+                    let value = match rest.get(1) {
+                        Some(&c) if c.is_ascii() => c,
+                        _ => return None,
+                    };
+                    match name {
+                        b"separator" => digit_separator = value,
+                        b"decimal_point" => decimal_point = value,
+                        b"exponent_decimal" => exponent_decimal = value,
+                        b"exponent_backup" => exponent_backup = value,
+                        _ => return None,
+                    }
+                    &rest[2..]
+                },
+                _ => {
+                    flags |= find_flag(name)?;
+                    rest
+                },
+            };
+            // Items must be separated by a single comma, without a trailing comma.
+            match bytes.first() {
+                None => break,
+                Some(&b',') if bytes.len() > 1 => bytes = &bytes[1..],
+                _ => return None,
+            }
+        }
+
+        // The flags have no punctuation, so set every character explicitly.
+        flags
+            .rebuild()
+            .digit_separator(digit_separator)
+            .decimal_point(decimal_point)
+            .exponent_decimal(exponent_decimal)
+            .exponent_backup(exponent_backup)
+            .build()
+    }
+
+    /// Get the human-readable specification for the format.
+    ///
+    /// The returned value implements `Display`, and the written string
+    /// can be parsed back with `from_spec`. Only settings that differ
+    /// from the default format are written.
+    #[inline]
+    pub fn to_spec(self) -> NumberFormatSpec {
+        NumberFormatSpec(self)
+    }
+}
+
+// WRITE
+
+/// Human-readable specification for a `NumberFormat`, created by `to_spec`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NumberFormatSpec(NumberFormat);
+
+impl fmt::Display for NumberFormatSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let format = self.0;
+        let default = NumberFormat::builder();
+        let punctuation = [
+            ("separator", format.digit_separator(), default.get_digit_separator()),
+            ("decimal_point", format.decimal_point(), default.get_decimal_point()),
+            ("exponent_decimal", format.exponent_decimal(), default.get_exponent_decimal()),
+            ("exponent_backup", format.exponent_backup(), default.get_exponent_backup()),
+        ];
+
+        let mut comma = "";
+        for &(name, value, default) in punctuation.iter() {
+            if value != default {
+                write!(f, "{}{}={}", comma, name, value as char)?;
+                comma = ",";
+            }
+        }
+        for &(name, flag) in FLAGS.iter() {
+            if format.contains(flag) {
+                write!(f, "{}{}", comma, name)?;
+                comma = ",";
+            }
+        }
+        Ok(())
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_spec_flags_test() {
+        for &(name, flag) in FLAGS.iter().chain(GROUPS.iter()) {
+            // Consecutive digit separators are only valid with another digit separator flag.
+            let (spec, expected) = match name.contains("consecutive") {
+                true => {
+                    let internal = name.replace("consecutive", "internal");
+                    let expected = flag | find_flag(internal.as_bytes()).unwrap();
+                    (format!("separator=_,{},{}", name, internal), expected)
+                },
+                false => (format!("separator=_,{}", name), flag),
+            };
+            let format = NumberFormat::from_spec(&spec).unwrap();
+            assert_eq!(format.flags(), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn from_spec_punctuation_test() {
+        let format = NumberFormat::from_spec("").unwrap();
+        assert_eq!(format, NumberFormat::builder().build().unwrap());
+
+        let spec = "separator=_,required_exponent_digits,no_special";
+        let format = NumberFormat::from_spec(spec).unwrap();
+        assert_eq!(
+            format.flags(),
+            NumberFormat::REQUIRED_EXPONENT_DIGITS | NumberFormat::NO_SPECIAL
+        );
+        assert_eq!(format.digit_separator(), b'\x00');
+
+        let spec = "decimal_point=,,separator=.,internal_digit_separator";
+        let format = NumberFormat::from_spec(spec).unwrap();
+        assert_eq!(format.decimal_point(), b',');
+        assert_eq!(format.digit_separator(), b'.');
+
+        let format = NumberFormat::from_spec("exponent_decimal=d,exponent_backup=#").unwrap();
+        assert_eq!(format.exponent_decimal(), b'd');
+        assert_eq!(format.exponent_backup(), b'#');
+    }
+
+    #[test]
+    fn from_spec_invalid_test() {
+        assert_eq!(NumberFormat::from_spec("unknown"), None);
+        assert_eq!(NumberFormat::from_spec("no_special,"), None);
+        assert_eq!(NumberFormat::from_spec(",no_special"), None);
+        assert_eq!(NumberFormat::from_spec("no_special,,required_digits"), None);
+        assert_eq!(NumberFormat::from_spec("No_Special"), None);
+        assert_eq!(NumberFormat::from_spec("no_special=_"), None);
+        assert_eq!(NumberFormat::from_spec("separator="), None);
+        assert_eq!(NumberFormat::from_spec("separator=__"), None);
+        assert_eq!(NumberFormat::from_spec("separator=é"), None);
+        assert_eq!(NumberFormat::from_spec("separator=.,internal_digit_separator"), None);
+        assert_eq!(NumberFormat::from_spec("no_special,case_sensitive_special"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_spec_test() {
+        let format = NumberFormat::builder().build().unwrap();
+        assert_eq!(format.to_spec().to_string(), "");
+
+        let spec =
+            "separator=_,required_exponent_digits,no_special,integer_internal_digit_separator";
+        let format = NumberFormat::from_spec(spec).unwrap();
+        assert_eq!(format.to_spec().to_string(), spec);
+
+        let formats = [
+            NumberFormat::RUST_LITERAL,
+            NumberFormat::CSHARP7_LITERAL,
+            NumberFormat::JSON,
+            NumberFormat::C_STRING,
+            NumberFormat::PERMISSIVE,
+            NumberFormat::IGNORE.rebuild().digit_separator(b'_').build().unwrap(),
+        ];
+        for &format in formats.iter() {
+            let spec = format.to_spec().to_string();
+            assert_eq!(NumberFormat::from_spec(&spec), Some(format), "{}", spec);
+        }
+    }
+}
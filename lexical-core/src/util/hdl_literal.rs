@@ -0,0 +1,282 @@
+//! Sized and based literals of hardware description languages.
+
+#![cfg(feature = "hdl")]
+
+use crate::error::ErrorCode;
+
+// SIZED LITERAL
+// -------------
+
+/// Integer literal of a hardware description language, with its width.
+///
+/// Returned by `parse_verilog_literal` and `parse_vhdl_literal`. The
+/// value holds the bits of the literal, so the value of signed literals
+/// is the two's complement of the bits, with the given width.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::SizedLiteral;
+///
+/// # pub fn main() {
+/// let value = lexical_core::parse_verilog_literal::<u32>(b"8'shFF");
+/// assert_eq!(value, Ok(SizedLiteral { width: Some(8), signed: true, value: 0xFF }));
+///
+/// let value = lexical_core::parse_vhdl_literal::<u32>(b"16#FF#");
+/// assert_eq!(value, Ok(SizedLiteral { width: None, signed: false, value: 0xFF }));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SizedLiteral<N> {
+    /// Width of the literal in bits, or `None` if the literal is unsized.
+    pub width: Option<u32>,
+    /// If the literal is signed.
+    pub signed: bool,
+    /// Bits of the literal.
+    pub value: N,
+}
+
+// VERILOG PREFIX
+// --------------
+
+/// Width, signedness and radix of a Verilog literal, and the index of its digits.
+pub(crate) struct VerilogPrefix {
+    /// Width of the literal in bits, if sized.
+    pub(crate) width: Option<u32>,
+    /// If the literal is signed.
+    pub(crate) signed: bool,
+    /// Radix of the digits.
+    pub(crate) radix: u32,
+    /// Index of the first digit.
+    pub(crate) start: usize,
+}
+
+/// Parse the width, signedness and base of a Verilog literal, like `8'sh`.
+///
+/// The width is a non-zero decimal number, and may be omitted. The
+/// base is `b`, `o`, `d` or `h`, and may follow an `s` for signed
+/// literals, both case-insensitive. Literals without a base, like `15`,
+/// are unsized, signed decimal numbers. Returns the error code and its
+/// index for an invalid prefix.
+pub(crate) fn verilog_literal_prefix(bytes: &[u8]) -> Result<VerilogPrefix, (ErrorCode, usize)> {
+    let size_len = bytes.iter().take_while(|&&c| c.is_ascii_digit() || c == b'_').count();
+    if bytes.get(size_len) != Some(&b'\'') {
+        return Ok(VerilogPrefix {
+            width: None,
+            signed: true,
+            radix: 10,
+            start: 0,
+        });
+    }
+
+    // Parse the width, which may have `_` after the first digit.
+    let width = match bytes[..size_len].first() {
+        None => None,
+        Some(b'1'..=b'9') => {
+            let mut width: u32 = 0;
+            for &c in bytes[..size_len].iter().filter(|&&c| c != b'_') {
+                width = width
+                    .checked_mul(10)
+                    .and_then(|width| width.checked_add((c - b'0') as u32))
+                    .ok_or((ErrorCode::Overflow, 0))?;
+            }
+            Some(width)
+        },
+        Some(_) => return Err((ErrorCode::InvalidDigit, 0)),
+    };
+
+    // Parse the signedness and base.
+    let mut index = size_len + 1;
+    let signed = match bytes.get(index) {
+        Some(&b's') | Some(&b'S') => {
+            index += 1;
+            true
+        },
+        _ => false,
+    };
+    let radix = match bytes.get(index) {
+        Some(&b'b') | Some(&b'B') => 2,
+        Some(&b'o') | Some(&b'O') => 8,
+        Some(&b'd') | Some(&b'D') => 10,
+        Some(&b'h') | Some(&b'H') => 16,
+        Some(_) => return Err((ErrorCode::InvalidDigit, index)),
+        None => return Err((ErrorCode::Empty, index)),
+    };
+    Ok(VerilogPrefix {
+        width,
+        signed,
+        radix,
+        start: index + 1,
+    })
+}
+
+// VHDL BASED LITERAL
+// ------------------
+
+/// Parts of a VHDL based literal, like `16#FF#E2`.
+pub(crate) struct VhdlParts {
+    /// Radix of the digits.
+    pub(crate) radix: u32,
+    /// Range of the digits, between the `#` delimiters.
+    pub(crate) digits: (usize, usize),
+    /// The exponent, which may not be negative.
+    pub(crate) exponent: u32,
+}
+
+/// Get the index of the first `_` separator that is not between two digits.
+#[inline]
+fn invalid_separator(digits: &[u8]) -> Option<usize> {
+    let is_separator = |index: usize| digits.get(index) == Some(&b'_');
+    (0..digits.len()).find(|&index| {
+        is_separator(index)
+            && (index == 0 || is_separator(index - 1) || index + 1 == digits.len())
+    })
+}
+
+/// Parse a decimal VHDL integer, with `_` between digits.
+///
+/// Returns the value, saturated to `u32::max_value()`, and the number
+/// of bytes processed, or the error code and its index.
+fn vhdl_decimal(bytes: &[u8]) -> Result<(u32, usize), (ErrorCode, usize)> {
+    let count = bytes.iter().take_while(|&&c| c.is_ascii_digit() || c == b'_').count();
+    match bytes.first() {
+        Some(c) if c.is_ascii_digit() => (),
+        Some(_) => return Err((ErrorCode::InvalidDigit, 0)),
+        None => return Err((ErrorCode::Empty, 0)),
+    }
+    if let Some(index) = invalid_separator(&bytes[..count]) {
+        return Err((ErrorCode::InvalidDigit, index));
+    }
+    let value = bytes[..count].iter().filter(|&&c| c != b'_').fold(0u32, |value, &c| {
+        value.saturating_mul(10).saturating_add((c - b'0') as u32)
+    });
+    Ok((value, count))
+}
+
+/// Split a VHDL based literal, like `16#FF#` or `2:1010:`, into its parts.
+///
+/// The base is from 2 to 16, and the delimiters are `#`, or `:` for
+/// both. An exponent, like `E2`, may follow, and multiplies the value
+/// by a power of the base. Separators must be between two digits, and
+/// the digits are not validated. Returns the error code and its index
+/// for an invalid literal.
+pub(crate) fn vhdl_literal_parts(bytes: &[u8]) -> Result<VhdlParts, (ErrorCode, usize)> {
+    let (radix, mut index) = vhdl_decimal(bytes)?;
+    if !(2..=16).contains(&radix) {
+        return Err((ErrorCode::InvalidDigit, 0));
+    }
+    let delimiter = match bytes.get(index) {
+        Some(&c) if c == b'#' || c == b':' => c,
+        Some(_) => return Err((ErrorCode::InvalidDigit, index)),
+        None => return Err((ErrorCode::Empty, index)),
+    };
+    index += 1;
+
+    // Find the digits, which must be followed by the same delimiter.
+    let start = index;
+    let is_digit = |&&c: &&u8| c.is_ascii_alphanumeric() || c == b'_';
+    let count = bytes[start..].iter().take_while(is_digit).count();
+    if let Some(offset) = invalid_separator(&bytes[start..start + count]) {
+        return Err((ErrorCode::InvalidDigit, start + offset));
+    }
+    index += count;
+    match bytes.get(index) {
+        Some(&c) if c == delimiter => (),
+        Some(_) => return Err((ErrorCode::InvalidDigit, index)),
+        None => return Err((ErrorCode::Empty, index)),
+    }
+    let digits = (start, index);
+    index += 1;
+
+    // Parse the exponent, which may have a `+` sign.
+    let exponent = match bytes.get(index) {
+        None => 0,
+        Some(&b'e') | Some(&b'E') => {
+            index += 1;
+            if bytes.get(index) == Some(&b'+') {
+                index += 1;
+            }
+            let (exponent, count) =
+                vhdl_decimal(&bytes[index..]).map_err(|(code, offset)| (code, index + offset))?;
+            index += count;
+            exponent
+        },
+        Some(_) => return Err((ErrorCode::InvalidDigit, index)),
+    };
+    if index != bytes.len() {
+        return Err((ErrorCode::InvalidDigit, index));
+    }
+    Ok(VhdlParts {
+        radix,
+        digits,
+        exponent,
+    })
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verilog_literal_prefix_test() {
+        let check = |bytes: &[u8], width, signed, radix, start| {
+            let prefix = verilog_literal_prefix(bytes).unwrap();
+            assert_eq!(prefix.width, width);
+            assert_eq!(prefix.signed, signed);
+            assert_eq!(prefix.radix, radix);
+            assert_eq!(prefix.start, start);
+        };
+        check(b"8'hFF", Some(8), false, 16, 3);
+        check(b"12'b1010", Some(12), false, 2, 4);
+        check(b"1_6'sD9", Some(16), true, 10, 6);
+        check(b"'o17", None, false, 8, 2);
+        check(b"'SH1", None, true, 16, 3);
+        check(b"15", None, true, 10, 0);
+        check(b"1_5", None, true, 10, 0);
+
+        let error = |bytes: &[u8]| verilog_literal_prefix(bytes).err().unwrap();
+        assert_eq!(error(b"0'h1"), (ErrorCode::InvalidDigit, 0));
+        assert_eq!(error(b"_8'h1"), (ErrorCode::InvalidDigit, 0));
+        assert_eq!(error(b"4294967296'h1"), (ErrorCode::Overflow, 0));
+        assert_eq!(error(b"8'x1"), (ErrorCode::InvalidDigit, 2));
+        assert_eq!(error(b"8's"), (ErrorCode::Empty, 3));
+        assert_eq!(error(b"8'"), (ErrorCode::Empty, 2));
+    }
+
+    #[test]
+    fn vhdl_literal_parts_test() {
+        let check = |bytes: &[u8], radix, digits, exponent| {
+            let parts = vhdl_literal_parts(bytes).unwrap();
+            assert_eq!(parts.radix, radix);
+            assert_eq!(parts.digits, digits);
+            assert_eq!(parts.exponent, exponent);
+        };
+        check(b"16#FF#", 16, (3, 5), 0);
+        check(b"2:1010_1010:", 2, (2, 11), 0);
+        check(b"1_6#F#e+1_0", 16, (4, 5), 10);
+        check(b"8#17#E2", 8, (2, 4), 2);
+        check(b"3##", 3, (2, 2), 0);
+
+        let error = |bytes: &[u8]| vhdl_literal_parts(bytes).err().unwrap();
+        assert_eq!(error(b""), (ErrorCode::Empty, 0));
+        assert_eq!(error(b"#F#"), (ErrorCode::InvalidDigit, 0));
+        assert_eq!(error(b"1#1#"), (ErrorCode::InvalidDigit, 0));
+        assert_eq!(error(b"17#1#"), (ErrorCode::InvalidDigit, 0));
+        assert_eq!(error(b"1__6#F#"), (ErrorCode::InvalidDigit, 2));
+        assert_eq!(error(b"16"), (ErrorCode::Empty, 2));
+        assert_eq!(error(b"16#FF"), (ErrorCode::Empty, 5));
+        assert_eq!(error(b"16#FF:"), (ErrorCode::InvalidDigit, 5));
+        assert_eq!(error(b"16#_FF#"), (ErrorCode::InvalidDigit, 3));
+        assert_eq!(error(b"16#F__F#"), (ErrorCode::InvalidDigit, 5));
+        assert_eq!(error(b"16#FF_#"), (ErrorCode::InvalidDigit, 5));
+        assert_eq!(error(b"16#F.F#"), (ErrorCode::InvalidDigit, 4));
+        assert_eq!(error(b"16#FF#E-1"), (ErrorCode::InvalidDigit, 7));
+        assert_eq!(error(b"16#FF#E"), (ErrorCode::Empty, 7));
+        assert_eq!(error(b"16#FF#1"), (ErrorCode::InvalidDigit, 6));
+        assert_eq!(error(b"16#FF#E1 "), (ErrorCode::InvalidDigit, 8));
+    }
+}
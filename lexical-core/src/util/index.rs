@@ -1,7 +1,11 @@
 //! Macro to facilitate indexing for unchecked variants.
+//!
+//! With the `safe` feature, these macros use checked indexing,
+//! which panics rather than invoking undefined behavior.
 
 /// Macro to index without bounds checking.
 #[allow(unused_macros)]
+#[cfg(not(feature = "safe"))]
 macro_rules! unchecked_index {
     // Get
     ($container:ident[$index:expr]) => (
@@ -16,6 +20,7 @@ macro_rules! unchecked_index {
 
 /// Macro to mutably index without bounds checking.
 #[allow(unused_macros)]
+#[cfg(not(feature = "safe"))]
 macro_rules! unchecked_index_mut {
     // Get
     ($container:ident[$index:expr]) => {
@@ -27,3 +32,33 @@ macro_rules! unchecked_index_mut {
         unsafe { *$container.get_unchecked_mut($index) = $rhs }
     };
 }
+
+/// Macro to index with bounds checking.
+#[allow(unused_macros)]
+#[cfg(feature = "safe")]
+macro_rules! unchecked_index {
+    // Get
+    ($container:ident[$index:expr]) => (
+        $container[$index]
+    );
+
+    // Get
+    ($obj:ident$(.$subobj:ident)*[$index:expr]) => (
+        $obj$(.$subobj)*[$index]
+    );
+}
+
+/// Macro to mutably index with bounds checking.
+#[allow(unused_macros)]
+#[cfg(feature = "safe")]
+macro_rules! unchecked_index_mut {
+    // Get
+    ($container:ident[$index:expr]) => {
+        $container[$index]
+    };
+
+    // Set
+    ($container:ident[$index:expr] = $rhs:expr) => {
+        $container[$index] = $rhs
+    };
+}
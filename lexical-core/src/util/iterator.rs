@@ -2,9 +2,6 @@
 
 use crate::lib::slice;
 
-#[cfg(feature = "format")]
-use super::skip_value::*;
-
 /// An iterator that knows if it has been fully consumed yet.
 ///
 /// A consumed iterator will guarantee to return `None` for the next
@@ -40,27 +37,113 @@ impl<'a, T> AsPtrIterator<'a, T> for slice::Iter<'a, T> {
     }
 }
 
-// Type for iteration without any digit separators.
-pub(crate) type IteratorNoSeparator<'a> = slice::Iter<'a, u8>;
+/// Iterator over the digits of a number.
+///
+/// The parsers are generic over the digits iterator, which is selected
+/// statically by the number format, so the digit separator behavior
+/// is implemented once, here, rather than in each parse function.
+pub(crate) trait Digits<'a>: Clone + ConsumedIterator + AsPtrIterator<'a, u8> {
+    /// Create a digits iterator from bytes and the digit separator.
+    fn new(bytes: &'a [u8], digit_separator: u8) -> Self;
+}
+
+/// Digits iterator over contiguous bytes, without digit separators.
+#[derive(Clone)]
+pub(crate) struct ContiguousDigits<'a> {
+    /// Slice iterator to wrap.
+    iter: slice::Iter<'a, u8>,
+}
+
+impl<'a> Digits<'a> for ContiguousDigits<'a> {
+    #[inline(always)]
+    fn new(bytes: &'a [u8], _: u8) -> Self {
+        ContiguousDigits {
+            iter: bytes.iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for ContiguousDigits<'a> {
+    type Item = &'a u8;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline(always)]
+    fn count(self) -> usize {
+        self.iter.len()
+    }
+}
 
-// Iterate without any skipping any digit separators.
-#[inline(always)]
-pub(crate) fn iterate_digits_no_separator<'a>(bytes: &'a [u8], _: u8) -> IteratorNoSeparator<'a> {
-    bytes.iter()
+impl<'a> ExactSizeIterator for ContiguousDigits<'a> {
 }
 
-// Type for iteration with a digit separator.
-#[cfg(feature = "format")]
-pub(crate) type IteratorSeparator<'a> = SkipValueIterator<'a, u8>;
+impl<'a> AsPtrIterator<'a, u8> for ContiguousDigits<'a> {
+    #[inline(always)]
+    fn as_ptr(&self) -> *const u8 {
+        self.iter.as_slice().as_ptr()
+    }
+}
 
-// Iterate while skipping digit separators.
-#[inline(always)]
-#[cfg(feature = "format")]
-pub(crate) fn iterate_digits_ignore_separator<'a>(
-    bytes: &'a [u8],
+/// Digits iterator that skips digit separators.
+#[derive(Clone)]
+pub(crate) struct SeparatorDigits<'a> {
+    /// Slice iterator to wrap.
+    iter: slice::Iter<'a, u8>,
+    /// Digit separator to skip.
     digit_separator: u8,
-) -> IteratorSeparator<'a> {
-    IteratorSeparator::new(bytes, digit_separator)
+}
+
+impl<'a> Digits<'a> for SeparatorDigits<'a> {
+    #[inline(always)]
+    fn new(bytes: &'a [u8], digit_separator: u8) -> Self {
+        SeparatorDigits {
+            iter: bytes.iter(),
+            digit_separator,
+        }
+    }
+}
+
+impl<'a> Iterator for SeparatorDigits<'a> {
+    type Item = &'a u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let value = self.iter.next()?;
+            if *value != self.digit_separator {
+                return Some(value);
+            }
+        }
+    }
+}
+
+impl<'a> ConsumedIterator for SeparatorDigits<'a> {
+    // Preconditions: The iterator cannot end with digit separators.
+    // Use debug_assert to enforce this is removed successfully in test scenarios.
+    #[inline]
+    fn consumed(&self) -> bool {
+        // This implementation is essentially a hack.
+        // We rely on callers to ensure this is only ever called without
+        // any trailing digit separators, otherwise, it will incorrectly
+        // report if the iterator itself is consumed.
+        debug_assert!(self.iter.as_slice().last() != Some(&self.digit_separator));
+        self.iter.len() == 0
+    }
+}
+
+impl<'a> AsPtrIterator<'a, u8> for SeparatorDigits<'a> {
+    #[inline(always)]
+    fn as_ptr(&self) -> *const u8 {
+        self.iter.as_slice().as_ptr()
+    }
 }
 
 // TESTS
@@ -88,15 +171,29 @@ mod tests {
     }
 
     #[test]
-    fn iterate_digits_no_separator_test() {
-        assert!(iterate_digits_no_separator(b"01", b'\x00').eq(b"01".iter()));
-        assert!(iterate_digits_no_separator(b"01_01", b'_').eq(b"01_01".iter()));
+    fn contiguous_digits_test() {
+        assert!(ContiguousDigits::new(b"01", b'\x00').eq(b"01".iter()));
+        assert!(ContiguousDigits::new(b"01_01", b'_').eq(b"01_01".iter()));
+
+        let digits = b"12345";
+        let mut iter = ContiguousDigits::new(digits, b'_');
+        assert_eq!(iter.as_ptr(), digits.as_ptr());
+        assert_eq!(iter.clone().count(), 5);
+        assert_eq!(iter.nth(4).unwrap(), &b'5');
+        assert_eq!(iter.consumed(), true);
     }
 
     #[test]
-    #[cfg(feature = "format")]
-    fn iterate_digits_ignore_separator_test() {
-        assert!(iterate_digits_ignore_separator(b"01", b'_').eq(b"01".iter()));
-        assert!(iterate_digits_ignore_separator(b"01_01", b'_').eq(b"0101".iter()));
+    fn separator_digits_test() {
+        assert!(SeparatorDigits::new(b"01", b'_').eq(b"01".iter()));
+        assert!(SeparatorDigits::new(b"01_01", b'_').eq(b"0101".iter()));
+        assert!(SeparatorDigits::new(b"_1__2_", b'_').eq(b"12".iter()));
+
+        let digits = b"1_2";
+        let mut iter = SeparatorDigits::new(digits, b'_');
+        assert_eq!(iter.next(), Some(&b'1'));
+        assert_eq!(iter.as_ptr(), digits[1..].as_ptr());
+        assert_eq!(iter.next(), Some(&b'2'));
+        assert_eq!(iter.consumed(), true);
     }
 }
@@ -0,0 +1,44 @@
+//! JSON integer that may not fit in an `i64`.
+
+// JSON INTEGER
+// ------------
+
+/// Integer parsed as an `i64`, or as a `u64` if it is too large.
+///
+/// Created by `parse_json_integer`. JSON does not limit the range of
+/// integers, but most JSON parsers store them as `i64`, and fall back
+/// to `u64` for large positive values, such as 64-bit hashes and IDs.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{JsonInteger, ParseIntegerOptions};
+///
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::new();
+/// let value = lexical_core::parse_json_integer(b"-15", &options);
+/// assert_eq!(value, Ok(JsonInteger::Signed(-15)));
+///
+/// let value = lexical_core::parse_json_integer(b"18446744073709551615", &options);
+/// assert_eq!(value, Ok(JsonInteger::Unsigned(u64::max_value())));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JsonInteger {
+    /// The integer fits in an `i64`.
+    Signed(i64),
+    /// The integer is positive, and only fits in a `u64`.
+    Unsigned(u64),
+}
+
+impl JsonInteger {
+    /// Get the integer as an `i128`, which holds either variant.
+    #[inline]
+    pub fn as_i128(self) -> i128 {
+        match self {
+            JsonInteger::Signed(value) => value as i128,
+            JsonInteger::Unsigned(value) => value as i128,
+        }
+    }
+}
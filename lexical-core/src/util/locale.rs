@@ -0,0 +1,193 @@
+//! Decimal and grouping conventions of common locales.
+//!
+//! The table is a compact subset of the CLDR number symbols, for the
+//! Latin digits of each locale: the decimal point, the group separator
+//! and the sizes of the digit groups.
+
+#![cfg(feature = "locale")]
+
+// LOCALE
+
+/// Number punctuation of a locale.
+///
+/// Find a locale by name with `Locale::find`, or create options for it
+/// with `WriteFloatOptions::for_locale` or `ParseFloatOptions::for_locale`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::Locale;
+///
+/// # pub fn main() {
+/// let locale = Locale::find("de_DE").unwrap();
+/// assert_eq!(locale.decimal_point(), b',');
+/// assert_eq!(locale.group_separator(), ".");
+/// assert_eq!(locale.grouping(), &[3]);
+///
+/// // Indian grouping, as in `12,34,56,789`.
+/// let locale = Locale::find("en-IN").unwrap();
+/// assert_eq!(locale.grouping(), &[3, 2]);
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// Locale name, as a language and region, such as `de_DE`.
+    name: &'static str,
+    /// Decimal point character.
+    decimal_point: u8,
+    /// Group separator, which may not be ASCII, such as a no-break space.
+    group_separator: &'static str,
+    /// Sizes of the digit groups, from the decimal point.
+    grouping: &'static [u8],
+}
+
+impl Locale {
+    /// Get all known locales, sorted by name.
+    #[inline]
+    pub fn all() -> &'static [Locale] {
+        &LOCALES
+    }
+
+    /// Find a locale by name, such as `de_DE`.
+    ///
+    /// The language and region may be separated by `_` or `-`, and
+    /// are compared case-insensitively. Returns `None` for unknown
+    /// locales.
+    pub fn find(name: &str) -> Option<&'static Locale> {
+        LOCALES.iter().find(|locale| is_locale_name(locale.name, name))
+    }
+
+    /// Get the locale name, such as `de_DE`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the decimal point character.
+    #[inline]
+    pub fn decimal_point(&self) -> u8 {
+        self.decimal_point
+    }
+
+    /// Get the group separator.
+    ///
+    /// Many locales separate groups with a no-break space, `U+00A0`,
+    /// or a narrow no-break space, `U+202F`, which are not ASCII.
+    #[inline]
+    pub fn group_separator(&self) -> &'static str {
+        self.group_separator
+    }
+
+    /// Get the group separator, if it is a single ASCII character.
+    #[inline]
+    pub fn ascii_group_separator(&self) -> Option<u8> {
+        match self.group_separator.as_bytes() {
+            &[c] => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Get the sizes of the digit groups, from the decimal point.
+    ///
+    /// The last size repeats for the remaining digits, like the grouping
+    /// of POSIX `localeconv`, so `[3, 2]` groups `123456789` as
+    /// `12,34,56,789`.
+    #[inline]
+    pub fn grouping(&self) -> &'static [u8] {
+        self.grouping
+    }
+}
+
+/// Check if a locale name matches, ignoring case and the separator.
+fn is_locale_name(expected: &str, name: &str) -> bool {
+    let normalize = |c: &u8| match c {
+        b'-' => b'_',
+        _ => c.to_ascii_lowercase(),
+    };
+    expected.len() == name.len()
+        && expected.as_bytes().iter().map(normalize).eq(name.as_bytes().iter().map(normalize))
+}
+
+// TABLE
+
+macro_rules! locale {
+    ($name:literal, $decimal_point:literal, $group_separator:literal, $grouping:expr) => {
+        Locale {
+            name: $name,
+            decimal_point: $decimal_point,
+            group_separator: $group_separator,
+            grouping: &$grouping,
+        }
+    };
+}
+
+/// Known locales, sorted by name.
+const LOCALES: [Locale; 28] = [
+    locale!("cs_CZ", b',', "\u{a0}", [3]),
+    locale!("da_DK", b',', ".", [3]),
+    locale!("de_AT", b',', "\u{a0}", [3]),
+    locale!("de_CH", b'.', "\u{2019}", [3]),
+    locale!("de_DE", b',', ".", [3]),
+    locale!("en_AU", b'.', ",", [3]),
+    locale!("en_CA", b'.', ",", [3]),
+    locale!("en_GB", b'.', ",", [3]),
+    locale!("en_IN", b'.', ",", [3, 2]),
+    locale!("en_US", b'.', ",", [3]),
+    locale!("es_ES", b',', ".", [3]),
+    locale!("es_MX", b'.', ",", [3]),
+    locale!("fi_FI", b',', "\u{a0}", [3]),
+    locale!("fr_CA", b',', "\u{a0}", [3]),
+    locale!("fr_CH", b',', "\u{202f}", [3]),
+    locale!("fr_FR", b',', "\u{202f}", [3]),
+    locale!("hi_IN", b'.', ",", [3, 2]),
+    locale!("it_IT", b',', ".", [3]),
+    locale!("ja_JP", b'.', ",", [3]),
+    locale!("ko_KR", b'.', ",", [3]),
+    locale!("nb_NO", b',', "\u{a0}", [3]),
+    locale!("nl_NL", b',', ".", [3]),
+    locale!("pl_PL", b',', "\u{a0}", [3]),
+    locale!("pt_BR", b',', ".", [3]),
+    locale!("pt_PT", b',', "\u{a0}", [3]),
+    locale!("ru_RU", b',', "\u{a0}", [3]),
+    locale!("sv_SE", b',', "\u{a0}", [3]),
+    locale!("zh_CN", b'.', ",", [3]),
+];
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_test() {
+        assert_eq!(Locale::find("de_DE").map(Locale::name), Some("de_DE"));
+        assert_eq!(Locale::find("de-de").map(Locale::name), Some("de_DE"));
+        assert_eq!(Locale::find("DE_DE").map(Locale::name), Some("de_DE"));
+        assert_eq!(Locale::find("de"), None);
+        assert_eq!(Locale::find("de_DEX"), None);
+        assert_eq!(Locale::find("xx_XX"), None);
+    }
+
+    #[test]
+    fn table_test() {
+        let locales = Locale::all();
+        for pair in locales.windows(2) {
+            assert!(pair[0].name < pair[1].name);
+        }
+        for locale in locales.iter() {
+            assert!(locale.decimal_point == b'.' || locale.decimal_point == b',');
+            assert!(locale.group_separator.as_bytes() != [locale.decimal_point]);
+            assert!(!locale.grouping.is_empty());
+        }
+    }
+
+    #[test]
+    fn ascii_group_separator_test() {
+        assert_eq!(Locale::find("en_US").unwrap().ascii_group_separator(), Some(b','));
+        assert_eq!(Locale::find("fr_FR").unwrap().ascii_group_separator(), None);
+        assert_eq!(Locale::find("de_CH").unwrap().ascii_group_separator(), None);
+    }
+}
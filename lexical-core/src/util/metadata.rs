@@ -0,0 +1,201 @@
+//! Metadata about how a float was parsed.
+
+// PARSE METADATA
+// --------------
+
+/// Metadata describing how a float was parsed.
+///
+/// Returned by the `FromLexicalMetadata` parsers, alongside the parsed
+/// value, to expose details that do not affect whether parsing succeeds.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{FromLexicalMetadata, ParseFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = ParseFloatOptions::builder()
+///     .max_mantissa_digits(Some(3))
+///     .truncate_mantissa(true)
+///     .build()
+///     .unwrap();
+/// let (value, metadata) = f64::from_lexical_with_metadata(b"1.2345", &options).unwrap();
+/// assert_eq!(value, 1.23);
+/// assert!(metadata.truncated());
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseMetadata {
+    /// If non-zero mantissa digits were discarded.
+    truncated: bool,
+    /// Index of the first byte of the number.
+    start: usize,
+    /// Index past the last byte of the number.
+    end: usize,
+    /// Statistics of the digits, if requested.
+    info: Option<ParseInfo>,
+}
+
+impl ParseMetadata {
+    /// Create metadata from the parse results.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn new(truncated: bool) -> Self {
+        Self {
+            truncated,
+            start: 0,
+            end: 0,
+            info: None,
+        }
+    }
+
+    /// Set the span of the number in the input.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn with_span(mut self, start: usize, end: usize) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+
+    /// Set the statistics of the digits.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn with_info(mut self, info: ParseInfo) -> Self {
+        self.info = Some(info);
+        self
+    }
+
+    /// Get the statistics of the digits, if they were requested.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn info(&self) -> Option<ParseInfo> {
+        self.info
+    }
+
+    /// Get if non-zero mantissa digits were discarded.
+    ///
+    /// Only set if `truncate_mantissa` is enabled and the mantissa
+    /// had more significant digits than `max_mantissa_digits`.
+    #[inline]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Get the index of the first byte of the number.
+    ///
+    /// Excludes skipped whitespace and prefixes, so the number is
+    /// the bytes from `start` to `end`.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Get the index past the last byte of the number.
+    ///
+    /// Excludes skipped whitespace and suffixes, but includes a closing
+    /// parenthesis or the `suffix` option, which are part of the number.
+    #[inline]
+    pub fn end(&self) -> usize {
+        self.end
+    }
+}
+
+// PARSE INFO
+// ----------
+
+/// Statistics of the digits of a parsed float.
+///
+/// Returned by the `FromLexicalInfo` parsers, alongside the parsed
+/// value, so schema inference and precision audits do not need to
+/// parse the string again. Special values, such as NaN and infinity,
+/// have no digits and are exact.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{FromLexicalInfo, ParseFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = ParseFloatOptions::new();
+/// let (value, info) = f64::from_lexical_with_info(b"0.12500", &options).unwrap();
+/// assert_eq!(value, 0.125);
+/// assert_eq!(info.digits(), 3);
+/// assert_eq!(info.exponent(), -1);
+/// assert!(info.exact());
+///
+/// let (_, info) = f64::from_lexical_with_info(b"0.1", &options).unwrap();
+/// assert!(!info.exact());
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseInfo {
+    /// Number of significant digits.
+    digits: usize,
+    /// Exponent of the first significant digit.
+    exponent: i32,
+    /// If non-zero mantissa digits were discarded.
+    truncated: bool,
+    /// If the float exactly represents the digits.
+    exact: bool,
+}
+
+impl ParseInfo {
+    /// Create info from the digit statistics.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn new(digits: usize, exponent: i32, truncated: bool, exact: bool) -> Self {
+        Self {
+            digits,
+            exponent,
+            truncated,
+            exact,
+        }
+    }
+
+    /// Get the number of significant digits.
+    ///
+    /// Leading and trailing zeros are not significant, so `"0.012500"`
+    /// has 3 significant digits, and zero has none. Counts every
+    /// digit of the input, including truncated digits.
+    #[inline]
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Get the exponent of the first significant digit.
+    ///
+    /// The value is `d.ddd * radix^exponent`, so `"1234.5"` has an
+    /// exponent of 3, and `"0.0125"` an exponent of -2. Zero has an
+    /// exponent of 0.
+    #[inline]
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Get if non-zero mantissa digits were discarded.
+    ///
+    /// Only set if `truncate_mantissa` is enabled and the mantissa
+    /// had more significant digits than `max_mantissa_digits`.
+    #[inline]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Get if the float exactly represents the digits.
+    ///
+    /// Floats rounded from the digits, or clamped to infinity or
+    /// zero, are not exact.
+    #[inline]
+    pub fn exact(&self) -> bool {
+        self.exact
+    }
+}
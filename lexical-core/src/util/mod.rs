@@ -18,30 +18,91 @@ if #[cfg(test)] {
 }} // cfg_if
 
 mod algorithm;
+mod alternate_form;
+#[cfg(feature = "floats")]
+mod big_exponent;
 mod consume;
+#[cfg(feature = "format")]
+mod cxx_literal;
+mod decimal_digits;
 mod digit;
+#[cfg(feature = "integers")]
 mod div128;
+mod exactness;
+mod field;
+mod float_part;
 mod format; // TODO(ahuszagh) Move to crate::options
+#[cfg(feature = "hdl")]
+mod hdl_literal;
 mod iterator;
+mod json_integer;
+#[cfg(any(feature = "floats", test))]
 mod limb;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(feature = "floats")]
 mod log2;
+mod metadata;
+mod number_class;
+#[cfg(feature = "floats")]
+mod positional;
 mod options; // TODO(ahuszagh) Move to crate::options
+mod overflow;
+#[cfg(feature = "floats")]
+mod parsed_number;
+#[cfg(feature = "floats")]
+mod raw_number;
 mod rounding;
+#[cfg(feature = "format")]
+mod rust_literal;
+mod serialize;
 mod sign;
+#[cfg(feature = "std")]
+mod sort_key;
 
 // Publicly export everything with crate-visibility.
 pub(crate) use self::algorithm::*;
 pub(crate) use self::consume::*;
 pub(crate) use self::digit::*;
+#[cfg(feature = "integers")]
 pub(crate) use self::div128::*;
 pub(crate) use self::iterator::*;
+#[cfg(feature = "floats")]
 pub(crate) use self::limb::*;
+#[cfg(feature = "floats")]
 pub(crate) use self::log2::*;
+#[cfg(feature = "std")]
+pub(crate) use self::sort_key::*;
 
 // Publicly export config globally.
+pub use self::alternate_form::*;
+#[cfg(feature = "floats")]
+pub use self::big_exponent::*;
+#[cfg(feature = "format")]
+pub use self::cxx_literal::*;
+pub use self::decimal_digits::*;
+pub use self::exactness::*;
+pub use self::field::*;
+pub use self::float_part::*;
+pub use self::json_integer::*;
 pub use self::format::*; // TODO(ahuszagh) Move to crate::options
+#[cfg(feature = "hdl")]
+pub use self::hdl_literal::*;
+#[cfg(feature = "locale")]
+pub use self::locale::*;
+pub use self::metadata::*;
+pub use self::number_class::*;
+#[cfg(feature = "floats")]
+pub use self::positional::*;
 pub use self::options::*; // TODO(ahuszagh) Move to crate::options
+pub use self::overflow::*;
+#[cfg(feature = "floats")]
+pub use self::parsed_number::*;
+#[cfg(feature = "floats")]
+pub use self::raw_number::*;
 pub use self::rounding::*;
+#[cfg(feature = "format")]
+pub use self::rust_literal::*;
 pub use self::sign::*;
 
 cfg_if! {
@@ -49,9 +110,3 @@ if #[cfg(feature = "power_of_two")] {
     mod fill;
     pub(crate) use self::fill::*;
 }} // cfg_if
-
-cfg_if! {
-if #[cfg(feature = "format")] {
-    mod skip_value;
-    pub(crate) use self::skip_value::*;
-}} // cfg_if
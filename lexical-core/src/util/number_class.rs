@@ -0,0 +1,57 @@
+//! Syntactic class of a numeric string.
+
+// NUMBER CLASS
+// ------------
+
+/// Syntactic class of a numeric string.
+///
+/// Returned by `classify`, which checks the syntax of a number against
+/// the number format without converting it, so parsers may decide how
+/// to store a number before parsing it, such as an `i64` for integers
+/// and an `f64` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{NumberClass, ParseFloatOptions};
+///
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = ParseFloatOptions::new();
+/// assert_eq!(lexical_core::classify(b"-12", &options), NumberClass::Integer);
+/// assert_eq!(lexical_core::classify(b"1.5e3", &options), NumberClass::Float);
+/// assert_eq!(lexical_core::classify(b"inf", &options), NumberClass::Infinity);
+/// assert_eq!(lexical_core::classify(b"NaN", &options), NumberClass::Nan);
+/// assert_eq!(lexical_core::classify(b"1.5x", &options), NumberClass::Invalid);
+/// # }
+/// # }
+/// ```
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NumberClass {
+    /// Digits without a fraction or an exponent, such as `-12`.
+    Integer,
+    /// Digits with a fraction or an exponent, such as `1.5` or `1e3`.
+    Float,
+    /// The infinity or inf string, with an optional sign.
+    Infinity,
+    /// The NaN string, with an optional sign.
+    Nan,
+    /// Not a complete number in the format.
+    Invalid,
+}
+
+impl NumberClass {
+    /// Get if the number is finite, an integer or a float.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        *self == NumberClass::Integer || *self == NumberClass::Float
+    }
+
+    /// Get if the string is a valid number.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        *self != NumberClass::Invalid
+    }
+}
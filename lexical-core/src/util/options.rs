@@ -2,10 +2,22 @@
 
 #![cfg_attr(rustfmt, rustfmt::skip::macros(const_fn))]
 
-use super::format::NumberFormat;
+use super::format::{
+    is_valid_digit_separator,
+    is_valid_exponent_backup,
+    is_valid_punctuation,
+    NumberFormat
+};
+use super::digit::is_digit;
+use super::alternate_form::AlternateForm;
+#[cfg(feature = "locale")]
+use super::locale::Locale;
 use super::rounding::RoundingKind;
 use crate::config::F64_FORMATTED_SIZE_DECIMAL as FLOAT_SIZE;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 // CONSTANTS
 // ---------
 
@@ -17,8 +29,47 @@ pub(crate) const DEFAULT_NAN_STRING: &'static [u8] = b"NaN";
 pub(crate) const DEFAULT_INFINITY_STRING: &'static [u8] = b"infinity";
 pub(crate) const DEFAULT_INCORRECT: bool = false;
 pub(crate) const DEFAULT_LOSSY: bool = false;
+pub(crate) const DEFAULT_LENIENT: bool = false;
+pub(crate) const DEFAULT_EXPONENT_OVERFLOW_ERROR: bool = false;
+pub(crate) const DEFAULT_UNDERFLOW_ERROR: bool = false;
+pub(crate) const DEFAULT_MAX_EXPONENT_DIGITS: Option<usize> = None;
+pub(crate) const DEFAULT_MAX_MANTISSA_DIGITS: Option<usize> = None;
+pub(crate) const DEFAULT_TRUNCATE_MANTISSA: bool = false;
+pub(crate) const DEFAULT_TRIM_WHITESPACE: bool = false;
+pub(crate) const DEFAULT_SUFFIX: Option<u8> = None;
+pub(crate) const DEFAULT_EMPTY_NAN: bool = false;
+pub(crate) const DEFAULT_SKIP_AFFIXES: &'static [&'static [u8]] = &[];
+pub(crate) const DEFAULT_SIGN_STRINGS: &'static [&'static [u8]] = &[];
+pub(crate) const DEFAULT_EXACT: bool = false;
+pub(crate) const DEFAULT_EXPONENT_BACKUP: Option<u8> = None;
+pub(crate) const DEFAULT_RADIX_PREFIX: bool = false;
+pub(crate) const DEFAULT_DIGIT_SEPARATOR: Option<u8> = None;
 pub(crate) const DEFAULT_ROUNDING: RoundingKind = RoundingKind::NearestTieEven;
 pub(crate) const DEFAULT_TRIM_FLOATS: bool = false;
+pub(crate) const DEFAULT_SCIENTIFIC: bool = false;
+pub(crate) const DEFAULT_POSITIONAL_EXPONENTS: Option<(i32, i32)> = None;
+pub(crate) const DEFAULT_POSITIVE_EXPONENT_SIGN: bool = false;
+pub(crate) const DEFAULT_MIN_EXPONENT_DIGITS: Option<usize> = None;
+pub(crate) const DEFAULT_MAX_WIDTH: Option<usize> = None;
+pub(crate) const DEFAULT_POSITIVE_SIGN: Option<u8> = None;
+pub(crate) const DEFAULT_ALTERNATE_FORM: Option<AlternateForm> = None;
+pub(crate) const DEFAULT_SI_PREFIX: Option<usize> = None;
+pub(crate) const DEFAULT_ROUND_TIES_EVEN: bool = false;
+pub(crate) const DEFAULT_UNSIGNED_ZERO: bool = false;
+pub(crate) const DEFAULT_NEGATIVE_PARENTHESES: bool = false;
+pub(crate) const MAX_MIN_EXPONENT_DIGITS: usize = 5;
+pub(crate) const MAX_SI_PREFIX_DIGITS: usize = 20;
+pub(crate) const C_NAN_STRING: &'static [u8] = b"nan";
+pub(crate) const JS_INF_STRING: &'static [u8] = b"Infinity";
+#[cfg(feature = "format")]
+pub(crate) const XSD_INF_STRING: &'static [u8] = b"INF";
+#[cfg(feature = "format")]
+pub(crate) const PG_INFINITY_STRING: &'static [u8] = b"Infinity";
+
+// Special strings in builders use an alias, so serde does not try to
+// borrow them from the deserializer input.
+type StaticBytes = &'static [u8];
+type StaticBytesList = &'static [&'static [u8]];
 
 // VALIDATORS
 // ----------
@@ -125,17 +176,295 @@ macro_rules! to_infinity_string {
     }};
 }
 
+const_fn!(
+/// Convert an ASCII letter to lowercase.
+#[inline]
+const fn to_ascii_lowercase(c: u8) -> u8 {
+    match c {
+        b'A'..=b'Z' => c + (b'a' - b'A'),
+        _ => c,
+    }
+});
+
+const_fn!(
+/// Check if the float suffix is valid.
+///
+/// The suffix must be an ASCII letter that is not a digit in the
+/// radix, or either exponent character.
+#[inline]
+const fn is_valid_suffix(suffix: u8, radix: u32, format: NumberFormat) -> bool {
+    let lower = to_ascii_lowercase(suffix);
+    if lower < b'a' || lower > b'z' {
+        false
+    } else if is_digit(suffix, radix) {
+        false
+    } else if lower == to_ascii_lowercase(format.exponent_decimal()) {
+        false
+    } else {
+        lower != to_ascii_lowercase(format.exponent_backup())
+    }
+});
+
+const_fn!(
+/// Check if the sign written before non-negative numbers is valid.
+///
+/// The sign must be `+` or a space, like the flags of C's `printf`.
+#[inline]
+const fn is_valid_positive_sign(positive_sign: Option<u8>) -> bool {
+    match positive_sign {
+        None | Some(b'+') | Some(b' ') => true,
+        _ => false,
+    }
+});
+
+const_fn!(
+/// Check if the skipped prefixes or suffixes, or the sign strings, are valid.
+///
+/// Each must be non-empty, and must not start with a digit in the radix,
+/// a sign or the decimal point. Suffixes must also not start with either
+/// exponent character.
+#[inline]
+const fn is_valid_affixes(
+    affixes: &'static [&'static [u8]],
+    radix: u32,
+    format: NumberFormat,
+    is_suffix: bool,
+) -> bool {
+    let mut index = 0;
+    while index < affixes.len() {
+        let affix = affixes[index];
+        if affix.is_empty() {
+            return false;
+        }
+        let first = affix[0];
+        let lower = to_ascii_lowercase(first);
+        if is_digit(first, radix) || first == b'+' || first == b'-' {
+            return false;
+        } else if first == format.decimal_point() {
+            return false;
+        } else if is_suffix && lower == to_ascii_lowercase(format.exponent_decimal()) {
+            return false;
+        } else if is_suffix && lower == to_ascii_lowercase(format.exponent_backup()) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+});
+
+const_fn!(
+/// Override the backup exponent character of the format.
+///
+/// Returns `None` if the character is not valid, or conflicts with
+/// the other punctuation in the format.
+#[inline]
+const fn to_exponent_backup_format(
+    format: NumberFormat,
+    exponent_backup: Option<u8>,
+) -> Option<NumberFormat> {
+    let exponent_backup = match exponent_backup {
+        Some(exponent_backup) => to_ascii_lowercase(exponent_backup),
+        None => return Some(format),
+    };
+    let digit_separator = format.digit_separator();
+    let decimal_point = format.decimal_point();
+    let exponent_decimal = format.exponent_decimal();
+    if !is_valid_exponent_backup(exponent_backup)
+        || !is_valid_punctuation(digit_separator, decimal_point, exponent_decimal, exponent_backup)
+    {
+        None
+    } else {
+        Some(format.with_exponent_backup(exponent_backup))
+    }
+});
+
+/// Override the exponent character of the format used for the radix.
+///
+/// Returns `None` if the character is not valid, or conflicts with
+/// the other punctuation in the format.
+#[inline]
+fn to_exponent_char_format(format: NumberFormat, radix: u32, exponent: u8) -> Option<NumberFormat> {
+    let builder = format.rebuild();
+    if cfg!(feature = "power_of_two") && radix != 10 {
+        builder.exponent_backup(exponent).build()
+    } else {
+        builder.exponent_decimal(exponent).build()
+    }
+}
+
+// RADIX CONSTANTS
+// ---------------
+
+/// Define pre-built options for every supported radix.
+///
+/// Requires a `const fn for_radix(radix: u32) -> Self` in the impl.
+macro_rules! radix_constants {
+    () => {
+        /// Default options for radix 2.
+        #[cfg(feature = "power_of_two")]
+        pub const RADIX_2: Self = Self::for_radix(2);
+
+        /// Default options for radix 3.
+        #[cfg(feature = "radix")]
+        pub const RADIX_3: Self = Self::for_radix(3);
+
+        /// Default options for radix 4.
+        #[cfg(feature = "power_of_two")]
+        pub const RADIX_4: Self = Self::for_radix(4);
+
+        /// Default options for radix 5.
+        #[cfg(feature = "radix")]
+        pub const RADIX_5: Self = Self::for_radix(5);
+
+        /// Default options for radix 6.
+        #[cfg(feature = "radix")]
+        pub const RADIX_6: Self = Self::for_radix(6);
+
+        /// Default options for radix 7.
+        #[cfg(feature = "radix")]
+        pub const RADIX_7: Self = Self::for_radix(7);
+
+        /// Default options for radix 8.
+        #[cfg(feature = "power_of_two")]
+        pub const RADIX_8: Self = Self::for_radix(8);
+
+        /// Default options for radix 9.
+        #[cfg(feature = "radix")]
+        pub const RADIX_9: Self = Self::for_radix(9);
+
+        /// Default options for radix 10.
+        pub const RADIX_10: Self = Self::for_radix(10);
+
+        /// Default options for radix 11.
+        #[cfg(feature = "radix")]
+        pub const RADIX_11: Self = Self::for_radix(11);
+
+        /// Default options for radix 12.
+        #[cfg(feature = "radix")]
+        pub const RADIX_12: Self = Self::for_radix(12);
+
+        /// Default options for radix 13.
+        #[cfg(feature = "radix")]
+        pub const RADIX_13: Self = Self::for_radix(13);
+
+        /// Default options for radix 14.
+        #[cfg(feature = "radix")]
+        pub const RADIX_14: Self = Self::for_radix(14);
+
+        /// Default options for radix 15.
+        #[cfg(feature = "radix")]
+        pub const RADIX_15: Self = Self::for_radix(15);
+
+        /// Default options for radix 16.
+        #[cfg(feature = "power_of_two")]
+        pub const RADIX_16: Self = Self::for_radix(16);
+
+        /// Default options for radix 17.
+        #[cfg(feature = "radix")]
+        pub const RADIX_17: Self = Self::for_radix(17);
+
+        /// Default options for radix 18.
+        #[cfg(feature = "radix")]
+        pub const RADIX_18: Self = Self::for_radix(18);
+
+        /// Default options for radix 19.
+        #[cfg(feature = "radix")]
+        pub const RADIX_19: Self = Self::for_radix(19);
+
+        /// Default options for radix 20.
+        #[cfg(feature = "radix")]
+        pub const RADIX_20: Self = Self::for_radix(20);
+
+        /// Default options for radix 21.
+        #[cfg(feature = "radix")]
+        pub const RADIX_21: Self = Self::for_radix(21);
+
+        /// Default options for radix 22.
+        #[cfg(feature = "radix")]
+        pub const RADIX_22: Self = Self::for_radix(22);
+
+        /// Default options for radix 23.
+        #[cfg(feature = "radix")]
+        pub const RADIX_23: Self = Self::for_radix(23);
+
+        /// Default options for radix 24.
+        #[cfg(feature = "radix")]
+        pub const RADIX_24: Self = Self::for_radix(24);
+
+        /// Default options for radix 25.
+        #[cfg(feature = "radix")]
+        pub const RADIX_25: Self = Self::for_radix(25);
+
+        /// Default options for radix 26.
+        #[cfg(feature = "radix")]
+        pub const RADIX_26: Self = Self::for_radix(26);
+
+        /// Default options for radix 27.
+        #[cfg(feature = "radix")]
+        pub const RADIX_27: Self = Self::for_radix(27);
+
+        /// Default options for radix 28.
+        #[cfg(feature = "radix")]
+        pub const RADIX_28: Self = Self::for_radix(28);
+
+        /// Default options for radix 29.
+        #[cfg(feature = "radix")]
+        pub const RADIX_29: Self = Self::for_radix(29);
+
+        /// Default options for radix 30.
+        #[cfg(feature = "radix")]
+        pub const RADIX_30: Self = Self::for_radix(30);
+
+        /// Default options for radix 31.
+        #[cfg(feature = "radix")]
+        pub const RADIX_31: Self = Self::for_radix(31);
+
+        /// Default options for radix 32.
+        #[cfg(feature = "power_of_two")]
+        pub const RADIX_32: Self = Self::for_radix(32);
+
+        /// Default options for radix 33.
+        #[cfg(feature = "radix")]
+        pub const RADIX_33: Self = Self::for_radix(33);
+
+        /// Default options for radix 34.
+        #[cfg(feature = "radix")]
+        pub const RADIX_34: Self = Self::for_radix(34);
+
+        /// Default options for radix 35.
+        #[cfg(feature = "radix")]
+        pub const RADIX_35: Self = Self::for_radix(35);
+
+        /// Default options for radix 36.
+        #[cfg(feature = "radix")]
+        pub const RADIX_36: Self = Self::for_radix(36);
+    };
+}
+
 // PARSE INTEGER
 // -------------
 
 /// Builder for `ParseIntegerOptions`.
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct ParseIntegerOptionsBuilder {
     /// Radix for integer string.
     radix: u8,
     /// Number format.
     format: Option<NumberFormat>,
+    /// Accept integral values written with a fraction or exponent.
+    lenient: bool,
+    /// Digit separator to skip anywhere in the digits.
+    digit_separator: Option<u8>,
+    /// Detect the radix from a C-style prefix.
+    radix_prefix: bool,
+    /// Strings accepted as a plus sign, in addition to `+`.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    plus_signs: StaticBytesList,
+    /// Strings accepted as a minus sign, in addition to `-`.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    minus_signs: StaticBytesList,
 }
 
 impl ParseIntegerOptionsBuilder {
@@ -145,6 +474,11 @@ impl ParseIntegerOptionsBuilder {
         Self {
             radix: DEFAULT_RADIX,
             format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
         }
     }
 
@@ -162,6 +496,36 @@ impl ParseIntegerOptionsBuilder {
         self.format
     }
 
+    /// Get if integral values with a fraction or exponent are accepted.
+    #[inline(always)]
+    pub const fn get_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Get the digit separator to skip anywhere in the digits.
+    #[inline(always)]
+    pub const fn get_digit_separator(&self) -> Option<u8> {
+        self.digit_separator
+    }
+
+    /// Get if the radix is detected from a C-style prefix.
+    #[inline(always)]
+    pub const fn get_radix_prefix(&self) -> bool {
+        self.radix_prefix
+    }
+
+    /// Get the strings accepted as a plus sign, in addition to `+`.
+    #[inline(always)]
+    pub const fn get_plus_signs(&self) -> &'static [&'static [u8]] {
+        self.plus_signs
+    }
+
+    /// Get the strings accepted as a minus sign, in addition to `-`.
+    #[inline(always)]
+    pub const fn get_minus_signs(&self) -> &'static [&'static [u8]] {
+        self.minus_signs
+    }
+
     // SETTERS
 
     /// Set the radix for ParseIntegerOptionsBuilder.
@@ -179,6 +543,70 @@ impl ParseIntegerOptionsBuilder {
         self
     }
 
+    /// Set if integral values with a fraction or exponent are accepted.
+    ///
+    /// When enabled, inputs such as `"3.000"`, `"1e3"` or `"2.5e1"` parse
+    /// as integers, and an error is only returned if a non-zero fractional
+    /// part would be discarded. Digit separators are not supported
+    /// in lenient mode.
+    #[inline(always)]
+    pub const fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Set the digit separator to skip anywhere in the digits.
+    ///
+    /// Unlike the digit separator of a `NumberFormat`, this does not
+    /// require the `format` feature: the separator is skipped anywhere
+    /// in the digits, so `"1_000_000"` parses as `1000000` with a digit
+    /// separator of `b'_'`. The digit separator must be an ASCII
+    /// character that is not a digit or sign, and cannot be used
+    /// in lenient mode.
+    #[inline(always)]
+    pub const fn digit_separator(mut self, digit_separator: Option<u8>) -> Self {
+        self.digit_separator = digit_separator;
+        self
+    }
+
+    /// Set if the radix is detected from a C-style prefix.
+    ///
+    /// When enabled, the radix is detected like C's `strtol` with a base
+    /// of 0: digits prefixed with `0x` or `0X` are hexadecimal, digits
+    /// with a leading `0` are octal, and all other digits are decimal.
+    /// The sign comes before the prefix, so `"-0x1F"` parses as `-31`.
+    /// The radix must be 10, and the number format, digit separators
+    /// and lenient mode are not supported.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn radix_prefix(mut self, radix_prefix: bool) -> Self {
+        self.radix_prefix = radix_prefix;
+        self
+    }
+
+    /// Set the strings accepted as a plus sign, in addition to `+`.
+    ///
+    /// The longest string that matches is the sign of the integer, and
+    /// must not be followed by another sign. Sign strings must be
+    /// non-empty, and must not start with a digit, `+` or `-`.
+    #[inline(always)]
+    pub const fn plus_signs(mut self, plus_signs: &'static [&'static [u8]]) -> Self {
+        self.plus_signs = plus_signs;
+        self
+    }
+
+    /// Set the strings accepted as a minus sign, in addition to `-`.
+    ///
+    /// For example, `&[b"\xE2\x88\x92"]` accepts the Unicode minus
+    /// sign, U+2212, so `"\u{2212}15"` parses as `-15`. Negative integers
+    /// are rejected for unsigned types, like with `-`. Sign strings must
+    /// be non-empty, and must not start with a digit, `+` or `-`.
+    #[inline(always)]
+    pub const fn minus_signs(mut self, minus_signs: &'static [&'static [u8]]) -> Self {
+        self.minus_signs = minus_signs;
+        self
+    }
+
     // BUILDERS
 
     const_fn!(
@@ -187,9 +615,44 @@ impl ParseIntegerOptionsBuilder {
     pub const fn build(self) -> Option<ParseIntegerOptions> {
         let radix = to_radix!(self.radix) as u32;
         let format = self.format;
+        let lenient = self.lenient;
+        let digit_separator = self.digit_separator;
+        let radix_prefix = self.radix_prefix;
+        let plus_signs = self.plus_signs;
+        let minus_signs = self.minus_signs;
+
+        // Validate the digit separator, which is not supported in lenient mode.
+        if let Some(digit_separator) = digit_separator {
+            if lenient || !is_valid_digit_separator(digit_separator) {
+                return None;
+            }
+        }
+        // Validate the radix prefix, which only supports the default parser.
+        if radix_prefix {
+            match (radix, format, lenient, digit_separator) {
+                (10, None, false, None) => (),
+                _ => return None,
+            }
+        }
+        // Validate the sign strings are not part of the number.
+        let number_format = match format {
+            Some(format) => format,
+            None => DEFAULT_FORMAT,
+        };
+        if !is_valid_affixes(plus_signs, radix, number_format, false)
+            || !is_valid_affixes(minus_signs, radix, number_format, false)
+        {
+            return None;
+        }
+
         Some(ParseIntegerOptions {
             radix,
             format,
+            lenient,
+            digit_separator,
+            radix_prefix,
+            plus_signs,
+            minus_signs,
         })
     });
 }
@@ -216,12 +679,22 @@ impl Default for ParseIntegerOptionsBuilder {
 /// # }
 /// ```
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ParseIntegerOptions {
     /// Radix for integer string.
     radix: u32,
     /// Number format.
     format: Option<NumberFormat>,
+    /// Accept integral values written with a fraction or exponent.
+    lenient: bool,
+    /// Digit separator to skip anywhere in the digits.
+    digit_separator: Option<u8>,
+    /// Detect the radix from a C-style prefix.
+    radix_prefix: bool,
+    /// Strings accepted as a plus sign, in addition to `+`.
+    plus_signs: &'static [&'static [u8]],
+    /// Strings accepted as a minus sign, in addition to `-`.
+    minus_signs: &'static [&'static [u8]],
 }
 
 impl ParseIntegerOptions {
@@ -231,11 +704,55 @@ impl ParseIntegerOptions {
         Self {
             radix: DEFAULT_RADIX as u32,
             format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
         }
     }
 
+    /// Create default options for a radix, without validation.
+    #[inline(always)]
+    const fn for_radix(radix: u32) -> Self {
+        Self {
+            radix,
+            format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    const_fn!(
+    /// Create default options for a format and radix, without validation.
+    ///
+    /// The digit separator of the format is skipped anywhere in the digits.
+    #[inline(always)]
+    #[cfg(feature = "floats")]
+    pub(crate) const fn for_format(format: NumberFormat, radix: u32) -> Self {
+        let digit_separator = match format.digit_separator() {
+            0 => None,
+            digit_separator => Some(digit_separator),
+        };
+        Self {
+            format: Some(format),
+            digit_separator,
+            ..Self::for_radix(radix)
+        }
+    });
+
     // PRE-DEFINED CONSTANTS
 
+    /// Default options, for const and static contexts.
+    ///
+    /// Identical to `new()` and `default()`.
+    pub const DEFAULT: Self = Self::new();
+
+    radix_constants!();
+
     /// Create new options to parse the default binary format.
     #[inline(always)]
     #[cfg(feature = "power_of_two")]
@@ -243,6 +760,11 @@ impl ParseIntegerOptions {
         Self {
             radix: 2,
             format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
         }
     }
 
@@ -252,6 +774,11 @@ impl ParseIntegerOptions {
         Self {
             radix: 10,
             format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
         }
     }
 
@@ -262,6 +789,131 @@ impl ParseIntegerOptions {
         Self {
             radix: 16,
             format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse JSON integers.
+    ///
+    /// Integers must follow the RFC 8259 syntax: an optional minus sign,
+    /// then digits without leading zeros, so `"+1"` and `"01"` are
+    /// rejected. Use `parse_json_integer` to parse integers that may
+    /// only fit in a `u64`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn json() -> Self {
+        Self {
+            radix: 10,
+            format: Some(NumberFormat::JSON),
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse integers like C's `strtol` in the C locale.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn c_locale() -> Self {
+        Self {
+            radix: 10,
+            format: Some(NumberFormat::C_STRING),
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse ANSI SQL integer literals.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn sql() -> Self {
+        Self {
+            radix: 10,
+            format: Some(NumberFormat::SQL),
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse protobuf text format integers.
+    ///
+    /// Integers may be decimal, octal with a leading `0`, or hexadecimal
+    /// with a `0x` or `0X` prefix.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn protobuf_text() -> Self {
+        Self {
+            radix: 10,
+            format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: true,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse the digits of C++ integer literals.
+    ///
+    /// Digit separators are `'`, and must be between digits. Use
+    /// `parse_cxx_literal` to parse literals with radix prefixes,
+    /// such as `0xFF`, and type suffixes, such as `255ull`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn cxx_literal() -> Self {
+        Self {
+            radix: 10,
+            format: Some(NumberFormat::CXX_LITERAL),
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse the digits of Rust integer literals.
+    ///
+    /// Digit separators are `_`, and may not lead the digits. Use
+    /// `parse_rust_literal` to parse literals with radix prefixes,
+    /// such as `0xFF`, and type suffixes, such as `255u8`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn rust_literal() -> Self {
+        Self {
+            radix: 10,
+            format: Some(NumberFormat::RUST_LITERAL),
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+        }
+    }
+
+    /// Create new options to parse integers like Rust's `FromStr`.
+    #[inline(always)]
+    pub const fn rust_string() -> Self {
+        Self {
+            radix: 10,
+            format: None,
+            lenient: DEFAULT_LENIENT,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            radix_prefix: DEFAULT_RADIX_PREFIX,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
         }
     }
 
@@ -279,6 +931,46 @@ impl ParseIntegerOptions {
         self.format
     }
 
+    /// Get if integral values with a fraction or exponent are accepted.
+    #[inline(always)]
+    pub const fn lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Get the digit separator to skip anywhere in the digits.
+    #[inline(always)]
+    pub const fn digit_separator(&self) -> Option<u8> {
+        self.digit_separator
+    }
+
+    /// Get if the radix is detected from a C-style prefix.
+    #[inline(always)]
+    pub const fn radix_prefix(&self) -> bool {
+        self.radix_prefix
+    }
+
+    /// Get the strings accepted as a plus sign, in addition to `+`.
+    #[inline(always)]
+    pub const fn plus_signs(&self) -> &'static [&'static [u8]] {
+        self.plus_signs
+    }
+
+    /// Get the strings accepted as a minus sign, in addition to `-`.
+    #[inline(always)]
+    pub const fn minus_signs(&self) -> &'static [&'static [u8]] {
+        self.minus_signs
+    }
+
+    /// Get the number format used for parsing.
+    #[inline(always)]
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        let format = self.format.unwrap_or(DEFAULT_FORMAT);
+        match self.digit_separator {
+            Some(digit_separator) => format.with_ignored_digit_separator(digit_separator),
+            None => format,
+        }
+    }
+
     // SETTERS
 
     /// Set the radix.
@@ -295,6 +987,41 @@ impl ParseIntegerOptions {
         self.format = format
     }
 
+    /// Set if integral values with a fraction or exponent are accepted.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient
+    }
+
+    /// Set the digit separator to skip anywhere in the digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_digit_separator(&mut self, digit_separator: Option<u8>) {
+        self.digit_separator = digit_separator
+    }
+
+    /// Set if the radix is detected from a C-style prefix.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_radix_prefix(&mut self, radix_prefix: bool) {
+        self.radix_prefix = radix_prefix
+    }
+
+    /// Set the strings accepted as a plus sign, in addition to `+`.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_plus_signs(&mut self, plus_signs: &'static [&'static [u8]]) {
+        self.plus_signs = plus_signs
+    }
+
+    /// Set the strings accepted as a minus sign, in addition to `-`.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_minus_signs(&mut self, minus_signs: &'static [&'static [u8]]) {
+        self.minus_signs = minus_signs
+    }
+
     // BUILDERS
 
     /// Get ParseIntegerOptionsBuilder as a static function.
@@ -308,8 +1035,28 @@ impl ParseIntegerOptions {
         ParseIntegerOptionsBuilder {
             radix: self.radix as u8,
             format: self.format,
+            lenient: self.lenient,
+            digit_separator: self.digit_separator,
+            radix_prefix: self.radix_prefix,
+            plus_signs: self.plus_signs,
+            minus_signs: self.minus_signs,
         }
     }
+
+    /// Create options with a different radix, or `None` if invalid.
+    ///
+    /// Shorthand for `rebuild()`, setting the radix, and `build()`.
+    #[inline]
+    #[cfg(feature = "power_of_two")]
+    pub fn with_radix(self, radix: u8) -> Option<Self> {
+        self.rebuild().radix(radix).build()
+    }
+
+    /// Create options with a different number format, or `None` if invalid.
+    #[inline]
+    pub fn with_format(self, format: NumberFormat) -> Option<Self> {
+        self.rebuild().format(Some(format)).build()
+    }
 }
 
 impl Default for ParseIntegerOptions {
@@ -324,7 +1071,8 @@ impl Default for ParseIntegerOptions {
 
 /// Builder for `ParseFloatOptions`.
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct ParseFloatOptionsBuilder {
     /// Radix for float string.
     radix: u8,
@@ -334,18 +1082,55 @@ pub struct ParseFloatOptionsBuilder {
     exponent_radix: u8,
     /// Number format.
     format: NumberFormat,
+    /// Backup exponent character, overriding the format.
+    exponent_backup: Option<u8>,
     /// Rounding kind for float.
     rounding: RoundingKind,
     /// Use the incorrect, fast parser.
     incorrect: bool,
     /// Use the lossy, intermediate parser.
     lossy: bool,
+    /// Error, rather than clamp, if the exponent overflows.
+    exponent_overflow_error: bool,
+    /// Error, rather than return zero, if the float underflows.
+    underflow_error: bool,
+    /// Maximum number of digits in the exponent.
+    max_exponent_digits: Option<usize>,
+    /// Maximum number of significant digits in the mantissa.
+    max_mantissa_digits: Option<usize>,
+    /// Truncate, rather than error, if the mantissa has too many digits.
+    truncate_mantissa: bool,
+    /// Skip leading and trailing whitespace.
+    trim_whitespace: bool,
+    /// Digit separator to skip anywhere in the digits.
+    digit_separator: Option<u8>,
+    /// Optional suffix after the digits, such as `f` in `1.5f`.
+    suffix: Option<u8>,
+    /// Parse empty strings as `NaN`.
+    empty_nan: bool,
+    /// Prefixes to skip before the number, such as currency symbols.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    skip_prefixes: StaticBytesList,
+    /// Suffixes to skip after the number, such as units.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    skip_suffixes: StaticBytesList,
+    /// Strings accepted as a plus sign, in addition to `+`.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    plus_signs: StaticBytesList,
+    /// Strings accepted as a minus sign, in addition to `-`.
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes_list"))]
+    minus_signs: StaticBytesList,
+    /// Error if the float does not exactly represent the digits.
+    exact: bool,
     /// String representation of Not A Number, aka `NaN`.
-    nan_string: &'static [u8],
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes"))]
+    nan_string: StaticBytes,
     /// Short string representation of `Infinity`.
-    inf_string: &'static [u8],
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes"))]
+    inf_string: StaticBytes,
     /// Long string representation of `Infinity`.
-    infinity_string: &'static [u8],
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes"))]
+    infinity_string: StaticBytes,
 }
 
 impl ParseFloatOptionsBuilder {
@@ -357,9 +1142,24 @@ impl ParseFloatOptionsBuilder {
             exponent_base: DEFAULT_RADIX,
             exponent_radix: DEFAULT_RADIX,
             format: DEFAULT_FORMAT,
+            exponent_backup: DEFAULT_EXPONENT_BACKUP,
             rounding: DEFAULT_ROUNDING,
             incorrect: DEFAULT_INCORRECT,
             lossy: DEFAULT_LOSSY,
+            exponent_overflow_error: DEFAULT_EXPONENT_OVERFLOW_ERROR,
+            underflow_error: DEFAULT_UNDERFLOW_ERROR,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
             infinity_string: DEFAULT_INFINITY_STRING,
@@ -392,6 +1192,12 @@ impl ParseFloatOptionsBuilder {
         self.format
     }
 
+    /// Get the backup exponent character, overriding the format.
+    #[inline(always)]
+    pub const fn get_exponent_backup(&self) -> Option<u8> {
+        self.exponent_backup
+    }
+
     /// Get the rounding kind for float.
     #[inline(always)]
     pub const fn get_rounding(&self) -> RoundingKind {
@@ -410,61 +1216,158 @@ impl ParseFloatOptionsBuilder {
         self.lossy
     }
 
-    /// Get the string representation for `NaN`.
+    /// Get if we error, rather than clamp, on exponent overflow.
     #[inline(always)]
-    pub const fn get_nan_string(&self) -> &'static [u8] {
-        self.nan_string
+    pub const fn get_exponent_overflow_error(&self) -> bool {
+        self.exponent_overflow_error
     }
 
-    /// Get the short string representation for `Infinity`.
+    /// Get if we error, rather than return zero, on underflow.
     #[inline(always)]
-    pub const fn get_inf_string(&self) -> &'static [u8] {
-        self.inf_string
+    pub const fn get_underflow_error(&self) -> bool {
+        self.underflow_error
     }
 
-    /// Get the long string representation for `Infinity`.
+    /// Get the maximum number of digits in the exponent.
     #[inline(always)]
-    pub const fn get_infinity_string(&self) -> &'static [u8] {
-        self.infinity_string
+    pub const fn get_max_exponent_digits(&self) -> Option<usize> {
+        self.max_exponent_digits
     }
 
-    // SETTERS
+    /// Get the maximum number of significant digits in the mantissa.
+    #[inline(always)]
+    pub const fn get_max_mantissa_digits(&self) -> Option<usize> {
+        self.max_mantissa_digits
+    }
 
-    /// Set the radix for ParseFloatOptionsBuilder.
+    /// Get if we truncate, rather than error, on too many mantissa digits.
     #[inline(always)]
-    #[cfg(feature = "power_of_two")]
-    pub const fn radix(mut self, radix: u8) -> Self {
-        self.radix = radix;
-        self
+    pub const fn get_truncate_mantissa(&self) -> bool {
+        self.truncate_mantissa
     }
 
-    /// Set the exponent base for ParseFloatOptionsBuilder.
+    /// Get if we skip leading and trailing whitespace.
     #[inline(always)]
-    #[cfg(feature = "power_of_two")]
-    pub const fn exponent_base(mut self, exponent_base: u8) -> Self {
-        self.exponent_base = exponent_base;
-        self
+    pub const fn get_trim_whitespace(&self) -> bool {
+        self.trim_whitespace
     }
 
-    /// Set the exponent radix for ParseFloatOptionsBuilder.
+    /// Get the digit separator to skip anywhere in the digits.
     #[inline(always)]
-    #[cfg(feature = "power_of_two")]
-    pub const fn exponent_radix(mut self, exponent_radix: u8) -> Self {
-        self.exponent_radix = exponent_radix;
-        self
+    pub const fn get_digit_separator(&self) -> Option<u8> {
+        self.digit_separator
     }
 
-    const_fn!(
-    /// Set the format specifier for ParseFloatOptionsBuilder.
+    /// Get the optional suffix after the digits.
     #[inline(always)]
-    pub const fn format(mut self, format: Option<NumberFormat>) -> Self {
-        self.format = match format {
+    pub const fn get_suffix(&self) -> Option<u8> {
+        self.suffix
+    }
+
+    /// Get if empty strings are parsed as `NaN`.
+    #[inline(always)]
+    pub const fn get_empty_nan(&self) -> bool {
+        self.empty_nan
+    }
+
+    /// Get the prefixes to skip before the number.
+    #[inline(always)]
+    pub const fn get_skip_prefixes(&self) -> &'static [&'static [u8]] {
+        self.skip_prefixes
+    }
+
+    /// Get the suffixes to skip after the number.
+    #[inline(always)]
+    pub const fn get_skip_suffixes(&self) -> &'static [&'static [u8]] {
+        self.skip_suffixes
+    }
+
+    /// Get the strings accepted as a plus sign, in addition to `+`.
+    #[inline(always)]
+    pub const fn get_plus_signs(&self) -> &'static [&'static [u8]] {
+        self.plus_signs
+    }
+
+    /// Get the strings accepted as a minus sign, in addition to `-`.
+    #[inline(always)]
+    pub const fn get_minus_signs(&self) -> &'static [&'static [u8]] {
+        self.minus_signs
+    }
+
+    /// Get if floats that do not exactly represent the digits are rejected.
+    #[inline(always)]
+    pub const fn get_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// Get the string representation for `NaN`.
+    #[inline(always)]
+    pub const fn get_nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Get the short string representation for `Infinity`.
+    #[inline(always)]
+    pub const fn get_inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Get the long string representation for `Infinity`.
+    #[inline(always)]
+    pub const fn get_infinity_string(&self) -> &'static [u8] {
+        self.infinity_string
+    }
+
+    // SETTERS
+
+    /// Set the radix for ParseFloatOptionsBuilder.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn radix(mut self, radix: u8) -> Self {
+        self.radix = radix;
+        self
+    }
+
+    /// Set the exponent base for ParseFloatOptionsBuilder.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn exponent_base(mut self, exponent_base: u8) -> Self {
+        self.exponent_base = exponent_base;
+        self
+    }
+
+    /// Set the exponent radix for ParseFloatOptionsBuilder.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn exponent_radix(mut self, exponent_radix: u8) -> Self {
+        self.exponent_radix = exponent_radix;
+        self
+    }
+
+    const_fn!(
+    /// Set the format specifier for ParseFloatOptionsBuilder.
+    #[inline(always)]
+    pub const fn format(mut self, format: Option<NumberFormat>) -> Self {
+        self.format = match format {
             Some(format) => format,
             None => DEFAULT_FORMAT,
         };
         self
     });
 
+    /// Set the backup exponent character, overriding the format.
+    ///
+    /// The backup exponent character is used for non-decimal radixes,
+    /// where `e` may be a digit, so `b'#'` parses `"1.1#10"` as `6.0`
+    /// in radix 2. The character must not be a letter, digit, or sign,
+    /// or conflict with the decimal point or digit separator.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn exponent_backup(mut self, exponent_backup: Option<u8>) -> Self {
+        self.exponent_backup = exponent_backup;
+        self
+    }
+
     /// Set the rounding kind for ParseFloatOptionsBuilder.
     #[inline(always)]
     #[cfg(feature = "rounding")]
@@ -487,6 +1390,186 @@ impl ParseFloatOptionsBuilder {
         self
     }
 
+    /// Set if we error, rather than clamp, on exponent overflow.
+    ///
+    /// By default, exponents too large to be stored, such as
+    /// `2E200000000000`, are clamped, producing infinity or zero.
+    /// If set, `ErrorCode::ExponentOverflow` is returned instead.
+    /// Exponents that fit in an `i32`, such as `1e2147483647`, are
+    /// still clamped.
+    #[inline(always)]
+    pub const fn exponent_overflow_error(mut self, exponent_overflow_error: bool) -> Self {
+        self.exponent_overflow_error = exponent_overflow_error;
+        self
+    }
+
+    /// Set if we error, rather than return zero, on underflow.
+    ///
+    /// By default, non-zero values too small to be represented,
+    /// such as `1e-5000`, return zero. If set, `ErrorCode::Underflow`
+    /// is returned instead, with the index of the exponent.
+    #[inline(always)]
+    pub const fn underflow_error(mut self, underflow_error: bool) -> Self {
+        self.underflow_error = underflow_error;
+        self
+    }
+
+    /// Set the maximum number of digits in the exponent.
+    ///
+    /// By default, the exponent may have any number of digits. If set,
+    /// floats with more exponent digits, including leading zeros, return
+    /// `ErrorCode::TooManyExponentDigits`, without processing the
+    /// remaining digits. The limit must be non-zero.
+    #[inline(always)]
+    pub const fn max_exponent_digits(mut self, max_exponent_digits: Option<usize>) -> Self {
+        self.max_exponent_digits = max_exponent_digits;
+        self
+    }
+
+    /// Set the maximum number of significant digits in the mantissa.
+    ///
+    /// By default, every digit is examined, and the float is always
+    /// correctly rounded, which may require arbitrary-precision
+    /// arithmetic for long mantissas. If set, mantissas with non-zero
+    /// digits past the limit either return
+    /// `ErrorCode::TooManyMantissaDigits`, or are truncated, depending
+    /// on `truncate_mantissa`. Leading zeros are not significant.
+    /// The limit must be non-zero.
+    #[inline(always)]
+    pub const fn max_mantissa_digits(mut self, max_mantissa_digits: Option<usize>) -> Self {
+        self.max_mantissa_digits = max_mantissa_digits;
+        self
+    }
+
+    /// Set if we truncate, rather than error, on too many mantissa digits.
+    ///
+    /// Digits past `max_mantissa_digits` are discarded, and the float
+    /// is rounded from the remaining digits. Truncating to `N` digits
+    /// changes the value by less than one unit in the `N`th digit,
+    /// a relative error below `radix^(1-N)`, in addition to the error
+    /// from rounding. For 17 or more decimal digits, the result is
+    /// at most 1 ULP from the correctly rounded `f64`.
+    #[inline(always)]
+    pub const fn truncate_mantissa(mut self, truncate_mantissa: bool) -> Self {
+        self.truncate_mantissa = truncate_mantissa;
+        self
+    }
+
+    /// Set if we skip leading and trailing whitespace.
+    ///
+    /// Whitespace is the ASCII whitespace characters, the Unicode
+    /// space separators, line and paragraph separators, and the
+    /// byte order mark, encoded as UTF-8, like JavaScript's `Number()`.
+    /// Trailing whitespace is included in the processed bytes, so it
+    /// is accepted when parsing the complete string.
+    #[inline(always)]
+    pub const fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+
+    /// Set the digit separator to skip anywhere in the digits.
+    ///
+    /// Unlike the digit separator of a `NumberFormat`, this does not
+    /// require the `format` feature: the separator is skipped anywhere
+    /// in the integer, fraction, and exponent digits, so `"1_000.5"`
+    /// parses as `1000.5` with a digit separator of `b'_'`. The digit
+    /// separator must be an ASCII character that is not a digit or sign,
+    /// and must differ from the decimal point and exponent characters.
+    #[inline(always)]
+    pub const fn digit_separator(mut self, digit_separator: Option<u8>) -> Self {
+        self.digit_separator = digit_separator;
+        self
+    }
+
+    /// Set the optional suffix after the digits.
+    ///
+    /// The suffix is skipped if it directly follows the digits or decimal
+    /// point, and is matched case-insensitively, so a suffix of `b'f'`
+    /// accepts `"1.5f"` and `"1.5F"`, like C float literals. The suffix
+    /// must be an ASCII letter that is not a digit or exponent character.
+    #[inline(always)]
+    pub const fn suffix(mut self, suffix: Option<u8>) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Set if empty strings are parsed as `NaN`.
+    ///
+    /// When enabled, an empty string, or a string of only whitespace when
+    /// trimming whitespace, parses as `NaN` rather than returning
+    /// `ErrorCode::Empty`, like missing values in CSV files.
+    #[inline(always)]
+    pub const fn empty_nan(mut self, empty_nan: bool) -> Self {
+        self.empty_nan = empty_nan;
+        self
+    }
+
+    /// Set the prefixes to skip before the number.
+    ///
+    /// At most one prefix is skipped, the longest that matches, so
+    /// `&[b"$", b"\xE2\x82\xAC"]` accepts `"$1.5"` and `"€1.5"`, like
+    /// currency values in spreadsheets. A prefix may follow the sign,
+    /// as in `"-$1.5"`, or the opening parenthesis of a negative value. When trimming whitespace,
+    /// whitespace is also skipped around the prefix. Prefixes must be
+    /// non-empty, and must not start with a digit, sign or decimal point.
+    #[inline(always)]
+    pub const fn skip_prefixes(mut self, skip_prefixes: &'static [&'static [u8]]) -> Self {
+        self.skip_prefixes = skip_prefixes;
+        self
+    }
+
+    /// Set the suffixes to skip after the number.
+    ///
+    /// At most one suffix is skipped, the longest that matches, so
+    /// `&[b"%", b"kg"]` accepts `"15%"` and `"15kg"`. When trimming
+    /// whitespace, whitespace is also skipped around the suffix. Suffixes
+    /// must be non-empty, and must not start with a digit, sign, decimal
+    /// point or exponent character.
+    #[inline(always)]
+    pub const fn skip_suffixes(mut self, skip_suffixes: &'static [&'static [u8]]) -> Self {
+        self.skip_suffixes = skip_suffixes;
+        self
+    }
+
+    /// Set the strings accepted as a plus sign, in addition to `+`.
+    ///
+    /// The longest string that matches is the sign of the number, and
+    /// must not be followed by another sign or an opening parenthesis.
+    /// Sign strings must be non-empty, and must not start with a digit,
+    /// `+`, `-` or the decimal point.
+    #[inline(always)]
+    pub const fn plus_signs(mut self, plus_signs: &'static [&'static [u8]]) -> Self {
+        self.plus_signs = plus_signs;
+        self
+    }
+
+    /// Set the strings accepted as a minus sign, in addition to `-`.
+    ///
+    /// For example, `&[b"\xE2\x88\x92"]` accepts the Unicode minus
+    /// sign, U+2212, so `"\u{2212}1.5"` parses as `-1.5`, like numbers
+    /// formatted by ICU. A prefix may follow the sign, as in
+    /// `"\u{2212}$1.5"`. Sign strings must be non-empty, and must not
+    /// start with a digit, `+`, `-` or the decimal point.
+    #[inline(always)]
+    pub const fn minus_signs(mut self, minus_signs: &'static [&'static [u8]]) -> Self {
+        self.minus_signs = minus_signs;
+        self
+    }
+
+    /// Set if floats that do not exactly represent the digits are rejected.
+    ///
+    /// When enabled, parsing fails with `ErrorCode::Inexact` if the
+    /// digits are not exactly representable, so rounding would change
+    /// the value, such as `"0.1"`, or if the value overflows to infinity
+    /// or underflows to zero. Special values are always exact. Requires
+    /// the correct algorithm, so cannot be used with `incorrect` or `lossy`.
+    #[inline(always)]
+    pub const fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
     /// Set the string representation for `NaN`.
     #[inline(always)]
     pub const fn nan_string(mut self, nan_string: &'static [u8]) -> Self {
@@ -520,8 +1603,20 @@ impl ParseFloatOptionsBuilder {
         let kind = self.rounding.as_u32() << 24;
         let incorrect = (self.incorrect as u32) << 28;
         let lossy = (self.lossy as u32) << 29;
-        let compressed = radix | exponent_base | exponent_radix | kind | incorrect | lossy;
-        let format = self.format;
+        let exponent_overflow_error = (self.exponent_overflow_error as u32) << 30;
+        let underflow_error = (self.underflow_error as u32) << 31;
+        let compressed = radix
+            | exponent_base
+            | exponent_radix
+            | kind
+            | incorrect
+            | lossy
+            | exponent_overflow_error
+            | underflow_error;
+        let format = match to_exponent_backup_format(self.format, self.exponent_backup) {
+            Some(format) => format,
+            None => return None,
+        };
         let nan_string = to_nan_string!(self.nan_string);
         let inf_string = to_inf_string!(self.inf_string);
         let infinity_string = to_infinity_string!(self.infinity_string, self.inf_string);
@@ -530,10 +1625,63 @@ impl ParseFloatOptionsBuilder {
         if self.incorrect && self.lossy {
             return None;
         }
+        // Validate exact floats use the correct algorithm.
+        if self.exact && (self.incorrect || self.lossy) {
+            return None;
+        }
+        // Validate the exponent and mantissa digit limits, if present, are non-zero.
+        if let Some(0) = self.max_exponent_digits {
+            return None;
+        }
+        if let Some(0) = self.max_mantissa_digits {
+            return None;
+        }
+        // Validate the digit separator does not conflict with the punctuation.
+        if let Some(digit_separator) = self.digit_separator {
+            let decimal_point = format.decimal_point();
+            let exponent_decimal = format.exponent_decimal();
+            let exponent_backup = format.exponent_backup();
+            if !is_valid_digit_separator(digit_separator)
+                || !is_valid_punctuation(digit_separator, decimal_point, exponent_decimal, exponent_backup)
+            {
+                return None;
+            }
+        }
+
+        // Validate the suffix is not part of the number.
+        if let Some(suffix) = self.suffix {
+            if !is_valid_suffix(suffix, radix, format) {
+                return None;
+            }
+        }
+        // Validate the skipped prefixes and suffixes are not part of the number.
+        if !is_valid_affixes(self.skip_prefixes, radix, format, false)
+            || !is_valid_affixes(self.skip_suffixes, radix, format, true)
+        {
+            return None;
+        }
+        // Validate the sign strings are not part of the number.
+        if !is_valid_affixes(self.plus_signs, radix, format, false)
+            || !is_valid_affixes(self.minus_signs, radix, format, false)
+        {
+            return None;
+        }
 
         Some(ParseFloatOptions {
             compressed,
             format,
+            max_exponent_digits: self.max_exponent_digits,
+            max_mantissa_digits: self.max_mantissa_digits,
+            truncate_mantissa: self.truncate_mantissa,
+            trim_whitespace: self.trim_whitespace,
+            digit_separator: self.digit_separator,
+            suffix: self.suffix,
+            empty_nan: self.empty_nan,
+            skip_prefixes: self.skip_prefixes,
+            skip_suffixes: self.skip_suffixes,
+            plus_signs: self.plus_signs,
+            minus_signs: self.minus_signs,
+            exact: self.exact,
             nan_string,
             inf_string,
             infinity_string,
@@ -567,16 +1715,42 @@ impl Default for ParseFloatOptionsBuilder {
 /// # }
 /// ```
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ParseFloatOptions {
     /// Compressed storage of the radix, exponent base, exponent radix,
-    /// rounding kind, incorrect, and lossy.
+    /// rounding kind, incorrect, lossy, exponent overflow error,
+    /// and underflow error.
     /// Radix is the lower 8 bits, bits 8-16 are the exponent base,
     /// bits 16-24 are the exponent radix, bits 24-28 are the rounding
-    /// kind, bit 28 is incorrect, and bit 29 is lossy.
+    /// kind, bit 28 is incorrect, bit 29 is lossy, bit 30 is
+    /// exponent overflow error, and bit 31 is underflow error.
     compressed: u32,
     /// Number format.
     format: NumberFormat,
+    /// Maximum number of digits in the exponent.
+    max_exponent_digits: Option<usize>,
+    /// Maximum number of significant digits in the mantissa.
+    max_mantissa_digits: Option<usize>,
+    /// Truncate, rather than error, if the mantissa has too many digits.
+    truncate_mantissa: bool,
+    /// Skip leading and trailing whitespace.
+    trim_whitespace: bool,
+    /// Digit separator to skip anywhere in the digits.
+    digit_separator: Option<u8>,
+    /// Optional suffix after the digits, such as `f` in `1.5f`.
+    suffix: Option<u8>,
+    /// Parse empty strings as `NaN`.
+    empty_nan: bool,
+    /// Prefixes to skip before the number, such as currency symbols.
+    skip_prefixes: &'static [&'static [u8]],
+    /// Suffixes to skip after the number, such as units.
+    skip_suffixes: &'static [&'static [u8]],
+    /// Strings accepted as a plus sign, in addition to `+`.
+    plus_signs: &'static [&'static [u8]],
+    /// Strings accepted as a minus sign, in addition to `-`.
+    minus_signs: &'static [&'static [u8]],
+    /// Error if the float does not exactly represent the digits.
+    exact: bool,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: &'static [u8],
     /// Short string representation of `Infinity`.
@@ -594,14 +1768,78 @@ impl ParseFloatOptions {
         Self {
             compressed,
             format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
             infinity_string: DEFAULT_INFINITY_STRING,
         }
     }
 
+    /// Create default options for a radix, without validation.
+    ///
+    /// The exponent base and exponent radix are the same as the radix.
+    #[inline(always)]
+    const fn for_radix(radix: u32) -> Self {
+        let compressed = radix | (radix << 8) | (radix << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create default options for a format and radix, without validation.
+    ///
+    /// The exponent base and exponent radix are the same as the radix.
+    #[inline(always)]
+    #[cfg(feature = "floats")]
+    pub(crate) const fn for_format(format: NumberFormat, radix: u32) -> Self {
+        Self {
+            format,
+            ..Self::for_radix(radix)
+        }
+    }
+
     // PRE-DEFINED CONSTANTS
 
+    /// Default options, for const and static contexts.
+    ///
+    /// Identical to `new()` and `default()`.
+    pub const DEFAULT: Self = Self::new();
+
+    radix_constants!();
+
+    /// Default decimal options, using the lossy algorithm.
+    pub const LOSSY: Self = Self {
+        compressed: Self::RADIX_10.compressed | 1 << 29,
+        ..Self::RADIX_10
+    };
+
     /// Create new options to write the default binary format.
     #[inline(always)]
     #[cfg(feature = "power_of_two")]
@@ -610,6 +1848,18 @@ impl ParseFloatOptions {
         Self {
             compressed,
             format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
             infinity_string: DEFAULT_INFINITY_STRING,
@@ -623,6 +1873,18 @@ impl ParseFloatOptions {
         Self {
             compressed,
             format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
             infinity_string: DEFAULT_INFINITY_STRING,
@@ -637,12 +1899,470 @@ impl ParseFloatOptions {
         Self {
             compressed,
             format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse JSON numbers.
+    ///
+    /// Special values are rejected, as required by the JSON specification.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn json() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::JSON,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
             infinity_string: DEFAULT_INFINITY_STRING,
         }
     }
 
+    /// Create new options to parse floats like C's `strtod` in the C locale.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn c_locale() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::C_STRING,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: C_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse floats like JavaScript's `Number()`
+    /// and `parseFloat`.
+    ///
+    /// Parsing the complete string matches `Number()`, and parsing a
+    /// partial string matches `parseFloat`, after skipping whitespace.
+    /// Strings JavaScript converts to `NaN` are errors, except `NaN`
+    /// itself, and empty strings are errors rather than `0`. Only
+    /// decimal strings are supported, and exponents require digits,
+    /// so a trailing `e` is an error even when parsing a partial string.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn javascript_string() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        let format = NumberFormat::JAVASCRIPT_STRING.bits()
+            | NumberFormat::REQUIRED_EXPONENT_DIGITS.bits();
+        Self {
+            compressed,
+            format: NumberFormat::new(format),
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: true,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: JS_INF_STRING,
+            infinity_string: JS_INF_STRING,
+        }
+    }
+
+    /// Create new options to parse XML Schema `xs:double` and `xs:float`
+    /// values.
+    ///
+    /// Special values are the case-sensitive `INF`, `-INF` and `NaN`, and
+    /// exponents require digits. Leading and trailing whitespace is not
+    /// trimmed, since XML processors collapse it before validation. A sign
+    /// before `NaN` is accepted, although XML Schema does not allow it.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn xml_schema_double() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::XML_SCHEMA_DOUBLE,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: XSD_INF_STRING,
+            infinity_string: XSD_INF_STRING,
+        }
+    }
+
+    /// Create new options to parse XML Schema `xs:decimal` values.
+    ///
+    /// Exponent notation and special values are rejected.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn xml_schema_decimal() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::XML_SCHEMA_DECIMAL,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse ANSI SQL numeric literals.
+    ///
+    /// Literals may have a sign, and digits on either side of the decimal
+    /// point, and exponents require digits. Special values are rejected,
+    /// since SQL has no literals for them.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn sql() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::SQL,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse floats like PostgreSQL's `float8` input.
+    ///
+    /// Literals use the SQL syntax, and strings cast to a float may also
+    /// be the case-insensitive `NaN`, `inf` or `Infinity`, with leading
+    /// and trailing whitespace.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn postgresql() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        let format = NumberFormat::POSTGRESQL.bits() & !NumberFormat::NO_SPECIAL.bits();
+        Self {
+            compressed,
+            format: NumberFormat::new(format),
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: true,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: PG_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse MySQL numeric literals.
+    ///
+    /// Special values are rejected. MySQL converts strings in a numeric
+    /// context from their longest numeric prefix: parse partial strings
+    /// to match this.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn mysql() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::MYSQL,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse protobuf text format floats.
+    ///
+    /// Floats may have an `f` or `F` suffix, and the special values
+    /// `inf`, `infinity` and `nan` are case-insensitive, like protobuf.
+    /// Integers written in octal or hexadecimal are not floats: parse
+    /// them with `ParseIntegerOptions::protobuf_text()`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn protobuf_text() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::PROTOBUF_TEXT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: Some(b'f'),
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: C_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse numeric fields in CSV files.
+    ///
+    /// Fields use a `.` decimal point, and may have surrounding whitespace
+    /// and `,` thousands separators, which are skipped anywhere in the
+    /// digits. Empty fields parse as `NaN`: disable `empty_nan` to return
+    /// `ErrorCode::Empty` instead, for example to map them to `None`.
+    #[inline(always)]
+    pub const fn csv() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: true,
+            digit_separator: Some(b','),
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: true,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse numeric fields in CSV files with a
+    /// `,` decimal point, as written in most European locales.
+    ///
+    /// Like `csv`, except thousands separators are `.`.
+    #[inline(always)]
+    pub const fn csv_european() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: DEFAULT_FORMAT.with_decimal_point(b','),
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: true,
+            digit_separator: Some(b'.'),
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: true,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse C++ float literals.
+    ///
+    /// Digit separators are `'`, and special values are rejected. Use
+    /// `parse_cxx_literal` to parse literals with type suffixes, such
+    /// as `1.5f`, and hexadecimal floats, such as `0x1.8p3`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn cxx_literal() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::CXX_LITERAL,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse Rust float literals.
+    ///
+    /// Digit separators are `_`, and special values are rejected. Use
+    /// `parse_rust_literal` to parse literals with type suffixes, such
+    /// as `1.5f32`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn rust_literal() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: NumberFormat::RUST_LITERAL,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse floats like Rust's `FromStr`.
+    #[inline(always)]
+    pub const fn rust_string() -> Self {
+        let compressed = 10 | (10 << 8) | (10 << 16) | DEFAULT_ROUNDING.as_u32() << 24;
+        Self {
+            compressed,
+            format: DEFAULT_FORMAT,
+            max_exponent_digits: DEFAULT_MAX_EXPONENT_DIGITS,
+            max_mantissa_digits: DEFAULT_MAX_MANTISSA_DIGITS,
+            truncate_mantissa: DEFAULT_TRUNCATE_MANTISSA,
+            trim_whitespace: DEFAULT_TRIM_WHITESPACE,
+            digit_separator: DEFAULT_DIGIT_SEPARATOR,
+            suffix: DEFAULT_SUFFIX,
+            empty_nan: DEFAULT_EMPTY_NAN,
+            skip_prefixes: DEFAULT_SKIP_AFFIXES,
+            skip_suffixes: DEFAULT_SKIP_AFFIXES,
+            plus_signs: DEFAULT_SIGN_STRINGS,
+            minus_signs: DEFAULT_SIGN_STRINGS,
+            exact: DEFAULT_EXACT,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+            infinity_string: DEFAULT_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to parse floats written in a locale, such as `de_DE`.
+    ///
+    /// Floats use the decimal point of the locale, and group separators
+    /// are skipped anywhere in the digits, if they are a single ASCII
+    /// character. Group separators that are not ASCII, such as the
+    /// no-break spaces of `fr_FR`, are not skipped. Returns `None` for
+    /// unknown locales.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// use lexical_core::ParseFloatOptions;
+    ///
+    /// # pub fn main() {
+    /// let options = ParseFloatOptions::for_locale("de_DE").unwrap();
+    /// let result = lexical_core::parse_with_options::<f64>(b"1.234,5", &options);
+    /// assert_eq!(result, Ok(1234.5));
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "locale")]
+    pub fn for_locale(name: &str) -> Option<Self> {
+        Locale::find(name).map(|locale| {
+            let mut options = Self::decimal();
+            options.format = DEFAULT_FORMAT.with_decimal_point(locale.decimal_point());
+            options.digit_separator = locale.ascii_group_separator();
+            options
+        })
+    }
+
     // GETTERS
 
     /// Get the radix.
@@ -666,7 +2386,11 @@ impl ParseFloatOptions {
     /// Get the rounding kind for float.
     #[inline(always)]
     pub const fn rounding(&self) -> RoundingKind {
-        unsafe { RoundingKind::from_u32((self.compressed & 0xF000000) >> 24) }
+        let bits = (self.compressed & 0xF000000) >> 24;
+        #[cfg(not(feature = "safe"))]
+        return unsafe { RoundingKind::from_u32(bits) };
+        #[cfg(feature = "safe")]
+        return RoundingKind::from_bits_truncate(bits);
     }
 
     /// Get if we use the incorrect, fast parser.
@@ -681,12 +2405,124 @@ impl ParseFloatOptions {
         self.compressed & 0x20000000 != 0
     }
 
+    /// Get if we error, rather than clamp, on exponent overflow.
+    #[inline(always)]
+    pub const fn exponent_overflow_error(&self) -> bool {
+        self.compressed & 0x40000000 != 0
+    }
+
+    /// Get if we error, rather than return zero, on underflow.
+    #[inline(always)]
+    pub const fn underflow_error(&self) -> bool {
+        self.compressed & 0x80000000 != 0
+    }
+
+    /// Get the maximum number of digits in the exponent.
+    #[inline(always)]
+    pub const fn max_exponent_digits(&self) -> Option<usize> {
+        self.max_exponent_digits
+    }
+
+    /// Get the maximum number of significant digits in the mantissa.
+    #[inline(always)]
+    pub const fn max_mantissa_digits(&self) -> Option<usize> {
+        self.max_mantissa_digits
+    }
+
+    /// Get if we truncate, rather than error, on too many mantissa digits.
+    #[inline(always)]
+    pub const fn truncate_mantissa(&self) -> bool {
+        self.truncate_mantissa
+    }
+
+    /// Get if we skip leading and trailing whitespace.
+    #[inline(always)]
+    pub const fn trim_whitespace(&self) -> bool {
+        self.trim_whitespace
+    }
+
+    /// Get the digit separator to skip anywhere in the digits.
+    #[inline(always)]
+    pub const fn digit_separator(&self) -> Option<u8> {
+        self.digit_separator
+    }
+
+    /// Get the optional suffix after the digits.
+    #[inline(always)]
+    pub const fn suffix(&self) -> Option<u8> {
+        self.suffix
+    }
+
+    /// Get if empty strings are parsed as `NaN`.
+    #[inline(always)]
+    pub const fn empty_nan(&self) -> bool {
+        self.empty_nan
+    }
+
+    /// Get the prefixes to skip before the number.
+    #[inline(always)]
+    pub const fn skip_prefixes(&self) -> &'static [&'static [u8]] {
+        self.skip_prefixes
+    }
+
+    /// Get the suffixes to skip after the number.
+    #[inline(always)]
+    pub const fn skip_suffixes(&self) -> &'static [&'static [u8]] {
+        self.skip_suffixes
+    }
+
+    /// Get the strings accepted as a plus sign, in addition to `+`.
+    #[inline(always)]
+    pub const fn plus_signs(&self) -> &'static [&'static [u8]] {
+        self.plus_signs
+    }
+
+    /// Get the strings accepted as a minus sign, in addition to `-`.
+    #[inline(always)]
+    pub const fn minus_signs(&self) -> &'static [&'static [u8]] {
+        self.minus_signs
+    }
+
+    /// Get if floats that do not exactly represent the digits are rejected.
+    #[inline(always)]
+    pub const fn exact(&self) -> bool {
+        self.exact
+    }
+
     /// Get the number format.
     #[inline(always)]
     pub const fn format(&self) -> NumberFormat {
         self.format
     }
 
+    const_fn!(
+    /// Get the number format used for parsing.
+    #[inline(always)]
+    #[cfg(feature = "floats")]
+    pub(crate) const fn number_format(&self) -> NumberFormat {
+        match self.digit_separator {
+            Some(digit_separator) => self.format.with_ignored_digit_separator(digit_separator),
+            None => self.format,
+        }
+    });
+
+    /// Get the options to parse integers with the same syntax, if valid.
+    ///
+    /// The radix, format, digit separator and sign strings are shared.
+    #[inline]
+    #[cfg(feature = "floats")]
+    pub(crate) fn integer_options(&self) -> Option<ParseIntegerOptions> {
+        ParseIntegerOptionsBuilder {
+            radix: self.radix() as u8,
+            format: Some(self.format),
+            digit_separator: self.digit_separator,
+            plus_signs: self.plus_signs,
+            minus_signs: self.minus_signs,
+            ..ParseIntegerOptionsBuilder::new()
+        }
+        .build()
+    }
+
     /// Get the string representation for `NaN`.
     #[inline(always)]
     pub const fn nan_string(&self) -> &'static [u8] {
@@ -707,18 +2543,18 @@ impl ParseFloatOptions {
 
     // NUMBER FORMAT
 
-    /// Get the digit separator character.
-    #[inline(always)]
-    pub const fn digit_separator(&self) -> u8 {
-        self.format.digit_separator()
-    }
-
     /// Get the decimal point character.
     #[inline(always)]
     pub const fn decimal_point(&self) -> u8 {
         self.format.decimal_point()
     }
 
+    /// Get the backup exponent character, for non-decimal radixes.
+    #[inline(always)]
+    pub const fn exponent_backup(&self) -> u8 {
+        self.format.exponent_backup()
+    }
+
     const_fn!(
     /// Get the exponent character.
     #[inline(always)]
@@ -783,6 +2619,108 @@ impl ParseFloatOptions {
         self.compressed |= (lossy as u32) << 29;
     }
 
+    /// Set if we error, rather than clamp, on exponent overflow.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_exponent_overflow_error(&mut self, exponent_overflow_error: bool) {
+        // Unset the 30th bit, then set it based on the exponent overflow error value.
+        self.compressed &= !0x40000000;
+        self.compressed |= (exponent_overflow_error as u32) << 30;
+    }
+
+    /// Set if we error, rather than return zero, on underflow.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_underflow_error(&mut self, underflow_error: bool) {
+        // Unset the 31st bit, then set it based on the underflow error value.
+        self.compressed &= !0x80000000;
+        self.compressed |= (underflow_error as u32) << 31;
+    }
+
+    /// Set the maximum number of digits in the exponent.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_max_exponent_digits(&mut self, max_exponent_digits: Option<usize>) {
+        self.max_exponent_digits = max_exponent_digits
+    }
+
+    /// Set the maximum number of significant digits in the mantissa.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_max_mantissa_digits(&mut self, max_mantissa_digits: Option<usize>) {
+        self.max_mantissa_digits = max_mantissa_digits
+    }
+
+    /// Set if we truncate, rather than error, on too many mantissa digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_truncate_mantissa(&mut self, truncate_mantissa: bool) {
+        self.truncate_mantissa = truncate_mantissa
+    }
+
+    /// Set if we skip leading and trailing whitespace.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_trim_whitespace(&mut self, trim_whitespace: bool) {
+        self.trim_whitespace = trim_whitespace
+    }
+
+    /// Set the digit separator to skip anywhere in the digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_digit_separator(&mut self, digit_separator: Option<u8>) {
+        self.digit_separator = digit_separator
+    }
+
+    /// Set the optional suffix after the digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_suffix(&mut self, suffix: Option<u8>) {
+        self.suffix = suffix
+    }
+
+    /// Set if empty strings are parsed as `NaN`.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_empty_nan(&mut self, empty_nan: bool) {
+        self.empty_nan = empty_nan
+    }
+
+    /// Set the prefixes to skip before the number.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_skip_prefixes(&mut self, skip_prefixes: &'static [&'static [u8]]) {
+        self.skip_prefixes = skip_prefixes
+    }
+
+    /// Set the suffixes to skip after the number.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_skip_suffixes(&mut self, skip_suffixes: &'static [&'static [u8]]) {
+        self.skip_suffixes = skip_suffixes
+    }
+
+    /// Set the strings accepted as a plus sign, in addition to `+`.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_plus_signs(&mut self, plus_signs: &'static [&'static [u8]]) {
+        self.plus_signs = plus_signs
+    }
+
+    /// Set the strings accepted as a minus sign, in addition to `-`.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_minus_signs(&mut self, minus_signs: &'static [&'static [u8]]) {
+        self.minus_signs = minus_signs
+    }
+
+    /// Set if floats that do not exactly represent the digits are rejected.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_exact(&mut self, exact: bool) {
+        self.exact = exact
+    }
+
     /// Set the number format.
     /// Unsafe, use the builder API for option validation.
     #[inline(always)]
@@ -826,14 +2764,57 @@ impl ParseFloatOptions {
             exponent_base: self.exponent_base() as u8,
             exponent_radix: self.exponent_radix() as u8,
             format: self.format,
+            exponent_backup: DEFAULT_EXPONENT_BACKUP,
             rounding: self.rounding(),
             incorrect: self.incorrect(),
             lossy: self.lossy(),
+            exponent_overflow_error: self.exponent_overflow_error(),
+            underflow_error: self.underflow_error(),
+            max_exponent_digits: self.max_exponent_digits,
+            max_mantissa_digits: self.max_mantissa_digits,
+            truncate_mantissa: self.truncate_mantissa,
+            trim_whitespace: self.trim_whitespace,
+            digit_separator: self.digit_separator,
+            suffix: self.suffix,
+            empty_nan: self.empty_nan,
+            skip_prefixes: self.skip_prefixes,
+            skip_suffixes: self.skip_suffixes,
+            plus_signs: self.plus_signs,
+            minus_signs: self.minus_signs,
+            exact: self.exact,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
         }
     }
+
+    /// Create options with a different radix, or `None` if invalid.
+    ///
+    /// Shorthand for `rebuild()`, setting the radix, and `build()`.
+    /// The exponent base and exponent radix are not changed.
+    #[inline]
+    #[cfg(feature = "power_of_two")]
+    pub fn with_radix(self, radix: u8) -> Option<Self> {
+        self.rebuild().radix(radix).build()
+    }
+
+    /// Create options with a different number format, or `None` if invalid.
+    #[inline]
+    pub fn with_format(self, format: NumberFormat) -> Option<Self> {
+        self.rebuild().format(Some(format)).build()
+    }
+
+    /// Create options with a different exponent character, or `None` if invalid.
+    ///
+    /// Sets the exponent character of the format used for the radix:
+    /// the decimal exponent character for radix 10, otherwise the
+    /// backup exponent character. Letters are lowercased, as in the
+    /// `NumberFormatBuilder`.
+    #[inline]
+    pub fn with_exponent_char(self, exponent: u8) -> Option<Self> {
+        let format = to_exponent_char_format(self.format, self.radix(), exponent)?;
+        self.with_format(format)
+    }
 }
 
 impl Default for ParseFloatOptions {
@@ -848,9 +2829,13 @@ impl Default for ParseFloatOptions {
 
 /// Builder for `WriteIntegerOptions`.
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct WriteIntegerOptionsBuilder {
+    /// Radix for integer string.
     radix: u8,
+    /// Sign written before non-negative signed integers.
+    positive_sign: Option<u8>,
 }
 
 impl WriteIntegerOptionsBuilder {
@@ -858,6 +2843,7 @@ impl WriteIntegerOptionsBuilder {
     pub const fn new() -> WriteIntegerOptionsBuilder {
         WriteIntegerOptionsBuilder {
             radix: DEFAULT_RADIX,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
         }
     }
 
@@ -869,6 +2855,12 @@ impl WriteIntegerOptionsBuilder {
         self.radix
     }
 
+    /// Get the sign written before non-negative signed integers.
+    #[inline(always)]
+    pub const fn get_positive_sign(&self) -> Option<u8> {
+        self.positive_sign
+    }
+
     // SETTERS
 
     /// Set the radix for WriteIntegerOptionsBuilder.
@@ -879,6 +2871,17 @@ impl WriteIntegerOptionsBuilder {
         self
     }
 
+    /// Set the sign written before non-negative signed integers.
+    ///
+    /// A sign of `b'+'` or `b' '` matches the `+` and space flags of
+    /// C's `printf`, so columns of mixed-sign integers align. Unsigned
+    /// integers are written without a sign, like `printf`'s `%u`.
+    #[inline(always)]
+    pub const fn positive_sign(mut self, positive_sign: Option<u8>) -> Self {
+        self.positive_sign = positive_sign;
+        self
+    }
+
     // BUILDERS
 
     const_fn!(
@@ -886,8 +2889,12 @@ impl WriteIntegerOptionsBuilder {
     #[inline(always)]
     pub const fn build(self) -> Option<WriteIntegerOptions> {
         let radix = to_radix!(self.radix) as u32;
+        if !is_valid_positive_sign(self.positive_sign) {
+            return None;
+        }
         Some(WriteIntegerOptions {
             radix,
+            positive_sign: self.positive_sign,
         })
     });
 }
@@ -914,10 +2921,12 @@ impl Default for WriteIntegerOptionsBuilder {
 /// # }
 /// ```
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WriteIntegerOptions {
     /// Radix for integer string.
     radix: u32,
+    /// Sign written before non-negative signed integers.
+    positive_sign: Option<u8>,
 }
 
 impl WriteIntegerOptions {
@@ -926,17 +2935,35 @@ impl WriteIntegerOptions {
     pub const fn new() -> Self {
         Self {
             radix: DEFAULT_RADIX as u32,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+        }
+    }
+
+    /// Create default options for a radix, without validation.
+    #[inline(always)]
+    const fn for_radix(radix: u32) -> Self {
+        Self {
+            radix,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
         }
     }
 
     // PRE-DEFINED CONSTANTS
 
+    /// Default options, for const and static contexts.
+    ///
+    /// Identical to `new()` and `default()`.
+    pub const DEFAULT: Self = Self::new();
+
+    radix_constants!();
+
     /// Create new options to write the default binary format.
     #[inline(always)]
     #[cfg(feature = "power_of_two")]
     pub const fn binary() -> Self {
         Self {
             radix: 2,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
         }
     }
 
@@ -945,6 +2972,7 @@ impl WriteIntegerOptions {
     pub const fn decimal() -> Self {
         Self {
             radix: 10,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
         }
     }
 
@@ -954,9 +2982,30 @@ impl WriteIntegerOptions {
     pub const fn hexadecimal() -> Self {
         Self {
             radix: 16,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
         }
     }
 
+    /// Create new options to write JSON integers.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn json() -> Self {
+        Self::decimal()
+    }
+
+    /// Create new options to write integers like C's `printf` in the C locale.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn c_locale() -> Self {
+        Self::decimal()
+    }
+
+    /// Create new options to write integers like Rust's `ToString`.
+    #[inline(always)]
+    pub const fn rust_string() -> Self {
+        Self::decimal()
+    }
+
     // GETTERS
 
     /// Get the radix.
@@ -965,6 +3014,12 @@ impl WriteIntegerOptions {
         self.radix
     }
 
+    /// Get the sign written before non-negative signed integers.
+    #[inline(always)]
+    pub const fn positive_sign(&self) -> Option<u8> {
+        self.positive_sign
+    }
+
     // SETTERS
 
     /// Set the radix.
@@ -974,6 +3029,13 @@ impl WriteIntegerOptions {
         self.radix = radix;
     }
 
+    /// Set the sign written before non-negative signed integers.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_positive_sign(&mut self, positive_sign: Option<u8>) {
+        self.positive_sign = positive_sign;
+    }
+
     // BUILDERS
 
     /// Get WriteIntegerOptionsBuilder as a static function.
@@ -986,8 +3048,18 @@ impl WriteIntegerOptions {
     pub const fn rebuild(self) -> WriteIntegerOptionsBuilder {
         WriteIntegerOptionsBuilder {
             radix: self.radix as u8,
+            positive_sign: self.positive_sign,
         }
     }
+
+    /// Create options with a different radix, or `None` if invalid.
+    ///
+    /// Shorthand for `rebuild()`, setting the radix, and `build()`.
+    #[inline]
+    #[cfg(feature = "power_of_two")]
+    pub fn with_radix(self, radix: u8) -> Option<Self> {
+        self.rebuild().radix(radix).build()
+    }
 }
 
 impl Default for WriteIntegerOptions {
@@ -1002,18 +3074,47 @@ impl Default for WriteIntegerOptions {
 
 /// Builder for `WriteFloatOptions`.
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct WriteFloatOptionsBuilder {
     /// Radix for float string.
     radix: u8,
     /// Number format.
     format: Option<NumberFormat>,
+    /// Backup exponent character, overriding the format.
+    exponent_backup: Option<u8>,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Always write floats in scientific notation, like Rust's `{:e}`.
+    scientific: bool,
+    /// Exponents written in positional notation, like Rust's `Display`.
+    positional_exponents: Option<(i32, i32)>,
+    /// Write a `+` sign before positive exponents.
+    positive_exponent_sign: bool,
+    /// Minimum number of exponent digits, padded with leading zeros.
+    min_exponent_digits: Option<usize>,
+    /// Maximum number of bytes written for a float.
+    max_width: Option<usize>,
+    /// Sign written before non-negative floats.
+    positive_sign: Option<u8>,
+    /// Always write a decimal point in the mantissa.
+    alternate_form: Option<AlternateForm>,
+    /// Significant digits of floats written with an SI prefix.
+    si_prefix: Option<usize>,
+    /// Round exact ties between the shortest digits to even, rather than up.
+    round_ties_even: bool,
+    /// Write negative zero without a sign.
+    unsigned_zero: bool,
+    /// Rounding kind for the shortest digits.
+    rounding: RoundingKind,
+    /// Write negative values in parentheses, rather than with a sign.
+    negative_parentheses: bool,
     /// String representation of Not A Number, aka `NaN`.
-    nan_string: &'static [u8],
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes"))]
+    nan_string: StaticBytes,
     /// Short string representation of `Infinity`.
-    inf_string: &'static [u8],
+    #[cfg_attr(feature = "serde", serde(with = "super::serialize::static_bytes"))]
+    inf_string: StaticBytes,
 }
 
 impl WriteFloatOptionsBuilder {
@@ -1023,7 +3124,20 @@ impl WriteFloatOptionsBuilder {
         Self {
             radix: DEFAULT_RADIX,
             format: None,
+            exponent_backup: DEFAULT_EXPONENT_BACKUP,
             trim_floats: DEFAULT_TRIM_FLOATS,
+            scientific: DEFAULT_SCIENTIFIC,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            positive_exponent_sign: DEFAULT_POSITIVE_EXPONENT_SIGN,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            round_ties_even: DEFAULT_ROUND_TIES_EVEN,
+            unsigned_zero: DEFAULT_UNSIGNED_ZERO,
+            rounding: DEFAULT_ROUNDING,
+            negative_parentheses: DEFAULT_NEGATIVE_PARENTHESES,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
         }
@@ -1043,12 +3157,90 @@ impl WriteFloatOptionsBuilder {
         self.format
     }
 
+    /// Get the backup exponent character, overriding the format.
+    #[inline(always)]
+    pub const fn get_exponent_backup(&self) -> Option<u8> {
+        self.exponent_backup
+    }
+
     /// Get if we should trim a trailing `".0"` from floats.
     #[inline(always)]
     pub const fn get_trim_floats(&self) -> bool {
         self.trim_floats
     }
 
+    /// Get if we should always write floats in scientific notation.
+    #[inline(always)]
+    pub const fn get_scientific(&self) -> bool {
+        self.scientific
+    }
+
+    /// Get the range of exponents written in positional notation.
+    #[inline(always)]
+    pub const fn get_positional_exponents(&self) -> Option<(i32, i32)> {
+        self.positional_exponents
+    }
+
+    /// Get if we write a `+` sign before positive exponents.
+    #[inline(always)]
+    pub const fn get_positive_exponent_sign(&self) -> bool {
+        self.positive_exponent_sign
+    }
+
+    /// Get the minimum number of exponent digits.
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> Option<usize> {
+        self.min_exponent_digits
+    }
+
+    /// Get the maximum number of bytes written for a float.
+    #[inline(always)]
+    pub const fn get_max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Get the sign written before non-negative floats.
+    #[inline(always)]
+    pub const fn get_positive_sign(&self) -> Option<u8> {
+        self.positive_sign
+    }
+
+    /// Get how integral mantissas are written in the alternate form.
+    #[inline(always)]
+    pub const fn get_alternate_form(&self) -> Option<AlternateForm> {
+        self.alternate_form
+    }
+
+    /// Get the significant digits of floats written with an SI prefix.
+    #[inline(always)]
+    pub const fn get_si_prefix(&self) -> Option<usize> {
+        self.si_prefix
+    }
+
+    /// Get if we round exact ties between the shortest digits to even.
+    #[inline(always)]
+    pub const fn get_round_ties_even(&self) -> bool {
+        self.round_ties_even
+    }
+
+    /// Get if we write negative zero without a sign.
+    #[inline(always)]
+    pub const fn get_unsigned_zero(&self) -> bool {
+        self.unsigned_zero
+    }
+
+    /// Get the rounding kind for the shortest digits.
+    #[inline(always)]
+    pub const fn get_rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Get if we write negative values in parentheses.
+    #[inline(always)]
+    pub const fn get_negative_parentheses(&self) -> bool {
+        self.negative_parentheses
+    }
+
     /// Get the string representation for `NaN`.
     #[inline(always)]
     pub const fn get_nan_string(&self) -> &'static [u8] {
@@ -1072,12 +3264,34 @@ impl WriteFloatOptionsBuilder {
     }
 
     /// Set the format specifier for WriteFloatOptionsBuilder.
+    ///
+    /// Besides the punctuation, floats are written with the flags of the
+    /// format that apply to writing, so the same format parses them back:
+    /// `required_exponent_sign` writes a `+` before positive exponents,
+    /// `required_fraction_digits` writes integral mantissas as `3.0`, and
+    /// the internal digit separator flags group the integer and fraction
+    /// digits in threes from the decimal point, as `1_234.567_8`. Options
+    /// conflicting with the format, such as a `+` sign with
+    /// `no_positive_mantissa_sign`, are invalid.
     #[inline(always)]
     pub const fn format(mut self, format: Option<NumberFormat>) -> Self {
         self.format = format;
         self
     }
 
+    /// Set the backup exponent character, overriding the format.
+    ///
+    /// The backup exponent character is used for non-decimal radixes,
+    /// where `e` may be a digit, so `b'#'` writes `2^100` as
+    /// `"1.0#1100100"` in radix 2. The character must not be a letter,
+    /// digit, or sign, or conflict with the decimal point.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn exponent_backup(mut self, exponent_backup: Option<u8>) -> Self {
+        self.exponent_backup = exponent_backup;
+        self
+    }
+
     /// Set if we should trim a trailing `".0"` from floats.
     #[inline(always)]
     pub const fn trim_floats(mut self, trim_floats: bool) -> Self {
@@ -1085,6 +3299,153 @@ impl WriteFloatOptionsBuilder {
         self
     }
 
+    /// Set if we should always write floats in scientific notation.
+    ///
+    /// Floats are written with the shortest digits, a decimal point only
+    /// if there is more than one digit, and an exponent without a
+    /// positive sign, like Rust's `{:e}`. Only valid for decimal floats.
+    #[inline(always)]
+    pub const fn scientific(mut self, scientific: bool) -> Self {
+        self.scientific = scientific;
+        self
+    }
+
+    /// Set the inclusive range of exponents written in positional notation.
+    ///
+    /// Floats are written with the shortest digits, in positional notation
+    /// if the decimal exponent of the first digit is within the range, and
+    /// otherwise in scientific notation, like `scientific`. Integral floats
+    /// end with `".0"` unless `trim_floats` is set. Only valid for decimal
+    /// floats, and cannot be combined with `scientific`.
+    ///
+    /// Very large or small floats may need up to `POSITIONAL_BUFFER_SIZE`
+    /// bytes in positional notation.
+    #[inline(always)]
+    pub const fn positional_exponents(mut self, positional_exponents: Option<(i32, i32)>) -> Self {
+        self.positional_exponents = positional_exponents;
+        self
+    }
+
+    /// Set if we write a `+` sign before positive exponents, like `1e+16`.
+    #[inline(always)]
+    pub const fn positive_exponent_sign(mut self, positive_exponent_sign: bool) -> Self {
+        self.positive_exponent_sign = positive_exponent_sign;
+        self
+    }
+
+    /// Set the minimum number of exponent digits, padded with leading
+    /// zeros, like `1e-05`. Must be between 1 and 5.
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: Option<usize>) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set the maximum number of bytes written for a float, for fixed-width
+    /// displays.
+    ///
+    /// Floats are written with the other options if they fit, and
+    /// otherwise in scientific notation with the shortest digits, then
+    /// with fewer, correctly rounded digits. If even a single digit
+    /// does not fit, `try_to_lexical_with_options` fails with
+    /// `ErrorCode::ExceedsMaxWidth`, and `to_lexical_with_options`
+    /// panics. Only valid for decimal floats.
+    #[inline(always)]
+    pub const fn max_width(mut self, max_width: Option<usize>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Set the sign written before non-negative floats.
+    ///
+    /// A sign of `b'+'` or `b' '` matches the `+` and space flags of
+    /// C's `printf`, so columns of mixed-sign floats align. The sign is
+    /// also written before positive zero, infinity and `NaN`, like
+    /// `printf`, and before negative zero with `unsigned_zero`.
+    #[inline(always)]
+    pub const fn positive_sign(mut self, positive_sign: Option<u8>) -> Self {
+        self.positive_sign = positive_sign;
+        self
+    }
+
+    /// Set how integral mantissas are written in the alternate form.
+    ///
+    /// The alternate form always writes a decimal point in the mantissa,
+    /// like the `#` flag of C's `printf`, as `3.` or `3.0`. It takes
+    /// precedence over `trim_floats`, which otherwise writes `3`, and
+    /// applies to the mantissa of scientific notation, like `1.e10`.
+    /// `NaN` and infinity are written unchanged.
+    #[inline(always)]
+    pub const fn alternate_form(mut self, alternate_form: Option<AlternateForm>) -> Self {
+        self.alternate_form = alternate_form;
+        self
+    }
+
+    /// Set the significant digits of floats written with an SI prefix.
+    ///
+    /// Floats are scaled by a power of 1000 and written with the SI
+    /// prefix of the power, for human-readable metrics, like `1.5k`,
+    /// `2.3M` or `4.7µ`, where `µ` is the UTF-8 micro sign. The digits
+    /// are correctly rounded to the significant digits, keeping all
+    /// 1 to 3 integer digits, and padded with trailing zeros unless
+    /// `trim_floats` is set. Floats outside the range of the prefixes,
+    /// `q` (`1e-30`) to `Q` (`1e30`), are written in scientific notation.
+    /// Only valid for decimal floats, with 1 to 20 significant digits,
+    /// and cannot be combined with `scientific`, `positional_exponents`
+    /// or `max_width`.
+    #[inline(always)]
+    pub const fn si_prefix(mut self, si_prefix: Option<usize>) -> Self {
+        self.si_prefix = si_prefix;
+        self
+    }
+
+    /// Set if we round exact ties between the shortest digits to even.
+    ///
+    /// If a float is exactly halfway between the two closest candidates
+    /// with the fewest digits, Rust writes the larger candidate, while
+    /// Python and C write the candidate with an even last digit.
+    /// Ties are rounded up by default.
+    /// Only valid with `scientific` or `positional_exponents`.
+    #[inline(always)]
+    pub const fn round_ties_even(mut self, round_ties_even: bool) -> Self {
+        self.round_ties_even = round_ties_even;
+        self
+    }
+
+    /// Set if we write negative zero without a sign, like JavaScript's `0`.
+    ///
+    /// Only valid with `scientific` or `positional_exponents`.
+    #[inline(always)]
+    pub const fn unsigned_zero(mut self, unsigned_zero: bool) -> Self {
+        self.unsigned_zero = unsigned_zero;
+        self
+    }
+
+    /// Set the rounding kind for the shortest digits.
+    ///
+    /// With a directed rounding kind, the shortest digits are a bound
+    /// on the float, in the rounding direction, that is within one ULP
+    /// of the float, so interval arithmetic can write guaranteed lower
+    /// and upper bounds. The nearest rounding kinds write the shortest
+    /// digits that round-trip, with ties set by `round_ties_even`.
+    /// Only valid with `scientific` or `positional_exponents`.
+    #[inline(always)]
+    #[cfg(feature = "rounding")]
+    pub const fn rounding(mut self, rounding: RoundingKind) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set if we write negative values in parentheses, rather than with a sign.
+    ///
+    /// Accounting formats write negative values as `(1234.56)`, which
+    /// may be parsed with the `negative_parentheses` number format flag.
+    #[inline(always)]
+    pub const fn negative_parentheses(mut self, negative_parentheses: bool) -> Self {
+        self.negative_parentheses = negative_parentheses;
+        self
+    }
+
     /// Set the string representation for `NaN`.
     #[inline(always)]
     pub const fn nan_string(mut self, nan_string: &'static [u8]) -> Self {
@@ -1107,14 +3468,98 @@ impl WriteFloatOptionsBuilder {
     pub const fn build(self) -> Option<WriteFloatOptions> {
         let radix = to_radix!(self.radix) as u32;
         let trim_floats = (self.trim_floats as u32) << 8;
-        let compressed = radix | trim_floats;
-        let format = self.format;
+        let scientific = (self.scientific as u32) << 9;
+        let positive_exponent_sign = (self.positive_exponent_sign as u32) << 10;
+        let round_ties_even = (self.round_ties_even as u32) << 11;
+        let unsigned_zero = (self.unsigned_zero as u32) << 12;
+        let rounding = self.rounding.as_u32() << 13;
+        let negative_parentheses = (self.negative_parentheses as u32) << 17;
+        let compressed = radix
+            | trim_floats
+            | scientific
+            | positive_exponent_sign
+            | round_ties_even
+            | unsigned_zero
+            | rounding
+            | negative_parentheses;
+        let format = match self.exponent_backup {
+            Some(_) => {
+                // Const fn version of unwrap_or().
+                let format = match self.format {
+                    Some(format) => format,
+                    None => DEFAULT_FORMAT,
+                };
+                match to_exponent_backup_format(format, self.exponent_backup) {
+                    Some(format) => Some(format),
+                    None => return None,
+                }
+            },
+            None => self.format,
+        };
         let nan_string = to_nan_string!(self.nan_string);
         let inf_string = to_inf_string!(self.inf_string);
 
+        // Validate scientific and positional notation are only used for
+        // decimal floats, and are not combined.
+        let is_positional = self.positional_exponents.is_some();
+        if (self.scientific || is_positional) && radix != 10 {
+            return None;
+        } else if self.scientific && is_positional {
+            return None;
+        }
+        // Validate the shortest layout options are only used with scientific
+        // or positional notation, and the minimum exponent digits are in range.
+        let is_shortest = self.scientific || is_positional;
+        let is_toward = self.rounding.is_toward();
+        let is_layout = self.round_ties_even || self.unsigned_zero || is_toward;
+        if !is_shortest && is_layout {
+            return None;
+        }
+        if let Some(min_exponent_digits) = self.min_exponent_digits {
+            if min_exponent_digits == 0 || min_exponent_digits > MAX_MIN_EXPONENT_DIGITS {
+                return None;
+            }
+        }
+        // Validate the maximum width is only used for decimal floats.
+        if self.max_width.is_some() && radix != 10 {
+            return None;
+        }
+        if !is_valid_positive_sign(self.positive_sign) {
+            return None;
+        }
+        // Validate SI prefixes are only used for decimal floats, with
+        // 1 to 20 significant digits, and not with another notation or width.
+        if let Some(si_prefix) = self.si_prefix {
+            if si_prefix == 0 || si_prefix > MAX_SI_PREFIX_DIGITS || radix != 10 {
+                return None;
+            } else if is_shortest || self.max_width.is_some() {
+                return None;
+            }
+        }
+        // Validate the sign and the alternate form are permitted by the
+        // number format, which may forbid `+` signs or trailing points.
+        if let Some(format) = format {
+            if format.no_positive_mantissa_sign() {
+                if let Some(b'+') = self.positive_sign {
+                    return None;
+                }
+            }
+            if format.required_fraction_digits() {
+                if let Some(AlternateForm::Point) = self.alternate_form {
+                    return None;
+                }
+            }
+        }
+
         Some(WriteFloatOptions {
             compressed,
             format,
+            positional_exponents: self.positional_exponents,
+            min_exponent_digits: self.min_exponent_digits,
+            max_width: self.max_width,
+            positive_sign: self.positive_sign,
+            alternate_form: self.alternate_form,
+            si_prefix: self.si_prefix,
             nan_string,
             inf_string,
         })
@@ -1146,68 +3591,410 @@ impl Default for WriteFloatOptionsBuilder {
 /// # }
 /// ```
 #[repr(C)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WriteFloatOptions {
-    /// Compressed storage of radix and trim floats.
-    /// Radix is the lower 8 bits, trim_floats is bit 9.
+    /// Compressed storage of radix, trim floats, scientific, positive
+    /// exponent sign, round ties to even, unsigned zero, rounding kind,
+    /// and negative parentheses. Radix is the lower 8 bits, trim_floats
+    /// is bit 8, scientific is bit 9, positive_exponent_sign is bit 10,
+    /// round_ties_even is bit 11, unsigned_zero is bit 12, bits 13-16
+    /// are the rounding kind, and negative_parentheses is bit 17.
     compressed: u32,
     /// Number format.
     format: Option<NumberFormat>,
+    /// Exponents written in positional notation.
+    positional_exponents: Option<(i32, i32)>,
+    /// Minimum number of exponent digits.
+    min_exponent_digits: Option<usize>,
+    /// Maximum number of bytes written for a float.
+    max_width: Option<usize>,
+    /// Sign written before non-negative floats.
+    positive_sign: Option<u8>,
+    /// Always write a decimal point in the mantissa.
+    alternate_form: Option<AlternateForm>,
+    /// Significant digits of floats written with an SI prefix.
+    si_prefix: Option<usize>,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: &'static [u8],
     /// Short string representation of `Infinity`.
     inf_string: &'static [u8],
 }
 
-impl WriteFloatOptions {
-    /// Create options with default values.
+impl WriteFloatOptions {
+    /// Create options with default values.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            compressed: DEFAULT_RADIX as u32,
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create default options for a radix, without validation.
+    #[inline(always)]
+    const fn for_radix(radix: u32) -> Self {
+        Self {
+            compressed: radix,
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    // PRE-DEFINED CONSTANTS
+
+    /// Default options, for const and static contexts.
+    ///
+    /// Identical to `new()` and `default()`.
+    pub const DEFAULT: Self = Self::new();
+
+    radix_constants!();
+
+    /// Create new options to write the default binary format.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn binary() -> Self {
+        Self {
+            compressed: 2,
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write the default decimal format.
+    #[inline(always)]
+    pub const fn decimal() -> Self {
+        Self {
+            compressed: 10,
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write the default hexadecimal format.
+    #[inline(always)]
+    #[cfg(feature = "power_of_two")]
+    pub const fn hexadecimal() -> Self {
+        Self {
+            compressed: 16,
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write JSON numbers.
+    ///
+    /// JSON cannot represent special values, so these use the defaults.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn json() -> Self {
+        Self {
+            compressed: 10,
+            format: Some(NumberFormat::JSON),
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write floats in the C locale.
+    ///
+    /// This uses C's number syntax and special strings, but writes the
+    /// shortest digits that round-trip, not `printf("%g")`'s 6 significant
+    /// digits.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn c_locale() -> Self {
+        Self {
+            compressed: 10 | (1 << 8),
+            format: Some(NumberFormat::C_STRING),
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: C_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write XML Schema `xs:double` and `xs:float`
+    /// values.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn xml_schema_double() -> Self {
+        Self {
+            compressed: 10,
+            format: Some(NumberFormat::XML_SCHEMA_DOUBLE),
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: XSD_INF_STRING,
+        }
+    }
+
+    /// Create new options to write XML Schema `xs:decimal` values.
+    ///
+    /// Floats are always written in positional notation, which requires
+    /// up to `POSITIONAL_BUFFER_SIZE` bytes. `xs:decimal` cannot represent
+    /// special values, so these use the defaults.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn xml_schema_decimal() -> Self {
+        Self {
+            compressed: 10,
+            format: Some(NumberFormat::XML_SCHEMA_DECIMAL),
+            positional_exponents: Some((i32::min_value(), i32::max_value())),
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write ANSI SQL numeric literals.
+    ///
+    /// SQL cannot represent special values, so these use the defaults.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn sql() -> Self {
+        Self {
+            compressed: 10,
+            format: Some(NumberFormat::SQL),
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write floats identically to PostgreSQL's
+    /// `float8` output.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn postgresql() -> Self {
+        Self {
+            compressed: 10 | (1 << 8) | (1 << 10),
+            format: Some(NumberFormat::POSTGRESQL),
+            positional_exponents: Some((-4, 14)),
+            min_exponent_digits: Some(2),
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: PG_INFINITY_STRING,
+        }
+    }
+
+    /// Create new options to write protobuf text format floats.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn protobuf_text() -> Self {
+        Self {
+            compressed: 10,
+            format: Some(NumberFormat::PROTOBUF_TEXT),
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: C_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write floats like Rust's `ToString`.
+    ///
+    /// Large and small floats are written in scientific notation: use
+    /// `rust_display` for output identical to `ToString`.
     #[inline(always)]
-    pub const fn new() -> Self {
+    pub const fn rust_string() -> Self {
         Self {
-            compressed: DEFAULT_RADIX as u32,
+            compressed: 10 | (1 << 8),
             format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
         }
     }
 
-    // PRE-DEFINED CONSTANTS
-
-    /// Create new options to write the default binary format.
+    /// Create new options to write floats identically to Rust's `Display`
+    /// and `ToString`.
+    ///
+    /// Floats are always written in positional notation, which requires
+    /// up to `POSITIONAL_BUFFER_SIZE` bytes.
     #[inline(always)]
-    #[cfg(feature = "power_of_two")]
-    pub const fn binary() -> Self {
+    pub const fn rust_display() -> Self {
         Self {
-            compressed: 2,
+            compressed: 10 | (1 << 8),
             format: None,
+            positional_exponents: Some((i32::min_value(), i32::max_value())),
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
         }
     }
 
-    /// Create new options to write the default decimal format.
+    /// Create new options to write floats identically to Rust's `Debug`.
     #[inline(always)]
-    pub const fn decimal() -> Self {
+    pub const fn rust_debug() -> Self {
         Self {
             compressed: 10,
             format: None,
+            positional_exponents: Some((-4, 15)),
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
         }
     }
 
-    /// Create new options to write the default hexadecimal format.
+    /// Create new options to write floats identically to Python's `repr`.
     #[inline(always)]
-    #[cfg(feature = "power_of_two")]
-    pub const fn hexadecimal() -> Self {
+    pub const fn python_repr() -> Self {
         Self {
-            compressed: 16,
+            compressed: 10 | (1 << 10) | (1 << 11),
             format: None,
+            positional_exponents: Some((-4, 15)),
+            min_exponent_digits: Some(2),
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: C_NAN_STRING,
+            inf_string: DEFAULT_INF_STRING,
+        }
+    }
+
+    /// Create new options to write floats identically to JavaScript's
+    /// `Number.prototype.toString`.
+    #[inline(always)]
+    pub const fn javascript_string() -> Self {
+        Self {
+            compressed: 10 | (1 << 8) | (1 << 10) | (1 << 11) | (1 << 12),
+            format: None,
+            positional_exponents: Some((-6, 20)),
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
+            nan_string: DEFAULT_NAN_STRING,
+            inf_string: JS_INF_STRING,
+        }
+    }
+
+    /// Create new options to write floats like Rust's `format!("{:e}")`.
+    #[inline(always)]
+    pub const fn rust_scientific() -> Self {
+        Self {
+            compressed: 10 | (1 << 9),
+            format: None,
+            positional_exponents: DEFAULT_POSITIONAL_EXPONENTS,
+            min_exponent_digits: DEFAULT_MIN_EXPONENT_DIGITS,
+            max_width: DEFAULT_MAX_WIDTH,
+            positive_sign: DEFAULT_POSITIVE_SIGN,
+            alternate_form: DEFAULT_ALTERNATE_FORM,
+            si_prefix: DEFAULT_SI_PREFIX,
             nan_string: DEFAULT_NAN_STRING,
             inf_string: DEFAULT_INF_STRING,
         }
     }
 
+    /// Create new options to write floats in a locale, such as `de_DE`.
+    ///
+    /// Floats are written with the decimal point of the locale. Digits
+    /// are not grouped: use the group separator and grouping of the
+    /// `Locale` to group them. Returns `None` for unknown locales.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical_core;
+    /// use lexical_core::WriteFloatOptions;
+    ///
+    /// # pub fn main() {
+    /// let options = WriteFloatOptions::for_locale("de_DE").unwrap();
+    /// let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    /// assert_eq!(lexical_core::write_with_options(1234.5f64, &mut buffer, &options), b"1234,5");
+    /// # }
+    /// ```
+    #[inline]
+    #[cfg(feature = "locale")]
+    pub fn for_locale(name: &str) -> Option<Self> {
+        Locale::find(name).map(|locale| {
+            let mut options = Self::decimal();
+            options.format = Some(DEFAULT_FORMAT.with_decimal_point(locale.decimal_point()));
+            options
+        })
+    }
+
     // GETTERS
 
     /// Get the radix.
@@ -1222,6 +4009,82 @@ impl WriteFloatOptions {
         self.compressed & 0x100 != 0
     }
 
+    /// Get if we should always write floats in scientific notation.
+    #[inline(always)]
+    pub const fn scientific(&self) -> bool {
+        self.compressed & 0x200 != 0
+    }
+
+    /// Get the range of exponents written in positional notation.
+    #[inline(always)]
+    pub const fn positional_exponents(&self) -> Option<(i32, i32)> {
+        self.positional_exponents
+    }
+
+    /// Get if we write a `+` sign before positive exponents.
+    #[inline(always)]
+    pub const fn positive_exponent_sign(&self) -> bool {
+        self.compressed & 0x400 != 0
+    }
+
+    /// Get the minimum number of exponent digits.
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> Option<usize> {
+        self.min_exponent_digits
+    }
+
+    /// Get the maximum number of bytes written for a float.
+    #[inline(always)]
+    pub const fn max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Get the sign written before non-negative floats.
+    #[inline(always)]
+    pub const fn positive_sign(&self) -> Option<u8> {
+        self.positive_sign
+    }
+
+    /// Get how integral mantissas are written in the alternate form.
+    #[inline(always)]
+    pub const fn alternate_form(&self) -> Option<AlternateForm> {
+        self.alternate_form
+    }
+
+    /// Get the significant digits of floats written with an SI prefix.
+    #[inline(always)]
+    pub const fn si_prefix(&self) -> Option<usize> {
+        self.si_prefix
+    }
+
+    /// Get if we round exact ties between the shortest digits to even.
+    #[inline(always)]
+    pub const fn round_ties_even(&self) -> bool {
+        self.compressed & 0x800 != 0
+    }
+
+    /// Get if we write negative zero without a sign.
+    #[inline(always)]
+    pub const fn unsigned_zero(&self) -> bool {
+        self.compressed & 0x1000 != 0
+    }
+
+    /// Get the rounding kind for the shortest digits.
+    #[inline(always)]
+    pub const fn rounding(&self) -> RoundingKind {
+        let bits = (self.compressed & 0x1E000) >> 13;
+        #[cfg(not(feature = "safe"))]
+        return unsafe { RoundingKind::from_u32(bits) };
+        #[cfg(feature = "safe")]
+        return RoundingKind::from_bits_truncate(bits);
+    }
+
+    /// Get if we write negative values in parentheses.
+    #[inline(always)]
+    pub const fn negative_parentheses(&self) -> bool {
+        self.compressed & 0x20000 != 0
+    }
+
     /// Get the number format.
     #[inline(always)]
     pub const fn format(&self) -> Option<NumberFormat> {
@@ -1260,6 +4123,16 @@ impl WriteFloatOptions {
         }
     });
 
+    const_fn!(
+    /// Get the backup exponent character, for non-decimal radixes.
+    #[inline(always)]
+    pub const fn exponent_backup(&self) -> u8 {
+        match self.format {
+            Some(format) => format.exponent_backup(),
+            None => DEFAULT_FORMAT.exponent_backup(),
+        }
+    });
+
     const_fn!(
     /// Get the exponent character.
     #[inline(always)]
@@ -1293,6 +4166,102 @@ impl WriteFloatOptions {
         self.compressed |= (trim_floats as u32) << 8;
     }
 
+    /// Set if we should always write floats in scientific notation.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_scientific(&mut self, scientific: bool) {
+        // Unset the 9th bit, then set it based on the scientific value.
+        self.compressed &= !0x200;
+        self.compressed |= (scientific as u32) << 9;
+    }
+
+    /// Set the range of exponents written in positional notation.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_positional_exponents(&mut self, positional_exponents: Option<(i32, i32)>) {
+        self.positional_exponents = positional_exponents
+    }
+
+    /// Set if we write a `+` sign before positive exponents.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_positive_exponent_sign(&mut self, positive_exponent_sign: bool) {
+        // Unset the 10th bit, then set it based on the positive exponent sign value.
+        self.compressed &= !0x400;
+        self.compressed |= (positive_exponent_sign as u32) << 10;
+    }
+
+    /// Set the minimum number of exponent digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_min_exponent_digits(&mut self, min_exponent_digits: Option<usize>) {
+        self.min_exponent_digits = min_exponent_digits
+    }
+
+    /// Set the maximum number of bytes written for a float.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_max_width(&mut self, max_width: Option<usize>) {
+        self.max_width = max_width
+    }
+
+    /// Set the sign written before non-negative floats.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_positive_sign(&mut self, positive_sign: Option<u8>) {
+        self.positive_sign = positive_sign
+    }
+
+    /// Set how integral mantissas are written in the alternate form.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_alternate_form(&mut self, alternate_form: Option<AlternateForm>) {
+        self.alternate_form = alternate_form
+    }
+
+    /// Set the significant digits of floats written with an SI prefix.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_si_prefix(&mut self, si_prefix: Option<usize>) {
+        self.si_prefix = si_prefix
+    }
+
+    /// Set if we round exact ties between the shortest digits to even.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_round_ties_even(&mut self, round_ties_even: bool) {
+        // Unset the 11th bit, then set it based on the round ties even value.
+        self.compressed &= !0x800;
+        self.compressed |= (round_ties_even as u32) << 11;
+    }
+
+    /// Set if we write negative zero without a sign.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_unsigned_zero(&mut self, unsigned_zero: bool) {
+        // Unset the 12th bit, then set it based on the unsigned zero value.
+        self.compressed &= !0x1000;
+        self.compressed |= (unsigned_zero as u32) << 12;
+    }
+
+    /// Set the rounding kind for the shortest digits.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_rounding(&mut self, rounding: RoundingKind) {
+        // Unset bits 13-17, then set the rounding kind.
+        self.compressed &= !0x1E000;
+        self.compressed |= (rounding.as_u32() & 0xF) << 13;
+    }
+
+    /// Set if we write negative values in parentheses.
+    /// Unsafe, use the builder API for option validation.
+    #[inline(always)]
+    pub unsafe fn set_negative_parentheses(&mut self, negative_parentheses: bool) {
+        // Unset the 17th bit, then set it based on the negative parentheses value.
+        self.compressed &= !0x20000;
+        self.compressed |= (negative_parentheses as u32) << 17;
+    }
+
     /// Set the number format.
     /// Unsafe, use the builder API for option validation.
     #[inline(always)]
@@ -1327,11 +4296,52 @@ impl WriteFloatOptions {
         WriteFloatOptionsBuilder {
             radix: self.radix() as u8,
             trim_floats: self.trim_floats(),
+            scientific: self.scientific(),
+            positional_exponents: self.positional_exponents,
+            positive_exponent_sign: self.positive_exponent_sign(),
+            min_exponent_digits: self.min_exponent_digits,
+            max_width: self.max_width,
+            positive_sign: self.positive_sign,
+            alternate_form: self.alternate_form,
+            si_prefix: self.si_prefix,
+            round_ties_even: self.round_ties_even(),
+            unsigned_zero: self.unsigned_zero(),
+            rounding: self.rounding(),
+            negative_parentheses: self.negative_parentheses(),
             format: self.format,
+            exponent_backup: DEFAULT_EXPONENT_BACKUP,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
         }
     }
+
+    /// Create options with a different radix, or `None` if invalid.
+    ///
+    /// Shorthand for `rebuild()`, setting the radix, and `build()`.
+    #[inline]
+    #[cfg(feature = "power_of_two")]
+    pub fn with_radix(self, radix: u8) -> Option<Self> {
+        self.rebuild().radix(radix).build()
+    }
+
+    /// Create options with a different number format, or `None` if invalid.
+    #[inline]
+    pub fn with_format(self, format: NumberFormat) -> Option<Self> {
+        self.rebuild().format(Some(format)).build()
+    }
+
+    /// Create options with a different exponent character, or `None` if invalid.
+    ///
+    /// Sets the exponent character of the format used for the radix:
+    /// the decimal exponent character for radix 10, otherwise the
+    /// backup exponent character. Letters are lowercased, as in the
+    /// `NumberFormatBuilder`.
+    #[inline]
+    pub fn with_exponent_char(self, exponent: u8) -> Option<Self> {
+        let format = self.format.unwrap_or(DEFAULT_FORMAT);
+        let format = to_exponent_char_format(format, self.radix(), exponent)?;
+        self.with_format(format)
+    }
 }
 
 impl Default for WriteFloatOptions {
@@ -1341,6 +4351,164 @@ impl Default for WriteFloatOptions {
     }
 }
 
+// FROM RADIX
+// ----------
+
+/// Create default options from a radix.
+///
+/// The options are identical to the `RADIX_*` constants, and allow
+/// generic code to select options from a radix known only at runtime.
+#[cfg(feature = "power_of_two")]
+pub trait FromRadix: Sized {
+    /// Get the default options for the radix, or `None` if the radix is invalid.
+    fn from_radix(radix: u32) -> Option<Self>;
+}
+
+/// Implement `FromRadix` for an options type.
+#[cfg(feature = "power_of_two")]
+macro_rules! from_radix_impl {
+    ($($t:ident)*) => ($(
+        impl FromRadix for $t {
+            #[inline]
+            fn from_radix(radix: u32) -> Option<Self> {
+                let radix = to_radix!(radix);
+                Some(Self::for_radix(radix))
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "power_of_two")]
+from_radix_impl! { ParseIntegerOptions ParseFloatOptions WriteIntegerOptions WriteFloatOptions }
+
+// FROM FORMAT
+// -----------
+
+/// Create default options from a number format.
+///
+/// Allows generic code, such as the `FormatPolicy` parsers, to
+/// select parse options from a number format.
+#[cfg(feature = "format")]
+pub trait FromFormat: Sized {
+    /// Get the default options for the format, or `None` if the format is invalid.
+    fn from_format(format: NumberFormat) -> Option<Self>;
+}
+
+/// Implement `FromFormat` for an options type.
+#[cfg(feature = "format")]
+macro_rules! from_format_impl {
+    ($($t:ident)*) => ($(
+        impl FromFormat for $t {
+            #[inline]
+            fn from_format(format: NumberFormat) -> Option<Self> {
+                Self::builder().format(Some(format)).build()
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "format")]
+from_format_impl! { ParseIntegerOptions ParseFloatOptions }
+
+// FROM RUST LITERAL
+// -----------------
+
+/// Create options to parse the digits of Rust numeric literals.
+///
+/// Allows `parse_rust_literal` to select parse options from the radix
+/// of the literal prefix.
+#[cfg(feature = "format")]
+pub trait FromRustLiteral: Sized {
+    /// If the literals are floats, with the `f32` or `f64` suffixes.
+    const IS_FLOAT: bool;
+
+    /// Get the options for the radix, or `None` if literals may not have the radix.
+    fn from_rust_literal(radix: u32) -> Option<Self>;
+}
+
+#[cfg(feature = "format")]
+impl FromRustLiteral for ParseIntegerOptions {
+    const IS_FLOAT: bool = false;
+
+    #[inline]
+    fn from_rust_literal(radix: u32) -> Option<Self> {
+        match radix {
+            10 => Some(Self::rust_literal()),
+            #[cfg(feature = "power_of_two")]
+            2 | 8 | 16 => Some(Self {
+                radix,
+                ..Self::rust_literal()
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "format")]
+impl FromRustLiteral for ParseFloatOptions {
+    const IS_FLOAT: bool = true;
+
+    #[inline]
+    fn from_rust_literal(radix: u32) -> Option<Self> {
+        match radix {
+            10 => Some(Self::rust_literal()),
+            _ => None,
+        }
+    }
+}
+
+// FROM CXX LITERAL
+// ----------------
+
+/// Create options to parse the digits of C++ numeric literals.
+///
+/// Allows `parse_cxx_literal` to select parse options from the radix
+/// of the literal prefix.
+#[cfg(feature = "format")]
+pub trait FromCxxLiteral: Sized {
+    /// If the literals are floats, with the `f` or `l` suffixes.
+    const IS_FLOAT: bool;
+
+    /// Get the options for the radix, or `None` if literals may not have the radix.
+    fn from_cxx_literal(radix: u32) -> Option<Self>;
+}
+
+#[cfg(feature = "format")]
+impl FromCxxLiteral for ParseIntegerOptions {
+    const IS_FLOAT: bool = false;
+
+    #[inline]
+    fn from_cxx_literal(radix: u32) -> Option<Self> {
+        match radix {
+            10 => Some(Self::cxx_literal()),
+            #[cfg(feature = "power_of_two")]
+            2 | 8 | 16 => Some(Self {
+                radix,
+                ..Self::cxx_literal()
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "format")]
+impl FromCxxLiteral for ParseFloatOptions {
+    const IS_FLOAT: bool = true;
+
+    #[inline]
+    fn from_cxx_literal(radix: u32) -> Option<Self> {
+        // Float literals with leading zeros are decimal, like `01.5`,
+        // and hexadecimal floats are rewritten as binary floats, since
+        // the exponent is binary.
+        match radix {
+            8 | 10 => Some(Self::cxx_literal()),
+            #[cfg(feature = "power_of_two")]
+            16 => Some(Self::binary()),
+            _ => None,
+        }
+    }
+}
+
 // TESTS
 // -----
 
@@ -1459,6 +4627,11 @@ mod tests {
         let options = options.rebuild().radix(10).build().unwrap();
         assert_eq!(options.radix(), 10);
         assert_eq!(options.format(), None);
+        assert_eq!(options.lenient(), false);
+
+        let options = options.rebuild().lenient(true).build().unwrap();
+        assert_eq!(options.radix(), 10);
+        assert_eq!(options.lenient(), true);
     }
 
     #[test]
@@ -1528,6 +4701,36 @@ mod tests {
         assert_eq!(options.radix(), 16);
         assert_eq!(options.exponent_base(), 2);
         assert_eq!(options.exponent_radix(), 10);
+        assert_eq!(options.exponent_overflow_error(), false);
+
+        let options = options.rebuild().exponent_overflow_error(true).build().unwrap();
+        assert_eq!(options.radix(), 16);
+        assert_eq!(options.lossy(), false);
+        assert_eq!(options.exponent_overflow_error(), true);
+        assert_eq!(options.underflow_error(), false);
+
+        let options = options.rebuild().underflow_error(true).build().unwrap();
+        assert_eq!(options.exponent_overflow_error(), true);
+        assert_eq!(options.underflow_error(), true);
+        assert_eq!(options.max_exponent_digits(), None);
+
+        let options = options.rebuild().max_exponent_digits(Some(4)).build().unwrap();
+        assert_eq!(options.underflow_error(), true);
+        assert_eq!(options.max_exponent_digits(), Some(4));
+        assert!(options.rebuild().max_exponent_digits(Some(0)).build().is_none());
+
+        let builder = options.rebuild().max_mantissa_digits(Some(20)).truncate_mantissa(true);
+        let options = builder.build().unwrap();
+        assert_eq!(options.max_exponent_digits(), Some(4));
+        assert_eq!(options.max_mantissa_digits(), Some(20));
+        assert_eq!(options.truncate_mantissa(), true);
+        assert!(options.rebuild().max_mantissa_digits(Some(0)).build().is_none());
+
+        let options = options.rebuild().exact(true).build().unwrap();
+        assert_eq!(options.truncate_mantissa(), true);
+        assert_eq!(options.exact(), true);
+        assert!(options.rebuild().lossy(true).build().is_none());
+        assert!(options.rebuild().incorrect(true).build().is_none());
     }
 
     #[test]
@@ -1552,5 +4755,302 @@ mod tests {
         assert_eq!(options.format(), None);
         assert_eq!(options.nan_string(), b"NaN");
         assert_eq!(options.inf_string(), b"infinity");
+
+        let options = options.rebuild().scientific(true).build().unwrap();
+        assert_eq!(options.scientific(), true);
+        assert_eq!(options.trim_floats(), false);
+        assert_eq!(options.rebuild().radix(32).build(), None);
+        assert_eq!(WriteFloatOptions::rust_scientific().rebuild().build().unwrap().scientific(), true);
+
+        let options = options.rebuild().scientific(false).inf_string(b"inf");
+        let options = options.positional_exponents(Some((-4, 15)));
+        let options = options.build().unwrap();
+        assert_eq!(options.positional_exponents(), Some((-4, 15)));
+        assert_eq!(options, WriteFloatOptions::rust_debug());
+        let options = WriteFloatOptions::rust_display();
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let options = options.rebuild().max_width(Some(8)).build().unwrap();
+        assert_eq!(options.max_width(), Some(8));
+        assert_eq!(options.rebuild().build(), Some(options));
+        assert_eq!(WriteFloatOptions::builder().radix(2).max_width(Some(8)).build(), None);
+
+        let options = options.rebuild().positive_sign(Some(b' ')).build().unwrap();
+        assert_eq!(options.positive_sign(), Some(b' '));
+        assert_eq!(options.rebuild().build(), Some(options));
+        assert_eq!(WriteFloatOptions::builder().positive_sign(Some(b'-')).build(), None);
+
+        let options = options.rebuild().alternate_form(Some(AlternateForm::Point)).build().unwrap();
+        assert_eq!(options.alternate_form(), Some(AlternateForm::Point));
+        assert_eq!(options.rebuild().build(), Some(options));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(ParseFloatOptions::new());
+        set.insert(ParseFloatOptions::builder().lossy(true).build().unwrap());
+        set.insert(ParseFloatOptions::new());
+        assert_eq!(set.len(), 2);
+
+        let mut set = HashSet::new();
+        set.insert(NumberFormat::STANDARD);
+        set.insert(NumberFormat::STANDARD);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_rust_string_presets() {
+        let options = ParseIntegerOptions::rust_string();
+        assert_eq!(options, ParseIntegerOptions::decimal());
+
+        let options = ParseFloatOptions::rust_string();
+        assert_eq!(options, ParseFloatOptions::decimal());
+
+        let options = WriteIntegerOptions::rust_string();
+        assert_eq!(options, WriteIntegerOptions::decimal());
+
+        let options = WriteFloatOptions::rust_string();
+        assert_eq!(options.radix(), 10);
+        assert_eq!(options.trim_floats(), true);
+        assert_eq!(options.nan_string(), b"NaN");
+        assert_eq!(options.inf_string(), b"inf");
+    }
+
+    #[test]
+    fn test_csv_presets() {
+        let options = ParseFloatOptions::csv();
+        assert_eq!(options.decimal_point(), b'.');
+        assert_eq!(options.digit_separator(), Some(b','));
+        assert_eq!(options.trim_whitespace(), true);
+        assert_eq!(options.empty_nan(), true);
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let options = ParseFloatOptions::csv_european();
+        assert_eq!(options.decimal_point(), b',');
+        assert_eq!(options.digit_separator(), Some(b'.'));
+        assert_eq!(options.rebuild().build(), Some(options));
+    }
+
+    #[test]
+    #[cfg(feature = "locale")]
+    fn test_locale_presets() {
+        let options = ParseFloatOptions::for_locale("de_DE").unwrap();
+        assert_eq!(options.decimal_point(), b',');
+        assert_eq!(options.digit_separator(), Some(b'.'));
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let options = ParseFloatOptions::for_locale("fr_FR").unwrap();
+        assert_eq!(options.decimal_point(), b',');
+        assert_eq!(options.digit_separator(), None);
+
+        let options = WriteFloatOptions::for_locale("de_DE").unwrap();
+        assert_eq!(options.decimal_point(), b',');
+        assert_eq!(options.rebuild().build(), Some(options));
+
+        let options = WriteFloatOptions::for_locale("en_US").unwrap();
+        assert_eq!(options.decimal_point(), b'.');
+
+        assert_eq!(ParseFloatOptions::for_locale("xx_XX"), None);
+        assert_eq!(WriteFloatOptions::for_locale("xx_XX"), None);
+        for locale in Locale::all().iter() {
+            assert!(ParseFloatOptions::for_locale(locale.name()).is_some());
+            assert!(WriteFloatOptions::for_locale(locale.name()).is_some());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn test_format_presets() {
+        let options = ParseIntegerOptions::json();
+        assert_eq!(options.format(), Some(NumberFormat::JSON));
+        let options = ParseIntegerOptions::c_locale();
+        assert_eq!(options.format(), Some(NumberFormat::C_STRING));
+        let options = ParseIntegerOptions::rust_literal();
+        assert_eq!(options.format(), Some(NumberFormat::RUST_LITERAL));
+        assert_eq!(ParseIntegerOptions::from_rust_literal(10), Some(options));
+        assert_eq!(ParseFloatOptions::from_rust_literal(16), None);
+        let options = ParseIntegerOptions::cxx_literal();
+        assert_eq!(options.format(), Some(NumberFormat::CXX_LITERAL));
+        assert_eq!(ParseIntegerOptions::from_cxx_literal(10), Some(options));
+        let options = ParseFloatOptions::cxx_literal();
+        assert_eq!(options.format(), NumberFormat::CXX_LITERAL);
+        assert_eq!(ParseFloatOptions::from_cxx_literal(8), Some(options));
+        assert_eq!(ParseFloatOptions::from_cxx_literal(2), None);
+
+        let options = ParseFloatOptions::json();
+        assert_eq!(options.radix(), 10);
+        assert_eq!(options.format(), NumberFormat::JSON);
+        let options = ParseFloatOptions::c_locale();
+        assert_eq!(options.format(), NumberFormat::C_STRING);
+        assert_eq!(options.nan_string(), b"nan");
+        assert_eq!(options.inf_string(), b"inf");
+        assert_eq!(options.infinity_string(), b"infinity");
+
+        let options = WriteFloatOptions::json();
+        assert_eq!(options.trim_floats(), false);
+        assert_eq!(options.format(), Some(NumberFormat::JSON));
+        let options = WriteFloatOptions::c_locale();
+        assert_eq!(options.trim_floats(), true);
+        assert_eq!(options.format(), Some(NumberFormat::C_STRING));
+        assert_eq!(options.nan_string(), b"nan");
+
+        let options = ParseFloatOptions::xml_schema_double();
+        assert_eq!(options.format(), NumberFormat::XML_SCHEMA_DOUBLE);
+        assert_eq!(options.nan_string(), b"NaN");
+        assert_eq!(options.inf_string(), b"INF");
+        assert_eq!(options.infinity_string(), b"INF");
+        let options = ParseFloatOptions::xml_schema_decimal();
+        assert_eq!(options.format(), NumberFormat::XML_SCHEMA_DECIMAL);
+
+        let options = WriteFloatOptions::xml_schema_double();
+        assert_eq!(options.format(), Some(NumberFormat::XML_SCHEMA_DOUBLE));
+        assert_eq!(options.inf_string(), b"INF");
+        let options = WriteFloatOptions::xml_schema_decimal();
+        assert_eq!(options.format(), Some(NumberFormat::XML_SCHEMA_DECIMAL));
+
+        let options = ParseFloatOptions::protobuf_text();
+        assert_eq!(options.format(), NumberFormat::PROTOBUF_TEXT);
+        assert_eq!(options.suffix(), Some(b'f'));
+        assert_eq!(options.nan_string(), b"nan");
+        let options = WriteFloatOptions::protobuf_text();
+        assert_eq!(options.format(), Some(NumberFormat::PROTOBUF_TEXT));
+        assert_eq!(options.nan_string(), b"nan");
+
+        let options = ParseIntegerOptions::sql();
+        assert_eq!(options.format(), Some(NumberFormat::SQL));
+        let options = ParseFloatOptions::sql();
+        assert_eq!(options.format(), NumberFormat::SQL);
+        let options = ParseFloatOptions::postgresql();
+        assert_eq!(options.format().no_special(), false);
+        assert_eq!(options.trim_whitespace(), true);
+        assert_eq!(options.infinity_string(), b"Infinity");
+        let options = ParseFloatOptions::mysql();
+        assert_eq!(options.format(), NumberFormat::MYSQL);
+        let options = WriteFloatOptions::postgresql();
+        assert_eq!(options.inf_string(), b"Infinity");
+
+        // Presets must be valid builder outputs.
+        let options = ParseFloatOptions::c_locale();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = WriteFloatOptions::c_locale();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = ParseFloatOptions::xml_schema_double();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = WriteFloatOptions::xml_schema_decimal();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = ParseFloatOptions::protobuf_text();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = ParseFloatOptions::postgresql();
+        assert_eq!(options.rebuild().build(), Some(options));
+        let options = WriteFloatOptions::postgresql();
+        assert_eq!(options.rebuild().build(), Some(options));
+        #[cfg(feature = "power_of_two")]
+        {
+            let options = ParseIntegerOptions::protobuf_text();
+            assert_eq!(options.radix_prefix(), true);
+            assert_eq!(options.rebuild().build(), Some(options));
+        }
+    }
+
+    #[test]
+    fn test_radix_constants() {
+        assert_eq!(ParseIntegerOptions::RADIX_10, ParseIntegerOptions::decimal());
+        assert_eq!(ParseFloatOptions::RADIX_10, ParseFloatOptions::decimal());
+        assert_eq!(WriteIntegerOptions::RADIX_10, WriteIntegerOptions::decimal());
+        assert_eq!(WriteFloatOptions::RADIX_10, WriteFloatOptions::decimal());
+
+        let options = ParseFloatOptions::LOSSY;
+        assert_eq!(options.radix(), 10);
+        assert_eq!(options.lossy(), true);
+        assert_eq!(Some(options), ParseFloatOptions::builder().lossy(true).build());
+    }
+
+    #[test]
+    fn test_default_constants() {
+        static PARSE_FLOAT: ParseFloatOptions = ParseFloatOptions::DEFAULT;
+        static WRITE_FLOAT: WriteFloatOptions = WriteFloatOptions::DEFAULT;
+        assert_eq!(ParseIntegerOptions::DEFAULT, ParseIntegerOptions::default());
+        assert_eq!(PARSE_FLOAT, ParseFloatOptions::default());
+        assert_eq!(WriteIntegerOptions::DEFAULT, WriteIntegerOptions::default());
+        assert_eq!(WRITE_FLOAT, WriteFloatOptions::default());
+    }
+
+    #[test]
+    fn test_with() {
+        let format = NumberFormat::STANDARD;
+        let options = ParseIntegerOptions::new().with_format(format);
+        assert_eq!(options, ParseIntegerOptions::builder().format(Some(format)).build());
+        let options = WriteFloatOptions::new().with_format(format);
+        assert_eq!(options, WriteFloatOptions::builder().format(Some(format)).build());
+
+        // Other fields of the preset are kept.
+        let options = ParseFloatOptions::LOSSY.with_exponent_char(b'd').unwrap();
+        assert_eq!(options.exponent(), b'd');
+        assert_eq!(options.lossy(), true);
+        let options = WriteFloatOptions::rust_debug().with_exponent_char(b'D').unwrap();
+        assert_eq!(options.exponent(), b'd');
+        assert_eq!(options.rebuild().build(), Some(options));
+        assert_eq!(ParseFloatOptions::new().with_exponent_char(b'.'), None);
+        assert_eq!(WriteFloatOptions::new().with_exponent_char(b'5'), None);
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn test_with_radix() {
+        let options = ParseFloatOptions::LOSSY.with_radix(2).unwrap();
+        assert_eq!(options.radix(), 2);
+        assert_eq!(options.exponent_base(), 10);
+        assert_eq!(options.lossy(), true);
+        assert_eq!(WriteIntegerOptions::new().with_radix(16), Some(WriteIntegerOptions::RADIX_16));
+        assert_eq!(ParseIntegerOptions::new().with_radix(8), Some(ParseIntegerOptions::RADIX_8));
+        assert_eq!(WriteFloatOptions::new().with_radix(37), None);
+
+        // The backup exponent character is used for radix 2.
+        let options = WriteFloatOptions::binary().with_exponent_char(b'#').unwrap();
+        assert_eq!(options.exponent(), b'#');
+        assert_eq!(options.with_radix(10).unwrap().exponent(), b'e');
+    }
+
+    #[test]
+    #[cfg(feature = "power_of_two")]
+    fn test_from_radix() {
+        assert_eq!(ParseIntegerOptions::from_radix(16), Some(ParseIntegerOptions::RADIX_16));
+        assert_eq!(ParseFloatOptions::from_radix(2), Some(ParseFloatOptions::binary()));
+        assert_eq!(ParseFloatOptions::from_radix(16), Some(ParseFloatOptions::hexadecimal()));
+        assert_eq!(WriteIntegerOptions::from_radix(32), Some(WriteIntegerOptions::RADIX_32));
+        assert_eq!(WriteFloatOptions::from_radix(8), Some(WriteFloatOptions::RADIX_8));
+        assert_eq!(ParseIntegerOptions::from_radix(1), None);
+        assert_eq!(WriteFloatOptions::from_radix(37), None);
+        assert_eq!(WriteFloatOptions::from_radix(266), None);
+
+        // Must match the builder, for every valid radix.
+        for radix in 2..37u32 {
+            let options = ParseFloatOptions::builder()
+                .radix(radix as u8)
+                .exponent_base(radix as u8)
+                .exponent_radix(radix as u8)
+                .build();
+            assert_eq!(ParseFloatOptions::from_radix(radix), options);
+            let options = WriteFloatOptions::builder().radix(radix as u8).build();
+            assert_eq!(WriteFloatOptions::from_radix(radix), options);
+            let options = ParseIntegerOptions::builder().radix(radix as u8).build();
+            assert_eq!(ParseIntegerOptions::from_radix(radix), options);
+            let options = WriteIntegerOptions::builder().radix(radix as u8).build();
+            assert_eq!(WriteIntegerOptions::from_radix(radix), options);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn test_radix_constants_radix() {
+        assert_eq!(ParseIntegerOptions::RADIX_36.radix(), 36);
+        assert_eq!(ParseFloatOptions::RADIX_3.radix(), 3);
+        assert_eq!(ParseFloatOptions::RADIX_3.exponent_base(), 3);
+        assert_eq!(WriteIntegerOptions::RADIX_7.radix(), 7);
+        assert_eq!(WriteFloatOptions::RADIX_25.radix(), 25);
     }
 }
@@ -0,0 +1,90 @@
+//! Context about an integer that overflowed its type.
+
+use super::sign::Sign;
+
+// INTEGER OVERFLOW
+// ----------------
+
+/// Magnitude of an integer that overflowed or underflowed its type.
+///
+/// Returned by the `FromLexicalOverflow` parsers alongside the error,
+/// so callers can retry with a wider type, or keep the wrapped value,
+/// without reparsing the digits.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{ErrorCode, FromLexicalOverflow, ParseIntegerOptions};
+///
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::new();
+/// let (error, overflow) = u8::from_lexical_with_overflow(b"1000", &options).unwrap_err();
+/// assert_eq!(error.code, ErrorCode::Overflow);
+/// let overflow = overflow.unwrap();
+/// assert_eq!(overflow.digits(), 4);
+/// assert_eq!(overflow.magnitude(), Some(1000));
+/// assert_eq!(overflow.wrapped() as u8, 232);
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IntegerOverflow {
+    /// Sign of the integer.
+    sign: Sign,
+    /// Number of digits in the integer, excluding digit separators.
+    digits: usize,
+    /// Magnitude of the integer, modulo `2^128`.
+    wrapped: u128,
+    /// If the magnitude of the integer fits in a `u128`.
+    exact: bool,
+}
+
+impl IntegerOverflow {
+    /// Create overflow context from the accumulated digits.
+    #[inline]
+    pub(crate) fn new(sign: Sign, digits: usize, wrapped: u128, exact: bool) -> Self {
+        Self {
+            sign,
+            digits,
+            wrapped,
+            exact,
+        }
+    }
+
+    /// Get the sign of the integer.
+    ///
+    /// Overflow is always positive, and underflow always negative.
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// Get the number of digits in the integer.
+    ///
+    /// Excludes the sign, radix prefix and any digit separators.
+    #[inline]
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Get the magnitude of the integer, if it fits in a `u128`.
+    #[inline]
+    pub fn magnitude(&self) -> Option<u128> {
+        match self.exact {
+            true => Some(self.wrapped),
+            false => None,
+        }
+    }
+
+    /// Get the integer modulo `2^128`, in two's complement.
+    ///
+    /// Casting the wrapped value with `as` gives the integer modulo a
+    /// narrower type, like wrapping arithmetic in that type.
+    #[inline]
+    pub fn wrapped(&self) -> u128 {
+        match self.sign {
+            Sign::Positive => self.wrapped,
+            Sign::Negative => self.wrapped.wrapping_neg(),
+        }
+    }
+}
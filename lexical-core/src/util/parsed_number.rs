@@ -0,0 +1,46 @@
+//! Number parsed as the narrowest lossless type.
+
+// PARSED NUMBER
+// -------------
+
+/// Number parsed as a `u64`, an `i64`, or an `f64`.
+///
+/// Created by `parse_number`. Like JSON and TOML parsers, integers
+/// are parsed as a `u64` if they are positive, or an `i64` if they are
+/// negative, and all other numbers, including integers that overflow
+/// both, are parsed as an `f64`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{ParseFloatOptions, ParsedNumber};
+///
+/// # pub fn main() {
+/// let options = ParseFloatOptions::new();
+/// assert_eq!(lexical_core::parse_number(b"15", &options), Ok(ParsedNumber::U64(15)));
+/// assert_eq!(lexical_core::parse_number(b"-15", &options), Ok(ParsedNumber::I64(-15)));
+/// assert_eq!(lexical_core::parse_number(b"1.5", &options), Ok(ParsedNumber::F64(1.5)));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ParsedNumber {
+    /// The number is a non-negative integer that fits in a `u64`.
+    U64(u64),
+    /// The number is a negative integer that fits in an `i64`.
+    I64(i64),
+    /// The number is not an integer, or the integer overflows.
+    F64(f64),
+}
+
+impl ParsedNumber {
+    /// Get the number as an `f64`, which may round large integers.
+    #[inline]
+    pub fn as_f64(self) -> f64 {
+        match self {
+            ParsedNumber::U64(value) => value as f64,
+            ParsedNumber::I64(value) => value as f64,
+            ParsedNumber::F64(value) => value,
+        }
+    }
+}
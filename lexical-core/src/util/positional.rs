@@ -0,0 +1,210 @@
+//! Lazy positional notation of the shortest decimal digits.
+
+#![cfg(feature = "floats")]
+
+use super::decimal_digits::DecimalDigits;
+
+// POSITIONAL BYTES
+// ----------------
+
+/// Iterator over the bytes of a float in positional notation.
+///
+/// Created by `write_positional_iter`. Each byte is computed from the
+/// shortest decimal digits of the float when it is requested, so the
+/// leading or trailing zeros of large exponents, such as the 301 digits
+/// of `1e300`, are streamed without a buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::WriteFloatOptions;
+///
+/// # pub fn main() {
+/// let options = WriteFloatOptions::new();
+/// let mut bytes = lexical_core::write_positional_iter(-1.5e-3f64, &options);
+/// assert_eq!(bytes.len(), 7);
+/// assert!(bytes.by_ref().eq(b"-0.0015".iter().cloned()));
+///
+/// let bytes = lexical_core::write_positional_iter(1e300f64, &options);
+/// assert_eq!(bytes.len(), 303);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PositionalBytes {
+    /// Write a leading `-`.
+    is_negative: bool,
+    /// Special string written instead of the digits.
+    special: Option<&'static [u8]>,
+    /// Shortest decimal digits.
+    decimal: DecimalDigits,
+    /// Decimal point character.
+    decimal_point: u8,
+    /// Index of the next byte.
+    index: usize,
+    /// Number of bytes.
+    len: usize,
+}
+
+impl PositionalBytes {
+    /// Create the iterator for the digits of a finite float.
+    #[inline]
+    pub(crate) fn new(
+        is_negative: bool,
+        decimal: DecimalDigits,
+        decimal_point: u8,
+        trim_floats: bool,
+    ) -> Self {
+        let count = decimal.digits().len();
+        let exponent = decimal.exponent();
+        let digits_len = match exponent >= 0 {
+            // The integer digits, then the fraction, if any.
+            true => {
+                let integer = exponent as usize + 1;
+                match count > integer {
+                    true => count + 1,
+                    false if trim_floats => integer,
+                    false => integer + 2,
+                }
+            },
+            // `0.`, then the leading zeros and the digits.
+            false => exponent.wrapping_neg() as usize + 1 + count,
+        };
+        PositionalBytes {
+            is_negative,
+            special: None,
+            decimal,
+            decimal_point,
+            index: 0,
+            len: is_negative as usize + digits_len,
+        }
+    }
+
+    /// Create the iterator for a special string, such as NaN or infinity.
+    #[inline]
+    pub(crate) fn special(is_negative: bool, special: &'static [u8]) -> Self {
+        PositionalBytes {
+            is_negative,
+            special: Some(special),
+            decimal: DecimalDigits::zero(),
+            decimal_point: b'.',
+            index: 0,
+            len: is_negative as usize + special.len(),
+        }
+    }
+
+    /// Get the byte at the index, which must be less than the length.
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        let index = match self.is_negative {
+            true if index == 0 => return b'-',
+            true => index - 1,
+            false => index,
+        };
+        if let Some(special) = self.special {
+            return special[index];
+        }
+
+        let digits = self.decimal.digits();
+        let exponent = self.decimal.exponent();
+        if exponent < 0 {
+            let digits_start = exponent.wrapping_neg() as usize + 1;
+            match index {
+                1 => self.decimal_point,
+                _ if index < digits_start => b'0',
+                _ => digits[index - digits_start],
+            }
+        } else {
+            // Digits past the significant digits are zeros, including
+            // the `0` of the `.0` suffix.
+            let integer = exponent as usize + 1;
+            match index {
+                _ if index < integer => digits.get(index).cloned().unwrap_or(b'0'),
+                _ if index == integer => self.decimal_point,
+                _ => digits.get(index - 1).cloned().unwrap_or(b'0'),
+            }
+        }
+    }
+}
+
+impl Iterator for PositionalBytes {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.index == self.len {
+            return None;
+        }
+        let byte = self.byte(self.index);
+        self.index += 1;
+        Some(byte)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<u8> {
+        self.index += n.min(self.len - self.index);
+        self.next()
+    }
+}
+
+impl ExactSizeIterator for PositionalBytes {
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(iter: PositionalBytes) -> ([u8; 64], usize) {
+        let mut buffer = [0u8; 64];
+        let mut len = 0;
+        for byte in iter {
+            buffer[len] = byte;
+            len += 1;
+        }
+        (buffer, len)
+    }
+
+    fn check(iter: PositionalBytes, expected: &[u8]) {
+        assert_eq!(iter.len(), expected.len());
+        let (buffer, len) = collect(iter);
+        assert_eq!(&buffer[..len], expected);
+    }
+
+    #[test]
+    fn positional_bytes_test() {
+        let new = |digits, exponent, trim| {
+            PositionalBytes::new(false, DecimalDigits::new(digits, exponent), b'.', trim)
+        };
+        check(new(b"15", 0, false), b"1.5");
+        check(new(b"15", -3, false), b"0.0015");
+        check(new(b"15", 1, false), b"15.0");
+        check(new(b"15", 1, true), b"15");
+        check(new(b"15", 4, false), b"15000.0");
+        check(new(b"12345", 1, true), b"12.345");
+        check(PositionalBytes::new(false, DecimalDigits::zero(), b'.', false), b"0.0");
+        check(PositionalBytes::new(true, DecimalDigits::zero(), b',', true), b"-0");
+        check(PositionalBytes::new(true, DecimalDigits::new(b"25", -1), b',', true), b"-0,25");
+        check(PositionalBytes::special(true, b"inf"), b"-inf");
+        check(PositionalBytes::special(false, b"NaN"), b"NaN");
+    }
+
+    #[test]
+    fn nth_test() {
+        let mut iter = PositionalBytes::new(false, DecimalDigits::new(b"1", 40), b'.', false);
+        assert_eq!(iter.len(), 43);
+        assert_eq!(iter.nth(0), Some(b'1'));
+        assert_eq!(iter.nth(39), Some(b'0'));
+        assert_eq!(iter.nth(0), Some(b'.'));
+        assert_eq!(iter.nth(5), None);
+        assert_eq!(iter.next(), None);
+    }
+}
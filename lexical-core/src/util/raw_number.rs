@@ -0,0 +1,187 @@
+//! Number scanned without converting it, for arbitrary precision.
+
+use crate::result::*;
+use crate::traits::*;
+
+use super::format::NumberFormat;
+use super::options::*;
+use super::sign::Sign;
+
+// RAW NUMBER
+// ----------
+
+/// Validated number, stored verbatim without converting it.
+///
+/// Created by `parse_raw_number`, which checks the syntax of a number
+/// against the number format, like `classify`, and stores the sign and
+/// the ranges of the integer digits, fraction digits and exponent. The
+/// digits are never rounded, so the number may be converted on demand,
+/// to a float, a 128-bit integer, or an arbitrary-precision decimal
+/// from its parts, like `arbitrary_precision` numbers in `serde_json`.
+///
+/// The storage `B` is a borrowed slice, `&[u8]`, or any owned bytes,
+/// such as a `Vec<u8>` from `to_vec`, so a raw number may outlive
+/// the buffer it was scanned from.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::{ParseFloatOptions, Sign};
+///
+/// # pub fn main() {
+/// let options = ParseFloatOptions::new();
+/// let raw = lexical_core::parse_raw_number(b"-12.50e+3", &options).unwrap();
+/// assert_eq!(raw.sign(), Sign::Negative);
+/// assert_eq!(raw.integer(), b"12");
+/// assert_eq!(raw.fraction(), Some(&b"50"[..]));
+/// assert_eq!(raw.exponent(), Some(&b"+3"[..]));
+/// assert_eq!(raw.to_float::<f64>(), Ok(-12500.0));
+///
+/// let raw = lexical_core::parse_raw_number(b"9223372036854775807", &options).unwrap();
+/// assert!(raw.is_integer());
+/// assert_eq!(raw.to_integer::<i64>(), Ok(i64::max_value()));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RawNumber<B> {
+    /// Bytes of the number, from the sign to the end of the exponent.
+    bytes: B,
+    /// Sign of the number.
+    sign: Sign,
+    /// Range of the integer digits.
+    integer: (usize, usize),
+    /// Range of the fraction digits, after the decimal point.
+    fraction: Option<(usize, usize)>,
+    /// Range of the exponent, with its sign, after the exponent character.
+    exponent: Option<(usize, usize)>,
+    /// Number format the number was scanned with.
+    format: NumberFormat,
+    /// Radix of the digits.
+    radix: u32,
+}
+
+impl<'a> RawNumber<&'a [u8]> {
+    /// Create a raw number from the scanned bytes and the ranges of its parts.
+    #[inline]
+    pub(crate) fn new(
+        bytes: &'a [u8],
+        sign: Sign,
+        integer: (usize, usize),
+        fraction: Option<(usize, usize)>,
+        exponent: Option<(usize, usize)>,
+        format: NumberFormat,
+        radix: u32,
+    ) -> Self {
+        RawNumber {
+            bytes,
+            sign,
+            integer,
+            fraction,
+            exponent,
+            format,
+            radix,
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> RawNumber<B> {
+    /// Get the bytes of the number, from the sign to the end of the exponent.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+
+    /// Get the sign of the number.
+    #[inline]
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// Get the integer digits, which may be empty, like in `.5`.
+    #[inline]
+    pub fn integer(&self) -> &[u8] {
+        &self.as_bytes()[self.integer.0..self.integer.1]
+    }
+
+    /// Get the fraction digits after the decimal point, if present.
+    #[inline]
+    pub fn fraction(&self) -> Option<&[u8]> {
+        self.fraction.map(|(start, end)| &self.as_bytes()[start..end])
+    }
+
+    /// Get the exponent after the exponent character, with its sign, if present.
+    #[inline]
+    pub fn exponent(&self) -> Option<&[u8]> {
+        self.exponent.map(|(start, end)| &self.as_bytes()[start..end])
+    }
+
+    /// Get if the number has no fraction or exponent.
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.fraction.is_none() && self.exponent.is_none()
+    }
+
+    /// Get the number format the number was scanned with.
+    #[inline]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the radix of the digits.
+    #[inline]
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Borrow the raw number, with a slice as the storage.
+    #[inline]
+    pub fn as_slice(&self) -> RawNumber<&[u8]> {
+        RawNumber {
+            bytes: self.as_bytes(),
+            sign: self.sign,
+            integer: self.integer,
+            fraction: self.fraction,
+            exponent: self.exponent,
+            format: self.format,
+            radix: self.radix,
+        }
+    }
+
+    /// Copy the raw number, with a vector as the storage.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub fn to_vec(&self) -> RawNumber<Vec<u8>> {
+        RawNumber {
+            bytes: self.as_bytes().to_vec(),
+            sign: self.sign,
+            integer: self.integer,
+            fraction: self.fraction,
+            exponent: self.exponent,
+            format: self.format,
+            radix: self.radix,
+        }
+    }
+
+    /// Convert the number to a float, in the format and radix it was scanned with.
+    #[inline]
+    pub fn to_float<F>(&self) -> Result<F>
+    where
+        F: FromLexicalOptions<ParseOptions = ParseFloatOptions>,
+    {
+        let options = ParseFloatOptions::for_format(self.format, self.radix);
+        F::from_lexical_with_options(self.as_bytes(), &options)
+    }
+
+    /// Convert the number to an integer, in the format and radix it was scanned with.
+    ///
+    /// Numbers with a fraction or an exponent are invalid, even if integral.
+    #[inline]
+    pub fn to_integer<I>(&self) -> Result<I>
+    where
+        I: FromLexicalOptions<ParseOptions = ParseIntegerOptions>,
+    {
+        let options = ParseIntegerOptions::for_format(self.format, self.radix);
+        I::from_lexical_with_options(self.as_bytes(), &options)
+    }
+}
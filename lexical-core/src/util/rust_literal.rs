@@ -0,0 +1,176 @@
+//! Radix prefixes and type suffixes of Rust numeric literals.
+
+#![cfg(feature = "format")]
+
+// RUST SUFFIX
+// -----------
+
+/// Type suffix of a Rust numeric literal, such as `u8` in `255u8`.
+///
+/// Returned by `parse_rust_literal`, with the value of the literal.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// use lexical_core::RustSuffix;
+///
+/// # pub fn main() {
+/// let value = lexical_core::parse_rust_literal::<u32>(b"1_000u32");
+/// assert_eq!(value, Ok((1000, Some(RustSuffix::U32))));
+///
+/// let value = lexical_core::parse_rust_literal::<f64>(b"1.5e3_f64");
+/// assert_eq!(value, Ok((1500.0, Some(RustSuffix::F64))));
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RustSuffix {
+    /// The `u8` suffix.
+    U8,
+    /// The `u16` suffix.
+    U16,
+    /// The `u32` suffix.
+    U32,
+    /// The `u64` suffix.
+    U64,
+    /// The `u128` suffix.
+    U128,
+    /// The `usize` suffix.
+    Usize,
+    /// The `i8` suffix.
+    I8,
+    /// The `i16` suffix.
+    I16,
+    /// The `i32` suffix.
+    I32,
+    /// The `i64` suffix.
+    I64,
+    /// The `i128` suffix.
+    I128,
+    /// The `isize` suffix.
+    Isize,
+    /// The `f32` suffix.
+    F32,
+    /// The `f64` suffix.
+    F64,
+}
+
+impl RustSuffix {
+    /// Get the suffix from its string, or `None` if it is not a suffix.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let suffix = match bytes {
+            b"u8" => RustSuffix::U8,
+            b"u16" => RustSuffix::U16,
+            b"u32" => RustSuffix::U32,
+            b"u64" => RustSuffix::U64,
+            b"u128" => RustSuffix::U128,
+            b"usize" => RustSuffix::Usize,
+            b"i8" => RustSuffix::I8,
+            b"i16" => RustSuffix::I16,
+            b"i32" => RustSuffix::I32,
+            b"i64" => RustSuffix::I64,
+            b"i128" => RustSuffix::I128,
+            b"isize" => RustSuffix::Isize,
+            b"f32" => RustSuffix::F32,
+            b"f64" => RustSuffix::F64,
+            _ => return None,
+        };
+        Some(suffix)
+    }
+
+    /// Get the string of the suffix.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RustSuffix::U8 => "u8",
+            RustSuffix::U16 => "u16",
+            RustSuffix::U32 => "u32",
+            RustSuffix::U64 => "u64",
+            RustSuffix::U128 => "u128",
+            RustSuffix::Usize => "usize",
+            RustSuffix::I8 => "i8",
+            RustSuffix::I16 => "i16",
+            RustSuffix::I32 => "i32",
+            RustSuffix::I64 => "i64",
+            RustSuffix::I128 => "i128",
+            RustSuffix::Isize => "isize",
+            RustSuffix::F32 => "f32",
+            RustSuffix::F64 => "f64",
+        }
+    }
+
+    /// Get if the suffix is a float type.
+    #[inline]
+    pub fn is_float(self) -> bool {
+        match self {
+            RustSuffix::F32 | RustSuffix::F64 => true,
+            _ => false,
+        }
+    }
+}
+
+// RUST PREFIX
+// -----------
+
+/// Get the radix and length of the radix prefix of a Rust literal.
+///
+/// The prefixes are `0x`, `0o` and `0b`, and are case-sensitive.
+/// Literals without a prefix are decimal.
+#[inline]
+pub(crate) fn rust_literal_prefix(bytes: &[u8]) -> (u32, usize) {
+    match (bytes.get(0), bytes.get(1)) {
+        (Some(&b'0'), Some(&b'x')) => (16, 2),
+        (Some(&b'0'), Some(&b'o')) => (8, 2),
+        (Some(&b'0'), Some(&b'b')) => (2, 2),
+        _ => (10, 0),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rust_suffix_test() {
+        let suffixes = [
+            RustSuffix::U8,
+            RustSuffix::U16,
+            RustSuffix::U32,
+            RustSuffix::U64,
+            RustSuffix::U128,
+            RustSuffix::Usize,
+            RustSuffix::I8,
+            RustSuffix::I16,
+            RustSuffix::I32,
+            RustSuffix::I64,
+            RustSuffix::I128,
+            RustSuffix::Isize,
+            RustSuffix::F32,
+            RustSuffix::F64,
+        ];
+        for &suffix in suffixes.iter() {
+            assert_eq!(RustSuffix::from_bytes(suffix.as_str().as_bytes()), Some(suffix));
+        }
+        assert!(RustSuffix::F32.is_float());
+        assert!(!RustSuffix::Usize.is_float());
+        assert_eq!(RustSuffix::from_bytes(b""), None);
+        assert_eq!(RustSuffix::from_bytes(b"u"), None);
+        assert_eq!(RustSuffix::from_bytes(b"U8"), None);
+        assert_eq!(RustSuffix::from_bytes(b"f16"), None);
+    }
+
+    #[test]
+    fn rust_literal_prefix_test() {
+        assert_eq!(rust_literal_prefix(b"0xFF"), (16, 2));
+        assert_eq!(rust_literal_prefix(b"0o17"), (8, 2));
+        assert_eq!(rust_literal_prefix(b"0b1"), (2, 2));
+        assert_eq!(rust_literal_prefix(b"0XFF"), (10, 0));
+        assert_eq!(rust_literal_prefix(b"0"), (10, 0));
+        assert_eq!(rust_literal_prefix(b"12"), (10, 0));
+        assert_eq!(rust_literal_prefix(b""), (10, 0));
+    }
+}
@@ -0,0 +1,278 @@
+//! Serde support for number formats and options.
+//!
+//! Number formats and rounding kinds are serialized as their raw bits,
+//! and validated on deserialization. Options are serialized through
+//! their builders, so deserialized options are always validated.
+//!
+//! Options may only hold static strings, which cannot be created from
+//! the deserializer input without leaking memory. Only the built-in
+//! special strings and empty lists of strings are therefore serialized,
+//! and serializing options with other strings fails, so all serialized
+//! options may be deserialized.
+
+#![cfg(feature = "serde")]
+
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{self, Serialize, Serializer};
+
+use crate::lib::str;
+use crate::lib::string::String;
+
+use super::format::NumberFormat;
+use super::options::*;
+use super::rounding::RoundingKind;
+
+// NUMBER FORMAT
+
+impl Serialize for NumberFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for NumberFormat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u64::deserialize(deserializer)?;
+        let format = NumberFormat::new(bits);
+        match format.rebuild().build() {
+            Some(valid) if valid == format => Ok(format),
+            _ => Err(de::Error::custom("invalid number format")),
+        }
+    }
+}
+
+// ROUNDING KIND
+
+impl Serialize for RoundingKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for RoundingKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u32::deserialize(deserializer)? {
+            0 => Ok(RoundingKind::NearestTieEven),
+            1 => Ok(RoundingKind::NearestTieAwayZero),
+            2 => Ok(RoundingKind::TowardPositiveInfinity),
+            3 => Ok(RoundingKind::TowardNegativeInfinity),
+            4 => Ok(RoundingKind::TowardZero),
+            _ => Err(de::Error::custom("invalid rounding kind")),
+        }
+    }
+}
+
+// SPECIAL STRINGS
+
+/// Serialize and deserialize the static special strings in options.
+///
+/// Only the built-in special strings are supported.
+pub(crate) mod static_bytes {
+    use super::*;
+
+    /// Built-in special strings, which may be serialized.
+    const KNOWN: &[&'static [u8]] = &[
+        DEFAULT_NAN_STRING,
+        C_NAN_STRING,
+        DEFAULT_INF_STRING,
+        DEFAULT_INFINITY_STRING,
+        JS_INF_STRING,
+        #[cfg(feature = "format")]
+        XSD_INF_STRING,
+    ];
+
+    pub(crate) fn serialize<S: Serializer>(
+        bytes: &&'static [u8],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match KNOWN.contains(bytes) {
+            // The built-in strings are ASCII.
+            true => serializer.serialize_str(str::from_utf8(bytes).unwrap()),
+            false => Err(ser::Error::custom("unsupported special string")),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static [u8], D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match KNOWN.iter().find(|&&known| known == string.as_bytes()) {
+            Some(&known) => Ok(known),
+            None => Err(de::Error::custom("unsupported special string")),
+        }
+    }
+}
+
+/// Serialize and deserialize the static lists of signs and skipped affixes in options.
+///
+/// Only the empty default list is supported.
+pub(crate) mod static_bytes_list {
+    use super::*;
+    use crate::lib::vec::Vec;
+
+    pub(crate) fn serialize<S: Serializer>(
+        list: &&'static [&'static [u8]],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match list.is_empty() {
+            true => Vec::<&str>::new().serialize(serializer),
+            false => Err(ser::Error::custom("unsupported list of strings")),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<&'static [&'static [u8]], D::Error> {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        match strings.is_empty() {
+            true => Ok(DEFAULT_SKIP_AFFIXES),
+            false => Err(de::Error::custom("unsupported list of strings")),
+        }
+    }
+}
+
+// OPTIONS
+
+/// Implement serde for options through their builders.
+macro_rules! options_serde_impl {
+    ($options:ident, $builder:ident) => {
+        impl Serialize for $options {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.rebuild().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $options {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let builder = $builder::deserialize(deserializer)?;
+                builder.build().ok_or(de::Error::custom(concat!("invalid ", stringify!($options))))
+            }
+        }
+    };
+}
+
+options_serde_impl!(ParseIntegerOptions, ParseIntegerOptionsBuilder);
+options_serde_impl!(ParseFloatOptions, ParseFloatOptionsBuilder);
+options_serde_impl!(WriteIntegerOptions, WriteIntegerOptionsBuilder);
+options_serde_impl!(WriteFloatOptions, WriteFloatOptionsBuilder);
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::AlternateForm;
+
+    #[test]
+    fn number_format_test() {
+        let format = NumberFormat::STANDARD;
+        let json = serde_json::to_string(&format).unwrap();
+        assert_eq!(serde_json::from_str::<NumberFormat>(&json).unwrap(), format);
+        assert!(serde_json::from_str::<NumberFormat>("1").is_err());
+    }
+
+    #[test]
+    fn rounding_kind_test() {
+        let kind = RoundingKind::TowardZero;
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(json, "4");
+        assert_eq!(serde_json::from_str::<RoundingKind>(&json).unwrap(), kind);
+        assert!(serde_json::from_str::<RoundingKind>("15").is_err());
+    }
+
+    #[test]
+    fn options_test() {
+        let options = ParseIntegerOptions::new();
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(serde_json::from_str::<ParseIntegerOptions>(&json).unwrap(), options);
+
+        let options = ParseIntegerOptions::builder().minus_signs(&[b"\xE2\x88\x92"]).build().unwrap();
+        assert!(serde_json::to_string(&options).is_err());
+
+        let options = ParseFloatOptions::builder().nan_string(b"nan").lossy(true).build().unwrap();
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(serde_json::from_str::<ParseFloatOptions>(&json).unwrap(), options);
+
+        let options = WriteIntegerOptions::new();
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(serde_json::from_str::<WriteIntegerOptions>(&json).unwrap(), options);
+
+        let options = WriteFloatOptions::builder().inf_string(b"Infinity");
+        let options = options.alternate_form(Some(AlternateForm::PointZero)).build().unwrap();
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(serde_json::from_str::<WriteFloatOptions>(&json).unwrap(), options);
+
+        // Only the built-in special strings may be serialized.
+        let options = WriteFloatOptions::builder().inf_string(b"Inf").build().unwrap();
+        assert!(serde_json::to_string(&options).is_err());
+
+        // Missing fields use the defaults, and invalid values are rejected.
+        let options = serde_json::from_str::<ParseFloatOptions>(r#"{"lossy":true}"#).unwrap();
+        assert_eq!(options.lossy(), true);
+        assert_eq!(options.nan_string(), b"NaN");
+        assert!(serde_json::from_str::<ParseFloatOptions>(r#"{"nan_string":"x"}"#).is_err());
+        assert!(serde_json::from_str::<WriteIntegerOptions>(r#"{"radix":1}"#).is_err());
+
+        // Skipped affixes serialize as lists of strings, and only empty lists are supported.
+        let options = ParseFloatOptions::builder()
+            .skip_prefixes(&[b"$", b"USD"])
+            .skip_suffixes(&[b"%"])
+            .build()
+            .unwrap();
+        assert!(serde_json::to_string(&options).is_err());
+        let json = serde_json::to_string(&ParseFloatOptions::new()).unwrap();
+        assert!(json.contains(r#""skip_prefixes":[]"#));
+        let options = serde_json::from_str::<ParseFloatOptions>(r#"{"skip_prefixes":[]}"#);
+        assert_eq!(options.unwrap(), ParseFloatOptions::new());
+        assert!(serde_json::from_str::<ParseFloatOptions>(r#"{"skip_suffixes":["e"]}"#).is_err());
+    }
+
+    #[test]
+    fn round_trip_test() {
+        macro_rules! check {
+            ($t:ident, $options:expr) => {{
+                let options = $options;
+                let json = serde_json::to_string(&options).unwrap();
+                assert_eq!(serde_json::from_str::<$t>(&json).unwrap(), options);
+            }};
+        }
+
+        // Non-default special strings and signs.
+        let options = ParseFloatOptions::builder()
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .infinity_string(b"Infinity")
+            .build()
+            .unwrap();
+        check!(ParseFloatOptions, options);
+        let options = WriteFloatOptions::builder()
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .positive_sign(Some(b'+'))
+            .negative_parentheses(true)
+            .build()
+            .unwrap();
+        check!(WriteFloatOptions, options);
+
+        // Presets.
+        check!(ParseIntegerOptions, ParseIntegerOptions::rust_string());
+        check!(ParseFloatOptions, ParseFloatOptions::csv_european());
+        check!(ParseFloatOptions, ParseFloatOptions::rust_string());
+        check!(WriteIntegerOptions, WriteIntegerOptions::rust_string());
+        check!(WriteFloatOptions, WriteFloatOptions::python_repr());
+        check!(WriteFloatOptions, WriteFloatOptions::javascript_string());
+        check!(WriteFloatOptions, WriteFloatOptions::rust_debug());
+        #[cfg(feature = "format")]
+        {
+            check!(ParseIntegerOptions, ParseIntegerOptions::c_locale());
+            check!(ParseFloatOptions, ParseFloatOptions::c_locale());
+            check!(ParseFloatOptions, ParseFloatOptions::javascript_string());
+            check!(ParseFloatOptions, ParseFloatOptions::xml_schema_double());
+            check!(ParseFloatOptions, ParseFloatOptions::postgresql());
+            check!(WriteFloatOptions, WriteFloatOptions::c_locale());
+            check!(WriteFloatOptions, WriteFloatOptions::xml_schema_double());
+            check!(WriteFloatOptions, WriteFloatOptions::postgresql());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Enumerations for the sign-bit of a number.
 
+#[cfg(any(feature = "floats", feature = "format"))]
 use super::format::NumberFormat;
 
 // TRAITS
@@ -14,7 +15,6 @@ pub trait IsSigned {
 // ENUMERATION
 
 /// Enumeration for the sign of a a number.
-#[doc(hidden)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Sign {
     /// Negative value.
@@ -27,14 +27,12 @@ pub enum Sign {
 
 // Get if an option contains a digit separator.
 #[inline(always)]
-#[cfg(feature = "format")]
 fn is_digit_separator(option: Option<&u8>, digit_separator: u8) -> bool {
     option == Some(&digit_separator)
 }
 
 // Convert option of byte to option of sign.
 #[inline(always)]
-#[cfg(feature = "format")]
 fn to_sign<T>(option: Option<&u8>) -> Option<Sign>
 where
     T: IsSigned,
@@ -51,7 +49,6 @@ where
 /// We need to consider the following possibilities:
 ///     1). _*[+-]\d+
 #[inline(always)]
-#[cfg(feature = "format")]
 pub(crate) fn parse_sign_lc_separator<'a, T>(
     bytes: &'a [u8],
     digit_separator: u8,
@@ -134,6 +131,7 @@ where
 
 /// Find and parse sign.
 #[inline]
+#[cfg(feature = "floats")]
 pub(crate) fn parse_sign<'a, T>(bytes: &'a [u8], format: NumberFormat) -> (Sign, &'a [u8])
 where
     T: IsSigned,
@@ -145,6 +143,29 @@ where
     return parse_sign_separator::<T>(bytes, format);
 }
 
+/// Find and parse a sign string, such as the Unicode minus sign.
+///
+/// Returns the sign and the length of the longest string that matches,
+/// or `None` if no sign string matches.
+#[inline]
+pub(crate) fn parse_sign_string(
+    bytes: &[u8],
+    plus_signs: &[&[u8]],
+    minus_signs: &[&[u8]],
+) -> Option<(Sign, usize)> {
+    let plus = plus_signs.iter().map(|&sign| (Sign::Positive, sign));
+    let minus = minus_signs.iter().map(|&sign| (Sign::Negative, sign));
+    let mut result = None;
+    for (sign, string) in plus.chain(minus) {
+        match result {
+            Some((_, len)) if len >= string.len() => (),
+            _ if bytes.starts_with(string) => result = Some((sign, string.len())),
+            _ => (),
+        }
+    }
+    result
+}
+
 // TESTS
 // -----
 
@@ -170,7 +191,6 @@ mod tests {
     }
 
     #[test]
-    #[cfg(feature = "format")]
     fn parse_sign_lc_separator_test() {
         assert_eq!(parse_sign_lc_separator::<i32>(b"", b'_'), (Sign::Positive, b!("")));
         assert_eq!(parse_sign_lc_separator::<i32>(b"+", b'_'), (Sign::Positive, b!("")));
@@ -220,6 +240,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "floats")]
     fn parse_sign_test() {
         let format = NumberFormat::STANDARD;
         assert_eq!(parse_sign::<i32>(b"", format), (Sign::Positive, b!("")));
@@ -234,4 +255,18 @@ mod tests {
         assert_eq!(parse_sign::<u32>(b"+5", format), (Sign::Positive, b!("5")));
         assert_eq!(parse_sign::<u32>(b"-5", format), (Sign::Positive, b!("-5")));
     }
+
+    #[test]
+    fn parse_sign_string_test() {
+        let plus: &[&[u8]] = &[b"plus"];
+        let minus: &[&[u8]] = &[b"\xE2\x88\x92", b"minus", b"min"];
+        assert_eq!(parse_sign_string(b"", plus, minus), None);
+        assert_eq!(parse_sign_string(b"-5", plus, minus), None);
+        assert_eq!(parse_sign_string(b"plus5", plus, minus), Some((Sign::Positive, 4)));
+        assert_eq!(parse_sign_string(b"\xE2\x88\x925", plus, minus), Some((Sign::Negative, 3)));
+        assert_eq!(parse_sign_string(b"\xE2\x885", plus, minus), None);
+        assert_eq!(parse_sign_string(b"minus5", plus, minus), Some((Sign::Negative, 5)));
+        assert_eq!(parse_sign_string(b"mint", plus, minus), Some((Sign::Negative, 3)));
+        assert_eq!(parse_sign_string(b"plus5", &[], &[]), None);
+    }
 }
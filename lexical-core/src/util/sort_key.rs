@@ -0,0 +1,102 @@
+//! Order-preserving sort keys for natural sorting.
+//!
+//! Runs of decimal digits are encoded as a marker, the number of
+//! significant digits and the significant digits, so byte-wise
+//! comparison of the keys orders the runs by value, and other bytes
+//! are copied to the key. The number of significant digits is written
+//! as the count of its big-endian bytes, followed by those bytes, so
+//! runs of any length are ordered.
+
+#![cfg(feature = "std")]
+
+use super::algorithm::*;
+use super::consume::*;
+use super::format::NumberFormat;
+use crate::lib::mem;
+use crate::lib::vec::Vec;
+
+// SORT KEY
+
+/// Marker for a run of digits in the key.
+///
+/// The marker is a digit, so runs sort against other bytes like the
+/// digits they replace, since the copied bytes are never digits.
+const DIGITS_MARKER: u8 = b'0';
+
+/// Append the number of significant digits of a run to the key.
+#[inline]
+fn push_digit_count(vec: &mut Vec<u8>, count: usize) {
+    let bytes = (count as u64).to_be_bytes();
+    let leading_zeros = bytes.iter().take_while(|&&c| c == 0).count();
+    vec.push((mem::size_of::<u64>() - leading_zeros) as u8);
+    vec.extend_from_slice(&bytes[leading_zeros..]);
+}
+
+/// Append the natural sort key of the bytes to the vector.
+pub(crate) fn write_natural_sort_key(bytes: &[u8], vec: &mut Vec<u8>) {
+    let mut bytes = bytes;
+    while let Some(&c) = bytes.first() {
+        let (digits, rest) = consume_digits_no_separator(bytes, 10, NumberFormat::STANDARD);
+        if digits.is_empty() {
+            vec.push(c);
+            bytes = &bytes[1..];
+        } else {
+            let digits = ltrim_char_slice(digits, b'0').0;
+            vec.push(DIGITS_MARKER);
+            push_digit_count(vec, digits.len());
+            vec.extend_from_slice(digits);
+            bytes = rest;
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sort_key(bytes: &[u8]) -> Vec<u8> {
+        let mut vec = Vec::new();
+        write_natural_sort_key(bytes, &mut vec);
+        vec
+    }
+
+    #[test]
+    fn sort_key_test() {
+        assert_eq!(sort_key(b""), b"");
+        assert_eq!(sort_key(b"abc"), b"abc");
+        assert_eq!(sort_key(b"a12b"), b"a0\x01\x0212b");
+        assert_eq!(sort_key(b"007"), b"0\x01\x017");
+        assert_eq!(sort_key(b"0"), b"0\x00");
+        assert_eq!(sort_key(b"x2"), sort_key(b"x02"));
+    }
+
+    #[test]
+    fn natural_order_test() {
+        let sorted: [&[u8]; 12] = [
+            b"",
+            b"-5",
+            b"0",
+            b"1",
+            b"2",
+            b"10",
+            b"10a",
+            b"10b",
+            b"file2",
+            b"file2.txt",
+            b"file10",
+            b"file10.txt",
+        ];
+        for pair in sorted.windows(2) {
+            assert!(sort_key(pair[0]) < sort_key(pair[1]));
+        }
+
+        // Runs longer than 255 digits.
+        let long = [b'9'; 300];
+        let longer = [b'1'; 301];
+        assert!(sort_key(&long) < sort_key(&longer));
+        assert!(sort_key(b"99") < sort_key(&long));
+    }
+}
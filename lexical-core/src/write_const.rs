@@ -0,0 +1,120 @@
+//! Integer writers that may be evaluated at compile time.
+//!
+//! The writers are `const fn`, so string tables may be built from
+//! numeric constants without a proc-macro. They write decimal digits
+//! to a fixed-size array, and agree with `write` for these integers.
+//! Requires loops in const fn, so the module is only compiled with
+//! Rustc 1.46.0 or later.
+
+/// Get the number of decimal digits in an integer.
+#[inline]
+const fn digit_count(mut value: u64) -> usize {
+    let mut count = 1;
+    while value >= 10 {
+        value /= 10;
+        count += 1;
+    }
+    count
+}
+
+macro_rules! to_digits {
+    ($(#[$meta:meta])* $name:ident, $t:ty, $size:literal) => (
+        $(#[$meta])*
+        #[inline]
+        pub const fn $name(value: $t) -> ([u8; $size], usize) {
+            let count = digit_count(value as u64);
+            let mut digits = [0u8; $size];
+            let mut value = value;
+            let mut index = count;
+            while index > 0 {
+                index -= 1;
+                digits[index] = b'0' + (value % 10) as u8;
+                value /= 10;
+            }
+            (digits, count)
+        }
+    );
+}
+
+to_digits!(
+    /// Write a `u32` to decimal digits, in a const context.
+    ///
+    /// Returns an array with the digits at the start, followed by
+    /// zeros, and the number of digits. The digits are the same as
+    /// the digits written by `write`.
+    ///
+    /// * `value`   - Number to serialize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lexical_core::u32_to_digits;
+    ///
+    /// const NOT_FOUND: ([u8; 10], usize) = u32_to_digits(404);
+    /// let (digits, count) = NOT_FOUND;
+    /// assert_eq!(&digits[..count], b"404");
+    /// ```
+    u32_to_digits,
+    u32,
+    10
+);
+
+to_digits!(
+    /// Write a `u64` to decimal digits, in a const context.
+    ///
+    /// Returns an array with the digits at the start, followed by
+    /// zeros, and the number of digits. The digits are the same as
+    /// the digits written by `write`.
+    ///
+    /// * `value`   - Number to serialize.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use lexical_core::u64_to_digits;
+    ///
+    /// const MAX: ([u8; 20], usize) = u64_to_digits(u64::max_value());
+    /// let (digits, count) = MAX;
+    /// assert_eq!(&digits[..count], b"18446744073709551615");
+    /// ```
+    u64_to_digits,
+    u64,
+    20
+);
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{write, BUFFER_SIZE};
+
+    #[test]
+    fn u32_to_digits_test() {
+        const DIGITS: ([u8; 10], usize) = u32_to_digits(200);
+        assert_eq!(DIGITS, (*b"200\0\0\0\0\0\0\0", 3));
+
+        let mut buffer = [0u8; BUFFER_SIZE];
+        for &value in [0u32, 1, 9, 10, 99, 100, 12345, 999999999, u32::max_value()].iter() {
+            let (digits, count) = u32_to_digits(value);
+            assert_eq!(&digits[..count], write(value, &mut buffer));
+            assert!(digits[count..].iter().all(|&c| c == 0));
+        }
+    }
+
+    #[test]
+    fn u64_to_digits_test() {
+        const DIGITS: ([u8; 20], usize) = u64_to_digits(0);
+        assert_eq!(DIGITS.1, 1);
+        assert_eq!(DIGITS.0[0], b'0');
+
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let values = [0u64, 9, 10, 4294967296, 9999999999999999999, u64::max_value()];
+        for &value in values.iter() {
+            let (digits, count) = u64_to_digits(value);
+            assert_eq!(&digits[..count], write(value, &mut buffer));
+            assert!(digits[count..].iter().all(|&c| c == 0));
+        }
+    }
+}
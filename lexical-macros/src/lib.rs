@@ -0,0 +1,371 @@
+//! Compile-time number parsing with lexical-core.
+//!
+//! The `lex!` macro parses a string literal with lexical-core when the
+//! crate is compiled, and expands to the parsed value as a numeric
+//! literal. Since the value is parsed by the same algorithms as the
+//! runtime parser, constants and runtime values always agree, including
+//! for custom number formats.
+//!
+//! # Getting Started
+//!
+//! ```rust
+//! use lexical_macros::lex;
+//!
+//! const VALUE: f64 = lex!("1.5e3", f64);
+//! assert_eq!(VALUE, 1500.0);
+//!
+//! #[cfg(feature = "format")] {
+//! // Parse with a pre-defined number format.
+//! const RUST: f64 = lex!("1_234.5e3", f64, RUST_LITERAL);
+//! assert_eq!(RUST, 1234500.0);
+//!
+//! // Parse with a custom number format, from its builder settings.
+//! const CUSTOM: u32 = lex!("1'000'000", u32, {
+//!     digit_separator: b'\'',
+//!     integer_internal_digit_separator: true,
+//! });
+//! assert_eq!(CUSTOM, 1000000);
+//! }
+//! ```
+//!
+//! # Number Formats
+//!
+//! The optional third argument is the number format of the string. It
+//! may be the name of a pre-defined `NumberFormat`, such as `JSON` or
+//! `CXX17_LITERAL`, settings of a `NumberFormatBuilder` in braces, or
+//! a pre-defined format followed by settings to change. The settings
+//! are the names of the builder methods, with a byte or a bool value.
+//! Without a format, the string is parsed like `lexical_core::parse`.
+//! The pre-defined formats and the digit separator settings require
+//! the `format` feature, which is enabled by default.
+
+extern crate proc_macro;
+
+use lexical_core::{
+    FromLexicalOptions,
+    NumberFormat,
+    NumberFormatBuilder,
+    ParseFloatOptions,
+    ParseIntegerOptions,
+};
+use proc_macro::TokenStream;
+use std::fmt::Debug;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, parse_macro_input, token, Ident, Lit, LitStr, Token};
+
+// INPUT
+// -----
+
+/// Setting of a custom number format, like `digit_separator: b'_'`.
+struct Setting {
+    name: Ident,
+    value: Lit,
+}
+
+impl Parse for Setting {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value = input.parse()?;
+        Ok(Setting {
+            name,
+            value,
+        })
+    }
+}
+
+/// Number format argument, with a pre-defined format, settings or both.
+struct Format {
+    preset: Option<Ident>,
+    settings: Vec<Setting>,
+}
+
+impl Parse for Format {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let preset = match input.peek(Ident) {
+            true => Some(input.parse()?),
+            false => None,
+        };
+        let mut settings = Vec::new();
+        if input.peek(token::Brace) {
+            let content;
+            braced!(content in input);
+            let parsed = Punctuated::<Setting, Token![,]>::parse_terminated(&content)?;
+            settings.extend(parsed);
+        } else if preset.is_none() {
+            return Err(input.error("expected a number format name or settings"));
+        }
+        Ok(Format {
+            preset,
+            settings,
+        })
+    }
+}
+
+/// Arguments of `lex!`: the string, the numeric type and the number format.
+struct Input {
+    string: LitStr,
+    ty: Ident,
+    format: Option<Format>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let string = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let ty = input.parse()?;
+        let mut format = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            format = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Input {
+            string,
+            ty,
+            format,
+        })
+    }
+}
+
+// FORMAT
+// ------
+
+/// Get a pre-defined number format from its name.
+fn preset(name: &Ident) -> syn::Result<NumberFormat> {
+    macro_rules! presets {
+        ($($preset:ident)*) => (
+            match name.to_string().as_str() {
+                $(stringify!($preset) => Ok(NumberFormat::$preset),)*
+                _ => Err(syn::Error::new(name.span(), "unknown number format")),
+            }
+        );
+    }
+
+    #[cfg(not(feature = "format"))]
+    return presets!(STANDARD);
+
+    #[cfg(feature = "format")]
+    return presets!(
+        RUST_LITERAL RUST_STRING RUST_STRING_STRICT
+        PYTHON_LITERAL PYTHON_STRING PYTHON3_LITERAL PYTHON3_STRING
+        PYTHON2_LITERAL PYTHON2_STRING
+        CXX_LITERAL CXX_STRING CXX17_LITERAL CXX17_STRING CXX14_LITERAL CXX14_STRING
+        CXX11_LITERAL CXX11_STRING CXX03_LITERAL CXX03_STRING CXX98_LITERAL CXX98_STRING
+        C_LITERAL C_STRING C18_LITERAL C18_STRING C11_LITERAL C11_STRING
+        C99_LITERAL C99_STRING C90_LITERAL C90_STRING C89_LITERAL C89_STRING
+        RUBY_LITERAL RUBY_STRING SWIFT_LITERAL SWIFT_STRING GO_LITERAL GO_STRING
+        HASKELL_LITERAL HASKELL_STRING JAVASCRIPT_LITERAL JAVASCRIPT_STRING
+        PERL_LITERAL PERL_STRING PHP_LITERAL PHP_STRING JAVA_LITERAL JAVA_STRING
+        R_LITERAL R_STRING KOTLIN_LITERAL KOTLIN_STRING JULIA_LITERAL JULIA_STRING
+        CSHARP_LITERAL CSHARP_STRING CSHARP7_LITERAL CSHARP7_STRING
+        CSHARP6_LITERAL CSHARP6_STRING CSHARP5_LITERAL CSHARP5_STRING
+        CSHARP4_LITERAL CSHARP4_STRING CSHARP3_LITERAL CSHARP3_STRING
+        CSHARP2_LITERAL CSHARP2_STRING CSHARP1_LITERAL CSHARP1_STRING
+        KAWA_LITERAL KAWA_STRING GAMBITC_LITERAL GAMBITC_STRING
+        GUILE_LITERAL GUILE_STRING CLOJURE_LITERAL CLOJURE_STRING
+        ERLANG_LITERAL ERLANG_STRING ELM_LITERAL ELM_STRING
+        SCALA_LITERAL SCALA_STRING ELIXIR_LITERAL ELIXIR_STRING
+        FORTRAN_LITERAL FORTRAN_STRING D_LITERAL D_STRING
+        COFFEESCRIPT_LITERAL COFFEESCRIPT_STRING COBOL_LITERAL COBOL_STRING
+        FSHARP_LITERAL FSHARP_STRING VB_LITERAL VB_STRING OCAML_LITERAL OCAML_STRING
+        OBJECTIVEC_LITERAL OBJECTIVEC_STRING REASONML_LITERAL REASONML_STRING
+        OCTAVE_LITERAL OCTAVE_STRING MATLAB_LITERAL MATLAB_STRING
+        ZIG_LITERAL ZIG_STRING SAGE_LITERAL SAGE_STRING
+        JSON TOML YAML XML XML_SCHEMA_DOUBLE XML_SCHEMA_DECIMAL PROTOBUF_TEXT
+        SQL SQLITE POSTGRESQL MYSQL MONGODB
+        PERMISSIVE PERMISSIVE_INTERFACE STANDARD STANDARD_INTERFACE
+        IGNORE IGNORE_INTERFACE
+    );
+}
+
+/// Get the byte value of a setting.
+fn byte_value(setting: &Setting) -> syn::Result<u8> {
+    match &setting.value {
+        Lit::Byte(value) => Ok(value.value()),
+        value => Err(syn::Error::new(value.span(), "expected a byte literal")),
+    }
+}
+
+/// Get the bool value of a setting.
+#[cfg(feature = "format")]
+fn bool_value(setting: &Setting) -> syn::Result<bool> {
+    match &setting.value {
+        Lit::Bool(value) => Ok(value.value),
+        value => Err(syn::Error::new(value.span(), "expected a bool literal")),
+    }
+}
+
+/// Apply a setting to a number format builder.
+fn apply(builder: NumberFormatBuilder, setting: &Setting) -> syn::Result<NumberFormatBuilder> {
+    macro_rules! apply {
+        (bytes: $($byte:ident)*; bools: $($bool:ident)*) => (
+            match setting.name.to_string().as_str() {
+                $(stringify!($byte) => Ok(builder.$byte(byte_value(setting)?)),)*
+                $(stringify!($bool) => Ok(builder.$bool(bool_value(setting)?)),)*
+                _ => Err(syn::Error::new(setting.name.span(), "unknown number format setting")),
+            }
+        );
+    }
+
+    #[cfg(not(feature = "format"))]
+    return apply!(bytes: decimal_point exponent_decimal exponent_backup; bools:);
+
+    #[cfg(feature = "format")]
+    return apply!(
+        bytes: digit_separator decimal_point exponent_decimal exponent_backup;
+        bools:
+            required_integer_digits required_fraction_digits required_exponent_digits
+            required_digits no_positive_mantissa_sign required_mantissa_sign
+            no_exponent_notation no_positive_exponent_sign required_exponent_sign
+            no_special case_sensitive_special no_integer_leading_zeros
+            no_float_leading_zeros required_exponent_notation negative_parentheses
+            times_ten_exponent
+            integer_internal_digit_separator fraction_internal_digit_separator
+            exponent_internal_digit_separator integer_leading_digit_separator
+            fraction_leading_digit_separator exponent_leading_digit_separator
+            integer_trailing_digit_separator fraction_trailing_digit_separator
+            exponent_trailing_digit_separator integer_consecutive_digit_separator
+            fraction_consecutive_digit_separator exponent_consecutive_digit_separator
+            special_digit_separator digit_separator_flag_mask
+            integer_digit_separator_flag_mask fraction_digit_separator_flag_mask
+            exponent_digit_separator_flag_mask
+    );
+}
+
+/// Get the number format from the format argument.
+fn number_format(format: &Format) -> syn::Result<NumberFormat> {
+    let preset = match &format.preset {
+        Some(name) => Some(preset(name)?),
+        None => None,
+    };
+    if format.settings.is_empty() {
+        // Only a preset is given, which is always valid.
+        return Ok(preset.unwrap_or(NumberFormat::STANDARD));
+    }
+
+    let mut builder = match preset {
+        Some(preset) => preset.rebuild(),
+        None => NumberFormat::builder(),
+    };
+    for setting in format.settings.iter() {
+        builder = apply(builder, setting)?;
+    }
+    builder.build().ok_or_else(|| {
+        let span = format.settings[0].name.span();
+        syn::Error::new(span, "invalid number format")
+    })
+}
+
+// EXPAND
+// ------
+
+/// Parse an integer string with the number format.
+fn parse_integer<N>(bytes: &[u8], format: Option<NumberFormat>) -> Result<N, String>
+where
+    N: FromLexicalOptions<ParseOptions = ParseIntegerOptions>,
+{
+    let options = ParseIntegerOptions::builder()
+        .format(format)
+        .build()
+        .ok_or_else(|| "invalid number format for integers".to_string())?;
+    N::from_lexical_with_options(bytes, &options).map_err(|error| {
+        format!("{} at index {}", error.code.description(), error.index)
+    })
+}
+
+/// Parse a float string with the number format.
+fn parse_float<N>(bytes: &[u8], format: Option<NumberFormat>) -> Result<N, String>
+where
+    N: FromLexicalOptions<ParseOptions = ParseFloatOptions>,
+{
+    let options = ParseFloatOptions::builder()
+        .format(format)
+        .build()
+        .ok_or_else(|| "invalid number format for floats".to_string())?;
+    N::from_lexical_with_options(bytes, &options).map_err(|error| {
+        format!("{} at index {}", error.code.description(), error.index)
+    })
+}
+
+/// Expand to a suffixed literal, with the sign outside the literal.
+fn expand_integer<N: ToString>(value: N, ty: &str) -> String {
+    let repr = value.to_string();
+    match repr.starts_with('-') {
+        true => format!("(-{}{})", &repr[1..], ty),
+        false => format!("{}{}", repr, ty),
+    }
+}
+
+/// Expand to a suffixed float literal, or a constant for special values.
+///
+/// The representation is the shortest one that rounds to the same
+/// float, so the compiler parses the literal to the exact value.
+fn expand_float<N: Debug>(value: N, ty: &str) -> String {
+    let repr = format!("{:?}", value);
+    match repr.as_str() {
+        "NaN" => format!("::core::{}::NAN", ty),
+        "inf" => format!("::core::{}::INFINITY", ty),
+        "-inf" => format!("::core::{}::NEG_INFINITY", ty),
+        _ => expand_integer(repr, ty),
+    }
+}
+
+/// Parse the string of the input, and get the tokens of the expansion.
+fn expand(input: &Input) -> syn::Result<String> {
+    let format = match &input.format {
+        Some(format) => Some(number_format(format)?),
+        None => None,
+    };
+    let string = input.string.value();
+    let bytes = string.as_bytes();
+    let ty = input.ty.to_string();
+    let expanded = match ty.as_str() {
+        "u8" => parse_integer::<u8>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "u16" => parse_integer::<u16>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "u32" => parse_integer::<u32>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "u64" => parse_integer::<u64>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "u128" => parse_integer::<u128>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "usize" => parse_integer::<usize>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "i8" => parse_integer::<i8>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "i16" => parse_integer::<i16>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "i32" => parse_integer::<i32>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "i64" => parse_integer::<i64>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "i128" => parse_integer::<i128>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "isize" => parse_integer::<isize>(bytes, format).map(|v| expand_integer(v, &ty)),
+        "f32" => parse_float::<f32>(bytes, format).map(|v| expand_float(v, &ty)),
+        "f64" => parse_float::<f64>(bytes, format).map(|v| expand_float(v, &ty)),
+        _ => return Err(syn::Error::new(input.ty.span(), "expected a numeric type")),
+    };
+    expanded.map_err(|message| {
+        let message = format!("invalid {} literal: {}", ty, message);
+        syn::Error::new(input.string.span(), message)
+    })
+}
+
+// MACRO
+// -----
+
+/// Parse a number at compile time, and expand to its value.
+///
+/// The arguments are the string to parse, the numeric type, and an
+/// optional number format. Parse errors are reported as compile errors.
+///
+/// # Example
+///
+/// ```rust
+/// use lexical_macros::lex;
+///
+/// const MAX: u8 = lex!("255", u8);
+/// const HALF: f32 = lex!("-0.5", f32);
+/// const COMMA: f64 = lex!("1,5", f64, STANDARD { decimal_point: b',' });
+/// assert_eq!((MAX, HALF, COMMA), (255, -0.5, 1.5));
+/// ```
+#[proc_macro]
+pub fn lex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    match expand(&input) {
+        Ok(expanded) => expanded.parse().unwrap(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
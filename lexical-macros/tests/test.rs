@@ -0,0 +1,62 @@
+//! Test the proc-macro.
+
+use lexical_macros::lex;
+
+#[test]
+fn integer_test() {
+    assert_eq!(lex!("255", u8), 255u8);
+    assert_eq!(lex!("-128", i8), -128i8);
+    let value = lex!("-170141183460469231731687303715884105728", i128);
+    assert_eq!(value, -170141183460469231731687303715884105728i128);
+    let value = lex!("340282366920938463463374607431768211455", u128);
+    assert_eq!(value, 340282366920938463463374607431768211455u128);
+    assert_eq!(lex!("+15", isize,), 15isize);
+}
+
+#[test]
+fn float_test() {
+    assert_eq!(lex!("1.5e3", f64), 1500.0f64);
+    assert_eq!(lex!("0.1", f32), 0.1f32);
+    assert_eq!(lex!("-0.5", f32), -0.5f32);
+    assert!(lex!("-0.0", f64).is_sign_negative());
+    assert_eq!(lex!("1e308", f64), 1e308f64);
+    assert_eq!(lex!("2.2250738585072014e-308", f64), 2.2250738585072014e-308f64);
+    assert_eq!(lex!("inf", f64), f64::INFINITY);
+    assert_eq!(lex!("-inf", f32), f32::NEG_INFINITY);
+    assert!(lex!("NaN", f64).is_nan());
+}
+
+#[test]
+fn constant_test() {
+    const VALUE: f64 = lex!("-1.25", f64);
+    const MAX: u64 = lex!("18446744073709551615", u64);
+    assert_eq!(VALUE, -1.25);
+    assert_eq!(MAX, 18446744073709551615);
+}
+
+#[test]
+fn runtime_test() {
+    // Values halfway between floats agree with the runtime parser.
+    let value = lex!("9007199254740993", f64);
+    assert_eq!(lexical_core::parse::<f64>(b"9007199254740993"), Ok(value));
+    let value = lex!("1.00000000000000011102230246251565404236316680908203125", f64);
+    let bytes = b"1.00000000000000011102230246251565404236316680908203125";
+    assert_eq!(lexical_core::parse::<f64>(bytes), Ok(value));
+}
+
+#[cfg(feature = "format")]
+#[test]
+fn format_test() {
+    assert_eq!(lex!("1_234.5e3", f64, RUST_LITERAL), 1234500.0);
+    assert_eq!(lex!("1'000", u32, CXX14_LITERAL), 1000);
+    assert_eq!(lex!("1e5", f64, JSON), 1e5);
+    let value = lex!("1'000'000", u32, {
+        digit_separator: b'\'',
+        integer_internal_digit_separator: true,
+    });
+    assert_eq!(value, 1000000);
+    let value = lex!("1,5", f64, STANDARD { decimal_point: b',' });
+    assert_eq!(value, 1.5);
+    let value = lex!("_10", u8, RUST_LITERAL { integer_leading_digit_separator: true });
+    assert_eq!(value, 10);
+}
@@ -0,0 +1,117 @@
+//! Stack buffer for writing numbers to strings.
+
+use crate::{bytes_to_str, ToLexical, ToLexicalOptions};
+use lexical_core::POSITIONAL_BUFFER_SIZE;
+
+/// Stack buffer to write numbers to string slices.
+///
+/// Mirrors the `Buffer` types of the `itoa` and `ryu` crates, so code
+/// using them keeps the same call shape, and adds the write options.
+/// The buffer is large enough for any number, in any radix or in
+/// positional notation, and does not require an allocator.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let mut buffer = lexical::Buffer::new();
+/// assert_eq!(buffer.format(128u8), "128");
+/// # #[cfg(feature = "floats")] {
+/// assert_eq!(buffer.format(-1.5f64), "-1.5");
+///
+/// let options = lexical::WriteFloatOptions::rust_display();
+/// assert_eq!(buffer.format_with_options(1e20f64, &options), "100000000000000000000");
+/// # }
+/// # }
+/// ```
+#[derive(Copy, Clone)]
+pub struct Buffer {
+    bytes: [u8; POSITIONAL_BUFFER_SIZE],
+}
+
+impl Buffer {
+    /// Create a new buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Buffer {
+            bytes: [0u8; POSITIONAL_BUFFER_SIZE],
+        }
+    }
+
+    /// Write a number to the buffer as a decimal string.
+    ///
+    /// * `n`       - Number to convert to string.
+    #[inline]
+    pub fn format<N: ToLexical>(&mut self, n: N) -> &str {
+        bytes_to_str(lexical_core::write(n, &mut self.bytes))
+    }
+
+    /// Write a number to the buffer as a string with custom writing options.
+    ///
+    /// * `n`       - Number to convert to string.
+    /// * `options` - Options to specify number writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a custom special string in the options is not valid
+    /// UTF-8, or if the options may require more than
+    /// [`POSITIONAL_BUFFER_SIZE`] bytes, such as a large minimum number
+    /// of exponent digits.
+    ///
+    /// [`POSITIONAL_BUFFER_SIZE`]: constant.POSITIONAL_BUFFER_SIZE.html
+    #[inline]
+    pub fn format_with_options<N: ToLexicalOptions>(
+        &mut self,
+        n: N,
+        options: &N::WriteOptions,
+    ) -> &str {
+        bytes_to_str(lexical_core::write_with_options(n, &mut self.bytes, options))
+    }
+}
+
+impl Default for Buffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WriteIntegerOptions;
+    #[cfg(feature = "floats")]
+    use crate::WriteFloatOptions;
+
+    #[test]
+    fn format_test() {
+        let mut buffer = Buffer::default();
+        assert_eq!(buffer.format(0u64), "0");
+        #[cfg(feature = "integers")]
+        assert_eq!(buffer.format(i128::MIN), "-170141183460469231731687303715884105728");
+        #[cfg(feature = "floats")]
+        assert_eq!(buffer.format(f32::NAN), "NaN");
+        #[cfg(feature = "floats")]
+        assert_eq!(buffer.format(0.1f64), "0.1");
+    }
+
+    #[test]
+    fn format_with_options_test() {
+        let mut buffer = Buffer::new();
+        let options = WriteIntegerOptions::builder().positive_sign(Some(b'+')).build().unwrap();
+        assert_eq!(buffer.format_with_options(42i32, &options), "+42");
+
+        #[cfg(feature = "floats")]
+        {
+            let options = WriteFloatOptions::rust_display();
+            #[cfg(feature = "std")]
+            assert_eq!(buffer.format_with_options(5e-324f64, &options), format!("{}", 5e-324f64));
+            let options = options.rebuild().inf_string(b"Infinity").build().unwrap();
+            assert_eq!(buffer.format_with_options(f64::NEG_INFINITY, &options), "-Infinity");
+        }
+    }
+}
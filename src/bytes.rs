@@ -0,0 +1,116 @@
+//! Input types accepted by the high-level parse functions.
+
+#[cfg(feature = "alloc")]
+use crate::lib::{Box, Cow, String, Vec};
+
+/// Input that can be parsed as the bytes of a number.
+///
+/// Implemented for string and byte slices, arrays, and the owned and
+/// borrowed types wrapping them, so the high-level parse functions
+/// accept `&String`, `&Cow<str>` or `[u8; N]` without converting the
+/// input first. Implement this trait for other input types to parse
+/// them with the high-level parse functions.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use std::borrow::Cow;
+///
+/// let value: Cow<str> = Cow::Borrowed("1.5");
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical::parse::<f64, _>(&value), Ok(1.5));
+/// assert_eq!(lexical::parse::<i32, _>([b'4', b'2']), Ok(42));
+/// assert_eq!(lexical::parse::<i32, _>(String::from("42")), Ok(42));
+/// # }
+/// ```
+pub trait AsLexicalBytes {
+    /// Get the bytes to parse.
+    fn as_lexical_bytes(&self) -> &[u8];
+}
+
+impl<T: ?Sized + AsLexicalBytes> AsLexicalBytes for &T {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        (**self).as_lexical_bytes()
+    }
+}
+
+impl<T: ?Sized + AsLexicalBytes> AsLexicalBytes for &mut T {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        (**self).as_lexical_bytes()
+    }
+}
+
+impl AsLexicalBytes for str {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsLexicalBytes for [u8] {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+// Implement AsLexicalBytes for byte arrays.
+macro_rules! array_as_lexical_bytes {
+    ($($n:literal)*) => ($(
+        impl AsLexicalBytes for [u8; $n] {
+            #[inline]
+            fn as_lexical_bytes(&self) -> &[u8] {
+                self
+            }
+        }
+    )*);
+}
+
+array_as_lexical_bytes! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+}
+
+#[cfg(feature = "alloc")]
+impl AsLexicalBytes for String {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsLexicalBytes for Vec<u8> {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + AsLexicalBytes> AsLexicalBytes for Box<T> {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        (**self).as_lexical_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> AsLexicalBytes for Cow<'a, str> {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> AsLexicalBytes for Cow<'a, [u8]> {
+    #[inline]
+    fn as_lexical_bytes(&self) -> &[u8] {
+        self
+    }
+}
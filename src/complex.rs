@@ -0,0 +1,553 @@
+//! Parse and write complex numbers.
+//!
+//! Scientific data files, such as SPICE netlists and Touchstone files,
+//! store complex numbers as a real and an imaginary float. This module
+//! parses and writes [`Complex<f64>`] in the rectangular form, using the
+//! lexical float parsers and writers for each component.
+//!
+//! The supported grammar is:
+//!
+//! ```text
+//! complex     := real | imaginary | real space* sign space* coefficient suffix
+//! imaginary   := float suffix
+//! real        := float
+//! coefficient := unsigned float
+//! sign        := '+' | '-'
+//! ```
+//!
+//! The imaginary suffix defaults to `i`, and spaces around the sign of
+//! the imaginary part are only accepted, and written, if `spacing` is
+//! enabled. The coefficient of the imaginary part is required, so `1+i`
+//! is not a valid complex number.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # extern crate num_complex;
+//! # pub fn main() {
+//! use lexical::complex::{self, ComplexOptions};
+//! use num_complex::Complex;
+//!
+//! let options = ComplexOptions::new();
+//! assert_eq!(complex::parse("1.5-2i", &options), Ok(Complex::new(1.5, -2.0)));
+//! assert_eq!(complex::parse("3i", &options), Ok(Complex::new(0.0, 3.0)));
+//!
+//! let options = ComplexOptions::builder()
+//!     .imaginary_suffix(b'j')
+//!     .spacing(true)
+//!     .build()
+//!     .unwrap();
+//! let mut buffer = [0u8; complex::BUFFER_SIZE];
+//! assert_eq!(complex::to_str(Complex::new(1.5, -2.0), &mut buffer, &options), "1.5 - 2.0j");
+//! # }
+//! ```
+//!
+//! [`Complex<f64>`]: https://docs.rs/num-complex/0.4/num_complex/struct.Complex.html
+
+#[cfg(feature = "alloc")]
+use crate::lib::String;
+use crate::AsLexicalBytes;
+use lexical_core::{Error, ErrorCode, ParseFloatOptions, Result, WriteFloatOptions};
+use num_complex::Complex;
+
+/// Maximum number of bytes required to write a complex number.
+///
+/// Each component is written with the float writer, which may require
+/// up to [`POSITIONAL_BUFFER_SIZE`] bytes, plus the sign, spacing and
+/// imaginary suffix.
+///
+/// [`POSITIONAL_BUFFER_SIZE`]: ../constant.POSITIONAL_BUFFER_SIZE.html
+pub const BUFFER_SIZE: usize = 2 * lexical_core::POSITIONAL_BUFFER_SIZE + 4;
+
+// OPTIONS
+// -------
+
+/// Builder for `ComplexOptions`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ComplexOptionsBuilder {
+    /// Character after the coefficient of the imaginary part.
+    imaginary_suffix: u8,
+    /// Allow and write spaces around the sign of the imaginary part.
+    spacing: bool,
+    /// Options to parse each component.
+    parse_float_options: ParseFloatOptions,
+    /// Options to write each component.
+    write_float_options: WriteFloatOptions,
+}
+
+impl ComplexOptionsBuilder {
+    /// Create new, default options builder.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            imaginary_suffix: b'i',
+            spacing: false,
+            parse_float_options: ParseFloatOptions::new(),
+            write_float_options: WriteFloatOptions::new(),
+        }
+    }
+
+    // GETTERS
+
+    /// Get the character after the coefficient of the imaginary part.
+    #[inline]
+    pub const fn get_imaginary_suffix(&self) -> u8 {
+        self.imaginary_suffix
+    }
+
+    /// Get if spaces are allowed and written around the sign of the imaginary part.
+    #[inline]
+    pub const fn get_spacing(&self) -> bool {
+        self.spacing
+    }
+
+    /// Get the options to parse each component.
+    #[inline]
+    pub const fn get_parse_float_options(&self) -> ParseFloatOptions {
+        self.parse_float_options
+    }
+
+    /// Get the options to write each component.
+    #[inline]
+    pub const fn get_write_float_options(&self) -> WriteFloatOptions {
+        self.write_float_options
+    }
+
+    // SETTERS
+
+    /// Set the character after the coefficient of the imaginary part.
+    #[inline]
+    pub const fn imaginary_suffix(mut self, imaginary_suffix: u8) -> Self {
+        self.imaginary_suffix = imaginary_suffix;
+        self
+    }
+
+    /// Set if spaces are allowed and written around the sign of the imaginary part.
+    #[inline]
+    pub const fn spacing(mut self, spacing: bool) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the options to parse each component.
+    #[inline]
+    pub const fn parse_float_options(mut self, options: ParseFloatOptions) -> Self {
+        self.parse_float_options = options;
+        self
+    }
+
+    /// Set the options to write each component.
+    #[inline]
+    pub const fn write_float_options(mut self, options: WriteFloatOptions) -> Self {
+        self.write_float_options = options;
+        self
+    }
+
+    // BUILDERS
+
+    /// Build the ComplexOptions struct.
+    ///
+    /// Returns `None` if the imaginary suffix is not an ASCII letter,
+    /// or if it is a digit or the exponent character of the float options.
+    #[inline]
+    pub fn build(self) -> Option<ComplexOptions> {
+        let suffix = self.imaginary_suffix;
+        let parse_radix = self.parse_float_options.radix();
+        let parse_exponent = self.parse_float_options.format().exponent(parse_radix);
+        let write_radix = self.write_float_options.radix();
+        let is_digit = |radix: u32| (suffix as char).is_digit(radix);
+        if !suffix.is_ascii_alphabetic()
+            || suffix.eq_ignore_ascii_case(&parse_exponent)
+            || is_digit(parse_radix)
+            || is_digit(write_radix)
+        {
+            return None;
+        }
+
+        Some(ComplexOptions {
+            imaginary_suffix: self.imaginary_suffix,
+            spacing: self.spacing,
+            parse_float_options: self.parse_float_options,
+            write_float_options: self.write_float_options,
+        })
+    }
+}
+
+impl Default for ComplexOptionsBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options to customize parsing and writing complex numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// use lexical::complex::ComplexOptions;
+///
+/// # pub fn main() {
+/// let options = ComplexOptions::builder()
+///     .imaginary_suffix(b'j')
+///     .spacing(true)
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ComplexOptions {
+    /// Character after the coefficient of the imaginary part.
+    imaginary_suffix: u8,
+    /// Allow and write spaces around the sign of the imaginary part.
+    spacing: bool,
+    /// Options to parse each component.
+    parse_float_options: ParseFloatOptions,
+    /// Options to write each component.
+    write_float_options: WriteFloatOptions,
+}
+
+impl ComplexOptions {
+    /// Create options with default values.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            imaginary_suffix: b'i',
+            spacing: false,
+            parse_float_options: ParseFloatOptions::new(),
+            write_float_options: WriteFloatOptions::new(),
+        }
+    }
+
+    /// Get a builder to create custom options.
+    #[inline]
+    pub const fn builder() -> ComplexOptionsBuilder {
+        ComplexOptionsBuilder::new()
+    }
+
+    /// Create a builder from the existing options.
+    #[inline]
+    pub const fn rebuild(&self) -> ComplexOptionsBuilder {
+        ComplexOptionsBuilder {
+            imaginary_suffix: self.imaginary_suffix,
+            spacing: self.spacing,
+            parse_float_options: self.parse_float_options,
+            write_float_options: self.write_float_options,
+        }
+    }
+
+    /// Get the character after the coefficient of the imaginary part.
+    #[inline]
+    pub const fn imaginary_suffix(&self) -> u8 {
+        self.imaginary_suffix
+    }
+
+    /// Get if spaces are allowed and written around the sign of the imaginary part.
+    #[inline]
+    pub const fn spacing(&self) -> bool {
+        self.spacing
+    }
+
+    /// Get the options to parse each component.
+    #[inline]
+    pub const fn parse_float_options(&self) -> &ParseFloatOptions {
+        &self.parse_float_options
+    }
+
+    /// Get the options to write each component.
+    #[inline]
+    pub const fn write_float_options(&self) -> &WriteFloatOptions {
+        &self.write_float_options
+    }
+}
+
+impl Default for ComplexOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// PARSE
+// -----
+
+/// Skip spaces from the index, if spacing is enabled.
+#[inline]
+fn skip_spaces(bytes: &[u8], mut index: usize, options: &ComplexOptions) -> usize {
+    if options.spacing {
+        while bytes.get(index) == Some(&b' ') {
+            index += 1;
+        }
+    }
+    index
+}
+
+/// Parse the sign, coefficient and suffix of the imaginary part.
+///
+/// Returns the imaginary part and the number of processed bytes,
+/// or `None` if the bytes do not start with an imaginary part.
+#[inline]
+fn parse_imaginary(bytes: &[u8], options: &ComplexOptions) -> Option<(f64, usize)> {
+    let mut index = skip_spaces(bytes, 0, options);
+    let is_negative = match bytes.get(index) {
+        Some(&b'+') => false,
+        Some(&b'-') => true,
+        _ => return None,
+    };
+    index = skip_spaces(bytes, index + 1, options);
+
+    // The sign has been consumed, so the coefficient must be unsigned.
+    match bytes.get(index) {
+        Some(&b'+') | Some(&b'-') | None => return None,
+        _ => (),
+    }
+    let float_options = &options.parse_float_options;
+    let (imag, count) =
+        lexical_core::parse_partial_with_options::<f64>(&bytes[index..], float_options).ok()?;
+    index += count;
+    match bytes.get(index) == Some(&options.imaginary_suffix) {
+        true if is_negative => Some((-imag, index + 1)),
+        true => Some((imag, index + 1)),
+        false => None,
+    }
+}
+
+/// Parse a complex number, returning the number and the number of processed bytes.
+///
+/// Stops at the first byte that is not part of the complex number. If
+/// the real part is not followed by a valid imaginary part, only the
+/// real part is processed.
+///
+/// * `bytes`   - Byte slice containing a complex number.
+/// * `options` - Options to specify complex number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate num_complex;
+/// # pub fn main() {
+/// use lexical::complex::{self, ComplexOptions};
+/// use num_complex::Complex;
+///
+/// let options = ComplexOptions::new();
+/// assert_eq!(complex::parse_partial("1+2i 3", &options), Ok((Complex::new(1.0, 2.0), 4)));
+/// assert_eq!(complex::parse_partial("1+2", &options), Ok((Complex::new(1.0, 0.0), 1)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial<Bytes: AsLexicalBytes>(
+    bytes: Bytes,
+    options: &ComplexOptions,
+) -> Result<(Complex<f64>, usize)> {
+    let bytes = bytes.as_lexical_bytes();
+    let float_options = &options.parse_float_options;
+    let (value, index) = lexical_core::parse_partial_with_options::<f64>(bytes, float_options)?;
+    if bytes.get(index) == Some(&options.imaginary_suffix) {
+        return Ok((Complex::new(0.0, value), index + 1));
+    }
+    match parse_imaginary(&bytes[index..], options) {
+        Some((imag, count)) => Ok((Complex::new(value, imag), index + count)),
+        None => Ok((Complex::new(value, 0.0), index)),
+    }
+}
+
+/// Parse a complex number from a byte slice, such as `1.5-2i`.
+///
+/// * `bytes`   - Byte slice containing a complex number.
+/// * `options` - Options to specify complex number parsing.
+///
+/// Returns an error with the index of the first invalid byte.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate num_complex;
+/// # pub fn main() {
+/// use lexical::complex::{self, ComplexOptions};
+/// use lexical::ErrorCode;
+/// use num_complex::Complex;
+///
+/// let options = ComplexOptions::new();
+/// assert_eq!(complex::parse("1e3+2.5i", &options), Ok(Complex::new(1000.0, 2.5)));
+/// assert_eq!(complex::parse("1+2", &options).err().map(|e| e.code), Some(ErrorCode::InvalidDigit));
+/// # }
+/// ```
+#[inline]
+pub fn parse<Bytes: AsLexicalBytes>(bytes: Bytes, options: &ComplexOptions) -> Result<Complex<f64>> {
+    let bytes = bytes.as_lexical_bytes();
+    let (value, index) = parse_partial(bytes, options)?;
+    match index == bytes.len() {
+        true => Ok(value),
+        false => Err(Error::from((ErrorCode::InvalidDigit, index))),
+    }
+}
+
+// WRITE
+// -----
+
+/// Write a complex number to a buffer, returning the written bytes.
+#[inline]
+fn write<'a>(n: Complex<f64>, buffer: &'a mut [u8], options: &ComplexOptions) -> &'a mut [u8] {
+    assert!(buffer.len() >= BUFFER_SIZE, "Buffer is too small: may overwrite buffer, panicking!");
+    let float_options = &options.write_float_options;
+    let mut index = lexical_core::write_with_options(n.re, buffer, float_options).len();
+
+    // Write the sign separately from the coefficient, so it can be spaced.
+    let (sign, imag) = match n.im.is_sign_negative() && !n.im.is_nan() {
+        true => (b'-', -n.im),
+        false => (b'+', n.im),
+    };
+    if options.spacing {
+        buffer[index..index + 3].copy_from_slice(&[b' ', sign, b' ']);
+        index += 3;
+    } else {
+        buffer[index] = sign;
+        index += 1;
+    }
+    index += lexical_core::write_with_options(imag, &mut buffer[index..], float_options).len();
+    buffer[index] = options.imaginary_suffix;
+    &mut buffer[..index + 1]
+}
+
+/// Write a complex number to a string, in a buffer.
+///
+/// Does not require an allocator. Both the real and imaginary parts
+/// are always written.
+///
+/// * `n`       - Complex number to convert to string.
+/// * `buffer`  - Buffer to write the string to.
+/// * `options` - Options to specify complex number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`BUFFER_SIZE`], or if a custom
+/// special string in the float options is not valid UTF-8.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate num_complex;
+/// # pub fn main() {
+/// use lexical::complex::{self, ComplexOptions};
+/// use num_complex::Complex;
+///
+/// let options = ComplexOptions::new();
+/// let mut buffer = [0u8; complex::BUFFER_SIZE];
+/// assert_eq!(complex::to_str(Complex::new(1.5, -2.0), &mut buffer, &options), "1.5-2.0i");
+/// assert_eq!(complex::to_str(Complex::new(0.0, 3.0), &mut buffer, &options), "0.0+3.0i");
+/// # }
+/// ```
+///
+/// [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+#[inline]
+pub fn to_str<'a>(n: Complex<f64>, buffer: &'a mut [u8], options: &ComplexOptions) -> &'a str {
+    crate::bytes_to_str(write(n, buffer, options))
+}
+
+/// Write a complex number to a string.
+///
+/// Both the real and imaginary parts are always written.
+///
+/// * `n`       - Complex number to convert to string.
+/// * `options` - Options to specify complex number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate num_complex;
+/// # pub fn main() {
+/// use lexical::complex::{self, ComplexOptions};
+/// use num_complex::Complex;
+///
+/// let options = ComplexOptions::builder().imaginary_suffix(b'j').build().unwrap();
+/// assert_eq!(complex::to_string(Complex::new(1.0, 0.5), &options), "1.0+0.5j");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn to_string(n: Complex<f64>, options: &ComplexOptions) -> String {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    String::from_utf8_lossy(write(n, &mut buffer, options)).into_owned()
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_test() {
+        let builder = ComplexOptions::builder().imaginary_suffix(b'j').spacing(true);
+        assert_eq!(builder.get_imaginary_suffix(), b'j');
+        assert!(builder.get_spacing());
+        let options = builder.build().unwrap();
+        assert_eq!(options.imaginary_suffix(), b'j');
+        assert!(options.spacing());
+        assert_eq!(options.rebuild(), builder);
+
+        assert!(ComplexOptions::builder().imaginary_suffix(b'e').build().is_none());
+        assert!(ComplexOptions::builder().imaginary_suffix(b'E').build().is_none());
+        assert!(ComplexOptions::builder().imaginary_suffix(b'1').build().is_none());
+        assert!(ComplexOptions::builder().imaginary_suffix(b'+').build().is_none());
+    }
+
+    #[test]
+    fn parse_test() {
+        let options = ComplexOptions::new();
+        assert_eq!(parse("1", &options), Ok(Complex::new(1.0, 0.0)));
+        assert_eq!(parse("-2.5i", &options), Ok(Complex::new(0.0, -2.5)));
+        assert_eq!(parse("1+2i", &options), Ok(Complex::new(1.0, 2.0)));
+        assert_eq!(parse("-1.5e-3-2e+2i", &options), Ok(Complex::new(-0.0015, -200.0)));
+        assert_eq!(parse("1+infi", &options), Ok(Complex::new(1.0, f64::INFINITY)));
+        assert!(parse("NaN-NaNi", &options).unwrap().im.is_nan());
+
+        let err = |s: &str| parse(s, &options).err().map(|e| (e.code, e.index));
+        assert_eq!(err(""), Some((ErrorCode::Empty, 0)));
+        assert_eq!(err("1+i"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("1+-2i"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("1 + 2i"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("1+2j"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("2ii"), Some((ErrorCode::InvalidDigit, 2)));
+    }
+
+    #[test]
+    fn parse_spacing_test() {
+        let options = ComplexOptions::builder().imaginary_suffix(b'j').spacing(true).build().unwrap();
+        assert_eq!(parse("1 + 2j", &options), Ok(Complex::new(1.0, 2.0)));
+        assert_eq!(parse("1 -2j", &options), Ok(Complex::new(1.0, -2.0)));
+        assert_eq!(parse("1-2j", &options), Ok(Complex::new(1.0, -2.0)));
+        assert_eq!(parse_partial("1 + 2", &options), Ok((Complex::new(1.0, 0.0), 1)));
+        assert_eq!(parse_partial("1 + 2j, 3", &options), Ok((Complex::new(1.0, 2.0), 6)));
+    }
+
+    #[test]
+    fn write_test() {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let options = ComplexOptions::new();
+        assert_eq!(to_str(Complex::new(1.0, 2.0), &mut buffer, &options), "1.0+2.0i");
+        assert_eq!(to_str(Complex::new(-1.5, -0.0), &mut buffer, &options), "-1.5-0.0i");
+        assert_eq!(to_str(Complex::new(0.0, f64::NAN), &mut buffer, &options), "0.0+NaNi");
+        assert_eq!(to_str(Complex::new(1e300, -1e-300), &mut buffer, &options), "1e300-1e-300i");
+
+        let options = ComplexOptions::builder().imaginary_suffix(b'j').spacing(true).build().unwrap();
+        assert_eq!(to_str(Complex::new(1.0, -2.0), &mut buffer, &options), "1.0 - 2.0j");
+    }
+
+    #[test]
+    fn roundtrip_test() {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let options = ComplexOptions::builder().spacing(true).build().unwrap();
+        for &(re, im) in &[(0.0, 0.0), (1.5, -2.25), (-1e-10, 3e20), (f64::MAX, f64::MIN_POSITIVE)] {
+            let n = Complex::new(re, im);
+            assert_eq!(parse(to_str(n, &mut buffer, &options), &options), Ok(n));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Conversions for APIs generic over `TryFrom`.
+
+use crate::{Buffer, Error, FromLexical, ToLexical};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+/// Number parsed and written with lexical.
+///
+/// Implements `TryFrom<&str>`, `TryFrom<&[u8]>` and `FromStr` by
+/// parsing the complete string as a decimal number, so lexical may be
+/// used with APIs generic over `TryFrom` or `FromStr`, such as
+/// configuration and command-line parsers. Invalid numbers return the
+/// lexical [`Error`], with the index of the invalid byte. `Display`
+/// writes the number with lexical.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::{ErrorCode, Lexical};
+/// use std::convert::TryFrom;
+///
+/// # #[cfg(feature = "floats")] {
+/// let value = Lexical::<f64>::try_from("1.5e3").unwrap();
+/// assert_eq!(value.into_inner(), 1500.0);
+/// # }
+///
+/// let value = Lexical::<u8>::try_from(&b"255"[..]).unwrap();
+/// assert_eq!(value, Lexical(255));
+///
+/// let error = Lexical::<u8>::try_from("256").unwrap_err();
+/// assert_eq!(error.code, ErrorCode::Overflow);
+///
+/// let value: Lexical<i32> = "-12".parse().unwrap();
+/// assert_eq!(value.to_string(), "-12");
+/// # }
+/// ```
+///
+/// [`Error`]: struct.Error.html
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Lexical<N>(pub N);
+
+impl<N> Lexical<N> {
+    /// Get the wrapped number.
+    #[inline]
+    pub fn into_inner(self) -> N {
+        self.0
+    }
+}
+
+impl<'a, N: FromLexical> TryFrom<&'a [u8]> for Lexical<N> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Error> {
+        N::from_lexical(bytes).map(Lexical)
+    }
+}
+
+impl<'a, N: FromLexical> TryFrom<&'a str> for Lexical<N> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(string: &'a str) -> Result<Self, Error> {
+        Self::try_from(string.as_bytes())
+    }
+}
+
+impl<N: FromLexical> FromStr for Lexical<N> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Self, Error> {
+        Self::try_from(string.as_bytes())
+    }
+}
+
+impl<N: ToLexical + Copy> fmt::Display for Lexical<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(Buffer::new().format(self.0))
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorCode;
+
+    // Convert through a `TryFrom` bound, as generic APIs do.
+    fn convert<'a, T: TryFrom<&'a str>>(string: &'a str) -> Result<T, T::Error> {
+        T::try_from(string)
+    }
+
+    #[test]
+    fn try_from_test() {
+        assert_eq!(convert::<Lexical<u32>>("42"), Ok(Lexical(42)));
+        #[cfg(feature = "floats")]
+        assert_eq!(convert::<Lexical<f32>>("-0.5"), Ok(Lexical(-0.5)));
+        assert_eq!(convert::<Lexical<i8>>("-129"), Err((ErrorCode::Underflow, 3).into()));
+        assert_eq!(convert::<Lexical<u32>>("4x"), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(convert::<Lexical<u32>>(""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(Lexical::<u16>::try_from(&b"65535"[..]), Ok(Lexical(65535)));
+    }
+
+    #[test]
+    #[cfg(feature = "floats")]
+    fn from_str_test() {
+        assert_eq!("1e3".parse::<Lexical<f64>>(), Ok(Lexical(1000.0)));
+        assert_eq!("1e".parse::<Lexical<f64>>(), Err((ErrorCode::EmptyExponent, 2).into()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "floats"))]
+    fn display_test() {
+        use crate::lib::String;
+        use core::fmt::Write;
+
+        let mut string = String::new();
+        write!(string, "{} {}", Lexical(1.5f64), Lexical(-3i64)).unwrap();
+        assert_eq!(string, "1.5 -3");
+    }
+}
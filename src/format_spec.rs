@@ -0,0 +1,924 @@
+//! Runtime interpreter for a subset of Rust's format specifications.
+//!
+//! Format strings passed to `format!` must be known at compile time,
+//! so user-supplied format strings would otherwise need a hand-written
+//! interpreter over `core::fmt`. This module parses a single replacement
+//! field, such as `"{:>+10.3e}"`, and writes the number with the lexical
+//! writers.
+//!
+//! The supported grammar is:
+//!
+//! ```text
+//! spec        := '{' [ ':' [[fill] align] ['+'] [width] ['.' precision] [type] ] '}'
+//! fill        := character
+//! align       := '<' | '^' | '>'
+//! width       := integer
+//! precision   := integer
+//! type        := 'e' | 'E'
+//! ```
+//!
+//! Positional arguments, `*` or `$` widths and precisions, the `#` and
+//! `0` flags, and the non-exponent types are not supported.
+//!
+//! # Precision
+//!
+//! Like `core::fmt`, a precision rounds the exact binary value of a
+//! float, with ties rounded to even, so `1.015` is written as `1.01`
+//! with `"{:.2}"`, since it is stored as `1.01499999999999990230...`.
+//! Without a precision, the shortest digits that round-trip to the
+//! float are written.
+//!
+//! Other rounding kinds may be selected with
+//! [`with_rounding`](struct.FormatSpec.html#method.with_rounding),
+//! such as ties away from zero, which is required by many financial
+//! standards: `0.125` is written as `0.13` with `"{:.2}"`.
+//!
+//! # Formatter
+//!
+//! [`display`], [`lower_exp`] and [`upper_exp`] write a number to a
+//! `core::fmt::Formatter`, with its width, precision, fill, alignment,
+//! and `+` and `0` flags, so `Display` and `LowerExp` implementations
+//! of numeric wrappers can use the lexical writers and still support
+//! specifications such as `"{:>10.3}"`.
+//!
+//! [`display`]: fn.display.html
+//! [`lower_exp`]: fn.lower_exp.html
+//! [`upper_exp`]: fn.upper_exp.html
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # pub fn main() {
+//! # #[cfg(feature = "floats")]
+//! assert_eq!(lexical::format_spec::write(1234.5, "{:.3e}"), Ok("1.234e3".into()));
+//! assert_eq!(lexical::format_spec::write(-12, "{:*^7}"), Ok("**-12**".into()));
+//! # #[cfg(feature = "floats")]
+//! assert_eq!(lexical::format_spec::write(0.5, "{:+8.2}"), Ok("   +0.50".into()));
+//! # }
+//! ```
+
+use crate::lib::{String, Vec};
+use core::fmt;
+use lexical_core::{Error, ErrorCode, Result, RoundingKind, ToLexical};
+#[cfg(feature = "floats")]
+use lexical_core::{Float, ToDecimalDigits};
+
+// SPEC
+// ----
+
+/// Alignment of the number within the width.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// Pad after the number (`<`).
+    Left,
+    /// Pad evenly on both sides of the number (`^`).
+    Center,
+    /// Pad before the number (`>`), the default for numbers.
+    Right,
+}
+
+impl Alignment {
+    /// Get the alignment from the align character.
+    #[inline]
+    fn from_char(c: char) -> Option<Alignment> {
+        match c {
+            '<' => Some(Alignment::Left),
+            '^' => Some(Alignment::Center),
+            '>' => Some(Alignment::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Parsed format specification for a single number.
+///
+/// Parse the specification once with [`parse`](#method.parse) to write
+/// many numbers with the same format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// Character used to pad the number to the width.
+    fill: char,
+    /// Alignment of the number within the width.
+    align: Alignment,
+    /// Write a `+` sign for non-negative numbers.
+    sign_plus: bool,
+    /// Minimum number of characters to write.
+    width: usize,
+    /// Number of digits after the decimal point.
+    precision: Option<usize>,
+    /// Exponent character, if written in scientific notation.
+    exponent: Option<u8>,
+    /// Rounding kind for the precision.
+    rounding: RoundingKind,
+}
+
+impl FormatSpec {
+    /// Parse a format specification, such as `"{:>+10.3e}"`.
+    ///
+    /// Returns an error with the index of the first invalid character
+    /// in the specification, or `ErrorCode::Empty` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical;
+    /// # pub fn main() {
+    /// use lexical::{ErrorCode, format_spec::FormatSpec};
+    ///
+    /// # #[cfg(feature = "floats")] {
+    /// let spec = FormatSpec::parse("{:.2}").unwrap();
+    /// assert_eq!(spec.write(1.23456), "1.23");
+    /// assert_eq!(spec.write(2.5f32), "2.50");
+    /// # }
+    ///
+    /// let err = FormatSpec::parse("{:.2f}").err().unwrap();
+    /// assert_eq!(err.code, ErrorCode::InvalidDigit);
+    /// assert_eq!(err.index, 4);
+    /// # }
+    /// ```
+    pub fn parse(spec: &str) -> Result<FormatSpec> {
+        let mut result = FormatSpec {
+            fill: ' ',
+            align: Alignment::Right,
+            sign_plus: false,
+            width: 0,
+            precision: None,
+            exponent: None,
+            rounding: RoundingKind::NearestTieEven,
+        };
+
+        if spec.is_empty() {
+            return Err(Error::from((ErrorCode::Empty, 0)));
+        }
+        let mut iter = spec.char_indices().peekable();
+        expect(&mut iter, '{', spec.len())?;
+        if iter.peek().map(|&(_, c)| c) == Some(':') {
+            iter.next();
+
+            // Fill and alignment, where the fill requires an alignment.
+            let mut lookahead = iter.clone();
+            let first = lookahead.next().map(|(_, c)| c);
+            let second = lookahead.next().map(|(_, c)| c);
+            if let (Some(fill), Some(align)) = (first, second.and_then(Alignment::from_char)) {
+                result.fill = fill;
+                result.align = align;
+                iter = lookahead;
+            } else if let Some(align) = first.and_then(Alignment::from_char) {
+                result.align = align;
+                iter.next();
+            }
+
+            // Sign.
+            if iter.peek().map(|&(_, c)| c) == Some('+') {
+                result.sign_plus = true;
+                iter.next();
+            }
+
+            // Width and precision.
+            if let Some(width) = parse_integer(&mut iter)? {
+                result.width = width;
+            }
+            if let Some(&(index, '.')) = iter.peek() {
+                iter.next();
+                match parse_integer(&mut iter)? {
+                    Some(precision) => result.precision = Some(precision),
+                    None => return Err(Error::from((ErrorCode::EmptyFraction, index + 1))),
+                }
+            }
+
+            // Type.
+            match iter.peek() {
+                Some(&(_, 'e')) => result.exponent = Some(b'e'),
+                Some(&(_, 'E')) => result.exponent = Some(b'E'),
+                _ => (),
+            }
+            if result.exponent.is_some() {
+                iter.next();
+            }
+        }
+        expect(&mut iter, '}', spec.len())?;
+        match iter.next() {
+            Some((index, _)) => Err(Error::from((ErrorCode::InvalidDigit, index))),
+            None => Ok(result),
+        }
+    }
+
+    /// Get the character used to pad the number to the width.
+    #[inline]
+    pub const fn fill(&self) -> char {
+        self.fill
+    }
+
+    /// Get the alignment of the number within the width.
+    #[inline]
+    pub const fn align(&self) -> Alignment {
+        self.align
+    }
+
+    /// Get if a `+` sign is written for non-negative numbers.
+    #[inline]
+    pub const fn sign_plus(&self) -> bool {
+        self.sign_plus
+    }
+
+    /// Get the minimum number of characters to write.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the number of digits after the decimal point.
+    #[inline]
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the exponent character, if written in scientific notation.
+    #[inline]
+    pub const fn exponent(&self) -> Option<u8> {
+        self.exponent
+    }
+
+    /// Get the rounding kind for the precision.
+    #[inline]
+    pub const fn rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Set the rounding kind for the precision.
+    ///
+    /// Defaults to `RoundingKind::NearestTieEven`. The exact value of
+    /// floats is rounded, so directed rounding kinds write bounds on
+    /// the float.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical;
+    /// # pub fn main() {
+    /// use lexical::{RoundingKind, format_spec::FormatSpec};
+    ///
+    /// # #[cfg(feature = "floats")] {
+    /// let spec = FormatSpec::parse("{:.2}").unwrap();
+    /// assert_eq!(spec.write(0.125), "0.12");
+    /// let spec = spec.with_rounding(RoundingKind::NearestTieAwayZero);
+    /// assert_eq!(spec.write(0.125), "0.13");
+    /// assert_eq!(spec.write(-0.125), "-0.13");
+    /// # }
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn with_rounding(mut self, rounding: RoundingKind) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Get the rounding kind for the magnitude of a number.
+    #[inline]
+    fn magnitude_rounding(&self, is_negative: bool) -> RoundingKind {
+        magnitude_rounding(self.rounding, is_negative)
+    }
+
+    /// Write a number to a string using the format specification.
+    #[inline]
+    pub fn write<N: ToFormatted>(&self, n: N) -> String {
+        n.to_formatted(self)
+    }
+
+    /// Write the sign and the formatted magnitude, padded to the width.
+    fn pad(&self, is_negative: bool, body: &[u8]) -> String {
+        let sign = match (is_negative, self.sign_plus) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+        // The body is ASCII, other than special strings, which are valid UTF-8.
+        let body = String::from_utf8_lossy(body);
+        let count = sign.len() + body.chars().count();
+        let padding = self.width.saturating_sub(count);
+        let (before, after) = match self.align {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        };
+
+        let mut string = String::with_capacity(body.len() + sign.len() + padding);
+        string.extend((0..before).map(|_| self.fill));
+        string.push_str(sign);
+        string.push_str(&body);
+        string.extend((0..after).map(|_| self.fill));
+        string
+    }
+}
+
+/// Consume the expected character, or return an error at its index.
+#[inline]
+fn expect<I>(iter: &mut core::iter::Peekable<I>, expected: char, end: usize) -> Result<()>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    match iter.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        Some((index, _)) => Err(Error::from((ErrorCode::InvalidDigit, index))),
+        None => Err(Error::from((ErrorCode::InvalidDigit, end))),
+    }
+}
+
+/// Parse an optional decimal integer, for the width or precision.
+#[inline]
+fn parse_integer<I>(iter: &mut core::iter::Peekable<I>) -> Result<Option<usize>>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    let mut value: Option<usize> = None;
+    while let Some(&(index, c)) = iter.peek() {
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit as usize,
+            None => break,
+        };
+        value = value
+            .unwrap_or(0)
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(digit))
+            .map(Some)
+            .ok_or_else(|| Error::from((ErrorCode::Overflow, index)))?;
+        iter.next();
+    }
+    Ok(value)
+}
+
+// DIGITS
+// ------
+
+/// Get the rounding kind for the magnitude of a number, from its sign.
+///
+/// Directed rounding kinds become `Upward` or `Downward`, which
+/// increase or decrease the magnitude of the number.
+pub(crate) fn magnitude_rounding(rounding: RoundingKind, is_negative: bool) -> RoundingKind {
+    match rounding {
+        RoundingKind::TowardPositiveInfinity if is_negative => RoundingKind::Downward,
+        RoundingKind::TowardPositiveInfinity => RoundingKind::Upward,
+        RoundingKind::TowardNegativeInfinity if is_negative => RoundingKind::Upward,
+        RoundingKind::TowardNegativeInfinity => RoundingKind::Downward,
+        RoundingKind::TowardZero => RoundingKind::Downward,
+        _ => rounding,
+    }
+}
+
+/// Round the digits to `count` significant digits.
+///
+/// The digits represent `d.ddd * 10^exponent`, and the rounding kind
+/// applies to their magnitude. If every digit is rounded away, the
+/// digits are cleared and the exponent is reset.
+pub(crate) fn round(digits: &mut Vec<u8>, exponent: &mut i32, count: isize, rounding: RoundingKind) {
+    if count >= digits.len() as isize {
+        return;
+    } else if count < 0 {
+        digits.clear();
+        *exponent = 0;
+        return;
+    }
+
+    let count = count as usize;
+    let is_even = count == 0 || (digits[count - 1] - b'0') & 1 == 0;
+    let is_above_half = digits[count + 1..].iter().any(|&c| c != b'0');
+    let round_up = match (rounding, digits[count]) {
+        (RoundingKind::Upward, _) => digits[count..].iter().any(|&c| c != b'0'),
+        (RoundingKind::Downward, _) => false,
+        (_, b'6'..=b'9') => true,
+        (RoundingKind::NearestTieAwayZero, b'5') => true,
+        (_, b'5') => is_above_half || !is_even,
+        _ => false,
+    };
+    digits.truncate(count);
+
+    if round_up {
+        // Propagate the carry, overflowing to a new leading digit.
+        while let Some(&b'9') = digits.last() {
+            digits.pop();
+        }
+        match digits.last_mut() {
+            Some(c) => *c += 1,
+            None => {
+                digits.push(b'1');
+                *exponent += 1;
+            },
+        }
+    } else if digits.is_empty() {
+        *exponent = 0;
+    }
+}
+
+/// Write the digits in scientific notation, like `1.234e-5`.
+fn write_scientific(
+    spec: &FormatSpec,
+    digits: &mut Vec<u8>,
+    mut exponent: i32,
+    rounding: RoundingKind,
+    body: &mut Vec<u8>,
+) {
+    // Count is always positive, so the digits are never cleared.
+    match spec.precision {
+        Some(precision) => {
+            round(digits, &mut exponent, precision as isize + 1, rounding);
+            digits.resize(precision + 1, b'0');
+        },
+        None => {
+            while digits.len() > 1 && digits.last() == Some(&b'0') {
+                digits.pop();
+            }
+        },
+    }
+
+    body.push(digits[0]);
+    if digits.len() > 1 {
+        body.push(b'.');
+        body.extend_from_slice(&digits[1..]);
+    }
+    body.push(spec.exponent.unwrap_or(b'e'));
+    let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    body.extend_from_slice(lexical_core::write(exponent, &mut buffer));
+}
+
+/// Write the digits in positional notation, like `0.00001234`.
+#[cfg(feature = "floats")]
+pub(crate) fn write_positional(
+    digits: &mut Vec<u8>,
+    mut exponent: i32,
+    precision: Option<usize>,
+    rounding: RoundingKind,
+    body: &mut Vec<u8>,
+) {
+    if let Some(precision) = precision {
+        let count = exponent as isize + 1 + precision as isize;
+        round(digits, &mut exponent, count, rounding);
+    }
+    let fraction = precision.unwrap_or_else(|| (digits.len() as isize - 1 - exponent as isize).max(0) as usize);
+    let digit = |index: isize| match index >= 0 {
+        true => *digits.get(index as usize).unwrap_or(&b'0'),
+        false => b'0',
+    };
+
+    match exponent < 0 {
+        true => body.push(b'0'),
+        false => body.extend((0..=exponent as isize).map(digit)),
+    }
+    if fraction > 0 {
+        body.push(b'.');
+        body.extend((1..=fraction as isize).map(|index| digit(exponent as isize + index)));
+    }
+}
+
+// TO FORMATTED
+// ------------
+
+/// Trait for numbers that can be written with a runtime format specification.
+pub trait ToFormatted: ToLexical {
+    /// Write the number to a string using the format specification.
+    fn to_formatted(self, spec: &FormatSpec) -> String;
+}
+
+/// Write an integer, from the bytes written by the integer writer.
+fn integer_to_formatted(spec: &FormatSpec, bytes: &[u8]) -> String {
+    let (is_negative, bytes) = match bytes.first() {
+        Some(&b'-') => (true, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let mut body = Vec::with_capacity(bytes.len() + spec.precision.unwrap_or(0) + 8);
+    match spec.exponent {
+        Some(_) => {
+            let mut digits = bytes.to_vec();
+            let rounding = spec.magnitude_rounding(is_negative);
+            write_scientific(spec, &mut digits, bytes.len() as i32 - 1, rounding, &mut body);
+        },
+        // Precision is ignored for integers in positional notation.
+        None => body.extend_from_slice(bytes),
+    }
+    spec.pad(is_negative, &body)
+}
+
+/// Get the exact decimal digits of a finite float and the exponent of
+/// the first digit, without leading or trailing zeros.
+///
+/// A precision rounds these digits, rather than the shortest digits,
+/// so the output is the correctly rounded exact value, like `core::fmt`.
+#[cfg(feature = "floats")]
+pub(crate) fn exact_digits<F>(float: F) -> (Vec<u8>, i32)
+where
+    F: ToDecimalDigits + Float,
+{
+    let mut buffer = [0u8; lexical_core::EXACT_BUFFER_SIZE];
+    let bytes = lexical_core::write_exact(float, &mut buffer);
+    let bytes = match bytes.first() {
+        Some(&b'-') => &bytes[1..],
+        _ => &bytes[..],
+    };
+    let integer = bytes.iter().position(|&c| c == b'.').unwrap_or(bytes.len());
+    let mut digits: Vec<u8> = bytes.iter().cloned().filter(|&c| c != b'.').collect();
+    let leading = match digits.iter().position(|&c| c != b'0') {
+        Some(leading) => leading,
+        None => return (crate::lib::vec![b'0'], 0),
+    };
+    let trailing = digits.iter().rposition(|&c| c != b'0').unwrap() + 1;
+    digits.truncate(trailing);
+    digits.drain(..leading);
+    (digits, integer as i32 - leading as i32 - 1)
+}
+
+/// Write a float, from its decimal digits, or a special value.
+#[cfg(feature = "floats")]
+fn float_to_formatted<F>(spec: &FormatSpec, float: F, is_negative: bool, is_nan: bool) -> String
+where
+    F: ToDecimalDigits + Float,
+{
+    match float.to_decimal_digits() {
+        Some(decimal) => {
+            let (mut digits, exponent) = match spec.precision {
+                Some(_) => exact_digits(float),
+                None => (decimal.digits().to_vec(), decimal.exponent()),
+            };
+            let mut body = Vec::with_capacity(digits.len() + spec.precision.unwrap_or(0) + 8);
+            let rounding = spec.magnitude_rounding(is_negative);
+            match spec.exponent {
+                Some(_) => write_scientific(spec, &mut digits, exponent, rounding, &mut body),
+                None => write_positional(&mut digits, exponent, spec.precision, rounding, &mut body),
+            }
+            spec.pad(is_negative, &body)
+        },
+        // NaN is never signed.
+        None if is_nan => FormatSpec {
+            sign_plus: false,
+            ..*spec
+        }
+        .pad(false, b"NaN"),
+        None => spec.pad(is_negative, b"inf"),
+    }
+}
+
+// Implement ToFormatted for integer types.
+macro_rules! integer_to_formatted {
+    ($($t:ty)*) => ($(
+        impl ToFormatted for $t {
+            #[inline]
+            fn to_formatted(self, spec: &FormatSpec) -> String {
+                let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+                integer_to_formatted(spec, lexical_core::write(self, &mut buffer))
+            }
+        }
+    )*);
+}
+
+integer_to_formatted! { u8 u16 u32 u64 i8 i16 i32 i64 }
+
+#[cfg(feature = "integers")]
+integer_to_formatted! { u128 usize i128 isize }
+
+// Implement ToFormatted for float types.
+#[cfg(feature = "floats")]
+macro_rules! float_to_formatted {
+    ($($t:ty)*) => ($(
+        impl ToFormatted for $t {
+            #[inline]
+            fn to_formatted(self, spec: &FormatSpec) -> String {
+                float_to_formatted(spec, self, self.is_sign_negative(), self.is_nan())
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "floats")]
+float_to_formatted! { f32 f64 }
+
+// API
+// ---
+
+/// Write a number to a string using a runtime format specification.
+///
+/// * `n`       - Number to convert to string.
+/// * `spec`    - Format specification, such as `"{:>+10.3e}"`.
+///
+/// Returns an error with the index of the first invalid character
+/// in the specification, or `ErrorCode::Empty` if it is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::format_spec;
+///
+/// assert_eq!(format_spec::write(5, "{}"), Ok("5".into()));
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(format_spec::write(1e-7, "{}"), Ok("0.0000001".into()));
+/// assert_eq!(format_spec::write(1250u32, "{:.1E}"), Ok("1.2E3".into()));
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(format_spec::write(-1.5f32, "{:<8e}|"), Err(lexical::Error::from((lexical::ErrorCode::InvalidDigit, 6))));
+/// # }
+/// ```
+#[inline]
+pub fn write<N: ToFormatted>(n: N, spec: &str) -> Result<String> {
+    Ok(FormatSpec::parse(spec)?.write(n))
+}
+
+/// Write a number to a formatter, with the exponent character of the type.
+fn write_to_formatter<N: ToFormatted>(
+    n: N,
+    f: &mut fmt::Formatter,
+    exponent: Option<u8>,
+) -> fmt::Result {
+    // Write the magnitude with the precision, and pad it with the formatter.
+    let spec = FormatSpec {
+        fill: ' ',
+        align: Alignment::Right,
+        sign_plus: false,
+        width: 0,
+        precision: f.precision(),
+        exponent,
+        rounding: RoundingKind::NearestTieEven,
+    };
+    let string = spec.write(n);
+    match string.as_bytes().first() {
+        // NaN is never signed, so it is padded without the sign flags.
+        Some(b'N') => {
+            let (fill, align) = match (f.sign_aware_zero_pad(), f.align()) {
+                (true, _) => ('0', Alignment::Right),
+                (false, Some(fmt::Alignment::Left)) => (f.fill(), Alignment::Left),
+                (false, Some(fmt::Alignment::Center)) => (f.fill(), Alignment::Center),
+                (false, _) => (f.fill(), Alignment::Right),
+            };
+            let spec = FormatSpec {
+                fill,
+                align,
+                width: f.width().unwrap_or(0),
+                ..spec
+            };
+            f.write_str(&spec.pad(false, string.as_bytes()))
+        },
+        Some(b'-') => f.pad_integral(false, "", &string[1..]),
+        _ => f.pad_integral(true, "", &string),
+    }
+}
+
+/// Write a number to a formatter, like `Display`.
+///
+/// The precision is the number of digits after the decimal point,
+/// and is ignored for integers, like `core::fmt`.
+///
+/// * `n`       - Number to write.
+/// * `f`       - Formatter with the requested width, precision and flags.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// use std::fmt;
+///
+/// struct Meters(f64);
+///
+/// impl fmt::Display for Meters {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         lexical::format_spec::display(self.0, f)
+///     }
+/// }
+///
+/// assert_eq!(format!("{}", Meters(1.5)), "1.5");
+/// assert_eq!(format!("{:>10.3}", Meters(1.5)), "     1.500");
+/// assert_eq!(format!("{:+08.2}", Meters(-0.125)), "-0000.12");
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn display<N: ToFormatted>(n: N, f: &mut fmt::Formatter) -> fmt::Result {
+    write_to_formatter(n, f, None)
+}
+
+/// Write a number to a formatter in scientific notation, like `LowerExp`.
+///
+/// * `n`       - Number to write.
+/// * `f`       - Formatter with the requested width, precision and flags.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// use std::fmt;
+///
+/// struct Meters(f64);
+///
+/// impl fmt::LowerExp for Meters {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         lexical::format_spec::lower_exp(self.0, f)
+///     }
+/// }
+///
+/// assert_eq!(format!("{:e}", Meters(1234.5)), "1.2345e3");
+/// assert_eq!(format!("{:<10.1e}|", Meters(1234.5)), "1.2e3     |");
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn lower_exp<N: ToFormatted>(n: N, f: &mut fmt::Formatter) -> fmt::Result {
+    write_to_formatter(n, f, Some(b'e'))
+}
+
+/// Write a number to a formatter in scientific notation, like `UpperExp`.
+///
+/// * `n`       - Number to write.
+/// * `f`       - Formatter with the requested width, precision and flags.
+#[inline]
+pub fn upper_exp<N: ToFormatted>(n: N, f: &mut fmt::Formatter) -> fmt::Result {
+    write_to_formatter(n, f, Some(b'E'))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check the output matches core::fmt for the spec.
+    macro_rules! check {
+        ($n:expr, $spec:literal) => {
+            assert_eq!(write($n, $spec).unwrap(), format!($spec, $n), "spec {}", $spec);
+        };
+    }
+
+    #[test]
+    fn parse_test() {
+        let spec = FormatSpec::parse("{:_^+12.3E}").unwrap();
+        assert_eq!(spec.fill(), '_');
+        assert_eq!(spec.align(), Alignment::Center);
+        assert!(spec.sign_plus());
+        assert_eq!(spec.width(), 12);
+        assert_eq!(spec.precision(), Some(3));
+        assert_eq!(spec.exponent(), Some(b'E'));
+
+        let spec = FormatSpec::parse("{:<}").unwrap();
+        assert_eq!(spec.fill(), ' ');
+        assert_eq!(spec.align(), Alignment::Left);
+
+        let spec = FormatSpec::parse("{:>>}").unwrap();
+        assert_eq!(spec.fill(), '>');
+        assert_eq!(spec.align(), Alignment::Right);
+
+        let spec = FormatSpec::parse("{:é<4}").unwrap();
+        assert_eq!(spec.fill(), 'é');
+        assert_eq!(spec.write(1), "1ééé");
+
+        assert_eq!(FormatSpec::parse("{}"), FormatSpec::parse("{:}"));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = |spec: &str| FormatSpec::parse(spec).err().map(|e| (e.code, e.index));
+        assert_eq!(err(""), Some((ErrorCode::Empty, 0)));
+        assert_eq!(err("{"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err(":}"), Some((ErrorCode::InvalidDigit, 0)));
+        assert_eq!(err("{0}"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("{:.}"), Some((ErrorCode::EmptyFraction, 3)));
+        assert_eq!(err("{:x}"), Some((ErrorCode::InvalidDigit, 2)));
+        assert_eq!(err("{:e}}"), Some((ErrorCode::InvalidDigit, 4)));
+        assert_eq!(err("{:99999999999999999999}"), Some((ErrorCode::Overflow, 21)));
+    }
+
+    #[test]
+    fn integer_test() {
+        check!(0, "{}");
+        check!(-1i32, "{:<6}");
+        check!(-12i8, "{:*^7}");
+        check!(5u8, "{:+.3}");
+        #[cfg(feature = "integers")]
+        check!(u128::MAX, "{:e}");
+        check!(i64::MIN, "{:.3E}");
+        check!(0u32, "{:e}");
+        check!(1200u32, "{:e}");
+        check!(1235u32, "{:.2e}");
+        check!(1225u32, "{:.2e}");
+        check!(1250u32, "{:.1e}");
+        check!(15u8, "{:.0e}");
+        check!(99u8, "{:.0e}");
+        check!(7u16, "{:.4e}");
+    }
+
+    #[test]
+    #[cfg(feature = "floats")]
+    fn float_test() {
+        check!(1.0f64, "{}");
+        check!(-0.0f64, "{}");
+        check!(0.0f64, "{:+}");
+        check!(1e-7f64, "{}");
+        check!(1e21f64, "{}");
+        check!(1234.5f64, "{:e}");
+        check!(1e300f64, "{:E}");
+        // Grisu2 does not generate the shortest digits for f32.
+        #[cfg(any(feature = "grisu3", feature = "ryu"))]
+        check!(-1e-10f32, "{:e}");
+        check!(0.0f64, "{:.2e}");
+        check!(1234.5f64, "{:+.1e}");
+        check!(9.5f64, "{:.0e}");
+        check!(2.71875f64, "{:*^9.2}");
+        check!(99.999f64, "{:.2}");
+        check!(0.5f64, "{:.0}");
+        check!(1.5f64, "{:.0}");
+        check!(-0.04f64, "{:.1}");
+        check!(0.001f64, "{:.1}");
+        check!(0.25f32, "{:>+10.4}");
+        check!(123.456f64, "{:.0}");
+        check!(1.015f64, "{:.2}");
+        check!(2.675f64, "{:.2}");
+        check!(1.0f64 / 3.0, "{:.20}");
+        check!(0.1f64, "{:.30}");
+        check!(2.675f64, "{:.2e}");
+        check!(1e23f64, "{:.1e}");
+        check!(0.3f32, "{:.10}");
+    }
+
+    #[test]
+    #[cfg(feature = "floats")]
+    fn rounding_test() {
+        let write = |n: f64, spec: &str, rounding| {
+            FormatSpec::parse(spec).unwrap().with_rounding(rounding).write(n)
+        };
+        assert_eq!(write(0.125, "{:.2}", RoundingKind::NearestTieEven), "0.12");
+        assert_eq!(write(0.125, "{:.2}", RoundingKind::NearestTieAwayZero), "0.13");
+        assert_eq!(write(-2.5, "{:.0}", RoundingKind::NearestTieAwayZero), "-3");
+        assert_eq!(write(0.124, "{:.2}", RoundingKind::NearestTieAwayZero), "0.12");
+        assert_eq!(write(99.5, "{:.0}", RoundingKind::NearestTieAwayZero), "100");
+        assert_eq!(write(9.95, "{:.1}", RoundingKind::NearestTieAwayZero), "9.9");
+        assert_eq!(write(1250.0, "{:.1e}", RoundingKind::NearestTieAwayZero), "1.3e3");
+        assert_eq!(write(1.21, "{:.1}", RoundingKind::TowardPositiveInfinity), "1.3");
+        assert_eq!(write(-1.29, "{:.1}", RoundingKind::TowardPositiveInfinity), "-1.2");
+        assert_eq!(write(-1.21, "{:.1}", RoundingKind::TowardNegativeInfinity), "-1.3");
+        assert_eq!(write(1.29, "{:.1}", RoundingKind::TowardZero), "1.2");
+        assert_eq!(write(1.2, "{:.1}", RoundingKind::TowardPositiveInfinity), "1.2");
+
+        let spec = FormatSpec::parse("{:.1e}").unwrap().with_rounding(RoundingKind::NearestTieAwayZero);
+        assert_eq!(spec.rounding(), RoundingKind::NearestTieAwayZero);
+        assert_eq!(spec.write(-1250i32), "-1.3e3");
+        assert_eq!(FormatSpec::parse("{}").unwrap().rounding(), RoundingKind::NearestTieEven);
+    }
+
+    #[test]
+    #[cfg(feature = "floats")]
+    fn special_test() {
+        check!(f64::NAN, "{:+}");
+        check!(f64::NAN, "{:.3}");
+        check!(f64::INFINITY, "{:+}");
+        check!(f64::NEG_INFINITY, "{:6}");
+        check!(f32::INFINITY, "{:-<6e}");
+    }
+
+    // Wrapper writing the number with the formatter helpers.
+    struct Lexical<T>(T);
+
+    impl<T: ToFormatted + Copy> fmt::Display for Lexical<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            display(self.0, f)
+        }
+    }
+
+    impl<T: ToFormatted + Copy> fmt::LowerExp for Lexical<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            lower_exp(self.0, f)
+        }
+    }
+
+    impl<T: ToFormatted + Copy> fmt::UpperExp for Lexical<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            upper_exp(self.0, f)
+        }
+    }
+
+    // Check the formatter helpers match core::fmt for the spec.
+    macro_rules! check_formatter {
+        ($n:expr, $spec:literal) => {
+            assert_eq!(format!($spec, Lexical($n)), format!($spec, $n), "spec {}", $spec);
+        };
+    }
+
+    #[test]
+    fn formatter_test() {
+        check_formatter!(-12i8, "{:*^7}");
+        check_formatter!(42u32, "{:+08}");
+        check_formatter!(-42i64, "{:08.3}");
+        #[cfg(feature = "floats")]
+        {
+            check_formatter!(1234.5f64, "{:+.1e}");
+            check_formatter!(1e300f64, "{:>12E}");
+            check_formatter!(2.71875f64, "{:*^9.2}");
+            check_formatter!(-0.25f32, "{:<+10.4}");
+            check_formatter!(-0.5f64, "{:010.3}");
+            check_formatter!(0.0f64, "{:+}");
+            check_formatter!(f64::NAN, "{:+08}");
+            check_formatter!(f64::NAN, "{:^7.1}");
+            check_formatter!(f64::NEG_INFINITY, "{:08}");
+            check_formatter!(f32::INFINITY, "{:-<6e}");
+        }
+    }
+}
@@ -19,13 +19,17 @@
 //! extern crate lexical;
 //!
 //! // Number to string
+//! # #[cfg(feature = "floats")]
 //! lexical::to_string(3.0);            // "3.0", always has a fraction suffix.
 //! lexical::to_string(3);              // "3"
 //!
 //! // String to number.
 //! let i: i32 = lexical::parse("3").unwrap();      // 3, auto-type deduction.
+//! # #[cfg(feature = "floats")]
 //! let f: f32 = lexical::parse("3.5").unwrap();    // 3.5
+//! # #[cfg(feature = "floats")]
 //! let d = lexical::parse::<f64, _>("3.5");        // Ok(3.5), successful parse.
+//! # #[cfg(feature = "floats")]
 //! let d = lexical::parse::<f64, _>("3a");         // Err(Error(_)), failed to parse.
 //! ```
 //!
@@ -35,12 +39,86 @@
 //! - [`to_string`]
 //! - [`to_string_with_options`]
 //!
+//! **To String, Without An Allocator**
+//! - [`to_str`]
+//! - [`to_str_with_options`]
+//! - [`Buffer`]
+//!
 //! **From String**
 //! - [`parse`]
 //! - [`parse_with_options`]
 //! - [`parse_partial`]
 //! - [`parse_partial_with_options`]
 //!
+//! **Through `TryFrom` And `FromStr`**
+//! - [`Lexical`]
+//!
+//! **Runtime Format Specifications**
+//! - [`format_spec::write`]
+//! - [`format_spec::display`], to implement `Display` with a `Formatter`.
+//! - [`printf::write`], with the `printf` feature.
+//!
+//! **From OS Strings, With The `std` Feature**
+//! - [`os_str::parse`]
+//! - [`os_str::parse_with_options`]
+//! - [`os_str::parse_partial`]
+//! - [`os_str::parse_partial_with_options`]
+//!
+//! **Complex Numbers, With The `complex` Feature**
+//! - [`complex::parse`]
+//! - [`complex::parse_partial`]
+//! - [`complex::to_str`]
+//! - [`complex::to_string`]
+//!
+//! **Embedded Writers, With The `ufmt` Feature**
+//! - [`ufmt::write`]
+//! - [`ufmt::write_with_options`]
+//!
+//! # Generic Programming
+//!
+//! The [`Number`], [`Integer`] and [`Float`] traits, implemented for the
+//! primitive integers and floats, allow functions generic over the types
+//! lexical parses and writes. They provide the buffer sizes, such as
+//! `Number::FORMATTED_SIZE_DECIMAL`, constants such as `Integer::ZERO`,
+//! and checked, wrapping and saturating arithmetic.
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # pub fn main() {
+//! use lexical::{FromLexical, Integer};
+//!
+//! // Sum integer fields, returning `None` for invalid fields or on overflow.
+//! fn sum<N: Integer + FromLexical>(fields: &[&str]) -> Option<N> {
+//!     let mut sum = N::ZERO;
+//!     for field in fields {
+//!         sum = sum.checked_add(lexical::parse(*field).ok()?)?;
+//!     }
+//!     Some(sum)
+//! }
+//!
+//! assert_eq!(sum::<u8>(&["100", "50"]), Some(150));
+//! assert_eq!(sum::<u8>(&["200", "100"]), None);
+//! assert_eq!(sum::<u8>(&["1x"]), None);
+//! # }
+//! ```
+//!
+//! # No Allocator
+//!
+//! The `String` APIs, including [`to_string`] and the runtime format
+//! specifications, require the `alloc` feature, which is enabled by `std`.
+//! Without an allocator, disable the default features and write numbers
+//! with [`to_str`] to a stack buffer of at least [`BUFFER_SIZE`] bytes.
+//! The parse functions never allocate.
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # pub fn main() {
+//! let mut buffer = [0u8; lexical::BUFFER_SIZE];
+//! # #[cfg(feature = "floats")]
+//! assert_eq!(lexical::to_str(3.5, &mut buffer), "3.5");
+//! # }
+//! ```
+//!
 //! # Configuration API
 //!
 //! Lexical provides two main levels of configuration:
@@ -130,11 +208,37 @@
 //!
 //! [`to_string`]: fn.to_string.html
 //! [`to_string_with_options`]: fn.to_string_with_options.html
+//! [`to_str`]: fn.to_str.html
+//! [`to_str_with_options`]: fn.to_str_with_options.html
+//! [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+//! [`Buffer`]: struct.Buffer.html
 //!
 //! [`parse`]: fn.parse.html
 //! [`parse_with_options`]: fn.parse_with_options.html
 //! [`parse_partial`]: fn.parse_partial.html
 //! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+//! [`Lexical`]: struct.Lexical.html
+//!
+//! [`format_spec::write`]: format_spec/fn.write.html
+//! [`format_spec::display`]: format_spec/fn.display.html
+//! [`printf::write`]: printf/fn.write.html
+//!
+//! [`os_str::parse`]: os_str/fn.parse.html
+//! [`os_str::parse_with_options`]: os_str/fn.parse_with_options.html
+//! [`os_str::parse_partial`]: os_str/fn.parse_partial.html
+//! [`os_str::parse_partial_with_options`]: os_str/fn.parse_partial_with_options.html
+//!
+//! [`complex::parse`]: complex/fn.parse.html
+//! [`complex::parse_partial`]: complex/fn.parse_partial.html
+//! [`complex::to_str`]: complex/fn.to_str.html
+//! [`complex::to_string`]: complex/fn.to_string.html
+//!
+//! [`ufmt::write`]: ufmt/fn.write.html
+//! [`ufmt::write_with_options`]: ufmt/fn.write_with_options.html
+//!
+//! [`Number`]: trait.Number.html
+//! [`Integer`]: trait.Integer.html
+//! [`Float`]: trait.Float.html
 //!
 //! [`NumberFormat`]: struct.NumberFormat.html
 //! [`ParseFloatOptions`]: struct.ParseFloatOptions.html
@@ -146,6 +250,8 @@
 
 // Require intrinsics and alloc in a no_std context.
 #![cfg_attr(not(feature = "std"), no_std)]
+// All unsafe code is in lexical-core.
+#![forbid(unsafe_code)]
 
 // EXTERNAL
 
@@ -156,36 +262,64 @@ extern crate lexical_core;
 // CONFIG
 
 // Need an allocator for String/Vec.
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 
 /// Facade around the core features for name mangling.
+#[cfg(feature = "alloc")]
 pub(crate) mod lib {
     cfg_if! {
     if #[cfg(feature = "std")] {
-        pub(crate) use std::*;
-    } else {
-        pub(crate) use core::*;
-    }} // cfg_if
-
-    cfg_if! {
-    if #[cfg(feature = "std")] {
+        pub(crate) use std::borrow::Cow;
+        pub(crate) use std::boxed::Box;
         pub(crate) use std::string::String;
+        pub(crate) use std::vec;
         pub(crate) use std::vec::Vec;
     } else {
+        pub(crate) use ::alloc::borrow::Cow;
+        pub(crate) use ::alloc::boxed::Box;
         pub(crate) use ::alloc::string::String;
+        pub(crate) use ::alloc::vec;
         pub(crate) use ::alloc::vec::Vec;
     }} // cfg_if
 } // cfg_if
 
 // API
 
+mod buffer;
+mod bytes;
+#[cfg(feature = "complex")]
+pub mod complex;
+mod convert;
+#[cfg(feature = "alloc")]
+pub mod format_spec;
+#[cfg(feature = "std")]
+pub mod os_str;
+#[cfg(feature = "printf")]
+pub mod printf;
+#[cfg(feature = "ufmt")]
+pub mod ufmt;
+
+// Re-export the buffer sizes for the stack-buffer APIs.
+pub use lexical_core::{BUFFER_SIZE, POSITIONAL_BUFFER_SIZE};
+
 // Re-export the float rounding scheme used.
 pub use lexical_core::RoundingKind;
 
+// Re-export the alternate form of written floats.
+pub use lexical_core::AlternateForm;
+
+// Re-export the number punctuation of common locales.
+#[cfg(feature = "locale")]
+pub use lexical_core::Locale;
+
 // Re-export the numerical format.
 pub use lexical_core::{NumberFormat, NumberFormatBuilder};
 
+// Re-export the number parsed as the narrowest lossless type.
+#[cfg(feature = "floats")]
+pub use lexical_core::ParsedNumber;
+
 // Re-export the Result, Error and ErrorCode globally.
 pub use lexical_core::{Error, ErrorCode, Result};
 
@@ -194,18 +328,51 @@ pub use lexical_core::{ParseFloatOptions, ParseFloatOptionsBuilder};
 pub use lexical_core::{ParseIntegerOptions, ParseIntegerOptionsBuilder};
 pub use lexical_core::{WriteFloatOptions, WriteFloatOptionsBuilder};
 pub use lexical_core::{WriteIntegerOptions, WriteIntegerOptionsBuilder};
+#[cfg(feature = "power_of_two")]
+pub use lexical_core::FromRadix;
+
+// Expose the stack buffer for writing numbers.
+pub use self::buffer::Buffer;
+
+// Expose the wrapper for conversions through `TryFrom`.
+pub use self::convert::Lexical;
 
 // Publicly expose traits so they may be used for generic programming.
+pub use self::bytes::AsLexicalBytes;
 pub use lexical_core::{FromLexical, FromLexicalOptions};
 pub use lexical_core::{ToLexical, ToLexicalOptions};
+pub use lexical_core::{Float, Integer, Number, SignedInteger, UnsignedInteger};
 
 // HELPERS
 
-/// Get a vector as a slice, including the capacity.
+/// Write a number to a buffer of at least `size` bytes, and copy the
+/// written bytes to a string.
+///
+/// The number is written to a stack buffer if `size` fits, and otherwise
+/// to a heap buffer, so options requiring large buffers never panic.
+/// The writers only write ASCII, other than custom special strings,
+/// so any invalid UTF-8 in a special string is replaced with `U+FFFD`.
+#[inline]
+#[cfg(feature = "alloc")]
+fn write_to_string<Cb>(size: usize, cb: Cb) -> lib::String
+where
+    Cb: FnOnce(&mut [u8]) -> &mut [u8],
+{
+    let mut buffer = [0u8; lexical_core::POSITIONAL_BUFFER_SIZE];
+    if size <= buffer.len() {
+        return lib::String::from_utf8_lossy(cb(&mut buffer)).into_owned();
+    }
+    let mut buffer = lib::vec![0u8; size];
+    lib::String::from_utf8_lossy(cb(&mut buffer)).into_owned()
+}
+
+/// Convert the written bytes to a string slice.
+///
+/// The writers only write ASCII, other than custom special strings,
+/// so this only panics if a special string is not valid UTF-8.
 #[inline]
-unsafe fn vector_as_slice<T>(buf: &mut lib::Vec<T>) -> &mut [T] {
-    let first = buf.as_mut_ptr();
-    lib::slice::from_raw_parts_mut(first, buf.capacity())
+fn bytes_to_str(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).expect("Special strings must be valid UTF-8.")
 }
 
 // HIGH LEVEL
@@ -220,17 +387,14 @@ unsafe fn vector_as_slice<T>(buf: &mut lib::Vec<T>) -> &mut [T] {
 /// # extern crate lexical;
 /// # pub fn main() {
 /// assert_eq!(lexical::to_string(5), "5");
+/// # #[cfg(feature = "floats")]
 /// assert_eq!(lexical::to_string(0.0), "0.0");
 /// # }
 /// ```
 #[inline]
+#[cfg(feature = "alloc")]
 pub fn to_string<N: ToLexical>(n: N) -> lib::String {
-    unsafe {
-        let mut buf = lib::Vec::<u8>::with_capacity(N::FORMATTED_SIZE_DECIMAL);
-        let len = lexical_core::write(n, vector_as_slice(&mut buf)).len();
-        buf.set_len(len);
-        lib::String::from_utf8_unchecked(buf)
-    }
+    write_to_string(BUFFER_SIZE, |buffer| lexical_core::write(n, buffer))
 }
 
 /// High-level conversion of a number to a string with custom writing options.
@@ -243,6 +407,7 @@ pub fn to_string<N: ToLexical>(n: N) -> lib::String {
 /// ```rust
 /// # extern crate lexical;
 /// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
 /// let options = lexical::WriteFloatOptions::builder()
 ///     .trim_floats(true)
 ///     .build()
@@ -250,20 +415,153 @@ pub fn to_string<N: ToLexical>(n: N) -> lib::String {
 /// assert_eq!(lexical::to_string_with_options(0.0, &options), "0");
 /// assert_eq!(lexical::to_string_with_options(123.456, &options), "123.456");
 /// # }
+/// # }
 /// ```
 #[inline]
+#[cfg(feature = "alloc")]
 pub fn to_string_with_options<N: ToLexicalOptions>(n: N, options: &N::WriteOptions) -> lib::String {
-    #[cfg(feature = "radix")]
-    let size = N::FORMATTED_SIZE;
-    #[cfg(not(feature = "radix"))]
-    let size = N::FORMATTED_SIZE_DECIMAL;
-
-    unsafe {
-        let mut buf = lib::Vec::<u8>::with_capacity(size);
-        let len = lexical_core::write_with_options(n, vector_as_slice(&mut buf), &options).len();
-        buf.set_len(len);
-        lib::String::from_utf8_unchecked(buf)
-    }
+    let size = N::formatted_size(options);
+    write_to_string(size, |buffer| lexical_core::write_with_options(n, buffer, options))
+}
+
+/// High-level conversion of a number to a string in a given radix.
+///
+/// * `n`       - Number to convert to string.
+/// * `radix`   - Radix for number encoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "power_of_two")] {
+/// assert_eq!(lexical::to_string_with_radix(255, 16), "FF");
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical::to_string_with_radix(0.5, 2), "0.1");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "alloc", feature = "power_of_two"))]
+pub fn to_string_with_radix<N: ToLexicalOptions>(n: N, radix: u32) -> lib::String
+where
+    N::WriteOptions: FromRadix,
+{
+    write_to_string(N::FORMATTED_SIZE, |buffer| lexical_core::write_with_radix(n, buffer, radix))
+}
+
+/// High-level conversion of a number to a decimal-encoded string, in a buffer.
+///
+/// Does not require an allocator.
+///
+/// * `n`       - Number to convert to string.
+/// * `buffer`  - Buffer to write the string to.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`BUFFER_SIZE`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let mut buffer = [0u8; lexical::BUFFER_SIZE];
+/// assert_eq!(lexical::to_str(5, &mut buffer), "5");
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical::to_str(0.0, &mut buffer), "0.0");
+/// # }
+/// ```
+///
+/// [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+#[inline]
+pub fn to_str<N: ToLexical>(n: N, buffer: &mut [u8]) -> &str {
+    bytes_to_str(lexical_core::write(n, buffer))
+}
+
+/// High-level conversion of a number to a string with custom writing options, in a buffer.
+///
+/// Does not require an allocator.
+///
+/// * `n`       - Number to convert to string.
+/// * `buffer`  - Buffer to write the string to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`BUFFER_SIZE`], or if a custom
+/// special string in the options is not valid UTF-8. Floats written with
+/// `positional_exponents` may require up to [`POSITIONAL_BUFFER_SIZE`] bytes.
+/// [`ToLexicalOptions::formatted_size`] gets the size required by the options.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let mut buffer = [0u8; lexical::BUFFER_SIZE];
+/// assert_eq!(lexical::to_str_with_options(0.0, &mut buffer, &options), "0");
+/// assert_eq!(lexical::to_str_with_options(123.456, &mut buffer, &options), "123.456");
+/// # }
+/// # }
+/// ```
+///
+/// [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+/// [`POSITIONAL_BUFFER_SIZE`]: constant.POSITIONAL_BUFFER_SIZE.html
+/// [`ToLexicalOptions::formatted_size`]: trait.ToLexicalOptions.html#tymethod.formatted_size
+#[inline]
+pub fn to_str_with_options<'a, N: ToLexicalOptions>(
+    n: N,
+    buffer: &'a mut [u8],
+    options: &N::WriteOptions,
+) -> &'a str {
+    bytes_to_str(lexical_core::write_with_options(n, buffer, options))
+}
+
+/// High-level conversion of a number to a string in a given radix, in a buffer.
+///
+/// Does not require an allocator.
+///
+/// * `n`       - Number to convert to string.
+/// * `buffer`  - Buffer to write the string to.
+/// * `radix`   - Radix for number encoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid, or if the buffer is smaller than
+/// [`BUFFER_SIZE`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "power_of_two")] {
+/// let mut buffer = [0u8; lexical::BUFFER_SIZE];
+/// assert_eq!(lexical::to_str_with_radix(255, &mut buffer, 16), "FF");
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical::to_str_with_radix(0.5, &mut buffer, 2), "0.1");
+/// # }
+/// # }
+/// ```
+///
+/// [`BUFFER_SIZE`]: constant.BUFFER_SIZE.html
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn to_str_with_radix<N: ToLexicalOptions>(n: N, buffer: &mut [u8], radix: u32) -> &str
+where
+    N::WriteOptions: FromRadix,
+{
+    bytes_to_str(lexical_core::write_with_radix(n, buffer, radix))
 }
 
 /// High-level conversion of decimal-encoded bytes to a number.
@@ -287,23 +585,27 @@ pub fn to_string_with_options<N: ToLexicalOptions>(n: N, options: &N::WriteOptio
 /// // String overloads
 /// assert_eq!(lexical::parse::<i32, _>("5"), Ok(5));
 /// assert_eq!(err_code(lexical::parse::<i32, _>("1a")), ErrorCode::InvalidDigit);
+/// # #[cfg(feature = "floats")] {
 /// assert_eq!(lexical::parse::<f32, _>("0"), Ok(0.0));
 /// assert_eq!(lexical::parse::<f32, _>("1.0"), Ok(1.0));
 /// assert_eq!(lexical::parse::<f32, _>("1."), Ok(1.0));
+/// # }
 ///
 /// // Bytes overloads
 /// assert_eq!(lexical::parse::<i32, _>(b"5"), Ok(5));
 /// assert_eq!(err_code(lexical::parse::<i32, _>(b"1a")), ErrorCode::InvalidDigit);
+/// # #[cfg(feature = "floats")] {
 /// assert_eq!(lexical::parse::<f32, _>(b"0"), Ok(0.0));
 /// assert_eq!(lexical::parse::<f32, _>(b"1.0"), Ok(1.0));
 /// assert_eq!(lexical::parse::<f32, _>(b"1."), Ok(1.0));
 /// # assert_eq!(lexical::parse::<f32, _>(b"5.002868148396374"), Ok(5.002868148396374));
 /// # assert_eq!(lexical::parse::<f64, _>(b"5.002868148396374"), Ok(5.002868148396374));
 /// # }
+/// # }
 /// ```
 #[inline]
-pub fn parse<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
-    N::from_lexical(bytes.as_ref())
+pub fn parse<N: FromLexical, Bytes: AsLexicalBytes>(bytes: Bytes) -> Result<N> {
+    N::from_lexical(bytes.as_lexical_bytes())
 }
 
 /// High-level conversion of bytes to a number with custom parsing options.
@@ -319,6 +621,7 @@ pub fn parse<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
 /// ```rust
 /// # extern crate lexical;
 /// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
 /// let format = lexical::NumberFormat::builder()
 ///     .exponent_decimal(b'^')
 ///     .decimal_point(b',')
@@ -334,13 +637,14 @@ pub fn parse<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
 /// assert_eq!(lexical::parse_with_options::<f32, _>("1,2345", &options), Ok(1.2345));
 /// assert_eq!(lexical::parse_with_options::<f32, _>("1,2345^4", &options), Ok(12345.0));
 /// # }
+/// # }
 /// ```
 #[inline]
-pub fn parse_with_options<N: FromLexicalOptions, Bytes: AsRef<[u8]>>(
+pub fn parse_with_options<N: FromLexicalOptions, Bytes: AsLexicalBytes>(
     bytes: Bytes,
     options: &N::ParseOptions,
 ) -> Result<N> {
-    N::from_lexical_with_options(bytes.as_ref(), options)
+    N::from_lexical_with_options(bytes.as_lexical_bytes(), options)
 }
 
 /// High-level, partial conversion of decimal-encoded bytes to a number.
@@ -363,23 +667,27 @@ pub fn parse_with_options<N: FromLexicalOptions, Bytes: AsRef<[u8]>>(
 /// // String overloads
 /// assert_eq!(lexical::parse_partial::<i32, _>("5"), Ok((5, 1)));
 /// assert_eq!(lexical::parse_partial::<i32, _>("1a"), Ok((1, 1)));
+/// # #[cfg(feature = "floats")] {
 /// assert_eq!(lexical::parse_partial::<f32, _>("0"), Ok((0.0, 1)));
 /// assert_eq!(lexical::parse_partial::<f32, _>("1.0"), Ok((1.0, 3)));
 /// assert_eq!(lexical::parse_partial::<f32, _>("1."), Ok((1.0, 2)));
+/// # }
 ///
 /// // Bytes overloads
 /// assert_eq!(lexical::parse_partial::<i32, _>(b"5"), Ok((5, 1)));
 /// assert_eq!(lexical::parse_partial::<i32, _>(b"1a"), Ok((1, 1)));
+/// # #[cfg(feature = "floats")] {
 /// assert_eq!(lexical::parse_partial::<f32, _>(b"0"), Ok((0.0, 1)));
 /// assert_eq!(lexical::parse_partial::<f32, _>(b"1.0"), Ok((1.0, 3)));
 /// assert_eq!(lexical::parse_partial::<f32, _>(b"1."), Ok((1.0, 2)));
 /// # assert_eq!(lexical::parse_partial::<f32, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
 /// # assert_eq!(lexical::parse_partial::<f64, _>(b"5.002868148396374"), Ok((5.002868148396374, 17)));
 /// # }
+/// # }
 /// ```
 #[inline]
-pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<(N, usize)> {
-    N::from_lexical_partial(bytes.as_ref())
+pub fn parse_partial<N: FromLexical, Bytes: AsLexicalBytes>(bytes: Bytes) -> Result<(N, usize)> {
+    N::from_lexical_partial(bytes.as_lexical_bytes())
 }
 
 /// High-level, partial conversion of bytes to a number with custom parsing options.
@@ -398,6 +706,7 @@ pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result
 /// ```rust
 /// # extern crate lexical;
 /// # pub fn main() {
+/// # #[cfg(feature = "floats")] {
 /// let format = lexical::NumberFormat::builder()
 ///     .exponent_decimal(b'^')
 ///     .decimal_point(b',')
@@ -413,11 +722,115 @@ pub fn parse_partial<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result
 /// assert_eq!(lexical::parse_partial_with_options::<f32, _>("1,2345", &options), Ok((1.2345, 6)));
 /// assert_eq!(lexical::parse_partial_with_options::<f32, _>("1,2345^4", &options), Ok((12345.0, 8)));
 /// # }
+/// # }
 /// ```
 #[inline]
-pub fn parse_partial_with_options<N: FromLexicalOptions, Bytes: AsRef<[u8]>>(
+pub fn parse_partial_with_options<N: FromLexicalOptions, Bytes: AsLexicalBytes>(
     bytes: Bytes,
     options: &N::ParseOptions,
 ) -> Result<(N, usize)> {
-    N::from_lexical_partial_with_options(bytes.as_ref(), options)
+    N::from_lexical_partial_with_options(bytes.as_lexical_bytes(), options)
+}
+
+/// High-level conversion of bytes in a given radix to a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "power_of_two")] {
+/// assert_eq!(lexical::parse_with_radix::<i32, _>("FF", 16), Ok(255));
+/// assert_eq!(lexical::parse_with_radix::<f32, _>("0.1", 2), Ok(0.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn parse_with_radix<N: FromLexicalOptions, Bytes: AsLexicalBytes>(
+    bytes: Bytes,
+    radix: u32,
+) -> Result<N>
+where
+    N::ParseOptions: FromRadix,
+{
+    lexical_core::parse_with_radix(bytes.as_lexical_bytes(), radix)
+}
+
+/// High-level, partial conversion of bytes in a given radix to a number.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `radix`   - Radix for number decoding.
+///
+/// # Panics
+///
+/// Panics if the radix is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// # #[cfg(feature = "power_of_two")] {
+/// assert_eq!(lexical::parse_partial_with_radix::<i32, _>("FFz", 16), Ok((255, 2)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "power_of_two")]
+pub fn parse_partial_with_radix<N: FromLexicalOptions, Bytes: AsLexicalBytes>(
+    bytes: Bytes,
+    radix: u32,
+) -> Result<(N, usize)>
+where
+    N::ParseOptions: FromRadix,
+{
+    lexical_core::parse_partial_with_radix(bytes.as_lexical_bytes(), radix)
+}
+
+/// High-level conversion of bytes to the narrowest lossless number type.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. Positive integers are parsed as a `u64`,
+/// negative integers as an `i64`, and all other numbers, including
+/// integers that overflow both and `-0`, as an `f64`, like JSON parsers.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::{ParseFloatOptions, ParsedNumber};
+/// # pub fn main() {
+/// let options = ParseFloatOptions::new();
+/// assert_eq!(lexical::parse_number("15", &options), Ok(ParsedNumber::U64(15)));
+/// assert_eq!(lexical::parse_number("-15", &options), Ok(ParsedNumber::I64(-15)));
+/// assert_eq!(lexical::parse_number(b"1.5", &options), Ok(ParsedNumber::F64(1.5)));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "floats")]
+pub fn parse_number<Bytes: AsLexicalBytes>(
+    bytes: Bytes,
+    options: &ParseFloatOptions,
+) -> Result<ParsedNumber> {
+    lexical_core::parse_number(bytes.as_lexical_bytes(), options)
 }
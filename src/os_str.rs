@@ -0,0 +1,196 @@
+//! Parse numbers from OS strings.
+//!
+//! Command-line arguments, environment variables and path components
+//! are OS strings, which may not be valid UTF-8. These functions parse
+//! them without a lossy conversion to a `String` first: any byte that
+//! is not part of a number, including non-ASCII characters, is an
+//! invalid digit.
+//!
+//! On Unix, the bytes of the OS string are parsed directly, so the
+//! error index is the index of the invalid byte. On other platforms,
+//! OS strings that are not valid UTF-8 are an invalid digit at the
+//! index of the first invalid character in the lossy conversion.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # pub fn main() {
+//! use std::ffi::OsString;
+//! use std::path::Path;
+//!
+//! let arg = OsString::from("42");
+//! assert_eq!(lexical::os_str::parse::<u32, _>(&arg), Ok(42));
+//!
+//! let path = Path::new("/proc/1234");
+//! assert_eq!(lexical::os_str::parse::<u32, _>(path.file_name().unwrap()), Ok(1234));
+//! let path = Path::new("frames/0.125.png");
+//! # #[cfg(feature = "floats")]
+//! assert_eq!(lexical::os_str::parse::<f64, _>(path.file_stem().unwrap()), Ok(0.125));
+//! # }
+//! ```
+
+use crate::{FromLexical, FromLexicalOptions};
+use lexical_core::Result;
+#[cfg(not(unix))]
+use lexical_core::{Error, ErrorCode};
+use std::ffi::OsStr;
+
+/// Call the parser with the bytes of the OS string.
+#[inline]
+#[cfg(unix)]
+fn with_bytes<T, Cb>(s: &OsStr, cb: Cb) -> Result<T>
+where
+    Cb: FnOnce(&[u8]) -> Result<T>,
+{
+    use std::os::unix::ffi::OsStrExt;
+    cb(s.as_bytes())
+}
+
+/// Call the parser with the bytes of the OS string.
+#[inline]
+#[cfg(not(unix))]
+fn with_bytes<T, Cb>(s: &OsStr, cb: Cb) -> Result<T>
+where
+    Cb: FnOnce(&[u8]) -> Result<T>,
+{
+    match s.to_str() {
+        Some(s) => cb(s.as_bytes()),
+        None => {
+            // The replacement character is never part of a number.
+            let lossy = s.to_string_lossy();
+            let index = lossy.find('\u{FFFD}').unwrap_or(0);
+            Err(Error::from((ErrorCode::InvalidDigit, index)))
+        },
+    }
+}
+
+/// Parse a number from an OS string.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `s`       - OS string, or path, to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use std::ffi::OsStr;
+///
+/// assert_eq!(lexical::os_str::parse::<i32, _>(OsStr::new("-5")), Ok(-5));
+/// # #[cfg(feature = "floats")]
+/// assert_eq!(lexical::os_str::parse::<f32, _>("1.5"), Ok(1.5));
+/// # }
+/// ```
+#[inline]
+pub fn parse<N: FromLexical, S: AsRef<OsStr>>(s: S) -> Result<N> {
+    with_bytes(s.as_ref(), N::from_lexical)
+}
+
+/// Parse a number from an OS string with custom parsing options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `s`       - OS string, or path, to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use std::ffi::OsStr;
+///
+/// # #[cfg(feature = "floats")] {
+/// let options = lexical::ParseFloatOptions::builder()
+///     .trim_whitespace(true)
+///     .build()
+///     .unwrap();
+/// let result = lexical::os_str::parse_with_options::<f64, _>(OsStr::new(" 1.5 "), &options);
+/// assert_eq!(result, Ok(1.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexicalOptions, S: AsRef<OsStr>>(
+    s: S,
+    options: &N::ParseOptions,
+) -> Result<N> {
+    with_bytes(s.as_ref(), |bytes| N::from_lexical_with_options(bytes, options))
+}
+
+/// Partially parse a number from an OS string.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of bytes processed.
+///
+/// * `s`       - OS string, or path, to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use std::path::Path;
+///
+/// assert_eq!(lexical::os_str::parse_partial::<u32, _>(Path::new("42.log")), Ok((42, 2)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial<N: FromLexical, S: AsRef<OsStr>>(s: S) -> Result<(N, usize)> {
+    with_bytes(s.as_ref(), N::from_lexical_partial)
+}
+
+/// Partially parse a number from an OS string with custom parsing options.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of bytes processed.
+///
+/// * `s`       - OS string, or path, to convert to number.
+/// * `options` - Options to specify number parsing.
+#[inline]
+pub fn parse_partial_with_options<N: FromLexicalOptions, S: AsRef<OsStr>>(
+    s: S,
+    options: &N::ParseOptions,
+) -> Result<(N, usize)> {
+    with_bytes(s.as_ref(), |bytes| N::from_lexical_partial_with_options(bytes, options))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorCode;
+    use std::ffi::OsString;
+    #[cfg(feature = "floats")]
+    use std::path::PathBuf;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(parse::<u8, _>(OsString::from("255")), Ok(255));
+        #[cfg(feature = "floats")]
+        assert_eq!(parse::<f64, _>(PathBuf::from("1e3")), Ok(1000.0));
+        assert_eq!(parse_partial::<i64, _>("-12.png"), Ok((-12, 3)));
+
+        let err = |s: &str| parse::<u32, _>(s).err().map(|e| (e.code, e.index));
+        assert_eq!(err(""), Some((ErrorCode::Empty, 0)));
+        assert_eq!(err("1é"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("12/3"), Some((ErrorCode::InvalidDigit, 2)));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn invalid_utf8_test() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let s = OsStr::from_bytes(b"12\xFF");
+        let err = parse::<u32, _>(s).err().map(|e| (e.code, e.index));
+        assert_eq!(err, Some((ErrorCode::InvalidDigit, 2)));
+        assert_eq!(parse_partial::<u32, _>(s), Ok((12, 2)));
+    }
+}
@@ -0,0 +1,823 @@
+//! Interpreter for C printf numeric conversions.
+//!
+//! Porting layers and logging frameworks often accept printf-style
+//! conversions, such as `"%08.2f"`, from user input. This module parses
+//! a single conversion specification and writes the number with the
+//! lexical writers, without translating the specification to `core::fmt`.
+//!
+//! The supported grammar is:
+//!
+//! ```text
+//! spec        := '%' flag* [width] ['.' [precision]] [length] conversion
+//! flag        := '-' | '+' | ' ' | '0' | '#'
+//! width       := integer
+//! precision   := integer
+//! length      := 'hh' | 'h' | 'l' | 'll' | 'j' | 'z' | 't' | 'L' | 'q'
+//! conversion  := 'd' | 'i' | 'u' | 'o' | 'x' | 'X'
+//!              | 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'a' | 'A'
+//! ```
+//!
+//! Length modifiers are accepted and ignored, since the width of the
+//! number is known from its type. `*` widths and precisions, positional
+//! arguments, and the non-numeric conversions are not supported.
+//!
+//! Integer conversions (`d`, `i`, `u`, `o`, `x`, `X`) require an integer,
+//! and the unsigned conversions write the two's complement of negative
+//! integers, like C. Float conversions require a float, and `f32` is
+//! promoted to `f64`, like C variadic arguments. Like C, the precision
+//! rounds the exact binary value of the float, so `2.675` is written as
+//! `2.67` with `"%.2f"`, with ties to even unless another kind is
+//! selected with [`with_rounding`](struct.PrintfSpec.html#method.with_rounding).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # pub fn main() {
+//! assert_eq!(lexical::printf::write(-1.23456, "%08.2f"), Ok("-0001.23".into()));
+//! assert_eq!(lexical::printf::write(1234.5, "%e"), Ok("1.234500e+03".into()));
+//! assert_eq!(lexical::printf::write(255u8, "%#x"), Ok("0xff".into()));
+//! assert_eq!(lexical::printf::write(3.0, "%a"), Ok("0x1.8p+1".into()));
+//! # }
+//! ```
+
+use crate::format_spec::{exact_digits, magnitude_rounding, round, write_positional};
+use crate::lib::{String, Vec};
+use lexical_core::{Error, ErrorCode, Result, RoundingKind, ToLexical};
+
+// SPEC
+// ----
+
+/// Parsed printf conversion specification for a single number.
+///
+/// Parse the specification once with [`parse`](#method.parse) to write
+/// many numbers with the same conversion.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PrintfSpec {
+    /// Left-justify the number within the width (`-`).
+    left_justify: bool,
+    /// Write a `+` sign for non-negative signed numbers (`+`).
+    sign_plus: bool,
+    /// Write a space for non-negative signed numbers (` `).
+    sign_space: bool,
+    /// Pad with leading zeros rather than spaces (`0`).
+    zero_pad: bool,
+    /// Use the alternate form of the conversion (`#`).
+    alternate: bool,
+    /// Minimum number of characters to write.
+    width: usize,
+    /// Minimum integer digits, or float digits after the decimal point.
+    precision: Option<usize>,
+    /// Conversion character.
+    conversion: u8,
+    /// Index of the conversion character in the specification.
+    index: usize,
+    /// Rounding kind for the precision.
+    rounding: RoundingKind,
+}
+
+impl PrintfSpec {
+    /// Parse a printf conversion specification, such as `"%08.2f"`.
+    ///
+    /// Returns an error with the index of the first invalid character
+    /// in the specification, or `ErrorCode::Empty` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical;
+    /// # pub fn main() {
+    /// use lexical::{ErrorCode, printf::PrintfSpec};
+    ///
+    /// let spec = PrintfSpec::parse("%5.1f").unwrap();
+    /// assert_eq!(spec.write(1.25), Ok("  1.2".into()));
+    /// assert_eq!(spec.write(0.5f32), Ok("  0.5".into()));
+    ///
+    /// let err = PrintfSpec::parse("%5.1s").err().unwrap();
+    /// assert_eq!(err.code, ErrorCode::InvalidDigit);
+    /// assert_eq!(err.index, 4);
+    /// # }
+    /// ```
+    pub fn parse(spec: &str) -> Result<PrintfSpec> {
+        let mut result = PrintfSpec {
+            left_justify: false,
+            sign_plus: false,
+            sign_space: false,
+            zero_pad: false,
+            alternate: false,
+            width: 0,
+            precision: None,
+            conversion: b'd',
+            index: 0,
+            rounding: RoundingKind::NearestTieEven,
+        };
+
+        let bytes = spec.as_bytes();
+        if bytes.is_empty() {
+            return Err(Error::from((ErrorCode::Empty, 0)));
+        } else if bytes[0] != b'%' {
+            return Err(Error::from((ErrorCode::InvalidDigit, 0)));
+        }
+        let mut index = 1;
+
+        // Flags.
+        while let Some(&c) = bytes.get(index) {
+            match c {
+                b'-' => result.left_justify = true,
+                b'+' => result.sign_plus = true,
+                b' ' => result.sign_space = true,
+                b'0' => result.zero_pad = true,
+                b'#' => result.alternate = true,
+                _ => break,
+            }
+            index += 1;
+        }
+
+        // Width and precision, where an empty precision is zero.
+        if let Some(width) = parse_integer(bytes, &mut index)? {
+            result.width = width;
+        }
+        if bytes.get(index) == Some(&b'.') {
+            index += 1;
+            result.precision = Some(parse_integer(bytes, &mut index)?.unwrap_or(0));
+        }
+
+        // Length modifiers, which are ignored.
+        match (bytes.get(index), bytes.get(index + 1)) {
+            (Some(&b'h'), Some(&b'h')) | (Some(&b'l'), Some(&b'l')) => index += 2,
+            (Some(&b'h'), _) | (Some(&b'l'), _) | (Some(&b'j'), _) | (Some(&b'z'), _) => index += 1,
+            (Some(&b't'), _) | (Some(&b'L'), _) | (Some(&b'q'), _) => index += 1,
+            _ => (),
+        }
+
+        // Conversion.
+        match bytes.get(index) {
+            Some(&c) if b"diuoxXfFeEgGaA".contains(&c) => {
+                result.conversion = c;
+                result.index = index;
+            },
+            _ => return Err(Error::from((ErrorCode::InvalidDigit, index))),
+        }
+        match index + 1 == bytes.len() {
+            true => Ok(result),
+            false => Err(Error::from((ErrorCode::InvalidDigit, index + 1))),
+        }
+    }
+
+    /// Get if the number is left-justified within the width (`-`).
+    #[inline]
+    pub const fn left_justify(&self) -> bool {
+        self.left_justify
+    }
+
+    /// Get if a `+` sign is written for non-negative signed numbers (`+`).
+    #[inline]
+    pub const fn sign_plus(&self) -> bool {
+        self.sign_plus
+    }
+
+    /// Get if a space is written for non-negative signed numbers (` `).
+    #[inline]
+    pub const fn sign_space(&self) -> bool {
+        self.sign_space
+    }
+
+    /// Get if the number is padded with leading zeros (`0`).
+    #[inline]
+    pub const fn zero_pad(&self) -> bool {
+        self.zero_pad
+    }
+
+    /// Get if the alternate form of the conversion is used (`#`).
+    #[inline]
+    pub const fn alternate(&self) -> bool {
+        self.alternate
+    }
+
+    /// Get the minimum number of characters to write.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the precision, if provided.
+    #[inline]
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the conversion character.
+    #[inline]
+    pub const fn conversion(&self) -> u8 {
+        self.conversion
+    }
+
+    /// Get the rounding kind for the precision.
+    #[inline]
+    pub const fn rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Set the rounding kind for the precision of float conversions.
+    ///
+    /// Defaults to `RoundingKind::NearestTieEven`, and also applies to
+    /// the hexadecimal digits of `%a`, which are exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate lexical;
+    /// # pub fn main() {
+    /// use lexical::{RoundingKind, printf::PrintfSpec};
+    ///
+    /// let spec = PrintfSpec::parse("%.1f").unwrap();
+    /// assert_eq!(spec.write(0.25), Ok("0.2".into()));
+    /// let spec = spec.with_rounding(RoundingKind::NearestTieAwayZero);
+    /// assert_eq!(spec.write(0.25), Ok("0.3".into()));
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn with_rounding(mut self, rounding: RoundingKind) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Write a number to a string using the conversion specification.
+    ///
+    /// Returns an error at the index of the conversion character if
+    /// the conversion does not support the type of the number.
+    #[inline]
+    pub fn write<N: ToPrintf>(&self, n: N) -> Result<String> {
+        n.to_printf(self)
+    }
+
+    /// Get if the conversion writes uppercase characters.
+    #[inline]
+    fn is_uppercase(&self) -> bool {
+        self.conversion.is_ascii_uppercase()
+    }
+
+    /// Get the sign to write for a signed number.
+    #[inline]
+    fn sign(&self, is_negative: bool) -> &'static str {
+        match (is_negative, self.sign_plus, self.sign_space) {
+            (true, _, _) => "-",
+            (false, true, _) => "+",
+            (false, false, true) => " ",
+            (false, false, false) => "",
+        }
+    }
+
+    /// Get the error for a number the conversion does not support.
+    #[inline]
+    fn conversion_error(&self) -> Error {
+        Error::from((ErrorCode::InvalidDigit, self.index))
+    }
+
+    /// Write the sign, prefix and body, padded to the width.
+    ///
+    /// Zeros are inserted between the prefix and the body, if zero
+    /// padding is allowed, otherwise spaces pad the whole number.
+    fn pad(&self, sign: &str, prefix: &str, body: &[u8], allow_zeros: bool) -> String {
+        let count = sign.len() + prefix.len() + body.len();
+        let padding = self.width.saturating_sub(count);
+        let (before, zeros, after) = match (self.left_justify, self.zero_pad && allow_zeros) {
+            (true, _) => (0, 0, padding),
+            (false, true) => (0, padding, 0),
+            (false, false) => (padding, 0, 0),
+        };
+
+        // The body is always ASCII.
+        let mut string = String::with_capacity(count + padding);
+        string.extend((0..before).map(|_| ' '));
+        string.push_str(sign);
+        string.push_str(prefix);
+        string.extend((0..zeros).map(|_| '0'));
+        string.extend(body.iter().map(|&c| c as char));
+        string.extend((0..after).map(|_| ' '));
+        string
+    }
+}
+
+/// Parse an optional decimal integer, for the width or precision.
+#[inline]
+fn parse_integer(bytes: &[u8], index: &mut usize) -> Result<Option<usize>> {
+    let mut value: Option<usize> = None;
+    while let Some(&c) = bytes.get(*index) {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        value = value
+            .unwrap_or(0)
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((c - b'0') as usize))
+            .map(Some)
+            .ok_or_else(|| Error::from((ErrorCode::Overflow, *index)))?;
+        *index += 1;
+    }
+    Ok(value)
+}
+
+// FLOAT
+// -----
+
+/// Default precision for float conversions.
+const DEFAULT_PRECISION: usize = 6;
+
+/// Write an exponent with a sign and a minimum number of digits, like `e+05`.
+fn write_exponent(exponent: i32, marker: u8, min_digits: usize, body: &mut Vec<u8>) {
+    body.push(marker);
+    body.push(if exponent < 0 { b'-' } else { b'+' });
+    if min_digits == 2 && exponent.abs() < 10 {
+        body.push(b'0');
+    }
+    let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+    body.extend_from_slice(lexical_core::write(exponent.unsigned_abs(), &mut buffer));
+}
+
+/// Write the digits in scientific notation, like `1.234500e+03`.
+fn write_scientific(
+    spec: &PrintfSpec,
+    digits: &mut Vec<u8>,
+    mut exponent: i32,
+    precision: usize,
+    rounding: RoundingKind,
+    body: &mut Vec<u8>,
+) {
+    round(digits, &mut exponent, precision as isize + 1, rounding);
+    digits.resize(precision + 1, b'0');
+    body.push(digits[0]);
+    if precision > 0 || spec.alternate {
+        body.push(b'.');
+    }
+    body.extend_from_slice(&digits[1..]);
+    write_exponent(exponent, if spec.is_uppercase() { b'E' } else { b'e' }, 2, body);
+}
+
+/// Write the digits in the shorter of positional or scientific notation.
+///
+/// Trailing zeros are removed from the fraction, unless using the
+/// alternate form.
+fn write_general(
+    spec: &PrintfSpec,
+    digits: &mut Vec<u8>,
+    exponent: i32,
+    rounding: RoundingKind,
+    body: &mut Vec<u8>,
+) {
+    let precision = spec.precision.unwrap_or(DEFAULT_PRECISION).max(1);
+    let mut rounded = digits.clone();
+    let mut rounded_exponent = exponent;
+    round(&mut rounded, &mut rounded_exponent, precision as isize, rounding);
+    let is_positional = (-4..precision as i32).contains(&rounded_exponent);
+
+    let start = body.len();
+    let end = match is_positional {
+        true => {
+            let fraction = (precision as i32 - 1 - rounded_exponent) as usize;
+            write_positional(digits, exponent, Some(fraction), rounding, body);
+            if spec.alternate && !body[start..].contains(&b'.') {
+                body.push(b'.');
+            }
+            body.len()
+        },
+        false => {
+            write_scientific(spec, digits, exponent, precision - 1, rounding, body);
+            start + body[start..].iter().position(|&c| c == b'e' || c == b'E').unwrap()
+        },
+    };
+
+    if !spec.alternate && body[start..end].contains(&b'.') {
+        let trimmed = body[start..end].iter().rev().take_while(|&&c| c == b'0').count();
+        let trimmed = match body[end - trimmed - 1] {
+            b'.' => trimmed + 1,
+            _ => trimmed,
+        };
+        body.drain(end - trimmed..end);
+    }
+}
+
+/// Write the float in hexadecimal scientific notation, like `0x1.8p+1`.
+fn write_hexadecimal(spec: &PrintfSpec, float: f64, rounding: RoundingKind, body: &mut Vec<u8>) {
+    const MANTISSA_BITS: i32 = 52;
+    const NIBBLES: usize = 13;
+
+    let bits = float.to_bits();
+    let biased_exponent = ((bits >> MANTISSA_BITS) & 0x7FF) as i32;
+    let mut mantissa = bits & ((1 << MANTISSA_BITS) - 1);
+    let (mut lead, exponent) = match (biased_exponent, mantissa) {
+        (0, 0) => (0u8, 0),
+        (0, _) => (0, -1022),
+        (e, _) => (1, e - 1023),
+    };
+
+    // Round the mantissa to the precision.
+    let nibbles = match spec.precision {
+        Some(precision) if precision < NIBBLES => {
+            let shift = 4 * (NIBBLES - precision) as u32;
+            let remainder = mantissa & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            mantissa >>= shift;
+            let is_odd = match precision {
+                0 => lead & 1 == 1,
+                _ => mantissa & 1 == 1,
+            };
+            let round_up = match rounding {
+                RoundingKind::Upward => remainder != 0,
+                RoundingKind::Downward => false,
+                RoundingKind::NearestTieAwayZero => remainder >= half,
+                _ => remainder > half || (remainder == half && is_odd),
+            };
+            if round_up {
+                mantissa += 1;
+                if mantissa >> (4 * precision) != 0 {
+                    mantissa = 0;
+                    lead += 1;
+                }
+            }
+            precision
+        },
+        Some(precision) => {
+            mantissa <<= 4 * (precision.min(16) - NIBBLES);
+            precision
+        },
+        None if mantissa == 0 => 0,
+        None => {
+            let trailing = mantissa.trailing_zeros() as usize / 4;
+            mantissa >>= 4 * trailing;
+            NIBBLES - trailing
+        },
+    };
+
+    let table: &[u8; 16] = match spec.is_uppercase() {
+        true => b"0123456789ABCDEF",
+        false => b"0123456789abcdef",
+    };
+    body.push(b'0' + lead);
+    if nibbles > 0 || spec.alternate {
+        body.push(b'.');
+    }
+    body.extend((0..nibbles.min(16)).rev().map(|i| table[((mantissa >> (4 * i)) & 0xF) as usize]));
+    body.extend((16..nibbles).map(|_| b'0'));
+    write_exponent(exponent, if spec.is_uppercase() { b'P' } else { b'p' }, 1, body);
+}
+
+/// Write a float using a float conversion.
+fn float_to_printf(spec: &PrintfSpec, float: f64) -> Result<String> {
+    let sign = spec.sign(float.is_sign_negative());
+    let mut body = Vec::with_capacity(spec.precision.unwrap_or(DEFAULT_PRECISION) + 32);
+    if !float.is_finite() {
+        let special: &[u8] = match (float.is_nan(), spec.is_uppercase()) {
+            (true, false) => b"nan",
+            (true, true) => b"NAN",
+            (false, false) => b"inf",
+            (false, true) => b"INF",
+        };
+        return match spec.conversion {
+            b'f' | b'F' | b'e' | b'E' | b'g' | b'G' | b'a' | b'A' => Ok(spec.pad(sign, "", special, false)),
+            _ => Err(spec.conversion_error()),
+        };
+    }
+
+    let (mut digits, exponent) = exact_digits(float);
+    let precision = spec.precision.unwrap_or(DEFAULT_PRECISION);
+    let rounding = magnitude_rounding(spec.rounding, float.is_sign_negative());
+    let prefix = match spec.conversion {
+        b'f' | b'F' => {
+            write_positional(&mut digits, exponent, Some(precision), rounding, &mut body);
+            if precision == 0 && spec.alternate {
+                body.push(b'.');
+            }
+            ""
+        },
+        b'e' | b'E' => {
+            write_scientific(spec, &mut digits, exponent, precision, rounding, &mut body);
+            ""
+        },
+        b'g' | b'G' => {
+            write_general(spec, &mut digits, exponent, rounding, &mut body);
+            ""
+        },
+        b'a' => {
+            write_hexadecimal(spec, float, rounding, &mut body);
+            "0x"
+        },
+        b'A' => {
+            write_hexadecimal(spec, float, rounding, &mut body);
+            "0X"
+        },
+        _ => return Err(spec.conversion_error()),
+    };
+    Ok(spec.pad(sign, prefix, &body, true))
+}
+
+// INTEGER
+// -------
+
+/// Write an integer, from the bytes written by the integer writer.
+///
+/// The bytes are the decimal digits for signed conversions, otherwise
+/// the digits of the unsigned reinterpretation in the conversion radix.
+fn integer_to_printf(spec: &PrintfSpec, bytes: &[u8]) -> String {
+    let (is_negative, digits) = match bytes.first() {
+        Some(&b'-') => (true, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let is_zero = digits == b"0";
+    let sign = match spec.conversion {
+        b'd' | b'i' => spec.sign(is_negative),
+        _ => "",
+    };
+    let prefix = match (spec.conversion, spec.alternate && !is_zero) {
+        (b'x', true) => "0x",
+        (b'X', true) => "0X",
+        _ => "",
+    };
+
+    // The precision is the minimum number of digits, and a zero
+    // precision writes no digits for zero.
+    let mut body = Vec::with_capacity(digits.len() + spec.precision.unwrap_or(0) + 1);
+    let count = match (spec.precision, is_zero) {
+        (Some(0), true) => 0,
+        _ => digits.len(),
+    };
+    let zeros = spec.precision.unwrap_or(0).saturating_sub(count);
+    body.extend((0..zeros).map(|_| b'0'));
+    if spec.conversion == b'o' && spec.alternate && body.is_empty() && !is_zero {
+        body.push(b'0');
+    }
+    match spec.conversion {
+        b'x' => body.extend(digits[..count].iter().map(|c| c.to_ascii_lowercase())),
+        _ => body.extend_from_slice(&digits[..count]),
+    }
+    if spec.conversion == b'o' && spec.alternate && body.is_empty() {
+        body.push(b'0');
+    }
+
+    // Zero padding is ignored if a precision is provided.
+    spec.pad(sign, prefix, &body, spec.precision.is_none())
+}
+
+// TO PRINTF
+// ---------
+
+/// Trait for numbers that can be written with a printf conversion.
+pub trait ToPrintf: ToLexical {
+    /// Write the number to a string using the conversion specification.
+    ///
+    /// Returns an error at the index of the conversion character if
+    /// the conversion does not support the type of the number.
+    fn to_printf(self, spec: &PrintfSpec) -> Result<String>;
+}
+
+// Implement ToPrintf for integer types.
+macro_rules! integer_to_printf {
+    ($($t:ty, $u:ty ;)*) => ($(
+        impl ToPrintf for $t {
+            #[inline]
+            fn to_printf(self, spec: &PrintfSpec) -> Result<String> {
+                let mut buffer = [0u8; lexical_core::BUFFER_SIZE];
+                let bytes = match spec.conversion {
+                    b'd' | b'i' => lexical_core::write(self, &mut buffer),
+                    b'u' => lexical_core::write(self as $u, &mut buffer),
+                    b'o' => lexical_core::write_with_radix(self as $u, &mut buffer, 8),
+                    b'x' | b'X' => lexical_core::write_with_radix(self as $u, &mut buffer, 16),
+                    _ => return Err(spec.conversion_error()),
+                };
+                Ok(integer_to_printf(spec, bytes))
+            }
+        }
+    )*);
+}
+
+integer_to_printf! {
+    u8, u8 ; u16, u16 ; u32, u32 ; u64, u64 ; u128, u128 ; usize, usize ;
+    i8, u8 ; i16, u16 ; i32, u32 ; i64, u64 ; i128, u128 ; isize, usize ;
+}
+
+// Implement ToPrintf for float types.
+macro_rules! float_to_printf {
+    ($($t:ty)*) => ($(
+        impl ToPrintf for $t {
+            #[inline]
+            fn to_printf(self, spec: &PrintfSpec) -> Result<String> {
+                float_to_printf(spec, self as f64)
+            }
+        }
+    )*);
+}
+
+float_to_printf! { f32 f64 }
+
+// API
+// ---
+
+/// Write a number to a string using a printf conversion specification.
+///
+/// * `n`       - Number to convert to string.
+/// * `spec`    - Conversion specification, such as `"%08.2f"`.
+///
+/// Returns an error with the index of the first invalid character
+/// in the specification, `ErrorCode::Empty` if it is empty, or an
+/// error at the index of the conversion character if the conversion
+/// does not support the type of the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// use lexical::printf;
+///
+/// assert_eq!(printf::write(-42, "%5d"), Ok("  -42".into()));
+/// assert_eq!(printf::write(-1i32, "%u"), Ok("4294967295".into()));
+/// assert_eq!(printf::write(0.0001, "%g"), Ok("0.0001".into()));
+/// assert_eq!(printf::write(1.5, "%d"), Err(lexical::Error::from((lexical::ErrorCode::InvalidDigit, 1))));
+/// # }
+/// ```
+#[inline]
+pub fn write<N: ToPrintf>(n: N, spec: &str) -> Result<String> {
+    PrintfSpec::parse(spec)?.write(n)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Check the output matches glibc's printf for the spec.
+    macro_rules! check {
+        ($n:expr, $spec:literal, $expected:literal) => {
+            assert_eq!(write($n, $spec).unwrap(), $expected, "spec {}", $spec);
+        };
+    }
+
+    #[test]
+    fn parse_test() {
+        let spec = PrintfSpec::parse("%-+ 0#12.3lf").unwrap();
+        assert!(spec.left_justify());
+        assert!(spec.sign_plus());
+        assert!(spec.sign_space());
+        assert!(spec.zero_pad());
+        assert!(spec.alternate());
+        assert_eq!(spec.width(), 12);
+        assert_eq!(spec.precision(), Some(3));
+        assert_eq!(spec.conversion(), b'f');
+
+        assert_eq!(PrintfSpec::parse("%.d").unwrap().precision(), Some(0));
+        assert_eq!(PrintfSpec::parse("%lld").unwrap().conversion(), b'd');
+        assert_eq!(PrintfSpec::parse("%hhx").unwrap().conversion(), b'x');
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let err = |spec: &str| PrintfSpec::parse(spec).err().map(|e| (e.code, e.index));
+        assert_eq!(err(""), Some((ErrorCode::Empty, 0)));
+        assert_eq!(err("d"), Some((ErrorCode::InvalidDigit, 0)));
+        assert_eq!(err("%"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("%*d"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("%s"), Some((ErrorCode::InvalidDigit, 1)));
+        assert_eq!(err("%dd"), Some((ErrorCode::InvalidDigit, 2)));
+        assert_eq!(err("%lllx"), Some((ErrorCode::InvalidDigit, 3)));
+        assert_eq!(err("%99999999999999999999d"), Some((ErrorCode::Overflow, 20)));
+    }
+
+    #[test]
+    fn conversion_error_test() {
+        let err = |spec: &str| PrintfSpec::parse(spec).unwrap().write(1.5).err().map(|e| e.index);
+        assert_eq!(err("%5d"), Some(2));
+        assert_eq!(err("%lx"), Some(2));
+        let err = |spec: &str| PrintfSpec::parse(spec).unwrap().write(1).err().map(|e| e.index);
+        assert_eq!(err("%f"), Some(1));
+        assert_eq!(err("%.2a"), Some(3));
+    }
+
+    #[test]
+    fn integer_test() {
+        check!(42, "%d", "42");
+        check!(-42, "%5d", "  -42");
+        check!(42, "%-5d", "42   ");
+        check!(-42, "%05d", "-0042");
+        check!(42, "%+d", "+42");
+        check!(42, "% d", " 42");
+        check!(7, "%.3d", "007");
+        check!(0, "%.0d", "");
+        check!(-7, "%08.3d", "    -007");
+        check!(3, "%-+6d", "+3    ");
+        check!(-1i32, "%u", "4294967295");
+        check!(255, "%x", "ff");
+        check!(255, "%X", "FF");
+        check!(255, "%#x", "0xff");
+        check!(8, "%#o", "010");
+        check!(8, "%o", "10");
+        check!(0, "%#x", "0");
+        check!(0, "%#o", "0");
+        check!(-1i64, "%lx", "ffffffffffffffff");
+        check!(200u8, "%hhx", "c8");
+        check!(255, "%#08x", "0x0000ff");
+        check!(i128::MIN, "%d", "-170141183460469231731687303715884105728");
+    }
+
+    #[test]
+    fn fixed_test() {
+        check!(3.5, "%f", "3.500000");
+        check!(-1.23456, "%08.2f", "-0001.23");
+        check!(2.5, "%.0f", "2");
+        check!(2.5, "%#.0f", "2.");
+        check!(1.0, "%-10.3f", "1.000     ");
+        check!(0.0, "%+f", "+0.000000");
+        check!(1.25, "% .1f", " 1.2");
+        check!(-0.0, "%f", "-0.000000");
+        check!(99.999, "%.2f", "100.00");
+        check!(0.1f32, "%.10f", "0.1000000015");
+        check!(2.675, "%.2f", "2.67");
+        check!(1.015, "%.2f", "1.01");
+        check!(1.0 / 3.0, "%.20f", "0.33333333333333331483");
+        check!(0.1, "%.30f", "0.100000000000000005551115123126");
+    }
+
+    #[test]
+    fn scientific_test() {
+        check!(1234.5, "%e", "1.234500e+03");
+        check!(0.000123, "%E", "1.230000E-04");
+        check!(1e100, "%.2e", "1.00e+100");
+        check!(15.0, "%.0e", "2e+01");
+        check!(1.0, "%#.0e", "1.e+00");
+        check!(0.0, "%e", "0.000000e+00");
+        check!(1e-300, "%e", "1.000000e-300");
+        check!(-1.5, "%012.3e", "-001.500e+00");
+        check!(9.9999999, "%e", "1.000000e+01");
+        check!(2.675, "%.2e", "2.67e+00");
+        check!(1e23, "%.20e", "9.99999999999999916114e+22");
+    }
+
+    #[test]
+    fn general_test() {
+        check!(100000.0, "%g", "100000");
+        check!(1000000.0, "%g", "1e+06");
+        check!(0.0001, "%g", "0.0001");
+        check!(0.00001, "%g", "1e-05");
+        check!(1e-10, "%G", "1E-10");
+        check!(1.5, "%#g", "1.50000");
+        check!(1234.0, "%.3g", "1.23e+03");
+        check!(0.0, "%g", "0");
+        check!(123.456, "%g", "123.456");
+        check!(15.0, "%.0g", "2e+01");
+        check!(1e100, "%g", "1e+100");
+        check!(2.675, "%.3g", "2.67");
+    }
+
+    #[test]
+    fn hexadecimal_test() {
+        check!(1.0, "%a", "0x1p+0");
+        check!(0.5, "%a", "0x1p-1");
+        check!(3.0, "%a", "0x1.8p+1");
+        check!(0.0, "%a", "0x0p+0");
+        check!(255.0, "%A", "0X1.FEP+7");
+        check!(1.96875, "%.1a", "0x2.0p+0");
+        check!(1.5, "%.0a", "0x2p+0");
+        check!(5e-324, "%a", "0x0.0000000000001p-1022");
+        check!(-0.1, "%a", "-0x1.999999999999ap-4");
+        check!(1.0, "%#a", "0x1.p+0");
+        check!(1.0, "%010a", "0x00001p+0");
+        check!(1.0, "%.2a", "0x1.00p+0");
+        check!(0.1f32, "%a", "0x1.99999ap-4");
+    }
+
+    #[test]
+    fn rounding_test() {
+        let write = |n: f64, spec: &str, rounding| {
+            PrintfSpec::parse(spec).unwrap().with_rounding(rounding).write(n).unwrap()
+        };
+        let away = RoundingKind::NearestTieAwayZero;
+        assert_eq!(write(2.5, "%.0f", RoundingKind::NearestTieEven), "2");
+        assert_eq!(write(2.5, "%.0f", away), "3");
+        assert_eq!(write(-2.5, "%.0f", away), "-3");
+        assert_eq!(write(1250.0, "%.1e", away), "1.3e+03");
+        assert_eq!(write(0.125, "%.2g", away), "0.13");
+        assert_eq!(write(99.95, "%.3g", away), "100");
+        assert_eq!(write(1.03125, "%.1a", RoundingKind::NearestTieEven), "0x1.0p+0");
+        assert_eq!(write(1.03125, "%.1a", away), "0x1.1p+0");
+        assert_eq!(write(-1.03125, "%.0a", RoundingKind::TowardNegativeInfinity), "-0x2p+0");
+        assert_eq!(write(1.03125, "%.0a", RoundingKind::TowardZero), "0x1p+0");
+        assert_eq!(write(1.21, "%.1f", RoundingKind::TowardPositiveInfinity), "1.3");
+
+        let spec = PrintfSpec::parse("%d").unwrap();
+        assert_eq!(spec.rounding(), RoundingKind::NearestTieEven);
+        assert_eq!(spec.with_rounding(away).write(25), Ok("25".into()));
+    }
+
+    #[test]
+    fn special_test() {
+        check!(f64::NAN, "%f", "nan");
+        check!(f64::INFINITY, "%F", "INF");
+        check!(f64::NEG_INFINITY, "%e", "-inf");
+        check!(f64::INFINITY, "%+f", "+inf");
+        check!(f64::INFINITY, "%05f", "  inf");
+        check!(f64::NAN, "%-6f", "nan   ");
+        check!(f64::NAN, "%E", "NAN");
+        check!(f64::INFINITY, "%a", "inf");
+    }
+}
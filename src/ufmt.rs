@@ -0,0 +1,144 @@
+//! Write numbers to `ufmt` writers.
+//!
+//! `ufmt` is a small, panic-free alternative to `core::fmt` for embedded
+//! targets, but it does not write floats. These functions write integers
+//! and floats with the lexical writers to any `uWrite` target, such as a
+//! UART or a logger, without the code size of `core::fmt`.
+//!
+//! Implement `uDisplay` for a numeric type by writing it to a [`Buffer`]
+//! and passing the string to `Formatter::write_str`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # extern crate ufmt_write;
+//! # pub fn main() {
+//! use ufmt_write::uWrite;
+//!
+//! // A writer to a fixed buffer, like a UART transmit buffer.
+//! struct Uart {
+//!     bytes: [u8; 32],
+//!     len: usize,
+//! }
+//!
+//! impl uWrite for Uart {
+//!     type Error = ();
+//!
+//!     fn write_str(&mut self, s: &str) -> Result<(), ()> {
+//!         let end = self.len + s.len();
+//!         self.bytes.get_mut(self.len..end).ok_or(())?.copy_from_slice(s.as_bytes());
+//!         self.len = end;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut uart = Uart { bytes: [0; 32], len: 0 };
+//! lexical::ufmt::write(-1.5f32, &mut uart).unwrap();
+//! uart.write_str(" V").unwrap();
+//! assert_eq!(&uart.bytes[..uart.len], b"-1.5 V");
+//! # }
+//! ```
+//!
+//! [`Buffer`]: ../struct.Buffer.html
+
+use crate::{to_str, to_str_with_options, ToLexical, ToLexicalOptions};
+use lexical_core::{BUFFER_SIZE, POSITIONAL_BUFFER_SIZE};
+use ufmt_write::uWrite;
+
+/// Write a number to a `ufmt` writer as a decimal string.
+///
+/// The number is written to a stack buffer of [`BUFFER_SIZE`] bytes,
+/// and passed to the writer with a single `write_str` call.
+///
+/// * `n`       - Number to write.
+/// * `w`       - Writer to write the number to.
+///
+/// [`BUFFER_SIZE`]: ../constant.BUFFER_SIZE.html
+#[inline]
+pub fn write<N: ToLexical, W: uWrite + ?Sized>(n: N, w: &mut W) -> Result<(), W::Error> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    w.write_str(to_str(n, &mut buffer))
+}
+
+/// Write a number to a `ufmt` writer with custom writing options.
+///
+/// The number is written to a stack buffer of [`POSITIONAL_BUFFER_SIZE`]
+/// bytes, and passed to the writer with a single `write_str` call.
+///
+/// * `n`       - Number to write.
+/// * `w`       - Writer to write the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// Panics if the options may require more than [`POSITIONAL_BUFFER_SIZE`]
+/// bytes, such as a large minimum number of exponent digits.
+///
+/// [`POSITIONAL_BUFFER_SIZE`]: ../constant.POSITIONAL_BUFFER_SIZE.html
+#[inline]
+pub fn write_with_options<N: ToLexicalOptions, W: uWrite + ?Sized>(
+    n: N,
+    w: &mut W,
+    options: &N::WriteOptions,
+) -> Result<(), W::Error> {
+    let mut buffer = [0u8; POSITIONAL_BUFFER_SIZE];
+    w.write_str(to_str_with_options(n, &mut buffer, options))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{WriteFloatOptions, WriteIntegerOptions};
+
+    // Writer that fails if the number does not fit.
+    struct Writer {
+        bytes: [u8; 16],
+        len: usize,
+    }
+
+    impl uWrite for Writer {
+        type Error = usize;
+
+        fn write_str(&mut self, s: &str) -> Result<(), usize> {
+            let end = self.len + s.len();
+            match self.bytes.get_mut(self.len..end) {
+                Some(bytes) => bytes.copy_from_slice(s.as_bytes()),
+                None => return Err(end),
+            }
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_test() {
+        let mut writer = Writer {
+            bytes: [0; 16],
+            len: 0,
+        };
+        assert_eq!(write(255u8, &mut writer), Ok(()));
+        assert_eq!(write(0.1f64, &mut writer), Ok(()));
+        assert_eq!(write(f32::NAN, &mut writer), Ok(()));
+        assert_eq!(&writer.bytes[..writer.len], b"2550.1NaN");
+        assert_eq!(write(u64::MAX, &mut writer), Err(29));
+    }
+
+    #[test]
+    fn write_with_options_test() {
+        let mut writer = Writer {
+            bytes: [0; 16],
+            len: 0,
+        };
+        let options = WriteIntegerOptions::builder().positive_sign(Some(b' ')).build().unwrap();
+        assert_eq!(write_with_options(42i32, &mut writer, &options), Ok(()));
+        let options = WriteFloatOptions::rust_display();
+        assert_eq!(write_with_options(1e15f64, &mut writer, &options), Err(19));
+        let options = WriteFloatOptions::rust_scientific();
+        assert_eq!(write_with_options(1e15f64, &mut writer, &options), Ok(()));
+        assert_eq!(&writer.bytes[..writer.len], b" 421e15");
+    }
+}
@@ -1,5 +1,6 @@
 //! Test comically large strings.
 
+#![cfg(feature = "floats")]
 #![allow(dead_code)]
 
 extern crate lexical;
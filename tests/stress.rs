@@ -1,7 +1,9 @@
 //! Stress tests adapted from:
 //!     https://www.icir.org/vern/papers/testbase-report.pdf
 
+#![cfg(feature = "floats")]
 #![allow(dead_code)]
+#![allow(clippy::eq_op, clippy::excessive_precision, clippy::zero_divided_by_zero)]
 
 extern crate lexical;
 use std::fmt::Debug;